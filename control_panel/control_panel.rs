@@ -76,6 +76,11 @@ impl ControlPanel {
                 .service(endpoints::health)
                 .service(endpoints::stop)
                 .service(endpoints::stats)
+                .service(endpoints::orders)
+                .service(endpoints::balances)
+                .service(endpoints::pnl)
+                .service(endpoints::snapshot)
+                .service(endpoints::resume_order_placement)
                 .service(endpoints::get_config)
                 .service(endpoints::set_config)
                 .service(
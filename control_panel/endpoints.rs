@@ -1,5 +1,6 @@
 use actix_web::{get, post, web, HttpResponse, Responder};
 use futures::FutureExt;
+use std::collections::HashMap;
 
 use crate::control_panel::{send_request, DataWebMmbRpcClient};
 
@@ -41,3 +42,88 @@ pub(super) async fn set_config(body: web::Bytes, client: DataWebMmbRpcClient) ->
 pub(super) async fn stats(client: DataWebMmbRpcClient) -> impl Responder {
     send_request(client, |client| client.stats().boxed()).await
 }
+
+#[get("/orders")]
+pub(super) async fn orders(client: DataWebMmbRpcClient) -> impl Responder {
+    send_request(client, |client| client.orders().boxed()).await
+}
+
+#[get("/balances")]
+pub(super) async fn balances(
+    query: web::Query<HashMap<String, String>>,
+    client: DataWebMmbRpcClient,
+) -> impl Responder {
+    let (valuation_mode, quote_currency_codes) = match valuation_query(&query) {
+        Ok(params) => params,
+        Err(err) => return HttpResponse::BadRequest().body(err),
+    };
+
+    send_request(client, move |client| {
+        client
+            .balances(valuation_mode.clone(), quote_currency_codes.clone())
+            .boxed()
+    })
+    .await
+}
+
+#[get("/pnl")]
+pub(super) async fn pnl(
+    query: web::Query<HashMap<String, String>>,
+    client: DataWebMmbRpcClient,
+) -> impl Responder {
+    let (valuation_mode, quote_currency_codes) = match valuation_query(&query) {
+        Ok(params) => params,
+        Err(err) => return HttpResponse::BadRequest().body(err),
+    };
+
+    send_request(client, move |client| {
+        client
+            .pnl(valuation_mode.clone(), quote_currency_codes.clone())
+            .boxed()
+    })
+    .await
+}
+
+#[get("/snapshot")]
+pub(super) async fn snapshot(client: DataWebMmbRpcClient) -> impl Responder {
+    send_request(client, |client| client.snapshot().boxed()).await
+}
+
+#[post("/resume_order_placement")]
+pub(super) async fn resume_order_placement(
+    query: web::Query<HashMap<String, String>>,
+    client: DataWebMmbRpcClient,
+) -> impl Responder {
+    let (exchange_account_id, currency_pair) =
+        match (query.get("exchange_account_id"), query.get("currency_pair")) {
+            (Some(exchange_account_id), Some(currency_pair)) => {
+                (exchange_account_id.clone(), currency_pair.clone())
+            }
+            _ => {
+                return HttpResponse::BadRequest().body(
+                    "Query parameters 'exchange_account_id' and 'currency_pair' are required",
+                )
+            }
+        };
+
+    send_request(client, move |client| {
+        client
+            .resume_order_placement(exchange_account_id.clone(), currency_pair.clone())
+            .boxed()
+    })
+    .await
+}
+
+/// Valuation mode and comma separated quote currencies from query parameters `valuation` and `quote`
+fn valuation_query(query: &HashMap<String, String>) -> Result<(String, Vec<String>), &'static str> {
+    let valuation_mode = query
+        .get("valuation")
+        .cloned()
+        .unwrap_or_else(|| "last_trade".to_owned());
+    let quote_currency_codes = match query.get("quote") {
+        Some(quote) => quote.split(',').map(|x| x.trim().to_owned()).collect(),
+        None => return Err("Query parameter 'quote' is required"),
+    };
+
+    Ok((valuation_mode, quote_currency_codes))
+}
@@ -1,6 +1,6 @@
-use rust_decimal::Decimal;
+use mmb_domain::order::snapshot::Amount;
 
 pub(crate) struct BalancePositionModel {
-    pub(crate) position: Decimal,
-    pub(crate) limit: Option<Decimal>,
+    pub(crate) position: Amount,
+    pub(crate) limit: Option<Amount>,
 }
@@ -40,9 +40,9 @@ use super::balance_reservation_preset::BalanceReservationPreset;
 pub(super) struct CanReserveResult {
     can_reserve: bool,
     preset: BalanceReservationPreset,
-    potential_position: Option<Decimal>,
-    old_balance: Decimal,
-    new_balance: Decimal,
+    potential_position: Option<Amount>,
+    old_balance: Amount,
+    new_balance: Amount,
 }
 
 #[derive(Clone)]
@@ -202,7 +202,7 @@ impl BalanceReservationManager {
             return Ok(());
         }
 
-        let balance_params = ReserveParameters::from_reservation(reservation, dec!(0));
+        let balance_params = ReserveParameters::from_reservation(reservation, Amount::ZERO);
 
         let old_balance = self.get_available_balance(&balance_params, true, &mut None);
 
@@ -219,7 +219,7 @@ impl BalanceReservationManager {
         log::info!("VirtualBalanceHolder {}", new_balance);
 
         let mut reservation = self.get_reservation_expected(reservation_id).clone();
-        if reservation.unreserved_amount < dec!(0)
+        if reservation.unreserved_amount < Amount::ZERO
             || reservation.is_amount_within_symbol_margin_error(reservation.unreserved_amount)
         {
             self.balance_reservation_storage.remove(reservation_id);
@@ -308,7 +308,7 @@ impl BalanceReservationManager {
             false,
             explanation,
         )
-        .unwrap_or(dec!(0))
+        .unwrap_or(Amount::ZERO)
     }
 
     pub fn try_get_available_balance_with_unknown_side(
@@ -463,18 +463,18 @@ impl BalanceReservationManager {
         exchange_account_id: ExchangeAccountId,
         symbol: Arc<Symbol>,
         side: OrderSide,
-    ) -> Decimal {
+    ) -> Amount {
         if !symbol.is_derivative {
-            return dec!(0);
+            return Amount::ZERO;
         }
 
         let current_position = self
             .position_by_fill_amount_in_amount_currency
             .get(exchange_account_id, symbol.currency_pair())
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
         match side {
-            OrderSide::Buy => dec!(0).max(-current_position),
-            OrderSide::Sell => dec!(0).max(current_position),
+            OrderSide::Buy => Amount::ZERO.max(-current_position),
+            OrderSide::Sell => Amount::ZERO.max(current_position),
         }
     }
 
@@ -483,7 +483,7 @@ impl BalanceReservationManager {
         exchange_account_id: ExchangeAccountId,
         symbol: Arc<Symbol>,
         side: OrderSide,
-    ) -> Decimal {
+    ) -> Amount {
         let position = self.get_position_in_amount_currency_code(exchange_account_id, symbol, side);
 
         let taken_amount = self
@@ -494,7 +494,7 @@ impl BalanceReservationManager {
             .map(|(_, balance_reservation)| balance_reservation.taken_free_amount)
             .sum::<Amount>();
 
-        dec!(0).max(position - taken_amount)
+        Amount::ZERO.max(position - taken_amount)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -523,7 +523,7 @@ impl BalanceReservationManager {
         let reserved_amount_in_amount_currency = self
             .reserved_amount_in_amount_currency
             .get_by_balance_request(request)
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
 
         explanation.with_reason(|| {
             format!("reserved_amount_in_amount_currency: {reserved_amount_in_amount_currency}")
@@ -535,7 +535,7 @@ impl BalanceReservationManager {
             format!("reservation_with_fills_in_amount_currency: {reservation_with_fills_in_amount_currency}")
         });
 
-        let total_amount_limit_in_amount_currency = position.limit.unwrap_or(dec!(0));
+        let total_amount_limit_in_amount_currency = position.limit.unwrap_or(Amount::ZERO);
         explanation.with_reason(|| {
             format!(
                 "total_amount_limit_in_amount_currency: {total_amount_limit_in_amount_currency}"
@@ -588,11 +588,11 @@ impl BalanceReservationManager {
             format!("limited_balance_in_currency_code without leverage and multiplier: {limited_balance_in_currency_code}")
         });
 
-        if limited_balance_in_currency_code < dec!(0) {
+        if limited_balance_in_currency_code < Amount::ZERO {
             log::warn!("Balance {limited_balance_in_currency_code} < 0 ({total_amount_limit_in_amount_currency} - ({reserved_amount_in_amount_currency} + {position_amount_in_amount_currency}) {balance_in_amount_currency} for {request:?} {symbol:?}");
         };
 
-        dec!(0).max(limited_balance_in_currency_code)
+        Amount::ZERO.max(limited_balance_in_currency_code)
     }
 
     fn get_untouchable_amount(symbol: Arc<Symbol>, amount: Amount) -> Amount {
@@ -600,7 +600,7 @@ impl BalanceReservationManager {
         // many derivative nuances (commissions, funding, probably something else
         match symbol.is_derivative {
             true => amount * dec!(0.05),
-            false => dec!(0),
+            false => Amount::ZERO,
         }
     }
 
@@ -650,7 +650,7 @@ impl BalanceReservationManager {
         exchange_account_id: ExchangeAccountId,
         currency_pair: CurrencyPair,
         side: OrderSide,
-    ) -> Decimal {
+    ) -> Amount {
         let symbol = self
             .currency_pair_to_symbol_converter
             .get_symbol(exchange_account_id, currency_pair);
@@ -659,7 +659,7 @@ impl BalanceReservationManager {
         let mut position_in_amount_currency = self
             .position_by_fill_amount_in_amount_currency
             .get(exchange_account_id, currency_pair)
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
 
         match (
             symbol.is_derivative,
@@ -685,7 +685,7 @@ impl BalanceReservationManager {
             None => {
                 reservation.not_approved_amount -= amount_to_unreserve;
                 // this case will be handled by UnReserve itself
-                if reservation.not_approved_amount < dec!(0)
+                if reservation.not_approved_amount < Amount::ZERO
                     && reservation.unreserved_amount > amount_to_unreserve
                 {
                     bail!("Possibly BalanceReservationManager::unreserve_not_approved_part {reservation_id} should be called with clientOrderId parameter");
@@ -699,7 +699,7 @@ impl BalanceReservationManager {
             None => {
                 log::warn!("unreserve({reservation_id}, {amount_to_unreserve}) called with clientOrderId {client_order_id} for reservation without the approved part {reservation:?}");
                 reservation.not_approved_amount -= amount_to_unreserve;
-                if reservation.not_approved_amount < dec!(0) {
+                if reservation.not_approved_amount < Amount::ZERO {
                     log::error!("not_approved_amount for {reservation_id} was unreserved for the missing order {client_order_id} and now < 0 {reservation:?}");
                 }
                 return Ok(());
@@ -708,7 +708,7 @@ impl BalanceReservationManager {
 
         let new_unreserved_amount_for_approved_part =
             approved_part.unreserved_amount - amount_to_unreserve;
-        if new_unreserved_amount_for_approved_part < dec!(0) {
+        if new_unreserved_amount_for_approved_part < Amount::ZERO {
             bail!("Attempt to unreserve more than was approved for order {client_order_id} ({reservation_id}): {amount_to_unreserve} > {}", approved_part.unreserved_amount);
         }
         approved_part.unreserved_amount = new_unreserved_amount_for_approved_part;
@@ -807,7 +807,7 @@ impl BalanceReservationManager {
         &mut self,
         exchange_account_id: ExchangeAccountId,
         symbol: Arc<Symbol>,
-        new_position: Decimal,
+        new_position: Amount,
     ) -> Result<()> {
         if !symbol.is_derivative {
             bail!("restore_fill_amount_position is available only for derivative exchanges");
@@ -867,7 +867,7 @@ impl BalanceReservationManager {
         exchange_account_id: ExchangeAccountId,
         symbol: Arc<Symbol>,
     ) -> (Amount, CurrencyCode) {
-        let mut change_amount_in_currency = dec!(0);
+        let mut change_amount_in_currency = Amount::ZERO;
 
         let currency_code = symbol.get_trade_code(side, before_after);
         let request = BalanceRequest::new(
@@ -897,8 +897,8 @@ impl BalanceReservationManager {
                     side,
                 );
                 let move_amount = fill_amount.abs();
-                let (add_amount, sub_amount) = if free_amount - move_amount >= dec!(0) {
-                    (move_amount, dec!(0))
+                let (add_amount, sub_amount) = if free_amount - move_amount >= Amount::ZERO {
+                    (move_amount, Amount::ZERO)
                 } else {
                     (free_amount, (free_amount - move_amount).abs())
                 };
@@ -1073,7 +1073,7 @@ impl BalanceReservationManager {
 
         reservation.not_approved_amount -= amount;
 
-        if reservation.not_approved_amount < dec!(0)
+        if reservation.not_approved_amount < Amount::ZERO
             && !reservation.is_amount_within_symbol_margin_error(reservation.not_approved_amount)
         {
             log::error!("RestApprovedAmount < 0 for order {client_order_id} {reservation_id} {amount} {reservation:?}");
@@ -1140,7 +1140,7 @@ impl BalanceReservationManager {
                     .with_expect(|| {
                         format!("failed to get available balance for {dst_reservation:?}")
                     });
-                if available_balance + balance_diff_amount < dec!(0) {
+                if available_balance + balance_diff_amount < Amount::ZERO {
                     log::warn!("Can't transfer {amount_to_move} because there will be insufficient balance ({src_reservation_id} => {dst_reservation_id})");
                     return false;
                 }
@@ -1172,7 +1172,7 @@ impl BalanceReservationManager {
             new_src_unreserved_amount,
             client_order_id,
             true,
-            dec!(0),
+            Amount::ZERO,
         );
 
         let dst_reservation = self.get_reservation_expected(dst_reservation_id);
@@ -1195,12 +1195,12 @@ impl BalanceReservationManager {
         new_unreserved_amount: Amount,
         client_order_id: &Option<ClientOrderId>,
         is_src_request: bool,
-        target_cost_diff: Decimal,
-    ) -> Decimal {
+        target_cost_diff: Amount,
+    ) -> Amount {
         let approve_time = time_manager::now();
         let reservation = self.get_mut_reservation_expected(reservation_id);
         // we should check the case when we have insignificant calculation errors
-        if new_unreserved_amount < dec!(0)
+        if new_unreserved_amount < Amount::ZERO
             && !reservation.is_amount_within_symbol_margin_error(new_unreserved_amount)
         {
             panic!("Can't set {new_unreserved_amount} amount to reservation {reservation_id}");
@@ -1212,7 +1212,7 @@ impl BalanceReservationManager {
                 let new_amount = approved_part.unreserved_amount + reservation_amount_diff;
                 if reservation.is_amount_within_symbol_margin_error(new_amount) {
                     let _ = reservation.approved_parts.remove(client_order_id);
-                } else if new_amount < dec!(0) {
+                } else if new_amount < Amount::ZERO {
                     panic!(
                         "Attempt to transfer more amount ({reservation_amount_diff}) than we have ({}) for approved part by ClientOrderId {client_order_id}",
                         reservation
@@ -1421,7 +1421,7 @@ impl BalanceReservationManager {
             .symbol
             .round_to_remove_amount_precision_error_expected(new_balance);
         CanReserveResult {
-            can_reserve: rounded_balance >= dec!(0),
+            can_reserve: rounded_balance >= Amount::ZERO,
             preset,
             potential_position,
             old_balance,
@@ -1441,7 +1441,7 @@ impl BalanceReservationManager {
     fn can_reserve_with_limit(
         &self,
         reserve_parameters: &ReserveParameters,
-    ) -> (bool, Option<Decimal>) {
+    ) -> (bool, Option<Amount>) {
         let reservation_currency_code = reserve_parameters
             .symbol
             .get_trade_code(reserve_parameters.order_side, BeforeAfter::Before);
@@ -1466,7 +1466,7 @@ impl BalanceReservationManager {
         let reserved_amount = self
             .reserved_amount_in_amount_currency
             .get_by_balance_request(&request)
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
         let new_reserved_amount = reserved_amount + reserve_parameters.amount;
 
         // The sign depends on reserve_parameters.order_side look comment for this function
@@ -1538,7 +1538,7 @@ impl BalanceReservationManager {
         reserve_parameters: &ReserveParameters,
     ) -> (Amount, Amount) {
         if !reserve_parameters.symbol.is_derivative {
-            return (reserve_parameters.amount, dec!(0));
+            return (reserve_parameters.amount, Amount::ZERO);
         }
 
         let free_amount = self.get_unreserved_position_in_amount_currency_code(
@@ -1547,7 +1547,7 @@ impl BalanceReservationManager {
             reserve_parameters.order_side,
         );
 
-        let amount_to_pay_for = dec!(0).max(reserve_parameters.amount - free_amount);
+        let amount_to_pay_for = Amount::ZERO.max(reserve_parameters.amount - free_amount);
 
         let taken_free_amount = reserve_parameters.amount - amount_to_pay_for;
 
@@ -1582,7 +1582,7 @@ impl BalanceReservationManager {
             }
         };
 
-        let approved_sum: Decimal = reservation
+        let approved_sum: Amount = reservation
             .approved_parts
             .iter()
             .filter(|(_, approved_part)| approved_part.is_canceled)
@@ -1621,7 +1621,7 @@ impl BalanceReservationManager {
             });
 
         let new_balance = old_balance - reservation_amount_diff_in_reservation_currency;
-        if new_balance < dec!(0) {
+        if new_balance < Amount::ZERO {
             log::info!(
                 "Failed to update reservation {} {} {} {:?} {} {} {} {} {}",
                 reservation_id,
@@ -1,5 +1,4 @@
 use mmb_domain::order::snapshot::Amount;
-use rust_decimal::Decimal;
 
 use mmb_domain::market::CurrencyCode;
 
@@ -7,8 +6,8 @@ pub(crate) struct BalanceReservationPreset {
     pub(crate) reservation_currency_code: CurrencyCode,
     pub(crate) amount_in_reservation_currency_code: Amount,
     pub(crate) taken_free_amount_in_amount_currency_code: Amount,
-    pub(crate) cost_in_reservation_currency_code: Decimal,
-    pub(crate) cost_in_amount_currency_code: Decimal,
+    pub(crate) cost_in_reservation_currency_code: Amount,
+    pub(crate) cost_in_amount_currency_code: Amount,
 }
 
 impl BalanceReservationPreset {
@@ -16,8 +15,8 @@ impl BalanceReservationPreset {
         reservation_currency_code: CurrencyCode,
         amount_in_reservation_currency_code: Amount,
         taken_free_amount_in_amount_currency_code: Amount,
-        cost_in_reservation_currency_code: Decimal,
-        cost_in_amount_currency_code: Decimal,
+        cost_in_reservation_currency_code: Amount,
+        cost_in_amount_currency_code: Amount,
     ) -> Self {
         Self {
             reservation_currency_code,
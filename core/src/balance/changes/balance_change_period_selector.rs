@@ -155,6 +155,7 @@ mod tests {
     use mmb_domain::order::snapshot::ClientOrderFillId;
 
     use super::*;
+    use mmb_domain::amount;
 
     #[test]
     fn test_position_change_before_period() {
@@ -180,7 +181,7 @@ mod tests {
 
         for i in 0..5 {
             let balance_change = create_balance_change(
-                dec!(1),
+                amount!(1),
                 time_manager::now() + Duration::minutes(10 * i),
                 ClientOrderFillId::new(i.to_string().into()),
             );
@@ -220,7 +221,7 @@ mod tests {
                             CurrencyPair::from_codes("BTC".into(), "ETH".into()),
                         );
                         let balance_change = create_balance_change_by_market_account_id(
-                            dec!(1),
+                            amount!(1),
                             time_manager::now() + Duration::minutes(10 * i as i64),
                             ClientOrderFillId::new(i.to_string().into()),
                             market_account_id,
@@ -4,6 +4,7 @@ use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::infrastructure::WithExpect;
 use mockall_double::double;
 
+use crate::math::{DecimalComputation, RoundForComputation};
 #[double]
 use crate::services::usd_convertion::usd_converter::UsdConverter;
 
@@ -15,7 +16,8 @@ pub(crate) fn calculate_raw(profit_loss_balance_changes: &[ProfitLossBalanceChan
     profit_loss_balance_changes
         .iter()
         .map(|x| x.usd_balance_change)
-        .sum()
+        .sum::<Amount>()
+        .round_for(DecimalComputation::ProfitLoss)
 }
 
 pub(crate) async fn calculate_over_market(
@@ -43,5 +45,9 @@ pub(crate) async fn calculate_over_market(
                 }
             });
 
-    join_all(usd_converter_actions).await.iter().sum()
+    join_all(usd_converter_actions)
+        .await
+        .iter()
+        .sum::<Amount>()
+        .round_for(DecimalComputation::ProfitLoss)
 }
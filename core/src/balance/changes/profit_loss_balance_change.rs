@@ -55,7 +55,7 @@ impl ProfitLossBalanceChange {
             ),
             currency_code: request.currency_code,
             balance_change,
-            usd_price: usd_balance_change / balance_change,
+            usd_price: Price::new(usd_balance_change / balance_change),
             usd_balance_change,
         }
     }
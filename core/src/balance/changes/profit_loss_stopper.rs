@@ -136,6 +136,7 @@ pub(crate) mod test {
     };
     use mmb_domain::order::snapshot::Amount;
     use mmb_domain::order::snapshot::ClientOrderFillId;
+    use mmb_domain::{amount, price};
     use mmb_utils::{logger::init_logger, DateTime};
     use parking_lot::{Mutex, ReentrantMutexGuard};
     use rust_decimal_macros::dec;
@@ -175,7 +176,7 @@ pub(crate) mod test {
         "BTC".into()
     }
 
-    static LIMIT: Amount = dec!(10);
+    static LIMIT: Amount = amount!(10);
 
     fn max_period() -> Duration {
         Duration::hours(1)
@@ -322,7 +323,7 @@ pub(crate) mod test {
             market_account_id,
             currency_code: btc(),
             balance_change: usd_balance_change * dec!(2),
-            usd_price: dec!(1),
+            usd_price: price!(1),
             usd_balance_change: usd_balance_change * dec!(2),
         }
     }
@@ -335,7 +336,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(2),
+                amount!(2),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -344,12 +345,12 @@ pub(crate) mod test {
             .balance_change_usd_periodic_calculator
             .calculate_over_market_usd_change(&context.usd_converter, CancellationToken::default())
             .await;
-        assert_eq!(over_market_usd_change_1, dec!(2));
+        assert_eq!(over_market_usd_change_1, amount!(2));
 
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(3),
+                amount!(3),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -358,7 +359,7 @@ pub(crate) mod test {
             .balance_change_usd_periodic_calculator
             .calculate_over_market_usd_change(&context.usd_converter, CancellationToken::default())
             .await;
-        assert_eq!(over_market_usd_change_2, dec!(2) + dec!(3));
+        assert_eq!(over_market_usd_change_2, amount!(2) + amount!(3));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -369,7 +370,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(1),
+                amount!(1),
                 time_manager::now() - (max_period() + Duration::seconds(1)),
                 ClientOrderFillId::unique_id(),
             ));
@@ -377,7 +378,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(2),
+                amount!(2),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -386,7 +387,7 @@ pub(crate) mod test {
             .balance_change_usd_periodic_calculator
             .calculate_over_market_usd_change(&context.usd_converter, CancellationToken::default())
             .await;
-        assert_eq!(over_market_usd_change, dec!(2));
+        assert_eq!(over_market_usd_change, amount!(2));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -413,12 +414,12 @@ pub(crate) mod test {
             .balance_manager
             .lock()
             .expect_get_position()
-            .returning(|_, _, _| dec!(0));
+            .returning(|_, _, _| amount!(0));
 
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-8),
+                amount!(-8),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -431,7 +432,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-3),
+                amount!(-3),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -477,12 +478,12 @@ pub(crate) mod test {
             .balance_manager
             .lock()
             .expect_get_position()
-            .returning(|_, _, _| dec!(0));
+            .returning(|_, _, _| amount!(0));
 
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-8),
+                amount!(-8),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -495,7 +496,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-3),
+                amount!(-3),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -508,7 +509,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(2),
+                amount!(2),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -554,12 +555,12 @@ pub(crate) mod test {
             .balance_manager
             .lock()
             .expect_get_position()
-            .returning(|_, _, _| dec!(0));
+            .returning(|_, _, _| amount!(0));
 
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-8),
+                amount!(-8),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -572,7 +573,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-3),
+                amount!(-3),
                 time_manager::now(),
                 ClientOrderFillId::new(
                     "needed_to_simulate_that_the_first_change_has_expired".into(),
@@ -639,12 +640,12 @@ pub(crate) mod test {
             .balance_manager
             .lock()
             .expect_get_position()
-            .returning(|_, _, _| dec!(0));
+            .returning(|_, _, _| amount!(0));
 
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-8),
+                amount!(-8),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
@@ -659,7 +660,7 @@ pub(crate) mod test {
         context
             .balance_change_usd_periodic_calculator
             .add_balance_change(&create_balance_change(
-                dec!(-3),
+                amount!(-3),
                 time_manager::now(),
                 client_order_fill_id(),
             ));
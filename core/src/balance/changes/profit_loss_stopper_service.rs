@@ -136,11 +136,11 @@ impl BalanceChangeAccumulator for ProfitLossStopperService {
 mod test {
     use mmb_domain::market::CurrencyPair;
     use mmb_domain::market::{ExchangeAccountId, MarketAccountId};
-    use rust_decimal_macros::dec;
 
     use crate::settings::StopperCondition;
 
     use super::*;
+    use mmb_domain::amount;
 
     fn exchange_account_id() -> ExchangeAccountId {
         ExchangeAccountId::new("exchange_test_id", 0)
@@ -159,7 +159,7 @@ mod test {
             conditions: vec![StopperCondition {
                 period_kind: TimePeriodKind::Day,
                 period_value: 1,
-                limit: dec!(50),
+                limit: amount!(50),
             }],
         };
 
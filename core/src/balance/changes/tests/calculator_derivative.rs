@@ -1,9 +1,9 @@
 #[cfg(test)]
 pub mod tests {
     use mmb_domain::order::snapshot::OrderSide;
-    use rust_decimal_macros::dec;
 
     use crate::balance::changes::tests::calculator_tests_base::tests::BalanceChangesCalculatorTestsBase;
+    use mmb_domain::{amount, price};
 
     type TestBase = BalanceChangesCalculatorTestsBase;
 
@@ -11,8 +11,8 @@ pub mod tests {
     pub async fn simple_buy() {
         let mut test_obj = TestBase::new(true, false);
 
-        let price_base_quote = dec!(0.133);
-        let amount_in_quote = dec!(103);
+        let price_base_quote = price!(0.133);
+        let amount_in_quote = amount!(103);
         let amount_in_base = amount_in_quote / price_base_quote;
         let filled_amount_in_quote = amount_in_quote;
         let commission_amount_in_base = amount_in_base * TestBase::commission_rate_1();
@@ -55,8 +55,8 @@ pub mod tests {
     pub async fn simple_sell() {
         let mut test_obj = TestBase::new(true, false);
 
-        let price_base_quote = dec!(0.843);
-        let amount_in_quote = dec!(12);
+        let price_base_quote = price!(0.843);
+        let amount_in_quote = amount!(12);
         let filled_amount_in_quote = amount_in_quote;
         let commission_amount_in_base =
             filled_amount_in_quote / price_base_quote * TestBase::commission_rate_1();
@@ -117,8 +117,8 @@ pub mod tests {
 
         // Direction 1 description
         let trade_side_1 = OrderSide::Buy;
-        let price_base_quote_1 = dec!(0.9483);
-        let amount_in_base_1 = dec!(10);
+        let price_base_quote_1 = price!(0.9483);
+        let amount_in_base_1 = amount!(10);
         let amount_in_quote_1 = amount_in_base_1 * price_base_quote_1;
         let filled_amount_in_quote_1 = amount_in_quote_1;
         let commission_amount_in_base_1 =
@@ -126,8 +126,8 @@ pub mod tests {
 
         // Direction 2 description
         let trade_side_2 = OrderSide::Sell;
-        let price_base_quote_2 = dec!(1.5302);
-        let amount_in_base_2 = dec!(15);
+        let price_base_quote_2 = price!(1.5302);
+        let amount_in_base_2 = amount!(15);
         let amount_in_quote_2 = amount_in_base_2 * price_base_quote_2;
         let filled_amount_in_quote_2 = amount_in_quote_2;
         let commission_amount_in_base_2 =
@@ -1,9 +1,9 @@
 #[cfg(test)]
 mod tests {
     use mmb_domain::order::snapshot::OrderSide;
-    use rust_decimal_macros::dec;
 
     use crate::balance::changes::tests::calculator_tests_base::tests::BalanceChangesCalculatorTestsBase;
+    use mmb_domain::{amount, price};
 
     type TestBase = BalanceChangesCalculatorTestsBase;
 
@@ -17,8 +17,8 @@ mod tests {
          */
         let mut test_obj = TestBase::new(false, false);
 
-        let price_base_quote = dec!(0.5);
-        let amount_in_base = dec!(5);
+        let price_base_quote = price!(0.5);
+        let amount_in_base = amount!(5);
         let amount_in_quote = amount_in_base * price_base_quote; // needed amount in quote for buy base
         let filled_amount_in_base = amount_in_base;
         let commission_amount_in_base = filled_amount_in_base * TestBase::commission_rate_1();
@@ -67,8 +67,8 @@ mod tests {
          */
         let mut test_obj = TestBase::new(false, false);
 
-        let price_base_quote = dec!(1.232);
-        let amount_in_base = dec!(14);
+        let price_base_quote = price!(1.232);
+        let amount_in_base = amount!(14);
         let amount_in_quote = amount_in_base * price_base_quote; // needed amount in quote for buy base
         let filled_amount_in_base = amount_in_base;
         let commission_amount_in_quote = amount_in_quote * TestBase::commission_rate_1();
@@ -133,10 +133,10 @@ mod tests {
         let mut test_obj = TestBase::new(false, false);
 
         // same for both directions
-        let price_base_quote = dec!(0.7);
-        let amount_in_base = dec!(12);
+        let price_base_quote = price!(0.7);
+        let amount_in_base = amount!(12);
         let filled_amount_in_base = amount_in_base;
-        let commission_amount_in_base = dec!(0);
+        let commission_amount_in_base = amount!(0);
 
         let trade_side_1 = OrderSide::Buy;
         let trade_side_2 = OrderSide::Sell;
@@ -164,8 +164,8 @@ mod tests {
         );
 
         // // Expected
-        let base_balance_changed = dec!(0);
-        let quote_balance_changed = dec!(0);
+        let base_balance_changed = amount!(0);
+        let quote_balance_changed = amount!(0);
 
         // // Actual
         test_obj
@@ -214,10 +214,10 @@ mod tests {
         let mut test_obj = TestBase::new(false, false);
 
         // same for both directions
-        let price_base_quote = dec!(1.2);
-        let amount_in_quote = dec!(40.2398462);
+        let price_base_quote = price!(1.2);
+        let amount_in_quote = amount!(40.2398462);
         let filled_amount_in_quote = amount_in_quote;
-        let commission_amount_in_base = dec!(0);
+        let commission_amount_in_base = amount!(0);
 
         let trade_side_1 = OrderSide::Buy;
         let trade_side_2 = OrderSide::Sell;
@@ -245,8 +245,8 @@ mod tests {
         );
 
         // // Expected
-        let base_balance_changed = dec!(0);
-        let quote_balance_changed = dec!(0);
+        let base_balance_changed = amount!(0);
+        let quote_balance_changed = amount!(0);
 
         // // Actual
         test_obj
@@ -291,15 +291,15 @@ mod tests {
 
         // Direction 1 description
         let trade_side_1 = OrderSide::Buy;
-        let price_base_quote_1 = dec!(1.843);
-        let amount_in_base_1 = dec!(49.1273);
+        let price_base_quote_1 = price!(1.843);
+        let amount_in_base_1 = amount!(49.1273);
         let filled_amount_in_base_1 = amount_in_base_1;
         let commission_amount_in_base_1 = filled_amount_in_base_1 * TestBase::commission_rate_1();
 
         // Direction 2 description
         let trade_side_2 = OrderSide::Sell;
-        let price_base_quote_2 = dec!(3.1231);
-        let amount_in_base_2 = dec!(50);
+        let price_base_quote_2 = price!(3.1231);
+        let amount_in_base_2 = amount!(50);
         let filled_amount_in_base_2 = amount_in_base_2;
         let commission_amount_in_quote_2 =
             filled_amount_in_base_2 * price_base_quote_2 * TestBase::commission_rate_1();
@@ -9,12 +9,15 @@ mod tests {
     use mockall_double::double;
     use parking_lot::ReentrantMutexGuard;
     use rstest::rstest;
-    use rust_decimal::Decimal;
+
     use rust_decimal_macros::dec;
 
     use crate::balance::changes::tests::calculator_tests_base::tests::BalanceChangesCalculatorTestsBase;
     #[double]
     use crate::services::usd_convertion::usd_converter::UsdConverter;
+    use mmb_domain::amount;
+    use mmb_domain::order::snapshot::Amount;
+    use mmb_domain::price;
 
     type TestBase = BalanceChangesCalculatorTestsBase;
 
@@ -37,18 +40,18 @@ mod tests {
     }
 
     #[rstest]
-    #[case(OrderSide::Buy, dec!(8_000), dec!(4_000), dec!(-50))] // buy, price dropped
-    #[case(OrderSide::Buy, dec!(4_000), dec!(8_000), dec!(100))] // buy, price rose
-    #[case(OrderSide::Buy, dec!(8_000), dec!(8_000), dec!(0))] // buy, same price
-    #[case(OrderSide::Sell, dec!(8_000), dec!(4_000), dec!(50))] // sell, price dropped
-    #[case(OrderSide::Sell, dec!(4_000), dec!(8_000), dec!(-100))] // sell, price rose
-    #[case(OrderSide::Sell, dec!(8_000), dec!(8_000), dec!(0))] // sell, same price
+    #[case(OrderSide::Buy, price!(8_000), price!(4_000), amount!(-50))] // buy, price dropped
+    #[case(OrderSide::Buy, price!(4_000), price!(8_000), amount!(100))] // buy, price rose
+    #[case(OrderSide::Buy, price!(8_000), price!(8_000), amount!(0))] // buy, same price
+    #[case(OrderSide::Sell, price!(8_000), price!(4_000), amount!(50))] // sell, price dropped
+    #[case(OrderSide::Sell, price!(4_000), price!(8_000), amount!(-100))] // sell, price rose
+    #[case(OrderSide::Sell, price!(8_000), price!(8_000), amount!(0))] // sell, same price
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_by_side_and_price_no_commission(
         #[case] side: OrderSide,
-        #[case] trade_price: Decimal,
-        #[case] new_price: Decimal,
-        #[case] profit: Decimal,
+        #[case] trade_price: Price,
+        #[case] new_price: Price,
+        #[case] profit: Amount,
     ) {
         let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
             TestBase::base() => new_price
@@ -57,7 +60,7 @@ mod tests {
         let mut test_obj =
             TestBase::new_with_usd_converter(true, true, usd_converter, usd_converter_locker);
 
-        let amount = dec!(100) / trade_price / TestBase::amount_multiplier(); //equivalent of $100
+        let amount = amount!(100) / trade_price / TestBase::amount_multiplier(); //equivalent of $100
 
         let order = TestBase::create_order_with_commission_amount(
             TestBase::exchange_account_id_1(),
@@ -67,7 +70,7 @@ mod tests {
             amount,
             amount,
             TestBase::quote(),
-            dec!(0),
+            amount!(0),
         );
 
         test_obj.calculate_balance_changes(vec![&order]).await;
@@ -80,16 +83,16 @@ mod tests {
     }
 
     #[rstest]
-    #[case(OrderSide::Buy, dec!(8_000), dec!(8_000), dec!(-10))] // no price change, minus commission
-    #[case(OrderSide::Sell, dec!(8_000), dec!(8_000), dec!(-10))] // no price change, minus commission
-    #[case(OrderSide::Buy, dec!(8_000), dec!(8_800), dec!(0))] // positive minus commission
-    #[case(OrderSide::Sell, dec!(8_000), dec!(7_200), dec!(0))] // positive minus commission
+    #[case(OrderSide::Buy, price!(8_000), price!(8_000), amount!(-10))] // no price change, minus commission
+    #[case(OrderSide::Sell, price!(8_000), price!(8_000), amount!(-10))] // no price change, minus commission
+    #[case(OrderSide::Buy, price!(8_000), price!(8_800), amount!(0))] // positive minus commission
+    #[case(OrderSide::Sell, price!(8_000), price!(7_200), amount!(0))] // positive minus commission
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_by_side_and_price_with_commission(
         #[case] side: OrderSide,
-        #[case] trade_price: Decimal,
-        #[case] new_price: Decimal,
-        #[case] profit: Decimal,
+        #[case] trade_price: Price,
+        #[case] new_price: Price,
+        #[case] profit: Amount,
     ) {
         let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
             TestBase::base() => new_price
@@ -98,8 +101,8 @@ mod tests {
         let mut test_obj =
             TestBase::new_with_usd_converter(true, true, usd_converter, usd_converter_locker);
 
-        let commission_in_quote = dec!(10);
-        let amount = dec!(100) / trade_price / TestBase::amount_multiplier(); //equivalent of $100
+        let commission_in_quote = amount!(10);
+        let amount = amount!(100) / trade_price / TestBase::amount_multiplier(); //equivalent of $100
 
         let order = TestBase::create_order_with_commission_amount(
             TestBase::exchange_account_id_1(),
@@ -123,8 +126,8 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_two_orders_with_commission() {
-        let first_price = dec!(10_000);
-        let second_price = dec!(2_000);
+        let first_price = price!(10_000);
+        let second_price = price!(2_000);
 
         let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
             TestBase::base() => second_price
@@ -133,7 +136,7 @@ mod tests {
         let mut test_obj =
             TestBase::new_with_usd_converter(true, true, usd_converter, usd_converter_locker);
 
-        let amount = dec!(10_000);
+        let amount = amount!(10_000);
         let commission_rate_make = dec!(-0.025);
 
         let first_side = OrderSide::Buy;
@@ -10,6 +10,7 @@ pub mod tests {
     use mmb_domain::order::snapshot::{
         ClientOrderFillId, ClientOrderId, OrderFillRole, OrderSide, OrderSnapshot,
     };
+    use mmb_domain::{amount, price};
     use mmb_utils::cancellation_token::CancellationToken;
     use mmb_utils::hashmap;
     use mockall_double::double;
@@ -177,8 +178,8 @@ pub mod tests {
             init_lifetime_manager();
 
             let (usd_converter, usd_converter_locker) = Self::init_usd_converter(hashmap![
-                Self::base() => dec!(1000),
-                Self::quote() => dec!(1)
+                Self::base() => price!(1000),
+                Self::quote() => price!(1)
             ]);
 
             Self::new_with_usd_converter(
@@ -280,7 +281,7 @@ pub mod tests {
                 "in test",
             );
 
-            if filled_amount > dec!(0) {
+            if filled_amount > amount!(0) {
                 order.add_fill(OrderFill::new(
                     Uuid::nil(),
                     None,
@@ -289,11 +290,11 @@ pub mod tests {
                     None,
                     price,
                     filled_amount,
-                    dec!(0),
+                    amount!(0),
                     OrderFillRole::Maker,
                     commission_currency_code,
                     commission_amount,
-                    dec!(0),
+                    amount!(0),
                     commission_currency_code,
                     commission_amount,
                     commission_amount,
@@ -347,7 +348,7 @@ pub mod tests {
             exchange_account_id: ExchangeAccountId,
             currency_pair: CurrencyPair,
             currency_code: CurrencyCode,
-        ) -> Decimal {
+        ) -> Amount {
             let request = BalanceRequest::new(
                 self.configuration_descriptor,
                 exchange_account_id,
@@ -360,16 +361,16 @@ pub mod tests {
                 .map(|x| {
                     x.get_changes()
                         .get_by_balance_request(&request)
-                        .unwrap_or(dec!(0))
+                        .unwrap_or(amount!(0))
                 })
                 .sum()
         }
 
-        pub fn calculate_raw_profit(&self) -> Decimal {
+        pub fn calculate_raw_profit(&self) -> Amount {
             profit_balance_changes_calculator::calculate_raw(&self.profit_loss_balance_changes)
         }
 
-        pub async fn calculate_over_market_profit(&self) -> Decimal {
+        pub async fn calculate_over_market_profit(&self) -> Amount {
             profit_balance_changes_calculator::calculate_over_market(
                 &self.profit_loss_balance_changes,
                 &self.usd_converter,
@@ -13,6 +13,8 @@ mod tests {
     use crate::balance::changes::tests::calculator_tests_base::tests::BalanceChangesCalculatorTestsBase;
     #[double]
     use crate::services::usd_convertion::usd_converter::UsdConverter;
+    use mmb_domain::amount;
+    use mmb_domain::price;
 
     type TestBase = BalanceChangesCalculatorTestsBase;
 
@@ -38,12 +40,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_by_side_and_price_no_commission() {
         let cases = vec![
-            (OrderSide::Buy, dec!(8_000), dec!(4_000), dec!(-50)), // buy, price dropped
-            (OrderSide::Buy, dec!(4_000), dec!(8_000), dec!(100)), // buy, price rose
-            (OrderSide::Buy, dec!(8_000), dec!(8_000), dec!(0)),   // buy, same price
-            (OrderSide::Sell, dec!(8_000), dec!(4_000), dec!(50)), // sell, price dropped
-            (OrderSide::Sell, dec!(4_000), dec!(8_000), dec!(-100)), // sell, price rose
-            (OrderSide::Sell, dec!(8_000), dec!(8_000), dec!(0)),  // sell, same price
+            (OrderSide::Buy, price!(8_000), price!(4_000), amount!(-50)), // buy, price dropped
+            (OrderSide::Buy, price!(4_000), price!(8_000), amount!(100)), // buy, price rose
+            (OrderSide::Buy, price!(8_000), price!(8_000), amount!(0)),   // buy, same price
+            (OrderSide::Sell, price!(8_000), price!(4_000), amount!(50)), // sell, price dropped
+            (OrderSide::Sell, price!(4_000), price!(8_000), amount!(-100)), // sell, price rose
+            (OrderSide::Sell, price!(8_000), price!(8_000), amount!(0)),  // sell, same price
         ];
         for (side, trade_price, new_price, profit) in cases.into_iter() {
             let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
@@ -58,10 +60,10 @@ mod tests {
                 TestBase::currency_pair(),
                 side,
                 trade_price,
-                dec!(100),
-                dec!(100),
+                amount!(100),
+                amount!(100),
                 TestBase::base(),
-                dec!(0),
+                amount!(0),
             );
 
             test_obj.calculate_balance_changes(vec![&order]).await;
@@ -77,10 +79,10 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_by_side_and_price_with_commission() {
         let cases = vec![
-            (OrderSide::Buy, dec!(8_000), dec!(8_000), dec!(-10)), // no price change, minus commission
-            (OrderSide::Sell, dec!(8_000), dec!(8_000), dec!(-10)), // no price change, minus commission
-            (OrderSide::Buy, dec!(8_000), dec!(8_800), dec!(-1)),   // positive minus commission
-            (OrderSide::Sell, dec!(8_000), dec!(7_200), dec!(1)),   // positive minus commission
+            (OrderSide::Buy, price!(8_000), price!(8_000), amount!(-10)), // no price change, minus commission
+            (OrderSide::Sell, price!(8_000), price!(8_000), amount!(-10)), // no price change, minus commission
+            (OrderSide::Buy, price!(8_000), price!(8_800), amount!(-1)), // positive minus commission
+            (OrderSide::Sell, price!(8_000), price!(7_200), amount!(1)), // positive minus commission
         ];
         for (side, trade_price, new_price, profit) in cases {
             let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
@@ -90,15 +92,15 @@ mod tests {
             let mut test_obj =
                 TestBase::new_with_usd_converter(true, false, usd_converter, usd_converter_locker);
 
-            let commission_in_base = dec!(10) / trade_price;
+            let commission_in_base = amount!(10) / trade_price;
 
             let order = TestBase::create_order_with_commission_amount(
                 TestBase::exchange_account_id_1(),
                 TestBase::currency_pair(),
                 side,
                 trade_price,
-                dec!(100),
-                dec!(100),
+                amount!(100),
+                amount!(100),
                 TestBase::base(),
                 commission_in_base,
             );
@@ -115,8 +117,8 @@ mod tests {
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn simple_profit_two_orders_with_commission() {
-        let first_price = dec!(10_000);
-        let second_price = dec!(2_000);
+        let first_price = price!(10_000);
+        let second_price = price!(2_000);
 
         let (usd_converter, usd_converter_locker) = init_usd_converter(hashmap![
             TestBase::base() => second_price
@@ -124,7 +126,7 @@ mod tests {
         let mut test_obj =
             TestBase::new_with_usd_converter(true, false, usd_converter, usd_converter_locker);
 
-        let amount = dec!(10_000);
+        let amount = amount!(10_000);
         let commission_rate_make = dec!(-0.025);
 
         let first_side = OrderSide::Buy;
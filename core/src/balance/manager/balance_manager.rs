@@ -7,7 +7,7 @@ use crate::balance::manager::balance_reservation::BalanceReservation;
 use crate::balance::manager::balances::Balances;
 use crate::balance::manager::position_change::PositionChange;
 use crate::exchanges::general::currency_pair_to_symbol_converter::CurrencyPairToSymbolConverter;
-use crate::explanation::Explanation;
+use crate::explanation::{Explanation, OptionExplanationAddReasonExt};
 use crate::misc::reserve_parameters::ReserveParameters;
 use crate::misc::service_value_tree::ServiceValueTree;
 use crate::service_configuration::configuration_descriptor::ConfigurationDescriptor;
@@ -31,7 +31,6 @@ use mmb_utils::infrastructure::WithExpect;
 use mmb_utils::{impl_mock_initializer, nothing_to_do, DateTime};
 use parking_lot::Mutex;
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 
 use crate::database::events::recorder::EventRecorder;
 #[cfg(test)]
@@ -52,6 +51,8 @@ pub struct BalanceManager {
     position_differs_times_in_row_by_exchange_id:
         HashMap<ExchangeAccountId, HashMap<CurrencyPair, u32>>,
     event_recorder: Option<Arc<EventRecorder>>,
+    /// Balances of exchange accounts received the first time, which PnL is counted from
+    initial_balances_by_exchange_id: HashMap<ExchangeAccountId, HashMap<CurrencyCode, Amount>>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -77,6 +78,7 @@ impl BalanceManager {
             balance_changes_service: None,
             position_differs_times_in_row_by_exchange_id: Default::default(),
             event_recorder,
+            initial_balances_by_exchange_id: HashMap::new(),
         }))
     }
 
@@ -268,18 +270,18 @@ impl BalanceManager {
                 .context("Failed to get fill_positions while restoring fill amount positions")?;
             let symbols = position_info_by_symbol.keys().cloned().collect_vec();
 
-            let expected_positions_by_currency_pair: HashMap<CurrencyPair, Decimal> =
+            let expected_positions_by_currency_pair: HashMap<CurrencyPair, Amount> =
                 position_info_by_symbol
                     .iter()
                     .map(|(k, v)| (k.currency_pair(), v.position))
                     .collect();
 
-            let actual_positions_by_currency_pair: HashMap<CurrencyPair, Decimal> = symbols
+            let actual_positions_by_currency_pair: HashMap<CurrencyPair, Amount> = symbols
                 .iter()
                 .map(|x| {
                     let position = fill_positions
                         .get(exchange_account_id, x.currency_pair())
-                        .unwrap_or(dec!(0));
+                        .unwrap_or(Amount::ZERO);
                     (x.currency_pair(), position)
                 })
                 .collect();
@@ -379,6 +381,9 @@ impl BalanceManager {
         self.balance_reservation_manager
             .virtual_balance_holder
             .update_balances(exchange_account_id, &filtered_exchange_balances);
+        self.initial_balances_by_exchange_id
+            .entry(exchange_account_id)
+            .or_insert_with(|| filtered_exchange_balances.clone());
 
         let whole_balances_after = self.calculate_whole_balances()?;
 
@@ -415,13 +420,13 @@ impl BalanceManager {
             };
 
             let reservation_currency_code = reservation.reservation_currency_code;
-            let mut balance = balances
+            let balance = balances
                 .get_mut(&reservation_currency_code)
                 .with_context(|| {
                     format!("failed to get balance from balances for {reservation_currency_code}")
                 })?;
 
-            balance += reservation.convert_in_reservation_currency(
+            *balance += reservation.convert_in_reservation_currency(
                 reservation.get_proportional_cost_amount(reservation.not_approved_amount)?,
             );
         }
@@ -459,7 +464,7 @@ impl BalanceManager {
             .balance_reservation_storage
             .get_all_raw_reservations()
             .iter()
-            .filter(|(_, reservation)| reservation.not_approved_amount > dec!(0))
+            .filter(|(_, reservation)| reservation.not_approved_amount > Amount::ZERO)
             .map(|(id, reservation)| (*id, reservation.clone()))
             .collect();
 
@@ -543,6 +548,20 @@ impl BalanceManager {
         self.order_was_filled_with_fill(configuration_descriptor, order_snapshot, order_fill)
     }
 
+    /// Apply the last `fills_count` fills of order reported by single aggregated
+    /// OrderEventType::OrderFilled
+    pub fn order_was_filled_by_last_fills(
+        &mut self,
+        configuration_descriptor: ConfigurationDescriptor,
+        order_snapshot: &OrderSnapshot,
+        fills_count: usize,
+    ) {
+        let fills = &order_snapshot.fills.fills;
+        for order_fill in &fills[fills.len().saturating_sub(fills_count)..] {
+            self.order_was_filled_with_fill(configuration_descriptor, order_snapshot, order_fill);
+        }
+    }
+
     pub fn order_was_filled_with_fill(
         &mut self,
         configuration_descriptor: ConfigurationDescriptor,
@@ -860,7 +879,7 @@ impl BalanceManager {
         symbol: Arc<Symbol>,
         price_quote_to_base: Price,
         explanation: &mut Option<Explanation>,
-    ) -> Option<Decimal> {
+    ) -> Option<Amount> {
         match self
             .balance_reservation_manager
             .get_available_leveraged_balance(
@@ -873,12 +892,33 @@ impl BalanceManager {
             ) {
             Some(balance) => {
                 let currency_code = symbol.get_trade_code(side, BeforeAfter::Before);
-                let balance_in_amount_currency_code = symbol
+                let mut balance_in_amount_currency_code = symbol
                     .convert_amount_into_amount_currency_code(
                         currency_code,
                         balance,
                         price_quote_to_base,
                     );
+
+                // position can't exceed notional allowed for current leverage by leverage brackets
+                let max_position_notional = self
+                    .balance_reservation_manager
+                    .exchanges_by_id()
+                    .get(&exchange_account_id)
+                    .and_then(|exchange| exchange.max_position_notional(symbol.currency_pair()));
+                if let Some(max_position_notional) = max_position_notional {
+                    let max_position_amount = symbol.convert_amount_into_amount_currency_code(
+                        symbol.quote_currency_code(),
+                        max_position_notional,
+                        price_quote_to_base,
+                    );
+                    if balance_in_amount_currency_code > max_position_amount {
+                        explanation.with_reason(|| {
+                            format!("balance is limited by max position amount {max_position_amount} of leverage bracket")
+                        });
+                        balance_in_amount_currency_code = max_position_amount;
+                    }
+                }
+
                 Some(symbol.round_to_remove_amount_precision_error_expected(
                     balance_in_amount_currency_code,
                 ))
@@ -959,6 +999,15 @@ impl BalanceManager {
             .get_balance_reservation_currency_code(symbol, side)
     }
 
+    /// Balances of exchange account received the first time
+    pub fn get_initial_balances(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+    ) -> Option<&HashMap<CurrencyCode, Amount>> {
+        self.initial_balances_by_exchange_id
+            .get(&exchange_account_id)
+    }
+
     pub fn balance_was_received(&self, exchange_account_id: ExchangeAccountId) -> bool {
         self.balance_reservation_manager
             .virtual_balance_holder
@@ -1057,7 +1106,7 @@ impl BalanceManager {
         exchange_account_id: ExchangeAccountId,
         currency_pair: CurrencyPair,
         side: OrderSide,
-    ) -> Decimal {
+    ) -> Amount {
         self.balance_reservation_manager
             .get_position(exchange_account_id, currency_pair, side)
     }
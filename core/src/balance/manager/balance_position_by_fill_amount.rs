@@ -6,14 +6,14 @@ use mmb_domain::order::snapshot::ClientOrderFillId;
 use serde::Serialize;
 
 use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::snapshot::Amount;
 use mmb_utils::DateTime;
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 #[derive(Clone, Debug, Default, Serialize)]
 pub struct BalancePositionByFillAmount {
     /// MarketAccountId -> AmountInAmountCurrency
-    position_by_fill_amount: HashMap<MarketAccountId, Decimal>,
+    position_by_fill_amount: HashMap<MarketAccountId, Amount>,
 
     /// MarketAccountId -> AmountInAmountCurrency
     position_changes: HashMap<MarketAccountId, Vec<PositionChange>>,
@@ -24,7 +24,7 @@ impl BalancePositionByFillAmount {
         &self,
         exchange_account_id: ExchangeAccountId,
         currency_pair: CurrencyPair,
-    ) -> Option<Decimal> {
+    ) -> Option<Amount> {
         self.position_by_fill_amount
             .get(&MarketAccountId::new(exchange_account_id, currency_pair))
             .cloned()
@@ -34,8 +34,8 @@ impl BalancePositionByFillAmount {
         &mut self,
         exchange_account_id: ExchangeAccountId,
         currency_pair: CurrencyPair,
-        previous_position: Option<Decimal>,
-        new_position: Decimal,
+        previous_position: Option<Amount>,
+        new_position: Amount,
         client_order_fill_id: Option<ClientOrderFillId>,
         now: DateTime,
     ) {
@@ -94,13 +94,13 @@ impl BalancePositionByFillAmount {
         &mut self,
         exchange_account_id: ExchangeAccountId,
         currency_pair: CurrencyPair,
-        value_to_add: Decimal,
+        value_to_add: Amount,
         client_order_fill_id: Option<ClientOrderFillId>,
         now: DateTime,
     ) {
         let current_value = self
             .get(exchange_account_id, currency_pair)
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
         let new_value = current_value + value_to_add;
         self.set(
             exchange_account_id,
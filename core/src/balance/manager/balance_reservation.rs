@@ -13,7 +13,6 @@ use mmb_domain::order::snapshot::Price;
 use serde::Serialize;
 
 use anyhow::{bail, Result};
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
 #[derive(Clone, Debug, Serialize)]
@@ -25,7 +24,7 @@ pub struct BalanceReservation {
     pub price: Price,
     pub amount: Amount,
     pub taken_free_amount: Amount,
-    pub cost: Decimal,
+    pub cost: Amount,
 
     /// CurrencyCode in which we take away amount
     pub reservation_currency_code: CurrencyCode,
@@ -46,7 +45,7 @@ impl BalanceReservation {
         price: Price,
         amount: Amount,
         taken_free_amount: Amount,
-        cost: Decimal,
+        cost: Amount,
         reservation_currency_code: CurrencyCode,
     ) -> Self {
         Self {
@@ -59,25 +58,25 @@ impl BalanceReservation {
             taken_free_amount,
             cost,
             reservation_currency_code,
-            unreserved_amount: dec!(0),
+            unreserved_amount: Amount::ZERO,
             not_approved_amount: amount,
             approved_parts: HashMap::new(),
         }
     }
 
-    pub(crate) fn get_proportional_cost_amount(&self, amount: Amount) -> Result<Decimal> {
+    pub(crate) fn get_proportional_cost_amount(&self, amount: Amount) -> Result<Amount> {
         if self.amount.is_zero() {
             if amount.is_zero() {
-                return Ok(dec!(0));
+                return Ok(Amount::ZERO);
             }
             bail!("Trying to receive a {amount} proportion out of zero")
         }
 
-        Ok(self.cost * amount / self.amount)
+        Ok(self.cost * amount.value() / self.amount.value())
     }
 
     pub fn is_amount_within_symbol_margin_error(&self, amount: Amount) -> bool {
-        amount.abs() <= self.symbol.get_amount_tick() * dec!(0.01)
+        amount.abs() <= Amount::new(self.symbol.get_amount_tick() * dec!(0.01))
     }
 
     pub(crate) fn convert_in_reservation_currency(
@@ -13,14 +13,14 @@ use mmb_domain::order::snapshot::ReservationId;
 use serde::Serialize;
 
 use mmb_database::impl_event;
+use mmb_domain::order::snapshot::Amount;
 use mmb_utils::DateTime;
-use rust_decimal::Decimal;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Balances {
     pub version: usize,
     pub init_time: DateTime,
-    pub balances_by_exchange_id: Option<HashMap<ExchangeAccountId, HashMap<CurrencyCode, Decimal>>>,
+    pub balances_by_exchange_id: Option<HashMap<ExchangeAccountId, HashMap<CurrencyCode, Amount>>>,
     pub virtual_diff_balances: Option<ServiceValueTree>,
 
     /// In Amount currency
@@ -37,7 +37,7 @@ pub struct Balances {
 
 impl Balances {
     pub fn new(
-        balances_by_exchange_id: HashMap<ExchangeAccountId, HashMap<CurrencyCode, Decimal>>,
+        balances_by_exchange_id: HashMap<ExchangeAccountId, HashMap<CurrencyCode, Amount>>,
         init_time: DateTime,
         virtual_diff_balances: ServiceValueTree,
         reserved_amount: ServiceValueTree,
@@ -1,3 +1,4 @@
+use mmb_domain::{amount, price};
 #[cfg(test)]
 use std::{collections::HashMap, sync::Arc};
 
@@ -91,7 +92,7 @@ impl BalanceManagerBase {
         balance_manager: &mut BalanceManager,
         exchange_account_id: ExchangeAccountId,
         balances_by_currency_code: HashMap<CurrencyCode, Amount>,
-        positions_by_currency_pair: HashMap<CurrencyPair, Decimal>,
+        positions_by_currency_pair: HashMap<CurrencyPair, Amount>,
     ) {
         let balances = balances_by_currency_code
             .into_iter()
@@ -104,7 +105,7 @@ impl BalanceManagerBase {
         let positions = Some(
             positions_by_currency_pair
                 .into_iter()
-                .map(|x| DerivativePosition::new(x.0, x.1, dec!(0), dec!(0), dec!(1)))
+                .map(|x| DerivativePosition::new(x.0, x.1, price!(0), price!(0), dec!(1)))
                 .collect_vec(),
         );
 
@@ -241,7 +242,7 @@ impl BalanceManagerBase {
         order_side: OrderSide,
         reservation_id: ReservationId,
     ) -> OrderSnapshot {
-        self.create_order_by_amount(order_side, dec!(0.2), dec!(5), reservation_id)
+        self.create_order_by_amount(order_side, price!(0.2), amount!(5), reservation_id)
     }
 
     pub fn create_order_by_amount(
@@ -22,6 +22,7 @@ use crate::{
         test_helper::get_test_exchange_with_symbol_and_id,
     },
 };
+use mmb_domain::{amount, price};
 
 pub struct BalanceManagerDerivative {
     balance_manager_base: BalanceManagerBase,
@@ -30,25 +31,25 @@ pub struct BalanceManagerDerivative {
 
 impl BalanceManagerDerivative {
     pub fn price() -> Price {
-        dec!(0.2)
+        price!(0.2)
     }
-    pub fn reversed_price_x_multiplier() -> Decimal {
+    pub fn reversed_price_x_multiplier() -> Price {
         BalanceManagerDerivative::price() * BalanceManagerDerivative::reversed_amount_multiplier()
     }
     pub fn amount() -> Amount {
-        dec!(1.9)
+        amount!(1.9)
     }
     pub fn amount_reversed() -> Amount {
-        dec!(1.9) / dec!(0.2)
+        amount!(1.9) / price!(0.2)
     }
-    pub fn reversed_amount_multiplier() -> Amount {
+    pub fn reversed_amount_multiplier() -> Decimal {
         dec!(0.001)
     }
     pub fn leverage() -> Decimal {
         dec!(7)
     }
-    fn position() -> Decimal {
-        dec!(1)
+    fn position() -> Amount {
+        amount!(1)
     }
 
     #[allow(clippy::type_complexity)]
@@ -134,8 +135,8 @@ impl BalanceManagerDerivative {
     fn create_order_fill(
         price: Price,
         amount: Amount,
-        cost: Decimal,
-        commission_amount: Decimal,
+        cost: Amount,
+        commission_amount: Amount,
         is_reversed: bool,
     ) -> OrderFill {
         let commission_currency_code = if is_reversed {
@@ -155,10 +156,10 @@ impl BalanceManagerDerivative {
             OrderFillRole::Taker,
             commission_currency_code,
             commission_amount,
-            dec!(0),
+            amount!(0),
             BalanceManagerBase::btc(),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             false,
             None,
             None,
@@ -204,8 +205,8 @@ impl BalanceManagerDerivative {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            price,
-            dec!(0),
+            Amount::new(price.value()),
+            amount!(0),
             is_reversed,
         ));
         let configuration_descriptor = self.balance_manager_base.configuration_descriptor;
@@ -239,6 +240,7 @@ mod tests {
     use mmb_domain::order::snapshot::{OrderSide, OrderStatus, ReservationId};
 
     use super::BalanceManagerDerivative;
+    use mmb_domain::{amount, price};
 
     fn create_eth_btc_test_obj(
         btc_amount: Amount,
@@ -311,8 +313,8 @@ mod tests {
             );
             let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
                 OrderSide::Buy,
-                dec!(0.2),
-                dec!(2),
+                price!(0.2),
+                amount!(2),
             );
             assert_eq!(
                 test_object
@@ -345,12 +347,12 @@ mod tests {
     pub async fn reservation_should_use_balance_currency() {
         init_logger();
         let test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), false);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), false);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            dec!(5),
+            amount!(5),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -365,18 +367,18 @@ mod tests {
                     BalanceManagerDerivative::price()
                 )
                 .expect("in test"),
-            (dec!(100) - dec!(5) / BalanceManagerDerivative::price()) * dec!(0.95)
+            (amount!(100) - amount!(5) / BalanceManagerDerivative::price()) * dec!(0.95)
         );
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect("in test");
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(4),
+            amount!(4),
         );
         assert!(test_object
             .balance_manager()
@@ -391,7 +393,7 @@ mod tests {
                     BalanceManagerDerivative::price()
                 )
                 .expect("in test"),
-            (dec!(100) - dec!(4) / BalanceManagerDerivative::price()) * dec!(0.95)
+            (amount!(100) - amount!(4) / BalanceManagerDerivative::price()) * dec!(0.95)
         );
     }
 
@@ -399,12 +401,12 @@ mod tests {
     pub async fn reservation_should_use_balance_currency_reversed() {
         init_logger();
         let test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), true);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), true);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            dec!(5),
+            amount!(5),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -419,19 +421,19 @@ mod tests {
                     BalanceManagerDerivative::price()
                 )
                 .expect("in test"),
-            (dec!(100) - dec!(5) * BalanceManagerDerivative::reversed_price_x_multiplier())
+            (amount!(100) - amount!(5) * BalanceManagerDerivative::reversed_price_x_multiplier())
                 * dec!(0.95)
         );
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect("in test");
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(4),
+            amount!(4),
         );
         assert!(test_object
             .balance_manager()
@@ -446,7 +448,7 @@ mod tests {
                     BalanceManagerDerivative::price()
                 )
                 .expect("in test"),
-            (dec!(100) - dec!(4) * BalanceManagerDerivative::reversed_price_x_multiplier())
+            (amount!(100) - amount!(4) * BalanceManagerDerivative::reversed_price_x_multiplier())
                 * dec!(0.95)
         );
     }
@@ -464,10 +466,10 @@ mod tests {
     ) {
         init_logger();
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), false);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), false);
 
-        let limit = dec!(2);
-        let fill_amount = dec!(3);
+        let limit = amount!(2);
+        let fill_amount = amount!(3);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
@@ -485,10 +487,10 @@ mod tests {
             .balance_manager_base
             .create_order(order_side, ReservationId::generate());
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
+            price!(0.1),
             fill_amount,
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
         test_object
@@ -497,24 +499,24 @@ mod tests {
     }
 
     #[rstest]
-    #[case(OrderSide::Buy, dec!(1), None, true)]
-    #[case(OrderSide::Sell, dec!(1),None, true)]
-    #[case(OrderSide::Buy, dec!(1), Some(dec!(5)), true)]
-    #[case(OrderSide::Sell, dec!(1),Some(dec!(5)), true)]
-    #[case(OrderSide::Buy, dec!(1), None,false)]
-    #[case(OrderSide::Sell, dec!(1),None, false)]
-    #[case(OrderSide::Buy, dec!(1), Some(dec!(5)),false)]
-    #[case(OrderSide::Sell, dec!(1),Some(dec!(5)), false)]
+    #[case(OrderSide::Buy, amount!(1), None, true)]
+    #[case(OrderSide::Sell, amount!(1),None, true)]
+    #[case(OrderSide::Buy, amount!(1), Some(dec!(5)), true)]
+    #[case(OrderSide::Sell, amount!(1),Some(dec!(5)), true)]
+    #[case(OrderSide::Buy, amount!(1), None,false)]
+    #[case(OrderSide::Sell, amount!(1),None, false)]
+    #[case(OrderSide::Buy, amount!(1), Some(dec!(5)),false)]
+    #[case(OrderSide::Sell, amount!(1),Some(dec!(5)), false)]
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn fill_should_change_position(
         #[case] order_side: OrderSide,
-        #[case] expected_position: Decimal,
+        #[case] expected_position: Amount,
         #[case] leverage: Option<Decimal>,
         #[case] is_reversed: bool,
     ) {
         init_logger();
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         if let Some(leverage) = leverage {
             let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
@@ -532,10 +534,10 @@ mod tests {
             .create_order(order_side, ReservationId::generate());
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            price!(0.1),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -558,7 +560,7 @@ mod tests {
     pub async fn fill_buy_should_commission_should_be_deducted_from_balance() {
         init_logger();
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), false);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), false);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -575,10 +577,10 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(1),
-            dec!(0.1),
-            dec!(-0.025) / dec!(100),
+            price!(0.1),
+            amount!(1),
+            amount!(0.1),
+            amount!(-0.025) / dec!(100),
             false,
         ));
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
@@ -589,18 +591,18 @@ mod tests {
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::eth(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::eth(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) + dec!(0.00005)) * dec!(0.95)
+            (amount!(100) + amount!(0.00005)) * dec!(0.95)
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::btc(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::btc(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) * dec!(0.1) - dec!(1) / dec!(0.1) / dec!(5) * dec!(0.1)
-                + dec!(0.00005) * dec!(0.1))
+            (amount!(100) * dec!(0.1) - amount!(1) / dec!(0.1) / dec!(5) * dec!(0.1)
+                + amount!(0.00005) * dec!(0.1))
                 * dec!(0.95)
         );
     }
@@ -609,7 +611,7 @@ mod tests {
     pub async fn fill_buy_should_commission_should_be_deducted_from_balance_reversed() {
         init_logger();
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), true);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), true);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -626,10 +628,10 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(1),
-            dec!(0.1),
-            dec!(-0.025) / dec!(100),
+            price!(0.1),
+            amount!(1),
+            amount!(0.1),
+            amount!(-0.025) / dec!(100),
             true,
         ));
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
@@ -640,18 +642,19 @@ mod tests {
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::eth(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::eth(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) / dec!(0.1) + dec!(0.00005) / dec!(0.1)) * dec!(0.95)
+            (amount!(100) / amount!(0.1) + amount!(0.00005) / amount!(0.1)) * amount!(0.95)
         );
 
         let multiplier = BalanceManagerDerivative::reversed_amount_multiplier();
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::btc(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::btc(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) - dec!(1) * dec!(0.1) / dec!(5) * multiplier + dec!(0.00005)) * dec!(0.95)
+            (amount!(100) - amount!(1) * dec!(0.1) / dec!(5) * multiplier + amount!(0.00005))
+                * dec!(0.95)
         );
     }
 
@@ -660,7 +663,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -677,10 +680,10 @@ mod tests {
             .create_order(OrderSide::Sell, ReservationId::generate());
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(1),
-            dec!(0.1),
-            dec!(-0.025) / dec!(100),
+            price!(0.1),
+            amount!(1),
+            amount!(0.1),
+            amount!(-0.025) / dec!(100),
             is_reversed,
         ));
 
@@ -692,17 +695,17 @@ mod tests {
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::eth(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::eth(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) - dec!(1) / dec!(0.1) / dec!(5) + dec!(0.00005)) * dec!(0.95)
+            (amount!(100) - amount!(1) / dec!(0.1) / dec!(5) + amount!(0.00005)) * dec!(0.95)
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::btc(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::btc(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) * dec!(0.1) + dec!(0.00005) * dec!(0.1)) * dec!(0.95)
+            (amount!(100) * dec!(0.1) + amount!(0.00005) * dec!(0.1)) * dec!(0.95)
         );
     }
 
@@ -711,7 +714,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -728,10 +731,10 @@ mod tests {
             .create_order(OrderSide::Sell, ReservationId::generate());
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(1),
-            dec!(0.1),
-            dec!(-0.025) / dec!(100),
+            price!(0.1),
+            amount!(1),
+            amount!(0.1),
+            amount!(-0.025) / dec!(100),
             is_reversed,
         ));
 
@@ -744,18 +747,19 @@ mod tests {
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::eth(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::eth(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) / dec!(0.1) - dec!(1) / dec!(5) * multiplier + dec!(0.00005) / dec!(0.1))
+            (amount!(100) / dec!(0.1) - amount!(1) / dec!(5) * multiplier
+                + amount!(0.00005) / dec!(0.1))
                 * dec!(0.95)
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
-                .get_balance_by_currency_code(BalanceManagerBase::btc(), dec!(0.1))
+                .get_balance_by_currency_code(BalanceManagerBase::btc(), price!(0.1))
                 .expect("in test"),
-            (dec!(100) + dec!(0.00005)) * dec!(0.95)
+            (amount!(100) + amount!(0.00005)) * dec!(0.95)
         );
     }
 
@@ -764,7 +768,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -776,12 +780,12 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
+        let price = price!(0.1);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -793,14 +797,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -809,9 +813,9 @@ mod tests {
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -822,7 +826,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(1))
+            .unreserve(reservation_id, amount!(1))
             .expect("in test");
 
         assert_eq!(
@@ -830,14 +834,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(100) * dec!(0.95)
+            amount!(100) * dec!(0.95)
         );
 
         assert_eq!(
@@ -846,7 +850,7 @@ mod tests {
                 test_object.balance_manager_base.symbol().currency_pair(),
                 OrderSide::Sell
             ),
-            dec!(-1)
+            amount!(-1)
         );
 
         assert!(test_object
@@ -859,14 +863,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.6) * dec!(0.95)
+            amount!(9.6) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
     }
 
@@ -875,7 +879,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -887,8 +891,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
-        let amount = dec!(1) / price;
+        let price = price!(0.1);
+        let amount = amount!(1) / price;
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
@@ -905,14 +909,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -922,8 +926,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -942,7 +946,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
 
         assert_eq!(
@@ -950,7 +954,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(1000) * dec!(0.95)
+            amount!(1000) * dec!(0.95)
         );
 
         assert_eq!(
@@ -972,14 +976,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9996) * dec!(0.95)
+            amount!(99.9996) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
     }
 
@@ -988,7 +992,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1000,12 +1004,12 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
+        let price = price!(0.1);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -1017,14 +1021,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1033,9 +1037,9 @@ mod tests {
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1046,7 +1050,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(1))
+            .unreserve(reservation_id, amount!(1))
             .expect("in test");
 
         assert_eq!(
@@ -1054,14 +1058,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(10) * dec!(0.95)
+            amount!(10) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1070,7 +1074,7 @@ mod tests {
                 test_object.balance_manager_base.symbol().currency_pair(),
                 OrderSide::Buy
             ),
-            dec!(-1)
+            amount!(-1)
         );
 
         assert!(test_object
@@ -1083,14 +1087,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(96) * dec!(0.95)
+            amount!(96) * dec!(0.95)
         );
     }
 
@@ -1099,7 +1103,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1111,8 +1115,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
-        let amount = dec!(1) / price;
+        let price = price!(0.1);
+        let amount = amount!(1) / price;
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
@@ -1129,14 +1133,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1146,8 +1150,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1166,7 +1170,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1174,7 +1178,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(100) * dec!(0.95)
+            amount!(100) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1196,14 +1200,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.996) * dec!(0.95)
+            amount!(999.996) * dec!(0.95)
         );
     }
 
@@ -1212,7 +1216,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1224,11 +1228,11 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
+        let price = price!(0.1);
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -1240,14 +1244,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1256,9 +1260,9 @@ mod tests {
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1269,7 +1273,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(1))
+            .unreserve(reservation_id, amount!(1))
             .expect("in test");
 
         assert_eq!(
@@ -1278,7 +1282,7 @@ mod tests {
                 test_object.balance_manager_base.symbol().currency_pair(),
                 OrderSide::Buy
             ),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -1286,7 +1290,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(100) * dec!(0.95)
+            amount!(100) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1294,13 +1298,13 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(1.5),
+            amount!(1.5),
         );
         let partially_free_reservation_id = test_object
             .balance_manager()
@@ -1312,14 +1316,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.7) * dec!(0.95)
+            amount!(9.7) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(97) * dec!(0.95)
+            amount!(97) * dec!(0.95)
         );
 
         //the whole 1.5 is not free as we've taken the whole free position with the previous reservation
@@ -1333,34 +1337,34 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.4) * dec!(0.95)
+            amount!(9.4) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(94) * dec!(0.95)
+            amount!(94) * dec!(0.95)
         );
 
         //free amount from position is available again
         test_object
             .balance_manager()
-            .unreserve(partially_free_reservation_id, dec!(1.5))
+            .unreserve(partially_free_reservation_id, amount!(1.5))
             .expect("in test");
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.5) * dec!(0.95)
+            amount!(9.5) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(97) * dec!(0.95)
+            amount!(97) * dec!(0.95)
         );
     }
 
@@ -1370,7 +1374,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1382,8 +1386,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
-        let amount = dec!(1) / price;
+        let price = price!(0.1);
+        let amount = amount!(1) / price;
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
@@ -1399,14 +1403,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1416,8 +1420,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1445,7 +1449,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(1000) * dec!(0.95)
+            amount!(1000) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1453,7 +1457,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -1473,14 +1477,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9997) * dec!(0.95)
+            amount!(99.9997) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.997) * dec!(0.95)
+            amount!(999.997) * dec!(0.95)
         );
 
         //the whole 1.5 is not free as we've taken the whole free position with the previous reservation
@@ -1493,14 +1497,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9994) * dec!(0.95)
+            amount!(99.9994) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.994) * dec!(0.95)
+            amount!(999.994) * dec!(0.95)
         );
 
         //free amount from position is available again
@@ -1513,14 +1517,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9995) * dec!(0.95)
+            amount!(99.9995) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.997) * dec!(0.95)
+            amount!(999.997) * dec!(0.95)
         );
     }
 
@@ -1529,7 +1533,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1541,11 +1545,11 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
+        let price = price!(0.1);
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -1557,14 +1561,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.8) * dec!(0.95)
+            amount!(9.8) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1573,9 +1577,9 @@ mod tests {
 
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1586,7 +1590,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(1))
+            .unreserve(reservation_id, amount!(1))
             .expect("in test");
 
         assert_eq!(
@@ -1595,7 +1599,7 @@ mod tests {
                 test_object.balance_manager_base.symbol().currency_pair(),
                 OrderSide::Buy
             ),
-            dec!(-1)
+            amount!(-1)
         );
 
         assert_eq!(
@@ -1603,7 +1607,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(10) * dec!(0.95)
+            amount!(10) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1611,13 +1615,13 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(98) * dec!(0.95)
+            amount!(98) * dec!(0.95)
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1.5),
+            amount!(1.5),
         );
 
         //1 out of 1.5 is free
@@ -1631,14 +1635,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.7) * dec!(0.95)
+            amount!(9.7) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(97) * dec!(0.95)
+            amount!(97) * dec!(0.95)
         );
 
         //the whole 1.5 is not free as we've taken the whole free position with the previous reservation
@@ -1652,34 +1656,34 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.4) * dec!(0.95)
+            amount!(9.4) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(94) * dec!(0.95)
+            amount!(94) * dec!(0.95)
         );
 
         //free amount from position is available again
         test_object
             .balance_manager()
-            .unreserve(partially_free_reservation_id, dec!(1.5))
+            .unreserve(partially_free_reservation_id, amount!(1.5))
             .expect("in test");
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(9.7) * dec!(0.95)
+            amount!(9.7) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(95) * dec!(0.95)
+            amount!(95) * dec!(0.95)
         );
     }
 
@@ -1689,7 +1693,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(100), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(100), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1701,8 +1705,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.1);
-        let amount = dec!(1) / price;
+        let price = price!(0.1);
+        let amount = amount!(1) / price;
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
@@ -1718,14 +1722,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9998) * dec!(0.95)
+            amount!(99.9998) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         let mut order = test_object
@@ -1735,8 +1739,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -1764,7 +1768,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.998) * dec!(0.95)
+            amount!(999.998) * dec!(0.95)
         );
 
         assert_eq!(
@@ -1772,7 +1776,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(100) * dec!(0.95)
+            amount!(100) * dec!(0.95)
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -1796,14 +1800,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9997) * dec!(0.95)
+            amount!(99.9997) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.997) * dec!(0.95)
+            amount!(999.997) * dec!(0.95)
         );
 
         //the whole 1.5 is not free as we've taken the whole free position with the previous reservation
@@ -1816,14 +1820,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9994) * dec!(0.95)
+            amount!(99.9994) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.994) * dec!(0.95)
+            amount!(999.994) * dec!(0.95)
         );
 
         //free amount from position is available again
@@ -1836,14 +1840,14 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price)
                 .expect("in test"),
-            dec!(999.995) * dec!(0.95)
+            amount!(999.995) * dec!(0.95)
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Buy, price)
                 .expect("in test"),
-            dec!(99.9997) * dec!(0.95)
+            amount!(99.9997) * dec!(0.95)
         );
     }
 
@@ -1855,7 +1859,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(10), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(10), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1869,8 +1873,8 @@ mod tests {
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -1905,7 +1909,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), order_snapshot.price())
                 .expect("in test"),
-            (dec!(10) - order_snapshot.amount() / order_snapshot.price() / dec!(5)) * dec!(0.95)
+            (amount!(10) - order_snapshot.amount() / order_snapshot.price() / dec!(5)) * dec!(0.95)
         );
 
         //cloned BalancedManager should be without reservation
@@ -1918,7 +1922,7 @@ mod tests {
                     order_snapshot.price()
                 )
                 .expect("in test"),
-            dec!(10) * dec!(0.95)
+            amount!(10) * dec!(0.95)
         );
     }
 
@@ -1927,7 +1931,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(10), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(10), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -1939,12 +1943,12 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(5),
+            amount!(5),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -1983,7 +1987,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            (dec!(10) - price / dec!(0.2) * dec!(5)) * dec!(0.95)
+            (amount!(10) - amount!(5) * price / dec!(0.2)) * dec!(0.95)
         );
 
         //cloned BalancedManager should be without reservation
@@ -1996,15 +2000,16 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            (dec!(10) - price / dec!(0.2) * dec!(5) + price / dec!(0.2) * dec!(5)) * dec!(0.95)
+            (amount!(10) - amount!(5) * price / dec!(0.2) + amount!(5) * price / dec!(0.2))
+                * dec!(0.95)
         );
     }
 
     #[rstest]
     #[ignore] // Transfer
-    #[case(dec!(25), dec!(0.2), dec!(3), dec!(0.5), dec!(2) ,dec!(2) )] // Optimistic case: price1 < price2
+    #[case(amount!(25), price!(0.2), amount!(3), price!(0.5), amount!(2) ,amount!(2) )] // Optimistic case: price1 < price2
     #[ignore] // Transfer
-    #[case(dec!(25), dec!(0.5), dec!(3), dec!(0.2), dec!(2) ,dec!(2) )] // Pessimistic case: price1 > price2
+    #[case(amount!(25), price!(0.5), amount!(3), price!(0.2), amount!(2) ,amount!(2) )] // Pessimistic case: price1 > price2
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservation_different_price_success(
         #[case] src_balance: Amount,
@@ -2023,7 +2028,7 @@ mod tests {
         let common_params =
             test_object
                 .balance_manager_base
-                .create_reserve_parameters(side, price_1, dec!(0));
+                .create_reserve_parameters(side, price_1, amount!(0));
         let initial_balance = test_object
             .balance_manager()
             .get_balance_by_reserve_parameters(&common_params)
@@ -2085,22 +2090,22 @@ mod tests {
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
 
-        assert_eq!(reservation.cost, dec!(3) - dec!(2));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(3) - amount!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
 
-        assert_eq!(reservation.cost, dec!(2) + dec!(2));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(2) + amount!(2));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
     }
 
     #[rstest]
     #[ignore] // Transfer
-    #[case(dec!(20), dec!(0.5), dec!(3), dec!(0.2), dec!(2) ,dec!(2) )] // Pessimistic case: price1 > price2
+    #[case(amount!(20), price!(0.5), amount!(3), price!(0.2), amount!(2) ,amount!(2) )] // Pessimistic case: price1 > price2
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservation_different_price_failure(
         #[case] src_balance: Amount,
@@ -2170,12 +2175,12 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(30), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(30), is_reversed);
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2184,15 +2189,15 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
             .try_reserve(&reserve_parameters_2, &mut None)
             .expect("in test");
 
-        let expected_balance = dec!(5);
+        let expected_balance = amount!(5);
         assert_eq!(
             test_object
                 .balance_manager()
@@ -2203,7 +2208,7 @@ mod tests {
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &None
         ));
         assert_eq!(
@@ -2215,14 +2220,14 @@ mod tests {
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -2231,14 +2236,14 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(25), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(25), is_reversed);
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(3),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2249,20 +2254,20 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_1)
                 .cost,
-            dec!(3)
+            amount!(3)
         );
 
         let buy_reservation_params = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&buy_reservation_params),
-            Some(dec!(25) * price - dec!(3))
+            Some(amount!(25) * price - amount!(3))
         );
 
         let buy_reservation_id = test_object
@@ -2275,9 +2280,9 @@ mod tests {
             .create_order(OrderSide::Buy, buy_reservation_id);
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
-            dec!(1),
-            dec!(0.1),
-            dec!(0),
+            amount!(1),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -2292,7 +2297,7 @@ mod tests {
                 test_object.balance_manager_base.symbol().currency_pair(),
                 OrderSide::Buy,
             ),
-            dec!(1)
+            amount!(1)
         );
         test_object
             .balance_manager()
@@ -2302,7 +2307,7 @@ mod tests {
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(1.9),
+            amount!(1.9),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2313,20 +2318,20 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_1)
                 .cost,
-            dec!(0.9)
+            amount!(0.9)
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
 
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &None
         ));
 
@@ -2334,21 +2339,21 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.cost, dec!(3) - dec!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.cost, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
-        assert_eq!(reservation.amount, dec!(1.9) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(1.9) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(1.9) + dec!(2));
-        assert_eq!(reservation.cost, dec!(0.9) + dec!(2));
+        assert_eq!(reservation.amount, amount!(1.9) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(1.9) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(1.9) + amount!(2));
+        assert_eq!(reservation.cost, amount!(0.9) + amount!(2));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -2356,14 +2361,14 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(25), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(25), is_reversed);
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(2),
+            amount!(2),
         );
         assert!(test_object
             .balance_manager()
@@ -2374,7 +2379,7 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price),
-            Some((dec!(25) - dec!(2) / price) * dec!(0.95))
+            Some((amount!(25) - amount!(2) / price) * dec!(0.95))
         );
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         assert_eq!(
@@ -2386,20 +2391,20 @@ mod tests {
                     BalanceManagerBase::eth(),
                 )
                 .expect("in test"),
-            dec!(25)
+            amount!(25)
         );
 
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap!(BalanceManagerBase::eth() => dec!(25)),
+            hashmap!(BalanceManagerBase::eth() => amount!(25)),
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price),
-            Some((dec!(25) - dec!(2) / price) * dec!(0.95))
+            Some((amount!(25) - amount!(2) / price) * dec!(0.95))
         );
         assert_eq!(
             test_object
@@ -2410,7 +2415,7 @@ mod tests {
                     BalanceManagerBase::eth(),
                 )
                 .expect("in test"),
-            dec!(25) - dec!(2) / price
+            amount!(25) - amount!(2) / price
         );
     }
 
@@ -2421,18 +2426,18 @@ mod tests {
         let is_reversed = false;
         let test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(25),
+            amount!(25),
             None,
             is_reversed,
-            Some(dec!(1)),
+            Some(amount!(1)),
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(2),
+            amount!(2),
         );
         assert!(test_object
             .balance_manager()
@@ -2443,7 +2448,7 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_trade_side(OrderSide::Sell, price),
-            Some((dec!(25) - (dec!(2) - dec!(1)) / price) * dec!(0.95))
+            Some((amount!(25) - (amount!(2) - amount!(1)) / price) * dec!(0.95))
         );
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         assert_eq!(
@@ -2455,22 +2460,22 @@ mod tests {
                     BalanceManagerBase::eth(),
                 )
                 .expect("in test"),
-            dec!(25)
+            amount!(25)
         );
 
         let symbol_currency_pair = test_object.balance_manager_base.symbol().currency_pair();
         BalanceManagerBase::update_balance_with_positions(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::eth() => dec!(25)],
-            hashmap![symbol_currency_pair => dec!(1)],
+            hashmap![BalanceManagerBase::eth() => amount!(25)],
+            hashmap![symbol_currency_pair => amount!(1)],
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price),
-            Some((dec!(25) - (dec!(2) - dec!(1)) / price) * dec!(0.95))
+            Some((amount!(25) - (amount!(2) - amount!(1)) / price) * dec!(0.95))
         );
         assert_eq!(
             test_object
@@ -2481,7 +2486,7 @@ mod tests {
                     BalanceManagerBase::eth(),
                 )
                 .expect("in test"),
-            dec!(25) - (dec!(2) - dec!(1)) / price
+            amount!(25) - (amount!(2) - amount!(1)) / price
         );
     }
 
@@ -2490,7 +2495,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -2503,8 +2508,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), BalanceManagerDerivative::leverage());
 
-        let original_balance = dec!(9);
-        let position = dec!(1);
+        let original_balance = amount!(9);
+        let position = amount!(1);
 
         let symbol_currency_pair = test_object.balance_manager_base.symbol().currency_pair();
         BalanceManagerBase::update_balance_with_positions(
@@ -2532,7 +2537,7 @@ mod tests {
             Some(sell_balance * dec!(0.95))
         );
 
-        let fill_amount = dec!(0.3);
+        let fill_amount = amount!(0.3);
         test_object.fill_order(OrderSide::Buy, None, Some(fill_amount), is_reversed);
 
         buy_balance = original_balance * price - fill_amount / BalanceManagerDerivative::leverage();
@@ -2560,7 +2565,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -2573,8 +2578,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(7));
 
-        let original_balance = dec!(9) / price;
-        let position = dec!(1) / price;
+        let original_balance = amount!(9) / price;
+        let position = amount!(1) / price;
 
         let symbol_currency_pair = test_object.balance_manager_base.symbol().currency_pair();
         BalanceManagerBase::update_balance_with_positions(
@@ -2603,7 +2608,7 @@ mod tests {
             Some(sell_balance * dec!(0.95))
         );
 
-        let fill_amount = dec!(0.3);
+        let fill_amount = amount!(0.3);
         test_object.fill_order(OrderSide::Buy, None, Some(fill_amount), is_reversed);
 
         buy_balance = original_balance
@@ -2642,7 +2647,7 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -2655,8 +2660,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), BalanceManagerDerivative::leverage());
 
-        let original_balance = dec!(9);
-        let position = dec!(1);
+        let original_balance = amount!(9);
+        let position = amount!(1);
 
         let symbol_currency_pair = test_object.balance_manager_base.symbol().currency_pair();
         BalanceManagerBase::update_balance_with_positions(
@@ -2684,7 +2689,7 @@ mod tests {
             Some(sell_balance * dec!(0.95))
         );
 
-        let fill_amount = dec!(0.3);
+        let fill_amount = amount!(0.3);
         test_object.fill_order(OrderSide::Sell, None, Some(fill_amount), is_reversed);
 
         buy_balance = original_balance * price + fill_amount / BalanceManagerDerivative::leverage();
@@ -2712,7 +2717,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -2725,8 +2730,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(7));
 
-        let original_balance = dec!(9) / price;
-        let position = dec!(1) / price;
+        let original_balance = amount!(9) / price;
+        let position = amount!(1) / price;
 
         let symbol_currency_pair = test_object.balance_manager_base.symbol().currency_pair();
         BalanceManagerBase::update_balance_with_positions(
@@ -2755,7 +2760,7 @@ mod tests {
             Some(sell_balance * dec!(0.95))
         );
 
-        let fill_amount = dec!(0.3);
+        let fill_amount = amount!(0.3);
         test_object.fill_order(OrderSide::Sell, None, Some(fill_amount), is_reversed);
 
         buy_balance = original_balance
@@ -2796,13 +2801,13 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
 
-        let amount_limit = dec!(2);
+        let amount_limit = amount!(2);
         test_object.balance_manager().set_target_amount_limit(
             configuration_descriptor,
             exchange_account_id,
@@ -2821,7 +2826,7 @@ mod tests {
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::eth()=> dec!(1000)],
+            hashmap![BalanceManagerBase::eth()=> amount!(1000)],
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -2861,8 +2866,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             BalanceManagerDerivative::amount(),
-            price,
-            dec!(0),
+            Amount::new(price.value()),
+            amount!(0),
             is_reversed,
         ));
 
@@ -2934,7 +2939,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -2943,7 +2948,7 @@ mod tests {
         let price = BalanceManagerDerivative::price();
         let amount = BalanceManagerDerivative::amount_reversed();
         let amount_multiplier = BalanceManagerDerivative::reversed_amount_multiplier();
-        let amount_limit = dec!(2);
+        let amount_limit = amount!(2);
         let adjusted_amount_limit = amount_limit / price / amount_multiplier;
         test_object.balance_manager().set_target_amount_limit(
             configuration_descriptor,
@@ -2962,7 +2967,7 @@ mod tests {
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::btc()=> dec!(1000)],
+            hashmap![BalanceManagerBase::btc()=> amount!(1000)],
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -3002,8 +3007,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            price,
-            dec!(0),
+            Amount::new(price.value()),
+            amount!(0),
             is_reversed,
         ));
 
@@ -3076,13 +3081,13 @@ mod tests {
         init_logger();
         let is_reversed = false;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
 
-        let amount_limit = dec!(2);
+        let amount_limit = amount!(2);
         test_object.balance_manager().set_target_amount_limit(
             configuration_descriptor,
             exchange_account_id,
@@ -3101,7 +3106,7 @@ mod tests {
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::eth()=> dec!(1000)],
+            hashmap![BalanceManagerBase::eth()=> amount!(1000)],
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -3160,8 +3165,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             BalanceManagerDerivative::amount(),
-            price,
-            dec!(0),
+            Amount::new(price.value()),
+            amount!(0),
             is_reversed,
         ));
 
@@ -3236,7 +3241,7 @@ mod tests {
         init_logger();
         let is_reversed = true;
         let mut test_object =
-            create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0), is_reversed);
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0), is_reversed);
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let symbol = test_object.balance_manager_base.symbol();
@@ -3245,7 +3250,7 @@ mod tests {
         let price = BalanceManagerDerivative::price();
         let amount = BalanceManagerDerivative::amount_reversed();
         let amount_multiplier = BalanceManagerDerivative::reversed_amount_multiplier();
-        let amount_limit = dec!(2);
+        let amount_limit = amount!(2);
         let adjusted_amount_limit = amount_limit / price / amount_multiplier;
         test_object.balance_manager().set_target_amount_limit(
             configuration_descriptor,
@@ -3264,7 +3269,7 @@ mod tests {
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::btc() => dec!(1000)],
+            hashmap![BalanceManagerBase::btc() => amount!(1000)],
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -3323,8 +3328,8 @@ mod tests {
         order.add_fill(BalanceManagerDerivative::create_order_fill(
             price,
             amount,
-            price,
-            dec!(0),
+            Amount::new(price.value()),
+            amount!(0),
             is_reversed,
         ));
 
@@ -3396,7 +3401,7 @@ mod tests {
         let is_reversed = false;
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
+            amount!(10),
             None,
             is_reversed,
             Some(BalanceManagerDerivative::position()),
@@ -3414,7 +3419,8 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            BalanceManagerDerivative::position() + dec!(1.9) * BalanceManagerDerivative::leverage(),
+            BalanceManagerDerivative::position()
+                + amount!(1.9) * BalanceManagerDerivative::leverage(),
         );
         assert!(test_object
             .balance_manager()
@@ -3423,7 +3429,8 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            BalanceManagerDerivative::position() + dec!(2) * BalanceManagerDerivative::leverage(),
+            BalanceManagerDerivative::position()
+                + amount!(2) * BalanceManagerDerivative::leverage(),
         );
         assert!(!test_object
             .balance_manager()
@@ -3432,7 +3439,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(1.9) * BalanceManagerDerivative::leverage(),
+            amount!(1.9) * BalanceManagerDerivative::leverage(),
         );
         assert!(test_object
             .balance_manager()
@@ -3441,7 +3448,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(2) * BalanceManagerDerivative::leverage(),
+            amount!(2) * BalanceManagerDerivative::leverage(),
         );
         assert!(!test_object
             .balance_manager()
@@ -3454,7 +3461,7 @@ mod tests {
         let is_reversed = true;
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(2),
+            amount!(2),
             None,
             is_reversed,
             Some(BalanceManagerDerivative::position()),
@@ -3473,7 +3480,7 @@ mod tests {
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
             BalanceManagerDerivative::position()
-                + dec!(1.9) / BalanceManagerDerivative::price()
+                + amount!(1.9) / BalanceManagerDerivative::price()
                     * BalanceManagerDerivative::leverage()
                     / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
@@ -3485,7 +3492,7 @@ mod tests {
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
             BalanceManagerDerivative::position()
-                + dec!(2) / BalanceManagerDerivative::price()
+                + amount!(2) / BalanceManagerDerivative::price()
                     * BalanceManagerDerivative::leverage()
                     / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
@@ -3496,7 +3503,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(1.9) / BalanceManagerDerivative::price() * BalanceManagerDerivative::leverage()
+            amount!(1.9) / BalanceManagerDerivative::price() * BalanceManagerDerivative::leverage()
                 / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
         assert!(test_object
@@ -3506,7 +3513,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(2) / BalanceManagerDerivative::price() * BalanceManagerDerivative::leverage()
+            amount!(2) / BalanceManagerDerivative::price() * BalanceManagerDerivative::leverage()
                 / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
         assert!(!test_object
@@ -3520,8 +3527,8 @@ mod tests {
         let is_reversed = false;
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
-            Some(dec!(2)),
+            amount!(10),
+            Some(amount!(2)),
             is_reversed,
             Some(BalanceManagerDerivative::position()),
         );
@@ -3539,7 +3546,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            BalanceManagerDerivative::position() + dec!(2),
+            BalanceManagerDerivative::position() + amount!(2),
         );
         assert!(test_object
             .balance_manager()
@@ -3548,7 +3555,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
-            BalanceManagerDerivative::position() + dec!(2) + dec!(0.0000000001),
+            BalanceManagerDerivative::position() + amount!(2) + amount!(0.0000000001),
         );
         assert!(!test_object
             .balance_manager()
@@ -3557,7 +3564,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(2) - BalanceManagerDerivative::position(),
+            amount!(2) - BalanceManagerDerivative::position(),
         );
         assert!(test_object
             .balance_manager()
@@ -3566,7 +3573,7 @@ mod tests {
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
-            dec!(2) + dec!(0.0000000001) - BalanceManagerDerivative::position(),
+            amount!(2) + amount!(0.0000000001) - BalanceManagerDerivative::position(),
         );
         assert!(!test_object
             .balance_manager()
@@ -3579,9 +3586,9 @@ mod tests {
         let is_reversed = true;
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(2),
+            amount!(2),
             Some(
-                dec!(2)
+                amount!(2)
                     / BalanceManagerDerivative::price()
                     / BalanceManagerDerivative::reversed_amount_multiplier(),
             ),
@@ -3602,7 +3609,7 @@ mod tests {
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
             BalanceManagerDerivative::position()
-                + dec!(2)
+                + amount!(2)
                     / BalanceManagerDerivative::price()
                     / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
@@ -3614,10 +3621,10 @@ mod tests {
             OrderSide::Sell,
             BalanceManagerDerivative::price(),
             BalanceManagerDerivative::position()
-                + dec!(2)
+                + amount!(2)
                     / BalanceManagerDerivative::price()
                     / BalanceManagerDerivative::reversed_amount_multiplier()
-                + dec!(0.0000000001),
+                + amount!(0.0000000001),
         );
         assert!(!test_object
             .balance_manager()
@@ -3627,7 +3634,7 @@ mod tests {
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
             -BalanceManagerDerivative::position()
-                + dec!(2)
+                + amount!(2)
                     / BalanceManagerDerivative::price()
                     / BalanceManagerDerivative::reversed_amount_multiplier(),
         );
@@ -3639,10 +3646,10 @@ mod tests {
             OrderSide::Buy,
             BalanceManagerDerivative::price(),
             -BalanceManagerDerivative::position()
-                + dec!(2)
+                + amount!(2)
                     / BalanceManagerDerivative::price()
                     / BalanceManagerDerivative::reversed_amount_multiplier()
-                + dec!(0.0000000001),
+                + amount!(0.0000000001),
         );
         assert!(!test_object
             .balance_manager()
@@ -3663,10 +3670,10 @@ mod tests {
         init_logger();
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(1000),
-            Some(dec!(450)),
+            amount!(1000),
+            Some(amount!(450)),
             is_reversed,
-            Some(dec!(610)),
+            Some(amount!(610)),
         );
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
@@ -3680,8 +3687,8 @@ mod tests {
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             order_side,
-            dec!(9570),
-            dec!(30),
+            price!(9570),
+            amount!(30),
         );
         assert_eq!(
             test_object
@@ -3695,12 +3702,12 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_more_than_limit_long_position(
     ) {
         init_logger();
-        let amount_limit = dec!(5);
+        let amount_limit = amount!(5);
         let is_reversed = false;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
+            amount!(10),
             Some(amount_limit),
             is_reversed,
             None,
@@ -3729,7 +3736,7 @@ mod tests {
             )
             .expect("in test");
 
-        assert_eq!(margin_buy, dec!(5) - dec!(1.9));
+        assert_eq!(margin_buy, amount!(5) - amount!(1.9));
 
         let margin_sell = test_object
             .balance_manager()
@@ -3742,19 +3749,22 @@ mod tests {
                 &mut Some(Explanation::default()),
             )
             .expect("in test");
-        assert_eq!(margin_sell, (dec!(5) + dec!(1.9)) / dec!(0.2) * dec!(0.2));
+        assert_eq!(
+            margin_sell,
+            (amount!(5) + amount!(1.9)) / amount!(0.2) * amount!(0.2)
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_more_than_limit_long_position_reversed(
     ) {
         init_logger();
-        let amount_limit = dec!(5) / BalanceManagerDerivative::price();
+        let amount_limit = amount!(5) / BalanceManagerDerivative::price();
         let is_reversed = true;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -3809,12 +3819,12 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_more_than_limit_short_position(
     ) {
         init_logger();
-        let amount_limit = dec!(5);
+        let amount_limit = amount!(5);
         let is_reversed = false;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
+            amount!(10),
             Some(amount_limit),
             is_reversed,
             None,
@@ -3843,7 +3853,7 @@ mod tests {
             )
             .expect("in test");
 
-        assert_eq!(margin_buy, dec!(5) + dec!(1.9));
+        assert_eq!(margin_buy, amount!(5) + amount!(1.9));
 
         let margin_sell = test_object
             .balance_manager()
@@ -3856,19 +3866,22 @@ mod tests {
                 &mut Some(Explanation::default()),
             )
             .expect("in test");
-        assert_eq!(margin_sell, (dec!(5) - dec!(1.9)) / dec!(0.2) * dec!(0.2));
+        assert_eq!(
+            margin_sell,
+            (amount!(5) - amount!(1.9)) / amount!(0.2) * amount!(0.2)
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_more_than_limit_short_position_reversed(
     ) {
         init_logger();
-        let amount_limit = dec!(5) / BalanceManagerDerivative::price();
+        let amount_limit = amount!(5) / BalanceManagerDerivative::price();
         let is_reversed = true;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -3923,12 +3936,12 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_less_than_limit_long_position(
     ) {
         init_logger();
-        let amount_limit = dec!(10);
+        let amount_limit = amount!(10);
         let is_reversed = false;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
+            amount!(10),
             Some(amount_limit),
             is_reversed,
             None,
@@ -3959,7 +3972,7 @@ mod tests {
 
         assert_eq!(
             margin_buy,
-            (dec!(10) - dec!(1.9)) * dec!(0.2) * dec!(5) * dec!(0.95)
+            (amount!(10) - amount!(1.9)) * dec!(0.2) * dec!(5) * dec!(0.95)
         );
 
         let margin_sell = test_object
@@ -3976,7 +3989,7 @@ mod tests {
 
         assert_eq!(
             margin_sell,
-            (dec!(10) - dec!(1.9) + dec!(1.9)) * dec!(5) * dec!(0.95) * dec!(0.2)
+            (amount!(10) - amount!(1.9) + amount!(1.9)) * dec!(5) * dec!(0.95) * dec!(0.2)
         );
     }
 
@@ -3984,14 +3997,14 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_less_than_limit_long_position_reversed(
     ) {
         init_logger();
-        let amount_limit = dec!(1000)
+        let amount_limit = amount!(1000)
             / BalanceManagerDerivative::price()
             / BalanceManagerDerivative::reversed_amount_multiplier();
         let is_reversed = true;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -4022,7 +4035,7 @@ mod tests {
 
         assert_eq!(
             margin_buy,
-            (dec!(100)
+            (amount!(100)
                 - BalanceManagerDerivative::amount_reversed() * BalanceManagerDerivative::price()
                     / dec!(5)
                     * BalanceManagerDerivative::reversed_amount_multiplier())
@@ -4045,7 +4058,7 @@ mod tests {
             .expect("in test");
         assert_eq!(
             margin_sell,
-            dec!(100) / BalanceManagerDerivative::price() * dec!(5)
+            amount!(100) / BalanceManagerDerivative::price() * dec!(5)
                 / BalanceManagerDerivative::reversed_amount_multiplier()
                 * dec!(0.95)
         );
@@ -4055,12 +4068,12 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_less_than_limit_short_position(
     ) {
         init_logger();
-        let amount_limit = dec!(10);
+        let amount_limit = amount!(10);
         let is_reversed = false;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(10),
+            amount!(10),
             Some(amount_limit),
             is_reversed,
             None,
@@ -4091,7 +4104,7 @@ mod tests {
 
         assert_eq!(
             margin_buy,
-            (dec!(10) - dec!(1.9) + dec!(1.9)) * dec!(0.2) * dec!(5) * dec!(0.95)
+            (amount!(10) - amount!(1.9) + amount!(1.9)) * dec!(0.2) * dec!(5) * dec!(0.95)
         );
 
         let margin_sell = test_object
@@ -4107,7 +4120,7 @@ mod tests {
             .expect("in test");
         assert_eq!(
             margin_sell,
-            (dec!(10) - dec!(1.9)) * dec!(5) * dec!(0.2) * dec!(0.95)
+            (amount!(10) - amount!(1.9)) * dec!(5) * dec!(0.2) * dec!(0.95)
         );
     }
 
@@ -4115,14 +4128,14 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_balance_is_less_than_limit_short_position_reversed(
     ) {
         init_logger();
-        let amount_limit = dec!(1000)
+        let amount_limit = amount!(1000)
             / BalanceManagerDerivative::price()
             / BalanceManagerDerivative::reversed_amount_multiplier();
         let is_reversed = true;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::btc(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -4153,7 +4166,7 @@ mod tests {
 
         assert_eq!(
             margin_buy,
-            dec!(100) / BalanceManagerDerivative::price() * dec!(5) * dec!(0.95)
+            amount!(100) / BalanceManagerDerivative::price() * dec!(5) * dec!(0.95)
                 / BalanceManagerDerivative::reversed_amount_multiplier()
         );
 
@@ -4170,7 +4183,7 @@ mod tests {
             .expect("in test");
         assert_eq!(
             margin_sell,
-            (dec!(100)
+            (amount!(100)
                 - BalanceManagerDerivative::amount_reversed() * BalanceManagerDerivative::price()
                     / dec!(5)
                     * BalanceManagerDerivative::reversed_amount_multiplier())
@@ -4185,13 +4198,13 @@ mod tests {
     pub async fn get_leveraged_balance_in_amount_currency_code_max_rounding_error() {
         //real-life case with a rounding error https://github.com/CryptoDreamTeam/CryptoLp/issues/1348
         init_logger();
-        let amount_limit = dec!(30);
-        let price = dec!(9341);
+        let amount_limit = amount!(30);
+        let price = price!(9341);
         let is_reversed = false;
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -4207,8 +4220,8 @@ mod tests {
             .leverage_by_currency_pair
             .insert(symbol.currency_pair(), dec!(5));
 
-        test_object.fill_order(OrderSide::Sell, Some(price), Some(dec!(20)), is_reversed);
-        let balance = dec!(0.0139536399914456800684345595);
+        test_object.fill_order(OrderSide::Sell, Some(price), Some(amount!(20)), is_reversed);
+        let balance = amount!(0.0139536399914456800684345595);
 
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
@@ -4228,7 +4241,7 @@ mod tests {
                     &mut Some(Explanation::default())
                 )
                 .expect("in test"),
-            dec!(10)
+            amount!(10)
         );
     }
 
@@ -4240,11 +4253,11 @@ mod tests {
         #[case] is_reversed: bool,
     ) {
         init_logger();
-        let position = dec!(2);
+        let position = amount!(2);
 
         let test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(0),
+            amount!(0),
             None,
             is_reversed,
             Some(position),
@@ -4256,7 +4269,7 @@ mod tests {
         BalanceManagerBase::update_balance_with_positions(
             &mut test_object.balance_manager(),
             exchange_account_id_2,
-            hashmap![BalanceManagerBase::eth() => dec!(0)],
+            hashmap![BalanceManagerBase::eth() => amount!(0)],
             hashmap![symbol_currency_pair => position],
         );
 
@@ -4288,11 +4301,11 @@ mod tests {
         #[case] is_reversed: bool,
     ) {
         init_logger();
-        let position = dec!(2);
+        let position = amount!(2);
 
         let test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(0),
+            amount!(0),
             None,
             is_reversed,
             Some(position),
@@ -4316,8 +4329,8 @@ mod tests {
         BalanceManagerBase::update_balance_with_positions(
             &mut test_object.balance_manager(),
             exchange_account_id,
-            hashmap![BalanceManagerBase::eth() => dec!(1)],
-            hashmap![symbol_currency_pair => dec!(3)],
+            hashmap![BalanceManagerBase::eth() => amount!(1)],
+            hashmap![symbol_currency_pair => amount!(3)],
         );
 
         let positions = test_object
@@ -4341,11 +4354,11 @@ mod tests {
         #[case] is_reversed: bool,
     ) {
         init_logger();
-        let amount_limit = dec!(2);
+        let amount_limit = amount!(2);
 
         let mut test_object = create_test_obj_by_currency_code_and_symbol_currency_pair(
             BalanceManagerBase::eth(),
-            dec!(100),
+            amount!(100),
             Some(amount_limit),
             is_reversed,
             None,
@@ -4355,10 +4368,10 @@ mod tests {
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
         order.add_fill(BalanceManagerDerivative::create_order_fill(
-            dec!(0.1),
-            dec!(2),
-            dec!(0.1),
-            dec!(0),
+            price!(0.1),
+            amount!(2),
+            amount!(0.1),
+            amount!(0),
             is_reversed,
         ));
 
@@ -4369,8 +4382,8 @@ mod tests {
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.1),
-            dec!(1),
+            price!(0.1),
+            amount!(1),
         );
         assert!(test_object
             .balance_manager()
@@ -4379,8 +4392,8 @@ mod tests {
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.1),
-            dec!(4),
+            price!(0.1),
+            amount!(4),
         );
         assert!(test_object
             .balance_manager()
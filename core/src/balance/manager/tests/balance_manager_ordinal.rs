@@ -3,7 +3,6 @@ use mmb_utils::DateTime;
 use mockall_double::double;
 use parking_lot::Mutex;
 use parking_lot::MutexGuard;
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use uuid::Uuid;
 
@@ -25,6 +24,7 @@ use crate::{
         test_helper::get_test_exchange_with_symbol_and_id,
     },
 };
+use mmb_domain::amount;
 
 pub struct BalanceManagerOrdinal {
     pub balance_manager_base: BalanceManagerBase,
@@ -93,14 +93,14 @@ impl BalanceManagerOrdinal {
         }
     }
 
-    fn create_order_fill(price: Price, amount: Amount, cost: Decimal) -> OrderFill {
+    fn create_order_fill(price: Price, amount: Amount, cost: Amount) -> OrderFill {
         BalanceManagerOrdinal::create_order_fill_with_time(price, amount, cost, time_manager::now())
     }
 
     fn create_order_fill_with_time(
         price: Price,
         amount: Amount,
-        cost: Decimal,
+        cost: Amount,
         receive_time: DateTime,
     ) -> OrderFill {
         OrderFill::new(
@@ -114,11 +114,11 @@ impl BalanceManagerOrdinal {
             cost,
             OrderFillRole::Taker,
             BalanceManagerBase::bnb(),
-            dec!(0.1),
-            dec!(0),
+            amount!(0.1),
+            amount!(0),
             BalanceManagerBase::bnb(),
-            dec!(0.1),
-            dec!(0.1),
+            amount!(0.1),
+            amount!(0.1),
             false,
             None,
             None,
@@ -150,7 +150,7 @@ mod tests {
     use mmb_utils::logger::init_logger;
     use parking_lot::Mutex;
     use rstest::rstest;
-    use rust_decimal::Decimal;
+
     use rust_decimal_macros::dec;
 
     use crate::balance::manager::balance_manager::BalanceManager;
@@ -166,6 +166,7 @@ mod tests {
     };
 
     use super::BalanceManagerOrdinal;
+    use mmb_domain::{amount, price};
 
     fn create_eth_btc_test_obj(btc_amount: Amount, eth_amount: Amount) -> BalanceManagerOrdinal {
         let test_object = BalanceManagerOrdinal::new();
@@ -258,8 +259,8 @@ mod tests {
             );
             let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
                 OrderSide::Buy,
-                dec!(0.2),
-                dec!(2),
+                price!(0.2),
+                amount!(2),
             );
             assert_eq!(
                 test_object
@@ -300,7 +301,7 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn balance_was_received_existing_exchange_account_id_with_currency() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(2));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(2));
 
         assert!(test_object
             .balance_manager()
@@ -321,26 +322,26 @@ mod tests {
 
         let balance_manager = &mut test_object.balance_manager();
         let currencies = hashmap![
-            btc => dec!(2),
-            eth => dec!(1),
-            bnb => dec!(7.5),
-            eos => dec!(0)
+            btc => amount!(2),
+            eth => amount!(1),
+            bnb => amount!(7.5),
+            eos => amount!(0)
         ];
         BalanceManagerBase::update_balance(balance_manager, exchange_account_id, currencies);
 
         let symbol = test_object.balance_manager_base.symbol();
         let btc_balance =
             balance_manager.get_exchange_balance(exchange_account_id, symbol.clone(), btc);
-        assert_eq!(btc_balance, Some(dec!(2)));
+        assert_eq!(btc_balance, Some(amount!(2)));
 
         assert_eq!(
             balance_manager.get_exchange_balance(exchange_account_id, symbol.clone(), eth),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
             balance_manager.get_exchange_balance(exchange_account_id, symbol.clone(), bnb),
-            Some(dec!(7.5))
+            Some(amount!(7.5))
         );
 
         assert_eq!(
@@ -349,10 +350,37 @@ mod tests {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    pub async fn keep_initial_balances_after_update() {
+        init_logger();
+        let test_object = create_eth_btc_test_obj(amount!(2), amount!(1));
+
+        let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
+        let btc = BalanceManagerBase::btc();
+        let eth = BalanceManagerBase::eth();
+
+        let balance_manager = &mut test_object.balance_manager();
+        BalanceManagerBase::update_balance(
+            balance_manager,
+            exchange_account_id,
+            hashmap![btc => amount!(1), eth => amount!(3)],
+        );
+
+        assert_eq!(
+            balance_manager.get_initial_balances(exchange_account_id),
+            Some(&hashmap![btc => amount!(2), eth => amount!(1)])
+        );
+        assert_eq!(
+            balance_manager
+                .get_initial_balances(test_object.balance_manager_base.exchange_account_id_2),
+            None
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_balance_buy_returns_quote_balance_and_currency_code() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(0.5), dec!(0.1));
+        let test_object = create_eth_btc_test_obj(amount!(0.5), amount!(0.1));
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
 
         let side = OrderSide::Buy;
@@ -374,16 +402,16 @@ mod tests {
                 exchange_account_id,
                 test_object.balance_manager_base.symbol(),
                 side,
-                dec!(1),
+                price!(1),
             ),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_balance_sell_return_base_balance_and_currency_code() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(0.5), dec!(0.1));
+        let test_object = create_eth_btc_test_obj(amount!(0.5), amount!(0.1));
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
 
         let side = OrderSide::Sell;
@@ -405,21 +433,21 @@ mod tests {
                 exchange_account_id,
                 test_object.balance_manager_base.symbol(),
                 side,
-                dec!(1),
+                price!(1),
             ),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn can_reserve_buy_not_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0.5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0.5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(!test_object
@@ -430,19 +458,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn can_reserve_buy_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1.0));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1.0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -453,19 +481,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1.0))
+            Some(amount!(1.0))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn can_reserve_sell_not_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0.5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0.5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(!test_object
@@ -476,19 +504,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn can_reserve_sell_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5.0));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5.0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -499,19 +527,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(5.0))
+            Some(amount!(5.0))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_buy_not_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0.5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0.5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -522,19 +550,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_buy_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -546,7 +574,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         let balance_manager = test_object.balance_manager();
@@ -561,22 +589,22 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Buy);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_sell_not_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(0.5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(0.5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -587,19 +615,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_sell_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -610,7 +638,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         let balance_manager = test_object.balance_manager();
@@ -625,22 +653,22 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Sell);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_update_reservation_buy_worse_price_not_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1.1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1.1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -650,29 +678,29 @@ mod tests {
 
         assert!(!test_object
             .balance_manager()
-            .try_update_reservation(reservation_id, dec!(0.3)));
+            .try_update_reservation(reservation_id, price!(0.3)));
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_update_reservation_buy_worse_price_enough_balance() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1.5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1.5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -682,29 +710,29 @@ mod tests {
 
         assert!(test_object
             .balance_manager()
-            .try_update_reservation(reservation_id, dec!(0.3)));
+            .try_update_reservation(reservation_id, price!(0.3)));
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.price, dec!(0.3));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.3));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_update_reservation_buy_better_price() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1.1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1.1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -715,34 +743,34 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
 
         assert!(test_object
             .balance_manager()
-            .try_update_reservation(reservation_id, dec!(0.1)));
+            .try_update_reservation(reservation_id, price!(0.1)));
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.6))
+            Some(amount!(0.6))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.price, dec!(0.1));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.1));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_update_reservation_sell() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5.0));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5.0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -751,35 +779,35 @@ mod tests {
             .expect("in test");
         assert!(test_object
             .balance_manager()
-            .try_update_reservation(reservation_id, dec!(0.1)));
+            .try_update_reservation(reservation_id, price!(0.1)));
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.price, dec!(0.1));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.1));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_pair_not_enough_balance_for_1() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(0.0), dec!(5));
+        let test_object = create_eth_btc_test_obj(amount!(0.0), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -791,32 +819,32 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_2),
-            Some(dec!(5))
+            Some(amount!(5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_pair_not_enough_balance_for_2() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(3), dec!(0));
+        let test_object = create_eth_btc_test_obj(amount!(3), amount!(0));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -828,32 +856,32 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(3))
+            Some(amount!(3))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_2),
-            Some(dec!(0))
+            Some(amount!(0))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_pair_enough_balance() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(1), dec!(5));
+        let test_object = create_eth_btc_test_obj(amount!(1), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let (reservation_id_1, reservation_id_2) = test_object
@@ -865,14 +893,14 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_2),
-            Some(dec!(0.0))
+            Some(amount!(0.0))
         );
 
         let balance_manager = test_object.balance_manager();
@@ -887,10 +915,10 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Buy);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
@@ -904,34 +932,34 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Sell);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_three_not_enough_balance_for_1() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(0.0), dec!(5));
+        let test_object = create_eth_btc_test_obj(amount!(0.0), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(4),
+            price!(0.2),
+            amount!(4),
         );
 
         let reserve_parameters_3 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(1),
+            price!(0.2),
+            amount!(1),
         );
 
         assert!(test_object
@@ -947,38 +975,38 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_3),
-            Some(dec!(5))
+            Some(amount!(5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_three_not_enough_balance_for_2() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(1), dec!(5));
+        let test_object = create_eth_btc_test_obj(amount!(1), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(6),
+            price!(0.2),
+            amount!(6),
         );
 
         let reserve_parameters_3 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(1),
+            price!(0.2),
+            amount!(1),
         );
 
         assert!(test_object
@@ -994,38 +1022,38 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_3),
-            Some(dec!(5))
+            Some(amount!(5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_three_not_enough_balance_for_3() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(1), dec!(5));
+        let test_object = create_eth_btc_test_obj(amount!(1), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_3 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(1),
+            price!(0.2),
+            amount!(1),
         );
 
         assert!(test_object
@@ -1040,38 +1068,38 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_3),
-            Some(dec!(5))
+            Some(amount!(5))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn try_reserve_three_enough_balance() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(1), dec!(6));
+        let test_object = create_eth_btc_test_obj(amount!(1), amount!(6));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_3 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(1),
+            price!(0.2),
+            amount!(1),
         );
 
         let (reservation_id_1, reservation_id_2, reservation_id_3) = test_object
@@ -1087,14 +1115,14 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_3),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         let balance_manager = test_object.balance_manager();
@@ -1117,10 +1145,10 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Buy);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
@@ -1134,10 +1162,10 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Sell);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(5));
-        assert_eq!(reservation.not_approved_amount, dec!(5));
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(5));
+        assert_eq!(reservation.not_approved_amount, amount!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
         assert!(reservation.approved_parts.is_empty());
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_3);
@@ -1151,22 +1179,22 @@ mod tests {
             test_object.balance_manager_base.symbol()
         );
         assert_eq!(reservation.order_side, OrderSide::Sell);
-        assert_eq!(reservation.price, dec!(0.2));
-        assert_eq!(reservation.amount, dec!(1));
-        assert_eq!(reservation.not_approved_amount, dec!(1));
-        assert_eq!(reservation.unreserved_amount, dec!(1));
+        assert_eq!(reservation.price, price!(0.2));
+        assert_eq!(reservation.amount, amount!(1));
+        assert_eq!(reservation.not_approved_amount, amount!(1));
+        assert_eq!(reservation.unreserved_amount, amount!(1));
         assert!(reservation.approved_parts.is_empty());
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_should_not_unreserve_for_unknown_exchange_account_id() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1182,24 +1210,24 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect("in test");
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.unreserved_amount, dec!(5));
+        assert_eq!(reservation.unreserved_amount, amount!(5));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_can_unreserve_more_than_reserved_with_compensation_amounts() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1209,7 +1237,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5.00001))
+            .unreserve(reservation_id, amount!(5.00001))
             .expect("in test");
 
         assert!(test_object
@@ -1221,19 +1249,19 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1))
+            Some(amount!(1))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_can_not_unreserve_after_complete_unreserved() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1243,12 +1271,12 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect("in test");
 
         let error = test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect_err("should be error");
 
         if !error.to_string().contains("Can't find reservation_id=") {
@@ -1262,13 +1290,13 @@ mod tests {
     }
 
     #[rstest]
-    #[case(dec!(0))]
+    #[case(amount!(0))]
     // min positive value in rust_decimaL::Decimal (Scale maximum precision - 28)
-    #[case(dec!(1e-28))]
+    #[case(amount!(1e-28))]
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_zero_amount(#[case] amount_to_unreserve: Amount) {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let symbol = Arc::from(Symbol::new(
             false,
@@ -1280,7 +1308,7 @@ mod tests {
             None,
             None,
             None,
-            Some(dec!(1)),
+            Some(amount!(1)),
             BalanceManagerBase::eth(),
             Some(BalanceManagerBase::btc()),
             Precision::ByTick { tick: dec!(0.1) },
@@ -1292,8 +1320,8 @@ mod tests {
             test_object.balance_manager_base.exchange_account_id_1,
             symbol,
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(1),
+            price!(0.2),
+            amount!(1),
         );
 
         let reservation_id = test_object
@@ -1311,25 +1339,25 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(4))
+            Some(amount!(4))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.unreserved_amount, dec!(1));
-        assert_eq!(reservation.not_approved_amount, dec!(1));
+        assert_eq!(reservation.unreserved_amount, amount!(1));
+        assert_eq!(reservation.not_approved_amount, amount!(1));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_buy() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1339,31 +1367,31 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(4))
+            .unreserve(reservation_id, amount!(4))
             .expect("in test");
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(0.8))
+            Some(amount!(0.8))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.unreserved_amount, dec!(1));
+        assert_eq!(reservation.unreserved_amount, amount!(1));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_sell() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1373,31 +1401,31 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(4))
+            .unreserve(reservation_id, amount!(4))
             .expect("in test");
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(4))
+            Some(amount!(4))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.unreserved_amount, dec!(1));
+        assert_eq!(reservation.unreserved_amount, amount!(1));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_rest_buy() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1414,7 +1442,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert!(test_object
@@ -1426,12 +1454,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_rest_sell() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1448,7 +1476,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(5))
+            Some(amount!(5))
         );
 
         assert!(test_object
@@ -1460,12 +1488,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_rest_partially_unreserved_buy() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1475,7 +1503,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(4))
+            .unreserve(reservation_id, amount!(4))
             .expect("in test");
 
         test_object
@@ -1487,7 +1515,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert!(test_object
@@ -1499,12 +1527,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_rest_partially_unreserved_sell() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reservation_id = test_object
@@ -1514,7 +1542,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(4))
+            .unreserve(reservation_id, amount!(4))
             .expect("in test");
 
         test_object
@@ -1526,7 +1554,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(5))
+            Some(amount!(5))
         );
 
         assert!(test_object
@@ -1536,8 +1564,8 @@ mod tests {
     }
 
     #[rstest]
-    #[case(dec!(5), dec!(0.2), dec!(3), dec!(0.5), dec!(2) ,dec!(2) )]
-    #[case(dec!(5), dec!(0.2), dec!(3), dec!(0.2), dec!(2) ,dec!(2) )]
+    #[case(amount!(5), price!(0.2), amount!(3), price!(0.5), amount!(2) ,amount!(2) )]
+    #[case(amount!(5), price!(0.2), amount!(3), price!(0.2), amount!(2) ,amount!(2) )]
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservation_different_price_sell(
         #[case] src_balance: Amount,
@@ -1597,21 +1625,21 @@ mod tests {
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.cost, dec!(1));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(1));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
-        assert_eq!(reservation.cost, dec!(4));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(4));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
     }
 
     #[rstest]
-    #[case(dec!(5), dec!(0.2), dec!(3), dec!(0.5), dec!(2) ,dec!(2) )]
-    #[case(dec!(5), dec!(0.2), dec!(3), dec!(0.2), dec!(2) ,dec!(2) )]
+    #[case(amount!(5), price!(0.2), amount!(3), price!(0.5), amount!(2) ,amount!(2) )]
+    #[case(amount!(5), price!(0.2), amount!(3), price!(0.2), amount!(2) ,amount!(2) )]
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservation_different_price_buy(
         #[case] src_balance: Amount,
@@ -1671,33 +1699,33 @@ mod tests {
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.cost, dec!(1));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(1));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
-        assert_eq!(reservation.cost, dec!(4));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(4));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservations_amount_partial() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
 
         let reservation_id_1 = test_object
@@ -1712,7 +1740,7 @@ mod tests {
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &None
         ));
 
@@ -1720,38 +1748,38 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.cost, dec!(1));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(1));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
-        assert_eq!(reservation.cost, dec!(4));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(4));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservations_amount_all() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
 
         let reservation_id_1 = test_object
@@ -1766,7 +1794,7 @@ mod tests {
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(3),
+            amount!(3),
             &None
         ));
 
@@ -1774,7 +1802,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         assert!(test_object
@@ -1785,10 +1813,10 @@ mod tests {
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
 
-        assert_eq!(reservation.cost, dec!(2) + dec!(3));
-        assert_eq!(reservation.amount, dec!(2) + dec!(3));
-        assert_eq!(reservation.not_approved_amount, dec!(2) + dec!(3));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(3));
+        assert_eq!(reservation.cost, amount!(2) + amount!(3));
+        assert_eq!(reservation.amount, amount!(2) + amount!(3));
+        assert_eq!(reservation.not_approved_amount, amount!(2) + amount!(3));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(3));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -1796,18 +1824,18 @@ mod tests {
         init_logger();
         let test_object = Arc::new(Mutex::new(create_test_obj_by_currency_code(
             BalanceManagerBase::eth(),
-            dec!(5),
+            amount!(5),
         )));
 
         let reserve_parameters_1 = test_object
             .lock()
             .balance_manager_base
-            .create_reserve_parameters(OrderSide::Sell, dec!(0.2), dec!(3));
+            .create_reserve_parameters(OrderSide::Sell, price!(0.2), amount!(3));
 
         let reserve_parameters_2 = test_object
             .lock()
             .balance_manager_base
-            .create_reserve_parameters(OrderSide::Sell, dec!(0.2), dec!(2));
+            .create_reserve_parameters(OrderSide::Sell, price!(0.2), amount!(2));
 
         let reservation_id_1 = test_object
             .lock()
@@ -1825,7 +1853,7 @@ mod tests {
             balance_manager_cloned.lock().try_transfer_reservation(
                 reservation_id_1,
                 reservation_id_2,
-                dec!(5),
+                amount!(5),
                 &None,
             );
         });
@@ -1839,7 +1867,7 @@ mod tests {
                 .lock()
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         assert_eq!(
@@ -1848,7 +1876,7 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_1)
                 .unreserved_amount,
-            dec!(3)
+            amount!(3)
         );
         assert_eq!(
             test_object
@@ -1856,19 +1884,19 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_2)
                 .unreserved_amount,
-            dec!(2)
+            amount!(2)
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_zero_from_zero_reservation_should_remove_reservation() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(0),
+            price!(0.2),
+            amount!(0),
         );
 
         let reservation_id = test_object
@@ -1878,7 +1906,7 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(0))
+            .unreserve(reservation_id, amount!(0))
             .expect("in test");
 
         assert!(test_object
@@ -1890,12 +1918,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservations_amount_with_unreserve() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -1904,8 +1932,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -1914,13 +1942,13 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id_1, dec!(1))
+            .unreserve(reservation_id_1, amount!(1))
             .expect("in test");
 
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(1),
+            amount!(1),
             &None
         ));
 
@@ -1928,7 +1956,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
@@ -1936,7 +1964,7 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_1)
                 .unreserved_amount,
-            dec!(3) - dec!(1) - dec!(1)
+            amount!(3) - amount!(1) - amount!(1)
         );
 
         assert_eq!(
@@ -1944,19 +1972,20 @@ mod tests {
                 .balance_manager()
                 .get_reservation_expected(reservation_id_2)
                 .unreserved_amount,
-            dec!(2) + dec!(1)
+            amount!(2) + amount!(1)
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservations_amount_partial_approve() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -1965,8 +1994,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2006,35 +2035,35 @@ mod tests {
         assert!(balance_manager.try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &Some(order.header.client_order_id.clone())
         ));
 
         assert_eq!(
             balance_manager.get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
-        assert_eq!(reservation.cost, dec!(3) - dec!(2));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(0));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(3) - amount!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(0));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
         assert_eq!(
             reservation
                 .approved_parts
                 .get(&order.header.client_order_id)
                 .expect("in test")
                 .amount,
-            dec!(3) - dec!(2)
+            amount!(3) - amount!(2)
         );
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
 
-        assert_eq!(reservation.cost, dec!(2) + dec!(2));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(2) + amount!(2));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
 
         assert_eq!(
             reservation
@@ -2042,7 +2071,7 @@ mod tests {
                 .get(&order.header.client_order_id)
                 .expect("in test")
                 .amount,
-            dec!(2)
+            amount!(2)
         );
     }
 
@@ -2050,12 +2079,13 @@ mod tests {
     #[should_panic]
     pub async fn transfer_reservations_amount_more_thane_we_have() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2064,8 +2094,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2100,7 +2130,7 @@ mod tests {
         balance_manager.try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(4),
+            amount!(4),
             &None,
         );
     }
@@ -2109,12 +2139,13 @@ mod tests {
     #[should_panic]
     pub async fn transfer_reservations_amount_more_than_we_have_by_approve_client_order_id() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2123,8 +2154,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2138,7 +2169,7 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id_1,
             &order.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         let mut balance_manager = test_object.balance_manager();
@@ -2149,13 +2180,13 @@ mod tests {
                 .get(&order.header.client_order_id)
                 .expect("in test")
                 .amount,
-            dec!(1)
+            amount!(1)
         );
 
         balance_manager.try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &Some(order.header.client_order_id),
         );
     }
@@ -2164,12 +2195,12 @@ mod tests {
     #[should_panic]
     pub async fn transfer_reservations_unknown_client_order_id() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2178,8 +2209,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2188,7 +2219,7 @@ mod tests {
         test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &Some(ClientOrderId::new("unknown_id".into())),
         );
     }
@@ -2196,12 +2227,13 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn transfer_reservations_amount_partial_approve_with_multiple_orders() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2210,8 +2242,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2228,19 +2260,19 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id_1,
             &order_1.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         test_object.balance_manager().approve_reservation(
             reservation_id_1,
             &order_2.header.client_order_id,
-            dec!(2),
+            amount!(2),
         );
 
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &Some(order_2.header.client_order_id.clone()),
         ));
 
@@ -2248,16 +2280,16 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
 
-        assert_eq!(reservation.cost, dec!(3) - dec!(2));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(0));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(3) - amount!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(0));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         assert!(reservation
             .approved_parts
@@ -2269,10 +2301,10 @@ mod tests {
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
 
-        assert_eq!(reservation.cost, dec!(2) + dec!(2));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(2));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(2) + amount!(2));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(2));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
 
         assert_eq!(
             reservation
@@ -2280,7 +2312,7 @@ mod tests {
                 .get(&order_2.header.client_order_id)
                 .expect("in test")
                 .amount,
-            dec!(2)
+            amount!(2)
         );
     }
 
@@ -2288,12 +2320,13 @@ mod tests {
     pub async fn transfer_reservations_amount_partial_approve_with_multiple_orders_to_existing_part(
     ) {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(3),
+            price!(0.2),
+            amount!(3),
         );
         let reservation_id_1 = test_object
             .balance_manager()
@@ -2302,8 +2335,8 @@ mod tests {
 
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
         let reservation_id_2 = test_object
             .balance_manager()
@@ -2320,25 +2353,25 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id_1,
             &order_1.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         test_object.balance_manager().approve_reservation(
             reservation_id_1,
             &order_2.header.client_order_id,
-            dec!(2),
+            amount!(2),
         );
 
         test_object.balance_manager().approve_reservation(
             reservation_id_2,
             &order_2.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         assert!(test_object.balance_manager().try_transfer_reservation(
             reservation_id_1,
             reservation_id_2,
-            dec!(2),
+            amount!(2),
             &Some(order_2.header.client_order_id.clone()),
         ));
 
@@ -2346,16 +2379,16 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(0))
+            Some(amount!(0))
         );
 
         let balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id_1);
 
-        assert_eq!(reservation.cost, dec!(3) - dec!(2));
-        assert_eq!(reservation.amount, dec!(3) - dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(0));
-        assert_eq!(reservation.unreserved_amount, dec!(3) - dec!(2));
+        assert_eq!(reservation.cost, amount!(3) - amount!(2));
+        assert_eq!(reservation.amount, amount!(3) - amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(0));
+        assert_eq!(reservation.unreserved_amount, amount!(3) - amount!(2));
 
         assert!(reservation
             .approved_parts
@@ -2367,10 +2400,10 @@ mod tests {
 
         let reservation = balance_manager.get_reservation_expected(reservation_id_2);
 
-        assert_eq!(reservation.cost, dec!(2) + dec!(2));
-        assert_eq!(reservation.amount, dec!(2) + dec!(2));
-        assert_eq!(reservation.not_approved_amount, dec!(1));
-        assert_eq!(reservation.unreserved_amount, dec!(2) + dec!(2));
+        assert_eq!(reservation.cost, amount!(2) + amount!(2));
+        assert_eq!(reservation.amount, amount!(2) + amount!(2));
+        assert_eq!(reservation.not_approved_amount, amount!(1));
+        assert_eq!(reservation.unreserved_amount, amount!(2) + amount!(2));
 
         assert_eq!(
             reservation
@@ -2378,7 +2411,7 @@ mod tests {
                 .get(&order_2.header.client_order_id)
                 .expect("in test")
                 .amount,
-            dec!(1) + dec!(2)
+            amount!(1) + amount!(2)
         );
     }
 
@@ -2387,15 +2420,15 @@ mod tests {
         init_logger();
         let test_object = create_eth_btc_test_obj_for_two_exchanges(
             BalanceManagerBase::btc(),
-            dec!(1),
+            amount!(1),
             BalanceManagerBase::eth(),
-            dec!(5),
+            amount!(5),
         );
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let reserve_parameters_2 = ReserveParameters::new(
@@ -2403,8 +2436,8 @@ mod tests {
             test_object.balance_manager_base.exchange_account_id_2,
             test_object.balance_manager_base.symbol(),
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         let (reservation_id_1, reservation_id_2) = test_object
@@ -2414,23 +2447,23 @@ mod tests {
 
         test_object.balance_manager().unreserve_pair(
             reservation_id_1,
-            dec!(5),
+            amount!(5),
             reservation_id_2,
-            dec!(5),
+            amount!(5),
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_1),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters_2),
-            Some(dec!(5))
+            Some(amount!(5))
         );
 
         assert!(test_object
@@ -2447,7 +2480,7 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_balance_not_existing_exchange_account_id() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(5));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(5));
 
         assert_eq!(
             test_object.balance_manager().get_balance_by_side(
@@ -2455,7 +2488,7 @@ mod tests {
                 ExchangeAccountId::new("unknown_id", 0),
                 test_object.balance_manager_base.symbol(),
                 OrderSide::Buy,
-                dec!(1),
+                price!(1),
             ),
             None
         );
@@ -2464,7 +2497,7 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_balance_not_existing_currency_code() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(2));
+        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(2));
 
         assert_eq!(
             test_object.balance_manager().get_balance_by_currency_code(
@@ -2472,7 +2505,7 @@ mod tests {
                 test_object.balance_manager_base.exchange_account_id_1,
                 test_object.balance_manager_base.symbol(),
                 "not_existing_currency_code".into(),
-                dec!(1),
+                price!(1),
             ),
             None
         );
@@ -2492,16 +2525,16 @@ mod tests {
             &mut test_object.balance_manager(),
             exchange_account_id,
             hashmap![
-                btc => dec!(2),
-                eth => dec!(0.5),
-                bnb => dec!(0.1)
+                btc => amount!(2),
+                eth => amount!(0.5),
+                bnb => amount!(0.1)
             ],
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(5),
+            price!(0.2),
+            amount!(5),
         );
 
         assert!(test_object
@@ -2513,9 +2546,9 @@ mod tests {
             &mut test_object.balance_manager(),
             exchange_account_id,
             hashmap![
-                btc => dec!(2),
-                eth => dec!(0.5),
-                bnb => dec!(0.1)
+                btc => amount!(2),
+                eth => amount!(0.5),
+                bnb => amount!(0.1)
             ],
         );
 
@@ -2523,7 +2556,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(2) - dec!(0.2) * dec!(5))
+            Some(amount!(2) - amount!(0.2) * dec!(5))
         );
 
         assert_eq!(
@@ -2532,7 +2565,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 eth
             ),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
         assert_eq!(
             test_object.balance_manager().get_exchange_balance(
@@ -2540,7 +2573,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 bnb
             ),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
     }
 
@@ -2558,18 +2591,18 @@ mod tests {
             &mut test_object.balance_manager(),
             exchange_account_id,
             hashmap![
-                btc => dec!(2),
-                eth => dec!(0.5),
-                bnb => dec!(0.1)
+                btc => amount!(2),
+                eth => amount!(0.5),
+                bnb => amount!(0.1)
             ],
         );
 
-        let amount = dec!(5);
+        let amount = amount!(5);
         let client_order_id = ClientOrderId::unique_id();
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
+            price!(0.2),
             amount,
         );
 
@@ -2586,9 +2619,9 @@ mod tests {
             &mut test_object.balance_manager(),
             exchange_account_id,
             hashmap![
-                btc => dec!(2),
-                eth => dec!(0.5),
-                bnb => dec!(0.1)
+                btc => amount!(2),
+                eth => amount!(0.5),
+                bnb => amount!(0.1)
             ],
         );
 
@@ -2596,7 +2629,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(2))
+            Some(amount!(2))
         );
 
         assert_eq!(
@@ -2605,7 +2638,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 eth
             ),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
         assert_eq!(
             test_object.balance_manager().get_exchange_balance(
@@ -2613,7 +2646,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 bnb
             ),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
     }
 
@@ -2629,9 +2662,9 @@ mod tests {
         let bnb = BalanceManagerBase::bnb();
 
         let mut balance_map = hashmap![
-            btc => dec!(2),
-            eth => dec!(0.5),
-            bnb => dec!(0.1)
+            btc => amount!(2),
+            eth => amount!(0.5),
+            bnb => amount!(0.1)
         ];
 
         BalanceManagerBase::update_balance(
@@ -2640,8 +2673,8 @@ mod tests {
             balance_map.clone(),
         );
 
-        let amount = dec!(5);
-        let price = dec!(0.2);
+        let amount = amount!(5);
+        let price = price!(0.2);
         let approved_amount = amount / dec!(2);
         let client_order_id = ClientOrderId::unique_id();
 
@@ -2662,7 +2695,7 @@ mod tests {
             approved_amount,
         );
 
-        balance_map.insert(btc, dec!(1.5));
+        balance_map.insert(btc, amount!(1.5));
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
             exchange_account_id,
@@ -2673,7 +2706,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         assert_eq!(
@@ -2682,7 +2715,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 eth
             ),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
         assert_eq!(
             test_object.balance_manager().get_exchange_balance(
@@ -2690,7 +2723,7 @@ mod tests {
                 test_object.balance_manager_base.symbol(),
                 bnb
             ),
-            Some(dec!(0.1))
+            Some(amount!(0.1))
         );
     }
 
@@ -2703,23 +2736,23 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(2), dec!(0.5), dec!(0.2)],
+            vec![amount!(2), amount!(0.5), amount!(0.2)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
@@ -2732,7 +2765,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -2740,7 +2773,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0.5) + dec!(5)
+            amount!(0.5) + amount!(5)
         );
 
         assert_eq!(
@@ -2748,7 +2781,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.2) - dec!(0.1)
+            amount!(0.2) - amount!(0.1)
         );
     }
 
@@ -2761,23 +2794,23 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(7), dec!(11), dec!(0.2)],
+            vec![amount!(7), amount!(11), amount!(0.2)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Sell, ReservationId::generate());
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
@@ -2790,7 +2823,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(7) + price * dec!(5)
+            amount!(7) + price * amount!(5)
         );
 
         assert_eq!(
@@ -2798,7 +2831,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(11) - dec!(5)
+            amount!(11) - amount!(5)
         );
 
         assert_eq!(
@@ -2806,7 +2839,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.2) - dec!(0.1)
+            amount!(0.2) - amount!(0.1)
         );
     }
 
@@ -2819,23 +2852,23 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(2), dec!(0.5), dec!(0.25)],
+            vec![amount!(2), amount!(0.5), amount!(0.25)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object.balance_manager().order_was_filled_with_fill(
@@ -2849,7 +2882,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -2857,7 +2890,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0.5) + dec!(5)
+            amount!(0.5) + amount!(5)
         );
 
         assert_eq!(
@@ -2865,7 +2898,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.25) - dec!(0.1)
+            amount!(0.25) - amount!(0.1)
         );
     }
 
@@ -2878,23 +2911,23 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(7), dec!(11), dec!(0.2)],
+            vec![amount!(7), amount!(11), amount!(0.2)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Sell, ReservationId::generate());
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object.balance_manager().order_was_filled_with_fill(
@@ -2908,7 +2941,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(7) + price * dec!(1)
+            amount!(7) + price * amount!(1)
         );
 
         assert_eq!(
@@ -2916,7 +2949,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(11) - dec!(1)
+            amount!(11) - amount!(1)
         );
 
         assert_eq!(
@@ -2924,7 +2957,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.2) - dec!(0.1)
+            amount!(0.2) - amount!(0.1)
         );
     }
 
@@ -2937,25 +2970,25 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(2), dec!(0.5), dec!(0.2)],
+            vec![amount!(2), amount!(0.5), amount!(0.2)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
-        order.fills.filled_amount = dec!(6);
+        order.fills.filled_amount = amount!(6);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object
@@ -2967,7 +3000,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5) - price * dec!(1)
+            amount!(2) - price * amount!(5) - price * amount!(1)
         );
 
         assert_eq!(
@@ -2975,7 +3008,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0.5) + dec!(5) + dec!(1)
+            amount!(0.5) + amount!(5) + amount!(1)
         );
 
         assert_eq!(
@@ -2983,7 +3016,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.2) - dec!(0.1) - dec!(0.1)
+            amount!(0.2) - amount!(0.1) - amount!(0.1)
         );
     }
 
@@ -2996,24 +3029,24 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(7), dec!(11), dec!(0.2)],
+            vec![amount!(7), amount!(11), amount!(0.2)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Sell, ReservationId::generate());
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
-        order.fills.filled_amount = dec!(6);
+        order.fills.filled_amount = amount!(6);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object
@@ -3025,7 +3058,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(7) + price * dec!(5) + price * dec!(1)
+            amount!(7) + price * amount!(5) + price * amount!(1)
         );
 
         assert_eq!(
@@ -3033,7 +3066,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(11) - dec!(5) - dec!(1)
+            amount!(11) - amount!(5) - amount!(1)
         );
 
         assert_eq!(
@@ -3041,7 +3074,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.2) - dec!(0.1) - dec!(0.1)
+            amount!(0.2) - amount!(0.1) - amount!(0.1)
         );
     }
 
@@ -3054,24 +3087,24 @@ mod tests {
                 BalanceManagerBase::eth(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(7), dec!(11), dec!(0.4)],
+            vec![amount!(7), amount!(11), amount!(0.4)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
-        order.fills.filled_amount = dec!(6);
+        order.fills.filled_amount = amount!(6);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object
@@ -3083,15 +3116,15 @@ mod tests {
             .create_order(OrderSide::Sell, ReservationId::generate());
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
         ));
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
         ));
-        order.fills.filled_amount = dec!(6);
+        order.fills.filled_amount = amount!(6);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object
@@ -3103,7 +3136,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(7)
+            amount!(7)
         );
 
         assert_eq!(
@@ -3111,7 +3144,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(11)
+            amount!(11)
         );
 
         assert_eq!(
@@ -3119,7 +3152,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(0.4) - dec!(0.1) - dec!(0.1) - dec!(0.1) - dec!(0.1)
+            amount!(0.4) - amount!(0.1) - amount!(0.1) - amount!(0.1) - amount!(0.1)
         );
     }
 
@@ -3132,10 +3165,10 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(2), dec!(1)],
+            vec![amount!(0), amount!(2), amount!(1)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let mut order_snapshot = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
@@ -3173,7 +3206,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3181,7 +3214,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -3189,7 +3222,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3201,7 +3234,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3213,7 +3246,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(2)
+            amount!(2)
         );
 
         assert_eq!(
@@ -3225,7 +3258,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3238,7 +3271,7 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(11), dec!(1)],
+            vec![amount!(0), amount!(11), amount!(1)],
         );
 
         let mut order_snapshot = test_object
@@ -3277,7 +3310,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3285,7 +3318,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(11) - price * order_snapshot.amount()
+            amount!(11) - price * order_snapshot.amount()
         );
 
         assert_eq!(
@@ -3293,7 +3326,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3305,7 +3338,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         test_object
@@ -3325,7 +3358,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(11)
+            amount!(11)
         );
 
         assert_eq!(
@@ -3337,7 +3370,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3350,7 +3383,7 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(2), dec!(1)],
+            vec![amount!(0), amount!(2), amount!(1)],
         );
 
         let mut order_snapshot = test_object
@@ -3358,7 +3391,7 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         order_snapshot.fills.filled_amount = order_snapshot.amount() / dec!(2);
         order_snapshot.set_status(OrderStatus::Created, Utc::now());
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             order_snapshot.header.side,
@@ -3395,7 +3428,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3403,7 +3436,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -3411,7 +3444,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3423,7 +3456,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         test_object
@@ -3443,7 +3476,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(2) - price * dec!(5) + price * dec!(5)
+            amount!(2) - price * amount!(5) + price * amount!(5)
         );
 
         assert_eq!(
@@ -3455,7 +3488,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3468,10 +3501,10 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(2), dec!(1)],
+            vec![amount!(0), amount!(2), amount!(1)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
         let order = test_object
             .balance_manager_base
             .create_order(OrderSide::Buy, ReservationId::generate());
@@ -3503,7 +3536,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3511,7 +3544,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -3519,7 +3552,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3531,7 +3564,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         test_object
@@ -3551,7 +3584,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(2)
+            amount!(2)
         );
 
         assert_eq!(
@@ -3563,7 +3596,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3576,15 +3609,15 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(2), dec!(1)],
+            vec![amount!(0), amount!(2), amount!(1)],
         );
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(5),
+            amount!(5),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -3616,7 +3649,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3624,7 +3657,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -3632,7 +3665,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3644,7 +3677,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3656,7 +3689,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(2) - price * dec!(5)
+            amount!(2) - price * amount!(5)
         );
 
         assert_eq!(
@@ -3668,7 +3701,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3682,7 +3715,7 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(11), dec!(1)],
+            vec![amount!(0), amount!(11), amount!(1)],
         );
 
         let order = test_object
@@ -3722,7 +3755,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3730,7 +3763,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(11) - price * reservation_amount
+            amount!(11) - price * reservation_amount
         );
 
         assert_eq!(
@@ -3738,7 +3771,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3750,7 +3783,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3762,7 +3795,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(11) - price * order.amount()
+            amount!(11) - price * order.amount()
         );
 
         assert_eq!(
@@ -3774,7 +3807,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3788,7 +3821,7 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(11), dec!(1)],
+            vec![amount!(0), amount!(11), amount!(1)],
         );
 
         let order_1 = test_object
@@ -3841,7 +3874,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3849,7 +3882,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(11) - price * reservation_amount
+            amount!(11) - price * reservation_amount
         );
 
         assert_eq!(
@@ -3857,7 +3890,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3869,7 +3902,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3881,7 +3914,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(11) - price * (order_1.amount() + order_2.amount())
+            amount!(11) - price * (order_1.amount() + order_2.amount())
         );
 
         assert_eq!(
@@ -3893,7 +3926,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -3907,12 +3940,12 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(11), dec!(1)],
+            vec![amount!(0), amount!(11), amount!(1)],
         );
 
-        let order_1_amount = dec!(5);
+        let order_1_amount = amount!(5);
         let order_2_amount = dec!(1.3) * order_1_amount;
-        let price = dec!(1.5) * dec!(0.2);
+        let price = price!(1.5) * dec!(0.2);
         let reservation_amount = order_1_amount + order_2_amount;
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -3928,7 +3961,7 @@ mod tests {
 
         let mut order_snapshot_1 = test_object.balance_manager_base.create_order_by_amount(
             OrderSide::Buy,
-            dec!(0.2),
+            price!(0.2),
             order_1_amount,
             reservation_id,
         );
@@ -3966,7 +3999,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -3974,7 +4007,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(11) - price * reservation_amount
+            amount!(11) - price * reservation_amount
         );
 
         assert_eq!(
@@ -3982,7 +4015,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -3994,7 +4027,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -4006,7 +4039,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(11)
+            amount!(11)
         );
 
         assert_eq!(
@@ -4018,7 +4051,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
@@ -4032,12 +4065,12 @@ mod tests {
                 BalanceManagerBase::btc(),
                 BalanceManagerBase::bnb(),
             ],
-            vec![dec!(0), dec!(11), dec!(1)],
+            vec![amount!(0), amount!(11), amount!(1)],
         );
-        let order_1_amount = dec!(5);
+        let order_1_amount = amount!(5);
         let order_2_amount = dec!(1.3) * order_1_amount;
         let order_3_amount = dec!(1.2) * order_1_amount;
-        let price = dec!(1.5) * dec!(0.2);
+        let price = price!(1.5) * dec!(0.2);
         let reservation_amount = order_1_amount + order_2_amount + order_3_amount;
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
@@ -4105,7 +4138,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price)
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -4113,7 +4146,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(11) - price * reservation_amount
+            amount!(11) - price * reservation_amount
         );
 
         assert_eq!(
@@ -4121,7 +4154,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::bnb(), price)
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
 
         assert_eq!(
@@ -4133,7 +4166,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
 
         assert_eq!(
@@ -4145,7 +4178,7 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(11)
+            amount!(11)
         );
 
         assert_eq!(
@@ -4157,19 +4190,20 @@ mod tests {
                     price
                 )
                 .expect("in test"),
-            dec!(1)
+            amount!(1)
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unmatched_reserved_amount_and_order_amount_sum_sell() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::eth(), dec!(1000));
+        let test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::eth(), amount!(1000));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
-            dec!(0.2),
-            dec!(99),
+            price!(0.2),
+            amount!(99),
         );
 
         let reservation_id = test_object
@@ -4179,15 +4213,15 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(33))
+            .unreserve(reservation_id, amount!(33))
             .expect("in test");
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(33))
+            .unreserve(reservation_id, amount!(33))
             .expect("in test");
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(34))
+            .unreserve(reservation_id, amount!(34))
             .expect("in test");
 
         assert!(test_object
@@ -4199,19 +4233,20 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1000))
+            Some(amount!(1000))
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unmatched_reserved_amount_and_order_amount_sum_buy() {
         init_logger();
-        let test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(1000));
+        let test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(1000));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(99),
+            price!(0.2),
+            amount!(99),
         );
 
         let reservation_id = test_object
@@ -4221,15 +4256,15 @@ mod tests {
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(33))
+            .unreserve(reservation_id, amount!(33))
             .expect("in test");
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(33))
+            .unreserve(reservation_id, amount!(33))
             .expect("in test");
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(34))
+            .unreserve(reservation_id, amount!(34))
             .expect("in test");
 
         assert!(test_object
@@ -4241,7 +4276,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(1000))
+            Some(amount!(1000))
         );
     }
 
@@ -4250,21 +4285,21 @@ mod tests {
         init_logger();
         let test_object = create_test_obj_by_currency_code_with_limit(
             BalanceManagerBase::btc(),
-            dec!(1),
-            Some(dec!(2)),
+            amount!(1),
+            Some(amount!(2)),
         );
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(2),
+            price!(0.2),
+            amount!(2),
         );
 
         assert_eq!(
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(2) * dec!(0.2))
+            Some(amount!(2) * dec!(0.2))
         );
         assert!(test_object
             .balance_manager()
@@ -4275,7 +4310,7 @@ mod tests {
             test_object
                 .balance_manager()
                 .get_balance_by_reserve_parameters(&reserve_parameters),
-            Some(dec!(2) * dec!(0.2) - dec!(2) * dec!(0.2))
+            Some(amount!(2) * dec!(0.2) - amount!(2) * dec!(0.2))
         );
 
         assert!(!test_object
@@ -4286,11 +4321,11 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn can_reserve_fill_and_reservation_limits_enough_and_not_enough() {
         init_logger();
-        let limit = dec!(2);
-        let start_amount = dec!(1);
+        let limit = amount!(2);
+        let start_amount = amount!(1);
         let mut test_object = create_test_obj_by_currency_code_with_limit(
             BalanceManagerBase::btc(),
-            dec!(1),
+            amount!(1),
             Some(limit),
         );
         assert_eq!(
@@ -4300,7 +4335,7 @@ mod tests {
                 .amount_currency_code,
             BalanceManagerBase::eth()
         );
-        let buy_price = dec!(0.2);
+        let buy_price = price!(0.2);
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             buy_price,
@@ -4319,7 +4354,7 @@ mod tests {
             .try_reserve(&reserve_parameters, &mut None)
             .expect("in test");
 
-        let reservation_amount = buy_price;
+        let reservation_amount = start_amount * buy_price;
         let balance_after_reservation = balance_before_reservations - reservation_amount;
 
         assert_eq!(
@@ -4341,7 +4376,7 @@ mod tests {
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             fill_price,
             fill_amount,
-            fill_price,
+            fill_amount * fill_price,
         ));
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
         test_object
@@ -4375,7 +4410,7 @@ mod tests {
                 fill_price
             ),
             Some(
-                (dec!(0.7) / fill_price)
+                (amount!(0.7) / fill_price)
                     .min((limit - (reservation_amount + fill_amount)) * fill_price)
             )
         );
@@ -4388,7 +4423,8 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn restore_state_ctor() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(0));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(0));
         let (_, exchanges_by_id) = BalanceManagerOrdinal::create_balance_manager_ctor_parameters();
 
         let currency_pair_to_symbol_converter = CurrencyPairToSymbolConverter::new(exchanges_by_id);
@@ -4398,7 +4434,7 @@ mod tests {
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
 
         let mut balance_map: HashMap<CurrencyCode, Amount> = HashMap::new();
-        balance_map.insert(BalanceManagerBase::btc(), dec!(1));
+        balance_map.insert(BalanceManagerBase::btc(), amount!(1));
 
         BalanceManagerBase::update_balance(
             &mut balance_manager.lock(),
@@ -4406,12 +4442,12 @@ mod tests {
             balance_map,
         );
 
-        let price = dec!(1);
+        let price = price!(1);
 
         let reserve_parameters_1 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(0.08),
+            amount!(0.08),
         );
 
         let reservation_id_1 = balance_manager
@@ -4422,7 +4458,7 @@ mod tests {
         let reserve_parameters_2 = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(0.1),
+            amount!(0.1),
         );
         let reservation_id_2 = balance_manager
             .lock()
@@ -4465,21 +4501,22 @@ mod tests {
                         .create_balance_request(BalanceManagerBase::btc())
                 )
                 .expect("in test"),
-            dec!(0)
+            amount!(0)
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn update_exchange_balance_should_ignore_approved_reservations_for_canceled_orders() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(10));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(10));
 
-        let price = dec!(1);
+        let price = price!(1);
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
 
         let reservation_id = test_object
@@ -4494,7 +4531,7 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id,
             &order.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         assert_eq!(
@@ -4502,7 +4539,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(9)
+            amount!(9)
         );
 
         order.set_status(OrderStatus::Canceled, Utc::now());
@@ -4513,7 +4550,7 @@ mod tests {
             .order_was_finished(configuration_descriptor, &order);
 
         let mut balance_map: HashMap<CurrencyCode, Amount> = HashMap::new();
-        balance_map.insert(BalanceManagerBase::btc(), dec!(10));
+        balance_map.insert(BalanceManagerBase::btc(), amount!(10));
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
@@ -4526,7 +4563,7 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(9)
+            amount!(9)
         );
         test_object
             .balance_manager()
@@ -4538,19 +4575,19 @@ mod tests {
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price)
                 .expect("in test"),
-            dec!(10)
+            amount!(10)
         );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_should_reduce_not_approved_amount() {
         init_logger();
-        let test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4561,29 +4598,29 @@ mod tests {
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.amount, dec!(9));
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(9));
+        assert_eq!(reservation.amount, amount!(9));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(9));
 
         balance_manager
-            .unreserve(reservation_id, dec!(6))
+            .unreserve(reservation_id, amount!(6))
             .expect("in test");
 
         let reservation = balance_manager.get_reservation_expected(reservation_id);
 
-        assert_eq!(reservation.unreserved_amount, dec!(3));
-        assert_eq!(reservation.not_approved_amount, dec!(3));
+        assert_eq!(reservation.unreserved_amount, amount!(3));
+        assert_eq!(reservation.not_approved_amount, amount!(3));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_should_reduce_not_approved_amount_approved_order_single_unreserve() {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4603,8 +4640,8 @@ mod tests {
 
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
 
         balance_manager
             .unreserve_by_client_order_id(
@@ -4615,20 +4652,20 @@ mod tests {
             .expect("in test");
 
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(4));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(4));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_should_reduce_not_approved_amount_approved_order_unreserve_twice_by_half(
     ) {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4648,8 +4685,8 @@ mod tests {
 
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
 
         balance_manager
             .unreserve_by_client_order_id(
@@ -4660,8 +4697,8 @@ mod tests {
             .expect("in test");
 
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(6.5));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(6.5));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
 
         balance_manager
             .unreserve_by_client_order_id(
@@ -4672,8 +4709,8 @@ mod tests {
             .expect("in test");
 
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(4));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(4));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -4681,12 +4718,12 @@ mod tests {
     pub async fn unreserve_should_reduce_not_approved_amount_approved_order_unreserve_more_than_we_have(
     ) {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4706,14 +4743,14 @@ mod tests {
 
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
 
         balance_manager
             .unreserve_by_client_order_id(
                 reservation_id,
                 order.header.client_order_id.clone(),
-                order.amount() + dec!(1),
+                order.amount() + amount!(1),
             )
             .expect("in test");
     }
@@ -4721,12 +4758,12 @@ mod tests {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn unreserve_should_reduce_not_approved_amount_not_approved_order_single_unreserve() {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4740,20 +4777,20 @@ mod tests {
         order.set_status(OrderStatus::Created, Utc::now());
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(9));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(9));
 
         balance_manager
             .unreserve_by_client_order_id(
                 reservation_id,
                 order.header.client_order_id.clone(),
-                dec!(5),
+                amount!(5),
             )
             .expect("in test");
 
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(4));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(4));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -4761,12 +4798,12 @@ mod tests {
     pub async fn unreserve_should_reduce_not_approved_amount_not_approved_order_unreserve_more_than_we_have(
     ) {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
-            dec!(0.2),
-            dec!(9),
+            price!(0.2),
+            amount!(9),
         );
 
         let reservation_id = test_object
@@ -4786,11 +4823,11 @@ mod tests {
 
         let mut balance_manager = test_object.balance_manager();
         let reservation = balance_manager.get_reservation_expected(reservation_id);
-        assert_eq!(reservation.unreserved_amount, dec!(9));
-        assert_eq!(reservation.not_approved_amount, dec!(4));
+        assert_eq!(reservation.unreserved_amount, amount!(9));
+        assert_eq!(reservation.not_approved_amount, amount!(4));
 
         balance_manager
-            .unreserve(reservation_id, dec!(5))
+            .unreserve(reservation_id, amount!(5))
             .expect("in test");
     }
 
@@ -4798,13 +4835,14 @@ mod tests {
     #[ignore] // TODO: Should be fixed by https://github.com/CryptoDreamTeam/CryptoDreamTraderSharp/issues/802
     pub async fn order_was_finished_should_unreserve_related_reservations() {
         init_logger();
-        let mut test_object = create_test_obj_by_currency_code(BalanceManagerBase::btc(), dec!(10));
+        let mut test_object =
+            create_test_obj_by_currency_code(BalanceManagerBase::btc(), amount!(10));
 
-        let price = dec!(1);
+        let price = price!(1);
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -4817,20 +4855,20 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id,
             &order.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(9))
+            Some(amount!(9))
         );
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(1),
-            dec!(1),
+            amount!(1),
+            amount!(1),
         ));
         order.set_status(OrderStatus::Completed, Utc::now());
 
@@ -4843,18 +4881,18 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(8))
+            Some(amount!(8))
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price),
-            Some(dec!(1))
+            Some(amount!(1))
         );
 
         let mut balance_map: HashMap<CurrencyCode, Amount> = HashMap::new();
-        balance_map.insert(BalanceManagerBase::btc(), dec!(9));
-        balance_map.insert(BalanceManagerBase::eth(), dec!(1));
+        balance_map.insert(BalanceManagerBase::btc(), amount!(9));
+        balance_map.insert(BalanceManagerBase::eth(), amount!(1));
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
@@ -4866,7 +4904,7 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(8))
+            Some(amount!(8))
         );
 
         test_object
@@ -4877,7 +4915,7 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(9))
+            Some(amount!(9))
         );
     }
 
@@ -4885,13 +4923,13 @@ mod tests {
     #[ignore] // TODO: Should be fixed by https://github.com/CryptoDreamTeam/CryptoDreamTraderSharp/issues/802
     pub async fn order_was_filled_should_unreserve_related_reservations() {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
-        let price = dec!(1);
+        let price = price!(1);
         let reserve_parameters = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(1),
+            amount!(1),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -4904,20 +4942,20 @@ mod tests {
         test_object.balance_manager().approve_reservation(
             reservation_id,
             &order.header.client_order_id,
-            dec!(1),
+            amount!(1),
         );
 
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(9))
+            Some(amount!(9))
         );
 
         order.add_fill(BalanceManagerOrdinal::create_order_fill(
             price,
-            dec!(0.5),
-            dec!(1),
+            amount!(0.5),
+            amount!(1),
         ));
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
@@ -4926,8 +4964,8 @@ mod tests {
             .order_was_filled(configuration_descriptor, &order);
 
         let mut balance_map: HashMap<CurrencyCode, Amount> = HashMap::new();
-        balance_map.insert(BalanceManagerBase::btc(), dec!(9));
-        balance_map.insert(BalanceManagerBase::eth(), dec!(0.5));
+        balance_map.insert(BalanceManagerBase::btc(), amount!(9));
+        balance_map.insert(BalanceManagerBase::eth(), amount!(0.5));
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         BalanceManagerBase::update_balance(
             &mut test_object.balance_manager(),
@@ -4939,12 +4977,12 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(9))
+            Some(amount!(9))
         );
 
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(0.5))
+            .unreserve(reservation_id, amount!(0.5))
             .expect("in test");
         order.set_status(OrderStatus::Canceled, Utc::now());
         test_object
@@ -4956,13 +4994,13 @@ mod tests {
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::btc(), price),
-            Some(dec!(9.5))
+            Some(amount!(9.5))
         );
         assert_eq!(
             test_object
                 .balance_manager_base
                 .get_balance_by_currency_code(BalanceManagerBase::eth(), price),
-            Some(dec!(0.5))
+            Some(amount!(0.5))
         );
     }
 
@@ -4976,7 +5014,7 @@ mod tests {
     pub async fn try_reserve_with_limit_for_borderline_case() {
         // preparing
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(100), dec!(100));
+        let mut test_object = create_eth_btc_test_obj(amount!(100), amount!(100));
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
         let trade_place = MarketAccountId::new(
@@ -4989,8 +5027,8 @@ mod tests {
             .get_last_position_change_before_period(&trade_place, test_object.now)
             .is_none());
 
-        let price = dec!(0.2);
-        let limit = dec!(10);
+        let price = price!(0.2);
+        let limit = amount!(10);
 
         let configuration_descriptor = test_object.balance_manager_base.configuration_descriptor;
 
@@ -5005,7 +5043,7 @@ mod tests {
         let reserve_parameters_sell = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(10),
+            amount!(10),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -5017,23 +5055,23 @@ mod tests {
             .create_order(OrderSide::Sell, ReservationId::generate());
         sell.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(10),
-            dec!(2.5),
+            amount!(10),
+            amount!(2.5),
             test_object.now,
         ));
 
         order_was_filled(&mut test_object, &mut sell);
-        check_position(&test_object, dec!(-10));
+        check_position(&test_object, amount!(-10));
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(20))
+            .unreserve(reservation_id, amount!(20))
             .expect("in test");
 
         // 2
         let reserve_parameters_buy = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Buy,
             price,
-            dec!(20),
+            amount!(20),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -5045,16 +5083,16 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         buy.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(20),
-            dec!(2.5),
+            amount!(20),
+            amount!(2.5),
             test_object.now,
         ));
 
         order_was_filled(&mut test_object, &mut buy);
-        check_position(&test_object, dec!(10));
+        check_position(&test_object, amount!(10));
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(20))
+            .unreserve(reservation_id, amount!(20))
             .expect("in test");
 
         // 3
@@ -5063,15 +5101,15 @@ mod tests {
             .create_order(OrderSide::Sell, ReservationId::generate());
         sell.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(20),
-            dec!(2.5),
+            amount!(20),
+            amount!(2.5),
             test_object.now,
         ));
 
         let reserve_parameters_sell = test_object.balance_manager_base.create_reserve_parameters(
             OrderSide::Sell,
             price,
-            dec!(20),
+            amount!(20),
         );
         let reservation_id = test_object
             .balance_manager()
@@ -5079,17 +5117,17 @@ mod tests {
             .expect("should reserve full amount");
 
         order_was_filled(&mut test_object, &mut sell);
-        check_position(&test_object, dec!(-10));
+        check_position(&test_object, amount!(-10));
         test_object
             .balance_manager()
-            .unreserve(reservation_id, dec!(20))
+            .unreserve(reservation_id, amount!(20))
             .expect("in test");
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     pub async fn get_last_position_change_before_period_base_cases() {
         init_logger();
-        let mut test_object = create_eth_btc_test_obj(dec!(10), dec!(0));
+        let mut test_object = create_eth_btc_test_obj(amount!(10), amount!(0));
 
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
 
@@ -5103,15 +5141,15 @@ mod tests {
             .get_last_position_change_before_period(&market_account_id, test_object.now)
             .is_none());
 
-        let price = dec!(0.2);
+        let price = price!(0.2);
 
         let mut sell_5 = test_object
             .balance_manager_base
             .create_order(OrderSide::Sell, ReservationId::generate());
         sell_5.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(5),
-            dec!(2.5),
+            amount!(5),
+            amount!(2.5),
             test_object.now,
         ));
 
@@ -5120,8 +5158,8 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         buy_1.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(1),
-            dec!(2.5),
+            amount!(1),
+            amount!(2.5),
             test_object.now,
         ));
 
@@ -5130,8 +5168,8 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         buy_2.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(2),
-            dec!(2.5),
+            amount!(2),
+            amount!(2.5),
             test_object.now,
         ));
 
@@ -5140,8 +5178,8 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         buy_4.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(4),
-            dec!(2.5),
+            amount!(4),
+            amount!(2.5),
             test_object.now,
         ));
 
@@ -5150,14 +5188,14 @@ mod tests {
             .create_order(OrderSide::Buy, ReservationId::generate());
         buy_0.add_fill(BalanceManagerOrdinal::create_order_fill_with_time(
             price,
-            dec!(0),
-            dec!(2.5),
+            amount!(0),
+            amount!(2.5),
             test_object.now,
         ));
 
         let order_fill_id_1 = order_was_filled(&mut test_object, &mut sell_5);
         test_object.check_time(0);
-        check_position(&test_object, dec!(-5));
+        check_position(&test_object, amount!(-5));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5168,7 +5206,7 @@ mod tests {
         test_object.timer_add_second();
         let _order_fill_id_2 = order_was_filled(&mut test_object, &mut buy_4);
         test_object.check_time(1);
-        check_position(&test_object, dec!(-1));
+        check_position(&test_object, amount!(-1));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5180,7 +5218,7 @@ mod tests {
         test_object.timer_add_second();
         let order_fill_id_3 = order_was_filled(&mut test_object, &mut buy_4);
         test_object.check_time(2);
-        check_position(&test_object, dec!(3));
+        check_position(&test_object, amount!(3));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5201,7 +5239,7 @@ mod tests {
         test_object.timer_add_second();
         let _order_fill_id_4 = order_was_filled(&mut test_object, &mut buy_4);
         test_object.check_time(3);
-        check_position(&test_object, dec!(7));
+        check_position(&test_object, amount!(7));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5222,7 +5260,7 @@ mod tests {
         test_object.timer_add_second();
         let _order_fill_id_5 = order_was_filled(&mut test_object, &mut sell_5);
         test_object.check_time(4);
-        check_position(&test_object, dec!(2));
+        check_position(&test_object, amount!(2));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5243,7 +5281,7 @@ mod tests {
         test_object.timer_add_second();
         let order_fill_id_6 = order_was_filled(&mut test_object, &mut sell_5);
         test_object.check_time(5);
-        check_position(&test_object, dec!(-3));
+        check_position(&test_object, amount!(-3));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5266,7 +5304,7 @@ mod tests {
         let order_fill_id_8 = order_was_filled(&mut test_object, &mut buy_2);
 
         test_object.check_time(6);
-        check_position(&test_object, dec!(0));
+        check_position(&test_object, amount!(0));
         assert_eq!(
             test_object
                 .balance_manager()
@@ -5308,7 +5346,7 @@ mod tests {
         order_fill_id
     }
 
-    fn check_position(test_object: &BalanceManagerOrdinal, position: Decimal) {
+    fn check_position(test_object: &BalanceManagerOrdinal, position: Amount) {
         let exchange_account_id = test_object.balance_manager_base.exchange_account_id_1;
 
         let currency_pair = test_object.balance_manager_base.symbol().currency_pair();
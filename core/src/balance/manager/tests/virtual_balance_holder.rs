@@ -89,9 +89,9 @@ impl VirtualBalanceHolderTests {
 mod tests {
     use std::collections::HashMap;
 
+    use mmb_domain::amount;
+    use mmb_domain::order::snapshot::Amount;
     use mmb_utils::{hashmap, logger::init_logger};
-    use rust_decimal::Decimal;
-    use rust_decimal_macros::dec;
 
     use crate::balance::manager::balance_request::BalanceRequest;
 
@@ -100,8 +100,8 @@ mod tests {
     fn add_balance_and_check(
         test_obj: &mut VirtualBalanceHolderTests,
         balance_request: &BalanceRequest,
-        balance_to_add: Decimal,
-        expect_balance: Option<Decimal>,
+        balance_to_add: Amount,
+        expect_balance: Option<Amount>,
     ) {
         test_obj
             .virtual_balance_holder
@@ -124,15 +124,30 @@ mod tests {
 
         let exchange_account_id = test_obj.exchange_account_id;
         let mut balances_by_currency_code = HashMap::new();
-        balances_by_currency_code.insert(VirtualBalanceHolderTests::btc(), dec!(0));
+        balances_by_currency_code.insert(VirtualBalanceHolderTests::btc(), amount!(0));
         test_obj
             .virtual_balance_holder
             .update_balances(exchange_account_id, &balances_by_currency_code);
 
         let balance_request = test_obj.create_balance_request(VirtualBalanceHolderTests::btc());
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(0), Some(dec!(0)));
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(10), Some(dec!(10)));
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(10), Some(dec!(20)));
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(0),
+            Some(amount!(0)),
+        );
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(10),
+            Some(amount!(10)),
+        );
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(10),
+            Some(amount!(20)),
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -142,17 +157,27 @@ mod tests {
 
         let exchange_account_id = test_obj.exchange_account_id;
         let mut balances_by_currency_code = HashMap::new();
-        balances_by_currency_code.insert(VirtualBalanceHolderTests::btc(), dec!(0));
-        balances_by_currency_code.insert(VirtualBalanceHolderTests::eth(), dec!(0));
+        balances_by_currency_code.insert(VirtualBalanceHolderTests::btc(), amount!(0));
+        balances_by_currency_code.insert(VirtualBalanceHolderTests::eth(), amount!(0));
         test_obj
             .virtual_balance_holder
             .update_balances(exchange_account_id, &balances_by_currency_code);
 
         let balance_request = test_obj.create_balance_request(VirtualBalanceHolderTests::btc());
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(10), Some(dec!(10)));
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(10),
+            Some(amount!(10)),
+        );
 
         let balance_request = test_obj.create_balance_request(VirtualBalanceHolderTests::eth());
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(10), Some(dec!(10)));
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(10),
+            Some(amount!(10)),
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -163,18 +188,23 @@ mod tests {
         let exchange_account_id = test_obj.exchange_account_id;
         let btc = VirtualBalanceHolderTests::btc();
 
-        let balances_by_currency_code = hashmap![btc => dec!(50)];
+        let balances_by_currency_code = hashmap![btc => amount!(50)];
         test_obj
             .virtual_balance_holder
             .update_balances(exchange_account_id, &balances_by_currency_code);
 
         let balance_request = test_obj.create_balance_request(btc);
-        add_balance_and_check(&mut test_obj, &balance_request, dec!(-40), Some(dec!(10)));
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request,
+            amount!(-40),
+            Some(amount!(10)),
+        );
 
         let balance_diffs = test_obj.virtual_balance_holder.get_virtual_balance_diffs();
         assert_eq!(
             balance_diffs.get_by_balance_request(&balance_request),
-            Some(dec!(-40))
+            Some(amount!(-40))
         );
 
         assert_eq!(
@@ -184,7 +214,7 @@ mod tests {
                 btc,
                 None
             ),
-            Some(dec!(50))
+            Some(amount!(50))
         );
     }
 
@@ -197,15 +227,20 @@ mod tests {
 
         let exchange_account_id = test_obj.exchange_account_id;
         let mut balances_by_currency_code = HashMap::new();
-        balances_by_currency_code.insert(VirtualBalanceHolderTests::eth(), dec!(50));
+        balances_by_currency_code.insert(VirtualBalanceHolderTests::eth(), amount!(50));
         test_obj
             .virtual_balance_holder
             .update_balances(exchange_account_id, &balances_by_currency_code);
 
         let balance_request_btc = test_obj.create_balance_request(VirtualBalanceHolderTests::btc());
         let balance_request_eth = test_obj.create_balance_request(VirtualBalanceHolderTests::eth());
-        add_balance_and_check(&mut test_obj, &balance_request_eth, dec!(0), Some(dec!(50)));
-        add_balance_and_check(&mut test_obj, &balance_request_btc, dec!(0), None);
+        add_balance_and_check(
+            &mut test_obj,
+            &balance_request_eth,
+            amount!(0),
+            Some(amount!(50)),
+        );
+        add_balance_and_check(&mut test_obj, &balance_request_btc, amount!(0), None);
 
         // TODO: fix me
         // _virtualBalanceHolder.SetMarkPrice(ExchangeName, CurrencyCodePair, 2.5m);
@@ -4,4 +4,6 @@ pub(crate) mod balance_reservation_preset;
 pub(crate) mod balance_reservation_storage;
 pub(crate) mod changes;
 pub mod manager;
+pub mod position_cost;
+pub mod valuation;
 pub(crate) mod virtual_balance_holder;
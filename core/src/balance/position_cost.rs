@@ -0,0 +1,167 @@
+use crate::math::{DecimalComputation, RoundForComputation};
+use mmb_domain::order::snapshot::{Amount, OrderSide, Price};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+/// Fee-inclusive cost of open position of currency pair accumulated from fills.
+/// Fees of the closed part of position are realized, so only fees of the open part are kept
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PositionCost {
+    /// Positive for long position and negative for short one
+    pub amount: Amount,
+    /// Notional of open position by entry prices in quote currency
+    pub entry_notional: Amount,
+    /// Fees paid for entry of open position in quote currency
+    pub paid_fees: Amount,
+}
+
+impl PositionCost {
+    pub fn apply_fill(&mut self, side: OrderSide, price: Price, amount: Amount, fee: Amount) {
+        if amount.is_zero() {
+            return;
+        }
+
+        let direction = match side {
+            OrderSide::Buy => dec!(1),
+            OrderSide::Sell => dec!(-1),
+        };
+
+        let is_increasing =
+            self.amount.is_zero() || self.amount.is_sign_positive() == (direction > dec!(0));
+        if is_increasing {
+            self.add_entry(direction, price, amount, fee);
+            return;
+        }
+
+        let closed_amount = amount.min(self.amount.abs());
+        let remaining_ratio = dec!(1) - closed_amount / self.amount.abs();
+        self.entry_notional *= remaining_ratio;
+        self.paid_fees *= remaining_ratio;
+        self.amount += direction * closed_amount;
+
+        // position is flipped, so the rest of fill opens position of opposite direction
+        let opening_amount = amount - closed_amount;
+        if !opening_amount.is_zero() {
+            self.add_entry(
+                direction,
+                price,
+                opening_amount,
+                fee * opening_amount.value() / amount.value(),
+            );
+        }
+    }
+
+    fn add_entry(&mut self, direction: Decimal, price: Price, amount: Amount, fee: Amount) {
+        self.amount += direction * amount;
+        self.entry_notional += price * amount;
+        self.paid_fees += fee;
+    }
+
+    /// Exit price at which closing position returns paid fees and exit fees, so trade has
+    /// zero profit. Exit fee rate is fraction of exit notional (not percents).
+    /// `None` if there is no open position
+    pub fn breakeven_price(&self, exit_fee_rate: Decimal) -> Option<Price> {
+        if self.amount.is_zero() {
+            return None;
+        }
+
+        let amount = self.amount.abs();
+        let price = match self.amount.is_sign_positive() {
+            // sell proceeds after exit fee cover entry notional and paid fees
+            true => (self.entry_notional + self.paid_fees) / (amount * (dec!(1) - exit_fee_rate)),
+            // buy cost with exit fee is covered by entry proceeds after paid fees
+            false => (self.entry_notional - self.paid_fees) / (amount * (dec!(1) + exit_fee_rate)),
+        };
+        let price = Price::new(price);
+
+        Some(price.round_for(DecimalComputation::ProfitLoss))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    fn position(fills: &[(OrderSide, Price, Amount, Amount)]) -> PositionCost {
+        let mut position = PositionCost::default();
+        for &(side, price, amount, fee) in fills {
+            position.apply_fill(side, price, amount, fee);
+        }
+        position
+    }
+
+    #[test]
+    fn breakeven_of_long_position_from_several_fills() {
+        let position = position(&[
+            (OrderSide::Buy, price!(100), amount!(1), amount!(0.1)),
+            (OrderSide::Buy, price!(110), amount!(2), amount!(0.22)),
+            (OrderSide::Buy, price!(95), amount!(1), amount!(0.095)),
+        ]);
+
+        assert_eq!(position.amount, amount!(4));
+        assert_eq!(position.entry_notional, amount!(415));
+        assert_eq!(position.paid_fees, amount!(0.415));
+        // (415 + 0.415) / (4 * 0.999)
+        assert_eq!(
+            position.breakeven_price(dec!(0.001)),
+            Some(price!(103.957707707707707708))
+        );
+    }
+
+    #[test]
+    fn breakeven_of_short_position_from_several_fills() {
+        let position = position(&[
+            (OrderSide::Sell, price!(200), amount!(1), amount!(0.2)),
+            (OrderSide::Sell, price!(190), amount!(1), amount!(0.19)),
+        ]);
+
+        assert_eq!(position.amount, amount!(-2));
+        // (390 - 0.39) / (2 * 1.001)
+        assert_eq!(
+            position.breakeven_price(dec!(0.001)),
+            Some(price!(194.610389610389610390))
+        );
+    }
+
+    #[test]
+    fn keep_fees_of_open_part_of_position_after_partial_close() {
+        let position = position(&[
+            (OrderSide::Buy, price!(100), amount!(2), amount!(0.4)),
+            (OrderSide::Buy, price!(120), amount!(2), amount!(0.4)),
+            (OrderSide::Sell, price!(130), amount!(1), amount!(0.13)),
+        ]);
+
+        assert_eq!(position.amount, amount!(3));
+        assert_eq!(position.entry_notional, amount!(330));
+        assert_eq!(position.paid_fees, amount!(0.6));
+        assert_eq!(position.breakeven_price(dec!(0)), Some(price!(110.2)));
+    }
+
+    #[test]
+    fn flip_position_by_fill_larger_than_position() {
+        let position = position(&[
+            (OrderSide::Buy, price!(100), amount!(1), amount!(0.1)),
+            (OrderSide::Sell, price!(105), amount!(3), amount!(0.3)),
+        ]);
+
+        assert_eq!(position.amount, amount!(-2));
+        assert_eq!(position.entry_notional, amount!(210));
+        assert_eq!(position.paid_fees, amount!(0.2));
+        assert_eq!(position.breakeven_price(dec!(0)), Some(price!(104.9)));
+    }
+
+    #[rstest]
+    #[case::no_fills(&[])]
+    #[case::closed(&[
+        (OrderSide::Buy, price!(100), amount!(1), amount!(0.1)),
+        (OrderSide::Sell, price!(110), amount!(1), amount!(0.11)),
+    ])]
+    fn no_breakeven_without_position(#[case] fills: &[(OrderSide, Price, Amount, Amount)]) {
+        let position = position(fills);
+
+        assert_eq!(position.breakeven_price(dec!(0.001)), None);
+        assert_eq!(position.paid_fees, amount!(0));
+    }
+}
@@ -0,0 +1,509 @@
+use anyhow::{bail, Error, Result};
+use itertools::Itertools;
+use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeAccountId, MarketId};
+use mmb_domain::order::snapshot::{Amount, OrderSide, Price};
+use mmb_domain::order_book::local_order_book_snapshot::LocalOrderBookSnapshot;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::exchanges::general::exchange::Exchange;
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+
+/// Price which balances are valued by
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValuationMode {
+    /// Price of the last public trade
+    #[default]
+    LastTrade,
+    /// Middle price between top ask and top bid
+    Mid,
+    /// Average price of liquidating the held amount by walking the opposite side of order book.
+    /// Middle price is used if order book is too thin to absorb the held amount
+    BookWeighted,
+}
+
+impl FromStr for ValuationMode {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "last_trade" => Ok(ValuationMode::LastTrade),
+            "mid" => Ok(ValuationMode::Mid),
+            "book_weighted" => Ok(ValuationMode::BookWeighted),
+            _ => bail!(
+                "Unknown valuation mode '{value}'. Expected one of: last_trade, mid, book_weighted"
+            ),
+        }
+    }
+}
+
+/// Kind of price which balance was actually valued by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriceSource {
+    LastTrade,
+    Mid,
+    BookWeighted,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceValuation {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_code: CurrencyCode,
+    pub amount: Amount,
+    /// Currency which balance is valued in
+    pub quote_currency_code: CurrencyCode,
+    /// Currency pairs which balance is valued by: a single pair for direct price or two pairs
+    /// for price bridged through an intermediate currency. Empty if balance is in quote currency
+    /// or there is no price
+    pub currency_pairs: Vec<CurrencyPair>,
+    pub price: Option<Price>,
+    pub price_source: Option<PriceSource>,
+    pub value: Option<Amount>,
+}
+
+impl BalanceValuation {
+    /// Value balance of currency in quote currency by direct or bridged price (see [`price_in_currency`]).
+    /// Price fields are empty if there is no such price
+    pub fn new(
+        exchange: &Exchange,
+        currency_code: CurrencyCode,
+        amount: Amount,
+        quote_currency_code: CurrencyCode,
+        mode: ValuationMode,
+        local_snapshots_service: &LocalSnapshotsService,
+    ) -> Self {
+        let routed_price = price_in_currency(
+            exchange,
+            currency_code,
+            amount,
+            quote_currency_code,
+            mode,
+            local_snapshots_service,
+        );
+
+        BalanceValuation {
+            exchange_account_id: exchange.exchange_account_id,
+            currency_code,
+            amount,
+            quote_currency_code,
+            value: routed_price.as_ref().map(|x| amount * x.price),
+            price: routed_price.as_ref().map(|x| x.price),
+            price_source: routed_price.as_ref().and_then(|x| x.price_source),
+            currency_pairs: routed_price.map(|x| x.currency_pairs).unwrap_or_default(),
+        }
+    }
+}
+
+/// Trading PnL of exchange account since its balances were received the first time: change of
+/// balances valued in quote currency at current prices, so price moves of initially held
+/// currencies aren't counted as PnL. Unrealized PnL of derivative positions isn't included
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfitLoss {
+    pub exchange_account_id: ExchangeAccountId,
+    pub quote_currency_code: CurrencyCode,
+    /// `None` if change of some balance can't be valued in quote currency
+    pub pnl: Option<Amount>,
+}
+
+impl ProfitLoss {
+    pub fn new(
+        exchange: &Exchange,
+        initial_balances: &HashMap<CurrencyCode, Amount>,
+        balances: &HashMap<CurrencyCode, Amount>,
+        quote_currency_code: CurrencyCode,
+        mode: ValuationMode,
+        local_snapshots_service: &LocalSnapshotsService,
+    ) -> Self {
+        let pnl = balance_changes(initial_balances, balances)
+            .into_iter()
+            .map(|(currency_code, amount)| {
+                value_in_currency(
+                    exchange,
+                    currency_code,
+                    amount,
+                    quote_currency_code,
+                    mode,
+                    local_snapshots_service,
+                )
+            })
+            .sum();
+
+        ProfitLoss {
+            exchange_account_id: exchange.exchange_account_id,
+            quote_currency_code,
+            pnl,
+        }
+    }
+}
+
+/// Nonzero changes of balances by currency
+fn balance_changes(
+    initial_balances: &HashMap<CurrencyCode, Amount>,
+    balances: &HashMap<CurrencyCode, Amount>,
+) -> HashMap<CurrencyCode, Amount> {
+    let mut changes = balances.clone();
+    for (currency_code, amount) in initial_balances {
+        *changes.entry(*currency_code).or_default() -= *amount;
+    }
+    changes.retain(|_, amount| !amount.is_zero());
+    changes
+}
+
+/// Price of currency in quote currency with currency pairs it's derived from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoutedPrice {
+    pub currency_pairs: Vec<CurrencyPair>,
+    pub price: Price,
+    /// `None` if currency is the quote currency itself
+    pub price_source: Option<PriceSource>,
+}
+
+/// Price of `amount` of currency in quote currency on exchange.
+/// Currency pair of both currencies (in either direction) is used if exchange has it, otherwise
+/// price is bridged through an intermediate currency which has currency pairs with both of them.
+/// Bridge currencies are tried in alphabetical order, so the same route is chosen every time.
+/// Returns `None` if there is neither direct nor bridged price
+pub fn price_in_currency(
+    exchange: &Exchange,
+    currency_code: CurrencyCode,
+    amount: Amount,
+    quote_currency_code: CurrencyCode,
+    mode: ValuationMode,
+    local_snapshots_service: &LocalSnapshotsService,
+) -> Option<RoutedPrice> {
+    let currency_pairs = exchange
+        .symbols
+        .iter()
+        .map(|x| (*x.key(), x.base_currency_code, x.quote_currency_code))
+        .collect_vec();
+
+    route_price(
+        currency_code,
+        quote_currency_code,
+        amount,
+        &currency_pairs,
+        |currency_pair, amount| {
+            let market_id = MarketId::new(exchange.exchange_account_id.exchange_id, currency_pair);
+            valuation_price(
+                mode,
+                amount,
+                local_snapshots_service.get_snapshot(market_id),
+                exchange.last_trade_price(currency_pair),
+            )
+        },
+    )
+}
+
+/// Value of `amount` of currency in target currency by direct or bridged price (see [`price_in_currency`]).
+/// Returns `None` if there is no such price
+pub fn value_in_currency(
+    exchange: &Exchange,
+    currency_code: CurrencyCode,
+    amount: Amount,
+    target_currency_code: CurrencyCode,
+    mode: ValuationMode,
+    local_snapshots_service: &LocalSnapshotsService,
+) -> Option<Amount> {
+    if amount.is_zero() {
+        return Some(amount);
+    }
+
+    price_in_currency(
+        exchange,
+        currency_code,
+        amount,
+        target_currency_code,
+        mode,
+        local_snapshots_service,
+    )
+    .map(|x| amount * x.price)
+}
+
+/// Route price of currency to quote currency through `currency_pairs` given as
+/// `(currency_pair, base, quote)`. `pair_price` is price of currency pair to value specified
+/// amount of its base currency by
+fn route_price(
+    currency_code: CurrencyCode,
+    quote_currency_code: CurrencyCode,
+    amount: Amount,
+    currency_pairs: &[(CurrencyPair, CurrencyCode, CurrencyCode)],
+    pair_price: impl Fn(CurrencyPair, Amount) -> Option<(Price, PriceSource)>,
+) -> Option<RoutedPrice> {
+    if currency_code == quote_currency_code {
+        return Some(RoutedPrice {
+            currency_pairs: vec![],
+            price: Price::ONE,
+            price_source: None,
+        });
+    }
+
+    let leg_price = |from: CurrencyCode, to: CurrencyCode, amount: Amount| {
+        let &(currency_pair, base, _) = currency_pairs.iter().find(|(_, base, quote)| {
+            (*base == from && *quote == to) || (*base == to && *quote == from)
+        })?;
+
+        if base == from {
+            let (price, price_source) = pair_price(currency_pair, amount)?;
+            return Some((currency_pair, price, price_source));
+        }
+
+        // quote currency of the pair is liquidated by buying its base currency, so amount in base
+        // currency is negative. It's estimated by price for the whole amount first to walk
+        // order book by the right amount for book weighted price
+        let (estimated_price, _) = pair_price(currency_pair, -amount)?;
+        if estimated_price.is_zero() {
+            return None;
+        }
+        let (price, price_source) = pair_price(currency_pair, -amount / estimated_price)?;
+        if price.is_zero() {
+            return None;
+        }
+
+        Some((
+            currency_pair,
+            Price::new(Decimal::ONE / price.value()),
+            price_source,
+        ))
+    };
+
+    if let Some((currency_pair, price, price_source)) =
+        leg_price(currency_code, quote_currency_code, amount)
+    {
+        return Some(RoutedPrice {
+            currency_pairs: vec![currency_pair],
+            price,
+            price_source: Some(price_source),
+        });
+    }
+
+    currency_pairs
+        .iter()
+        .filter_map(|&(_, base, quote)| match (base, quote) {
+            (base, bridge) | (bridge, base) if base == currency_code => Some(bridge),
+            _ => None,
+        })
+        .filter(|&bridge| bridge != quote_currency_code)
+        .sorted_by_key(|x| x.as_str().to_owned())
+        .dedup()
+        .find_map(|bridge| {
+            let (first_pair, first_price, first_source) = leg_price(currency_code, bridge, amount)?;
+            let (second_pair, second_price, second_source) =
+                leg_price(bridge, quote_currency_code, amount * first_price)?;
+
+            // bridged price is book weighted only if prices of both legs are,
+            // otherwise one of them fell back to middle price
+            let price_source = match first_source == second_source {
+                true => first_source,
+                false => PriceSource::Mid,
+            };
+
+            Some(RoutedPrice {
+                currency_pairs: vec![first_pair, second_pair],
+                price: first_price * second_price.value(),
+                price_source: Some(price_source),
+            })
+        })
+}
+
+/// Price to value `amount` of base currency by. Long (positive) amount is liquidated by selling,
+/// so bids are walked for book weighted price, and asks are walked for short (negative) one
+pub fn valuation_price(
+    mode: ValuationMode,
+    amount: Amount,
+    snapshot: Option<&LocalOrderBookSnapshot>,
+    last_trade_price: Option<Price>,
+) -> Option<(Price, PriceSource)> {
+    let middle_price = || {
+        let prices = snapshot?.get_top_prices();
+        Some((prices.top_ask? + prices.top_bid?) * dec!(0.5))
+    };
+    let from_middle_price = || middle_price().map(|price| (price, PriceSource::Mid));
+
+    match mode {
+        ValuationMode::LastTrade => last_trade_price.map(|price| (price, PriceSource::LastTrade)),
+        ValuationMode::Mid => from_middle_price(),
+        ValuationMode::BookWeighted => {
+            let side = match amount.is_sign_negative() {
+                true => OrderSide::Buy,
+                false => OrderSide::Sell,
+            };
+
+            snapshot
+                .and_then(|x| x.price_to_fill_amount(side, amount.abs()))
+                .map(|price| (price, PriceSource::BookWeighted))
+                .or_else(from_middle_price)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    fn snapshot() -> LocalOrderBookSnapshot {
+        let asks = [(price!(101), amount!(1)), (price!(105), amount!(1))];
+        let bids = [(price!(99), amount!(1)), (price!(95), amount!(1))];
+
+        LocalOrderBookSnapshot::new(
+            asks.into_iter().collect(),
+            bids.into_iter().collect(),
+            Utc::now(),
+        )
+    }
+
+    #[rstest]
+    #[case(ValuationMode::LastTrade, amount!(1), Some((price!(120), PriceSource::LastTrade)))]
+    #[case(ValuationMode::Mid, amount!(1), Some((price!(100), PriceSource::Mid)))]
+    #[case(ValuationMode::BookWeighted, amount!(2), Some((price!(97), PriceSource::BookWeighted)))]
+    #[case(ValuationMode::BookWeighted, amount!(-2), Some((price!(103), PriceSource::BookWeighted)))]
+    #[case(ValuationMode::BookWeighted, amount!(3), Some((price!(100), PriceSource::Mid)))]
+    fn valuation_price_by_mode(
+        #[case] mode: ValuationMode,
+        #[case] amount: Amount,
+        #[case] expected: Option<(Price, PriceSource)>,
+    ) {
+        assert_eq!(
+            valuation_price(mode, amount, Some(&snapshot()), Some(price!(120))),
+            expected
+        );
+    }
+
+    #[test]
+    fn no_valuation_price_without_order_book() {
+        assert_eq!(
+            valuation_price(
+                ValuationMode::BookWeighted,
+                amount!(1),
+                None,
+                Some(price!(120))
+            ),
+            None
+        );
+    }
+
+    fn route(
+        currency_code: &str,
+        quote_currency_code: &str,
+        prices: &[(&str, &str, Price)],
+    ) -> Option<RoutedPrice> {
+        let currency_pairs = prices
+            .iter()
+            .map(|&(base, quote, _)| {
+                let (base, quote) = (CurrencyCode::from(base), CurrencyCode::from(quote));
+                (CurrencyPair::from_codes(base, quote), base, quote)
+            })
+            .collect_vec();
+        let pair_price = |currency_pair: CurrencyPair, _: Amount| {
+            currency_pairs
+                .iter()
+                .zip(prices)
+                .find(|((x, _, _), _)| *x == currency_pair)
+                .map(|(_, &(_, _, price))| (price, PriceSource::LastTrade))
+        };
+
+        route_price(
+            currency_code.into(),
+            quote_currency_code.into(),
+            amount!(1),
+            &currency_pairs,
+            pair_price,
+        )
+    }
+
+    fn currency_pairs(currency_pairs: &[(&str, &str)]) -> Vec<CurrencyPair> {
+        currency_pairs
+            .iter()
+            .map(|&(base, quote)| CurrencyPair::from_codes(base.into(), quote.into()))
+            .collect()
+    }
+
+    #[test]
+    fn nonzero_balance_changes() {
+        let (btc, eth, usdt) = ("btc".into(), "eth".into(), "usdt".into());
+        let initial_balances = HashMap::from([(btc, amount!(2)), (eth, amount!(1))]);
+        let balances = HashMap::from([(btc, amount!(1)), (eth, amount!(1)), (usdt, amount!(20))]);
+
+        assert_eq!(
+            balance_changes(&initial_balances, &balances),
+            HashMap::from([(btc, amount!(-1)), (usdt, amount!(20))])
+        );
+    }
+
+    #[test]
+    fn route_price_by_pair_with_requested_quote() {
+        let prices = [
+            ("btc", "eur", price!(19000)),
+            ("btc", "usdt", price!(20000)),
+        ];
+
+        let routed_price = route("btc", "usdt", &prices).expect("in test");
+
+        assert_eq!(routed_price.price, price!(20000));
+        assert_eq!(
+            routed_price.currency_pairs,
+            currency_pairs(&[("btc", "usdt")])
+        );
+    }
+
+    #[test]
+    fn route_price_by_inverse_pair() {
+        let routed_price =
+            route("usdt", "btc", &[("btc", "usdt", price!(20000))]).expect("in test");
+
+        assert_eq!(routed_price.price, price!(0.00005));
+        assert_eq!(
+            routed_price.currency_pairs,
+            currency_pairs(&[("btc", "usdt")])
+        );
+    }
+
+    #[test]
+    fn route_price_through_bridge_currency() {
+        let prices = [
+            ("eth", "btc", price!(0.05)),
+            ("btc", "usdt", price!(20000)),
+            ("xrp", "usdt", price!(0.5)),
+        ];
+
+        let routed_price = route("eth", "usdt", &prices).expect("in test");
+
+        assert_eq!(routed_price.price, price!(1000));
+        assert_eq!(routed_price.price_source, Some(PriceSource::LastTrade));
+        assert_eq!(
+            routed_price.currency_pairs,
+            currency_pairs(&[("eth", "btc"), ("btc", "usdt")])
+        );
+    }
+
+    #[test]
+    fn route_price_of_quote_currency() {
+        let routed_price = route("usdt", "usdt", &[]).expect("in test");
+
+        assert_eq!(routed_price.price, Price::ONE);
+        assert!(routed_price.currency_pairs.is_empty());
+        assert_eq!(routed_price.price_source, None);
+    }
+
+    #[test]
+    fn no_route_price_without_direct_or_bridged_pairs() {
+        let prices = [("eth", "btc", price!(0.05)), ("xrp", "usdt", price!(0.5))];
+
+        assert_eq!(route("eth", "usdt", &prices), None);
+    }
+
+    #[rstest]
+    #[case("last_trade", ValuationMode::LastTrade)]
+    #[case("mid", ValuationMode::Mid)]
+    #[case("book_weighted", ValuationMode::BookWeighted)]
+    fn parse_valuation_mode(#[case] value: &str, #[case] expected: ValuationMode) {
+        assert_eq!(ValuationMode::from_str(value).expect("in test"), expected);
+    }
+}
@@ -10,7 +10,6 @@ use mmb_domain::market::ExchangeAccountId;
 
 use mmb_domain::market::CurrencyCode;
 use mmb_domain::order::snapshot::{Amount, Price};
-use rust_decimal_macros::dec;
 
 type BalanceByExchangeId = HashMap<ExchangeAccountId, HashMap<CurrencyCode, Amount>>;
 
@@ -54,7 +53,7 @@ impl VirtualBalanceHolder {
                     && balance_request.currency_code == *currency_code
                 {
                     self.balance_diff
-                        .set_by_balance_request(balance_request, dec!(0));
+                        .set_by_balance_request(balance_request, Amount::ZERO);
                     log::info!(
                         "VirtualBalanceHolder::update_balances Reset {} {}",
                         balance_request.exchange_account_id,
@@ -69,7 +68,7 @@ impl VirtualBalanceHolder {
         let current_diff_value = self
             .balance_diff
             .get_by_balance_request(balance_request)
-            .unwrap_or(dec!(0));
+            .unwrap_or(Amount::ZERO);
 
         let new_value = current_diff_value + balance_to_add;
         self.balance_diff
@@ -141,7 +140,7 @@ impl VirtualBalanceHolder {
         let current_balance_diff = if !symbol.is_derivative {
             self.balance_diff
                 .get_by_balance_request(balance_request)
-                .unwrap_or(dec!(0))
+                .unwrap_or(Amount::ZERO)
         } else {
             let price = price?;
             let balance_currency_code_request = BalanceRequest::new(
@@ -155,7 +154,7 @@ impl VirtualBalanceHolder {
             let balance_currency_code_balance_diff = self
                 .balance_diff
                 .get_by_balance_request(&balance_currency_code_request)
-                .unwrap_or(dec!(0));
+                .unwrap_or(Amount::ZERO);
 
             explanation.with_reason(|| format!("get_virtual_balance balance_currency_code_balance_diff = {balance_currency_code_balance_diff}"));
 
@@ -2,6 +2,9 @@ use std::fmt::{Display, Formatter};
 use thiserror::Error;
 use url::Url;
 
+mod permessage_deflate;
+mod reconnect_backoff;
+mod subscription;
 mod websocket;
 mod websocket_connection;
 
@@ -36,15 +39,79 @@ impl Display for WebSocketRole {
     }
 }
 
+/// Compression of websocket messages requested by exchange
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WsCompression {
+    /// `permessage-deflate` extension (RFC 7692)
+    PerMessageDeflate,
+}
+
+/// Compression requested for websocket connection
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct WsCompressionState {
+    pub role: WebSocketRole,
+    pub compression: WsCompression,
+    /// Exchange accepted compression during handshake
+    pub is_negotiated: bool,
+}
+
+/// Everything needed to establish websocket connection of exchange
 #[derive(Debug, Clone)]
 pub struct WebSocketParams {
     url: Url,
+    role: WebSocketRole,
+    /// Connection delivers private data of account, e.g. order updates
+    requires_auth: bool,
+    compression: Option<WsCompression>,
+    /// Message sent right after connection is established and before any other message
+    auth_message: Option<String>,
 }
 
 impl WebSocketParams {
-    pub fn new(url: Url) -> Self {
-        WebSocketParams { url }
+    pub fn new(role: WebSocketRole, url: Url) -> Self {
+        WebSocketParams {
+            url,
+            role,
+            requires_auth: false,
+            compression: None,
+            auth_message: None,
+        }
+    }
+
+    /// Mark connection as authenticated. `auth_message` is `None` for exchanges which
+    /// authenticate connection by url, e.g. by listen key
+    pub fn with_auth(mut self, auth_message: Option<String>) -> Self {
+        self.requires_auth = true;
+        self.auth_message = auth_message;
+        self
+    }
+
+    pub fn with_compression(mut self, compression: WsCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    pub fn role(&self) -> WebSocketRole {
+        self.role
+    }
+
+    pub fn requires_auth(&self) -> bool {
+        self.requires_auth
+    }
+
+    pub fn compression(&self) -> Option<WsCompression> {
+        self.compression
+    }
+
+    pub fn auth_message(&self) -> Option<&str> {
+        self.auth_message.as_deref()
     }
 }
 
-pub use websocket::{websocket_open, WsSender};
+pub use reconnect_backoff::ReconnectBackoff;
+pub use subscription::{StreamKind, Subscription};
+pub use websocket::{websocket_open, WsCloseReason, WsSender};
@@ -0,0 +1,490 @@
+//! Receiving side of `permessage-deflate` websocket extension (RFC 7692).
+//! Websocket library fails connection on frames with reserved bits set, so frames of compressed
+//! messages are replaced by a single uncompressed frame in stream adapter beneath it.
+//! Messages are sent uncompressed, which the extension allows.
+
+use flate2::{Decompress, FlushDecompress, Status};
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::{Certificate, ClientConfig, RootCertStore, ServerName};
+use tokio_rustls::TlsConnector;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::{TlsError, UrlError};
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Error;
+use tokio_tungstenite::{client_async, MaybeTlsStream, WebSocketStream};
+use url::Url;
+
+const EXTENSION_NAME: &str = "permessage-deflate";
+const EXTENSIONS_HEADER: &str = "Sec-WebSocket-Extensions";
+
+/// Tail of deflate block which is removed from compressed message by sender (RFC 7692 7.2.1)
+const DEFLATE_TAIL: [u8; 4] = [0x00, 0x00, 0xff, 0xff];
+/// The same as default message size limit of websocket library
+const MAX_MESSAGE_SIZE: usize = 64 << 20;
+const READ_CHUNK_SIZE: usize = 8 * 1024;
+
+const FIN: u8 = 0x80;
+const RSV1: u8 = 0x40;
+const OPCODE: u8 = 0x0f;
+const CONTROL_OPCODE: u8 = 0x08;
+const CONTINUATION_OPCODE: u8 = 0x00;
+const MASK: u8 = 0x80;
+const PAYLOAD_LEN: u8 = 0x7f;
+
+pub(super) type DeflateWebSocketStream = WebSocketStream<DeflateStream<MaybeTlsStream<TcpStream>>>;
+
+/// Connect to websocket with handshake offering `permessage-deflate` extension
+pub(super) async fn connect(url: &Url) -> Result<DeflateWebSocketStream, Error> {
+    let mut request = url.as_str().into_client_request()?;
+    let _ = request
+        .headers_mut()
+        .insert(EXTENSIONS_HEADER, HeaderValue::from_static(EXTENSION_NAME));
+
+    let host = url.host_str().ok_or(Error::Url(UrlError::NoHostName))?;
+    let port = url
+        .port_or_known_default()
+        .ok_or(Error::Url(UrlError::UnsupportedUrlScheme))?;
+    let socket = TcpStream::connect((host, port)).await?;
+    let stream = match url.scheme() {
+        "wss" => MaybeTlsStream::Rustls(tls_connect(host, socket).await?),
+        _ => MaybeTlsStream::Plain(socket),
+    };
+
+    let (ws_stream, _) = client_async(request, DeflateStream::new(stream)).await?;
+    Ok(ws_stream)
+}
+
+/// TLS connection trusting native root certificates like websocket library does
+async fn tls_connect(
+    host: &str,
+    socket: TcpStream,
+) -> Result<tokio_rustls::client::TlsStream<TcpStream>, Error> {
+    let mut root_store = RootCertStore::empty();
+    for cert in rustls_native_certs::load_native_certs()? {
+        root_store
+            .add(&Certificate(cert.0))
+            .map_err(TlsError::Webpki)?;
+    }
+
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(root_store)
+        .with_no_client_auth();
+    let server_name = ServerName::try_from(host).map_err(|_| TlsError::InvalidDnsName)?;
+
+    Ok(TlsConnector::from(Arc::new(config))
+        .connect(server_name, socket)
+        .await?)
+}
+
+enum State {
+    /// Waiting for the end of handshake response to find out if extension is negotiated
+    Handshake,
+    Passthrough,
+    Inflate(Inflater),
+}
+
+/// Stream adapter which inflates messages compressed by `permessage-deflate` extension
+/// if it's negotiated during handshake and passes bytes through as is otherwise
+pub(super) struct DeflateStream<S> {
+    inner: S,
+    state: State,
+    /// Bytes read from inner stream which aren't processed yet
+    input: Vec<u8>,
+    /// Processed bytes which aren't read yet
+    output: Vec<u8>,
+    output_pos: usize,
+}
+
+impl<S> DeflateStream<S> {
+    fn new(inner: S) -> Self {
+        DeflateStream {
+            inner,
+            state: State::Handshake,
+            input: vec![],
+            output: vec![],
+            output_pos: 0,
+        }
+    }
+
+    /// Whether exchange accepted the extension during handshake
+    pub(super) fn is_negotiated(&self) -> bool {
+        matches!(self.state, State::Inflate(_))
+    }
+
+    fn process_input(&mut self) -> io::Result<()> {
+        if let State::Handshake = self.state {
+            let end = match self.input.windows(4).position(|x| x == b"\r\n\r\n") {
+                Some(position) => position + 4,
+                None => return Ok(()),
+            };
+
+            self.state = match is_extension_negotiated(&self.input[..end]) {
+                true => State::Inflate(Inflater::new()),
+                false => State::Passthrough,
+            };
+            self.output.extend(self.input.drain(..end));
+        }
+
+        match &mut self.state {
+            State::Handshake => {}
+            State::Passthrough => self.output.append(&mut self.input),
+            State::Inflate(inflater) => {
+                let mut position = 0;
+                while let Some(frame) = Frame::parse(&self.input[position..])? {
+                    inflater.process_frame(&frame, &mut self.output)?;
+                    position += frame.raw.len();
+                }
+                let _ = self.input.drain(..position);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for DeflateStream<S> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            if this.output_pos < this.output.len() {
+                let len = buf.remaining().min(this.output.len() - this.output_pos);
+                buf.put_slice(&this.output[this.output_pos..this.output_pos + len]);
+                this.output_pos += len;
+                if this.output_pos == this.output.len() {
+                    this.output.clear();
+                    this.output_pos = 0;
+                }
+                return Poll::Ready(Ok(()));
+            }
+
+            if matches!(this.state, State::Passthrough) && this.input.is_empty() {
+                return Pin::new(&mut this.inner).poll_read(cx, buf);
+            }
+
+            let mut chunk = [0; READ_CHUNK_SIZE];
+            let mut chunk_buf = ReadBuf::new(&mut chunk);
+            ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk_buf))?;
+            if chunk_buf.filled().is_empty() {
+                // end of stream, incomplete frame is left for websocket library to report
+                if this.input.is_empty() {
+                    return Poll::Ready(Ok(()));
+                }
+                this.output.append(&mut this.input);
+                continue;
+            }
+
+            this.input.extend_from_slice(chunk_buf.filled());
+            this.process_input()?;
+        }
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for DeflateStream<S> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_shutdown(cx)
+    }
+}
+
+fn is_extension_negotiated(handshake_response: &[u8]) -> bool {
+    String::from_utf8_lossy(handshake_response)
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .filter(|(name, _)| name.trim().eq_ignore_ascii_case(EXTENSIONS_HEADER))
+        .flat_map(|(_, value)| value.split(',').map(|x| x.to_owned()).collect::<Vec<_>>())
+        .any(|extension| {
+            let name = extension.split(';').next().unwrap_or_default();
+            name.trim().eq_ignore_ascii_case(EXTENSION_NAME)
+        })
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+struct Frame<'a> {
+    first_byte: u8,
+    mask: Option<[u8; 4]>,
+    payload: &'a [u8],
+    /// Whole frame including header
+    raw: &'a [u8],
+}
+
+impl<'a> Frame<'a> {
+    /// `None` if frame isn't received completely yet
+    fn parse(bytes: &'a [u8]) -> io::Result<Option<Self>> {
+        let (first_byte, second_byte) = match bytes {
+            [first_byte, second_byte, ..] => (*first_byte, *second_byte),
+            _ => return Ok(None),
+        };
+
+        let (payload_len, mut header_len) = match second_byte & PAYLOAD_LEN {
+            126 => match bytes.get(2..4) {
+                Some(len) => (u16::from_be_bytes([len[0], len[1]]) as u64, 4),
+                None => return Ok(None),
+            },
+            127 => match bytes.get(2..10).and_then(|x| x.try_into().ok()) {
+                Some(len) => (u64::from_be_bytes(len), 10),
+                None => return Ok(None),
+            },
+            len => (len as u64, 2),
+        };
+        if payload_len > MAX_MESSAGE_SIZE as u64 {
+            return Err(invalid_data("websocket frame exceeds size limit"));
+        }
+
+        let mask = match second_byte & MASK != 0 {
+            true => match bytes.get(header_len..header_len + 4) {
+                Some(mask) => {
+                    header_len += 4;
+                    Some([mask[0], mask[1], mask[2], mask[3]])
+                }
+                None => return Ok(None),
+            },
+            false => None,
+        };
+
+        let frame_len = header_len + payload_len as usize;
+        Ok(bytes.get(..frame_len).map(|raw| Frame {
+            first_byte,
+            mask,
+            payload: &raw[header_len..],
+            raw,
+        }))
+    }
+
+    fn unmasked_payload(&self) -> Vec<u8> {
+        match self.mask {
+            None => self.payload.to_vec(),
+            Some(mask) => self
+                .payload
+                .iter()
+                .zip(mask.iter().cycle())
+                .map(|(byte, mask)| byte ^ mask)
+                .collect(),
+        }
+    }
+}
+
+struct Inflater {
+    decompress: Decompress,
+    /// Opcode and payload of compressed message which frames are being received
+    message: Option<(u8, Vec<u8>)>,
+}
+
+impl Inflater {
+    fn new() -> Self {
+        Inflater {
+            decompress: Decompress::new(false),
+            message: None,
+        }
+    }
+
+    /// Write frame to output as is unless it's a frame of compressed message.
+    /// Compressed message is written as a single uncompressed frame after its final frame
+    fn process_frame(&mut self, frame: &Frame, output: &mut Vec<u8>) -> io::Result<()> {
+        let opcode = frame.first_byte & OPCODE;
+        if opcode & CONTROL_OPCODE != 0 {
+            output.extend_from_slice(frame.raw);
+            return Ok(());
+        }
+
+        match (opcode, &mut self.message) {
+            (CONTINUATION_OPCODE, Some((_, payload))) => {
+                payload.extend(frame.unmasked_payload());
+                if payload.len() > MAX_MESSAGE_SIZE {
+                    return Err(invalid_data("websocket message exceeds size limit"));
+                }
+            }
+            _ if frame.first_byte & RSV1 != 0 => {
+                self.message = Some((opcode, frame.unmasked_payload()))
+            }
+            // frame of uncompressed message
+            _ => {
+                output.extend_from_slice(frame.raw);
+                return Ok(());
+            }
+        }
+
+        if frame.first_byte & FIN == 0 {
+            return Ok(());
+        }
+
+        let (opcode, payload) = self.message.take().expect("compressed message is received");
+        let inflated = self.inflate(payload)?;
+        write_frame(output, opcode, &inflated);
+        Ok(())
+    }
+
+    fn inflate(&mut self, mut payload: Vec<u8>) -> io::Result<Vec<u8>> {
+        payload.extend_from_slice(&DEFLATE_TAIL);
+
+        let mut inflated = Vec::with_capacity(payload.len() * 4);
+        let mut consumed = 0;
+        loop {
+            if inflated.capacity() - inflated.len() < READ_CHUNK_SIZE {
+                inflated.reserve(inflated.len().max(READ_CHUNK_SIZE));
+            }
+
+            let (total_in, total_out) = (self.decompress.total_in(), self.decompress.total_out());
+            let status = self
+                .decompress
+                .decompress_vec(&payload[consumed..], &mut inflated, FlushDecompress::Sync)
+                .map_err(|err| invalid_data(&format!("failed to inflate message: {err}")))?;
+            let consumed_now = (self.decompress.total_in() - total_in) as usize;
+            let is_progress = consumed_now > 0 || self.decompress.total_out() > total_out;
+            consumed += consumed_now;
+
+            if inflated.len() > MAX_MESSAGE_SIZE {
+                return Err(invalid_data("inflated message exceeds size limit"));
+            }
+
+            match status {
+                // sender compressed message without context takeover and finished deflate stream
+                Status::StreamEnd => {
+                    self.decompress.reset(false);
+                    return Ok(inflated);
+                }
+                _ if consumed == payload.len() && inflated.len() < inflated.capacity() => {
+                    return Ok(inflated)
+                }
+                _ if !is_progress => return Err(invalid_data("compressed message is truncated")),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn write_frame(output: &mut Vec<u8>, opcode: u8, payload: &[u8]) {
+    output.push(FIN | opcode);
+    match payload.len() {
+        len if len < 126 => output.push(len as u8),
+        len if len <= u16::MAX as usize => {
+            output.push(126);
+            output.extend_from_slice(&(len as u16).to_be_bytes());
+        }
+        len => {
+            output.push(127);
+            output.extend_from_slice(&(len as u64).to_be_bytes());
+        }
+    }
+    output.extend_from_slice(payload);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::{Compress, Compression, FlushCompress};
+    use tokio::io::AsyncReadExt;
+
+    const TEXT_OPCODE: u8 = 0x01;
+    const PING_OPCODE: u8 = 0x09;
+
+    const HANDSHAKE: &[u8] = b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n";
+
+    fn handshake(extensions: Option<&str>) -> Vec<u8> {
+        let mut handshake = HANDSHAKE.to_vec();
+        if let Some(extensions) = extensions {
+            handshake.extend(format!("Sec-WebSocket-Extensions: {extensions}\r\n").bytes());
+        }
+        handshake.extend(b"\r\n");
+        handshake
+    }
+
+    fn compress(compress: &mut Compress, message: &str) -> Vec<u8> {
+        let mut compressed = Vec::with_capacity(message.len() + 64);
+        let _ = compress
+            .compress_vec(message.as_bytes(), &mut compressed, FlushCompress::Sync)
+            .expect("in test");
+        assert!(compressed.ends_with(&DEFLATE_TAIL));
+        compressed.truncate(compressed.len() - DEFLATE_TAIL.len());
+        compressed
+    }
+
+    fn frame(first_byte: u8, payload: &[u8]) -> Vec<u8> {
+        let mut frame = vec![];
+        write_frame(&mut frame, 0, payload);
+        frame[0] = first_byte;
+        frame
+    }
+
+    async fn read_all(bytes: Vec<u8>) -> (Vec<u8>, bool) {
+        let mut stream = DeflateStream::new(bytes.as_slice());
+        let mut output = vec![];
+        let _ = stream.read_to_end(&mut output).await.expect("in test");
+        (output, stream.is_negotiated())
+    }
+
+    #[tokio::test]
+    async fn inflate_compressed_messages() {
+        let mut compressor = Compress::new(Compression::default(), false);
+        let first = compress(&mut compressor, "first message");
+        // compressed with context of the first message
+        let second = compress(&mut compressor, "second message");
+        let (second_head, second_tail) = second.split_at(second.len() / 2);
+
+        let mut input = handshake(Some("permessage-deflate; server_max_window_bits=15"));
+        input.extend(frame(FIN | RSV1 | TEXT_OPCODE, &first));
+        input.extend(frame(RSV1 | TEXT_OPCODE, second_head));
+        input.extend(frame(FIN | PING_OPCODE, b"ping"));
+        input.extend(frame(FIN | CONTINUATION_OPCODE, second_tail));
+        input.extend(frame(FIN | TEXT_OPCODE, b"uncompressed"));
+
+        let mut expected = handshake(Some("permessage-deflate; server_max_window_bits=15"));
+        expected.extend(frame(FIN | TEXT_OPCODE, b"first message"));
+        expected.extend(frame(FIN | PING_OPCODE, b"ping"));
+        expected.extend(frame(FIN | TEXT_OPCODE, b"second message"));
+        expected.extend(frame(FIN | TEXT_OPCODE, b"uncompressed"));
+
+        let (output, is_negotiated) = read_all(input).await;
+
+        assert!(is_negotiated);
+        assert_eq!(output, expected);
+    }
+
+    #[tokio::test]
+    async fn pass_through_if_extension_is_not_negotiated() {
+        let mut input = handshake(None);
+        input.extend(frame(FIN | TEXT_OPCODE, b"message"));
+
+        let (output, is_negotiated) = read_all(input.clone()).await;
+
+        assert!(!is_negotiated);
+        assert_eq!(output, input);
+    }
+
+    #[test]
+    fn parse_frame_with_extended_payload_len() {
+        let payload = vec![1; 300];
+        let bytes = frame(FIN | TEXT_OPCODE, &payload);
+
+        assert!(Frame::parse(&bytes[..bytes.len() - 1])
+            .expect("in test")
+            .is_none());
+
+        let frame = Frame::parse(&bytes).expect("in test").expect("in test");
+        assert_eq!(frame.payload, payload.as_slice());
+        assert_eq!(frame.raw.len(), bytes.len());
+    }
+}
@@ -0,0 +1,132 @@
+use mmb_domain::events::WebSocketClose;
+use std::time::{Duration, Instant};
+
+/// Delay before the first reconnect after transient failure. Single dropped connection
+/// is reconnected immediately
+const TRANSIENT_BASE_DELAY: Duration = Duration::from_secs(1);
+const TRANSIENT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Exchange closes connection with policy violation (1008) if client breaks its rules
+/// (rate limits, too many subscriptions, etc.), so immediate reconnect would be closed again
+const POLICY_VIOLATION_BASE_DELAY: Duration = Duration::from_secs(10);
+const POLICY_VIOLATION_MAX_DELAY: Duration = Duration::from_secs(600);
+
+/// Connection should stay alive for this period to forget previous policy violations
+const STABLE_CONNECTION_PERIOD: Duration = Duration::from_secs(300);
+
+/// Delays between websocket reconnect attempts depending on close codes of previous connections
+#[derive(Debug, Default)]
+pub struct ReconnectBackoff {
+    transient_failures: u32,
+    policy_violations: u32,
+    connected_at: Option<Instant>,
+}
+
+impl ReconnectBackoff {
+    pub fn on_connected(&mut self, now: Instant) {
+        self.transient_failures = 0;
+        self.connected_at = Some(now);
+    }
+
+    /// Register disconnection and return delay before next connect attempt
+    pub fn on_disconnected(&mut self, close: Option<&WebSocketClose>, now: Instant) -> Duration {
+        if let Some(connected_at) = self.connected_at.take() {
+            if now.saturating_duration_since(connected_at) >= STABLE_CONNECTION_PERIOD {
+                self.policy_violations = 0;
+            }
+        }
+
+        match close {
+            Some(close) if close.is_policy_violation() => {
+                self.policy_violations += 1;
+                exponential_delay(
+                    POLICY_VIOLATION_BASE_DELAY,
+                    POLICY_VIOLATION_MAX_DELAY,
+                    self.policy_violations - 1,
+                )
+            }
+            _ => {
+                self.transient_failures += 1;
+                match self.transient_failures {
+                    1 => Duration::ZERO,
+                    failures => {
+                        exponential_delay(TRANSIENT_BASE_DELAY, TRANSIENT_MAX_DELAY, failures - 2)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn exponential_delay(base: Duration, max: Duration, exponent: u32) -> Duration {
+    base.checked_mul(2u32.saturating_pow(exponent))
+        .map_or(max, |delay| delay.min(max))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy_violation() -> WebSocketClose {
+        WebSocketClose::new(WebSocketClose::POLICY_VIOLATION, "too many requests")
+    }
+
+    #[test]
+    fn transient_close_backs_off_until_connected() {
+        let mut backoff = ReconnectBackoff::default();
+        let now = Instant::now();
+        let abnormal = WebSocketClose::abnormal("connection reset");
+
+        backoff.on_connected(now);
+        assert_eq!(
+            backoff.on_disconnected(Some(&abnormal), now),
+            Duration::ZERO
+        );
+        assert_eq!(backoff.on_disconnected(None, now), Duration::from_secs(1));
+        assert_eq!(backoff.on_disconnected(None, now), Duration::from_secs(2));
+        for _ in 0..10 {
+            backoff.on_disconnected(None, now);
+        }
+        assert_eq!(backoff.on_disconnected(None, now), TRANSIENT_MAX_DELAY);
+
+        backoff.on_connected(now);
+        assert_eq!(
+            backoff.on_disconnected(Some(&abnormal), now),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn policy_violation_backs_off_more_aggressively() {
+        let mut backoff = ReconnectBackoff::default();
+        let now = Instant::now();
+
+        backoff.on_connected(now);
+        assert_eq!(
+            backoff.on_disconnected(Some(&policy_violation()), now),
+            Duration::from_secs(10)
+        );
+
+        // short-lived connection doesn't reset policy violations
+        backoff.on_connected(now);
+        assert_eq!(
+            backoff.on_disconnected(Some(&policy_violation()), now + Duration::from_secs(1)),
+            Duration::from_secs(20)
+        );
+    }
+
+    #[test]
+    fn stable_connection_resets_policy_violations() {
+        let mut backoff = ReconnectBackoff::default();
+        let now = Instant::now();
+
+        backoff.on_connected(now);
+        backoff.on_disconnected(Some(&policy_violation()), now);
+        backoff.on_connected(now);
+
+        assert_eq!(
+            backoff.on_disconnected(Some(&policy_violation()), now + STABLE_CONNECTION_PERIOD),
+            Duration::from_secs(10)
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use anyhow::{bail, Context, Error, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Exchange-agnostic websocket subscription. Each exchange client translates it into its own
+/// channel format. In settings it is specified by name: `depth`, `depth20`, `depth20@100ms`,
+/// `trade`, `aggTrade`, `bookTicker`, `userData`, `kline_1m`, `markPrice`, `markPrice@1000ms`,
+/// `liquidations`
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Subscription {
+    OrderBook {
+        /// Count of price levels. Full order book if not specified
+        depth: Option<u32>,
+        /// Interval between updates. Default one of exchange if not specified
+        update_speed_ms: Option<u32>,
+    },
+    Trades,
+    /// Public trades aggregated by taker order and price
+    AggTrades,
+    BookTicker,
+    UserData,
+    Klines {
+        interval: String,
+    },
+    /// Mark price, index price and funding rate of futures contracts
+    MarkPrice {
+        /// Interval between updates. Default one of exchange if not specified
+        update_speed_ms: Option<u32>,
+    },
+    /// Forced liquidation orders of futures contracts
+    Liquidations,
+}
+
+/// Kind of events delivered by websocket streams. Strategies declare kinds they need,
+/// so streams of the rest kinds aren't subscribed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    OrderBook,
+    Trades,
+    /// Order updates and fills of account
+    Fills,
+    Candles,
+    MarkPrice,
+    Liquidations,
+}
+
+impl StreamKind {
+    pub const ALL: [StreamKind; 6] = [
+        StreamKind::OrderBook,
+        StreamKind::Trades,
+        StreamKind::Fills,
+        StreamKind::Candles,
+        StreamKind::MarkPrice,
+        StreamKind::Liquidations,
+    ];
+}
+
+impl Subscription {
+    pub fn stream_kind(&self) -> StreamKind {
+        match self {
+            Subscription::OrderBook { .. } | Subscription::BookTicker => StreamKind::OrderBook,
+            Subscription::Trades | Subscription::AggTrades => StreamKind::Trades,
+            Subscription::UserData => StreamKind::Fills,
+            Subscription::Klines { .. } => StreamKind::Candles,
+            Subscription::MarkPrice { .. } => StreamKind::MarkPrice,
+            Subscription::Liquidations => StreamKind::Liquidations,
+        }
+    }
+}
+
+impl FromStr for Subscription {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let subscription = match value {
+            "trade" => Subscription::Trades,
+            "aggTrade" => Subscription::AggTrades,
+            "bookTicker" => Subscription::BookTicker,
+            "userData" => Subscription::UserData,
+            "liquidations" => Subscription::Liquidations,
+            "markPrice" => Subscription::MarkPrice {
+                update_speed_ms: None,
+            },
+            _ => {
+                if let Some(interval) = value.strip_prefix("kline_") {
+                    if interval.is_empty() {
+                        bail!("Kline interval isn't specified in subscription '{value}'");
+                    }
+
+                    Subscription::Klines {
+                        interval: interval.to_owned(),
+                    }
+                } else if let Some(update_speed) = value.strip_prefix("markPrice@") {
+                    let update_speed_ms = update_speed
+                        .strip_suffix("ms")
+                        .and_then(|x| x.parse().ok())
+                        .with_context(|| {
+                            format!("Unable to parse update speed of subscription '{value}'")
+                        })?;
+
+                    Subscription::MarkPrice {
+                        update_speed_ms: Some(update_speed_ms),
+                    }
+                } else if let Some(order_book) = value.strip_prefix("depth") {
+                    let (depth, update_speed) = match order_book.split_once('@') {
+                        None => (order_book, None),
+                        Some((depth, update_speed)) => (depth, Some(update_speed)),
+                    };
+
+                    let depth = match depth {
+                        "" => None,
+                        depth => Some(depth.parse().with_context(|| {
+                            format!("Unable to parse depth of subscription '{value}'")
+                        })?),
+                    };
+
+                    let update_speed_ms = update_speed
+                        .map(|x| {
+                            x.strip_suffix("ms")
+                                .and_then(|x| x.parse().ok())
+                                .with_context(|| {
+                                    format!(
+                                        "Unable to parse update speed of subscription '{value}'"
+                                    )
+                                })
+                        })
+                        .transpose()?;
+
+                    Subscription::OrderBook {
+                        depth,
+                        update_speed_ms,
+                    }
+                } else {
+                    bail!("Unknown websocket subscription '{value}'")
+                }
+            }
+        };
+
+        Ok(subscription)
+    }
+}
+
+impl TryFrom<String> for Subscription {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self> {
+        value.parse()
+    }
+}
+
+impl Display for Subscription {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Subscription::OrderBook {
+                depth,
+                update_speed_ms,
+            } => {
+                write!(f, "depth")?;
+                if let Some(depth) = depth {
+                    write!(f, "{depth}")?;
+                }
+                if let Some(update_speed_ms) = update_speed_ms {
+                    write!(f, "@{update_speed_ms}ms")?;
+                }
+                Ok(())
+            }
+            Subscription::Trades => write!(f, "trade"),
+            Subscription::AggTrades => write!(f, "aggTrade"),
+            Subscription::BookTicker => write!(f, "bookTicker"),
+            Subscription::UserData => write!(f, "userData"),
+            Subscription::Klines { interval } => write!(f, "kline_{interval}"),
+            Subscription::Liquidations => write!(f, "liquidations"),
+            Subscription::MarkPrice { update_speed_ms } => {
+                write!(f, "markPrice")?;
+                if let Some(update_speed_ms) = update_speed_ms {
+                    write!(f, "@{update_speed_ms}ms")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<Subscription> for String {
+    fn from(value: Subscription) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("depth", Subscription::OrderBook { depth: None, update_speed_ms: None })]
+    #[case("depth20", Subscription::OrderBook { depth: Some(20), update_speed_ms: None })]
+    #[case("depth20@100ms", Subscription::OrderBook { depth: Some(20), update_speed_ms: Some(100) })]
+    #[case("trade", Subscription::Trades)]
+    #[case("aggTrade", Subscription::AggTrades)]
+    #[case("bookTicker", Subscription::BookTicker)]
+    #[case("userData", Subscription::UserData)]
+    #[case("kline_1m", Subscription::Klines { interval: "1m".to_owned() })]
+    #[case("markPrice", Subscription::MarkPrice { update_speed_ms: None })]
+    #[case("markPrice@1000ms", Subscription::MarkPrice { update_speed_ms: Some(1000) })]
+    #[case("liquidations", Subscription::Liquidations)]
+    fn parse_and_display(#[case] value: &str, #[case] expected: Subscription) {
+        let subscription: Subscription = value.parse().expect("in test");
+
+        assert_eq!(subscription, expected);
+        assert_eq!(subscription.to_string(), value);
+    }
+
+    #[rstest]
+    #[case("depth20@100ms", StreamKind::OrderBook)]
+    #[case("bookTicker", StreamKind::OrderBook)]
+    #[case("trade", StreamKind::Trades)]
+    #[case("aggTrade", StreamKind::Trades)]
+    #[case("userData", StreamKind::Fills)]
+    #[case("kline_1m", StreamKind::Candles)]
+    fn stream_kind_of_subscription(#[case] value: &str, #[case] expected: StreamKind) {
+        let subscription: Subscription = value.parse().expect("in test");
+
+        assert_eq!(subscription.stream_kind(), expected);
+    }
+
+    #[rstest]
+    #[case("trades")]
+    #[case("depth_20")]
+    #[case("depth20@100")]
+    #[case("kline_")]
+    #[case("markPrice@1s")]
+    fn reject_unknown_subscription(#[case] value: &str) {
+        assert!(value.parse::<Subscription>().is_err());
+    }
+}
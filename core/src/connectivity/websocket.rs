@@ -1,18 +1,41 @@
 use super::websocket_connection::open_connection;
-use super::{ConnectivityError, Result, WebSocketParams, WebSocketRole};
+use super::{ConnectivityError, Result, WebSocketParams, WsCompressionState};
 use crate::infrastructure::spawn_future;
 use futures::FutureExt;
+use mmb_domain::events::WebSocketClose;
 use mmb_domain::market::ExchangeAccountId;
 use mmb_utils::infrastructure::SpawnFutureFlags;
+use parking_lot::Mutex;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_util::sync::{CancellationToken, DropGuard as CancellationTokenDropGuard};
 
+/// Reason of websocket connection close shared between connection futures.
+/// The first registered close is kept because the rest connections are closed as its consequence
+#[derive(Debug, Clone, Default)]
+pub struct WsCloseReason(Arc<Mutex<Option<WebSocketClose>>>);
+
+impl WsCloseReason {
+    pub(super) fn set(&self, close: WebSocketClose) {
+        self.0.lock().get_or_insert(close);
+    }
+
+    /// `None` if connection is still open or was closed by client
+    pub fn get(&self) -> Option<WebSocketClose> {
+        self.0.lock().clone()
+    }
+}
+
 pub struct WsSender {
     /// Main websocket connection sender
     main_sender: mpsc::UnboundedSender<Message>,
     /// Secondary websocket connection sender
     secondary_sender: Option<mpsc::UnboundedSender<Message>>,
+    /// Close reason of the first closed connection
+    close_reason: WsCloseReason,
+    /// Compression of connections which requested it
+    compression: Vec<WsCompressionState>,
     /// Cancellation token for service futures
     _cancel: CancellationTokenDropGuard,
 }
@@ -34,6 +57,14 @@ impl WsSender {
             .send(Message::Text(msg))
             .map_err(|_| ConnectivityError::NotConnected)
     }
+
+    pub fn close_reason(&self) -> WsCloseReason {
+        self.close_reason.clone()
+    }
+
+    pub fn compression(&self) -> &[WsCompressionState] {
+        &self.compression
+    }
 }
 
 pub async fn websocket_open(
@@ -57,18 +88,19 @@ async fn connect_both_parallel(
     exchange_account_id: ExchangeAccountId,
 ) -> Result<(WsSender, mpsc::UnboundedReceiver<String>)> {
     let cancel = CancellationToken::new();
+    let close_reason = WsCloseReason::default();
     let (main, secondary) = tokio::join!(
         open_connection(
             exchange_account_id,
-            WebSocketRole::Main,
             main,
-            cancel.clone()
+            cancel.clone(),
+            close_reason.clone()
         ),
         open_connection(
             exchange_account_id,
-            WebSocketRole::Secondary,
             secondary,
-            cancel.clone()
+            cancel.clone(),
+            close_reason.clone()
         )
     );
 
@@ -79,6 +111,8 @@ async fn connect_both_parallel(
             let sender = WsSender {
                 main_sender: main.0,
                 secondary_sender: Some(secondary.0),
+                close_reason,
+                compression: main.2.into_iter().chain(secondary.2).collect(),
                 _cancel: cancel.drop_guard(),
             };
             spawn_future(
@@ -127,16 +161,19 @@ async fn connect_only_main(
     exchange_account_id: ExchangeAccountId,
 ) -> Result<(WsSender, mpsc::UnboundedReceiver<String>)> {
     let cancel = CancellationToken::new();
-    let (tx, rx) = open_connection(
+    let close_reason = WsCloseReason::default();
+    let (tx, rx, compression) = open_connection(
         exchange_account_id,
-        WebSocketRole::Main,
         params,
         cancel.clone(),
+        close_reason.clone(),
     )
     .await?;
     let sender = WsSender {
         main_sender: tx,
         secondary_sender: None,
+        close_reason,
+        compression: compression.into_iter().collect(),
         _cancel: cancel.drop_guard(),
     };
     Ok((sender, rx))
@@ -1,15 +1,19 @@
-use super::{ConnectivityError, Result, WebSocketParams, WebSocketRole};
+use super::permessage_deflate;
+use super::websocket::WsCloseReason;
+use super::{
+    ConnectivityError, Result, WebSocketParams, WebSocketRole, WsCompression, WsCompressionState,
+};
 use crate::infrastructure::spawn_future_ok;
-use futures::stream::{SplitSink, SplitStream};
-use futures::{SinkExt, StreamExt};
+use futures::{Sink, SinkExt, Stream, StreamExt};
+use mmb_domain::events::WebSocketClose;
 use mmb_domain::market::ExchangeAccountId;
 use mmb_utils::infrastructure::SpawnFutureFlags;
 use std::fmt::Formatter;
-use tokio::net::TcpStream;
+use std::pin::Pin;
 use tokio::sync::mpsc;
 use tokio::time::{timeout, timeout_at, Duration, Instant};
-use tokio_tungstenite::tungstenite::Message;
-use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::{Error, Message};
 use tokio_util::sync::CancellationToken;
 
 /// Time interval between heartbeat pings are sent
@@ -37,8 +41,8 @@ impl std::fmt::Display for Meta {
     }
 }
 
-type WebSocketWriter = SplitSink<WebSocketStream<MaybeTlsStream<TcpStream>>, Message>;
-type WebSocketReader = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+type WebSocketWriter = Pin<Box<dyn Sink<Message, Error = Error> + Send>>;
+type WebSocketReader = Pin<Box<dyn Stream<Item = std::result::Result<Message, Error>> + Send>>;
 
 /// Websocket writer
 struct WriterHandle {
@@ -162,6 +166,8 @@ struct ReaderHandle {
     reader_tx: mpsc::UnboundedSender<String>,
     /// Channel to `WriterHandle`
     internal_tx: mpsc::Sender<Message>,
+    /// Close reason shared with the rest connections of exchange account
+    close_reason: WsCloseReason,
     /// Cancellation token.
     ///
     /// This one is bidirectional: we use it to trigger signal and to wait for the signal from
@@ -194,6 +200,7 @@ impl ReaderHandle {
             let msg = match result {
                 Ok(Some(Err(e))) => {
                     log::error!("Websocket {} reader recv failure: {:?}", self.meta, e);
+                    self.on_close(WebSocketClose::abnormal(e.to_string()));
                     return;
                 }
 
@@ -205,6 +212,9 @@ impl ReaderHandle {
                 Ok(None) => {
                     // clean close
                     log::debug!("Websocket {} reader received oef", self.meta);
+                    self.on_close(WebSocketClose::abnormal(
+                        "stream closed without close frame",
+                    ));
                     break;
                 }
 
@@ -212,6 +222,7 @@ impl ReaderHandle {
                     // heartbeat timeout
                     if receive_ts.elapsed() >= HEARTBEAT_FAIL_TIMEOUT {
                         log::error!("Websocket {} reader reached heartbeat deadline", self.meta);
+                        self.on_close(WebSocketClose::abnormal("heartbeat deadline reached"));
                         return;
                     }
                     // will send heartbeat again after HEARTBEAT_INTERVAL
@@ -219,6 +230,7 @@ impl ReaderHandle {
 
                     if (self.send_ping()).is_err() {
                         log::error!("Websocket {} reader failed to send ping", self.meta);
+                        self.on_close(WebSocketClose::abnormal("failed to send ping"));
                         return;
                     };
                     continue;
@@ -255,11 +267,12 @@ impl ReaderHandle {
                 Message::Pong(_) => {
                     // we don't care about it's content
                 }
-                Message::Close(reason) => {
-                    log::trace!(
-                        "Websocket {} reader received close with reason: {reason:?}",
-                        self.meta
-                    );
+                Message::Close(frame) => {
+                    let close = match frame {
+                        Some(frame) => WebSocketClose::new(frame.code.into(), frame.reason),
+                        None => WebSocketClose::new(WebSocketClose::NO_STATUS, ""),
+                    };
+                    self.on_close(close);
 
                     break;
                 }
@@ -272,6 +285,25 @@ impl ReaderHandle {
         log::debug!("Websocket {} reader finished", self.meta);
     }
 
+    fn on_close(&self, close: WebSocketClose) {
+        let WebSocketClose { code, reason } = &close;
+        if close.is_policy_violation() {
+            log::error!(
+                "Websocket {} closed by policy violation ({code}): {reason}",
+                self.meta
+            );
+        } else if close.is_abnormal() {
+            log::warn!(
+                "Websocket {} closed abnormally ({code}): {reason}",
+                self.meta
+            );
+        } else {
+            log::info!("Websocket {} closed with code {code}: {reason}", self.meta);
+        }
+
+        self.close_reason.set(close);
+    }
+
     fn send_ping(&self) -> TrySendResult {
         log::trace!("Websocket {} reader triggers ping packet", self.meta);
         self.internal_tx
@@ -295,29 +327,66 @@ impl ReaderHandle {
 /// Open WebSocket connection.
 ///
 /// Provided cancellation token can be used to shutdown service futures instantly.
+/// Reason of connection close is stored to `close_reason`.
+/// Auth message of params is sent before any message of user.
 ///
 /// # Return
-/// Tuple: (send channel, read channel)
+/// Tuple: (send channel, read channel, compression state if compression is requested)
 pub async fn open_connection(
     exchange_account_id: ExchangeAccountId,
-    role: WebSocketRole,
     params: WebSocketParams,
     cancel: CancellationToken,
+    close_reason: WsCloseReason,
 ) -> Result<(
     mpsc::UnboundedSender<Message>,
     mpsc::UnboundedReceiver<String>,
+    Option<WsCompressionState>,
 )> {
-    let (ws_stream, _) = connect_async(params.url.clone())
-        .await
-        .map_err(|e| ConnectivityError::FailedToConnect(role, params.url.to_string(), e))?;
-
+    let role = params.role();
     let meta = Meta(exchange_account_id, role);
+    let failed_to_connect =
+        |e| ConnectivityError::FailedToConnect(role, params.url().to_string(), e);
+
+    let (writer, reader, compression_state): (WebSocketWriter, WebSocketReader, _) = match params
+        .compression()
+    {
+        None => {
+            let (ws_stream, _) = connect_async(params.url().clone())
+                .await
+                .map_err(failed_to_connect)?;
+            let (writer, reader) = ws_stream.split();
+            (Box::pin(writer), Box::pin(reader), None)
+        }
+        Some(compression @ WsCompression::PerMessageDeflate) => {
+            let ws_stream = permessage_deflate::connect(params.url())
+                .await
+                .map_err(failed_to_connect)?;
+            let is_negotiated = ws_stream.get_ref().is_negotiated();
+            match is_negotiated {
+                    true => log::info!("Websocket {meta} compression {compression:?} is negotiated"),
+                    false => log::warn!("Websocket {meta} compression {compression:?} is declined by exchange, messages are received uncompressed"),
+                }
+
+            let compression_state = WsCompressionState {
+                role,
+                compression,
+                is_negotiated,
+            };
+            let (writer, reader) = ws_stream.split();
+            (Box::pin(writer), Box::pin(reader), Some(compression_state))
+        }
+    };
 
     let (writer_tx, writer_rx) = mpsc::unbounded_channel();
+    if let Some(auth_message) = params.auth_message() {
+        log::trace!("Websocket {meta} sending auth message");
+        // receiver is alive until writer future is spawned, so sending can't fail
+        let _ = writer_tx.send(Message::Text(auth_message.to_owned()));
+    }
+
     let (internal_tx, internal_rx) = mpsc::channel(1);
     let (reader_tx, reader_rx) = mpsc::unbounded_channel();
 
-    let (writer, reader) = ws_stream.split();
     let writer = WriterHandle {
         writer,
         meta,
@@ -331,6 +400,7 @@ pub async fn open_connection(
         meta,
         internal_tx,
         reader_tx,
+        close_reason,
         cancel,
     };
 
@@ -345,5 +415,5 @@ pub async fn open_connection(
         reader.run(),
     );
 
-    Ok((writer_tx, reader_rx))
+    Ok((writer_tx, reader_rx, compression_state))
 }
@@ -0,0 +1,125 @@
+use anyhow::{bail, Result};
+use mmb_domain::exchanges::symbol::{Round, Symbol};
+use mmb_domain::order::snapshot::Amount;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AmountQuantizationSettings {
+    /// Grid which order amounts of strategy are rounded to. It should be a multiple of
+    /// amount step of symbol, e.g. 0.01 for step 0.001
+    pub amount_grid: Amount,
+}
+
+/// Rounds order amounts of strategy to a grid coarser than amount step of exchange
+#[derive(Debug, Clone)]
+pub struct AmountQuantizer {
+    amount_grid: Amount,
+}
+
+impl AmountQuantizer {
+    /// Returns error if amount grid isn't a positive multiple of amount step of symbol,
+    /// because amounts rounded to such grid would be rounded once more by exchange
+    pub fn new(settings: &AmountQuantizationSettings, symbol: &Symbol) -> Result<Self> {
+        let amount_grid = settings.amount_grid;
+        let amount_step = symbol.amount_precision.get_tick();
+
+        if amount_grid <= Amount::ZERO {
+            bail!("Amount grid {amount_grid} of strategy should be positive");
+        }
+
+        if !(amount_grid.value() % amount_step).is_zero() {
+            bail!(
+                "Amount grid {amount_grid} of strategy isn't a multiple of amount step {amount_step} of {}",
+                symbol.currency_pair()
+            );
+        }
+
+        Ok(Self { amount_grid })
+    }
+
+    /// Amount rounded by amount step of symbol and then by amount grid of strategy
+    pub fn quantize(&self, symbol: &Symbol, amount: Amount, round: Round) -> Amount {
+        let round_steps: fn(Decimal) -> Decimal = match round {
+            Round::Floor => |steps| steps.floor(),
+            Round::Ceiling => |steps| steps.ceil(),
+            Round::ToNearest => |steps| {
+                let (floor, ceil) = (steps.floor(), steps.ceil());
+                match ceil - steps <= steps - floor {
+                    true => ceil,
+                    false => floor,
+                }
+            },
+        };
+
+        let amount = symbol.amount_round(amount, round);
+        let steps = round_steps(amount / self.amount_grid);
+
+        steps * self.amount_grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::amount;
+    use mmb_domain::exchanges::symbol::Precision;
+    use mmb_domain::market::CurrencyCode;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn symbol(amount_precision: Precision) -> Symbol {
+        let base: CurrencyCode = "BTC".into();
+        let quote: CurrencyCode = "USDT".into();
+
+        Symbol::new(
+            false,
+            base.as_str().into(),
+            base,
+            quote.as_str().into(),
+            quote,
+            None,
+            None,
+            None,
+            None,
+            None,
+            base,
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            amount_precision,
+        )
+    }
+
+    fn quantizer(amount_grid: Amount, symbol: &Symbol) -> Result<AmountQuantizer> {
+        AmountQuantizer::new(&AmountQuantizationSettings { amount_grid }, symbol)
+    }
+
+    #[rstest]
+    #[case::floor(amount!(0.0379), Round::Floor, amount!(0.03))]
+    #[case::ceiling(amount!(0.0311), Round::Ceiling, amount!(0.04))]
+    #[case::to_nearest_up(amount!(0.0351), Round::ToNearest, amount!(0.04))]
+    #[case::to_nearest_down(amount!(0.0341), Round::ToNearest, amount!(0.03))]
+    #[case::on_grid(amount!(0.05), Round::Floor, amount!(0.05))]
+    fn quantize_amount(#[case] amount: Amount, #[case] round: Round, #[case] expected: Amount) {
+        let symbol = symbol(Precision::ByTick { tick: dec!(0.001) });
+        let quantizer = quantizer(amount!(0.01), &symbol).expect("in test");
+
+        assert_eq!(quantizer.quantize(&symbol, amount, round), expected);
+    }
+
+    #[rstest]
+    #[case::multiple_of_tick(Precision::ByTick { tick: dec!(0.001) }, amount!(0.005), true)]
+    #[case::multiple_of_mantissa(Precision::ByMantissa { precision: 3 }, amount!(0.01), true)]
+    #[case::not_multiple(Precision::ByTick { tick: dec!(0.002) }, amount!(0.005), false)]
+    #[case::finer_than_step(Precision::ByMantissa { precision: 2 }, amount!(0.001), false)]
+    #[case::zero(Precision::ByTick { tick: dec!(0.001) }, amount!(0), false)]
+    fn validate_amount_grid(
+        #[case] amount_precision: Precision,
+        #[case] amount_grid: Amount,
+        #[case] is_valid: bool,
+    ) {
+        let symbol = symbol(amount_precision);
+
+        assert_eq!(quantizer(amount_grid, &symbol).is_ok(), is_valid);
+    }
+}
@@ -11,10 +11,12 @@ use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use tokio::sync::{broadcast, oneshot};
 
+use crate::disposition_execution::order_book_throttle::OrderBookUpdateThrottle;
 use crate::disposition_execution::strategy::DispositionStrategy;
 use crate::disposition_execution::trading_context_calculation::calculate_trading_context;
 use crate::exchanges::general::exchange::Exchange;
 use crate::exchanges::general::request_type::RequestType;
+use crate::exchanges::timeouts::requests_timeout_manager::RequestGroupId;
 use crate::explanation::{Explanation, WithExplanation};
 use crate::lifecycle::trading_engine::{EngineContext, Service};
 use crate::misc::reserve_parameters::ReserveParameters;
@@ -35,7 +37,7 @@ use mmb_domain::market::CurrencyPair;
 use mmb_domain::market::{ExchangeAccountId, MarketAccountId};
 use mmb_domain::order::event::OrderEventType;
 use mmb_domain::order::pool::OrderRef;
-use mmb_domain::order::snapshot::{Amount, Price, UserOrder};
+use mmb_domain::order::snapshot::{Amount, Price, ReservationId, UserOrder};
 use mmb_domain::order::snapshot::{
     ClientOrderId, OrderHeader, OrderSide, OrderSnapshot, OrderStatus,
 };
@@ -44,11 +46,12 @@ use mmb_utils::cancellation_token::CancellationToken;
 static DISPOSITION_EXECUTOR: &str = "DispositionExecutor";
 static DISPOSITION_EXECUTOR_REQUESTS_GROUP: &str = "DispositionExecutorRG";
 const ALLOWED_AMOUNT_DEVIATION_RATE: Decimal = dec!(0.001);
+const DEFAULT_ALLOWED_PRICE_DEVIATION_RATE: Decimal = dec!(0.00001);
 const GROUP_REQUESTS_COUNT: usize = 4;
 
 struct DisplaySmallOrder {
-    price: Decimal,
-    amount: Decimal,
+    price: Price,
+    amount: Amount,
 }
 
 impl Display for DisplaySmallOrder {
@@ -125,9 +128,11 @@ struct DispositionExecutor {
     local_snapshots_service: LocalSnapshotsService,
     orders_state: OrdersState,
     strategy: Box<dyn DispositionStrategy>,
+    order_book_throttle: Option<OrderBookUpdateThrottle>,
     work_finished_sender: Option<oneshot::Sender<Result<()>>>,
     cancellation_token: CancellationToken,
     statistics: Arc<StatisticService>,
+    allowed_price_deviation_rate: Decimal,
 }
 
 impl DispositionExecutor {
@@ -150,6 +155,17 @@ impl DispositionExecutor {
             .get_symbol(currency_pair)
             .expect("Currency pair symbol should exists for target trading place");
 
+        let order_book_throttle = strategy.order_book_update_interval().map(|interval| {
+            OrderBookUpdateThrottle::new(
+                Duration::from_std(interval).expect("Order book update interval is too large"),
+            )
+        });
+
+        let allowed_price_deviation_rate = engine_ctx
+            .core_settings
+            .requote_price_tolerance
+            .unwrap_or(DEFAULT_ALLOWED_PRICE_DEVIATION_RATE);
+
         DispositionExecutor {
             engine_ctx,
             events_receiver,
@@ -158,9 +174,11 @@ impl DispositionExecutor {
             symbol,
             orders_state: OrdersState::new(),
             strategy,
+            order_book_throttle,
             work_finished_sender: Some(work_finished_sender),
             cancellation_token,
             statistics,
+            allowed_price_deviation_rate,
         }
     }
 
@@ -168,8 +186,17 @@ impl DispositionExecutor {
         let mut trading_context: Option<TradingContext> = None;
 
         loop {
+            let flush_time = self
+                .order_book_throttle
+                .as_ref()
+                .and_then(|x| x.next_flush_time());
+
             let event = tokio::select! {
                 event_res = self.events_receiver.recv() => event_res.map_err(|e| anyhow!("Error during receiving event in DispositionExecutor::start(). Error: {e}."))?,
+                _ = sleep_until(flush_time) => {
+                    self.flush_throttled_order_book_updates(&mut trading_context)?;
+                    continue;
+                }
                 _ = self.cancellation_token.when_cancelled() => {
                     let _ = self.work_finished_sender.take().ok_or_else(|| anyhow!("Can't take `work_finished_sender` in DispositionExecutor"))?.send(Ok(()));
                     return Ok(());
@@ -186,11 +213,46 @@ impl DispositionExecutor {
         last_trading_context: &mut Option<TradingContext>,
     ) -> Result<()> {
         let now = now();
-        let need_recalculate_trading_context = self.prepare_estimate_trading_context(event, now);
+        if self.is_stale_market_data(event, now) {
+            // order book is still updated, otherwise it would diverge from exchange one
+            // until the next snapshot
+            if let ExchangeEvent::OrderBookEvent(order_book_event) = event {
+                let _ = self.local_snapshots_service.update(order_book_event);
+            }
+
+            return Ok(());
+        }
+
+        let mut need_recalculate_trading_context =
+            self.prepare_estimate_trading_context(event, now);
 
         match event {
             ExchangeEvent::OrderBookEvent(order_book_event) => {
-                let _ = self.local_snapshots_service.update(order_book_event);
+                // Update which isn't applied belongs to order book that isn't resynced yet
+                // after reconnect, so strategy shouldn't react on it
+                if self
+                    .local_snapshots_service
+                    .update(order_book_event)
+                    .is_none()
+                {
+                    need_recalculate_trading_context = false;
+                }
+
+                // throttled update is delivered later unless newer update replaces it
+                if need_recalculate_trading_context {
+                    if let Some(throttle) = &mut self.order_book_throttle {
+                        need_recalculate_trading_context =
+                            throttle.on_update(order_book_event, now);
+                    }
+                }
+            }
+            ExchangeEvent::Disconnected(disconnected) => {
+                self.local_snapshots_service
+                    .discard_snapshots(disconnected.exchange_account_id.exchange_id);
+            }
+            ExchangeEvent::Scheduled(scheduled_event) => {
+                need_recalculate_trading_context =
+                    self.strategy.on_scheduled(&scheduled_event.name, now);
             }
             ExchangeEvent::OrderEvent(order_event) => {
                 let order = &order_event.order;
@@ -203,22 +265,31 @@ impl DispositionExecutor {
                     OrderEventType::CreateOrderFailed => {
                         let client_order_id = order.client_order_id();
                         log::trace!("Started handling event CreateOrderFailed {client_order_id} in DispositionExecutor");
-                        let Some(price_slot) = self.get_price_slot(order) else { return Ok(()); };
+                        let Some(price_slot) = self.get_price_slot(order) else {
+                            return Ok(());
+                        };
 
                         self.finish_order(order, price_slot)?;
                         log::trace!("Finished handling event CreateOrderFailed {client_order_id} in DispositionExecutor");
                     }
-                    OrderEventType::OrderFilled { ref cloned_order } => {
+                    OrderEventType::OrderFilled {
+                        ref cloned_order,
+                        fills_count,
+                    } => {
                         log::trace!(
                             "Started handling event OrderFilled {} in DispositionExecutor",
                             cloned_order.client_order_id()
                         );
                         let price_slot = self.get_price_slot(order);
                         if let Some(price_slot) = price_slot {
-                            self.engine_ctx.balance_manager.lock().order_was_filled(
-                                self.strategy.configuration_descriptor(),
-                                cloned_order,
-                            );
+                            self.engine_ctx
+                                .balance_manager
+                                .lock()
+                                .order_was_filled_by_last_fills(
+                                    self.strategy.configuration_descriptor(),
+                                    cloned_order,
+                                    fills_count,
+                                );
 
                             if cloned_order.status() == OrderStatus::Completed {
                                 return Ok(());
@@ -266,12 +337,25 @@ impl DispositionExecutor {
 
                         // TODO save state to Database
                     }
+                    OrderEventType::ClientOrderIdRegenerated {
+                        ref previous_client_order_id,
+                    } => {
+                        let price_slot = self.orders_state.by_side[order.side()]
+                            .find_price_slot_by_client_order_id(previous_client_order_id);
+                        match price_slot {
+                            None => log::error!("Can't find order with client_order_id {previous_client_order_id} {} in orders state of DispositionExecutor", self.exchange_account_id),
+                            Some(price_slot) => price_slot
+                                .order
+                                .borrow_mut()
+                                .rekey_order_record(previous_client_order_id, order.clone()),
+                        }
+                    }
                 }
             }
             _ => nothing_to_do(),
         };
 
-        let mut new_trading_context = estimate_trading_context(
+        let new_trading_context = estimate_trading_context(
             need_recalculate_trading_context,
             event,
             self.strategy.as_mut(),
@@ -279,6 +363,42 @@ impl DispositionExecutor {
             now,
         )?;
 
+        self.apply_trading_context(new_trading_context, last_trading_context, now)
+    }
+
+    /// Pending order book updates are already applied to local snapshots, so strategy gets
+    /// the latest order book of currency pair
+    fn flush_throttled_order_book_updates(
+        &mut self,
+        last_trading_context: &mut Option<TradingContext>,
+    ) -> Result<()> {
+        let now = now();
+        let due_updates = match &mut self.order_book_throttle {
+            None => return Ok(()),
+            Some(throttle) => throttle.take_due_updates(now),
+        };
+
+        for order_book_event in due_updates {
+            let new_trading_context = estimate_trading_context(
+                true,
+                &ExchangeEvent::OrderBookEvent(order_book_event),
+                self.strategy.as_mut(),
+                &self.local_snapshots_service,
+                now,
+            )?;
+
+            self.apply_trading_context(new_trading_context, last_trading_context, now)?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_trading_context(
+        &mut self,
+        mut new_trading_context: Option<TradingContext>,
+        last_trading_context: &mut Option<TradingContext>,
+        now: DateTime,
+    ) -> Result<()> {
         if last_trading_context == &mut new_trading_context {
             return Ok(());
         }
@@ -327,7 +447,7 @@ impl DispositionExecutor {
         &self,
         slots: &[PriceSlot],
         estimating: &mut [WithExplanation<Option<TradeCycle>>],
-        max_amount: Decimal,
+        max_amount: Amount,
         now: DateTime,
     ) -> Result<()> {
         if slots.len() != estimating.len() {
@@ -350,7 +470,7 @@ impl DispositionExecutor {
         &self,
         new_estimating: &Option<TradeCycle>,
         price_slot: &PriceSlot,
-        max_amount: Decimal,
+        max_amount: Amount,
         now: DateTime,
         explanation: &mut Explanation,
     ) -> Result<()> {
@@ -416,13 +536,26 @@ impl DispositionExecutor {
         ));
 
         let desired_amount = new_estimating_disposition.order.amount;
-        if new_estimating_disposition.order.price == composite_order_ref.price {
+        if is_same_price(
+            new_estimating_disposition.order.price,
+            composite_order_ref.price,
+            self.allowed_price_deviation_rate,
+        ) {
             explanation.add_reason(format!(
-                "New price == old price ({})",
-                composite_order_ref.price
+                "New price ({}) == old price ({}) with allowed deviation",
+                new_estimating_disposition.order.price, composite_order_ref.price
             ));
 
             let remaining_amount = composite_order_ref.remaining_amount();
+            if !composite_order_ref.orders.is_empty()
+                && is_same_amount(desired_amount, remaining_amount)
+            {
+                // requote of the same order shouldn't lose queue position because of cancel-replace
+                let msg = format!("Skipped requote because existing orders have the same price and amount ({remaining_amount}) with allowed deviation");
+                drop(composite_order_ref);
+                return log_trace(msg, explanation);
+            }
+
             if remaining_amount >= desired_amount {
                 let desired_amount_with_allowed_deviation =
                     desired_amount * (dec!(1) + ALLOWED_AMOUNT_DEVIATION_RATE);
@@ -476,14 +609,10 @@ impl DispositionExecutor {
                     explanation,
                 )?;
             } else {
-                explanation.add_reason("Cancelling existing orders");
+                explanation.add_reason("Replacing existing orders");
 
                 drop(composite_order_ref);
-                self.start_cancelling_all_orders(
-                    "needed order recreation",
-                    &mut price_slot.order.borrow_mut(),
-                    explanation,
-                );
+                self.start_replacing_orders(price_slot, new_estimating, now, explanation)?;
             }
         }
 
@@ -550,6 +679,10 @@ impl DispositionExecutor {
         let cancellation_token = self.cancellation_token.clone();
 
         let action = async move {
+            exchange
+                .wait_min_order_lifetime(&order, cancellation_token.clone())
+                .await;
+
             log::trace!("Begin wait_cancel_order {client_order_id}");
             exchange
                 .wait_cancel_order(order, Some(request_group_id), false, cancellation_token)
@@ -580,10 +713,10 @@ impl DispositionExecutor {
 
     fn try_create_order(
         &self,
-        desired_amount: Decimal,
+        desired_amount: Amount,
         price_slot: &PriceSlot,
         new_estimating: &TradeCycle,
-        max_amount: Decimal,
+        max_amount: Amount,
         now: DateTime,
         explanation: &mut Explanation,
     ) -> Result<()> {
@@ -616,8 +749,84 @@ impl DispositionExecutor {
             );
         }
 
+        let Some((requests_group_id, reservation_id)) = self.try_reserve_order(
+            new_disposition.side(),
+            new_disposition.price(),
+            new_order_amount,
+            explanation,
+        )?
+        else {
+            return Ok(());
+        };
+
         let new_client_order_id = ClientOrderId::unique_id();
 
+        *price_slot.estimating.borrow_mut() = Some(Box::new(new_estimating.clone()));
+
+        let order_header = OrderHeader::with_user_order(
+            new_client_order_id.clone(),
+            self.exchange_account_id,
+            self.symbol.currency_pair(),
+            new_disposition.side(),
+            new_order_amount,
+            UserOrder::maker_only(new_disposition.price()),
+            Some(reservation_id),
+            None,
+            new_estimating.strategy_name.clone(),
+        );
+
+        let exchange = self.exchange();
+
+        let new_order = exchange.add_initial_order(&order_header, now);
+
+        price_slot.add_order(
+            new_disposition.side(),
+            new_disposition.price(),
+            new_order,
+            requests_group_id,
+        );
+
+        explanation.add_reason(format!("Creating order {new_client_order_id}"));
+
+        self.cancellation_token.error_if_cancellation_requested()?;
+
+        {
+            let new_client_order_id = new_client_order_id.clone();
+            let cancellation_token = self.cancellation_token.clone();
+
+            let action = async move {
+                log::trace!("Begin create_order {new_client_order_id}");
+
+                exchange
+                    .create_order(&order_header, Some(requests_group_id), cancellation_token)
+                    .await?;
+
+                log::trace!("Finished create_order {new_client_order_id}");
+
+                Ok(())
+            };
+
+            spawn_future(
+                "create_order in blocking try_create_order",
+                SpawnFutureFlags::empty(),
+                action,
+            );
+        }
+
+        log::trace!("Begin try_create_order {new_client_order_id}");
+
+        Ok(())
+    }
+
+    /// Reserves requests group and balance for new order. Nothing is left reserved if any of
+    /// reservations can't be made
+    fn try_reserve_order(
+        &self,
+        side: OrderSide,
+        price: Price,
+        amount: Amount,
+        explanation: &mut Explanation,
+    ) -> Result<Option<(RequestGroupId, ReservationId)>> {
         let requests_group_id = self.engine_ctx.timeout_manager.try_reserve_group(
             self.exchange_account_id,
             GROUP_REQUESTS_COUNT,
@@ -626,10 +835,11 @@ impl DispositionExecutor {
 
         let requests_group_id = match requests_group_id {
             None => {
-                return log_trace(
-                    "Finished `try_create_order` because can't reserve reservation group",
+                log_trace(
+                    "Order isn't created because can't reserve reservation group",
                     explanation,
-                )
+                )?;
+                return Ok(None);
             }
             Some(v) => v,
         };
@@ -638,9 +848,9 @@ impl DispositionExecutor {
             self.strategy.configuration_descriptor(),
             self.exchange_account_id,
             self.symbol.clone(),
-            new_disposition.side(),
-            new_disposition.price(),
-            new_order_amount,
+            side,
+            price,
+            amount,
         );
 
         let reservation_id;
@@ -649,7 +859,7 @@ impl DispositionExecutor {
 
             // This expect can happened if try_reserve() sets the explanation to None
             let explanation_err_msg =
-                "DispositionExecutor::try_create_order(): Explanation should be non None here";
+                "DispositionExecutor::try_reserve_order(): Explanation should be non None here";
 
             reservation_id = match self
                 .engine_ctx
@@ -663,9 +873,11 @@ impl DispositionExecutor {
                         .timeout_manager
                         .remove_group(self.exchange_account_id, requests_group_id);
 
-                    return log_trace(format!("Finished try_create_order because can't reserve balance {new_order_amount}"),
+                    log_trace(
+                        format!("Order isn't created because can't reserve balance {amount}"),
                         &mut explanation.expect(explanation_err_msg),
-                    );
+                    )?;
+                    return Ok(None);
                 }
             };
 
@@ -677,86 +889,177 @@ impl DispositionExecutor {
             RequestType::CreateOrder,
             Some(requests_group_id),
         ) {
-            self.engine_ctx
-                .balance_manager
-                .lock()
-                .unreserve_rest(
-                    reservation_id,
-                )
-                .with_expect(|| format!("DispositionExecutor::try_create_order() failed to unreserve_rest for: {reservation_id:?}"));
-
-            let _ = self
-                .engine_ctx
-                .timeout_manager
-                .remove_group(self.exchange_account_id, requests_group_id);
+            self.release_order_reservation(requests_group_id, reservation_id);
 
-            return log_trace(
-                "Finished `try_create_order` because can't reserve requests",
+            log_trace(
+                "Order isn't created because can't reserve requests",
                 explanation,
-            );
+            )?;
+            return Ok(None);
         }
 
-        *price_slot.estimating.borrow_mut() = Some(Box::new(new_estimating.clone()));
+        Ok(Some((requests_group_id, reservation_id)))
+    }
 
-        let order_header = OrderHeader::with_user_order(
-            new_client_order_id.clone(),
-            self.exchange_account_id,
-            self.symbol.currency_pair(),
-            new_disposition.side(),
-            new_order_amount,
-            UserOrder::maker_only(new_disposition.price()),
-            Some(reservation_id),
-            None,
-            new_estimating.strategy_name.clone(),
-        );
+    fn release_order_reservation(
+        &self,
+        requests_group_id: RequestGroupId,
+        reservation_id: ReservationId,
+    ) {
+        self.engine_ctx
+            .balance_manager
+            .lock()
+            .unreserve_rest(reservation_id)
+            .with_expect(|| format!("DispositionExecutor::release_order_reservation() failed to unreserve_rest for: {reservation_id:?}"));
+
+        let _ = self
+            .engine_ctx
+            .timeout_manager
+            .remove_group(self.exchange_account_id, requests_group_id);
+    }
+
+    /// Replaces orders of price slot by orders with new price, so new orders are placed
+    /// right after original ones are cancelled. Orders which can't be replaced are just
+    /// cancelled and price slot is filled again after that
+    fn start_replacing_orders(
+        &self,
+        price_slot: &PriceSlot,
+        new_estimating: &TradeCycle,
+        now: DateTime,
+        explanation: &mut Explanation,
+    ) -> Result<()> {
+        let new_price = new_estimating.disposition.price();
+        let client_order_ids = price_slot
+            .order
+            .borrow()
+            .orders
+            .values()
+            .filter(|or| !or.is_cancellation_requested)
+            .map(|or| or.order.client_order_id())
+            .collect_vec();
 
         let exchange = self.exchange();
+        for client_order_id in client_order_ids {
+            let mut composite_order = price_slot.order.borrow_mut();
+            let Some(order_record) = composite_order.orders.get_mut(&client_order_id) else {
+                continue;
+            };
 
-        let new_order = exchange.orders.add_simple_initial(
-            &order_header,
-            now,
-            exchange.exchange_client.get_initial_extension_data(),
-        );
+            let order = order_record.order.clone();
+            let Some(price) = exchange.replacement_price(&order, new_price) else {
+                explanation.add_reason(format!(
+                    "Replacement of order {client_order_id} is skipped by min price change"
+                ));
+                continue;
+            };
 
-        price_slot.add_order(
-            new_disposition.side(),
-            new_disposition.price(),
-            new_order,
-            requests_group_id,
-        );
+            let replacement =
+                self.try_prepare_replacement(&order, price, new_estimating, now, explanation)?;
+            let Some((replacement, requests_group_id)) = replacement else {
+                self.cancel_order(order_record, explanation);
+                continue;
+            };
 
-        explanation.add_reason(format!("Creating order {new_client_order_id}"));
+            order_record.is_cancellation_requested = true;
+            order_record.is_replaced = true;
+            let cancel_requests_group_id = order_record.request_group_id;
 
-        self.cancellation_token.error_if_cancellation_requested()?;
+            composite_order.price = price;
+            composite_order.add_order_record(replacement.clone(), requests_group_id);
+            drop(composite_order);
 
-        {
-            let new_client_order_id = new_client_order_id.clone();
-            let cancellation_token = self.cancellation_token.clone();
+            *price_slot.estimating.borrow_mut() = Some(Box::new(new_estimating.clone()));
+            explanation.add_reason(format!(
+                "Replacing order {client_order_id} by order {}",
+                replacement.client_order_id()
+            ));
 
+            self.cancellation_token.error_if_cancellation_requested()?;
+
+            let exchange = exchange.clone();
+            let cancellation_token = self.cancellation_token.clone();
             let action = async move {
-                log::trace!("Begin create_order {new_client_order_id}");
+                exchange
+                    .wait_min_order_lifetime(&order, cancellation_token.clone())
+                    .await;
 
+                log::trace!("Begin replace_order {client_order_id}");
                 exchange
-                    .create_order(&order_header, Some(requests_group_id), cancellation_token)
+                    .replace_order(
+                        &order,
+                        Some(cancel_requests_group_id),
+                        &replacement,
+                        Some(requests_group_id),
+                        cancellation_token,
+                    )
                     .await?;
-
-                log::trace!("Finished create_order {new_client_order_id}");
+                log::trace!("Finished replace_order {client_order_id}");
 
                 Ok(())
             };
-
             spawn_future(
-                "create_order in blocking try_create_order",
+                "Start replace_order from DispositionExecutor::start_replacing_orders()",
                 SpawnFutureFlags::empty(),
                 action,
             );
         }
 
-        log::trace!("Begin try_create_order {new_client_order_id}");
-
         Ok(())
     }
 
+    /// Registers replacement of order with its own requests group and balance reservation
+    /// for remaining amount of original order
+    fn try_prepare_replacement(
+        &self,
+        order: &OrderRef,
+        price: Price,
+        new_estimating: &TradeCycle,
+        now: DateTime,
+        explanation: &mut Explanation,
+    ) -> Result<Option<(OrderRef, RequestGroupId)>> {
+        let client_order_id = order.client_order_id();
+
+        let found = self.find_new_order_crossing_existing_orders(price, order.side());
+        if let Some(crossed_order) = found {
+            log_trace(format!("Order {client_order_id} isn't replaced because there is order {} with price {} that crossing new price {price}", crossed_order.client_order_id(), crossed_order.price()), explanation)?;
+            return Ok(None);
+        }
+
+        let amount = order.amount() - order.filled_amount();
+        if let Err(reason) =
+            is_enough_amount_and_cost(&new_estimating.disposition, amount, true, &self.symbol)
+        {
+            log_trace(
+                format!("Order {client_order_id} isn't replaced by reason: {reason}"),
+                explanation,
+            )?;
+            return Ok(None);
+        }
+
+        let Some((requests_group_id, reservation_id)) =
+            self.try_reserve_order(order.side(), price, amount, explanation)?
+        else {
+            return Ok(None);
+        };
+
+        let header = order.replacement_header(
+            ClientOrderId::unique_id(),
+            price,
+            Some(amount),
+            Some(reservation_id),
+        );
+        let header = match header {
+            Ok(header) => header,
+            Err(err) => {
+                self.release_order_reservation(requests_group_id, reservation_id);
+                return Err(err);
+            }
+        };
+
+        let replacement = self.exchange().add_initial_order(&header, now);
+        Ok(Some((replacement, requests_group_id)))
+    }
+
     fn find_new_order_crossing_existing_orders(
         &self,
         new_order_price: Price,
@@ -786,15 +1089,15 @@ impl DispositionExecutor {
         &self,
         _market_account_id: MarketAccountId,
         side: OrderSide,
-        desired_amount: Decimal,
-        max_amount: Decimal,
+        desired_amount: Amount,
+        max_amount: Amount,
         explanation: &mut Explanation,
-    ) -> Decimal {
+    ) -> Amount {
         let total_remaining_amount = self.orders_state.by_side[side].calc_total_remaining_amount();
         // TODO is needed high priority amount?
-        let high_priority_amount = dec!(0);
+        let high_priority_amount = Amount::ZERO;
         let balance_quota = max_amount - total_remaining_amount;
-        let new_amount = desired_amount.min(balance_quota).max(dec!(0)) - high_priority_amount;
+        let new_amount = desired_amount.min(balance_quota).max(Amount::ZERO) - high_priority_amount;
 
         explanation.add_reason(format!("max_amount {max_amount} total_remaining_amount {total_remaining_amount} high_priority_amount {high_priority_amount} balance_quota {balance_quota} new_order_amount {new_amount}"));
 
@@ -881,6 +1184,24 @@ impl DispositionExecutor {
             .clone()
     }
 
+    /// Market data older than max market data age is discarded, so strategy doesn't react on
+    /// prices which became stale while events were queued
+    fn is_stale_market_data(&self, event: &ExchangeEvent, now: DateTime) -> bool {
+        let Some(max_age_ms) = self.engine_ctx.core_settings.max_market_data_age_ms else {
+            return false;
+        };
+        let Some(market_data_time) = event.market_data_time() else {
+            return false;
+        };
+
+        if now - market_data_time <= Duration::milliseconds(max_age_ms as i64) {
+            return false;
+        }
+
+        self.statistics.register_discarded_stale_event();
+        true
+    }
+
     fn prepare_estimate_trading_context(&self, event: &ExchangeEvent, now: DateTime) -> bool {
         let event_time = match event {
             ExchangeEvent::OrderBookEvent(order_book_event) => order_book_event.creation_time,
@@ -935,13 +1256,13 @@ fn get_cancelling_orders<'a>(
 
     let mut cancelling_orders = Vec::with_capacity(sorted_order_records.len());
 
-    let mut sum = dec!(0);
+    let mut sum = Amount::ZERO;
 
     for record in sorted_order_records {
         let order = &mut record.order;
 
         let remaining_order_amount = order.fn_ref(|x| match x.is_finished() {
-            true => dec!(0),
+            true => Amount::ZERO,
             false => order.amount() - x.filled_amount(),
         });
 
@@ -959,10 +1280,25 @@ fn get_cancelling_orders<'a>(
     cancelling_orders
 }
 
+fn is_same_price(new_price: Price, old_price: Price, allowed_deviation_rate: Decimal) -> bool {
+    (new_price - old_price).abs() <= old_price.abs() * allowed_deviation_rate
+}
+
+fn is_same_amount(desired_amount: Amount, remaining_amount: Amount) -> bool {
+    (desired_amount - remaining_amount).abs() <= desired_amount * ALLOWED_AMOUNT_DEVIATION_RATE
+}
+
 fn now() -> DateTime {
     Utc::now()
 }
 
+async fn sleep_until(time: Option<DateTime>) {
+    match time {
+        None => std::future::pending().await,
+        Some(time) => tokio::time::sleep((time - now()).to_std().unwrap_or_default()).await,
+    }
+}
+
 #[inline(always)]
 fn log_trace(msg: impl AsRef<str>, explanation: &mut Explanation) -> Result<()> {
     let msg = msg.as_ref();
@@ -971,3 +1307,56 @@ fn log_trace(msg: impl AsRef<str>, explanation: &mut Explanation) -> Result<()>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::equal(price!(100), price!(100), true)]
+    #[case::within_deviation(price!(100.0005), price!(100), true)]
+    #[case::out_of_deviation(price!(100.01), price!(100), false)]
+    #[case::lower_out_of_deviation(price!(99.99), price!(100), false)]
+    fn same_price_with_allowed_deviation(
+        #[case] new_price: Price,
+        #[case] old_price: Price,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            is_same_price(new_price, old_price, DEFAULT_ALLOWED_PRICE_DEVIATION_RATE),
+            expected
+        );
+    }
+
+    #[test]
+    fn same_price_with_configured_deviation() {
+        let allowed_deviation_rate = dec!(0.001);
+
+        assert!(is_same_price(
+            price!(100.05),
+            price!(100),
+            allowed_deviation_rate
+        ));
+        assert!(!is_same_price(
+            price!(100.2),
+            price!(100),
+            allowed_deviation_rate
+        ));
+    }
+
+    #[rstest]
+    #[case::equal(amount!(10), amount!(10), true)]
+    #[case::slightly_more(amount!(10), amount!(10.005), true)]
+    #[case::slightly_less(amount!(10), amount!(9.995), true)]
+    #[case::less(amount!(10), amount!(9), false)]
+    #[case::more(amount!(10), amount!(11), false)]
+    fn same_amount_with_allowed_deviation(
+        #[case] desired_amount: Amount,
+        #[case] remaining_amount: Amount,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(is_same_amount(desired_amount, remaining_amount), expected);
+    }
+}
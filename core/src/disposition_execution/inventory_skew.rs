@@ -0,0 +1,80 @@
+use mmb_domain::order::snapshot::{Amount, Price};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct InventorySkewSettings {
+    /// Shift of quotes in half spreads when inventory reaches max inventory.
+    /// 0 disables skew, 1 moves quotes by half of spread at max inventory
+    pub aggressiveness: Decimal,
+}
+
+/// Calculates bid and ask prices around `mid_price` shifted against current inventory to
+/// encourage its mean-reversion to flat position: long inventory moves both quotes down
+/// (cheaper ask to sell, lower bid to buy less), short inventory moves them up.
+/// Inventory is normalized by `max_inventory` and clamped to [-1, 1].
+/// Returned prices aren't rounded to symbol precision.
+/// `LocalSnapshotsService::skewed_quotes` calculates quotes around middle price of market
+pub fn skewed_quotes_around(
+    mid_price: Price,
+    base_spread: Price,
+    inventory: Amount,
+    max_inventory: Amount,
+    settings: &InventorySkewSettings,
+) -> (Price, Price) {
+    let half_spread = base_spread * dec!(0.5);
+
+    let inventory_ratio = match max_inventory.is_zero() {
+        true => Decimal::ZERO,
+        false => (inventory / max_inventory.abs()).clamp(dec!(-1), dec!(1)),
+    };
+
+    let reservation_price = mid_price - inventory_ratio * settings.aggressiveness * half_spread;
+
+    let bid = reservation_price - half_spread;
+    let ask = reservation_price + half_spread;
+
+    (bid, ask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::flat(amount!(0), price!(99), price!(101))]
+    #[case::half_long(amount!(5), price!(98.5), price!(100.5))]
+    #[case::max_long(amount!(10), price!(98), price!(100))]
+    #[case::over_max_long(amount!(20), price!(98), price!(100))]
+    #[case::max_short(amount!(-10), price!(100), price!(102))]
+    fn skew_quotes_by_inventory(
+        #[case] inventory: Amount,
+        #[case] expected_bid: Price,
+        #[case] expected_ask: Price,
+    ) {
+        let settings = InventorySkewSettings {
+            aggressiveness: dec!(1),
+        };
+
+        let (bid, ask) =
+            skewed_quotes_around(price!(100), price!(2), inventory, amount!(10), &settings);
+
+        assert_eq!(bid, expected_bid);
+        assert_eq!(ask, expected_ask);
+    }
+
+    #[test]
+    fn without_skew() {
+        let settings = InventorySkewSettings {
+            aggressiveness: dec!(0),
+        };
+
+        let (bid, ask) =
+            skewed_quotes_around(price!(100), price!(2), amount!(10), amount!(10), &settings);
+
+        assert_eq!((bid, ask), (price!(99), price!(101)));
+    }
+}
@@ -1,4 +1,8 @@
+pub mod amount_quantization;
 pub mod executor;
+pub mod inventory_skew;
+pub mod order_book_throttle;
+pub mod price_improvement;
 pub mod strategy;
 pub mod trade_limit;
 mod trading_context_calculation;
@@ -11,8 +15,6 @@ use mmb_domain::market::{CurrencyPair, ExchangeAccountId, ExchangeId, MarketAcco
 use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::snapshot::{Amount, Price};
 use mmb_domain::order::snapshot::{ClientOrderId, OrderRole, OrderSide};
-use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
@@ -119,7 +121,7 @@ pub struct TradingContextBySide {
 impl TradingContextBySide {
     pub fn empty(slots_count: usize, explanation: Explanation) -> Self {
         TradingContextBySide {
-            max_amount: dec!(0),
+            max_amount: Amount::ZERO,
             estimating: (0..slots_count)
                 .map(|_| WithExplanation {
                     value: None,
@@ -166,7 +168,7 @@ fn to_price_level_explanation(
         .value
         .as_ref()
         .map(|x| x.disposition.order)
-        .unwrap_or_else(|| SmallOrder::new(dec!(0), dec!(0)));
+        .unwrap_or_else(|| SmallOrder::new(Price::ZERO, Amount::ZERO));
 
     PriceLevelExplanation {
         mode_name: "Disposition".to_string(),
@@ -201,6 +203,8 @@ impl Display for PriceSlotId {
 pub struct OrderRecord {
     pub order: OrderRef,
     pub is_cancellation_requested: bool,
+    /// Order is cancelled to be replaced, so its remaining amount is carried by replacement
+    pub is_replaced: bool,
     pub request_group_id: RequestGroupId,
 }
 
@@ -209,6 +213,7 @@ impl OrderRecord {
         OrderRecord {
             order,
             is_cancellation_requested: false,
+            is_replaced: false,
             request_group_id,
         }
     }
@@ -219,7 +224,7 @@ pub struct CompositeOrder {
     pub orders: HashMap<ClientOrderId, OrderRecord>,
 
     /// Last estimated target price
-    pub price: Decimal,
+    pub price: Price,
 
     pub side: OrderSide,
 }
@@ -228,14 +233,15 @@ impl CompositeOrder {
     pub fn new(side: OrderSide) -> Self {
         CompositeOrder {
             side,
-            price: dec!(0),
+            price: Price::ZERO,
             orders: Default::default(),
         }
     }
 
-    pub fn remaining_amount(&self) -> Decimal {
+    pub fn remaining_amount(&self) -> Amount {
         self.orders
             .iter()
+            .filter(|(_, or)| !or.is_replaced)
             .filter_map(|(_, or)| {
                 let order = &or.order;
                 order.fn_ref(|x| match !x.is_finished() {
@@ -261,6 +267,34 @@ impl CompositeOrder {
         }
     }
 
+    /// Keep record of order under its regenerated client order id
+    pub fn rekey_order_record(
+        &mut self,
+        previous_client_order_id: &ClientOrderId,
+        order: OrderRef,
+    ) {
+        match self.orders.remove(previous_client_order_id) {
+            None => log::error!(
+                "Can't find order {} for rekeying in CompositeOrder of DispositionExecutor state",
+                previous_client_order_id
+            ),
+            Some(order_record) => {
+                log::info!(
+                    "Order {} is rekeyed to {} in state of DispositionExecutor",
+                    previous_client_order_id,
+                    order.client_order_id()
+                );
+                let _ = self.orders.insert(
+                    order.client_order_id(),
+                    OrderRecord {
+                        order,
+                        ..order_record
+                    },
+                );
+            }
+        }
+    }
+
     pub fn remove_order(&mut self, order: &OrderRef) {
         let client_order_id = order.client_order_id();
         match self.orders.remove(&client_order_id) {
@@ -292,13 +326,6 @@ impl PriceSlot {
         }
     }
 
-    fn contains(&self, order: &OrderRef) -> bool {
-        self.order
-            .borrow()
-            .orders
-            .contains_key(&order.client_order_id())
-    }
-
     fn remove_order(&self, order: &OrderRef) {
         self.order.borrow_mut().remove_order(order)
     }
@@ -335,7 +362,7 @@ impl OrdersStateBySide {
         }
     }
 
-    pub fn calc_total_remaining_amount(&self) -> Decimal {
+    pub fn calc_total_remaining_amount(&self) -> Amount {
         self.slots
             .iter()
             .map(|x| x.order.borrow().remaining_amount())
@@ -347,7 +374,15 @@ impl OrdersStateBySide {
     }
 
     pub(crate) fn find_price_slot(&self, order: &OrderRef) -> Option<&PriceSlot> {
-        self.traverse_price_slots().find(|x| x.contains(order))
+        self.find_price_slot_by_client_order_id(&order.client_order_id())
+    }
+
+    pub(crate) fn find_price_slot_by_client_order_id(
+        &self,
+        client_order_id: &ClientOrderId,
+    ) -> Option<&PriceSlot> {
+        self.traverse_price_slots()
+            .find(|x| x.order.borrow().orders.contains_key(client_order_id))
     }
 }
 
@@ -0,0 +1,169 @@
+use chrono::Duration;
+use mmb_domain::market::MarketAccountId;
+use mmb_domain::order_book::event::OrderBookEvent;
+use mmb_utils::DateTime;
+use std::collections::HashMap;
+
+/// Limits rate of strategy recalculations by order book updates of each currency pair.
+/// Updates received within min interval after the last recalculation are coalesced, so
+/// strategy is notified by the latest of them when the interval elapses
+pub struct OrderBookUpdateThrottle {
+    min_interval: Duration,
+    last_notification_time: HashMap<MarketAccountId, DateTime>,
+    pending_updates: HashMap<MarketAccountId, OrderBookEvent>,
+}
+
+impl OrderBookUpdateThrottle {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_notification_time: HashMap::new(),
+            pending_updates: HashMap::new(),
+        }
+    }
+
+    /// Returns true if strategy should be notified by update right now. Otherwise update
+    /// replaces pending one of its currency pair
+    pub fn on_update(&mut self, event: &OrderBookEvent, now: DateTime) -> bool {
+        let market_account_id = event.market_account_id();
+        let is_throttled = self
+            .last_notification_time
+            .get(&market_account_id)
+            .map_or(false, |&last_time| now - last_time < self.min_interval);
+
+        if is_throttled {
+            let _ = self
+                .pending_updates
+                .insert(market_account_id, event.clone());
+            return false;
+        }
+
+        let _ = self.last_notification_time.insert(market_account_id, now);
+        let _ = self.pending_updates.remove(&market_account_id);
+        true
+    }
+
+    /// Time when the earliest pending update should be delivered to strategy
+    pub fn next_flush_time(&self) -> Option<DateTime> {
+        self.pending_updates
+            .keys()
+            .filter_map(|x| self.last_notification_time.get(x))
+            .min()
+            .map(|&last_time| last_time + self.min_interval)
+    }
+
+    /// Takes pending updates which min interval is elapsed for
+    pub fn take_due_updates(&mut self, now: DateTime) -> Vec<OrderBookEvent> {
+        let due_market_account_ids: Vec<MarketAccountId> = self
+            .pending_updates
+            .keys()
+            .filter(|x| {
+                self.last_notification_time
+                    .get(x)
+                    .map_or(true, |&last_time| now - last_time >= self.min_interval)
+            })
+            .copied()
+            .collect();
+
+        due_market_account_ids
+            .into_iter()
+            .filter_map(|market_account_id| {
+                let _ = self.last_notification_time.insert(market_account_id, now);
+                self.pending_updates.remove(&market_account_id)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+    use mmb_domain::order_book::event::EventType;
+    use mmb_domain::order_book_data;
+    use mmb_domain::price;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+
+    fn order_book_event(currency_pair: CurrencyPair, ask_price: Decimal) -> OrderBookEvent {
+        let data = order_book_data![
+            ask_price => dec!(1),
+            ;
+            dec!(1) => dec!(1),
+        ];
+
+        OrderBookEvent::new(
+            Utc::now(),
+            ExchangeAccountId::new("Binance", 0),
+            currency_pair,
+            "".to_owned(),
+            EventType::Update,
+            Arc::new(data),
+        )
+    }
+
+    fn btc_usdt() -> CurrencyPair {
+        CurrencyPair::from_codes("btc".into(), "usdt".into())
+    }
+
+    fn eth_usdt() -> CurrencyPair {
+        CurrencyPair::from_codes("eth".into(), "usdt".into())
+    }
+
+    #[test]
+    fn coalesce_updates_within_interval_into_latest() {
+        let mut throttle = OrderBookUpdateThrottle::new(Duration::milliseconds(100));
+        let now = Utc::now();
+
+        assert!(throttle.on_update(&order_book_event(btc_usdt(), dec!(10)), now));
+        assert!(!throttle.on_update(
+            &order_book_event(btc_usdt(), dec!(11)),
+            now + Duration::milliseconds(30)
+        ));
+        assert!(!throttle.on_update(
+            &order_book_event(btc_usdt(), dec!(12)),
+            now + Duration::milliseconds(60)
+        ));
+
+        let flush_time = now + Duration::milliseconds(100);
+        assert_eq!(throttle.next_flush_time(), Some(flush_time));
+        assert!(throttle
+            .take_due_updates(flush_time - Duration::milliseconds(1))
+            .is_empty());
+
+        let due_updates = throttle.take_due_updates(flush_time);
+        assert_eq!(due_updates.len(), 1);
+        assert_eq!(due_updates[0].data.asks.keys().next(), Some(&price!(12)));
+        assert_eq!(throttle.next_flush_time(), None);
+
+        // interval starts over from delivery of pending update
+        assert!(!throttle.on_update(
+            &order_book_event(btc_usdt(), dec!(13)),
+            flush_time + Duration::milliseconds(50)
+        ));
+    }
+
+    #[test]
+    fn throttle_each_currency_pair_separately() {
+        let mut throttle = OrderBookUpdateThrottle::new(Duration::milliseconds(100));
+        let now = Utc::now();
+
+        assert!(throttle.on_update(&order_book_event(btc_usdt(), dec!(10)), now));
+        assert!(throttle.on_update(
+            &order_book_event(eth_usdt(), dec!(20)),
+            now + Duration::milliseconds(10)
+        ));
+        assert!(!throttle.on_update(
+            &order_book_event(btc_usdt(), dec!(11)),
+            now + Duration::milliseconds(20)
+        ));
+
+        assert!(throttle.on_update(
+            &order_book_event(btc_usdt(), dec!(12)),
+            now + Duration::milliseconds(100)
+        ));
+        assert_eq!(throttle.next_flush_time(), None);
+    }
+}
@@ -0,0 +1,188 @@
+use mmb_domain::exchanges::symbol::{Round, Symbol};
+use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::snapshot::{OrderSide, Price, PriceByOrderSide};
+use mmb_domain::order_book::local_order_book_snapshot::LocalOrderBookSnapshot;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Number of ticks to place passive orders inside the best price of their side.
+/// 0 places orders at the best price
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PriceImprovementTicks {
+    #[serde(default)]
+    pub buy: u32,
+    #[serde(default)]
+    pub sell: u32,
+}
+
+impl PriceImprovementTicks {
+    pub fn by_side(&self, side: OrderSide) -> u32 {
+        match side {
+            OrderSide::Buy => self.buy,
+            OrderSide::Sell => self.sell,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PriceImprovementSettings {
+    /// Ticks for currency pairs without own settings
+    #[serde(default)]
+    pub default: PriceImprovementTicks,
+    #[serde(default)]
+    pub by_currency_pair: HashMap<CurrencyPair, PriceImprovementTicks>,
+}
+
+impl PriceImprovementSettings {
+    pub fn ticks(&self, currency_pair: CurrencyPair, side: OrderSide) -> u32 {
+        self.by_currency_pair
+            .get(&currency_pair)
+            .unwrap_or(&self.default)
+            .by_side(side)
+    }
+
+    /// Price of passive order for symbol by current top of order book.
+    /// Returns `None` if there are no orders on the side of order in order book
+    pub fn passive_price(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        snapshot: &LocalOrderBookSnapshot,
+    ) -> Option<Price> {
+        let ticks = self.ticks(symbol.currency_pair(), side);
+        improved_price(symbol, side, ticks, &snapshot.get_top_prices())
+    }
+}
+
+/// Calculates price `ticks` ticks inside the best price of order side (top bid for buy,
+/// top ask for sell) rounded to symbol tick towards the passive side.
+/// Price is limited to one tick before the best price of the opposite side, so order stays
+/// passive when spread is narrower than requested improvement.
+/// Returns `None` if there is no best price of order side
+pub fn improved_price(
+    symbol: &Symbol,
+    side: OrderSide,
+    ticks: u32,
+    top_prices: &PriceByOrderSide,
+) -> Option<Price> {
+    let tick = Price::new(symbol.price_precision.get_tick());
+    let improvement = tick * Decimal::from(ticks);
+
+    let price = match side {
+        OrderSide::Buy => {
+            let price = symbol.price_round(top_prices.top_bid? + improvement, Round::Floor);
+            match top_prices.top_ask {
+                Some(top_ask) => price.min(symbol.price_round(top_ask - tick, Round::Floor)),
+                None => price,
+            }
+        }
+        OrderSide::Sell => {
+            let price = symbol.price_round(top_prices.top_ask? - improvement, Round::Ceiling);
+            match top_prices.top_bid {
+                Some(top_bid) => price.max(symbol.price_round(top_bid + tick, Round::Ceiling)),
+                None => price,
+            }
+        }
+    };
+
+    Some(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::amount;
+    use mmb_domain::exchanges::symbol::Precision;
+    use mmb_domain::market::CurrencyCode;
+    use mmb_domain::price;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn symbol() -> Symbol {
+        let base: CurrencyCode = "BTC".into();
+        let quote: CurrencyCode = "USDT".into();
+
+        Symbol::new(
+            false,
+            base.as_str().into(),
+            base,
+            quote.as_str().into(),
+            quote,
+            None,
+            None,
+            None,
+            None,
+            None,
+            base,
+            None,
+            Precision::ByTick { tick: dec!(0.5) },
+            Precision::ByTick { tick: dec!(0.001) },
+        )
+    }
+
+    fn top_prices(top_bid: Price, top_ask: Price) -> PriceByOrderSide {
+        PriceByOrderSide::new(Some(top_bid), Some(top_ask))
+    }
+
+    #[rstest]
+    #[case::join_bid(OrderSide::Buy, 0, price!(100), price!(105), price!(100))]
+    #[case::improve_bid(OrderSide::Buy, 2, price!(100), price!(105), price!(101))]
+    #[case::bid_limited_by_ask(OrderSide::Buy, 20, price!(100), price!(105), price!(104.5))]
+    #[case::bid_with_tight_spread(OrderSide::Buy, 1, price!(100), price!(100.5), price!(100))]
+    #[case::off_tick_bid(OrderSide::Buy, 1, price!(100.2), price!(105), price!(100.5))]
+    #[case::join_ask(OrderSide::Sell, 0, price!(100), price!(105), price!(105))]
+    #[case::improve_ask(OrderSide::Sell, 2, price!(100), price!(105), price!(104))]
+    #[case::ask_limited_by_bid(OrderSide::Sell, 20, price!(100), price!(105), price!(100.5))]
+    #[case::off_tick_ask(OrderSide::Sell, 1, price!(100), price!(104.8), price!(104.5))]
+    fn improve_price_by_ticks(
+        #[case] side: OrderSide,
+        #[case] ticks: u32,
+        #[case] top_bid: Price,
+        #[case] top_ask: Price,
+        #[case] expected: Price,
+    ) {
+        let price = improved_price(&symbol(), side, ticks, &top_prices(top_bid, top_ask));
+
+        assert_eq!(price, Some(expected));
+    }
+
+    #[test]
+    fn no_price_without_own_side() {
+        let top_prices = PriceByOrderSide::new(None, Some(price!(105)));
+
+        assert_eq!(
+            improved_price(&symbol(), OrderSide::Buy, 1, &top_prices),
+            None
+        );
+        assert_eq!(
+            improved_price(&symbol(), OrderSide::Sell, 1, &top_prices),
+            Some(price!(104.5))
+        );
+    }
+
+    #[test]
+    fn passive_price_by_currency_pair_settings() {
+        let symbol = symbol();
+        let settings = PriceImprovementSettings {
+            default: PriceImprovementTicks { buy: 0, sell: 0 },
+            by_currency_pair: HashMap::from([(
+                symbol.currency_pair(),
+                PriceImprovementTicks { buy: 1, sell: 3 },
+            )]),
+        };
+
+        let snapshot = LocalOrderBookSnapshot::new(
+            [(price!(105), amount!(1))].into_iter().collect(),
+            [(price!(100), amount!(1))].into_iter().collect(),
+            Utc::now(),
+        );
+
+        let buy_price = settings.passive_price(&symbol, OrderSide::Buy, &snapshot);
+        let sell_price = settings.passive_price(&symbol, OrderSide::Sell, &snapshot);
+
+        assert_eq!(buy_price, Some(price!(100.5)));
+        assert_eq!(sell_price, Some(price!(103.5)));
+    }
+}
@@ -1,8 +1,11 @@
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::time::Duration;
 
 use anyhow::Result;
 use mmb_utils::DateTime;
 
+use crate::connectivity::StreamKind;
 use crate::disposition_execution::{PriceSlot, TradingContext};
 use crate::explanation::Explanation;
 use crate::order_book::local_snapshot_service::LocalSnapshotsService;
@@ -30,4 +33,23 @@ pub trait DispositionStrategy: Send + Sync + 'static {
     ) -> Result<()>;
 
     fn configuration_descriptor(&self) -> ConfigurationDescriptor;
+
+    /// Kinds of streams strategy needs for its currency pair, so streams of the rest kinds
+    /// aren't subscribed. All configured streams are required if not specified
+    fn required_streams(&self) -> Option<HashSet<StreamKind>> {
+        None
+    }
+
+    /// Min interval between recalculations of trading context by order book updates of the same
+    /// currency pair. Updates within the interval are coalesced, so the latest order book is
+    /// passed to strategy when the interval elapses. Every update is passed if not specified
+    fn order_book_update_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called on occurrence of schedule configured in core settings. Returns true if trading
+    /// context should be recalculated
+    fn on_scheduled(&mut self, _name: &str, _now: DateTime) -> bool {
+        false
+    }
 }
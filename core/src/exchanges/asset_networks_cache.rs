@@ -0,0 +1,88 @@
+use mmb_domain::exchanges::asset_network::NetworkInfo;
+use mmb_domain::market::CurrencyCode;
+use mmb_utils::DateTime;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub const DEFAULT_ASSET_NETWORKS_CACHE_TTL_SECS: u64 = 3600;
+
+pub type AssetNetworks = HashMap<CurrencyCode, Vec<NetworkInfo>>;
+
+/// Networks of all assets of exchange. They change rarely, so they are requested
+/// once per TTL only
+pub struct AssetNetworksCache {
+    ttl: chrono::Duration,
+    cached: Option<(DateTime, Arc<AssetNetworks>)>,
+}
+
+impl AssetNetworksCache {
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            ttl: chrono::Duration::seconds(ttl_secs as i64),
+            cached: None,
+        }
+    }
+
+    /// Networks received not earlier than TTL ago
+    pub fn get(&self, now: DateTime) -> Option<Arc<AssetNetworks>> {
+        self.cached
+            .as_ref()
+            .filter(|(received_time, _)| now - *received_time < self.ttl)
+            .map(|(_, networks)| networks.clone())
+    }
+
+    pub fn set(&mut self, networks: AssetNetworks, now: DateTime) -> Arc<AssetNetworks> {
+        let networks = Arc::new(networks);
+        self.cached = Some((now, networks.clone()));
+        networks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    use mmb_domain::amount;
+
+    fn networks() -> AssetNetworks {
+        HashMap::from([(
+            "btc".into(),
+            vec![NetworkInfo {
+                network: "BTC".to_owned(),
+                is_default: true,
+                deposit_enabled: true,
+                withdraw_enabled: true,
+                withdraw_fee: amount!(0.0002),
+                withdraw_min: amount!(0.001),
+                withdraw_max: amount!(0),
+                min_confirmations: 1,
+                unlock_confirmations: 2,
+            }],
+        )])
+    }
+
+    #[test]
+    fn return_cached_networks_within_ttl() {
+        let mut cache = AssetNetworksCache::new(60);
+        let now = Utc::now();
+        assert!(cache.get(now).is_none());
+
+        let _ = cache.set(networks(), now);
+
+        let cached = cache
+            .get(now + chrono::Duration::seconds(59))
+            .expect("in test");
+        assert_eq!(*cached, networks());
+    }
+
+    #[test]
+    fn expire_networks_after_ttl() {
+        let mut cache = AssetNetworksCache::new(60);
+        let now = Utc::now();
+
+        let _ = cache.set(networks(), now);
+
+        assert!(cache.get(now + chrono::Duration::seconds(60)).is_none());
+    }
+}
@@ -12,3 +12,7 @@ impl_block_reason!(CREATE_ORDER_INSUFFICIENT_FUNDS);
 impl_block_reason!(REST_RATE_LIMIT);
 impl_block_reason!(GRACEFUL_SHUTDOWN);
 impl_block_reason!(EXCHANGE_UNAVAILABLE);
+impl_block_reason!(EXCHANGE_MAINTENANCE);
+impl_block_reason!(SCHEDULED_FLATTEN);
+impl_block_reason!(REST_UNREACHABLE);
+impl_block_reason!(MAX_DRAWDOWN);
@@ -0,0 +1,92 @@
+use crate::settings::ClockSkewSettings;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// Last measured offset of local clock from exchange server clock
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ClockSkew {
+    /// Local time minus server time. `None` until the first successful measurement
+    pub offset_ms: Option<i64>,
+    pub is_exceeded: bool,
+}
+
+/// Exchange rejects signed requests with timestamp too far from its own clock, so while local
+/// clock is skewed by more than `max_offset_ms` orders are refused locally instead of being
+/// sent to be rejected. Refusal ends as soon as measured offset returns within bounds
+pub struct ClockSkewMonitor {
+    max_offset_ms: i64,
+    clock_skew: Mutex<ClockSkew>,
+}
+
+impl ClockSkewMonitor {
+    pub fn new(settings: &ClockSkewSettings) -> Self {
+        Self {
+            max_offset_ms: settings.max_offset_ms as i64,
+            clock_skew: Default::default(),
+        }
+    }
+
+    pub fn max_offset_ms(&self) -> i64 {
+        self.max_offset_ms
+    }
+
+    pub fn clock_skew(&self) -> ClockSkew {
+        *self.clock_skew.lock()
+    }
+
+    /// Save measured offset and return `true` if it crossed max offset in either direction
+    pub fn update(&self, offset_ms: i64) -> bool {
+        let is_exceeded = offset_ms.abs() > self.max_offset_ms;
+
+        let mut clock_skew = self.clock_skew.lock();
+        let is_changed = clock_skew.is_exceeded != is_exceeded;
+        *clock_skew = ClockSkew {
+            offset_ms: Some(offset_ms),
+            is_exceeded,
+        };
+
+        is_changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn monitor() -> ClockSkewMonitor {
+        ClockSkewMonitor::new(&ClockSkewSettings {
+            max_offset_ms: 1000,
+            check_interval_secs: 60,
+        })
+    }
+
+    #[rstest]
+    #[case::within_bounds(999, false)]
+    #[case::at_bound(1000, false)]
+    #[case::ahead_of_server(1001, true)]
+    #[case::behind_server(-1500, true)]
+    fn exceed_max_offset(#[case] offset_ms: i64, #[case] is_exceeded: bool) {
+        let monitor = monitor();
+
+        assert_eq!(monitor.update(offset_ms), is_exceeded);
+        assert_eq!(
+            monitor.clock_skew(),
+            ClockSkew {
+                offset_ms: Some(offset_ms),
+                is_exceeded,
+            }
+        );
+    }
+
+    #[test]
+    fn report_only_crossing_of_max_offset() {
+        let monitor = monitor();
+
+        assert!(monitor.update(2000));
+        assert!(!monitor.update(3000));
+        assert!(monitor.update(100));
+        assert!(!monitor.update(-100));
+        assert!(!monitor.clock_skew().is_exceeded);
+    }
+}
@@ -470,6 +470,17 @@ impl ExchangeBlocker {
             .is_empty()
     }
 
+    /// Reasons of active blocks of exchange account including manual pauses of trading
+    pub fn block_reasons(&self, exchange_account_id: ExchangeAccountId) -> Vec<BlockReason> {
+        self.blockers
+            .read()
+            .get(&exchange_account_id)
+            .expect(EXPECTED_EAI_SHOULD_BE_CREATED)
+            .keys()
+            .copied()
+            .collect()
+    }
+
     pub fn is_blocked_by_reason(
         &self,
         exchange_account_id: ExchangeAccountId,
@@ -0,0 +1,45 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use anyhow::{Context, Result};
+use mmb_domain::events::AggTrade;
+use mmb_domain::market::CurrencyPair;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
+
+impl Exchange {
+    /// Public trades of currency pair within time range aggregated by taker order and price.
+    /// Returns at most `limit` records if it is specified
+    pub async fn get_agg_trades(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+        limit: Option<u32>,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<AggTrade>> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetTrades,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .get_agg_trades(currency_pair, from, to, limit)
+            .await
+            .with_context(|| {
+                format!(
+                    "Aggregated trades aren't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get aggregated trades of {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })
+    }
+}
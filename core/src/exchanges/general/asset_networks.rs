@@ -0,0 +1,61 @@
+use crate::exchanges::asset_networks_cache::AssetNetworksCache;
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::misc::time::time_manager;
+use anyhow::{Context, Result};
+use mmb_domain::exchanges::asset_network::NetworkInfo;
+use mmb_domain::market::CurrencyCode;
+use mmb_utils::cancellation_token::CancellationToken;
+
+impl Exchange {
+    pub fn setup_asset_networks_cache_ttl(&self, ttl_secs: u64) {
+        *self.asset_networks_cache.lock() = AssetNetworksCache::new(ttl_secs);
+    }
+
+    /// Networks which asset can be deposited and withdrawn by with their fees and required
+    /// confirmations. Networks of all assets are requested at once and cached for TTL.
+    /// Returns empty list if asset isn't known by exchange
+    pub async fn get_asset_networks(
+        &self,
+        asset: CurrencyCode,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<NetworkInfo>> {
+        let cached = self.asset_networks_cache.lock().get(time_manager::now());
+        let networks = match cached {
+            Some(networks) => networks,
+            None => {
+                self.timeout_manager
+                    .reserve_when_available(
+                        self.exchange_account_id,
+                        RequestType::GetAssetNetworks,
+                        None,
+                        cancellation_token,
+                    )
+                    .await;
+
+                let networks = self
+                    .exchange_client
+                    .get_asset_networks()
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Asset networks aren't supported by exchange {}",
+                            self.exchange_account_id
+                        )
+                    })?
+                    .with_context(|| {
+                        format!(
+                            "Failed to get asset networks on {}",
+                            self.exchange_account_id
+                        )
+                    })?;
+
+                self.asset_networks_cache
+                    .lock()
+                    .set(networks, time_manager::now())
+            }
+        };
+
+        Ok(networks.get(&asset).cloned().unwrap_or_default())
+    }
+}
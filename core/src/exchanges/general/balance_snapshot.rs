@@ -0,0 +1,187 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::misc::time::time_manager;
+use itertools::Itertools;
+use mmb_domain::events::{
+    BalanceChange, BalanceChangedEvent, ExchangeBalancesAndPositions, ExchangeEvent,
+};
+use mmb_domain::market::CurrencyCode;
+use mmb_domain::order::snapshot::Amount;
+use mmb_utils::send_expected::SendExpectedByRef;
+use mmb_utils::DateTime;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Authoritative balances of exchange account polled by REST
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceSnapshot {
+    pub balances: HashMap<CurrencyCode, Amount>,
+    pub time: DateTime,
+}
+
+impl Exchange {
+    pub fn setup_balance_change_threshold(&self, threshold: Decimal) {
+        *self.balance_change_threshold.lock() = Some(threshold);
+    }
+
+    pub fn balance_snapshot(&self) -> Option<BalanceSnapshot> {
+        self.balance_snapshot.lock().clone()
+    }
+
+    pub fn last_balance_snapshot_time(&self) -> Option<DateTime> {
+        self.balance_snapshot.lock().as_ref().map(|x| x.time)
+    }
+
+    /// Caches polled balances and emits balance change event if any of them changed beyond
+    /// threshold. The first snapshot is a baseline of changes, so it emits nothing
+    pub(super) fn update_balance_snapshot(
+        &self,
+        balances_and_positions: &ExchangeBalancesAndPositions,
+    ) {
+        let now = time_manager::now();
+        let balances: HashMap<CurrencyCode, Amount> = balances_and_positions
+            .balances
+            .iter()
+            .map(|x| (x.currency_code, x.balance))
+            .collect();
+
+        let is_first_snapshot = self
+            .balance_snapshot
+            .lock()
+            .replace(BalanceSnapshot {
+                balances: balances.clone(),
+                time: now,
+            })
+            .is_none();
+
+        let mut reported_balances = self.reported_balances.lock();
+        if is_first_snapshot {
+            *reported_balances = balances;
+            return;
+        }
+
+        let threshold = *self.balance_change_threshold.lock();
+        let changes = reported_balances
+            .keys()
+            .chain(balances.keys())
+            .unique()
+            .filter_map(|&currency_code| {
+                let previous = reported_balances
+                    .get(&currency_code)
+                    .copied()
+                    .unwrap_or_default();
+                let current = balances.get(&currency_code).copied().unwrap_or_default();
+                is_changed_beyond(previous, current, threshold).then_some(BalanceChange {
+                    currency_code,
+                    previous,
+                    current,
+                })
+            })
+            .sorted_by_key(|x| x.currency_code.to_string())
+            .collect_vec();
+
+        if changes.is_empty() {
+            return;
+        }
+
+        for change in &changes {
+            let _ = reported_balances.insert(change.currency_code, change.current);
+        }
+        drop(reported_balances);
+
+        self.events_channel
+            .send_expected(ExchangeEvent::BalanceChanged(BalanceChangedEvent {
+                exchange_account_id: self.exchange_account_id,
+                changes,
+                snapshot_time: now,
+            }));
+    }
+}
+
+/// Threshold is relative to previous balance, so any change from zero balance exceeds it
+fn is_changed_beyond(previous: Amount, current: Amount, threshold: Option<Decimal>) -> bool {
+    let change = (current - previous).abs();
+    match threshold {
+        None => !change.is_zero(),
+        Some(threshold) => !change.is_zero() && change >= threshold * previous.abs(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use mmb_domain::amount;
+    use mmb_domain::events::ExchangeBalance;
+    use rust_decimal_macros::dec;
+    use tokio::sync::broadcast;
+
+    fn balances(btc: Amount, usdt: Amount) -> ExchangeBalancesAndPositions {
+        let balance = |currency_code: &str, balance| ExchangeBalance {
+            currency_code: currency_code.into(),
+            balance,
+        };
+
+        ExchangeBalancesAndPositions {
+            balances: vec![balance("btc", btc), balance("usdt", usdt)],
+            positions: None,
+        }
+    }
+
+    fn balance_changes(
+        events_receiver: &mut broadcast::Receiver<ExchangeEvent>,
+    ) -> Vec<Vec<BalanceChange>> {
+        std::iter::from_fn(|| events_receiver.try_recv().ok())
+            .filter_map(|x| match x {
+                ExchangeEvent::BalanceChanged(event) => Some(event.changes),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn emit_changes_beyond_threshold_only() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        exchange.setup_balance_change_threshold(dec!(0.01));
+
+        exchange.update_balance_snapshot(&balances(amount!(1), amount!(1000)));
+        assert!(balance_changes(&mut events_receiver).is_empty());
+        assert!(exchange.last_balance_snapshot_time().is_some());
+
+        // fee deductions
+        exchange.update_balance_snapshot(&balances(amount!(0.999), amount!(999)));
+        assert!(balance_changes(&mut events_receiver).is_empty());
+        assert_eq!(
+            exchange.balance_snapshot().expect("in test").balances[&CurrencyCode::from("btc")],
+            amount!(0.999)
+        );
+
+        // small changes are accumulated since the last event
+        exchange.update_balance_snapshot(&balances(amount!(0.99), amount!(998)));
+        assert_eq!(
+            balance_changes(&mut events_receiver),
+            vec![vec![BalanceChange {
+                currency_code: "btc".into(),
+                previous: amount!(1),
+                current: amount!(0.99),
+            }]]
+        );
+
+        exchange.update_balance_snapshot(&balances(amount!(0.985), amount!(998)));
+        assert!(balance_changes(&mut events_receiver).is_empty());
+    }
+
+    #[tokio::test]
+    async fn emit_every_change_without_threshold() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+
+        exchange.update_balance_snapshot(&balances(amount!(1), amount!(1000)));
+        exchange.update_balance_snapshot(&balances(amount!(1), amount!(1000)));
+        assert!(balance_changes(&mut events_receiver).is_empty());
+
+        exchange.update_balance_snapshot(&balances(amount!(1), amount!(999.99)));
+        let changes = balance_changes(&mut events_receiver);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0][0].currency_code, CurrencyCode::from("usdt"));
+    }
+}
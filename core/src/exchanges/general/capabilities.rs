@@ -0,0 +1,145 @@
+use crate::exchanges::traits::ExchangeError;
+use mmb_domain::market::ExchangeErrorType;
+use mmb_domain::order::snapshot::{OrderExecutionType, OrderHeader, OrderOptions, UserOrder};
+
+/// Trading operations which exchange client is able to perform.
+/// Core consults it before sending requests, so unsupported operations fail early
+/// with `ExchangeErrorType::Unsupported` instead of being rejected by exchange
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExchangeCapabilities {
+    pub market_orders: bool,
+    /// Limit orders which are rejected if they would be filled immediately
+    pub maker_only_orders: bool,
+    pub stop_loss_orders: bool,
+    pub trailing_stop_orders: bool,
+    /// One-cancels-the-other order pairs
+    pub oco_orders: bool,
+    /// Orders executed by exchange-side algorithms (TWAP, iceberg, etc.)
+    pub algo_orders: bool,
+    /// Several orders can be created or cancelled by single request
+    pub batch_orders: bool,
+    /// Orders which can only decrease current position
+    pub reduce_only_orders: bool,
+    /// Exchange limit of open orders per currency pair. Unlimited if `None`
+    pub max_open_orders_per_currency_pair: Option<usize>,
+    /// Limit orders which aren't maker only can be expired by exchange at specified time.
+    /// Otherwise good till date orders are cancelled by core on expiry
+    pub good_till_date_orders: bool,
+    /// Margin mode (cross or isolated) of futures currency pair can be switched
+    pub margin_modes: bool,
+}
+
+impl ExchangeCapabilities {
+    /// Limit orders are supported by all exchanges, so the rest of user orders are checked only.
+    /// Orders created by exchange itself aren't checked
+    pub fn check_order(&self, order_header: &OrderHeader) -> Result<(), ExchangeError> {
+        let user_order = match &order_header.options {
+            OrderOptions::User(user_order) => user_order,
+            _ => return Ok(()),
+        };
+
+        let (is_supported, order_kind) = match user_order {
+            UserOrder::Limit {
+                execution_type: OrderExecutionType::None,
+                ..
+            } => (true, "limit"),
+            UserOrder::Limit {
+                execution_type: OrderExecutionType::MakerOnly,
+                ..
+            } => (self.maker_only_orders, "maker only"),
+            UserOrder::Market => (self.market_orders, "market"),
+            UserOrder::StopLoss { .. } => (self.stop_loss_orders, "stop loss"),
+            UserOrder::TrailingStop { .. } => (self.trailing_stop_orders, "trailing stop"),
+        };
+
+        match is_supported {
+            true => Ok(()),
+            false => Err(ExchangeError::new(
+                ExchangeErrorType::Unsupported,
+                format!(
+                    "Order {} is rejected because {order_kind} orders aren't supported by exchange {}",
+                    order_header.client_order_id, order_header.exchange_account_id
+                ),
+                None,
+            )),
+        }
+    }
+
+    /// Whether good till date order should be cancelled by core because exchange can't expire it
+    pub fn should_emulate_expiration(&self, order_header: &OrderHeader) -> bool {
+        if order_header.time_in_force.expire_time().is_none() {
+            return false;
+        }
+
+        let is_expired_by_exchange = self.good_till_date_orders
+            && order_header.options.execution_type() == Some(OrderExecutionType::None);
+        !is_expired_by_exchange
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+    use mmb_domain::order::snapshot::{ClientOrderId, OrderSide, TimeInForce};
+    use rstest::rstest;
+
+    use mmb_domain::{amount, price};
+
+    fn order_header(user_order: UserOrder) -> OrderHeader {
+        OrderHeader::with_options(
+            ClientOrderId::unique_id(),
+            ExchangeAccountId::new("Binance", 0),
+            CurrencyPair::from_codes("eth".into(), "btc".into()),
+            OrderSide::Buy,
+            amount!(1),
+            OrderOptions::User(user_order),
+            None,
+            None,
+            "test".to_owned(),
+        )
+    }
+
+    #[rstest]
+    #[case(UserOrder::limit(price!(1)), true)]
+    #[case(UserOrder::maker_only(price!(1)), true)]
+    #[case(UserOrder::Market, false)]
+    #[case(UserOrder::StopLoss { stop_price: price!(1) }, false)]
+    fn check_order_by_capabilities(#[case] user_order: UserOrder, #[case] is_ok: bool) {
+        let capabilities = ExchangeCapabilities {
+            maker_only_orders: true,
+            ..Default::default()
+        };
+
+        let result = capabilities.check_order(&order_header(user_order));
+
+        assert_eq!(result.is_ok(), is_ok);
+        if let Err(error) = result {
+            assert_eq!(error.error_type, ExchangeErrorType::Unsupported);
+        }
+    }
+
+    #[rstest]
+    #[case(true, UserOrder::limit(price!(1)), TimeInForce::Gtc, false)]
+    #[case(true, UserOrder::limit(price!(1)), TimeInForce::Gtd(Utc::now()), false)]
+    #[case(true, UserOrder::maker_only(price!(1)), TimeInForce::Gtd(Utc::now()), true)]
+    #[case(false, UserOrder::limit(price!(1)), TimeInForce::Gtd(Utc::now()), true)]
+    fn emulate_expiration_if_unsupported(
+        #[case] good_till_date_orders: bool,
+        #[case] user_order: UserOrder,
+        #[case] time_in_force: TimeInForce,
+        #[case] expected: bool,
+    ) {
+        let capabilities = ExchangeCapabilities {
+            good_till_date_orders,
+            ..Default::default()
+        };
+        let order_header = order_header(user_order).with_time_in_force(time_in_force);
+
+        assert_eq!(
+            capabilities.should_emulate_expiration(&order_header),
+            expected
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::exchanges::clock_skew_monitor::{ClockSkew, ClockSkewMonitor};
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::exchanges::traits::ExchangeError;
+use crate::misc::time::time_manager;
+use crate::settings::ClockSkewSettings;
+use anyhow::{bail, Context, Result};
+use mmb_domain::events::{ClockSkewEvent, ExchangeEvent};
+use mmb_domain::market::ExchangeErrorType;
+use mmb_domain::order::snapshot::OrderHeader;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::send_expected::SendExpectedByRef;
+use mmb_utils::time::get_current_milliseconds;
+use std::sync::Arc;
+
+impl Exchange {
+    pub fn setup_clock_skew_monitor(&self, settings: &ClockSkewSettings) {
+        *self.clock_skew_monitor.lock() = Some(Arc::new(ClockSkewMonitor::new(settings)));
+    }
+
+    /// Last measured clock skew. `None` if clock skew isn't monitored
+    pub fn clock_skew(&self) -> Option<ClockSkew> {
+        self.clock_skew_monitor
+            .lock()
+            .as_ref()
+            .map(|x| x.clock_skew())
+    }
+
+    /// Offset of local clock from exchange server clock. Server time is compared with the
+    /// middle of request, so half of round trip is compensated
+    pub async fn measure_clock_offset(&self, cancellation_token: CancellationToken) -> Result<i64> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetServerTime,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        let local_send_time = get_current_milliseconds();
+        let server_time = self
+            .exchange_client
+            .get_server_time()
+            .await
+            .with_context(|| {
+                format!(
+                    "Server time isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!("Failed to get server time on {}", self.exchange_account_id)
+            })?;
+        let local_receive_time = get_current_milliseconds();
+
+        Ok((local_send_time + local_receive_time) / 2 - server_time)
+    }
+
+    /// Measure clock offset and refuse or resume order creation when it crosses max offset
+    pub async fn check_clock_skew(self: Arc<Self>, cancellation_token: CancellationToken) {
+        let clock_skew_monitor = match self.clock_skew_monitor.lock().clone() {
+            None => return,
+            Some(clock_skew_monitor) => clock_skew_monitor,
+        };
+
+        let offset_ms = match self.measure_clock_offset(cancellation_token).await {
+            Ok(offset_ms) => offset_ms,
+            Err(error) => {
+                log::warn!("{error:?}");
+                return;
+            }
+        };
+
+        if !clock_skew_monitor.update(offset_ms) {
+            return;
+        }
+
+        let max_offset_ms = clock_skew_monitor.max_offset_ms();
+        let is_exceeded = clock_skew_monitor.clock_skew().is_exceeded;
+        match is_exceeded {
+            true => log::error!(
+                "Local clock is skewed by {offset_ms} ms from server clock of {} which exceeds max {max_offset_ms} ms. New orders are refused until clock is corrected",
+                self.exchange_account_id
+            ),
+            false => log::warn!(
+                "Local clock skew {offset_ms} ms from server clock of {} returned within max {max_offset_ms} ms. Order creation is resumed",
+                self.exchange_account_id
+            ),
+        }
+
+        self.events_channel
+            .send_expected(ExchangeEvent::ClockSkew(ClockSkewEvent {
+                exchange_account_id: self.exchange_account_id,
+                offset_ms,
+                max_offset_ms,
+                is_exceeded,
+                event_creation_time: time_manager::now(),
+            }));
+    }
+
+    /// Exchange would reject orders signed by skewed clock anyway, so they aren't sent at all
+    pub(crate) fn check_clock_skew_for_order(&self, order_header: &OrderHeader) -> Result<()> {
+        let clock_skew = match self.clock_skew() {
+            Some(clock_skew) if clock_skew.is_exceeded => clock_skew,
+            _ => return Ok(()),
+        };
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::ClockSkewTooLarge,
+            format!(
+                "Order creation {} on {} is rejected because local clock is skewed by {} ms from server clock",
+                order_header.client_order_id,
+                self.exchange_account_id,
+                clock_skew.offset_ms.unwrap_or_default(),
+            ),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,74 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use mmb_domain::exchanges::dust_conversion::DustConversion;
+use mmb_domain::market::{CurrencyCode, CurrencyPair};
+use mmb_domain::order::snapshot::{Amount, Price};
+use mmb_utils::cancellation_token::CancellationToken;
+
+impl Exchange {
+    /// Convert dust balances of assets into exchange specific currency, e.g. into BNB on Binance
+    pub async fn convert_dust(
+        &self,
+        assets: &[CurrencyCode],
+        cancellation_token: CancellationToken,
+    ) -> Result<DustConversion> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::ConvertDust,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .convert_dust(assets)
+            .await
+            .with_context(|| {
+                format!(
+                    "Dust conversion isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| format!("Failed to convert dust on {}", self.exchange_account_id))
+    }
+
+    /// Balances of the last snapshot which can't be traded by single order of traded currency
+    /// pair with asset as base currency. Assets without such currency pair or its price are
+    /// skipped, because their tradeability is unknown
+    pub fn dust_balances(&self) -> Vec<(CurrencyCode, Amount)> {
+        let snapshot = match self.balance_snapshot() {
+            None => return Vec::new(),
+            Some(snapshot) => snapshot,
+        };
+
+        snapshot
+            .balances
+            .into_iter()
+            .filter(|(_, amount)| *amount > Amount::ZERO)
+            .filter(|&(asset, amount)| {
+                self.symbols
+                    .iter()
+                    .find(|x| x.base_currency_code == asset)
+                    .and_then(|symbol| {
+                        let price = self.reference_price(symbol.currency_pair())?;
+                        Some(symbol.is_dust(amount, price))
+                    })
+                    .unwrap_or(false)
+            })
+            .sorted_by_key(|(asset, _)| asset.to_string())
+            .collect()
+    }
+
+    /// Top bid price or price of the last trade if order book isn't received yet
+    fn reference_price(&self, currency_pair: CurrencyPair) -> Option<Price> {
+        let bid_price = self
+            .order_book_top
+            .get(&currency_pair)
+            .and_then(|x| x.bid.as_ref().map(|bid| bid.price));
+
+        bid_price.or_else(|| self.last_trade_price(currency_pair))
+    }
+}
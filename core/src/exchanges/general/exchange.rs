@@ -1,11 +1,20 @@
 use super::polling_timeout_manager::PollingTimeoutManager;
 use crate::balance::manager::balance_manager::BalanceManager;
+use crate::balance::position_cost::PositionCost;
 use crate::connectivity::{
-    websocket_open, ConnectivityError, WebSocketParams, WebSocketRole, WsSender,
+    websocket_open, ConnectivityError, ReconnectBackoff, StreamKind, WebSocketParams,
+    WebSocketRole, WsCloseReason, WsCompression, WsCompressionState, WsSender,
 };
 use crate::database::events::recorder::EventRecorder;
-use crate::exchanges::block_reasons::WEBSOCKET_DISCONNECTED;
+use crate::exchanges::asset_networks_cache::{
+    AssetNetworksCache, DEFAULT_ASSET_NETWORKS_CACHE_TTL_SECS,
+};
+use crate::exchanges::block_reasons::{
+    EXCHANGE_MAINTENANCE, REST_UNREACHABLE, WEBSOCKET_DISCONNECTED,
+};
+use crate::exchanges::clock_skew_monitor::ClockSkewMonitor;
 use crate::exchanges::exchange_blocker::{BlockType, ExchangeBlocker};
+use crate::exchanges::general::balance_snapshot::BalanceSnapshot;
 use crate::exchanges::general::features::ExchangeFeatures;
 use crate::exchanges::general::order::cancel::CancelOrderResult;
 use crate::exchanges::general::order::create::CreateOrderResult;
@@ -13,34 +22,62 @@ use crate::exchanges::general::request_type::RequestType;
 use crate::exchanges::timeouts::requests_timeout_manager_factory::RequestTimeoutArguments;
 use crate::exchanges::timeouts::timeout_manager::TimeoutManager;
 use crate::exchanges::traits::{ExchangeClient, ExchangeError};
-use crate::infrastructure::spawn_future;
+use crate::exchanges::warmup::Warmup;
+use crate::infrastructure::{spawn_future, spawn_future_ok};
 use crate::lifecycle::app_lifetime_manager::AppLifetimeManager;
 use crate::misc::time::time_manager;
+use crate::order_book::order_book_freshness::OrderBookFreshness;
 use crate::orders::buffered_fills::buffered_canceled_orders_manager::BufferedCanceledOrdersManager;
 use crate::orders::buffered_fills::buffered_fills_manager::BufferedFillsManager;
+use crate::orders::event_merge::OrderEventsMerger;
+use crate::orders::fill_aggregation::{take_unreported_fills, FillEventsAggregation};
+use crate::orders::idempotency_cache::IdempotencyCache;
+use crate::orders::min_order_lifetime::MinOrderLifetime;
+use crate::orders::near_cross::find_crossed_order;
+use crate::orders::order_rate_limiter::OrderRateLimiter;
+use crate::orders::price_band::{PriceBand, PriceBandCheck};
+use crate::orders::replace_price::ReplacePriceGuard;
+use crate::orders::retry_budget::{RetryBudget, RetryBudgetCheck};
+use crate::orders::working_exposure::WorkingExposure;
+use crate::settings::{
+    AccountType, CurrencyPairFeesSettings, FillEventsAggregationSettings, IdempotencyCacheSettings,
+    MarginModeSettings, MinOrderLifetimeSettings, OrderBookFreshnessSettings, OrderCheck,
+    OrderEventsMergeSettings, OrderRetryBudgetSettings, PriceBandSettings, RejectionPauseSettings,
+    StartupPolicy, WarmupSettings,
+};
+use crate::telemetry;
 use anyhow::{bail, Context, Result};
 use dashmap::DashMap;
 use function_name::named;
 use futures::future::join_all;
 use itertools::Itertools;
 use mmb_database::impl_event;
+use mmb_domain::candle::CandleAggregator;
 use mmb_domain::events::{
-    BalanceUpdateEvent, ExchangeBalancesAndPositions, ExchangeEvent, LiquidationPriceEvent,
-    MetricsEvent, MetricsEventInfo, MetricsEventInfoBase, MetricsEventType, MetricsTime, Trade,
+    BalanceUpdateEvent, DisconnectedEvent, ExchangeBalancesAndPositions, ExchangeEvent,
+    FlattenEvent, FlattenStage, LiquidationPriceEvent, MarkPriceEvent, MaxDrawdownReachedEvent,
+    MetricsEvent, MetricsEventInfo, MetricsEventInfoBase, MetricsEventType, MetricsTime,
+    NearCrossEvent, RetriesExhaustedEvent, SubscriptionFailedEvent, SystemStatus,
+    SystemStatusEvent, Trade, WarmupCompletedEvent, WebSocketClose,
 };
-use mmb_domain::exchanges::commission::Commission;
-use mmb_domain::exchanges::symbol::Symbol;
+use mmb_domain::exchanges::api_permissions::ApiPermissions;
+use mmb_domain::exchanges::commission::{Commission, CurrencyPairFees};
+use mmb_domain::exchanges::endpoint_latency::EndpointLatency;
+use mmb_domain::exchanges::leverage::LeverageBracket;
+use mmb_domain::exchanges::symbol::{Round, Symbol};
 use mmb_domain::market::{
-    CurrencyCode, CurrencyPair, ExchangeAccountId, MarketId, SpecificCurrencyPair,
+    CurrencyCode, CurrencyPair, ExchangeAccountId, ExchangeErrorType, MarketId,
+    SpecificCurrencyPair,
 };
 use mmb_domain::order::event::OrderEvent;
 use mmb_domain::order::event::OrderEventType;
 use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::pool::OrdersPool;
-use mmb_domain::order::snapshot::OrderSide;
 use mmb_domain::order::snapshot::{Amount, Price};
-use mmb_domain::order::snapshot::{ClientOrderId, ExchangeOrderId};
-use mmb_domain::position::{ActivePosition, ClosedPosition, DerivativePosition};
+use mmb_domain::order::snapshot::{ClientOrderId, ExchangeOrderId, OrderInfo};
+use mmb_domain::order::snapshot::{OrderHeader, OrderOptions, OrderType, UserOrder};
+use mmb_domain::order::snapshot::{OrderRole, OrderSide, OrderStatus};
+use mmb_domain::position::{ActivePosition, ClosedPosition, DerivativePosition, MarginMode};
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::infrastructure::{SpawnFutureFlags, WithExpect};
 use mmb_utils::send_expected::SendExpectedByRef;
@@ -48,11 +85,13 @@ use mmb_utils::{nothing_to_do, DateTime};
 use parking_lot::Mutex;
 use rust_decimal::Decimal;
 use serde::Serialize;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
 use std::ops::DerefMut;
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::{Arc, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{broadcast, oneshot};
 use tokio::time::sleep;
 
@@ -81,6 +120,55 @@ pub struct PriceLevel {
 pub struct OrderBookTop {
     pub ask: Option<PriceLevel>,
     pub bid: Option<PriceLevel>,
+    pub last_update_time: DateTime,
+}
+
+impl OrderBookTop {
+    pub fn is_stale(&self, max_age: Duration, now: DateTime) -> bool {
+        (now - self.last_update_time).to_std().unwrap_or_default() > max_age
+    }
+}
+
+/// Order can be filled immediately if it crosses order book top.
+/// Returns `true` if order book top is unknown because it's impossible to check it
+fn can_be_filled_immediately(order: &OrderInfo, order_book_top: Option<&OrderBookTop>) -> bool {
+    let order_book_top = match order_book_top {
+        None => return true,
+        Some(order_book_top) => order_book_top,
+    };
+
+    match order.order_side {
+        OrderSide::Buy => order_book_top
+            .ask
+            .as_ref()
+            .map_or(false, |ask| order.price >= ask.price),
+        OrderSide::Sell => order_book_top
+            .bid
+            .as_ref()
+            .map_or(false, |bid| order.price <= bid.price),
+    }
+}
+
+/// Trading permission is required for placing orders, enabled withdrawals are allowed
+/// but reported because it's a security risk in case of API key leak
+fn check_api_permissions(
+    exchange_account_id: ExchangeAccountId,
+    account_type: AccountType,
+    permissions: &ApiPermissions,
+) -> Result<()> {
+    if !permissions.is_enabled {
+        bail!("API key for {exchange_account_id} is disabled");
+    }
+
+    if !permissions.can_trade {
+        bail!("API key for {exchange_account_id} has no trading permission for {account_type:?} account");
+    }
+
+    if permissions.can_withdraw {
+        log::warn!("API key for {exchange_account_id} has withdraw permission enabled. It is a security risk, consider disabling it");
+    }
+
+    Ok(())
 }
 
 #[derive(Serialize)]
@@ -94,12 +182,18 @@ pub struct Exchange {
     pub orders: Arc<OrdersPool>,
     pub currencies: Mutex<Vec<CurrencyCode>>,
     pub leverage_by_currency_pair: DashMap<CurrencyPair, Decimal>,
+    /// Margin modes of futures currency pairs known to be set on exchange
+    pub margin_mode_by_currency_pair: DashMap<CurrencyPair, MarginMode>,
+    /// Leverage brackets of futures currency pairs received from exchange
+    pub leverage_brackets: DashMap<CurrencyPair, Vec<LeverageBracket>>,
     pub order_book_top: DashMap<CurrencyPair, OrderBookTop>,
+    /// The last received mark prices of futures contracts
+    pub mark_prices: DashMap<CurrencyPair, MarkPriceEvent>,
     pub exchange_client: BoxExchangeClient,
     pub(super) features: ExchangeFeatures,
     pub(super) events_channel: broadcast::Sender<ExchangeEvent>,
     pub(super) lifetime_manager: Arc<AppLifetimeManager>,
-    pub(super) commission: Commission,
+    pub(super) commission: Mutex<Commission>,
     pub(super) wait_cancel_order: DashMap<ClientOrderId, broadcast::Sender<()>>,
     pub(super) wait_finish_order: DashMap<ClientOrderId, broadcast::Sender<OrderRef>>,
     pub(super) polling_trades_counts: DashMap<ExchangeAccountId, u32>,
@@ -108,10 +202,53 @@ pub struct Exchange {
     pub(super) orders_created_events: DashMap<ClientOrderId, oneshot::Sender<()>>,
     pub(super) last_trades_update_time: DashMap<MarketId, DateTime>,
     pub(super) last_trades: DashMap<MarketId, Trade>,
+    pub(super) candle_aggregators: DashMap<CurrencyPair, CandleAggregator>,
     pub(super) timeout_manager: Arc<TimeoutManager>,
     pub(crate) balance_manager: Mutex<Option<Weak<Mutex<BalanceManager>>>>,
     pub(super) buffered_fills_manager: Mutex<BufferedFillsManager>,
     pub(super) buffered_canceled_orders_manager: Mutex<BufferedCanceledOrdersManager>,
+    pub(super) idempotency_cache: Mutex<Option<Arc<IdempotencyCache>>>,
+    pub(super) order_events_merger: Mutex<OrderEventsMerger>,
+    warmup: Mutex<Option<Arc<Warmup>>>,
+    pub(super) regenerate_duplicate_client_order_id: AtomicBool,
+    require_order_reservation: AtomicBool,
+    endpoint_latency: Mutex<Option<EndpointLatency>>,
+    min_order_lifetime: Mutex<Option<Arc<MinOrderLifetime>>>,
+    order_book_freshness: Mutex<Option<Arc<OrderBookFreshness>>>,
+    max_open_orders_per_currency_pair: Mutex<Option<usize>>,
+    price_band: Mutex<Option<Arc<PriceBand>>>,
+    pub(super) margin_mode_settings: Mutex<Option<Arc<MarginModeSettings>>>,
+    order_retry_budget: Mutex<Option<Arc<RetryBudget>>>,
+    fill_events_aggregation: Mutex<Option<Arc<FillEventsAggregation>>>,
+    pub(super) order_rate_limiter: Mutex<Option<Arc<OrderRateLimiter>>>,
+    pub(super) clock_skew_monitor: Mutex<Option<Arc<ClockSkewMonitor>>>,
+    pub(super) replace_price_guard: Mutex<Option<Arc<ReplacePriceGuard>>>,
+    pub(super) disabled_order_checks: Mutex<HashSet<OrderCheck>>,
+    pub(super) position_costs: DashMap<CurrencyPair, PositionCost>,
+    pub(super) breakeven_exit_order_role: Mutex<OrderRole>,
+    pub(super) asset_networks_cache: Mutex<AssetNetworksCache>,
+    pub(super) rejection_pause_settings: Mutex<Option<RejectionPauseSettings>>,
+    /// Count of order rejections in a row by currency pair
+    pub(super) consecutive_rejections: DashMap<CurrencyPair, u32>,
+    /// Time of pause of order placement by currency pair
+    pub(super) paused_placements: DashMap<CurrencyPair, DateTime>,
+    pub(super) balance_change_threshold: Mutex<Option<Decimal>>,
+    /// The last balances polled from exchange
+    pub(super) balance_snapshot: Mutex<Option<BalanceSnapshot>>,
+    /// Balances at the last change event which changes of polled balances are measured from
+    pub(super) reported_balances: Mutex<HashMap<CurrencyCode, Amount>>,
+    pub(super) reconnect_grace_period: Mutex<Option<Duration>>,
+    /// Cancelled at the end of the current grace period after reconnect
+    pub(super) reconnect_grace_token: Mutex<Option<CancellationToken>>,
+    /// Orders opened before startup which are left untouched according to startup policy
+    pub(super) unmanaged_orders: Mutex<HashSet<ExchangeOrderId>>,
+    /// Websocket streams which exchange refused to subscribe since the last connection
+    pub(crate) failed_subscriptions: DashMap<String, SubscriptionFailedEvent>,
+    /// Reasons of broken market data by currency pair
+    pub(super) broken_market_data: DashMap<CurrencyPair, String>,
+    /// End of time range of the last received funding payments per currency pair
+    pub(super) funding_payments_updated_at: DashMap<CurrencyPair, DateTime>,
+    system_status: Mutex<SystemStatus>,
     // It allows to send and receive notification about event in websocket channel
     // Websocket event is main source detecting order creation result
     // Rest response using only for unsuccessful operations as error
@@ -130,9 +267,16 @@ pub struct Exchange {
             Option<oneshot::Receiver<CancelOrderResult>>,
         ),
     >,
-    exchange_blocker: Weak<ExchangeBlocker>,
+    pub(super) exchange_blocker: Weak<ExchangeBlocker>,
     ws_sender: Mutex<Option<WsSender>>,
     auto_reconnect: AtomicBool,
+    reconnect_backoff: Mutex<ReconnectBackoff>,
+    /// Reconnect caused by repeated REST failures is in progress
+    is_rest_reconnecting: AtomicBool,
+    /// Websocket was connected at least once, so the next connection is reconnect
+    has_connected: AtomicBool,
+    /// Kinds of streams required by strategies per currency pair
+    required_streams: Mutex<HashMap<CurrencyPair, HashSet<StreamKind>>>,
 
     // Temporary fix before integration ExchangeBlocker to wait_order_finish/wait_cancel_order fallbacks #641
     timeout: Duration,
@@ -175,10 +319,11 @@ impl Exchange {
                 features,
                 events_channel,
                 timeout_manager,
-                commission,
+                commission: Mutex::new(commission),
                 symbols: Default::default(),
                 currencies: Default::default(),
                 order_book_top: Default::default(),
+                mark_prices: Default::default(),
                 wait_cancel_order: DashMap::new(),
                 wait_finish_order: DashMap::new(),
                 polling_trades_counts: DashMap::new(),
@@ -186,13 +331,55 @@ impl Exchange {
                 orders_finish_events: DashMap::new(),
                 orders_created_events: DashMap::new(),
                 leverage_by_currency_pair: DashMap::new(),
+                margin_mode_by_currency_pair: DashMap::new(),
+                leverage_brackets: DashMap::new(),
                 last_trades_update_time: DashMap::new(),
                 last_trades: DashMap::new(),
+                candle_aggregators: DashMap::new(),
                 balance_manager: Mutex::new(None),
                 buffered_fills_manager: Default::default(),
                 exchange_blocker,
                 buffered_canceled_orders_manager: Default::default(),
+                idempotency_cache: Mutex::new(None),
+                order_events_merger: Default::default(),
+                warmup: Mutex::new(None),
+                regenerate_duplicate_client_order_id: AtomicBool::new(false),
+                require_order_reservation: AtomicBool::new(false),
+                endpoint_latency: Mutex::new(None),
+                min_order_lifetime: Mutex::new(None),
+                order_book_freshness: Mutex::new(None),
+                max_open_orders_per_currency_pair: Mutex::new(None),
+                price_band: Mutex::new(None),
+                margin_mode_settings: Mutex::new(None),
+                order_retry_budget: Mutex::new(None),
+                fill_events_aggregation: Mutex::new(None),
+                order_rate_limiter: Mutex::new(None),
+                clock_skew_monitor: Mutex::new(None),
+                replace_price_guard: Mutex::new(None),
+                disabled_order_checks: Default::default(),
+                position_costs: DashMap::new(),
+                breakeven_exit_order_role: Mutex::new(OrderRole::Taker),
+                asset_networks_cache: Mutex::new(AssetNetworksCache::new(
+                    DEFAULT_ASSET_NETWORKS_CACHE_TTL_SECS,
+                )),
+                rejection_pause_settings: Mutex::new(None),
+                consecutive_rejections: DashMap::new(),
+                paused_placements: DashMap::new(),
+                balance_change_threshold: Mutex::new(None),
+                balance_snapshot: Mutex::new(None),
+                reported_balances: Default::default(),
+                reconnect_grace_period: Mutex::new(None),
+                reconnect_grace_token: Mutex::new(None),
+                unmanaged_orders: Default::default(),
+                failed_subscriptions: DashMap::new(),
+                broken_market_data: DashMap::new(),
+                funding_payments_updated_at: DashMap::new(),
+                system_status: Default::default(),
                 auto_reconnect: AtomicBool::new(false),
+                reconnect_backoff: Default::default(),
+                is_rest_reconnecting: AtomicBool::new(false),
+                has_connected: AtomicBool::new(false),
+                required_streams: Default::default(),
                 timeout,
                 server_time_latency: Default::default(),
                 event_recorder,
@@ -206,21 +393,31 @@ impl Exchange {
     ) {
         exchange_client.set_order_created_callback(Box::new({
             let exchange_weak = exchange_weak.clone();
-            move |client_order_id, exchange_order_id, source_type| match exchange_weak.upgrade() {
-                Some(exchange) => {
-                    exchange.raise_order_created(&client_order_id, &exchange_order_id, source_type)
+            move |client_order_id, exchange_order_id, source_type, exchange_time| {
+                match exchange_weak.upgrade() {
+                    Some(exchange) => exchange.raise_order_created(
+                        &client_order_id,
+                        &exchange_order_id,
+                        source_type,
+                        exchange_time,
+                    ),
+                    None => log::info!("Unable to upgrade weak reference to Exchange instance"),
                 }
-                None => log::info!("Unable to upgrade weak reference to Exchange instance"),
             }
         }));
 
         exchange_client.set_order_cancelled_callback(Box::new({
             let exchange_weak = exchange_weak.clone();
-            move |client_order_id, exchange_order_id, source_type| match exchange_weak.upgrade() {
-                Some(exchange) => {
-                    exchange.raise_order_cancelled(client_order_id, exchange_order_id, source_type);
+            move |client_order_id, exchange_order_id, source_type, exchange_time| {
+                match exchange_weak.upgrade() {
+                    Some(exchange) => exchange.raise_order_cancelled(
+                        client_order_id,
+                        exchange_order_id,
+                        source_type,
+                        exchange_time,
+                    ),
+                    None => log::info!("Unable to upgrade weak reference to Exchange instance"),
                 }
-                None => log::info!("Unable to upgrade weak reference to Exchange instance"),
             }
         }));
 
@@ -295,6 +492,7 @@ impl Exchange {
 
     fn on_connected(&self) {
         log::info!("Exchange account id {} connected", self.exchange_account_id);
+        self.reconnect_backoff.lock().on_connected(Instant::now());
         if let Some(exchange_blocker) = self.exchange_blocker.upgrade() {
             exchange_blocker.unblock(self.exchange_account_id, WEBSOCKET_DISCONNECTED);
         }
@@ -308,11 +506,17 @@ impl Exchange {
         }
     }
 
-    fn on_disconnected(self: &Arc<Self>) {
-        log::info!(
-            "Exchange account id {} disconnected",
-            self.exchange_account_id
-        );
+    fn on_disconnected(self: &Arc<Self>, close: Option<WebSocketClose>) {
+        match &close {
+            Some(WebSocketClose { code, reason }) => log::info!(
+                "Exchange account id {} disconnected with code {code}: {reason}",
+                self.exchange_account_id
+            ),
+            None => log::info!(
+                "Exchange account id {} disconnected",
+                self.exchange_account_id
+            ),
+        }
 
         self.exchange_client
             .on_disconnected()
@@ -331,14 +535,33 @@ impl Exchange {
             );
         }
 
+        // Messages received after reconnect can overlap with the state before disconnect,
+        // so order books should be resynced from fresh snapshots.
+        // Nobody can listen events during shutdown, so sending result is ignored
+        let _ = self
+            .events_channel
+            .send(ExchangeEvent::Disconnected(DisconnectedEvent {
+                exchange_account_id: self.exchange_account_id,
+                close: close.clone(),
+                event_creation_time: time_manager::now(),
+            }));
+
         // auto reconnect
         if !self.auto_reconnect.load(Ordering::SeqCst) {
             return;
         }
+        let delay = self
+            .reconnect_backoff
+            .lock()
+            .on_disconnected(close.as_ref(), Instant::now());
         let id = self.exchange_account_id;
         let action = format!("Exchange account id {} reconnect", id);
         let self_weak = Arc::downgrade(self);
         let future = async move {
+            if !delay.is_zero() {
+                log::info!("Exchange account id {id} reconnects in {delay:?}");
+                sleep(delay).await;
+            }
             if let Some(self_strong) = self_weak.upgrade() {
                 if let Err(e) = self_strong.connect_ws().await {
                     log::error!("Exchange account id {} failed to reconnect: {:?}", id, e)
@@ -349,6 +572,59 @@ impl Exchange {
         spawn_future(&action, SpawnFutureFlags::STOP_BY_TOKEN, future);
     }
 
+    /// Order creation is paused and exchange is fully reconnected, because REST path can be
+    /// broken while websocket connection still looks alive
+    pub(crate) fn on_rest_unreachable(self: &Arc<Self>, failures: usize) {
+        let id = self.exchange_account_id;
+        if self.is_rest_reconnecting.swap(true, Ordering::SeqCst) {
+            log::warn!("Exchange account id {id} is already reconnecting after REST failures");
+            return;
+        }
+
+        log::error!(
+            "Exchange account id {id} is unreachable by REST after {failures} failed requests"
+        );
+        if let Some(exchange_blocker) = self.exchange_blocker.upgrade() {
+            exchange_blocker.block(id, REST_UNREACHABLE, BlockType::Manual);
+        }
+
+        let action = format!("Exchange account id {id} reconnect after REST failures");
+        let self_weak = Arc::downgrade(self);
+        let future = async move {
+            loop {
+                let delay = {
+                    let self_strong = match self_weak.upgrade() {
+                        None => return,
+                        Some(self_strong) => self_strong,
+                    };
+
+                    match self_strong.reconnect_ws().await {
+                        Ok(()) => {
+                            log::info!("Exchange account id {id} reconnected after REST failures");
+                            if let Some(exchange_blocker) = self_strong.exchange_blocker.upgrade() {
+                                exchange_blocker.unblock(id, REST_UNREACHABLE);
+                            }
+                            self_strong
+                                .is_rest_reconnecting
+                                .store(false, Ordering::SeqCst);
+                            return;
+                        }
+                        Err(err) => {
+                            let delay = self_strong
+                                .reconnect_backoff
+                                .lock()
+                                .on_disconnected(None, Instant::now());
+                            log::error!("Exchange account id {id} failed to reconnect after REST failures, next attempt in {delay:?}: {err:?}");
+                            delay
+                        }
+                    }
+                };
+                sleep(delay).await;
+            }
+        };
+        spawn_future_ok(&action, SpawnFutureFlags::STOP_BY_TOKEN, future);
+    }
+
     fn maybe_log_websocket_message(&self, msg: &str) {
         if self.exchange_client.should_log_message(msg) {
             log::info!("Websocket message from {}: {msg}", self.exchange_account_id);
@@ -359,6 +635,764 @@ impl Exchange {
         *self.balance_manager.lock() = Some(Arc::downgrade(&balance_manager));
     }
 
+    pub fn setup_idempotency_cache(&self, settings: &IdempotencyCacheSettings) {
+        *self.idempotency_cache.lock() = Some(Arc::new(IdempotencyCache::new(settings)));
+    }
+
+    pub fn setup_order_events_merger(&self, settings: &OrderEventsMergeSettings) {
+        *self.order_events_merger.lock() = OrderEventsMerger::new(settings);
+    }
+
+    pub fn setup_regenerate_duplicate_client_order_id(&self, enabled: bool) {
+        self.regenerate_duplicate_client_order_id
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn setup_require_order_reservation(&self, enabled: bool) {
+        self.require_order_reservation
+            .store(enabled, Ordering::Relaxed);
+    }
+
+    /// Order is affordable only if its amount was reserved in `BalanceManager` before creation.
+    /// Liquidation orders are created by exchange, so they are never checked
+    pub(crate) fn check_order_reservation(&self, order_header: &OrderHeader) -> Result<()> {
+        if self.require_order_reservation.load(Ordering::Relaxed)
+            && order_header.reservation_id.is_none()
+            && order_header.order_type != OrderType::Liquidation
+        {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::InsufficientFunds,
+                format!(
+                    "Order creation {} on {} is rejected because balance isn't reserved for it",
+                    order_header.client_order_id, self.exchange_account_id
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn setup_max_open_orders_per_currency_pair(&self, max_open_orders: Option<usize>) {
+        *self.max_open_orders_per_currency_pair.lock() = max_open_orders;
+    }
+
+    /// Order is rejected locally if it would exceed limit of open orders for its currency pair,
+    /// because exchange would reject it anyway
+    pub(crate) fn check_open_orders_count(&self, order_header: &OrderHeader) -> Result<()> {
+        let max_open_orders = match *self.max_open_orders_per_currency_pair.lock() {
+            None => return Ok(()),
+            Some(max_open_orders) => max_open_orders,
+        };
+
+        let open_orders_count = self
+            .orders
+            .not_finished
+            .iter()
+            .filter(|x| {
+                x.currency_pair() == order_header.currency_pair
+                    && x.client_order_id() != order_header.client_order_id
+            })
+            .count();
+        if open_orders_count >= max_open_orders {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::OrderCountLimit,
+                format!(
+                    "Order creation {} on {} is rejected because {open_orders_count} orders are already open for {} with limit {max_open_orders}",
+                    order_header.client_order_id, self.exchange_account_id, order_header.currency_pair
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Order amount which is rounded to zero by amount step would be sent as zero order,
+    /// so such order is rejected before sending
+    pub(crate) fn check_order_amount(&self, order_header: &OrderHeader) -> Result<()> {
+        let symbol = match self.symbols.get(&order_header.currency_pair) {
+            None => return Ok(()),
+            Some(symbol) => symbol.clone(),
+        };
+
+        if !symbol.is_rounded_to_zero(order_header.amount) {
+            return Ok(());
+        }
+
+        let min_amount = symbol.min_order_amount();
+        let lots = match symbol.has_integer_lots() {
+            true => "integer lots",
+            false => "amount step",
+        };
+        bail!(ExchangeError::new(
+            ExchangeErrorType::AmountTooSmall { min_amount },
+            format!(
+                "Order creation {} on {} is rejected because amount {} is rounded to zero by {lots} of {}, min amount is {min_amount}",
+                order_header.client_order_id, self.exchange_account_id, order_header.amount, order_header.currency_pair
+            ),
+            None,
+        ))
+    }
+
+    /// Order for currency pair which isn't trading would be rejected by exchange,
+    /// so such order isn't sent at all
+    pub(crate) fn check_symbol_status(&self, order_header: &OrderHeader) -> Result<()> {
+        let symbol = match self.symbols.get(&order_header.currency_pair) {
+            None => return Ok(()),
+            Some(symbol) => symbol.clone(),
+        };
+
+        if symbol.is_trading() {
+            return Ok(());
+        }
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::SymbolNotTrading,
+            format!(
+                "Order creation {} on {} is rejected because {} has status {:?}",
+                order_header.client_order_id,
+                self.exchange_account_id,
+                order_header.currency_pair,
+                symbol.status
+            ),
+            None,
+        ))
+    }
+
+    pub(crate) fn check_order_type(&self, order_header: &OrderHeader) -> Result<()> {
+        let symbol = match self.symbols.get(&order_header.currency_pair) {
+            None => return Ok(()),
+            Some(symbol) => symbol.clone(),
+        };
+
+        let order_type = order_header.order_type;
+        if symbol.is_order_type_allowed(order_type) {
+            return Ok(());
+        }
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::OrderTypeNotAllowed,
+            format!(
+                "Order creation {} on {} is rejected because order type {order_type:?} isn't allowed for {}",
+                order_header.client_order_id,
+                self.exchange_account_id,
+                order_header.currency_pair,
+            ),
+            None,
+        ))
+    }
+
+    /// Good till date order expiring before it reaches exchange would be rejected or
+    /// cancelled right after creation, so such order isn't sent at all
+    pub(crate) fn check_time_in_force(&self, order_header: &OrderHeader) -> Result<()> {
+        let expire_time = match order_header.time_in_force.expire_time() {
+            None => return Ok(()),
+            Some(expire_time) => expire_time,
+        };
+
+        let now = time_manager::now();
+        if expire_time <= now {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::InvalidOrder,
+                format!(
+                    "Order creation {} on {} is rejected because expire time {expire_time} isn't in the future (now {now})",
+                    order_header.client_order_id, self.exchange_account_id
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Own orders of opposite sides at overlapping prices can match each other, which is
+    /// considered wash trading by exchanges. Order isn't rejected, near cross is only logged
+    /// and reported, so operators can review how often strategy generates it
+    pub(crate) fn detect_near_cross(&self, order_header: &OrderHeader) {
+        let open_orders = self
+            .orders
+            .not_finished
+            .iter()
+            .filter(|order| order.currency_pair() == order_header.currency_pair)
+            .map(|order| order.clone())
+            .collect_vec();
+
+        let crossed_order =
+            match find_crossed_order(order_header, open_orders.iter().map(|x| x.header())) {
+                None => return,
+                Some(crossed_order) => crossed_order,
+            };
+
+        let price = order_header.price();
+        log::warn!(
+            "Order {} {:?} at {price} on {} overlaps own order {} at {} of {}, so they can match each other",
+            order_header.client_order_id,
+            order_header.side,
+            self.exchange_account_id,
+            crossed_order.client_order_id,
+            crossed_order.price,
+            order_header.currency_pair
+        );
+
+        self.events_channel
+            .send_expected(ExchangeEvent::NearCross(NearCrossEvent {
+                exchange_account_id: self.exchange_account_id,
+                currency_pair: order_header.currency_pair,
+                client_order_id: order_header.client_order_id.clone(),
+                side: order_header.side,
+                price,
+                crossed_client_order_id: crossed_order.client_order_id,
+                crossed_price: crossed_order.price,
+                event_creation_time: time_manager::now(),
+            }));
+    }
+
+    pub fn setup_order_retry_budget(&self, settings: &OrderRetryBudgetSettings) {
+        *self.order_retry_budget.lock() = Some(Arc::new(RetryBudget::new(settings)));
+    }
+
+    pub fn setup_fill_events_aggregation(&self, settings: &FillEventsAggregationSettings) {
+        *self.fill_events_aggregation.lock() = Some(Arc::new(FillEventsAggregation::new(settings)));
+    }
+
+    /// Count of fills which should be reported by fill event after applying fill with specified
+    /// notional to order. Returns `None` if tiny fill is held back until more fills accumulate.
+    /// Every fill is reported if aggregation isn't configured
+    pub(crate) fn fills_count_to_report(
+        &self,
+        order: &OrderRef,
+        fill_notional: Amount,
+        is_order_completed: bool,
+    ) -> Option<usize> {
+        match self.fill_events_aggregation.lock().clone() {
+            Some(aggregation) => order.fn_mut(|x| {
+                aggregation.on_fill(&mut x.internal_props, fill_notional, is_order_completed)
+            }),
+            None => Some(1),
+        }
+    }
+
+    /// Spend one retry from retry budget of order. Returns `false` if budget is exhausted,
+    /// so retry shouldn't be made. Retries are unlimited if budget isn't configured
+    pub(crate) fn spend_order_retry(&self, order: &OrderRef, retry_kind: &str) -> bool {
+        let retry_budget = match self.order_retry_budget.lock().clone() {
+            Some(retry_budget) => retry_budget,
+            None => return true,
+        };
+
+        let now = time_manager::now();
+        let (check, retry_attempts) = order.fn_mut(|x| {
+            let check = retry_budget.try_spend(&mut x.internal_props, now);
+            (check, x.internal_props.retry_attempts)
+        });
+
+        match check {
+            RetryBudgetCheck::Allowed => true,
+            RetryBudgetCheck::AlreadyExhausted => false,
+            RetryBudgetCheck::Exhausted => {
+                let client_order_id = order.client_order_id();
+                log::error!(
+                    "Retry budget of order {client_order_id} on {} is exhausted after {retry_attempts} retries, {retry_kind} isn't retried anymore",
+                    self.exchange_account_id
+                );
+
+                self.events_channel
+                    .send_expected(ExchangeEvent::RetriesExhausted(RetriesExhaustedEvent {
+                        exchange_account_id: self.exchange_account_id,
+                        currency_pair: order.currency_pair(),
+                        client_order_id,
+                        retry_attempts,
+                        event_creation_time: now,
+                    }));
+                false
+            }
+        }
+    }
+
+    /// Cancel good till date orders which reached expire time but can't be expired by exchange
+    /// itself. Should be called periodically
+    pub async fn cancel_expired_orders(self: Arc<Self>, cancellation_token: CancellationToken) {
+        let capabilities = self.exchange_client.capabilities();
+        let now = time_manager::now();
+        let expired_orders = self
+            .orders
+            .not_finished
+            .iter()
+            .filter(|order| {
+                let header = order.header();
+                order.status() == OrderStatus::Created
+                    && header
+                        .time_in_force
+                        .expire_time()
+                        .map_or(false, |x| x <= now)
+                    && capabilities.should_emulate_expiration(header)
+            })
+            .map(|order| order.clone())
+            .collect_vec();
+
+        let cancellations = expired_orders.into_iter().map(|order| {
+            log::info!(
+                "Cancelling order {} on {} because it's expired",
+                order.client_order_id(),
+                self.exchange_account_id
+            );
+            self.wait_cancel_order(order, None, true, cancellation_token.clone())
+        });
+
+        for result in join_all(cancellations).await {
+            if let Err(error) = result {
+                log::error!(
+                    "Failed to cancel expired order on {}: {error:?}",
+                    self.exchange_account_id
+                );
+            }
+        }
+    }
+
+    /// Notional in quote currency of unfilled part of open orders for currency pair.
+    /// Orders without price (e.g. market orders) are valued by current order book top
+    /// and skipped if order book isn't received yet
+    pub fn get_working_exposure(&self, currency_pair: CurrencyPair) -> Result<WorkingExposure> {
+        let symbol = self.get_symbol(currency_pair)?;
+        let order_book_top = self.order_book_top.get(&currency_pair);
+
+        let mut exposure = WorkingExposure::default();
+        for order in self.orders.not_finished.iter() {
+            if order.currency_pair() != currency_pair {
+                continue;
+            }
+
+            let side = order.side();
+            let price = order.source_price().or_else(|| {
+                let top = order_book_top.as_deref()?;
+                let level = match side {
+                    OrderSide::Buy => top.ask.as_ref(),
+                    OrderSide::Sell => top.bid.as_ref(),
+                };
+                level.map(|x| x.price)
+            });
+            let price = match price {
+                None => continue,
+                Some(price) => price,
+            };
+
+            let remaining_amount = order.amount() - order.filled_amount();
+            let notional = symbol.convert_amount_from_amount_currency_code(
+                symbol.quote_currency_code,
+                remaining_amount,
+                price,
+            );
+            exposure.add(side, notional);
+        }
+
+        Ok(exposure)
+    }
+
+    pub fn setup_price_band(&self, settings: &PriceBandSettings) {
+        *self.price_band.lock() = Some(Arc::new(PriceBand::new(settings)));
+    }
+
+    pub fn setup_margin_mode(&self, settings: &MarginModeSettings) {
+        *self.margin_mode_settings.lock() = Some(Arc::new(settings.clone()));
+    }
+
+    /// Limit order priced outside of band around mark or index price is rejected. Orders are
+    /// checked only when mark price is already received
+    pub(crate) fn check_price_band(&self, order_header: &OrderHeader) -> Result<()> {
+        let (price, check) = match self.get_price_band_check(order_header) {
+            None => return Ok(()),
+            Some(price_band_check) => price_band_check,
+        };
+
+        let band = match check {
+            PriceBandCheck::Inside => return Ok(()),
+            PriceBandCheck::Rejected { lower, upper } => format!("[{lower}, {upper}]"),
+            // order should be clamped before it's added to orders pool
+            PriceBandCheck::Clamped(boundary) => format!("with boundary {boundary}"),
+        };
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::InvalidOrder,
+            format!(
+                "Order creation {} on {} is rejected because price {price} is outside of band {band}",
+                order_header.client_order_id, self.exchange_account_id
+            ),
+            None,
+        ))
+    }
+
+    /// Limit order priced outside of price band is clamped to band boundary if band is configured
+    /// to clamp. Header of order can't be changed after it's added to orders pool, so order
+    /// is clamped before that
+    pub(crate) fn clamp_by_price_band<'a>(
+        &self,
+        order_header: &'a OrderHeader,
+    ) -> Result<Cow<'a, OrderHeader>> {
+        let (price, boundary) = match self.get_price_band_check(order_header) {
+            Some((price, PriceBandCheck::Clamped(boundary))) => (price, boundary),
+            _ => return Ok(Cow::Borrowed(order_header)),
+        };
+        let execution_type = match &order_header.options {
+            OrderOptions::User(UserOrder::Limit { execution_type, .. }) => *execution_type,
+            _ => return Ok(Cow::Borrowed(order_header)),
+        };
+
+        let symbol = self.get_symbol(order_header.currency_pair)?;
+        let round = match boundary > price {
+            true => Round::Ceiling,
+            false => Round::Floor,
+        };
+        let clamped_price = symbol.price_round(boundary, round);
+        log::info!(
+            "Price {price} of order {} on {} is clamped to {clamped_price} by price band",
+            order_header.client_order_id,
+            self.exchange_account_id
+        );
+
+        let options = OrderOptions::User(UserOrder::Limit {
+            price: clamped_price,
+            execution_type,
+        });
+        Ok(Cow::Owned(OrderHeader {
+            source_price: Some(clamped_price),
+            options,
+            ..order_header.clone()
+        }))
+    }
+
+    fn get_price_band_check(&self, order_header: &OrderHeader) -> Option<(Price, PriceBandCheck)> {
+        let price = match &order_header.options {
+            OrderOptions::User(UserOrder::Limit { price, .. }) => *price,
+            _ => return None,
+        };
+
+        let price_band = self.price_band.lock().clone()?;
+        let mark_price = self.mark_prices.get(&order_header.currency_pair)?;
+        let check = price_band.check(order_header.currency_pair, price, &mark_price);
+
+        Some((price, check))
+    }
+
+    pub fn setup_min_order_lifetime(&self, settings: &MinOrderLifetimeSettings) {
+        *self.min_order_lifetime.lock() = Some(Arc::new(MinOrderLifetime::new(settings)));
+    }
+
+    /// Wait until order rests on exchange min lifetime before it is cancelled by strategy.
+    /// Risk-driven cancellations shouldn't wait it
+    pub async fn wait_min_order_lifetime(
+        &self,
+        order: &OrderRef,
+        cancellation_token: CancellationToken,
+    ) {
+        let min_order_lifetime = match self.min_order_lifetime.lock().clone() {
+            None => return,
+            Some(min_order_lifetime) => min_order_lifetime,
+        };
+
+        let init_time = order.fn_ref(|x| x.props.init_time);
+        let remaining =
+            min_order_lifetime.remaining(order.currency_pair(), init_time, time_manager::now());
+        if let Some(remaining) = remaining {
+            log::trace!(
+                "Cancellation of order {} on {} is delayed for {remaining:?} until min order lifetime",
+                order.client_order_id(),
+                self.exchange_account_id
+            );
+
+            tokio::select! {
+                _ = sleep(remaining) => nothing_to_do(),
+                _ = cancellation_token.when_cancelled() => nothing_to_do(),
+            }
+        }
+    }
+
+    pub fn setup_order_book_freshness(&self, settings: &OrderBookFreshnessSettings) {
+        *self.order_book_freshness.lock() = Some(Arc::new(OrderBookFreshness::new(settings)));
+    }
+
+    /// Order priced off local order book is rejected if order book wasn't updated within max age
+    /// or wasn't received at all. Orders without source price don't depend on order book,
+    /// so they are never checked
+    pub(crate) fn check_order_book_freshness(&self, order_header: &OrderHeader) -> Result<()> {
+        if order_header.source_price.is_none() {
+            return Ok(());
+        }
+
+        let max_age = match self.order_book_freshness.lock().as_ref() {
+            None => return Ok(()),
+            Some(freshness) => match freshness.max_age(order_header.currency_pair) {
+                None => return Ok(()),
+                Some(max_age) => max_age,
+            },
+        };
+
+        let is_stale = self
+            .order_book_top
+            .get(&order_header.currency_pair)
+            .map_or(true, |top| top.is_stale(max_age, time_manager::now()));
+        if is_stale {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::StaleMarketData,
+                format!(
+                    "Order creation {} on {} is rejected because order book for {} wasn't received or updated for more than {max_age:?}",
+                    order_header.client_order_id, self.exchange_account_id, order_header.currency_pair
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn setup_warmup(&self, settings: &WarmupSettings) {
+        *self.warmup.lock() = Some(Arc::new(Warmup::new(settings)));
+    }
+
+    /// Currency pair is ready for trading if warmup isn't configured or completed for it
+    pub fn is_warmed_up(&self, currency_pair: CurrencyPair) -> bool {
+        self.warmup
+            .lock()
+            .as_ref()
+            .map_or(true, |warmup| warmup.is_ready(currency_pair))
+    }
+
+    pub(crate) fn check_warmup(&self, currency_pair: CurrencyPair) -> Result<()> {
+        if !self.is_warmed_up(currency_pair) {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::StaleMarketData,
+                format!(
+                    "Order creation for {currency_pair} on {} is rejected because warmup isn't completed yet",
+                    self.exchange_account_id
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn update_warmup(
+        &self,
+        currency_pair: CurrencyPair,
+        update: impl FnOnce(&Warmup) -> bool,
+    ) {
+        let warmup = match self.warmup.lock().clone() {
+            None => return,
+            Some(warmup) => warmup,
+        };
+
+        if !update(&warmup) {
+            return;
+        }
+
+        log::info!(
+            "Warmup completed for {currency_pair} on {}",
+            self.exchange_account_id
+        );
+
+        self.events_channel
+            .send_expected(ExchangeEvent::WarmupCompleted(WarmupCompletedEvent {
+                exchange_account_id: self.exchange_account_id,
+                currency_pair,
+            }));
+    }
+
+    pub fn system_status(&self) -> SystemStatus {
+        *self.system_status.lock()
+    }
+
+    /// Request exchange-wide status and pause order placement while exchange is under maintenance
+    pub async fn update_system_status(&self) {
+        match self.exchange_client.get_system_status().await {
+            Ok(status) => self.set_system_status(status),
+            Err(error) => log::warn!(
+                "Unable to get system status for {}: {error:?}",
+                self.exchange_account_id
+            ),
+        }
+    }
+
+    /// REST endpoint selected by the last latency probing
+    pub fn endpoint_latency(&self) -> Option<EndpointLatency> {
+        self.endpoint_latency.lock().clone()
+    }
+
+    pub(crate) fn on_subscription_failed(&self, event: SubscriptionFailedEvent) {
+        if let Some(currency_pair) = event.currency_pair {
+            self.mark_market_data_broken(currency_pair, &event.reason);
+        }
+
+        let _ = self
+            .failed_subscriptions
+            .insert(event.stream.clone(), event);
+    }
+
+    /// Names of websocket streams which exchange refused to subscribe since the last connection,
+    /// so settings can be fixed while other streams keep working
+    pub fn failed_subscriptions(&self) -> Vec<String> {
+        self.failed_subscriptions
+            .iter()
+            .map(|x| x.key().clone())
+            .sorted()
+            .collect()
+    }
+
+    /// Compression of websocket connections which requested it
+    pub fn websocket_compression(&self) -> Vec<WsCompressionState> {
+        self.ws_sender
+            .lock()
+            .as_ref()
+            .map(|x| x.compression().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Price of the last public trade of currency pair. Trades are tracked only if
+    /// `request_trades` is enabled for exchange
+    pub fn last_trade_price(&self, currency_pair: CurrencyPair) -> Option<Price> {
+        let market_id = MarketId::new(self.exchange_account_id.exchange_id, currency_pair);
+        self.last_trades.get(&market_id).map(|x| x.price)
+    }
+
+    /// Probe latency of exchange REST endpoints and switch exchange client to the fastest one
+    pub async fn update_endpoint(&self) {
+        match self.exchange_client.select_fastest_endpoint().await {
+            None => log::info!(
+                "Endpoint latency probing isn't supported for {}",
+                self.exchange_account_id
+            ),
+            Some(Ok(endpoint_latency)) => {
+                log::info!(
+                    "Selected endpoint {} with latency {:?} for {}",
+                    endpoint_latency.endpoint,
+                    endpoint_latency.latency,
+                    self.exchange_account_id
+                );
+                *self.endpoint_latency.lock() = Some(endpoint_latency);
+            }
+            Some(Err(error)) => log::warn!(
+                "Unable to probe endpoints latency for {}: {error:?}",
+                self.exchange_account_id
+            ),
+        }
+    }
+
+    /// Load fees of currency pairs from exchange to override default commission
+    pub async fn update_trading_fees(&self) {
+        match self.exchange_client.get_trading_fees().await {
+            None => log::info!(
+                "Trading fees requesting isn't supported for {}",
+                self.exchange_account_id
+            ),
+            Some(Ok(fees)) => {
+                log::info!(
+                    "Received trading fees of {} currency pairs for {}",
+                    fees.len(),
+                    self.exchange_account_id
+                );
+                let mut commission = self.commission.lock();
+                for (currency_pair, currency_pair_fees) in fees {
+                    commission.set_currency_pair_fees(currency_pair, currency_pair_fees);
+                }
+            }
+            Some(Err(error)) => log::warn!(
+                "Unable to get trading fees for {}: {error:?}",
+                self.exchange_account_id
+            ),
+        }
+    }
+
+    pub fn setup_currency_pair_fees(&self, settings: &[CurrencyPairFeesSettings]) {
+        let mut commission = self.commission.lock();
+        for fees in settings {
+            commission.set_currency_pair_fees(
+                fees.currency_pair,
+                CurrencyPairFees::new(fees.maker, fees.taker),
+            );
+        }
+    }
+
+    fn set_system_status(&self, status: SystemStatus) {
+        let previous_status = std::mem::replace(self.system_status.lock().deref_mut(), status);
+        if previous_status == status {
+            return;
+        }
+
+        log::warn!(
+            "System status of {} changed from {previous_status:?} to {status:?}",
+            self.exchange_account_id
+        );
+
+        if let Some(exchange_blocker) = self.exchange_blocker.upgrade() {
+            match status {
+                SystemStatus::Maintenance => exchange_blocker.block(
+                    self.exchange_account_id,
+                    EXCHANGE_MAINTENANCE,
+                    BlockType::Manual,
+                ),
+                SystemStatus::Normal => {
+                    exchange_blocker.unblock(self.exchange_account_id, EXCHANGE_MAINTENANCE)
+                }
+            }
+        }
+
+        self.events_channel
+            .send_expected(ExchangeEvent::SystemStatus(SystemStatusEvent {
+                exchange_account_id: self.exchange_account_id,
+                previous_status,
+                status,
+                event_creation_time: time_manager::now(),
+            }));
+    }
+
+    /// Verify API key has permissions required for trading to fail fast on misconfigured keys.
+    /// Permissions request is retried on failure, and if it still fails verification is skipped
+    /// with warning, because failure to get permissions doesn't mean that key is misconfigured
+    #[named]
+    pub async fn verify_api_permissions(&self) -> Result<()> {
+        let mut permissions = None;
+        for retry_attempt in 1..=5 {
+            match self.exchange_client.get_api_permissions().await {
+                None => {
+                    log::info!(
+                        "Verification of API permissions isn't supported for {}",
+                        self.exchange_account_id
+                    );
+                    return Ok(());
+                }
+                Some(Ok(received)) => {
+                    permissions = Some(received);
+                    break;
+                }
+                Some(Err(error)) => {
+                    print_warn(
+                        retry_attempt,
+                        function_name!(),
+                        &self.exchange_account_id,
+                        error,
+                    );
+                    sleep(Duration::from_secs(1)).await;
+                }
+            }
+        }
+
+        let Some(permissions) = permissions else {
+            log::warn!(
+                "Unable to get API permissions for {}, continuing without their verification",
+                self.exchange_account_id
+            );
+            return Ok(());
+        };
+
+        log::info!(
+            "API permissions for {}: {permissions:?}",
+            self.exchange_account_id
+        );
+
+        let account_type = self.exchange_client.get_settings().account_type;
+        check_api_permissions(self.exchange_account_id, account_type, &permissions)
+    }
+
     pub async fn reconnect_ws(self: &Arc<Self>) -> Result<()> {
         self.disconnect_ws().await;
         self.connect_ws().await
@@ -370,24 +1404,47 @@ impl Exchange {
         self.ws_sender.lock().take();
     }
 
+    /// Register kinds of streams which strategy needs for currency pair. Requirements of all
+    /// strategies are aggregated, so they should be registered before connecting
+    pub fn require_streams(
+        &self,
+        currency_pair: CurrencyPair,
+        streams: impl IntoIterator<Item = StreamKind>,
+    ) {
+        self.required_streams
+            .lock()
+            .entry(currency_pair)
+            .or_default()
+            .extend(streams);
+    }
+
+    pub fn required_streams(&self, currency_pair: CurrencyPair) -> Option<HashSet<StreamKind>> {
+        self.required_streams.lock().get(&currency_pair).cloned()
+    }
+
     pub async fn connect_ws(self: &Arc<Self>) -> Result<()> {
+        self.exchange_client
+            .set_required_streams(self.required_streams.lock().clone());
         // fire connecting callback
         self.on_connecting();
         // do connect
         match self.connect_internal().await {
-            Ok(reader) => {
+            Ok((reader, close_reason)) => {
                 // enable auto reconnect after first success
                 self.auto_reconnect.store(true, Ordering::SeqCst);
                 spawn_future(
                     &format!("Exchange account id {} reader", self.exchange_account_id),
                     SpawnFutureFlags::STOP_BY_TOKEN,
-                    Self::reader_future(Arc::downgrade(self), reader),
+                    Self::reader_future(Arc::downgrade(self), reader, close_reason),
                 );
                 self.on_connected();
+                if self.has_connected.swap(true, Ordering::SeqCst) {
+                    self.start_reconnect_grace_period();
+                }
                 Ok(())
             }
             Err(e) => {
-                self.on_disconnected();
+                self.on_disconnected(None);
                 Err(e.into())
             }
         }
@@ -397,6 +1454,7 @@ impl Exchange {
     async fn reader_future(
         instance: Weak<Self>,
         mut reader: tokio::sync::mpsc::UnboundedReceiver<String>,
+        close_reason: WsCloseReason,
     ) -> Result<()> {
         while let Some(msg) = reader.recv().await {
             match instance.upgrade() {
@@ -410,7 +1468,7 @@ impl Exchange {
 
         // channel exhausted, so, disconnected
         if let Some(strong) = instance.upgrade() {
-            strong.on_disconnected()
+            strong.on_disconnected(close_reason.get())
         }
 
         Ok(())
@@ -419,7 +1477,8 @@ impl Exchange {
     /// Actual connect function, all internal work here.
     async fn connect_internal(
         self: &Arc<Self>,
-    ) -> Result<tokio::sync::mpsc::UnboundedReceiver<String>, ConnectivityError> {
+    ) -> Result<(tokio::sync::mpsc::UnboundedReceiver<String>, WsCloseReason), ConnectivityError>
+    {
         log::info!("Websocket: Connecting on {}", self.exchange_account_id);
 
         if !self
@@ -460,8 +1519,9 @@ impl Exchange {
             None
         };
         let (tx, rx) = websocket_open(self.exchange_account_id, main, secondary).await?;
+        let close_reason = tx.close_reason();
         self.ws_sender.lock().replace(tx);
-        Ok(rx)
+        Ok((rx, close_reason))
     }
 
     fn forward_websocket_message(&self, role: WebSocketRole, msg: String) -> Result<()> {
@@ -489,8 +1549,17 @@ impl Exchange {
         self: &Arc<Self>,
         role: WebSocketRole,
     ) -> Result<WebSocketParams> {
-        let ws_url = self.exchange_client.create_ws_url(role).await?;
-        Ok(WebSocketParams::new(ws_url))
+        let params = self.exchange_client.create_ws_params(role).await?;
+        let is_compression_enabled = self
+            .exchange_client
+            .get_settings()
+            .websocket_compression
+            .unwrap_or(false);
+
+        Ok(match is_compression_enabled {
+            true => params.with_compression(WsCompression::PerMessageDeflate),
+            false => params,
+        })
     }
 
     pub(crate) fn add_event_on_order_change(
@@ -502,10 +1571,27 @@ impl Exchange {
             order.fn_mut(|order| order.internal_props.was_cancellation_event_raised = true)
         }
 
+        // held back fills should be reported before any other event of order
+        if !matches!(event_type, OrderEventType::OrderFilled { .. }) {
+            let unreported_fills = order.fn_mut(|x| take_unreported_fills(&mut x.internal_props));
+            if let Some(fills_count) = unreported_fills {
+                let cloned_order = Arc::new(order.deep_clone());
+                self.add_event_on_order_change(
+                    order,
+                    OrderEventType::OrderFilled {
+                        cloned_order,
+                        fills_count,
+                    },
+                )?;
+            }
+        }
+
         if order.is_finished() {
             let _ = self.orders.not_finished.remove(&order.client_order_id());
         }
 
+        telemetry::record_order_event(order, &event_type);
+
         let event = ExchangeEvent::OrderEvent(OrderEvent::new(order.clone(), event_type));
         self.events_channel
             .send(event)
@@ -518,6 +1604,34 @@ impl Exchange {
         self: Arc<Self>,
         cancellation_token: CancellationToken,
         add_missing_open_orders: bool,
+    ) {
+        self.cancel_opened_orders_by_filter(cancellation_token, add_missing_open_orders, |_| true)
+            .await
+    }
+
+    /// Cancel only opened orders that can be filled immediately by current order book top
+    pub async fn cancel_non_passive_orders(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+        add_missing_open_orders: bool,
+    ) {
+        let this = self.clone();
+        self.cancel_opened_orders_by_filter(
+            cancellation_token,
+            add_missing_open_orders,
+            move |order| {
+                let order_book_top = this.order_book_top.get(&order.currency_pair);
+                can_be_filled_immediately(order, order_book_top.as_deref())
+            },
+        )
+        .await
+    }
+
+    async fn cancel_opened_orders_by_filter(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+        add_missing_open_orders: bool,
+        filter: impl Fn(&OrderInfo) -> bool,
     ) {
         match self.get_open_orders(add_missing_open_orders).await {
             Err(error) => {
@@ -527,6 +1641,7 @@ impl Exchange {
                 );
             }
             Ok(orders) => {
+                let orders = orders.into_iter().filter(|x| filter(x)).collect_vec();
                 tokio::select! {
                     _ = self.cancel_orders(orders.clone(), cancellation_token.clone()) => nothing_to_do(),
                     _ = cancellation_token.when_cancelled() => {
@@ -544,6 +1659,112 @@ impl Exchange {
         }
     }
 
+    /// Handle orders opened on exchange before startup according to startup policy
+    pub async fn reconcile_open_orders(
+        self: Arc<Self>,
+        startup_policy: StartupPolicy,
+        cancellation_token: CancellationToken,
+    ) {
+        let exchange_account_id = self.exchange_account_id;
+        match startup_policy {
+            StartupPolicy::Adopt => match self.get_open_orders(true).await {
+                Ok(orders) => {
+                    log::info!(
+                        "Adopted {} opened orders on {exchange_account_id}",
+                        orders.len()
+                    )
+                }
+                Err(error) => log::error!(
+                    "Unable to get opened orders for adoption on {exchange_account_id}: {error:?}"
+                ),
+            },
+            StartupPolicy::CancelAll => self.cancel_opened_orders(cancellation_token, true).await,
+            StartupPolicy::Ignore => match self.get_open_orders(false).await {
+                Ok(orders) => {
+                    log::info!(
+                        "Ignored {} opened orders on {exchange_account_id}",
+                        orders.len()
+                    );
+                    self.unmanaged_orders
+                        .lock()
+                        .extend(orders.into_iter().map(|x| x.exchange_order_id));
+                }
+                Err(error) => log::error!(
+                    "Unable to get opened orders for ignoring on {exchange_account_id}: {error:?}"
+                ),
+            },
+        }
+    }
+
+    /// Whether there are orders opened before startup which shouldn't be touched,
+    /// so orders can't be cancelled all at once
+    pub fn has_unmanaged_orders(&self) -> bool {
+        !self.unmanaged_orders.lock().is_empty()
+    }
+
+    /// Cancel all orders and close all positions by market
+    pub async fn flatten(self: Arc<Self>, cancellation_token: CancellationToken) {
+        self.send_flatten_event(FlattenStage::Started);
+
+        match self
+            .cancel_all_orders_global(cancellation_token.clone())
+            .await
+        {
+            Ok(summary) => {
+                for (currency_pair, result) in &summary {
+                    if let Err(error) = result {
+                        log::error!(
+                            "Failed to cancel orders for {currency_pair} on {} during flatten: {error:?}",
+                            self.exchange_account_id
+                        );
+                    }
+                }
+            }
+            Err(error) => log::error!(
+                "Failed to cancel orders on {} during flatten: {error:?}",
+                self.exchange_account_id
+            ),
+        }
+
+        if self
+            .exchange_client
+            .get_settings()
+            .account_type
+            .is_derivative()
+        {
+            self.clone()
+                .close_active_positions(cancellation_token)
+                .await;
+        }
+
+        self.send_flatten_event(FlattenStage::Completed);
+    }
+
+    fn send_flatten_event(&self, stage: FlattenStage) {
+        self.events_channel
+            .send_expected(ExchangeEvent::Flatten(FlattenEvent {
+                exchange_account_id: self.exchange_account_id,
+                stage,
+                event_creation_time: time_manager::now(),
+            }));
+    }
+
+    pub(crate) fn send_max_drawdown_reached_event(
+        &self,
+        peak_equity: Amount,
+        equity: Amount,
+        drawdown_percent: Decimal,
+    ) {
+        self.events_channel
+            .send_expected(ExchangeEvent::MaxDrawdownReached(MaxDrawdownReachedEvent {
+                exchange_account_id: self.exchange_account_id,
+                peak_equity,
+                equity,
+                drawdown_percent,
+                event_creation_time: time_manager::now(),
+            }));
+    }
+
     pub async fn close_active_positions(self: Arc<Self>, cancellation_token: CancellationToken) {
         let positions = self.get_active_positions(cancellation_token.clone()).await;
 
@@ -584,10 +1805,15 @@ impl Exchange {
     pub async fn close_position(
         &self,
         position: &ActivePosition,
-        price: Option<Decimal>,
+        price: Option<Price>,
         cancellation_token: CancellationToken,
     ) -> Option<ClosedPosition> {
-        match self.exchange_client.get_settings().is_margin_trading {
+        match self
+            .exchange_client
+            .get_settings()
+            .account_type
+            .is_derivative()
+        {
             true => {
                 log::info!("Closing position {}", position.id);
 
@@ -637,7 +1863,12 @@ impl Exchange {
         &self,
         cancellation_token: CancellationToken,
     ) -> Vec<ActivePosition> {
-        match self.exchange_client.get_settings().is_margin_trading {
+        match self
+            .exchange_client
+            .get_settings()
+            .account_type
+            .is_derivative()
+        {
             true => {
                 for retry_attempt in 1..=5 {
                     self.timeout_manager
@@ -685,10 +1916,21 @@ impl Exchange {
         }
     }
 
+    fn update_positions_margin_mode(&self, positions: &[DerivativePosition]) {
+        for position in positions {
+            if let Some(margin_mode) = position.margin_mode {
+                self.margin_mode_by_currency_pair
+                    .insert(position.currency_pair, margin_mode);
+            }
+        }
+    }
+
     fn handle_balances_and_positions(
         &self,
         balances_and_positions: ExchangeBalancesAndPositions,
     ) -> ExchangeBalancesAndPositions {
+        self.update_balance_snapshot(&balances_and_positions);
+
         self.events_channel
             .send_expected(ExchangeEvent::BalanceUpdate(BalanceUpdateEvent {
                 exchange_account_id: self.exchange_account_id,
@@ -727,6 +1969,7 @@ impl Exchange {
                 Ok(balance_and_positions) => {
                     if let Some(positions) = &balance_and_positions.positions {
                         self.update_positions_leverage(positions);
+                        self.update_positions_margin_mode(positions);
                     }
                     if balance_and_positions.balances.is_empty() {
                         print_warn(
@@ -818,7 +2061,9 @@ impl Exchange {
             }
             MetricsEventType::MlPrediction
             | MetricsEventType::OrderFromCreateToFill
-            | MetricsEventType::TradeToMl => 0,
+            | MetricsEventType::OrderFromCreateToAck
+            | MetricsEventType::TradeToMl
+            | MetricsEventType::EventLoopStall => 0,
             MetricsEventType::OrderLifeCycle(_) => unimplemented!(),
         };
 
@@ -859,3 +2104,224 @@ fn print_warn(
 ) {
     log::warn!("Failed to {fn_name} for {exchange_account_id} on retry {retry_attempt}: {error:?}");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::{get_test_exchange, order_header};
+    use crate::settings::CurrencyPairMarginMode;
+    use mmb_domain::order::snapshot::UserOrder;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    fn order_info(order_side: OrderSide, price: Price) -> OrderInfo {
+        OrderInfo::new(
+            CurrencyPair::from_codes("eth".into(), "btc".into()),
+            ExchangeOrderId::from(1),
+            ClientOrderId::unique_id(),
+            order_side,
+            OrderStatus::Created,
+            price,
+            amount!(1),
+            price!(0),
+            amount!(0),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, price!(9), false)]
+    #[case(OrderSide::Buy, price!(11), true)]
+    #[case(OrderSide::Buy, price!(12), true)]
+    #[case(OrderSide::Sell, price!(12), false)]
+    #[case(OrderSide::Sell, price!(10), true)]
+    #[case(OrderSide::Sell, price!(9), true)]
+    fn can_be_filled_immediately_by_order_book_top(
+        #[case] order_side: OrderSide,
+        #[case] price: Price,
+        #[case] expected: bool,
+    ) {
+        let order_book_top = OrderBookTop {
+            ask: Some(PriceLevel {
+                price: price!(11),
+                amount: amount!(1),
+            }),
+            bid: Some(PriceLevel {
+                price: price!(10),
+                amount: amount!(1),
+            }),
+            last_update_time: time_manager::now(),
+        };
+
+        let order = order_info(order_side, price);
+        assert_eq!(
+            can_be_filled_immediately(&order, Some(&order_book_top)),
+            expected
+        );
+    }
+
+    #[tokio::test]
+    async fn emit_event_only_on_system_status_transition() {
+        let (exchange, mut events_rx) = get_test_exchange(false);
+
+        exchange.set_system_status(SystemStatus::Maintenance);
+        exchange.set_system_status(SystemStatus::Maintenance);
+        exchange.set_system_status(SystemStatus::Normal);
+
+        let mut transitions = vec![];
+        while let Ok(event) = events_rx.try_recv() {
+            if let ExchangeEvent::SystemStatus(event) = event {
+                transitions.push((event.previous_status, event.status));
+            }
+        }
+
+        assert_eq!(
+            transitions,
+            vec![
+                (SystemStatus::Normal, SystemStatus::Maintenance),
+                (SystemStatus::Maintenance, SystemStatus::Normal),
+            ]
+        );
+        assert_eq!(exchange.system_status(), SystemStatus::Normal);
+    }
+
+    #[tokio::test]
+    async fn aggregate_required_streams_of_strategies() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = CurrencyPair::from_codes("phb".into(), "btc".into());
+        assert_eq!(exchange.required_streams(currency_pair), None);
+
+        exchange.require_streams(currency_pair, [StreamKind::Trades]);
+        exchange.require_streams(currency_pair, [StreamKind::OrderBook, StreamKind::Trades]);
+
+        assert_eq!(
+            exchange.required_streams(currency_pair),
+            Some(HashSet::from([StreamKind::Trades, StreamKind::OrderBook]))
+        );
+    }
+
+    #[test]
+    fn can_be_filled_immediately_without_order_book_top() {
+        let order = order_info(OrderSide::Buy, price!(1));
+        assert!(can_be_filled_immediately(&order, None));
+    }
+
+    fn api_permissions(can_trade: bool, can_withdraw: bool, is_enabled: bool) -> ApiPermissions {
+        ApiPermissions {
+            can_trade,
+            can_withdraw,
+            is_ip_restricted: true,
+            is_enabled,
+        }
+    }
+
+    #[rstest]
+    #[case::trading(api_permissions(true, false, true), true)]
+    #[case::trading_with_withdraw(api_permissions(true, true, true), true)]
+    #[case::without_trading(api_permissions(false, false, true), false)]
+    #[case::disabled(api_permissions(true, false, false), false)]
+    fn check_permissions(#[case] permissions: ApiPermissions, #[case] is_ok: bool) {
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+
+        let result = check_api_permissions(exchange_account_id, AccountType::Spot, &permissions);
+
+        assert_eq!(result.is_ok(), is_ok);
+    }
+
+    #[tokio::test]
+    async fn working_exposure_of_open_orders() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = CurrencyPair::from_codes("phb".into(), "btc".into());
+        let add_order = |side, amount, user_order| {
+            let header = order_header(&exchange, currency_pair, side, amount, user_order);
+            let _ = exchange
+                .orders
+                .add_simple_initial(&header, time_manager::now(), None);
+        };
+
+        add_order(OrderSide::Buy, amount!(10), UserOrder::limit(price!(0.5)));
+        add_order(OrderSide::Buy, amount!(2), UserOrder::limit(price!(0.4)));
+        add_order(OrderSide::Sell, amount!(4), UserOrder::Market);
+
+        // market order is skipped until order book is received
+        let exposure = exchange
+            .get_working_exposure(currency_pair)
+            .expect("in test");
+        assert_eq!(exposure.buy_notional, amount!(5.8));
+        assert_eq!(exposure.sell_notional, amount!(0));
+
+        let _ = exchange.order_book_top.insert(
+            currency_pair,
+            OrderBookTop {
+                ask: None,
+                bid: Some(PriceLevel {
+                    price: price!(0.3),
+                    amount: amount!(1),
+                }),
+                last_update_time: time_manager::now(),
+            },
+        );
+
+        let exposure = exchange
+            .get_working_exposure(currency_pair)
+            .expect("in test");
+        assert_eq!(
+            exposure,
+            WorkingExposure {
+                buy_notional: amount!(5.8),
+                sell_notional: amount!(1.2),
+                net: amount!(4.6),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn margin_mode_of_order_or_settings_is_required() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = CurrencyPair::from_codes("phb".into(), "btc".into());
+        let order_header = order_header(
+            &exchange,
+            currency_pair,
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+        );
+        assert_eq!(exchange.required_margin_mode(&order_header), None);
+
+        exchange.setup_margin_mode(&MarginModeSettings {
+            default: Some(MarginMode::Cross),
+            currency_pairs: vec![CurrencyPairMarginMode {
+                currency_pair,
+                margin_mode: MarginMode::Isolated,
+            }],
+        });
+        assert_eq!(
+            exchange.required_margin_mode(&order_header),
+            Some(MarginMode::Isolated)
+        );
+        let cross_order_header = order_header.clone().with_margin_mode(MarginMode::Cross);
+        assert_eq!(
+            exchange.required_margin_mode(&cross_order_header),
+            Some(MarginMode::Cross)
+        );
+
+        // required margin mode is already set on exchange
+        exchange
+            .margin_mode_by_currency_pair
+            .insert(currency_pair, MarginMode::Isolated);
+        exchange
+            .ensure_margin_mode(&order_header, CancellationToken::default())
+            .await
+            .expect("in test");
+
+        let error = exchange
+            .ensure_margin_mode(&cross_order_header, CancellationToken::default())
+            .await
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::Unsupported);
+    }
+}
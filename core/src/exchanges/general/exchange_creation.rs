@@ -16,6 +16,7 @@ use crate::{
 use mmb_domain::events::ExchangeEvent;
 use mmb_domain::exchanges::commission::Commission;
 use mmb_domain::order::pool::OrdersPool;
+use mmb_utils::infrastructure::WithExpect;
 use tokio::sync::broadcast;
 
 pub fn create_timeout_manager(
@@ -53,8 +54,28 @@ pub async fn create_exchange(
     event_recorder: Arc<EventRecorder>,
 ) -> Arc<Exchange> {
     let exchange_account_id = user_settings.exchange_account_id;
+    // check settings before any requests to exchange
+    for currency_pair in user_settings.currency_pairs.iter().flatten() {
+        currency_pair
+            .validate(user_settings.known_quote_currencies.as_deref())
+            .with_expect(|| format!("Invalid currency pair in settings of {exchange_account_id}"));
+    }
+    if let Some(candles) = &user_settings.candles {
+        candles
+            .validate()
+            .with_expect(|| format!("Invalid candles settings of {exchange_account_id}"));
+    }
+
     let exchange_client_builder =
         &build_settings.supported_exchange_clients[&exchange_account_id.exchange_id];
+    let account_type = user_settings.account_type;
+    if !exchange_client_builder
+        .get_supported_account_types()
+        .contains(&account_type)
+    {
+        panic!("Account type {account_type:?} isn't supported for {exchange_account_id}");
+    }
+
     let orders = OrdersPool::new();
 
     let exchange_client = exchange_client_builder.create_exchange_client(
@@ -65,11 +86,16 @@ pub async fn create_exchange(
         orders.clone(),
     );
 
+    let mut features = exchange_client.features;
+    if let Some(event_source_preference) = &user_settings.event_source_preference {
+        features = features.with_event_source_preference(event_source_preference);
+    }
+
     let exchange = Exchange::new(
         exchange_account_id,
         exchange_client.client,
         orders,
-        exchange_client.features,
+        features,
         exchange_client_builder.get_timeout_arguments(),
         events_channel,
         lifetime_manager,
@@ -79,7 +105,123 @@ pub async fn create_exchange(
         event_recorder,
     );
 
+    if let Some(idempotency_cache_settings) = &user_settings.idempotency_cache {
+        exchange.setup_idempotency_cache(idempotency_cache_settings);
+    }
+
+    if let Some(order_events_merge_settings) = &user_settings.order_events_merge {
+        exchange.setup_order_events_merger(order_events_merge_settings);
+    }
+
+    if let Some(warmup_settings) = &user_settings.warmup {
+        exchange.setup_warmup(warmup_settings);
+    }
+
+    if let Some(regenerate) = user_settings.regenerate_duplicate_client_order_id {
+        exchange.setup_regenerate_duplicate_client_order_id(regenerate);
+    }
+
+    if let Some(require_order_reservation) = user_settings.require_order_reservation {
+        exchange.setup_require_order_reservation(require_order_reservation);
+    }
+
+    if let Some(min_order_lifetime_settings) = &user_settings.min_order_lifetime {
+        exchange.setup_min_order_lifetime(min_order_lifetime_settings);
+    }
+
+    if let Some(order_book_freshness_settings) = &user_settings.order_book_freshness {
+        exchange.setup_order_book_freshness(order_book_freshness_settings);
+    }
+
+    if let Some(price_band_settings) = &user_settings.price_band {
+        exchange.setup_price_band(price_band_settings);
+    }
+
+    if let Some(margin_mode_settings) = &user_settings.margin_mode {
+        exchange.setup_margin_mode(margin_mode_settings);
+    }
+
+    if let Some(order_retry_budget_settings) = &user_settings.order_retry_budget {
+        exchange.setup_order_retry_budget(order_retry_budget_settings);
+    }
+
+    if let Some(fill_events_aggregation_settings) = &user_settings.fill_events_aggregation {
+        exchange.setup_fill_events_aggregation(fill_events_aggregation_settings);
+    }
+
+    if let Some(order_rate_limit_settings) = &user_settings.order_rate_limit {
+        exchange.setup_order_rate_limiter(order_rate_limit_settings);
+    }
+
+    if let Some(clock_skew_settings) = &user_settings.clock_skew {
+        exchange.setup_clock_skew_monitor(clock_skew_settings);
+    }
+
+    if let Some(replace_price_settings) = &user_settings.replace_price {
+        exchange.setup_replace_price_guard(replace_price_settings);
+    }
+
+    if let Some(disabled_order_checks) = &user_settings.disabled_order_checks {
+        exchange.setup_disabled_order_checks(disabled_order_checks);
+    }
+
+    if let Some(breakeven_exit_order_role) = user_settings.breakeven_exit_order_role {
+        exchange.setup_breakeven_exit_order_role(breakeven_exit_order_role);
+    }
+
+    if let Some(ttl_secs) = user_settings.asset_networks_cache_ttl_secs {
+        exchange.setup_asset_networks_cache_ttl(ttl_secs);
+    }
+
+    if let Some(rejection_pause_settings) = &user_settings.rejection_pause {
+        exchange.setup_rejection_pause(rejection_pause_settings);
+    }
+
+    if let Some(balance_change_threshold) = user_settings.balance_change_threshold {
+        exchange.setup_balance_change_threshold(balance_change_threshold);
+    }
+
+    if let Some(grace_period_ms) = user_settings.reconnect_grace_period_ms {
+        exchange.setup_reconnect_grace_period(grace_period_ms);
+    }
+
+    if let Some(adaptive_pacing_settings) = &user_settings.adaptive_pacing {
+        exchange
+            .timeout_manager
+            .setup_adaptive_pacing(exchange_account_id, adaptive_pacing_settings);
+    }
+
+    let capabilities = exchange.exchange_client.capabilities();
+    exchange.setup_max_open_orders_per_currency_pair(
+        user_settings
+            .max_open_orders_per_currency_pair
+            .or(capabilities.max_open_orders_per_currency_pair),
+    );
+
+    if user_settings.endpoint_probing.is_some() {
+        exchange.update_endpoint().await;
+    }
+
+    // private API is required for trading, so check its permissions only if API key is set
+    if !user_settings.api_key.is_empty() {
+        exchange
+            .verify_api_permissions()
+            .await
+            .with_expect(|| format!("Invalid API key for {exchange_account_id}"));
+    }
+
     exchange.build_symbols(&user_settings.currency_pairs).await;
+
+    // trading fees are private information of account
+    if !user_settings.api_key.is_empty() {
+        exchange.update_trading_fees().await;
+    }
+
+    // fees from settings take precedence over ones received from exchange
+    if let Some(currency_pair_fees) = &user_settings.currency_pair_fees {
+        exchange.setup_currency_pair_fees(currency_pair_fees);
+    }
+
     exchange.exchange_client.initialized(exchange.clone()).await;
 
     exchange
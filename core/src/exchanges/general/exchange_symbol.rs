@@ -3,12 +3,13 @@ use itertools::Itertools;
 use mmb_domain::market::CurrencyCode;
 use mmb_utils::infrastructure::WithExpect;
 use rust_decimal_macros::dec;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use crate::settings::CurrencyPairSetting;
-use mmb_domain::exchanges::symbol::Symbol;
-use mmb_domain::market::{CurrencyId, ExchangeAccountId};
+use mmb_domain::exchanges::symbol::{Symbol, SymbolStatus};
+use mmb_domain::market::{CurrencyId, CurrencyPair, ExchangeAccountId};
+use mmb_domain::order::snapshot::OrderType;
 
 use super::exchange::Exchange;
 
@@ -38,6 +39,59 @@ impl Exchange {
         ));
     }
 
+    /// Request symbols again and update trading status and allowed order types of traded ones,
+    /// so orders for currency pairs halted during session are rejected locally
+    pub async fn refresh_symbol_statuses(self: Arc<Self>) {
+        let exchange_symbols = match self.exchange_client.build_all_symbols().await {
+            Ok(exchange_symbols) => exchange_symbols,
+            Err(error) => {
+                log::warn!(
+                    "Unable to refresh symbol statuses for {}: {error:?}",
+                    self.exchange_account_id
+                );
+                return;
+            }
+        };
+
+        let traded_symbols = self.symbols.iter().map(|x| x.value().clone()).collect_vec();
+        for (symbol, status) in get_changed_statuses(&traded_symbols, &exchange_symbols) {
+            let currency_pair = symbol.currency_pair();
+            match status {
+                SymbolStatus::Trading => log::info!(
+                    "{currency_pair} on {} is trading again after {:?}",
+                    self.exchange_account_id,
+                    symbol.status
+                ),
+                status => log::warn!(
+                    "{currency_pair} on {} changed status from {:?} to {status:?}, orders for it are rejected",
+                    self.exchange_account_id,
+                    symbol.status
+                ),
+            }
+
+            let mut updated_symbol = (*symbol).clone();
+            updated_symbol.status = status;
+            self.symbols.insert(currency_pair, Arc::new(updated_symbol));
+        }
+
+        for (currency_pair, allowed_order_types) in
+            get_changed_order_types(&traded_symbols, &exchange_symbols)
+        {
+            log::info!(
+                "Allowed order types of {currency_pair} on {} changed to {allowed_order_types:?}",
+                self.exchange_account_id
+            );
+
+            // symbol can be already updated by status change
+            let symbol = self.symbols.get(&currency_pair).map(|x| x.value().clone());
+            if let Some(symbol) = symbol {
+                let mut updated_symbol = (*symbol).clone();
+                updated_symbol.allowed_order_types = allowed_order_types;
+                self.symbols.insert(currency_pair, Arc::new(updated_symbol));
+            }
+        }
+    }
+
     async fn request_symbols_with_retries(&self) -> Vec<Arc<Symbol>> {
         const MAX_RETRIES: u8 = 5;
         for retry in 0..=MAX_RETRIES {
@@ -104,6 +158,52 @@ fn get_supported_currencies(symbols: &[Arc<Symbol>]) -> DashMap<CurrencyCode, Cu
         .collect()
 }
 
+/// Traded symbols which status differs from received one with their new status.
+/// Symbol which isn't received anymore is considered closed, since exchanges don't list
+/// delisted currency pairs
+fn get_changed_statuses(
+    traded_symbols: &[Arc<Symbol>],
+    exchange_symbols: &[Arc<Symbol>],
+) -> Vec<(Arc<Symbol>, SymbolStatus)> {
+    let received_statuses: HashMap<_, _> = exchange_symbols
+        .iter()
+        .map(|x| (x.currency_pair(), x.status))
+        .collect();
+
+    traded_symbols
+        .iter()
+        .filter_map(|symbol| {
+            let status = received_statuses
+                .get(&symbol.currency_pair())
+                .copied()
+                .unwrap_or(SymbolStatus::Closed);
+            (status != symbol.status).then(|| (symbol.clone(), status))
+        })
+        .collect()
+}
+
+/// Traded symbols which allowed order types differ from received ones with new allowed order
+/// types. Symbols which aren't received anymore are skipped, they are closed by status refresh
+fn get_changed_order_types(
+    traded_symbols: &[Arc<Symbol>],
+    exchange_symbols: &[Arc<Symbol>],
+) -> Vec<(CurrencyPair, Option<Vec<OrderType>>)> {
+    let received_order_types: HashMap<_, _> = exchange_symbols
+        .iter()
+        .map(|x| (x.currency_pair(), &x.allowed_order_types))
+        .collect();
+
+    traded_symbols
+        .iter()
+        .filter_map(|symbol| {
+            let currency_pair = symbol.currency_pair();
+            let allowed_order_types = *received_order_types.get(&currency_pair)?;
+            (*allowed_order_types != symbol.allowed_order_types)
+                .then(|| (currency_pair, allowed_order_types.clone()))
+        })
+        .collect()
+}
+
 fn get_symbols(
     currency_pairs: &[CurrencyPairSetting],
     exchange_symbols: &[Arc<Symbol>],
@@ -143,3 +243,96 @@ fn get_matched_currency_pair(
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::exchanges::symbol::Precision;
+    use rust_decimal_macros::dec;
+
+    fn symbol(base: &str, status: SymbolStatus) -> Arc<Symbol> {
+        let mut symbol = Symbol::new(
+            false,
+            base.into(),
+            base.into(),
+            "usdt".into(),
+            "usdt".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            base.into(),
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            Precision::ByTick { tick: dec!(0.001) },
+        );
+        symbol.status = status;
+        Arc::new(symbol)
+    }
+
+    #[test]
+    fn changed_statuses_of_traded_symbols() {
+        let traded_symbols = [
+            symbol("btc", SymbolStatus::Trading),
+            symbol("eth", SymbolStatus::Trading),
+            symbol("bnb", SymbolStatus::Break),
+            symbol("ltc", SymbolStatus::Trading),
+        ];
+        let exchange_symbols = [
+            symbol("btc", SymbolStatus::Trading),
+            symbol("eth", SymbolStatus::Halt),
+            symbol("bnb", SymbolStatus::Trading),
+            symbol("xrp", SymbolStatus::Break),
+        ];
+
+        let changed_statuses = get_changed_statuses(&traded_symbols, &exchange_symbols)
+            .into_iter()
+            .map(|(symbol, status)| (symbol.currency_pair(), status))
+            .collect_vec();
+
+        let currency_pair = |base: &str| CurrencyPair::from_codes(base.into(), "usdt".into());
+        assert_eq!(
+            changed_statuses,
+            vec![
+                (currency_pair("eth"), SymbolStatus::Halt),
+                (currency_pair("bnb"), SymbolStatus::Trading),
+                (currency_pair("ltc"), SymbolStatus::Closed),
+            ]
+        );
+    }
+
+    #[test]
+    fn changed_order_types_of_traded_symbols() {
+        let with_order_types = |base: &str, order_types: Option<Vec<OrderType>>| {
+            let mut symbol = (*symbol(base, SymbolStatus::Trading)).clone();
+            symbol.allowed_order_types = order_types;
+            Arc::new(symbol)
+        };
+        let limit_only = || Some(vec![OrderType::Limit]);
+        let limit_and_market = || Some(vec![OrderType::Limit, OrderType::Market]);
+
+        let traded_symbols = [
+            with_order_types("btc", limit_and_market()),
+            with_order_types("eth", limit_and_market()),
+            with_order_types("bnb", None),
+            with_order_types("ltc", limit_only()),
+        ];
+        let exchange_symbols = [
+            with_order_types("btc", limit_and_market()),
+            with_order_types("eth", limit_only()),
+            with_order_types("bnb", limit_only()),
+        ];
+
+        let changed_order_types = get_changed_order_types(&traded_symbols, &exchange_symbols);
+
+        let currency_pair = |base: &str| CurrencyPair::from_codes(base.into(), "usdt".into());
+        assert_eq!(
+            changed_order_types,
+            vec![
+                (currency_pair("eth"), limit_only()),
+                (currency_pair("bnb"), limit_only()),
+            ]
+        );
+    }
+}
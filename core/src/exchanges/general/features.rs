@@ -1,3 +1,4 @@
+use crate::settings::EventSourcePreferenceSettings;
 use mmb_domain::events::AllowedEventSourceType;
 
 #[derive(Debug)]
@@ -83,9 +84,14 @@ pub struct OrderFeatures {
     /// Stop loss orders are supported
     // TODO Flag is not used in core, is it redundant?
     pub supports_stop_loss_order: bool,
+    /// Text tag can be attached to order. Otherwise tag is encoded to client order id
+    pub supports_order_tag: bool,
+    /// Max length of client order id including encoded tag
+    pub max_client_order_id_len: Option<usize>,
 }
 
 impl OrderFeatures {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         maker_only: bool,
         supports_get_order_info_by_client_order_id: bool,
@@ -94,6 +100,8 @@ impl OrderFeatures {
         order_was_completed_error_for_cancellation: bool,
         supports_already_cancelled_order: bool,
         supports_stop_loss_order: bool,
+        supports_order_tag: bool,
+        max_client_order_id_len: Option<usize>,
     ) -> Self {
         Self {
             maker_only,
@@ -103,6 +111,8 @@ impl OrderFeatures {
             order_was_completed_error_for_cancellation,
             supports_already_cancelled_order,
             supports_stop_loss_order,
+            supports_order_tag,
+            max_client_order_id_len,
         }
     }
 }
@@ -138,11 +148,11 @@ pub struct ExchangeFeatures {
     /// If empty content string of RestClient response is normal situation for the exchange
     pub empty_response_is_ok: bool,
 
-    // used only for debug
+    /// Sources of events which confirm order creation
     pub allowed_create_event_source_type: AllowedEventSourceType,
-    // used only for debug
+    /// Sources of events which confirm order fills
     pub allowed_fill_event_source_type: AllowedEventSourceType,
-    // used only for debug
+    /// Sources of events which confirm order cancellation
     pub allowed_cancel_event_source_type: AllowedEventSourceType,
 }
 
@@ -171,4 +181,21 @@ impl ExchangeFeatures {
             allowed_cancel_event_source_type,
         }
     }
+
+    /// Overrides exchange client defaults of event sources by specified request types only
+    pub fn with_event_source_preference(
+        mut self,
+        settings: &EventSourcePreferenceSettings,
+    ) -> Self {
+        if let Some(create) = settings.create {
+            self.allowed_create_event_source_type = create;
+        }
+        if let Some(fill) = settings.fill {
+            self.allowed_fill_event_source_type = fill;
+        }
+        if let Some(cancel) = settings.cancel {
+            self.allowed_cancel_event_source_type = cancel;
+        }
+        self
+    }
 }
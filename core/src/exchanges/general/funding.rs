@@ -0,0 +1,97 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::misc::time::time_manager;
+use anyhow::{Context, Result};
+use mmb_domain::events::{ExchangeEvent, FundingPaymentEvent};
+use mmb_domain::exchanges::funding::FundingPayment;
+use mmb_domain::market::CurrencyPair;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::send_expected::SendExpectedByRef;
+use mmb_utils::DateTime;
+use std::sync::Arc;
+
+impl Exchange {
+    /// Funding payments of perpetual futures positions of currency pair within time range
+    pub async fn get_funding_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<FundingPayment>> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetFundingInfo,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .get_funding_history(currency_pair, from, to)
+            .await
+            .with_context(|| {
+                format!(
+                    "Funding history isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get funding history of {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })
+    }
+
+    /// Request funding payments of derivative currency pairs settled since previous update
+    /// and publish them as events. Payments are collected from the first update only
+    pub async fn update_funding_payments(self: Arc<Self>, cancellation_token: CancellationToken) {
+        let currency_pairs: Vec<_> = self
+            .symbols
+            .iter()
+            .filter(|symbol| symbol.is_derivative())
+            .map(|symbol| *symbol.key())
+            .collect();
+
+        for currency_pair in currency_pairs {
+            let now = time_manager::now();
+            let from = *self
+                .funding_payments_updated_at
+                .entry(currency_pair)
+                .or_insert(now);
+            if from >= now {
+                continue;
+            }
+
+            let payments = match self
+                .get_funding_history(currency_pair, from, now, cancellation_token.clone())
+                .await
+            {
+                Ok(payments) => payments,
+                Err(error) => {
+                    // time range isn't moved, so payments will be requested again on next update
+                    log::warn!("{error:?}");
+                    continue;
+                }
+            };
+
+            let _ = self.funding_payments_updated_at.insert(currency_pair, now);
+            for payment in payments {
+                log::info!(
+                    "Funding payment {} {} of {currency_pair} at {} on {}",
+                    payment.amount,
+                    payment.currency_code,
+                    payment.time,
+                    self.exchange_account_id
+                );
+                self.events_channel
+                    .send_expected(ExchangeEvent::FundingPayment(FundingPaymentEvent {
+                        exchange_account_id: self.exchange_account_id,
+                        payment,
+                    }));
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::exchanges::general::exchange::Exchange;
 use crate::exchanges::general::handlers::should_ignore_event;
 use crate::exchanges::traits::ExchangeError;
-use chrono::Utc;
+use crate::misc::time::time_manager;
 use function_name::named;
 use mmb_domain::events::EventSourceType;
 use mmb_domain::market::ExchangeErrorType;
@@ -69,16 +69,25 @@ impl Exchange {
         event_source_type: EventSourceType,
     ) {
         match error.error_type {
-            ExchangeErrorType::OrderNotFound => {
-                self.handle_cancel_order_succeeded(None, exchange_order_id, None, event_source_type)
-            }
+            ExchangeErrorType::OrderNotFound => self.handle_cancel_order_succeeded(
+                None,
+                exchange_order_id,
+                None,
+                event_source_type,
+                None,
+            ),
             ExchangeErrorType::OrderCompleted => nothing_to_do(),
             _ => {
                 if event_source_type == EventSourceType::RestFallback {
                     // TODO Some metrics
                 }
 
-                order.fn_mut(|x| x.set_status(OrderStatus::FailedToCancel, Utc::now()));
+                let is_applied = order
+                    .fn_mut(|x| x.set_status(OrderStatus::FailedToCancel, time_manager::now()));
+                if !is_applied {
+                    log::warn!("Failed cancellation of order {client_order_id} {exchange_order_id:?} on {} is ignored because its status {:?} can't be changed to FailedToCancel", self.exchange_account_id, order.status());
+                    return;
+                }
 
                 self.add_event_on_order_change(order, OrderEventType::CancelOrderFailed)
                     .with_expect(|| format!("Failed to add event CancelOrderFailed on order change {client_order_id:?}"));
@@ -104,6 +113,7 @@ mod test {
     use super::*;
     use crate::exchanges::general::test_helper;
     use crate::exchanges::general::test_helper::get_test_exchange;
+    use chrono::Utc;
     use mmb_domain::events::ExchangeEvent;
     use mmb_domain::market::CurrencyPair;
     use mmb_domain::market::ExchangeErrorType;
@@ -113,7 +123,8 @@ mod test {
         OrderStatusHistory, SystemInternalOrderProps,
     };
     use mmb_domain::order::snapshot::{OrderRole, UserOrder};
-    use rust_decimal_macros::dec;
+    use mmb_domain::{amount, price};
+
     use std::mem::discriminant;
     use tokio::sync::broadcast::error::TryRecvError;
 
@@ -145,8 +156,8 @@ mod test {
 
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-            let order_amount = dec!(12);
-            let order_price = dec!(0.2);
+            let order_amount = amount!(12);
+            let order_price = price!(0.2);
             let order_role = OrderRole::Maker;
 
             let header = OrderHeader::with_user_order(
@@ -201,8 +212,8 @@ mod test {
 
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-            let order_amount = dec!(12);
-            let order_price = dec!(0.2);
+            let order_amount = amount!(12);
+            let order_price = price!(0.2);
             let order_role = OrderRole::Maker;
 
             let header = OrderHeader::with_user_order(
@@ -246,6 +257,64 @@ mod test {
             let error = event_receiver.try_recv().expect_err("should be error");
             assert_eq!(error, TryRecvError::Empty);
         }
+
+        #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+        async fn order_failed_to_create() {
+            // Arrange
+            let (exchange, mut event_receiver) = get_test_exchange(false);
+            let exchange_order_id = ExchangeOrderId::new("test".into());
+            let error =
+                ExchangeError::new(ExchangeErrorType::Unknown, "test_error".to_owned(), None);
+
+            let client_order_id = ClientOrderId::unique_id();
+            let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
+            let order_amount = amount!(12);
+            let order_price = price!(0.2);
+            let order_role = OrderRole::Maker;
+
+            let header = OrderHeader::with_user_order(
+                client_order_id,
+                exchange.exchange_account_id,
+                currency_pair,
+                OrderSide::Buy,
+                order_amount,
+                UserOrder::limit(order_price),
+                None,
+                None,
+                "FromTest".to_owned(),
+            );
+            let props = OrderSimpleProps::new(
+                Utc::now(),
+                Some(order_role),
+                Some(exchange_order_id.clone()),
+                OrderStatus::FailedToCreate,
+                None,
+            );
+            let order = OrderSnapshot::new(
+                header,
+                props,
+                OrderFills::default(),
+                OrderStatusHistory::default(),
+                SystemInternalOrderProps::default(),
+                None,
+            );
+            let order_pool = OrdersPool::new();
+            let order_ref = order_pool.add_snapshot_initial(&order);
+            test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
+
+            // Act
+            exchange.handle_cancel_order_failed(
+                &exchange_order_id,
+                error,
+                EventSourceType::WebSocket,
+            );
+
+            // Assert
+            // status of order which wasn't created can't be changed to FailedToCancel
+            let error = event_receiver.try_recv().expect_err("should be error");
+            assert_eq!(error, TryRecvError::Empty);
+            assert_eq!(order_ref.status(), OrderStatus::FailedToCreate);
+        }
     }
 
     mod order_not_found {
@@ -262,8 +331,8 @@ mod test {
 
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-            let order_amount = dec!(12);
-            let order_price = dec!(0.2);
+            let order_amount = amount!(12);
+            let order_price = price!(0.2);
             let order_role = OrderRole::Maker;
 
             let header = OrderHeader::with_user_order(
@@ -337,8 +406,8 @@ mod test {
 
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-            let order_amount = dec!(12);
-            let order_price = dec!(0.2);
+            let order_amount = amount!(12);
+            let order_price = price!(0.2);
             let order_role = OrderRole::Maker;
 
             let header = OrderHeader::with_user_order(
@@ -421,8 +490,8 @@ mod test {
 
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let order_amount = dec!(12);
-        let order_price = dec!(0.2);
+        let order_amount = amount!(12);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
 
         let header = OrderHeader::with_user_order(
@@ -495,8 +564,8 @@ mod test {
 
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let order_amount = dec!(12);
-        let order_price = dec!(0.2);
+        let order_amount = amount!(12);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
 
         let header = OrderHeader::with_user_order(
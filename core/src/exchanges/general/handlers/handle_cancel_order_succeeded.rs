@@ -1,6 +1,7 @@
 use crate::exchanges::general::exchange::Exchange;
 use crate::exchanges::general::handlers::should_ignore_event;
-use chrono::Utc;
+use crate::misc::time::time_manager;
+use crate::orders::event_merge::{MergeDecision, SourcedEvent};
 use function_name::named;
 use mmb_domain::events::EventSourceType;
 use mmb_domain::order::event::OrderEventType;
@@ -10,6 +11,7 @@ use mmb_domain::order::snapshot::ClientOrderId;
 use mmb_domain::order::snapshot::ExchangeOrderId;
 use mmb_domain::order::snapshot::OrderStatus;
 use mmb_utils::infrastructure::WithExpect;
+use mmb_utils::DateTime;
 
 impl Exchange {
     #[named]
@@ -19,16 +21,18 @@ impl Exchange {
         exchange_order_id: &ExchangeOrderId,
         filled_amount: Option<Amount>,
         source_type: EventSourceType,
+        exchange_time: Option<DateTime>,
     ) {
         log::trace!(
             concat!(
                 "started ",
                 function_name!(),
-                " {:?} {:?} {:?} filled amount {:?}"
+                " {:?} {:?} {:?} {:?} filled amount {:?}"
             ),
             client_order_id,
             exchange_order_id,
             source_type,
+            exchange_time,
             filled_amount,
         );
 
@@ -55,13 +59,16 @@ impl Exchange {
                     .add_order(self.exchange_account_id, exchange_order_id.clone());
 
                 match client_order_id {
-                    Some(client_order_id) => self.raise_order_created(client_order_id, exchange_order_id, source_type),
+                    Some(client_order_id) => self.raise_order_created(client_order_id, exchange_order_id, source_type, None),
                     None => log::error!("cancel_order_succeeded was received for an order which is not in the system {} {exchange_order_id:?}", self.exchange_account_id),
                 }
             }
-            Some(order_ref) => {
-                self.update_local_order(&order_ref, filled_amount, source_type, exchange_order_id)
-            }
+            Some(order_ref) => self.update_local_order(
+                &order_ref,
+                filled_amount,
+                SourcedEvent::new(source_type, exchange_time),
+                exchange_order_id,
+            ),
         }
     }
 
@@ -80,31 +87,83 @@ impl Exchange {
         }
     }
 
+    fn should_replace_cancellation(&self, order: &OrderRef, received: SourcedEvent) -> bool {
+        let applied = order.fn_ref(|x| {
+            x.internal_props
+                .cancellation_event_source_type
+                .map(|source_type| {
+                    SourcedEvent::new(source_type, x.internal_props.cancellation_event_time)
+                })
+        });
+
+        self.order_events_merger.lock().merge(applied, received) == MergeDecision::Replace
+    }
+
+    /// Replace data of already applied cancellation with data from event preferred by merge policy
+    fn reconcile_cancellation(
+        &self,
+        order: &OrderRef,
+        filled_amount: Option<Amount>,
+        received: SourcedEvent,
+        exchange_order_id: &ExchangeOrderId,
+    ) {
+        let previous_source_type = order.fn_mut(|x| {
+            if filled_amount.is_some() {
+                x.internal_props.filled_amount_after_cancellation = filled_amount;
+            }
+
+            x.internal_props.cancellation_event_time = received.exchange_time;
+            x.internal_props
+                .cancellation_event_source_type
+                .replace(received.source_type)
+        });
+
+        log::info!(
+            "Cancellation of order {} {exchange_order_id:?} on {} from {previous_source_type:?} was reconciled with event from {:?}",
+            order.client_order_id(),
+            self.exchange_account_id,
+            received.source_type,
+        );
+    }
+
     fn update_local_order(
         &self,
         order: &OrderRef,
         filled_amount: Option<Amount>,
-        source_type: EventSourceType,
+        received: SourcedEvent,
         exchange_order_id: &ExchangeOrderId,
     ) {
         let client_order_id = order.client_order_id();
         let status = order.status();
 
+        if status == OrderStatus::Canceled && self.should_replace_cancellation(order, received) {
+            self.reconcile_cancellation(order, filled_amount, received, exchange_order_id);
+            return;
+        }
+
         if self.order_already_closed(status, &client_order_id, exchange_order_id) {
             log::trace!("handle_cancel_order_succeeded order_already_closed {status:?}, {client_order_id}, {exchange_order_id:?}");
             return;
         }
 
-        if source_type == EventSourceType::RestFallback {
+        if received.source_type == EventSourceType::RestFallback {
             // TODO some metrics
         }
 
         let is_canceling_from_wait_cancel_order = order.fn_mut(|x| {
-            x.set_status(OrderStatus::Canceled, Utc::now());
+            if !x.set_status(OrderStatus::Canceled, time_manager::now()) {
+                return None;
+            }
+
             x.internal_props.filled_amount_after_cancellation = filled_amount;
-            x.internal_props.cancellation_event_source_type = Some(source_type);
-            x.internal_props.is_canceling_from_wait_cancel_order
+            x.internal_props.cancellation_event_source_type = Some(received.source_type);
+            x.internal_props.cancellation_event_time = received.exchange_time;
+            Some(x.internal_props.is_canceling_from_wait_cancel_order)
         });
+        let Some(is_canceling_from_wait_cancel_order) = is_canceling_from_wait_cancel_order else {
+            log::warn!("Cancellation of order {client_order_id} {exchange_order_id:?} on {} is ignored because status {status:?} can't be changed to Canceled", self.exchange_account_id);
+            return;
+        };
 
         // Here we cover the situation with MakerOnly orders
         // As soon as we created an order, it was automatically canceled
@@ -138,11 +197,14 @@ impl Exchange {
 mod test {
     use super::*;
     use crate::exchanges::general::test_helper;
+    use crate::settings::{EventMergePolicy, OrderEventsMergeSettings};
+    use chrono::{TimeZone, Utc};
     use mmb_domain::events::ExchangeEvent;
     use mmb_domain::market::CurrencyPair;
     use mmb_domain::order::snapshot::{OrderRole, OrderSide};
     use rstest::rstest;
-    use rust_decimal_macros::dec;
+
+    use mmb_domain::{amount, price};
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     #[should_panic(expected = "Received HandleOrderFilled with an empty exchangeOrderId")]
@@ -151,7 +213,7 @@ mod test {
 
         let client_order_id = ClientOrderId::unique_id();
         let exchange_order_id = ExchangeOrderId::new("".into());
-        let filled_amount = dec!(1);
+        let filled_amount = amount!(1);
         let source_type = EventSourceType::Rest;
 
         exchange.handle_cancel_order_succeeded(
@@ -159,6 +221,7 @@ mod test {
             &exchange_order_id,
             Some(filled_amount),
             source_type,
+            None,
         );
     }
 
@@ -186,9 +249,9 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let order_role = OrderRole::Maker;
-        let fill_price = dec!(0.8);
+        let fill_price = price!(0.8);
 
         let order_ref = test_helper::create_order_ref(
             &client_order_id,
@@ -204,9 +267,14 @@ mod test {
         test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
 
         let exchange_order_id = ExchangeOrderId::new("".into());
-        let filled_amount = Some(dec!(5));
+        let filled_amount = Some(amount!(5));
         let source_type = EventSourceType::Rest;
-        exchange.update_local_order(&order_ref, filled_amount, source_type, &exchange_order_id);
+        exchange.update_local_order(
+            &order_ref,
+            filled_amount,
+            SourcedEvent::new(source_type, None),
+            &exchange_order_id,
+        );
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -216,9 +284,9 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let order_role = OrderRole::Maker;
-        let fill_price = dec!(0.8);
+        let fill_price = price!(0.8);
 
         let order_ref = test_helper::create_order_ref(
             &client_order_id,
@@ -233,9 +301,14 @@ mod test {
         test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
 
         let exchange_order_id = ExchangeOrderId::new("".into());
-        let filled_amount = Some(dec!(5));
+        let filled_amount = Some(amount!(5));
         let source_type = EventSourceType::Rest;
-        exchange.update_local_order(&order_ref, filled_amount, source_type, &exchange_order_id);
+        exchange.update_local_order(
+            &order_ref,
+            filled_amount,
+            SourcedEvent::new(source_type, None),
+            &exchange_order_id,
+        );
 
         let changed_amount =
             order_ref.fn_ref(|x| x.internal_props.filled_amount_after_cancellation);
@@ -250,9 +323,9 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let order_role = OrderRole::Maker;
-        let fill_price = dec!(0.8);
+        let fill_price = price!(0.8);
 
         let order_ref = test_helper::create_order_ref(
             &client_order_id,
@@ -267,9 +340,14 @@ mod test {
         test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
 
         let exchange_order_id = ExchangeOrderId::new("".into());
-        let filled_amount = Some(dec!(5));
+        let filled_amount = Some(amount!(5));
         let source_type = EventSourceType::Rest;
-        exchange.update_local_order(&order_ref, filled_amount, source_type, &exchange_order_id);
+        exchange.update_local_order(
+            &order_ref,
+            filled_amount,
+            SourcedEvent::new(source_type, None),
+            &exchange_order_id,
+        );
 
         let order_status = order_ref.status();
         assert_eq!(order_status, OrderStatus::Canceled);
@@ -287,9 +365,9 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let order_role = OrderRole::Maker;
-        let fill_price = dec!(0.8);
+        let fill_price = price!(0.8);
 
         let order_ref = test_helper::create_order_ref(
             &client_order_id,
@@ -304,9 +382,14 @@ mod test {
         test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
 
         let exchange_order_id = ExchangeOrderId::new("".into());
-        let filled_amount = Some(dec!(5));
+        let filled_amount = Some(amount!(5));
         let source_type = EventSourceType::Rest;
-        exchange.update_local_order(&order_ref, filled_amount, source_type, &exchange_order_id);
+        exchange.update_local_order(
+            &order_ref,
+            filled_amount,
+            SourcedEvent::new(source_type, None),
+            &exchange_order_id,
+        );
 
         let canceled_not_from_wait_cancel_order =
             order_ref.fn_ref(|x| x.internal_props.canceled_not_from_wait_cancel_order);
@@ -320,4 +403,73 @@ mod test {
         let gotten_id = event.order.client_order_id();
         assert_eq!(gotten_id, client_order_id);
     }
+
+    fn exchange_time(secs: i64) -> Option<DateTime> {
+        Utc.timestamp_opt(secs, 0).single()
+    }
+
+    #[rstest]
+    #[case::first_wins(EventMergePolicy::FirstWins, 11, EventSourceType::WebSocket, amount!(5))]
+    #[case::source_priority(EventMergePolicy::SourcePriority, 11, EventSourceType::Rest, amount!(6))]
+    #[case::latest_timestamp_wins(
+        EventMergePolicy::LatestTimestampWins,
+        11,
+        EventSourceType::Rest,
+        amount!(6)
+    )]
+    #[case::outdated(
+        EventMergePolicy::LatestTimestampWins,
+        9,
+        EventSourceType::WebSocket,
+        amount!(5)
+    )]
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn merge_cancellation_events_from_different_sources(
+        #[case] policy: EventMergePolicy,
+        #[case] rest_exchange_time: i64,
+        #[case] expected_source_type: EventSourceType,
+        #[case] expected_filled_amount: Amount,
+    ) {
+        let (exchange, _rx) = test_helper::get_test_exchange(false);
+        exchange.setup_order_events_merger(&OrderEventsMergeSettings {
+            policy,
+            ..Default::default()
+        });
+
+        let client_order_id = ClientOrderId::unique_id();
+        let order_ref = test_helper::create_order_ref(
+            &client_order_id,
+            Some(OrderRole::Maker),
+            exchange.exchange_account_id,
+            CurrencyPair::from_codes("PHB".into(), "BTC".into()),
+            price!(0.8),
+            amount!(12),
+            OrderSide::Buy,
+        );
+        test_helper::try_add_snapshot_by_exchange_id(&exchange, &order_ref);
+
+        let exchange_order_id = ExchangeOrderId::new("".into());
+        exchange.update_local_order(
+            &order_ref,
+            Some(amount!(5)),
+            SourcedEvent::new(EventSourceType::WebSocket, exchange_time(10)),
+            &exchange_order_id,
+        );
+        exchange.update_local_order(
+            &order_ref,
+            Some(amount!(6)),
+            SourcedEvent::new(EventSourceType::Rest, exchange_time(rest_exchange_time)),
+            &exchange_order_id,
+        );
+
+        let (source_type, filled_amount) = order_ref.fn_ref(|x| {
+            (
+                x.internal_props.cancellation_event_source_type,
+                x.internal_props.filled_amount_after_cancellation,
+            )
+        });
+        assert_eq!(order_ref.status(), OrderStatus::Canceled);
+        assert_eq!(source_type, Some(expected_source_type));
+        assert_eq!(filled_amount, Some(expected_filled_amount));
+    }
 }
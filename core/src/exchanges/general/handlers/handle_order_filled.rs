@@ -1,4 +1,6 @@
 use crate::exchanges::general::handlers::should_ignore_event;
+use crate::math::{DecimalComputation, RoundForComputation};
+use crate::misc::time::time_manager;
 use crate::{exchanges::general::exchange::Exchange, math::ConvertPercentToRate};
 use chrono::Utc;
 use function_name::named;
@@ -18,7 +20,6 @@ use mmb_domain::order::snapshot::{
 };
 use mmb_utils::DateTime;
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 use std::sync::Arc;
 use uuid::Uuid;
 
@@ -112,11 +113,10 @@ impl Exchange {
 
         self.add_special_order_if_need(fill_event, &args_to_log);
 
-        match self
-            .orders
-            .cache_by_exchange_id
-            .get(&fill_event.exchange_order_id)
-        {
+        match self.orders.find(
+            &fill_event.exchange_order_id,
+            fill_event.client_order_id.as_ref(),
+        ) {
             None => {
                 log::info!("Received a fill for not existing order {args_to_log:?}",);
 
@@ -129,6 +129,7 @@ impl Exchange {
                         client_order_id,
                         &fill_event.exchange_order_id,
                         fill_event.source_type,
+                        None,
                     );
                 }
             }
@@ -224,12 +225,12 @@ impl Exchange {
         order_fills: &[OrderFill],
         order_filled_amount: Amount,
         order_ref: &OrderRef,
-    ) -> Option<(Price, Amount, Decimal)> {
+    ) -> Option<(Price, Amount, Amount)> {
         fn calc_last_fill(
             fill_event: &FillEvent,
             last_fill_amount: Amount,
             symbol: &Symbol,
-        ) -> (Price, Amount, Decimal) {
+        ) -> (Price, Amount, Amount) {
             let last_fill_price = fill_event.fill_price;
             let last_fill_cost = if !symbol.is_derivative() {
                 last_fill_amount * last_fill_price
@@ -282,12 +283,12 @@ impl Exchange {
     fn calculate_cost_diff(
         order_fills: &[OrderFill],
         order_ref: &OrderRef,
-        last_fill_cost: Decimal,
-    ) -> Option<Decimal> {
+        last_fill_cost: Amount,
+    ) -> Option<Amount> {
         // Diff should be calculated only if it is not the first fill
-        let total_filled_cost: Decimal = order_fills.iter().map(|fill| fill.cost()).sum();
+        let total_filled_cost: Amount = order_fills.iter().map(|fill| fill.cost()).sum();
         let cost_diff = last_fill_cost - total_filled_cost;
-        if cost_diff <= dec!(0) {
+        if cost_diff <= Amount::ZERO {
             log::warn!("cost_diff is {cost_diff} which is <= 0 for {order_ref:?}");
             return None;
         }
@@ -299,14 +300,14 @@ impl Exchange {
         last_fill_amount: Amount,
         order_filled_amount: Amount,
         symbol: &Symbol,
-        cost_diff: Price,
-    ) -> (Price, Amount, Price) {
+        cost_diff: Amount,
+    ) -> (Price, Amount, Amount) {
         let amount_diff = last_fill_amount - order_filled_amount;
-        let res_fill_price = if !symbol.is_derivative() {
+        let res_fill_price = Price::new(if !symbol.is_derivative() {
             cost_diff / amount_diff
         } else {
             amount_diff / cost_diff
-        };
+        });
         let last_fill_price = symbol.price_round(res_fill_price, Round::ToNearest);
 
         let last_fill_amount = amount_diff;
@@ -317,7 +318,7 @@ impl Exchange {
 
     fn set_commission_amount(fill_event: &mut FillEvent, order_fills: &[OrderFill]) {
         if let Some(commission_amount) = fill_event.commission_amount {
-            let current_commission: Decimal = order_fills
+            let current_commission: Amount = order_fills
                 .iter()
                 .map(|fill| fill.commission_amount())
                 .sum();
@@ -382,13 +383,23 @@ impl Exchange {
                         last_fill_amount,
                         last_fill_price,
                     );
-                last_fill_amount_in_currency_code * commission_rate
+                (last_fill_amount_in_currency_code * commission_rate)
+                    .round_for(DecimalComputation::Commission)
             }
         }
     }
 
-    fn set_commission_rate(&self, fill_event: &mut FillEvent, order_role: OrderRole) -> Decimal {
-        let commission = self.commission.get_commission(order_role).fee;
+    fn set_commission_rate(
+        &self,
+        fill_event: &mut FillEvent,
+        currency_pair: CurrencyPair,
+        order_role: OrderRole,
+    ) -> Decimal {
+        let commission = self
+            .commission
+            .lock()
+            .get_commission(currency_pair, order_role)
+            .fee;
         let expected_commission_rate = commission.percent_to_rate();
 
         if fill_event.commission_amount.is_none() && fill_event.commission_rate.is_none() {
@@ -418,7 +429,8 @@ impl Exchange {
                         .as_ref()
                         .expect("There are no top bid in order book");
                     let price_bnb_quote = bid.price;
-                    *converted_commission_amount = commission_amount * price_bnb_quote;
+                    *converted_commission_amount = (commission_amount * price_bnb_quote)
+                        .round_for(DecimalComputation::Commission);
                     *converted_commission_currency_code = symbol.quote_currency_code();
                 }
                 None => {
@@ -434,7 +446,8 @@ impl Exchange {
                                 .as_ref()
                                 .expect("There are no top ask in order book");
                             let price_quote_bnb = ask.price;
-                            *converted_commission_amount = commission_amount / price_quote_bnb;
+                            *converted_commission_amount = (commission_amount / price_quote_bnb)
+                                .round_for(DecimalComputation::Commission);
                             *converted_commission_currency_code = symbol.quote_currency_code();
                         }
                         None => log::error!(
@@ -459,17 +472,42 @@ impl Exchange {
         }
     }
 
-    fn send_order_filled_event(&self, order_ref: &OrderRef) {
+    fn send_order_filled_event(
+        &self,
+        order_ref: &OrderRef,
+        last_fill_notional: Amount,
+        is_order_completed: bool,
+    ) {
+        let Some(fills_count) =
+            self.fills_count_to_report(order_ref, last_fill_notional, is_order_completed)
+        else {
+            return;
+        };
+
         let cloned_order = Arc::new(order_ref.deep_clone());
-        self.add_event_on_order_change(order_ref, OrderEventType::OrderFilled { cloned_order })
-            .expect("Unable to send event, probably receiver is dropped already");
+        self.add_event_on_order_change(
+            order_ref,
+            OrderEventType::OrderFilled {
+                cloned_order,
+                fills_count,
+            },
+        )
+        .expect("Unable to send event, probably receiver is dropped already");
     }
 
     fn react_if_order_completed(&self, order_filled_amount: Amount, order_ref: &OrderRef) {
         if order_filled_amount == order_ref.amount() {
-            order_ref.fn_mut(|order| {
-                order.set_status(OrderStatus::Completed, Utc::now());
-            });
+            let is_completed = order_ref
+                .fn_mut(|order| order.set_status(OrderStatus::Completed, time_manager::now()));
+            if !is_completed {
+                log::warn!(
+                    "Completion of order {} on {} is ignored because its status {:?} can't be changed to Completed",
+                    order_ref.client_order_id(),
+                    self.exchange_account_id,
+                    order_ref.status()
+                );
+                return;
+            }
 
             let cloned_order = Arc::new(order_ref.deep_clone());
             self.add_event_on_order_change(
@@ -491,7 +529,7 @@ impl Exchange {
         converted_commission_currency_code: CurrencyCode,
         last_fill_amount: Amount,
         last_fill_price: Price,
-        last_fill_cost: Decimal,
+        last_fill_cost: Amount,
         expected_commission_rate: Percent,
         commission_amount: Amount,
         order_role: OrderRole,
@@ -507,7 +545,11 @@ impl Exchange {
         let expected_converted_commission_amount =
             last_fill_amount_in_converted_commission_currency_code * expected_commission_rate;
 
-        let referral_reward = self.commission.get_commission(order_role).referral_reward;
+        let referral_reward = self
+            .commission
+            .lock()
+            .get_commission(order_ref.currency_pair(), order_role)
+            .referral_reward;
         let referral_reward_amount = commission_amount * referral_reward.percent_to_rate();
 
         let rounded_fill_price = symbol.price_round(last_fill_price, Round::ToNearest);
@@ -542,6 +584,7 @@ impl Exchange {
             self.exchange_account_id
         );
 
+        self.update_position_cost(symbol, side, &order_fill);
         order_ref.fn_mut(move |order| order.add_fill(order_fill));
     }
 
@@ -582,7 +625,10 @@ impl Exchange {
             return;
         }
 
-        log::info!("Received fill {fill_event:?} {last_fill_price} {last_fill_amount}");
+        log::info!(
+            "Received fill {fill_event:?} {last_fill_price} {last_fill_amount}, correlation_id: {}",
+            order_ref.correlation_id()
+        );
 
         let commission_currency_code = fill_event
             .commission_currency_code
@@ -590,7 +636,8 @@ impl Exchange {
 
         let order_role = Self::get_order_role(fill_event, order_ref);
 
-        let expected_commission_rate = self.set_commission_rate(fill_event, order_role);
+        let expected_commission_rate =
+            self.set_commission_rate(fill_event, order_ref.currency_pair(), order_role);
 
         let commission_amount = Self::get_commission_amount(
             fill_event.commission_amount,
@@ -635,7 +682,11 @@ impl Exchange {
 
         self.panic_if_fill_amounts_conformity(order_filled_amount, order_ref);
 
-        self.send_order_filled_event(order_ref);
+        self.send_order_filled_event(
+            order_ref,
+            last_fill_amount * last_fill_price,
+            order_filled_amount == order_ref.amount(),
+        );
 
         if fill_event.source_type == EventSourceType::RestFallback {
             // TODO some metrics
@@ -704,6 +755,7 @@ impl Exchange {
                     &order.client_order_id(),
                     &fill_event.exchange_order_id,
                     fill_event.source_type,
+                    None,
                 )
                 .expect("Error handle create order succeeded");
             }
@@ -751,6 +803,8 @@ mod test {
         SystemInternalOrderProps,
     };
     use mmb_domain::order::snapshot::{OrderType, UserOrder};
+    use mmb_domain::{amount, price};
+    use rust_decimal_macros::dec;
     use serde_json::json;
     use uuid::Uuid;
 
@@ -769,9 +823,9 @@ mod test {
                 trade_id: Some(trade_id_from_str("empty")),
                 client_order_id: None,
                 exchange_order_id: ExchangeOrderId::new("test".into()),
-                fill_price: dec!(0),
+                fill_price: price!(0),
                 fill_amount: FillAmount::Total {
-                    total_filled_amount: dec!(0),
+                    total_filled_amount: amount!(0),
                 },
                 order_role: None,
                 commission_currency_code: None,
@@ -796,9 +850,9 @@ mod test {
                 trade_id: Some(trade_id_from_str("empty")),
                 client_order_id: Some(ClientOrderId::unique_id()),
                 exchange_order_id: ExchangeOrderId::new("test".into()),
-                fill_price: dec!(0),
+                fill_price: price!(0),
                 fill_amount: FillAmount::Total {
-                    total_filled_amount: dec!(0),
+                    total_filled_amount: amount!(0),
                 },
                 order_role: None,
                 commission_currency_code: None,
@@ -808,7 +862,7 @@ mod test {
                 special_order_data: Some(SpecialOrderData {
                     currency_pair: CurrencyPair::from_codes("te".into(), "st".into()),
                     order_side: OrderSide::Buy,
-                    order_amount: dec!(7),
+                    order_amount: amount!(7),
                 }),
                 fill_date: None,
             };
@@ -821,11 +875,11 @@ mod test {
         async fn should_add_order() {
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let order_amount = dec!(12);
+            let order_amount = amount!(12);
             let order_role = None;
-            let fill_price = dec!(0.2);
+            let fill_price = price!(0.2);
             let fill_amount = FillAmount::Total {
-                total_filled_amount: dec!(5),
+                total_filled_amount: amount!(5),
             };
 
             let mut fill_event = FillEvent {
@@ -878,9 +932,9 @@ mod test {
                 trade_id: Some(trade_id_from_str("empty")),
                 client_order_id: None,
                 exchange_order_id: ExchangeOrderId::new("".into()),
-                fill_price: dec!(0),
+                fill_price: price!(0),
                 fill_amount: FillAmount::Total {
-                    total_filled_amount: dec!(0),
+                    total_filled_amount: amount!(0),
                 },
                 order_role: None,
                 commission_currency_code: None,
@@ -890,7 +944,7 @@ mod test {
                 special_order_data: Some(SpecialOrderData {
                     currency_pair: CurrencyPair::from_codes("te".into(), "st".into()),
                     order_side: OrderSide::Buy,
-                    order_amount: dec!(0),
+                    order_amount: amount!(0),
                 }),
                 fill_date: None,
             };
@@ -907,10 +961,10 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("te".into(), "st".into());
         let order_side = OrderSide::Buy;
-        let order_price = dec!(1);
-        let order_amount = dec!(1);
+        let order_price = price!(1);
+        let order_amount = amount!(1);
         let trade_id = trade_id_from_str("test_trade_id");
-        let total_filled_amount = dec!(0.2);
+        let total_filled_amount = amount!(0.2);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
@@ -920,7 +974,7 @@ mod test {
             trade_id: Some(trade_id.clone()),
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0),
+            fill_price: price!(0),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -930,7 +984,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair: CurrencyPair::from_codes("te".into(), "st".into()),
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -947,7 +1001,7 @@ mod test {
             "FromTest",
         );
 
-        let cost = dec!(0);
+        let cost = amount!(0);
         let order_fill = OrderFill::new(
             Uuid::new_v4(),
             None,
@@ -959,11 +1013,11 @@ mod test {
             cost,
             OrderFillRole::Taker,
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             false,
             None,
             None,
@@ -985,14 +1039,14 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("te".into(), "st".into());
         let order_side = OrderSide::Buy;
-        let order_price = dec!(1);
-        let incremental_fill_amount = dec!(0.2);
+        let order_price = price!(1);
+        let incremental_fill_amount = amount!(0.2);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
 
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = trade_id_from_str("test_trade_id");
 
         let mut fill_event = FillEvent {
@@ -1000,7 +1054,7 @@ mod test {
             trade_id: Some(trade_id),
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0),
+            fill_price: price!(0),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1010,7 +1064,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair: CurrencyPair::from_codes("te".into(), "st".into()),
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1027,7 +1081,7 @@ mod test {
             "FromTest",
         );
 
-        let cost = dec!(0);
+        let cost = amount!(0);
         let order_fill = OrderFill::new(
             Uuid::new_v4(),
             None,
@@ -1039,11 +1093,11 @@ mod test {
             cost,
             OrderFillRole::Taker,
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             false,
             None,
             None,
@@ -1065,12 +1119,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("te".into(), "st".into());
         let order_side = OrderSide::Buy;
-        let order_price = dec!(1);
-        let total_filled_amount = dec!(0.2);
+        let order_price = price!(1);
+        let total_filled_amount = amount!(0.2);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -1078,7 +1132,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0),
+            fill_price: price!(0),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1088,7 +1142,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair: CurrencyPair::from_codes("te".into(), "st".into()),
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1105,7 +1159,7 @@ mod test {
             "FromTest",
         );
 
-        let cost = dec!(0);
+        let cost = amount!(0);
         let order_fill = OrderFill::new(
             Uuid::new_v4(),
             None,
@@ -1117,11 +1171,11 @@ mod test {
             cost,
             OrderFillRole::Taker,
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             false,
             None,
             None,
@@ -1143,13 +1197,13 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let order_price = dec!(1);
-        let incremental_fill_amount = dec!(0);
+        let order_price = price!(1);
+        let incremental_fill_amount = amount!(0);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -1157,7 +1211,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.2),
+            fill_price: price!(0.2),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1167,7 +1221,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1184,7 +1238,7 @@ mod test {
             "FromTest",
         );
 
-        let cost = dec!(0);
+        let cost = amount!(0);
         let order_fill = OrderFill::new(
             Uuid::new_v4(),
             None,
@@ -1196,11 +1250,11 @@ mod test {
             cost,
             OrderFillRole::Taker,
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             CurrencyCode::new("test"),
-            dec!(0),
-            dec!(0),
+            amount!(0),
+            amount!(0),
             true,
             None,
             None,
@@ -1212,7 +1266,7 @@ mod test {
         exchange.create_and_add_order_fill(&mut fill_event, &order_ref);
 
         let (_, order_filled_amount) = order_ref.get_fills();
-        assert_eq!(order_filled_amount, dec!(0));
+        assert_eq!(order_filled_amount, amount!(0));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -1223,12 +1277,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(1);
+        let incremental_fill_amount = amount!(1);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -1236,7 +1290,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.2),
+            fill_price: price!(0.2),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1246,7 +1300,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1278,12 +1332,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(1);
+        let incremental_fill_amount = amount!(1);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -1291,7 +1345,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.2),
+            fill_price: price!(0.2),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1301,7 +1355,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1332,14 +1386,14 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(1);
+        let incremental_fill_amount = amount!(1);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
-        let fill_price = dec!(0.2);
+        let fill_price = price!(0.2);
 
         let mut fill_event = FillEvent {
             source_type: EventSourceType::WebSocket,
@@ -1356,7 +1410,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1388,15 +1442,15 @@ mod test {
         let (exchange, _event_receiver) = get_test_exchange(false);
 
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let total_filled_amount = dec!(5);
+        let total_filled_amount = amount!(5);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let client_order_id = ClientOrderId::unique_id();
         let order_side = OrderSide::Buy;
-        let order_price = dec!(0.2);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
         let exchange_order_id: ExchangeOrderId = "some_order_id".into();
 
@@ -1437,17 +1491,17 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.2),
+            fill_price: price!(0.2),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.01)),
+            commission_amount: Some(amount!(0.01)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1459,19 +1513,19 @@ mod test {
             trade_id: Some(trade_id_from_str("another_trade_id")),
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.3),
+            fill_price: price!(0.3),
             fill_amount: FillAmount::Total {
-                total_filled_amount: dec!(10),
+                total_filled_amount: amount!(10),
             },
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.03)),
+            commission_amount: Some(amount!(0.03)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1487,13 +1541,13 @@ mod test {
 
         assert_eq!(fills.len(), 2);
         let first_fill = &fills[0];
-        assert_eq!(first_fill.price(), dec!(0.2));
-        assert_eq!(first_fill.amount(), dec!(5));
-        assert_eq!(first_fill.commission_amount(), dec!(0.01));
+        assert_eq!(first_fill.price(), price!(0.2));
+        assert_eq!(first_fill.amount(), amount!(5));
+        assert_eq!(first_fill.commission_amount(), amount!(0.01));
         let second_fill = &fills[1];
-        assert_eq!(second_fill.price(), dec!(0.4));
-        assert_eq!(second_fill.amount(), dec!(5));
-        assert_eq!(second_fill.commission_amount(), dec!(0.02));
+        assert_eq!(second_fill.price(), price!(0.4));
+        assert_eq!(second_fill.amount(), amount!(5));
+        assert_eq!(second_fill.commission_amount(), amount!(0.02));
     }
 
     // TODO Can be improved via testing only calculate_cost_diff_function
@@ -1502,15 +1556,15 @@ mod test {
         let (exchange, _event_receiver) = get_test_exchange(false);
 
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let total_filled_amount = dec!(5);
+        let total_filled_amount = amount!(5);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let client_order_id = ClientOrderId::unique_id();
         let order_side = OrderSide::Buy;
-        let order_price = dec!(0.2);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
         let exchange_order_id: ExchangeOrderId = "some_order_id".into();
 
@@ -1552,17 +1606,17 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.2),
+            fill_price: price!(0.2),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.01)),
+            commission_amount: Some(amount!(0.01)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1574,19 +1628,19 @@ mod test {
             trade_id: Some(trade_id_from_str("another_trade_id")),
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.3),
+            fill_price: price!(0.3),
             fill_amount: FillAmount::Total {
-                total_filled_amount: dec!(10),
+                total_filled_amount: amount!(10),
             },
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.03)),
+            commission_amount: Some(amount!(0.03)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1602,13 +1656,13 @@ mod test {
 
         assert_eq!(fills.len(), 2);
         let first_fill = &fills[0];
-        assert_eq!(first_fill.price(), dec!(0.2));
-        assert_eq!(first_fill.amount(), dec!(5));
-        assert_eq!(first_fill.commission_amount(), dec!(0.01));
+        assert_eq!(first_fill.price(), price!(0.2));
+        assert_eq!(first_fill.amount(), amount!(5));
+        assert_eq!(first_fill.commission_amount(), amount!(0.01));
         let second_fill = &fills[1];
-        assert_eq!(second_fill.price(), dec!(0.4));
-        assert_eq!(second_fill.amount(), dec!(5));
-        assert_eq!(second_fill.commission_amount(), dec!(0.02));
+        assert_eq!(second_fill.price(), price!(0.4));
+        assert_eq!(second_fill.amount(), amount!(5));
+        assert_eq!(second_fill.commission_amount(), amount!(0.02));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -1616,15 +1670,15 @@ mod test {
         let (exchange, _event_receiver) = get_test_exchange(true);
 
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let total_filled_amount = dec!(5);
+        let total_filled_amount = amount!(5);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let client_order_id = ClientOrderId::unique_id();
         let order_side = OrderSide::Buy;
-        let order_price = dec!(0.2);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
         let exchange_order_id: ExchangeOrderId = "some_order_id".into();
 
@@ -1665,17 +1719,17 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(2000),
+            fill_price: price!(2000),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.01)),
+            commission_amount: Some(amount!(0.01)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1687,19 +1741,19 @@ mod test {
             trade_id: Some(trade_id_from_str("another_trade_id")),
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(3000),
+            fill_price: price!(3000),
             fill_amount: FillAmount::Total {
-                total_filled_amount: dec!(10),
+                total_filled_amount: amount!(10),
             },
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.03)),
+            commission_amount: Some(amount!(0.03)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1714,18 +1768,18 @@ mod test {
 
         let (fills, filled_amount) = order_ref.get_fills();
 
-        assert_eq!(filled_amount, dec!(10));
+        assert_eq!(filled_amount, amount!(10));
         assert_eq!(fills.len(), 2);
 
         let first_fill = &fills[0];
-        assert_eq!(first_fill.price(), dec!(2000));
-        assert_eq!(first_fill.amount(), dec!(5));
-        assert_eq!(first_fill.commission_amount(), dec!(0.01));
+        assert_eq!(first_fill.price(), price!(2000));
+        assert_eq!(first_fill.amount(), amount!(5));
+        assert_eq!(first_fill.commission_amount(), amount!(0.01));
 
         let second_fill = &fills[1];
-        assert_eq!(second_fill.price(), dec!(6000));
-        assert_eq!(second_fill.amount(), dec!(5));
-        assert_eq!(second_fill.commission_amount(), dec!(0.02));
+        assert_eq!(second_fill.price(), price!(6000));
+        assert_eq!(second_fill.amount(), amount!(5));
+        assert_eq!(second_fill.commission_amount(), amount!(0.02));
     }
 
     // TODO Why do we need tests like this?
@@ -1735,15 +1789,15 @@ mod test {
         let (exchange, _event_receiver) = get_test_exchange(true);
 
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let total_filled_amount = dec!(5);
+        let total_filled_amount = amount!(5);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let client_order_id = ClientOrderId::unique_id();
         let order_side = OrderSide::Buy;
-        let order_price = dec!(0.2);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
         let exchange_order_id: ExchangeOrderId = "some_order_id".into();
 
@@ -1784,17 +1838,17 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(2000),
+            fill_price: price!(2000),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.01)),
+            commission_amount: Some(amount!(0.01)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1806,19 +1860,19 @@ mod test {
             trade_id: Some(trade_id_from_str("another_trade_id")),
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(3000),
+            fill_price: price!(3000),
             fill_amount: FillAmount::Total {
-                total_filled_amount: dec!(10),
+                total_filled_amount: amount!(10),
             },
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.03)),
+            commission_amount: Some(amount!(0.03)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1833,18 +1887,18 @@ mod test {
 
         let (fills, filled_amount) = order_ref.get_fills();
 
-        assert_eq!(filled_amount, dec!(10));
+        assert_eq!(filled_amount, amount!(10));
         assert_eq!(fills.len(), 2);
 
         let first_fill = &fills[0];
-        assert_eq!(first_fill.price(), dec!(2000));
-        assert_eq!(first_fill.amount(), dec!(5));
-        assert_eq!(first_fill.commission_amount(), dec!(0.01));
+        assert_eq!(first_fill.price(), price!(2000));
+        assert_eq!(first_fill.amount(), amount!(5));
+        assert_eq!(first_fill.commission_amount(), amount!(0.01));
 
         let second_fill = &fills[1];
-        assert_eq!(second_fill.price(), dec!(6000));
-        assert_eq!(second_fill.amount(), dec!(5));
-        assert_eq!(second_fill.commission_amount(), dec!(0.02));
+        assert_eq!(second_fill.price(), price!(6000));
+        assert_eq!(second_fill.amount(), amount!(5));
+        assert_eq!(second_fill.commission_amount(), amount!(0.02));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -1852,15 +1906,15 @@ mod test {
         let (exchange, _event_receiver) = get_test_exchange(false);
 
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
-        let total_filled_amount = dec!(5);
+        let total_filled_amount = amount!(5);
         let fill_amount = FillAmount::Total {
             total_filled_amount,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let client_order_id = ClientOrderId::unique_id();
         let order_side = OrderSide::Buy;
-        let order_price = dec!(0.2);
+        let order_price = price!(0.2);
         let order_role = OrderRole::Maker;
         let exchange_order_id: ExchangeOrderId = "some_order_id".into();
 
@@ -1901,17 +1955,17 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.01)),
+            commission_amount: Some(amount!(0.01)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1923,19 +1977,19 @@ mod test {
             trade_id: Some(trade_id_from_str("another_trade_id")),
             client_order_id: None,
             exchange_order_id: exchange_order_id.clone(),
-            fill_price: dec!(0.3),
+            fill_price: price!(0.3),
             fill_amount: FillAmount::Total {
-                total_filled_amount: dec!(10),
+                total_filled_amount: amount!(10),
             },
             order_role: None,
             commission_currency_code: None,
             commission_rate: None,
-            commission_amount: Some(dec!(0.03)),
+            commission_amount: Some(amount!(0.03)),
             fill_type: OrderFillType::Liquidation,
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1959,12 +2013,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
-            total_filled_amount: Some(dec!(9)),
+            total_filled_amount: Some(amount!(9)),
         };
-        let order_amount = dec!(1);
+        let order_amount = amount!(1);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -1972,7 +2026,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -1982,7 +2036,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -1998,7 +2052,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2016,12 +2070,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -2029,7 +2083,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: Some(OrderRole::Taker),
             commission_currency_code: None,
@@ -2039,7 +2093,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -2055,7 +2109,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2066,7 +2120,7 @@ mod test {
         assert_eq!(fills.len(), 1);
 
         let fill = &fills[0];
-        let right_value = dec!(0.2) / dec!(100) * dec!(5);
+        let right_value = dec!(0.2) / dec!(100) * amount!(5);
         assert_eq!(fill.commission_amount(), right_value);
     }
 
@@ -2077,12 +2131,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -2090,7 +2144,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -2100,14 +2154,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2116,7 +2170,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2127,7 +2181,7 @@ mod test {
         assert_eq!(fills.len(), 1);
 
         let fill = &fills[0];
-        let right_value = dec!(0.1) / dec!(100) * dec!(5);
+        let right_value = dec!(0.1) / dec!(100) * amount!(5);
         assert_eq!(fill.commission_amount(), right_value);
     }
 
@@ -2139,12 +2193,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let fill_event = FillEvent {
@@ -2152,7 +2206,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -2162,14 +2216,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             None,
             exchange.exchange_account_id,
             currency_pair,
@@ -2178,7 +2232,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2193,12 +2247,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let commission_currency_code = CurrencyCode::new("BTC");
 
@@ -2207,7 +2261,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: Some(commission_currency_code),
@@ -2217,14 +2271,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2233,7 +2287,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2256,12 +2310,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let base_currency_code = CurrencyCode::new("PHB");
 
@@ -2270,7 +2324,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -2280,14 +2334,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2296,7 +2350,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2320,12 +2374,12 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Sell;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -2333,7 +2387,7 @@ mod test {
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -2343,14 +2397,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2359,7 +2413,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2391,21 +2445,21 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Sell;
-        let incremental_fill_amount = dec!(5);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
-        let commission_amount = dec!(0.001);
+        let commission_amount = amount!(0.001);
 
         let mut fill_event = FillEvent {
             source_type: EventSourceType::WebSocket,
             trade_id,
             client_order_id: None,
             exchange_order_id: ExchangeOrderId::new("".into()),
-            fill_price: dec!(0.8),
+            fill_price: price!(0.8),
             fill_amount,
             order_role: None,
             commission_currency_code: None,
@@ -2415,14 +2469,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2431,7 +2485,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2452,13 +2506,13 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Sell;
-        let fill_price = dec!(0.8);
-        let incremental_fill_amount = dec!(5);
+        let fill_price = price!(0.8);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
         let commission_rate = dec!(0.3) / dec!(100);
 
@@ -2477,14 +2531,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2493,7 +2547,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2514,13 +2568,13 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Sell;
-        let fill_price = dec!(0.8);
-        let incremental_fill_amount = dec!(5);
+        let fill_price = price!(0.8);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -2538,14 +2592,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2554,7 +2608,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2575,13 +2629,13 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let fill_price = dec!(0.8);
-        let incremental_fill_amount = dec!(5);
+        let fill_price = price!(0.8);
+        let incremental_fill_amount = amount!(5);
         let fill_amount = FillAmount::Incremental {
             fill_amount: incremental_fill_amount,
             total_filled_amount: None,
         };
-        let order_amount = dec!(12);
+        let order_amount = amount!(12);
         let trade_id = Some(trade_id_from_str("test_trade_id"));
 
         let mut fill_event = FillEvent {
@@ -2599,14 +2653,14 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
 
         let mut order = OrderSnapshot::with_params(
             client_order_id,
-            OrderOptions::liquidation(dec!(0.2)),
+            OrderOptions::liquidation(price!(0.2)),
             Some(OrderRole::Maker),
             exchange.exchange_account_id,
             currency_pair,
@@ -2615,7 +2669,7 @@ mod test {
             None,
             "FromTest",
         );
-        order.fills.filled_amount = dec!(3);
+        order.fills.filled_amount = amount!(3);
 
         let order_pool = OrdersPool::new();
         let order_ref = order_pool.add_snapshot_initial(&order);
@@ -2640,11 +2694,11 @@ mod test {
 
             let commission_rate = dec!(0.001);
             let expected_commission_rate = dec!(0.001);
-            let last_fill_amount = dec!(5);
-            let last_fill_price = dec!(0.8);
+            let last_fill_amount = amount!(5);
+            let last_fill_price = price!(0.8);
             let commission_currency_code = CurrencyCode::new("PHB");
             let symbol = exchange.get_symbol(currency_pair)?;
-            let fill_event_commission_amount = dec!(6.3);
+            let fill_event_commission_amount = amount!(6.3);
 
             let commission_amount = Exchange::get_commission_amount(
                 Some(fill_event_commission_amount),
@@ -2670,8 +2724,8 @@ mod test {
 
             let commission_rate = dec!(0.001);
             let expected_commission_rate = dec!(0.001);
-            let last_fill_amount = dec!(5);
-            let last_fill_price = dec!(0.8);
+            let last_fill_amount = amount!(5);
+            let last_fill_price = price!(0.8);
             let commission_currency_code = CurrencyCode::new("PHB");
             let symbol = exchange.get_symbol(currency_pair)?;
             let commission_amount = Exchange::get_commission_amount(
@@ -2684,7 +2738,7 @@ mod test {
                 &symbol,
             );
 
-            let right_value = dec!(0.1) / dec!(100) * dec!(5) / dec!(0.8);
+            let right_value = dec!(0.1) / dec!(100) * amount!(5) / price!(0.8);
             assert_eq!(commission_amount, right_value);
 
             Ok(())
@@ -2701,9 +2755,9 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let order_amount = dec!(12);
+            let order_amount = amount!(12);
             let order_role = OrderRole::Maker;
-            let fill_price = dec!(0.8);
+            let fill_price = price!(0.8);
 
             let order_ref = create_order_ref(
                 &client_order_id,
@@ -2720,13 +2774,13 @@ mod test {
             let symbol = exchange.get_symbol(currency_pair)?;
             let converted_commission_currency_code =
                 symbol.get_commission_currency_code(order_side);
-            let last_fill_amount = dec!(5);
-            let last_fill_price = dec!(0.8);
-            let last_fill_cost = dec!(4.0);
+            let last_fill_amount = amount!(5);
+            let last_fill_price = price!(0.8);
+            let last_fill_cost = amount!(4.0);
             let expected_commission_rate = dec!(0.001);
             let commission_currency_code = CurrencyCode::new("PHB");
-            let converted_commission_amount = dec!(0.005);
-            let commission_amount = dec!(0.1) / dec!(100) * dec!(5);
+            let converted_commission_amount = amount!(0.005);
+            let commission_amount = dec!(0.1) / dec!(100) * amount!(5);
 
             exchange.add_fill(
                 &trade_id,
@@ -2763,8 +2817,8 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let order_amount = dec!(12);
-            let fill_price = dec!(0.8);
+            let order_amount = amount!(12);
+            let fill_price = price!(0.8);
             let order_role = OrderRole::Maker;
 
             let order_ref = create_order_ref(
@@ -2782,13 +2836,13 @@ mod test {
             let symbol = exchange.get_symbol(currency_pair)?;
             let converted_commission_currency_code =
                 symbol.get_commission_currency_code(order_side);
-            let last_fill_amount = dec!(5);
-            let last_fill_price = dec!(0.8);
-            let last_fill_cost = dec!(4.0);
+            let last_fill_amount = amount!(5);
+            let last_fill_price = price!(0.8);
+            let last_fill_cost = amount!(4.0);
             let expected_commission_rate = dec!(0.001);
             let commission_currency_code = CurrencyCode::new("PHB");
-            let converted_commission_amount = dec!(0.005);
-            let commission_amount = dec!(1000);
+            let converted_commission_amount = amount!(0.005);
+            let commission_amount = amount!(1000);
 
             exchange.add_fill(
                 &trade_id,
@@ -2810,7 +2864,7 @@ mod test {
             let fill = order_ref.get_fills().0.last().cloned().expect("in test");
 
             assert_eq!(fill.commission_amount(), commission_amount);
-            let right_value = dec!(0.1) / dec!(100) * dec!(5);
+            let right_value = dec!(0.1) / dec!(100) * amount!(5);
             assert_eq!(fill.expected_converted_commission_amount(), right_value);
 
             Ok(())
@@ -2824,8 +2878,8 @@ mod test {
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
             let order_role = OrderRole::Maker;
-            let order_amount = dec!(12);
-            let fill_price = dec!(0.8);
+            let order_amount = amount!(12);
+            let fill_price = price!(0.8);
 
             let order_ref = create_order_ref(
                 &client_order_id,
@@ -2842,13 +2896,13 @@ mod test {
             let symbol = exchange.get_symbol(currency_pair)?;
             let converted_commission_currency_code =
                 symbol.get_commission_currency_code(order_side);
-            let last_fill_amount = dec!(5);
-            let last_fill_price = dec!(0.8);
-            let last_fill_cost = dec!(4.0);
+            let last_fill_amount = amount!(5);
+            let last_fill_price = price!(0.8);
+            let last_fill_cost = amount!(4.0);
             let expected_commission_rate = dec!(0.001);
-            let commission_amount = dec!(0.005);
+            let commission_amount = amount!(0.005);
             let commission_currency_code = CurrencyCode::new("PHB");
-            let converted_commission_amount = dec!(0.005);
+            let converted_commission_amount = amount!(0.005);
 
             exchange.add_fill(
                 &trade_id,
@@ -2869,7 +2923,7 @@ mod test {
 
             let fill = order_ref.get_fills().0.last().cloned().expect("in test");
 
-            let right_value = dec!(5) * dec!(0.1) / dec!(100) * dec!(0.4);
+            let right_value = amount!(5) * dec!(0.1) / dec!(100) * dec!(0.4);
             assert_eq!(fill.referral_reward_amount(), right_value);
 
             Ok(())
@@ -2887,8 +2941,8 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let fill_price = dec!(0.8);
-            let order_amount = dec!(12);
+            let fill_price = price!(0.8);
+            let order_amount = amount!(12);
 
             let order_ref = create_order_ref(
                 &client_order_id,
@@ -2900,7 +2954,7 @@ mod test {
                 order_side,
             );
 
-            let fill_amount = dec!(13);
+            let fill_amount = amount!(13);
             exchange.panic_if_fill_amounts_conformity(fill_amount, &order_ref);
         }
 
@@ -2911,8 +2965,8 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let fill_price = dec!(0.8);
-            let order_amount = dec!(12);
+            let fill_price = price!(0.8);
+            let order_amount = amount!(12);
 
             let order_ref = create_order_ref(
                 &client_order_id,
@@ -2924,7 +2978,7 @@ mod test {
                 order_side,
             );
 
-            let fill_amount = dec!(10);
+            let fill_amount = amount!(10);
             exchange.panic_if_fill_amounts_conformity(fill_amount, &order_ref);
         }
     }
@@ -2939,8 +2993,8 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let fill_price = dec!(0.2);
-            let order_amount = dec!(12);
+            let fill_price = price!(0.2);
+            let order_amount = amount!(12);
             let order_ref = create_order_ref(
                 &client_order_id,
                 Some(OrderRole::Maker),
@@ -2975,8 +3029,8 @@ mod test {
             let client_order_id = ClientOrderId::unique_id();
             let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
             let order_side = OrderSide::Buy;
-            let fill_price = dec!(0.2);
-            let order_amount = dec!(12);
+            let fill_price = price!(0.2);
+            let order_amount = amount!(12);
 
             let order_ref = create_order_ref(
                 &client_order_id,
@@ -2988,7 +3042,7 @@ mod test {
                 order_side,
             );
 
-            let order_filled_amount = dec!(10);
+            let order_filled_amount = amount!(10);
             exchange.react_if_order_completed(order_filled_amount, &order_ref);
 
             let order_status = order_ref.status();
@@ -3012,8 +3066,8 @@ mod test {
                 .expect("in test")
                 .value()
                 .clone();
-            let commission_amount = dec!(15);
-            let mut converted_commission_amount = dec!(4.5);
+            let commission_amount = amount!(15);
+            let mut converted_commission_amount = amount!(4.5);
             let mut converted_commission_currency_code = CurrencyCode::new("BTC");
 
             let currency_pair =
@@ -3021,9 +3075,10 @@ mod test {
             let order_book_top = OrderBookTop {
                 ask: None,
                 bid: Some(PriceLevel {
-                    price: dec!(0.3),
-                    amount: dec!(0.1),
+                    price: price!(0.3),
+                    amount: amount!(0.1),
                 }),
+                last_update_time: Utc::now(),
             };
             exchange
                 .order_book_top
@@ -3037,7 +3092,7 @@ mod test {
                 &mut converted_commission_currency_code,
             );
 
-            let right_amount = dec!(4.5);
+            let right_amount = amount!(4.5);
             assert_eq!(converted_commission_amount, right_amount);
 
             let right_currency_code = CurrencyCode::new("BTC");
@@ -3056,17 +3111,18 @@ mod test {
                 .expect("in test")
                 .value()
                 .clone();
-            let commission_amount = dec!(15);
-            let mut converted_commission_amount = dec!(4.5);
+            let commission_amount = amount!(15);
+            let mut converted_commission_amount = amount!(4.5);
             let mut converted_commission_currency_code = CurrencyCode::new("BTC");
 
             let currency_pair = CurrencyPair::from_codes("BTC".into(), commission_currency_code);
             let order_book_top = OrderBookTop {
                 ask: Some(PriceLevel {
-                    price: dec!(0.3),
-                    amount: dec!(0.1),
+                    price: price!(0.3),
+                    amount: amount!(0.1),
                 }),
                 bid: None,
+                last_update_time: Utc::now(),
             };
             exchange
                 .order_book_top
@@ -3080,7 +3136,7 @@ mod test {
                 &mut converted_commission_currency_code,
             );
 
-            let right_amount = dec!(50);
+            let right_amount = amount!(50);
             assert_eq!(converted_commission_amount, right_amount);
 
             let right_currency_code = CurrencyCode::new("BTC");
@@ -3099,8 +3155,8 @@ mod test {
                 .expect("in test")
                 .value()
                 .clone();
-            let commission_amount = dec!(15);
-            let mut converted_commission_amount = dec!(3);
+            let commission_amount = amount!(15);
+            let mut converted_commission_amount = amount!(3);
             let mut converted_commission_currency_code = CurrencyCode::new("BTC");
 
             exchange.update_commission_for_bnb_case(
@@ -3111,7 +3167,7 @@ mod test {
                 &mut converted_commission_currency_code,
             );
 
-            let right_amount = dec!(3);
+            let right_amount = amount!(3);
             assert_eq!(converted_commission_amount, right_amount);
 
             let right_currency_code = CurrencyCode::new("BTC");
@@ -3126,8 +3182,8 @@ mod test {
         let client_order_id = ClientOrderId::unique_id();
         let currency_pair = CurrencyPair::from_codes("PHB".into(), "BTC".into());
         let order_side = OrderSide::Buy;
-        let fill_price = dec!(0.8);
-        let order_amount = dec!(12);
+        let fill_price = price!(0.8);
+        let order_amount = amount!(12);
         let exchange_order_id = ExchangeOrderId::new("some_exchange_order_id".into());
         let client_account_id = ClientOrderId::unique_id();
 
@@ -3147,7 +3203,7 @@ mod test {
         let order_ref = order_pool.add_snapshot_initial(&order);
 
         let fill_amount = FillAmount::Incremental {
-            fill_amount: dec!(5),
+            fill_amount: amount!(5),
             total_filled_amount: None,
         };
         let mut fill_event = FillEvent {
@@ -3165,7 +3221,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -3174,11 +3230,11 @@ mod test {
 
         let (_, filled_amount) = order_ref.get_fills();
 
-        let current_right_filled_amount = dec!(5);
+        let current_right_filled_amount = amount!(5);
         assert_eq!(filled_amount, current_right_filled_amount);
 
         let fill_amount = FillAmount::Incremental {
-            fill_amount: dec!(2),
+            fill_amount: amount!(2),
             total_filled_amount: None,
         };
         let mut second_fill_event = FillEvent {
@@ -3196,7 +3252,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -3205,11 +3261,11 @@ mod test {
 
         let (_, filled_amount) = order_ref.get_fills();
 
-        let right_filled_amount = dec!(7);
+        let right_filled_amount = amount!(7);
         assert_eq!(filled_amount, right_filled_amount);
 
         let fill_amount = FillAmount::Incremental {
-            fill_amount: dec!(5),
+            fill_amount: amount!(5),
             total_filled_amount: None,
         };
         let mut second_fill_event = FillEvent {
@@ -3227,7 +3283,7 @@ mod test {
             special_order_data: Some(SpecialOrderData {
                 currency_pair,
                 order_side: OrderSide::Buy,
-                order_amount: dec!(0),
+                order_amount: amount!(0),
             }),
             fill_date: None,
         };
@@ -3236,7 +3292,7 @@ mod test {
 
         let (_, filled_amount) = order_ref.get_fills();
 
-        let right_filled_amount = dec!(12);
+        let right_filled_amount = amount!(12);
         assert_eq!(filled_amount, right_filled_amount);
 
         let order_status = order_ref.status();
@@ -1,8 +1,12 @@
-use mmb_domain::events::{ExchangeEvent, Trade, TradesEvent};
+use chrono::Duration;
+use mmb_domain::candle::{Candle, CandleAggregator};
+use mmb_domain::events::{CandleClosedEvent, ExchangeEvent, Trade, TradesEvent};
 use mmb_domain::market::CurrencyPair;
 use mmb_domain::market::MarketId;
+use mmb_utils::send_expected::SendExpectedByRef;
 
 use crate::exchanges::{general::exchange::Exchange, timeouts::timeout_manager};
+use crate::misc::time::time_manager;
 
 impl Exchange {
     pub fn handle_trade(&self, currency_pair: CurrencyPair, trade: Trade) {
@@ -53,6 +57,14 @@ impl Exchange {
             }
         }
 
+        let currency_pair = trades_event.currency_pair;
+        let trades_count = trades_event.trades.len();
+        self.update_warmup(currency_pair, |warmup| {
+            warmup.on_trades(currency_pair, trades_count)
+        });
+
+        self.aggregate_candles(&trades_event);
+
         self.events_channel
             .send(ExchangeEvent::Trades(trades_event.clone()))
             .expect("Unable to send trades event. Probably receiver is already dropped");
@@ -61,4 +73,53 @@ impl Exchange {
             .save(trades_event)
             .expect("Failure save trades_event");
     }
+
+    fn aggregate_candles(&self, trades_event: &TradesEvent) {
+        let interval_secs = match &self.exchange_client.get_settings().candles {
+            Some(candles_settings) => candles_settings.interval_secs,
+            None => return,
+        };
+
+        let mut aggregator = self
+            .candle_aggregators
+            .entry(trades_event.currency_pair)
+            .or_insert_with(|| CandleAggregator::new(Duration::seconds(interval_secs as i64)));
+
+        let currency_pair = trades_event.currency_pair;
+        for trade in &trades_event.trades {
+            let closed_candles =
+                aggregator.add_trade(trade.price, trade.quantity, trade.transaction_time);
+            self.publish_closed_candles(currency_pair, closed_candles);
+        }
+    }
+
+    /// Candles are closed at interval boundaries even if there are no trades after them
+    pub fn close_candles(&self) {
+        let now = time_manager::now();
+        for mut aggregator in self.candle_aggregators.iter_mut() {
+            let currency_pair = *aggregator.key();
+            let closed_candles = aggregator.close_candles(now);
+            self.publish_closed_candles(currency_pair, closed_candles);
+        }
+    }
+
+    fn publish_closed_candles(&self, currency_pair: CurrencyPair, closed_candles: Vec<Candle>) {
+        let closed_candles_count = closed_candles.len();
+        if closed_candles_count == 0 {
+            return;
+        }
+
+        self.update_warmup(currency_pair, |warmup| {
+            warmup.on_candles_closed(currency_pair, closed_candles_count)
+        });
+
+        for candle in closed_candles {
+            self.events_channel
+                .send_expected(ExchangeEvent::CandleClosed(CandleClosedEvent {
+                    exchange_account_id: self.exchange_account_id,
+                    currency_pair,
+                    candle,
+                }));
+        }
+    }
 }
@@ -0,0 +1,205 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::exchanges::traits::ExchangeError;
+use anyhow::{bail, Context, Result};
+use itertools::Itertools;
+use mmb_domain::exchanges::leverage::{
+    maintenance_margin, max_notional_for_leverage, LeverageBracket,
+};
+use mmb_domain::market::{CurrencyPair, ExchangeErrorType};
+use mmb_domain::order::snapshot::Amount;
+use mmb_utils::cancellation_token::CancellationToken;
+use rust_decimal::Decimal;
+
+impl Exchange {
+    /// Leverage brackets of futures currency pair. Received brackets are cached for
+    /// position sizing
+    pub async fn get_leverage_brackets(
+        &self,
+        currency_pair: CurrencyPair,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<LeverageBracket>> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetLeverageBrackets,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        let brackets = self
+            .exchange_client
+            .get_leverage_brackets(currency_pair)
+            .await
+            .with_context(|| {
+                format!(
+                    "Leverage brackets aren't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get leverage brackets of {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })?;
+
+        self.leverage_brackets
+            .insert(currency_pair, brackets.clone());
+
+        Ok(brackets)
+    }
+
+    /// Set leverage of futures positions of currency pair. Leverage is checked against
+    /// bracket of open position notional, so change which exchange would reject isn't sent
+    pub async fn set_leverage(
+        &self,
+        currency_pair: CurrencyPair,
+        leverage: Decimal,
+        cancellation_token: CancellationToken,
+    ) -> Result<()> {
+        let brackets = self
+            .get_leverage_brackets(currency_pair, cancellation_token.clone())
+            .await?;
+
+        let positions = self.get_active_positions(cancellation_token.clone()).await;
+        let position_notional = positions
+            .iter()
+            .find(|x| x.derivative.currency_pair == currency_pair)
+            .map_or(Amount::ZERO, |x| {
+                x.derivative.position.abs() * x.derivative.average_entry_price
+            });
+
+        if let Some(reason) = leverage_rejection_reason(&brackets, leverage, position_notional) {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::LeverageNotAllowed,
+                format!(
+                    "Leverage {leverage} for {currency_pair} on {} is rejected because {reason}",
+                    self.exchange_account_id
+                ),
+                None,
+            ));
+        }
+
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::SetLeverage,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        log::info!(
+            "Setting leverage {leverage} for {currency_pair} on {}",
+            self.exchange_account_id
+        );
+        self.exchange_client
+            .set_leverage(currency_pair, leverage)
+            .await
+            .with_context(|| {
+                format!(
+                    "Setting leverage isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to set leverage {leverage} for {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })?;
+
+        self.leverage_by_currency_pair
+            .insert(currency_pair, leverage);
+
+        Ok(())
+    }
+
+    /// Max position notional in quote currency allowed for current leverage of currency pair.
+    /// Returns `None` if leverage brackets weren't received
+    pub fn max_position_notional(&self, currency_pair: CurrencyPair) -> Option<Amount> {
+        let leverage = *self.leverage_by_currency_pair.get(&currency_pair)?;
+        let brackets = self.leverage_brackets.get(&currency_pair)?;
+        Some(max_notional_for_leverage(&brackets, leverage).unwrap_or_default())
+    }
+
+    /// Margin required to keep position of currency pair with specified notional open.
+    /// Returns `None` if leverage brackets weren't received or notional exceeds all of them
+    pub fn position_maintenance_margin(
+        &self,
+        currency_pair: CurrencyPair,
+        notional: Amount,
+    ) -> Option<Amount> {
+        maintenance_margin(&self.leverage_brackets.get(&currency_pair)?, notional)
+    }
+}
+
+fn leverage_rejection_reason(
+    brackets: &[LeverageBracket],
+    leverage: Decimal,
+    position_notional: Amount,
+) -> Option<String> {
+    if leverage < Decimal::ONE {
+        return Some("leverage can't be less than 1".to_owned());
+    }
+
+    match max_notional_for_leverage(brackets, leverage) {
+        None => {
+            let max_leverage = brackets.iter().map(|x| x.max_leverage).max();
+            Some(format!("it exceeds max leverage {max_leverage:?}"))
+        }
+        Some(max_notional) if position_notional > max_notional => Some(format!(
+            "it allows position notional up to {max_notional}, but open position notional is {position_notional}. Brackets: {}",
+            brackets
+                .iter()
+                .map(|x| format!("{}x up to {}", x.max_leverage, x.notional_cap))
+                .join(", ")
+        )),
+        Some(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::amount;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn brackets() -> Vec<LeverageBracket> {
+        vec![
+            LeverageBracket {
+                notional_cap: amount!(50000),
+                max_leverage: dec!(125),
+                maintenance_margin_rate: dec!(0.004),
+                maintenance_amount: amount!(0),
+            },
+            LeverageBracket {
+                notional_cap: amount!(250000),
+                max_leverage: dec!(100),
+                maintenance_margin_rate: dec!(0.005),
+                maintenance_amount: amount!(50),
+            },
+        ]
+    }
+
+    #[rstest]
+    #[case(dec!(20), amount!(0), true)]
+    #[case(dec!(100), amount!(200000), true)]
+    #[case(dec!(125), amount!(40000), true)]
+    #[case(dec!(125), amount!(100000), false)]
+    #[case(dec!(150), amount!(0), false)]
+    #[case(dec!(0.5), amount!(0), false)]
+    fn leverage_is_checked_against_position_bracket(
+        #[case] leverage: Decimal,
+        #[case] position_notional: Amount,
+        #[case] is_allowed: bool,
+    ) {
+        assert_eq!(
+            leverage_rejection_reason(&brackets(), leverage, position_notional).is_none(),
+            is_allowed
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::exchanges::traits::ExchangeError;
+use anyhow::{bail, Context, Result};
+use mmb_domain::market::ExchangeErrorType;
+use mmb_domain::order::snapshot::OrderHeader;
+use mmb_domain::position::MarginMode;
+use mmb_utils::cancellation_token::CancellationToken;
+
+impl Exchange {
+    /// Margin mode specified in order header or exchange settings. `None` means margin mode
+    /// isn't managed, so order is routed to position with margin mode currently set on exchange
+    pub(crate) fn required_margin_mode(&self, order_header: &OrderHeader) -> Option<MarginMode> {
+        order_header.margin_mode.or_else(|| {
+            self.margin_mode_settings
+                .lock()
+                .as_ref()?
+                .margin_mode(order_header.currency_pair)
+        })
+    }
+
+    /// Switch margin mode of currency pair on exchange to the one required by order.
+    /// Exchange doesn't allow switching while position is open, so order which margin mode
+    /// conflicts with open position is rejected
+    pub(crate) async fn ensure_margin_mode(
+        &self,
+        order_header: &OrderHeader,
+        cancellation_token: CancellationToken,
+    ) -> Result<()> {
+        let margin_mode = match self.required_margin_mode(order_header) {
+            None => return Ok(()),
+            Some(margin_mode) => margin_mode,
+        };
+
+        let currency_pair = order_header.currency_pair;
+        let current_margin_mode = self
+            .margin_mode_by_currency_pair
+            .get(&currency_pair)
+            .map(|x| *x);
+        if current_margin_mode == Some(margin_mode) {
+            return Ok(());
+        }
+
+        if !self.exchange_client.capabilities().margin_modes {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::Unsupported,
+                format!(
+                    "Order {} is rejected because margin modes aren't supported by exchange {}",
+                    order_header.client_order_id, self.exchange_account_id
+                ),
+                None,
+            ));
+        }
+
+        let positions = self.get_active_positions(cancellation_token.clone()).await;
+        let position_margin_mode = positions
+            .iter()
+            .find(|x| x.derivative.currency_pair == currency_pair)
+            .and_then(|x| x.derivative.margin_mode);
+        if let Some(position_margin_mode) = position_margin_mode {
+            self.margin_mode_by_currency_pair
+                .insert(currency_pair, position_margin_mode);
+
+            if position_margin_mode != margin_mode {
+                bail!(ExchangeError::new(
+                    ExchangeErrorType::InvalidOrder,
+                    format!(
+                        "Order creation {} on {} is rejected because its {margin_mode} margin mode conflicts with open {position_margin_mode} position of {currency_pair}",
+                        order_header.client_order_id, self.exchange_account_id
+                    ),
+                    None,
+                ));
+            }
+
+            return Ok(());
+        }
+
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::SetMarginMode,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        log::info!(
+            "Setting {margin_mode} margin mode for {currency_pair} on {}",
+            self.exchange_account_id
+        );
+        self.exchange_client
+            .set_margin_mode(currency_pair, margin_mode)
+            .await
+            .with_context(|| {
+                format!(
+                    "Margin modes aren't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to set {margin_mode} margin mode for {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })?;
+
+        self.margin_mode_by_currency_pair
+            .insert(currency_pair, margin_mode);
+
+        Ok(())
+    }
+}
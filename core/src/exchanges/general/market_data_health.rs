@@ -0,0 +1,61 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::traits::ExchangeError;
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use mmb_domain::market::{CurrencyPair, ExchangeErrorType};
+use mmb_domain::order::snapshot::OrderHeader;
+
+impl Exchange {
+    /// Stream of currency pair failed, so orders priced off its market data are rejected until
+    /// data of the pair is received again. Other currency pairs of exchange keep trading
+    pub(crate) fn mark_market_data_broken(&self, currency_pair: CurrencyPair, reason: &str) {
+        log::warn!(
+            "Market data of {currency_pair} on {} is broken ({reason}). Orders for it are rejected until data is received again",
+            self.exchange_account_id
+        );
+        let _ = self
+            .broken_market_data
+            .insert(currency_pair, reason.to_owned());
+    }
+
+    pub(crate) fn on_market_data_received(&self, currency_pair: CurrencyPair) {
+        if self.broken_market_data.remove(&currency_pair).is_some() {
+            log::info!(
+                "Market data of {currency_pair} on {} is recovered",
+                self.exchange_account_id
+            );
+        }
+    }
+
+    /// Currency pairs which orders are rejected for because of broken market data
+    pub fn broken_market_data(&self) -> Vec<CurrencyPair> {
+        self.broken_market_data
+            .iter()
+            .map(|x| *x.key())
+            .sorted_by_key(|x| x.to_string())
+            .collect()
+    }
+
+    /// Orders without source price don't depend on market data, so they are never checked
+    pub(crate) fn check_market_data_health(&self, order_header: &OrderHeader) -> Result<()> {
+        if order_header.source_price.is_none() {
+            return Ok(());
+        }
+
+        if let Some(reason) = self.broken_market_data.get(&order_header.currency_pair) {
+            bail!(ExchangeError::new(
+                ExchangeErrorType::StaleMarketData,
+                format!(
+                    "Order creation {} on {} is rejected because market data of {} is broken: {}",
+                    order_header.client_order_id,
+                    self.exchange_account_id,
+                    order_header.currency_pair,
+                    reason.value()
+                ),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
+}
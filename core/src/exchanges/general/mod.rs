@@ -1,13 +1,31 @@
+pub mod agg_trades;
+pub mod asset_networks;
+pub mod balance_snapshot;
+pub mod capabilities;
+pub mod clock_skew;
 pub mod currency_pair_to_symbol_converter;
+pub mod dust_conversion;
 pub mod engine_api;
 pub mod exchange;
 pub mod exchange_creation;
 pub mod exchange_symbol;
 pub mod features;
+pub mod funding;
 pub mod handlers;
+pub mod leverage;
+pub mod margin_mode;
+pub mod market_data_health;
 pub mod order;
+pub mod order_book_refresh;
+pub mod order_checks;
+pub mod order_rate_limit;
 pub mod polling_timeout_manager;
+pub mod position_cost;
+pub mod reconnect_grace_period;
+pub mod rejection_pause;
 pub mod request_type;
+pub mod state_snapshot;
+pub mod strategy_handoff;
 
 #[cfg(test)]
 pub mod test_helper;
@@ -7,6 +7,7 @@ use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::snapshot::Amount;
 use mmb_domain::order::snapshot::{ClientOrderId, ExchangeOrderId, OrderInfo, OrderStatus};
 use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
 use tokio::sync::oneshot;
 
 use crate::exchanges::traits::ExchangeError;
@@ -19,6 +20,8 @@ pub struct CancelOrderResult {
     pub source_type: EventSourceType,
     // TODO Use it in the future
     pub filled_amount: Option<Amount>,
+    /// Time of order cancellation on exchange side if exchange reports it
+    pub exchange_time: Option<DateTime>,
 }
 
 impl CancelOrderResult {
@@ -31,6 +34,7 @@ impl CancelOrderResult {
             outcome: RequestResult::Success(client_order_id),
             source_type,
             filled_amount,
+            exchange_time: None,
         }
     }
 
@@ -39,8 +43,14 @@ impl CancelOrderResult {
             outcome: RequestResult::Error(error),
             source_type,
             filled_amount: None,
+            exchange_time: None,
         }
     }
+
+    pub fn with_exchange_time(mut self, exchange_time: Option<DateTime>) -> Self {
+        self.exchange_time = exchange_time;
+        self
+    }
 }
 
 impl Exchange {
@@ -49,6 +59,9 @@ impl Exchange {
         order: &OrderRef,
         cancellation_token: CancellationToken,
     ) -> Result<Option<CancelOrderResult>> {
+        // order can be already filled during disconnection, so its fills should be reconciled first
+        self.wait_reconnect_grace_period(&cancellation_token).await;
+
         let client_order_id = order.client_order_id();
         let (status, exchange_order_id) = order.fn_ref(|x| (x.status(), x.exchange_order_id()));
         match status {
@@ -67,7 +80,16 @@ impl Exchange {
                 Ok(None)
             }
             _ => {
-                order.fn_mut(|order| order.set_status(OrderStatus::Canceling, time_manager::now()));
+                let is_applied = order
+                    .fn_mut(|order| order.set_status(OrderStatus::Canceling, time_manager::now()));
+                if !is_applied {
+                    log::warn!(
+                        "Order {client_order_id} {exchange_order_id:?} on {} can't be canceled in status {:?}",
+                        self.exchange_account_id,
+                        order.status()
+                    );
+                    return Ok(None);
+                }
 
                 log::info!(
                     "Submitting order cancellation {client_order_id} {exchange_order_id:?} on {}",
@@ -105,6 +127,7 @@ impl Exchange {
                                 &exchange_order_id,
                                 cancel_outcome.filled_amount,
                                 cancel_outcome.source_type,
+                                cancel_outcome.exchange_time,
                             ),
                         RequestResult::Error(error) => {
                             if error.error_type != ExchangeErrorType::ParsingError {
@@ -167,15 +190,15 @@ impl Exchange {
         client_order_id: ClientOrderId,
         exchange_order_id: ExchangeOrderId,
         source_type: EventSourceType,
+        exchange_time: Option<DateTime>,
     ) {
         let filled_amount = None;
         match self.order_cancellation_events.remove(&exchange_order_id) {
             Some((_, (tx, _))) => {
-                let send_res = tx.send(CancelOrderResult::succeed(
-                    client_order_id,
-                    source_type,
-                    filled_amount,
-                ));
+                let send_res = tx.send(
+                    CancelOrderResult::succeed(client_order_id, source_type, filled_amount)
+                        .with_exchange_time(exchange_time),
+                );
                 if let Err(err) = send_res {
                     log::error!("raise_order_cancelled failed: unable to send thru oneshot channel: {err:?}");
                 }
@@ -185,6 +208,7 @@ impl Exchange {
                 &exchange_order_id,
                 filled_amount,
                 source_type,
+                exchange_time,
             ),
         }
     }
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use itertools::Itertools;
+use mmb_domain::market::CurrencyPair;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::nothing_to_do;
+
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+
+/// Outcome of cancellation of all orders for each currency pair which had opened orders
+pub type CancelAllOrdersSummary = HashMap<CurrencyPair, Result<()>>;
+
+impl Exchange {
+    /// Cancel all opened orders on account across all currency pairs.
+    /// Single request is used if exchange supports cancellation of all orders at once,
+    /// otherwise currency pairs with opened orders are cancelled concurrently within rate limits
+    pub async fn cancel_all_orders_global(
+        &self,
+        cancellation_token: CancellationToken,
+    ) -> Result<CancelAllOrdersSummary> {
+        let currency_pairs = self
+            .get_open_orders(false)
+            .await
+            .context("Unable to get opened orders for cancellation")?
+            .iter()
+            .map(|order| order.currency_pair)
+            .unique()
+            .collect_vec();
+
+        if currency_pairs.is_empty() {
+            return Ok(CancelAllOrdersSummary::new());
+        }
+
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::CancelOrder,
+                None,
+                cancellation_token.clone(),
+            )
+            .await
+            .into_result()?;
+
+        match self.exchange_client.cancel_all_orders_global().await {
+            None => nothing_to_do(),
+            Some(Ok(())) => {
+                return Ok(currency_pairs.into_iter().map(|x| (x, Ok(()))).collect());
+            }
+            Some(Err(error)) => log::warn!(
+                "Failed to cancel all orders at once on {}, cancelling by currency pairs: {error:?}",
+                self.exchange_account_id
+            ),
+        }
+
+        let summary = join_all(currency_pairs.into_iter().map(|currency_pair| {
+            let cancellation_token = cancellation_token.clone();
+            async move {
+                let result = self
+                    .cancel_all_orders_with_reservation(currency_pair, cancellation_token)
+                    .await;
+                (currency_pair, result)
+            }
+        }))
+        .await;
+
+        Ok(summary.into_iter().collect())
+    }
+
+    async fn cancel_all_orders_with_reservation(
+        &self,
+        currency_pair: CurrencyPair,
+        cancellation_token: CancellationToken,
+    ) -> Result<()> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::CancelOrder,
+                None,
+                cancellation_token,
+            )
+            .await
+            .into_result()?;
+
+        self.cancel_all_orders(currency_pair).await
+    }
+}
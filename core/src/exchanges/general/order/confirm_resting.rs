@@ -0,0 +1,95 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use anyhow::{bail, Result};
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::OrderStatus;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::{nothing_to_do, OPERATION_CANCELED_MSG};
+use std::time::Duration;
+use tokio::time::{sleep, timeout};
+
+const RESTING_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl Exchange {
+    /// Polls order info until exchange reports that created order is resting on order book.
+    /// Order which is already filled is confirmed as well, because exchange has accepted it
+    pub(super) async fn confirm_order_resting(
+        &self,
+        order: &OrderRef,
+        timeout_ms: u64,
+        cancellation_token: CancellationToken,
+    ) -> Result<()> {
+        let client_order_id = order.client_order_id();
+        let exchange_account_id = self.exchange_account_id;
+
+        let confirmation = async {
+            loop {
+                self.timeout_manager
+                    .reserve_when_available(
+                        exchange_account_id,
+                        RequestType::GetOrderInfo,
+                        None,
+                        cancellation_token.clone(),
+                    )
+                    .await;
+
+                if cancellation_token.is_cancellation_requested() {
+                    bail!(OPERATION_CANCELED_MSG);
+                }
+
+                match self.get_order_info(order).await {
+                    Ok(order_info) => {
+                        if is_confirmed_resting(order_info.order_status)? {
+                            return Ok(());
+                        }
+                    }
+                    Err(error) => log::warn!(
+                        "Failed to get info of order {client_order_id} on {exchange_account_id} during resting confirmation: {error:?}"
+                    ),
+                }
+
+                tokio::select! {
+                    _ = sleep(RESTING_CONFIRMATION_POLL_INTERVAL) => nothing_to_do(),
+                    _ = cancellation_token.when_cancelled() => nothing_to_do(),
+                }
+            }
+        };
+
+        let confirmation_timeout = Duration::from_millis(timeout_ms);
+        timeout(confirmation_timeout, confirmation)
+            .await
+            .unwrap_or_else(|_| {
+                bail!(
+                    "Order {client_order_id} on {exchange_account_id} isn't confirmed resting within {confirmation_timeout:?}"
+                )
+            })?;
+
+        log::info!("Order {client_order_id} on {exchange_account_id} is confirmed resting");
+        Ok(())
+    }
+}
+
+/// `Ok(false)` if exchange doesn't report order as created yet
+fn is_confirmed_resting(status: OrderStatus) -> Result<bool> {
+    match status {
+        OrderStatus::Created | OrderStatus::Completed => Ok(true),
+        OrderStatus::Creating => Ok(false),
+        _ => bail!("Order isn't resting on exchange, its status is {status:?}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::created(OrderStatus::Created, Some(true))]
+    #[case::filled(OrderStatus::Completed, Some(true))]
+    #[case::not_created_yet(OrderStatus::Creating, Some(false))]
+    #[case::canceled(OrderStatus::Canceled, None)]
+    #[case::failed_to_create(OrderStatus::FailedToCreate, None)]
+    fn resting_confirmation_by_status(#[case] status: OrderStatus, #[case] expected: Option<bool>) {
+        assert_eq!(is_confirmed_resting(status).ok(), expected);
+    }
+}
@@ -4,22 +4,33 @@ use crate::exchanges::general::request_type::RequestType;
 use crate::exchanges::timeouts::requests_timeout_manager::RequestGroupId;
 use crate::exchanges::traits::ExchangeError;
 use crate::misc::time::time_manager;
+use crate::orders::event_merge::{MergeDecision, SourcedEvent};
+use crate::orders::idempotency_cache::{IdempotencyCacheEntry, IdempotencyToken};
+use crate::telemetry;
 use crate::{exchanges::general::exchange::Exchange, exchanges::general::exchange::RequestResult};
 use anyhow::{bail, Context, Result};
-use chrono::Utc;
 use function_name::named;
 use futures::pin_mut;
-use mmb_domain::events::{AllowedEventSourceType, EventSourceType};
+use mmb_domain::events::{
+    AllowedEventSourceType, EventSourceType, ExchangeEvent, MetricsEventInfoBase, MetricsEventType,
+    OrderAckLatencyEvent,
+};
 use mmb_domain::market::{ExchangeAccountId, ExchangeErrorType};
 use mmb_domain::order::event::OrderEventType;
 use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::snapshot::{
     ClientOrderId, ExchangeOrderId, OrderHeader, OrderInfo, OrderStatus, OrderType,
 };
+use mmb_domain::order::tag::{
+    decode_tag_from_client_order_id, encode_tag_to_client_order_id, is_valid_tag,
+};
 use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::correlation_id::CorrelationId;
 use mmb_utils::time::ToStdExpected;
+use mmb_utils::DateTime;
 use mmb_utils::{nothing_to_do, OPERATION_CANCELED_MSG};
 use std::borrow::Cow;
+use std::sync::atomic::Ordering;
 use std::time::Duration;
 use tokio::sync::oneshot;
 use tokio::time::{sleep, timeout};
@@ -28,6 +39,12 @@ use tokio::time::{sleep, timeout};
 pub struct CreateOrderResult {
     pub outcome: RequestResult<ExchangeOrderId>,
     pub source_type: EventSourceType,
+    /// Whether order creation can succeed on retry. Always `false` for succeeded result
+    pub retryable: bool,
+    /// Suggested delay before retry of order creation
+    pub retry_delay: Option<Duration>,
+    /// Time of order creation on exchange side if exchange reports it
+    pub exchange_time: Option<DateTime>,
 }
 
 impl CreateOrderResult {
@@ -35,36 +52,264 @@ impl CreateOrderResult {
         CreateOrderResult {
             outcome: Success(order_id.clone()),
             source_type,
+            retryable: false,
+            retry_delay: None,
+            exchange_time: None,
         }
     }
 
     pub fn failed(error: ExchangeError, source_type: EventSourceType) -> Self {
         CreateOrderResult {
+            retryable: error.error_type.is_retryable(),
+            retry_delay: error.error_type.retry_delay(),
             outcome: Error(error),
             source_type,
+            exchange_time: None,
         }
     }
+
+    pub fn with_exchange_time(mut self, exchange_time: Option<DateTime>) -> Self {
+        self.exchange_time = exchange_time;
+        self
+    }
+}
+
+fn is_duplicate_client_order_id(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<ExchangeError>().map_or(false, |x| {
+        x.error_type == ExchangeErrorType::DuplicateClientOrderId
+    })
 }
 
 impl Exchange {
+    /// Create order only if order intent with the same idempotency token wasn't submitted
+    /// within TTL of idempotency cache. Otherwise cached order is returned.
+    /// Works as `create_order` if idempotency cache isn't configured
+    pub async fn create_order_idempotent(
+        &self,
+        order_header: &OrderHeader,
+        idempotency_token: &IdempotencyToken,
+        pre_reservation_group_id: Option<RequestGroupId>,
+        cancellation_token: CancellationToken,
+    ) -> Result<OrderRef> {
+        let idempotency_cache = self.idempotency_cache.lock().clone();
+        if let Some(idempotency_cache) = idempotency_cache {
+            let entry = idempotency_cache.get_or_insert_with(
+                idempotency_token,
+                time_manager::now(),
+                || self.add_initial_order(order_header, time_manager::now()),
+            );
+
+            if let IdempotencyCacheEntry::Cached(order) = entry {
+                log::warn!(
+                    "Order intent {order_header:?} with idempotency token {idempotency_token} is duplicate. Returning already submitted order {}",
+                    order.client_order_id()
+                );
+                return Ok(order);
+            }
+        }
+
+        self.create_order(order_header, pre_reservation_group_id, cancellation_token)
+            .await
+    }
+
+    /// Generate unique client order id for order with specified tag. Tag is encoded to client
+    /// order id if exchange can't attach tag to order, so order can be added to pool with
+    /// its final client order id before creation
+    pub fn generate_client_order_id(&self, tag: Option<&str>) -> Result<ClientOrderId> {
+        let client_order_id = ClientOrderId::unique_id();
+        let client_order_id = match tag {
+            Some(tag) if !self.features.order_features.supports_order_tag => {
+                encode_tag_to_client_order_id(tag, &client_order_id)
+            }
+            _ => client_order_id,
+        };
+
+        self.check_client_order_id(&client_order_id, tag)?;
+        Ok(client_order_id)
+    }
+
+    /// Order tag should be valid and already encoded to client order id if exchange can't
+    /// attach tag to order. Client order id can't be changed after order is added to pool
+    pub(crate) fn check_order_tag(&self, order_header: &OrderHeader) -> Result<()> {
+        let client_order_id = &order_header.client_order_id;
+        let tag = order_header.tag.as_deref();
+        self.check_client_order_id(client_order_id, tag)?;
+
+        if let Some(tag) = tag {
+            if !self.features.order_features.supports_order_tag
+                && decode_tag_from_client_order_id(client_order_id) != Some(tag)
+            {
+                bail!("Tag '{tag}' isn't encoded to client order id {client_order_id}, it should be generated by `generate_client_order_id`");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_client_order_id(
+        &self,
+        client_order_id: &ClientOrderId,
+        tag: Option<&str>,
+    ) -> Result<()> {
+        if let Some(tag) = tag {
+            if !is_valid_tag(tag) {
+                bail!("Order {client_order_id} has invalid tag '{tag}'");
+            }
+        }
+
+        if let Some(max_len) = self.features.order_features.max_client_order_id_len {
+            let len = client_order_id.as_str().len();
+            if len > max_len {
+                bail!(
+                    "Client order id {client_order_id} is longer than {max_len} characters allowed on {}",
+                    self.exchange_account_id
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add order to pool before its creation. Price of new order is clamped by price band,
+    /// because header of order can't be changed after that
+    pub(crate) fn add_initial_order(&self, order_header: &OrderHeader, time: DateTime) -> OrderRef {
+        let client_order_id = &order_header.client_order_id;
+        let order_header = match self.orders.cache_by_client_id.contains_key(client_order_id) {
+            true => Cow::Borrowed(order_header),
+            false => self
+                .clamp_by_price_band(order_header)
+                .unwrap_or_else(|err| {
+                    // order is rejected by price band check later
+                    log::error!("Failed to clamp price of order {client_order_id}: {err:?}");
+                    Cow::Borrowed(order_header)
+                }),
+        };
+
+        // returns existing order if it was already added to pool
+        self.orders.add_simple_initial(
+            &order_header,
+            time,
+            self.exchange_client.get_initial_extension_data(),
+        )
+    }
+
+    /// Submit order to exchange. If exchange rejects client order id as duplicate and
+    /// regeneration of it is enabled, order is rekeyed in pool and its creation is retried once
+    /// with new client order id. Returned order has the new client order id then
     pub async fn create_order(
         &self,
         order_header: &OrderHeader,
         pre_reservation_group_id: Option<RequestGroupId>,
         cancellation_token: CancellationToken,
+    ) -> Result<OrderRef> {
+        let correlation_id = CorrelationId::current_or_generate();
+        let result = correlation_id
+            .scope(self.create_order_with_correlation_id(
+                order_header,
+                pre_reservation_group_id,
+                cancellation_token.clone(),
+                correlation_id,
+            ))
+            .await;
+
+        let error = match result {
+            Err(error) if is_duplicate_client_order_id(&error) => error,
+            _ => return result,
+        };
+
+        let client_order_id = &order_header.client_order_id;
+        log::warn!(
+            "Client order id {client_order_id} collision on {}: {error:?}",
+            self.exchange_account_id
+        );
+
+        // order is left in `Creating` status only if its client order id should be regenerated
+        let order = match self.orders.cache_by_client_id.get(client_order_id) {
+            Some(order) if order.status() == OrderStatus::Creating => order.clone(),
+            _ => return Err(error),
+        };
+
+        if !self.spend_order_retry(&order, "creation with regenerated client order id") {
+            return Err(self.fail_creating_order(&order, error));
+        }
+
+        let order = match self.regenerate_client_order_id(&order) {
+            Ok(order) => order,
+            Err(regeneration_error) => {
+                log::error!("Failed to regenerate client order id {client_order_id}: {regeneration_error:?}");
+                return Err(self.fail_creating_order(&order, error));
+            }
+        };
+        log::info!(
+            "Retrying creation of order {client_order_id} with regenerated client order id {}",
+            order.client_order_id()
+        );
+
+        correlation_id
+            .scope(self.create_order_with_correlation_id(
+                order.header(),
+                pre_reservation_group_id,
+                cancellation_token,
+                correlation_id,
+            ))
+            .await
+    }
+
+    /// Rekey order in pool with new client order id and notify order owner about it
+    fn regenerate_client_order_id(&self, order: &OrderRef) -> Result<OrderRef> {
+        let client_order_id = self.generate_client_order_id(order.header().tag.as_deref())?;
+
+        let previous_client_order_id = order.client_order_id();
+        let order = self.orders.rekey(order, client_order_id);
+        self.add_event_on_order_change(
+            &order,
+            OrderEventType::ClientOrderIdRegenerated {
+                previous_client_order_id,
+            },
+        )?;
+
+        Ok(order)
+    }
+
+    async fn create_order_with_correlation_id(
+        &self,
+        order_header: &OrderHeader,
+        pre_reservation_group_id: Option<RequestGroupId>,
+        cancellation_token: CancellationToken,
+        correlation_id: CorrelationId,
     ) -> Result<OrderRef> {
         use AllowedEventSourceType::*;
 
-        log::info!("Submitting order {order_header:?}");
+        telemetry::start_order_span(order_header);
+        let order = self.add_initial_order(order_header, time_manager::now());
+        order.fn_mut(|x| {
+            // keep correlation id of the first submission if order was already added to pool
+            let _ = x
+                .internal_props
+                .correlation_id
+                .get_or_insert(correlation_id);
+        });
 
-        let order = self.orders.add_simple_initial(
-            order_header,
-            time_manager::now(),
-            self.exchange_client.get_initial_extension_data(),
+        // order can be already added to pool by caller, so it's checked after that
+        if let Err(error) = self.run_order_checks(order.header()) {
+            return Err(self.fail_creating_order(&order, error));
+        }
+        if let Err(error) = self
+            .ensure_margin_mode(order.header(), cancellation_token.clone())
+            .await
+        {
+            return Err(self.fail_creating_order(&order, error));
+        }
+        self.detect_near_cross(order.header());
+
+        log::info!(
+            "Submitting order {:?}, correlation_id: {correlation_id}",
+            order.header()
         );
 
         let linked_ct = cancellation_token.create_linked_token();
 
+        self.register_order_rate_limit_usage();
         let create_order_fut = self.create_order_base(&order, linked_ct.clone());
 
         let duration = Duration::from_secs(5 * 60);
@@ -107,11 +352,19 @@ impl Exchange {
                         )
                         .await;
 
+                        if order.status() == OrderStatus::FailedToCreate {
+                            bail!(
+                                "Order {} is marked as failed to create",
+                                order.client_order_id()
+                            );
+                        }
+
                         order
                             .exchange_order_id()
                             .expect("exchange_order_id should exists after check_order_creation");
                     } else {
-                        bail!("failed create_order: {}", exchange_error.message);
+                        // keep ExchangeError as source so caller can check if retry makes sense
+                        return Err(exchange_error).context("failed create_order");
                     }
                 }
             }
@@ -126,6 +379,13 @@ impl Exchange {
         ) -> Result<()> {
             linked_ct.cancel();
             poll_result.context("failed create_order fallback polling")?;
+            if order.status() == OrderStatus::FailedToCreate {
+                bail!(
+                    "Order {} is marked as failed to create",
+                    order.client_order_id()
+                );
+            }
+
             order
                 .exchange_order_id()
                 .expect("exchange_order_id should exists after poll_order_create");
@@ -177,13 +437,50 @@ impl Exchange {
             }
         }
 
-        self.handle_created_order(&order, pre_reservation_group_id, cancellation_token)
+        self.handle_created_order(&order, pre_reservation_group_id, cancellation_token.clone())
             .await
             .unwrap_or_else(|err| log::error!("failed handle_created_order: {err}"));
 
+        if let Some(timeout_ms) = order_header.resting_confirmation_timeout_ms {
+            self.confirm_order_resting(&order, timeout_ms, cancellation_token)
+                .await?;
+        }
+
         Ok(order)
     }
 
+    /// Order rejected before submission or not submitted again after rejection is marked as
+    /// failed to create the same way as order rejected by exchange, so order added to pool
+    /// isn't left in `Creating` status
+    pub(super) fn fail_creating_order(
+        &self,
+        order: &OrderRef,
+        error: anyhow::Error,
+    ) -> anyhow::Error {
+        let exchange_error = match error.downcast_ref::<ExchangeError>() {
+            Some(exchange_error) => exchange_error.clone(),
+            None => ExchangeError::unknown(&format!("{error:?}")),
+        };
+
+        let (client_order_id, exchange_order_id) = order.order_ids();
+        let args_to_log = (
+            self.exchange_account_id,
+            &client_order_id,
+            &exchange_order_id,
+        );
+        self.react_on_status_when_failed(
+            order,
+            args_to_log,
+            EventSourceType::Rest,
+            &exchange_error,
+        )
+        .unwrap_or_else(|err| {
+            log::error!("Failed to mark order {client_order_id} as failed: {err:?}")
+        });
+
+        error
+    }
+
     async fn handle_created_order(
         &self,
         order: &OrderRef,
@@ -282,6 +579,29 @@ impl Exchange {
                 return;
             }
 
+            if !self.spend_order_retry(&order, "creation status request") {
+                let error = ExchangeError::new(
+                    ExchangeErrorType::RetriesExhausted,
+                    format!("Retry budget of order {client_order_id} is exhausted"),
+                    None,
+                );
+                let args_to_log = (
+                    self.exchange_account_id,
+                    &client_order_id,
+                    &exchange_order_id,
+                );
+                self.react_on_status_when_failed(
+                    &order,
+                    args_to_log,
+                    EventSourceType::RestFallback,
+                    &error,
+                )
+                .unwrap_or_else(|err| {
+                    log::error!("Failed to mark order {client_order_id} as failed: {err:?}")
+                });
+                return;
+            }
+
             if self
                 .features
                 .order_features
@@ -454,6 +774,7 @@ impl Exchange {
                     exchange_order_id,
                     Some(order_info.filled_amount),
                     EventSourceType::RestFallback,
+                    None,
                 )
             }
             OrderStatus::Created | OrderStatus::Completed => {
@@ -470,6 +791,7 @@ impl Exchange {
                     client_order_id,
                     &order_info.exchange_order_id,
                     EventSourceType::RestFallback,
+                    None,
                 );
             }
             _ => log::warn!(
@@ -495,10 +817,20 @@ impl Exchange {
                         &client_order_id,
                         exchange_order_id,
                         created_order.source_type,
+                        created_order.exchange_time,
                     )?;
                 }
                 Error(exchange_error) => {
-                    if exchange_error.error_type != ExchangeErrorType::ParsingError {
+                    // order with duplicate client order id is rekeyed and submitted again
+                    let should_regenerate_client_order_id = exchange_error.error_type
+                        == ExchangeErrorType::DuplicateClientOrderId
+                        && self
+                            .regenerate_duplicate_client_order_id
+                            .load(Ordering::Relaxed);
+
+                    if exchange_error.error_type != ExchangeErrorType::ParsingError
+                        && !should_regenerate_client_order_id
+                    {
                         self.handle_create_order_failed(
                             &client_order_id,
                             exchange_error,
@@ -546,6 +878,11 @@ impl Exchange {
             error_msg
         })?;
 
+        // only rejections by exchange are counted, so local checks can't pause order placement
+        if order_ref.status() == OrderStatus::Creating {
+            self.register_order_rejection(order_ref.currency_pair());
+        }
+
         let args_to_log = (
             self.exchange_account_id,
             client_order_id,
@@ -582,11 +919,15 @@ impl Exchange {
             OrderStatus::Creating => {
                 // TODO RestFallback and some metrics
 
-                order.fn_mut(|x| {
-                    x.set_status(OrderStatus::FailedToCreate, Utc::now());
+                let is_applied = order.fn_mut(|x| {
                     x.internal_props.last_creation_error_type = Some(exchange_error.error_type);
                     x.internal_props.last_creation_error_message = exchange_error.message.clone();
+                    x.set_status(OrderStatus::FailedToCreate, time_manager::now())
                 });
+                if !is_applied {
+                    log::warn!("CreateOrderFailed is ignored for order {args_to_log:?} because its status can't be changed to FailedToCreate");
+                    return Ok(());
+                }
 
                 self.add_event_on_order_change(order, OrderEventType::CreateOrderFailed)?;
 
@@ -594,7 +935,10 @@ impl Exchange {
                     .save(&mut order.deep_clone())
                     .expect("Failure save order");
 
-                log::error!("Order creation failed {args_to_log:?}: {exchange_error:?}");
+                log::error!(
+                    "Order creation failed {args_to_log:?}, correlation_id: {}: {exchange_error:?}",
+                    order.correlation_id()
+                );
 
                 Ok(())
             }
@@ -608,11 +952,13 @@ impl Exchange {
         client_order_id: &ClientOrderId,
         exchange_order_id: &ExchangeOrderId,
         source_type: EventSourceType,
+        exchange_time: Option<DateTime>,
     ) -> Result<()> {
         log::trace!(
-            concat!("started ", function_name!(), " {} {:?}"),
+            concat!("started ", function_name!(), " {} {:?} {:?}"),
             client_order_id,
             source_type,
+            exchange_time,
         );
 
         if should_ignore_event(self.features.allowed_create_event_source_type, source_type) {
@@ -646,17 +992,51 @@ impl Exchange {
                 order_ref.fn_mut(|order| {
                     order.props.exchange_order_id = Some(exchange_order_id.clone());
                 });
-                self.react_on_status_when_succeed(&order_ref, args_to_log, source_type)
+                let received = SourcedEvent::new(source_type, exchange_time);
+                self.react_on_status_when_succeed(&order_ref, args_to_log, received)
             }
         }
     }
 
+    fn should_replace_creation(&self, order: &OrderRef, received: SourcedEvent) -> bool {
+        let applied = order.fn_ref(|x| {
+            x.internal_props
+                .creation_event_source_type
+                .map(|source_type| {
+                    SourcedEvent::new(source_type, x.internal_props.creation_event_time)
+                })
+        });
+
+        self.order_events_merger.lock().merge(applied, received) == MergeDecision::Replace
+    }
+
+    /// Replace source of already applied creation with event preferred by merge policy
+    fn reconcile_creation(
+        &self,
+        order: &OrderRef,
+        args_to_log: (ExchangeAccountId, &ClientOrderId, &ExchangeOrderId),
+        received: SourcedEvent,
+    ) {
+        let previous_source_type = order.fn_mut(|x| {
+            x.internal_props.creation_event_time = received.exchange_time;
+            x.internal_props
+                .creation_event_source_type
+                .replace(received.source_type)
+        });
+
+        log::info!(
+            "Creation of order {args_to_log:?} from {previous_source_type:?} was reconciled with event from {:?}",
+            received.source_type
+        );
+    }
+
     fn react_on_status_when_succeed(
         &self,
         order: &OrderRef,
         args_to_log: (ExchangeAccountId, &ClientOrderId, &ExchangeOrderId),
-        source_type: EventSourceType,
+        received: SourcedEvent,
     ) -> Result<()> {
+        let source_type = received.source_type;
         let status = order.status();
         let exchange_order_id = args_to_log.2;
         match status {
@@ -670,9 +1050,12 @@ impl Exchange {
             | OrderStatus::Canceled
             | OrderStatus::Completed
             | OrderStatus::FailedToCancel => {
-                log::warn!(
-                    "CreateOrderSucceeded was received for a {status:?} order {args_to_log:?}"
-                );
+                match self.should_replace_creation(order, received) {
+                    true => self.reconcile_creation(order, args_to_log, received),
+                    false => log::warn!(
+                        "CreateOrderSucceeded was received for a {status:?} order {args_to_log:?}"
+                    ),
+                }
                 Ok(())
             }
             OrderStatus::Creating => {
@@ -690,14 +1073,24 @@ impl Exchange {
 
                 // TODO RestFallback and some metrics
 
-                order.fn_mut(|order| {
-                    order.set_status(OrderStatus::Created, Utc::now());
+                let is_applied = order.fn_mut(|order| {
                     order.internal_props.creation_event_source_type = Some(source_type);
+                    order.internal_props.creation_event_time = received.exchange_time;
+                    order.set_status(OrderStatus::Created, time_manager::now())
                 });
+                if !is_applied {
+                    log::warn!("CreateOrderSucceeded is ignored for order {args_to_log:?} because its status can't be changed to Created");
+                    return Ok(());
+                }
 
                 self.orders
-                    .cache_by_exchange_id
-                    .insert(exchange_order_id.clone(), order.clone());
+                    .add_exchange_order_id(exchange_order_id.clone(), order);
+                self.register_order_placement(order.currency_pair());
+
+                log::info!(
+                    "Order created {args_to_log:?}, correlation_id: {}",
+                    order.correlation_id()
+                );
 
                 let header = order.header();
                 let client_order_id = header.client_order_id.clone();
@@ -721,6 +1114,7 @@ impl Exchange {
                 }
 
                 self.add_event_on_order_change(order, OrderEventType::CreateOrderSucceeded)?;
+                self.register_order_ack_latency(order, source_type)?;
 
                 let mut buffered_fills_manager = self.buffered_fills_manager.lock();
                 if let Some(buffered_fills) = buffered_fills_manager.get_fills(exchange_order_id) {
@@ -747,6 +1141,7 @@ impl Exchange {
                         exchange_order_id,
                         None,
                         source_type,
+                        None,
                     );
                     buffered_canceled_orders_manager.remove_order(exchange_order_id);
                 }
@@ -763,6 +1158,35 @@ impl Exchange {
         }
     }
 
+    /// Measure time from order submission to the first acknowledgement received from exchange
+    fn register_order_ack_latency(&self, order: &OrderRef, source: EventSourceType) -> Result<()> {
+        let submit_time = order.fn_ref(|o| o.init_time());
+        let ack_time = time_manager::now();
+
+        self.save_metrics(
+            &MetricsEventInfoBase::new(
+                submit_time.timestamp_millis(),
+                ack_time.timestamp_millis(),
+                MetricsEventType::OrderFromCreateToAck,
+            ),
+            0,
+        );
+
+        let event = ExchangeEvent::OrderAckLatency(OrderAckLatencyEvent {
+            exchange_account_id: self.exchange_account_id,
+            client_order_id: order.client_order_id(),
+            // clock can be adjusted between submission and acknowledgement
+            latency: (ack_time - submit_time).to_std().unwrap_or_default(),
+            source,
+            event_creation_time: ack_time,
+        });
+        self.events_channel
+            .send(event)
+            .context("Unable to send event. Probably receiver is already dropped")?;
+
+        Ok(())
+    }
+
     pub(super) async fn create_order_created_fut(
         &self,
         order: &OrderRef,
@@ -805,3 +1229,226 @@ impl Exchange {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use crate::settings::{EventMergePolicy, OrderEventsMergeSettings};
+    use chrono::{TimeZone, Utc};
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::order::event::OrderEvent;
+    use mmb_domain::order::snapshot::{OrderSide, UserOrder};
+
+    use mmb_domain::{amount, price};
+
+    fn order_header(exchange: &Exchange, client_order_id: ClientOrderId, tag: &str) -> OrderHeader {
+        OrderHeader::with_user_order(
+            client_order_id,
+            exchange.exchange_account_id,
+            CurrencyPair::from_codes("eth".into(), "btc".into()),
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+            None,
+            None,
+            "test".to_owned(),
+        )
+        .with_tag(tag.to_owned())
+    }
+
+    fn tagged_order_header(exchange: &Exchange, tag: &str) -> OrderHeader {
+        let client_order_id = exchange
+            .generate_client_order_id(Some(tag))
+            .expect("in test");
+        order_header(exchange, client_order_id, tag)
+    }
+
+    #[tokio::test]
+    async fn encode_tag_to_client_order_id_if_tags_not_supported() {
+        let (exchange, _) = get_test_exchange(false);
+
+        let client_order_id = exchange
+            .generate_client_order_id(Some("mm"))
+            .expect("in test");
+        assert_eq!(
+            decode_tag_from_client_order_id(&client_order_id),
+            Some("mm")
+        );
+
+        let order_header = order_header(&exchange, client_order_id, "mm");
+        assert!(exchange.check_order_tag(&order_header).is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_invalid_tag() {
+        let (exchange, _) = get_test_exchange(false);
+
+        assert!(exchange.generate_client_order_id(Some("mm:btc")).is_err());
+
+        let order_header = order_header(&exchange, ClientOrderId::from("mm:btc:1"), "mm:btc");
+        assert!(exchange.check_order_tag(&order_header).is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_tag_not_encoded_to_client_order_id() {
+        let (exchange, _) = get_test_exchange(false);
+
+        // client order id of order added to pool can't be changed, so tag isn't encoded on creation
+        let order_header = order_header(&exchange, ClientOrderId::unique_id(), "mm");
+        assert!(exchange.check_order_tag(&order_header).is_err());
+    }
+
+    #[tokio::test]
+    async fn reject_too_long_client_order_id() {
+        let (exchange, _) = get_test_exchange(false);
+
+        let tag = "a".repeat(36);
+        assert!(exchange.generate_client_order_id(Some(&tag)).is_err());
+
+        let client_order_id = ClientOrderId::from(format!("{tag}:1").as_str());
+        let order_header = order_header(&exchange, client_order_id, &tag);
+        assert!(exchange.check_order_tag(&order_header).is_err());
+    }
+
+    #[tokio::test]
+    async fn order_ack_latency_is_sent_on_first_creation_acknowledgement() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        let order_header = tagged_order_header(&exchange, "mm");
+        let order = exchange.add_initial_order(&order_header, time_manager::now());
+        let exchange_order_id = ExchangeOrderId::from("1");
+
+        for source_type in [EventSourceType::WebSocket, EventSourceType::Rest] {
+            exchange
+                .handle_create_order_succeeded(
+                    exchange.exchange_account_id,
+                    &order.client_order_id(),
+                    &exchange_order_id,
+                    source_type,
+                    None,
+                )
+                .expect("in test");
+        }
+
+        let mut ack_latency_events = vec![];
+        while let Ok(event) = events_receiver.try_recv() {
+            if let ExchangeEvent::OrderAckLatency(event) = event {
+                ack_latency_events.push(event);
+            }
+        }
+        assert_eq!(ack_latency_events.len(), 1);
+        assert_eq!(
+            ack_latency_events[0].client_order_id,
+            order.client_order_id()
+        );
+        assert_eq!(ack_latency_events[0].source, EventSourceType::WebSocket);
+    }
+
+    #[tokio::test]
+    async fn reconcile_creation_with_event_of_later_exchange_time() {
+        let (exchange, _rx) = get_test_exchange(false);
+        exchange.setup_order_events_merger(&OrderEventsMergeSettings {
+            policy: EventMergePolicy::LatestTimestampWins,
+            ..Default::default()
+        });
+        let order_header = tagged_order_header(&exchange, "mm");
+        let order = exchange.add_initial_order(&order_header, time_manager::now());
+        let exchange_order_id = ExchangeOrderId::from("1");
+
+        let events = [
+            (EventSourceType::WebSocket, 10),
+            (EventSourceType::Rest, 11),
+            (EventSourceType::RestFallback, 9),
+        ];
+        for (source_type, secs) in events {
+            exchange
+                .handle_create_order_succeeded(
+                    exchange.exchange_account_id,
+                    &order.client_order_id(),
+                    &exchange_order_id,
+                    source_type,
+                    Utc.timestamp_opt(secs, 0).single(),
+                )
+                .expect("in test");
+        }
+
+        let (source_type, exchange_time) = order.fn_ref(|x| {
+            (
+                x.internal_props.creation_event_source_type,
+                x.internal_props.creation_event_time,
+            )
+        });
+        assert_eq!(order.status(), OrderStatus::Created);
+        assert_eq!(source_type, Some(EventSourceType::Rest));
+        assert_eq!(exchange_time, Utc.timestamp_opt(11, 0).single());
+    }
+
+    #[tokio::test]
+    async fn rekey_order_with_regenerated_client_order_id() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        let order_header = tagged_order_header(&exchange, "mm");
+        let previous_order = exchange.add_initial_order(&order_header, time_manager::now());
+        let previous_client_order_id = previous_order.client_order_id();
+
+        let order = exchange
+            .regenerate_client_order_id(&previous_order)
+            .expect("in test");
+
+        let client_order_id = order.client_order_id();
+        assert_ne!(client_order_id, previous_client_order_id);
+        assert!(client_order_id.as_str().starts_with("mm:"));
+        assert_eq!(order.price(), previous_order.price());
+        assert_eq!(order.status(), OrderStatus::Creating);
+        assert!(!exchange
+            .orders
+            .cache_by_client_id
+            .contains_key(&previous_client_order_id));
+        assert!(exchange.orders.not_finished.contains_key(&client_order_id));
+
+        let event = events_receiver.try_recv().expect("in test");
+        match event {
+            ExchangeEvent::OrderEvent(OrderEvent {
+                order: event_order,
+                event_type:
+                    OrderEventType::ClientOrderIdRegenerated {
+                        previous_client_order_id: event_previous_client_order_id,
+                    },
+            }) => {
+                assert_eq!(event_order, order);
+                assert_eq!(event_previous_client_order_id, previous_client_order_id);
+            }
+            _ => panic!("ClientOrderIdRegenerated event should be sent, but got {event:?}"),
+        }
+    }
+
+    #[test]
+    fn duplicate_client_order_id_is_detected_through_context() {
+        let error = ExchangeError::new(
+            ExchangeErrorType::DuplicateClientOrderId,
+            "Duplicate order sent.".to_owned(),
+            Some(-2010),
+        );
+        let error = Err::<(), _>(error)
+            .context("failed create_order")
+            .expect_err("in test");
+
+        assert!(is_duplicate_client_order_id(&error));
+    }
+
+    #[test]
+    fn other_errors_are_not_duplicate_client_order_id() {
+        let error = ExchangeError::new(
+            ExchangeErrorType::InvalidOrder,
+            "Invalid quantity.".to_owned(),
+            Some(-1013),
+        );
+        let error = Err::<(), _>(error)
+            .context("failed create_order")
+            .expect_err("in test");
+
+        assert!(!is_duplicate_client_order_id(&error));
+        assert!(!is_duplicate_client_order_id(&anyhow::anyhow!(
+            "some error"
+        )));
+    }
+}
@@ -1,6 +1,7 @@
 use mmb_domain::events::EventSourceType;
 use mmb_domain::order::snapshot::{ClientOrderId, ExchangeOrderId};
 use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
 use tokio::sync::oneshot;
 
 use crate::{exchanges::general::exchange::Exchange, exchanges::general::exchange::RequestResult};
@@ -48,10 +49,12 @@ impl Exchange {
         client_order_id: &ClientOrderId,
         exchange_order_id: &ExchangeOrderId,
         source_type: EventSourceType,
+        exchange_time: Option<DateTime>,
     ) {
         if let Some((_, (tx, _))) = self.order_creation_events.remove(client_order_id) {
-            if let Err(error) = tx.send(CreateOrderResult::succeed(exchange_order_id, source_type))
-            {
+            let result = CreateOrderResult::succeed(exchange_order_id, source_type)
+                .with_exchange_time(exchange_time);
+            if let Err(error) = tx.send(result) {
                 log::error!("Unable to send thru oneshot channel: {error:?}");
             }
         } else {
@@ -60,6 +63,7 @@ impl Exchange {
                 client_order_id,
                 exchange_order_id,
                 source_type,
+                exchange_time,
             )
             .with_expect(|| format!("Error handle create order succeeded for {client_order_id:?}"));
         }
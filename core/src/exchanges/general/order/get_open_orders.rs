@@ -81,6 +81,8 @@ impl Exchange {
             ),
         };
 
+        let open_orders = self.exclude_unmanaged_orders(open_orders);
+
         if check_missing_orders {
             self.add_missing_open_orders(&open_orders);
         }
@@ -88,7 +90,19 @@ impl Exchange {
         Ok(open_orders)
     }
 
-    fn add_missing_open_orders(&self, open_orders: &[OrderInfo]) {
+    fn exclude_unmanaged_orders(&self, open_orders: Vec<OrderInfo>) -> Vec<OrderInfo> {
+        let unmanaged_orders = self.unmanaged_orders.lock();
+        if unmanaged_orders.is_empty() {
+            return open_orders;
+        }
+
+        open_orders
+            .into_iter()
+            .filter(|x| !unmanaged_orders.contains(&x.exchange_order_id))
+            .collect()
+    }
+
+    pub(crate) fn add_missing_open_orders(&self, open_orders: &[OrderInfo]) {
         for order_info in open_orders {
             if order_info.client_order_id.as_str().is_empty()
                 && self
@@ -146,11 +160,8 @@ impl Exchange {
                 extension_data: order_info.extension_data.clone(),
             };
 
-            let new_order = self.orders.add_snapshot_initial(&new_snapshot);
-
-            self.orders
-                .cache_by_exchange_id
-                .insert(order_info.exchange_order_id.clone(), new_order);
+            // order is indexed by exchange order id from snapshot
+            let _ = self.orders.add_snapshot_initial(&new_snapshot);
 
             log::trace!(
                 "Added open order {} {} on {}",
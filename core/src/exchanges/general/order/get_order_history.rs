@@ -0,0 +1,44 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use anyhow::{Context, Result};
+use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::snapshot::OrderInfo;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
+
+impl Exchange {
+    /// Orders of currency pair created within time range in any status including filled
+    /// and cancelled ones. Complements `get_open_orders` for reconciliation after restart
+    pub async fn get_order_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+        cancellation_token: CancellationToken,
+    ) -> Result<Vec<OrderInfo>> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetOrderHistory,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .get_order_history(currency_pair, from, to)
+            .await
+            .with_context(|| {
+                format!(
+                    "Order history isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get order history of {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })
+    }
+}
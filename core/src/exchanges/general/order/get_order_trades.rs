@@ -10,6 +10,7 @@ use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::snapshot::{Amount, Price};
 use mmb_domain::order::snapshot::{ExchangeOrderId, OrderRole};
 use mmb_utils::DateTime;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -21,7 +22,7 @@ pub struct OrderTrade {
     pub amount: Amount,
     pub order_role: OrderRole,
     pub fee_currency_code: CurrencyCode,
-    pub fee_rate: Option<Price>,
+    pub fee_rate: Option<Decimal>,
     pub fee_amount: Option<Amount>,
     pub fill_type: OrderFillType,
 }
@@ -36,7 +37,7 @@ impl OrderTrade {
         amount: Amount,
         order_role: OrderRole,
         fee_currency_code: CurrencyCode,
-        fee_rate: Option<Price>,
+        fee_rate: Option<Decimal>,
         fee_amount: Option<Amount>,
         fill_type: OrderFillType,
     ) -> Self {
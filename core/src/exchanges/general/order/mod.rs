@@ -1,8 +1,12 @@
 pub mod cancel;
+pub mod cancel_all;
+pub mod confirm_resting;
 pub mod create;
 pub mod create_websocket_based;
 pub mod get_info;
 pub mod get_open_orders;
+pub mod get_order_history;
 pub mod get_order_trades;
+pub mod replace;
 pub mod wait_cancel;
 pub mod wait_finish;
@@ -0,0 +1,203 @@
+use anyhow::{anyhow, Context, Result};
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::Price;
+use mmb_utils::cancellation_token::CancellationToken;
+use std::sync::Arc;
+
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::timeouts::requests_timeout_manager::RequestGroupId;
+use crate::orders::replace_price::{ReplacePriceCheck, ReplacePriceGuard};
+use crate::settings::ReplacePriceSettings;
+
+impl Exchange {
+    pub fn setup_replace_price_guard(&self, settings: &ReplacePriceSettings) {
+        *self.replace_price_guard.lock() = Some(Arc::new(ReplacePriceGuard::new(settings)));
+    }
+
+    /// Price of replacement of active order. If min price change of replacement is configured and
+    /// requested price is too close to price of original order, replacement is skipped (`None`)
+    /// or its price is adjusted by min price change according to configured action
+    pub fn replacement_price(&self, order: &OrderRef, price: Price) -> Option<Price> {
+        match self.check_replace_price(order, price) {
+            ReplacePriceCheck::Allowed => Some(price),
+            ReplacePriceCheck::Skipped => {
+                log::info!(
+                    "Replacement of order {} with price {price} is skipped because price change is less than min price change on {}",
+                    order.client_order_id(),
+                    self.exchange_account_id
+                );
+                None
+            }
+            ReplacePriceCheck::Adjusted(adjusted_price) => {
+                log::info!(
+                    "Price {price} of replacement of order {} is adjusted to {adjusted_price} by min price change on {}",
+                    order.client_order_id(),
+                    self.exchange_account_id
+                );
+                Some(adjusted_price)
+            }
+        }
+    }
+
+    /// Replace limit order with `replacement` which is already added to orders pool with
+    /// header from `OrderRef::replacement_header()` and its own balance reservation.
+    /// Original order is cancelled first and replacement is created only after that, so
+    /// replacement is failed if original order got fills during cancellation and replacement
+    /// amount exceeds amount left unfilled
+    pub async fn replace_order(
+        &self,
+        order: &OrderRef,
+        cancel_request_group_id: Option<RequestGroupId>,
+        replacement: &OrderRef,
+        create_request_group_id: Option<RequestGroupId>,
+        cancellation_token: CancellationToken,
+    ) -> Result<OrderRef> {
+        let replacement_client_order_id = replacement.client_order_id();
+
+        if !order.is_finished() {
+            let cancel_result = self
+                .wait_cancel_order(
+                    order.clone(),
+                    cancel_request_group_id,
+                    true,
+                    cancellation_token.clone(),
+                )
+                .await
+                .with_context(|| {
+                    format!(
+                        "Unable to cancel order {} for replacement by order {replacement_client_order_id}",
+                        order.client_order_id()
+                    )
+                });
+
+            if let Err(error) = cancel_result {
+                return Err(self.fail_creating_order(replacement, error));
+            }
+        }
+
+        let unfilled_amount = order.amount() - order.filled_amount();
+        if replacement.amount() > unfilled_amount {
+            let error = anyhow!(
+                "Replacement amount {} exceeds unfilled amount {unfilled_amount} of order {} after its cancellation",
+                replacement.amount(),
+                order.client_order_id()
+            );
+            return Err(self.fail_creating_order(replacement, error));
+        }
+
+        log::info!(
+            "Replacing order {} by order {replacement_client_order_id} with price {} and amount {}",
+            order.client_order_id(),
+            replacement.price(),
+            replacement.amount()
+        );
+
+        self.create_order(
+            replacement.header(),
+            create_request_group_id,
+            cancellation_token,
+        )
+        .await
+    }
+
+    fn check_replace_price(&self, order: &OrderRef, price: Price) -> ReplacePriceCheck {
+        let replace_price_guard = match self.replace_price_guard.lock().clone() {
+            None => return ReplacePriceCheck::Allowed,
+            Some(replace_price_guard) => replace_price_guard,
+        };
+
+        match self.symbols.get(&order.currency_pair()) {
+            None => ReplacePriceCheck::Allowed,
+            Some(symbol) => replace_price_guard.check(&symbol, order.price(), price),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::{get_test_exchange, order_header};
+    use crate::infrastructure::init_lifetime_manager;
+    use crate::misc::time::time_manager;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::order::fill::{OrderFill, OrderFillType};
+    use mmb_domain::order::snapshot::{
+        Amount, ClientOrderId, OrderFillRole, OrderHeader, OrderSide, OrderStatus, ReservationId,
+        UserOrder,
+    };
+    use mmb_domain::{amount, price};
+    use uuid::Uuid;
+
+    fn order_fill(amount: Amount) -> OrderFill {
+        OrderFill::new(
+            Uuid::new_v4(),
+            None,
+            time_manager::now(),
+            OrderFillType::UserTrade,
+            None,
+            price!(0.1),
+            amount,
+            amount * price!(0.1),
+            OrderFillRole::Maker,
+            "eth".into(),
+            amount!(0),
+            amount!(0),
+            "eth".into(),
+            amount!(0),
+            amount!(0),
+            false,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn fail_replacement_if_original_order_got_fills_after_its_preparation() {
+        let _ = init_lifetime_manager();
+        let (exchange, _) = get_test_exchange(false);
+        let now = time_manager::now();
+        let header = OrderHeader {
+            reservation_id: Some(ReservationId::generate()),
+            ..order_header(
+                &exchange,
+                CurrencyPair::from_codes("eth".into(), "btc".into()),
+                OrderSide::Buy,
+                amount!(1),
+                UserOrder::limit(price!(0.1)),
+            )
+        };
+        let order = exchange.add_initial_order(&header, now);
+
+        let replacement_header = order
+            .replacement_header(
+                ClientOrderId::unique_id(),
+                price!(0.2),
+                None,
+                Some(ReservationId::generate()),
+            )
+            .expect("in test");
+        let replacement = exchange.add_initial_order(&replacement_header, now);
+
+        order.fn_mut(|x| {
+            x.add_fill(order_fill(amount!(0.4)));
+            x.set_status(OrderStatus::Completed, now);
+        });
+
+        let result = exchange
+            .replace_order(
+                &order,
+                None,
+                &replacement,
+                None,
+                CancellationToken::default(),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(replacement.status(), OrderStatus::FailedToCreate);
+        assert_ne!(
+            replacement.header().reservation_id,
+            order.header().reservation_id
+        );
+    }
+}
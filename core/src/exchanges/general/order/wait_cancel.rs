@@ -137,6 +137,20 @@ impl Exchange {
 
             log!(log_event_level, "Cancellation iteration is {attempt_number} on {client_order_id} {exchange_order_id:?} {}", self.exchange_account_id);
 
+            if attempt_number > 1 && !self.spend_order_retry(order, "cancellation") {
+                let is_applied = order.fn_mut(|x| {
+                    x.internal_props.last_cancellation_error =
+                        Some(ExchangeErrorType::RetriesExhausted);
+                    x.set_status(OrderStatus::FailedToCancel, time_manager::now())
+                });
+                // order can be already finished, so there is nothing to report
+                if is_applied {
+                    self.add_event_on_order_change(order, OrderEventType::CancelOrderFailed)?;
+                }
+
+                bail!("Retry budget of order {client_order_id} is exhausted, so its cancellation is stopped");
+            }
+
             self.timeout_manager
                 .reserve_when_available(
                     self.exchange_account_id,
@@ -391,6 +405,7 @@ impl Exchange {
                                     &exchange_order_id,
                                     Some(order_info.filled_amount),
                                     EventSourceType::RestFallback,
+                                    None,
                                 );
                             }
                         }
@@ -0,0 +1,188 @@
+use crate::exchanges::general::exchange::{Exchange, OrderBookTop};
+use crate::exchanges::general::request_type::RequestType;
+use crate::misc::time::time_manager;
+use anyhow::{Context, Result};
+use mmb_domain::events::ExchangeEvent;
+use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::snapshot::Price;
+use mmb_domain::order_book::event::{EventType, OrderBookEvent};
+use mmb_domain::order_book::order_book_data::OrderBookData;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::send_expected::SendExpectedByRef;
+use std::sync::Arc;
+
+impl Exchange {
+    /// Full order book snapshot of currency pair requested by REST
+    pub async fn get_order_book_snapshot(
+        &self,
+        currency_pair: CurrencyPair,
+        cancellation_token: CancellationToken,
+    ) -> Result<OrderBookData> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetOrderBook,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .get_order_book_snapshot(currency_pair)
+            .await
+            .with_context(|| {
+                format!(
+                    "Order book snapshots aren't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get order book snapshot of {currency_pair} on {}",
+                    self.exchange_account_id
+                )
+            })
+    }
+
+    /// Request REST snapshots of order books which are maintained from streams and publish them
+    /// as snapshot events, so local order books are replaced entirely instead of being updated.
+    /// Runs apart from stream processing, which keeps applying updates while snapshots are requested
+    pub async fn refresh_order_book_snapshots(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+    ) {
+        let currency_pairs: Vec<_> = self.order_book_top.iter().map(|x| *x.key()).collect();
+
+        for currency_pair in currency_pairs {
+            self.refresh_order_book_snapshot(currency_pair, cancellation_token.clone())
+                .await;
+        }
+    }
+
+    /// Replace order books restored from persisted snapshots on startup with REST snapshots,
+    /// so they are trusted only until actual state is received
+    pub async fn resync_warm_started_order_books(
+        self: Arc<Self>,
+        currency_pairs: Vec<CurrencyPair>,
+        cancellation_token: CancellationToken,
+    ) {
+        for currency_pair in currency_pairs {
+            self.refresh_order_book_snapshot(currency_pair, cancellation_token.clone())
+                .await;
+        }
+    }
+
+    async fn refresh_order_book_snapshot(
+        &self,
+        currency_pair: CurrencyPair,
+        cancellation_token: CancellationToken,
+    ) {
+        let order_book_data = match self
+            .get_order_book_snapshot(currency_pair, cancellation_token)
+            .await
+        {
+            Ok(order_book_data) => order_book_data,
+            Err(error) => {
+                log::warn!("{error:?}");
+                return;
+            }
+        };
+
+        if let Some(top) = self.order_book_top.get(&currency_pair) {
+            if let Some(divergence) = top_divergence(&top, &order_book_data) {
+                log::warn!(
+                    "Local order book of {currency_pair} on {} diverged from REST snapshot: {divergence}",
+                    self.exchange_account_id
+                );
+            }
+        }
+
+        self.events_channel
+            .send_expected(ExchangeEvent::OrderBookEvent(OrderBookEvent::new(
+                time_manager::now(),
+                self.exchange_account_id,
+                currency_pair,
+                String::new(),
+                EventType::Snapshot,
+                Arc::new(order_book_data),
+            )));
+    }
+}
+
+/// Description of mismatched top prices of local order book and received snapshot.
+/// Returns `None` if they match
+fn top_divergence(top: &OrderBookTop, snapshot: &OrderBookData) -> Option<String> {
+    let local_ask = top.ask.as_ref().map(|x| x.price);
+    let local_bid = top.bid.as_ref().map(|x| x.price);
+    let snapshot_ask = snapshot.asks.keys().next().copied();
+    let snapshot_bid = snapshot.bids.keys().next_back().copied();
+
+    let describe = |side: &str, local: Option<Price>, snapshot: Option<Price>| {
+        (local != snapshot)
+            .then(|| format!("top {side} {local:?} locally, {snapshot:?} in snapshot"))
+    };
+
+    let divergences: Vec<_> = [
+        describe("ask", local_ask, snapshot_ask),
+        describe("bid", local_bid, snapshot_bid),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    (!divergences.is_empty()).then(|| divergences.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::exchange::PriceLevel;
+    use chrono::Utc;
+    use mmb_domain::order_book_data;
+    use mmb_domain::{amount, price};
+    use rust_decimal_macros::dec;
+
+    fn order_book_top(ask: Price, bid: Price) -> OrderBookTop {
+        OrderBookTop {
+            ask: Some(PriceLevel {
+                price: ask,
+                amount: amount!(1),
+            }),
+            bid: Some(PriceLevel {
+                price: bid,
+                amount: amount!(1),
+            }),
+            last_update_time: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn matched_top_prices_are_not_divergence() {
+        let snapshot = order_book_data![
+            dec!(10.5) => dec!(3),
+            dec!(10.2) => dec!(2),
+            ;
+            dec!(10.1) => dec!(5),
+            dec!(9.8) => dec!(1),
+        ];
+
+        assert_eq!(
+            top_divergence(&order_book_top(price!(10.2), price!(10.1)), &snapshot),
+            None
+        );
+    }
+
+    #[test]
+    fn mismatched_top_price_is_divergence() {
+        let snapshot = order_book_data![
+            dec!(10.5) => dec!(3),
+            ;
+            dec!(10.1) => dec!(5),
+        ];
+
+        assert_eq!(
+            top_divergence(&order_book_top(price!(10.2), price!(10.1)), &snapshot),
+            Some("top ask Some(10.2) locally, Some(10.5) in snapshot".to_owned())
+        );
+    }
+}
@@ -0,0 +1,602 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::settings::OrderCheck;
+use anyhow::Result;
+use mmb_domain::order::snapshot::OrderHeader;
+
+/// Pre-flight checks of order in order of running. Cheap checks of exchange state go first
+pub const ORDER_CHECKS: [OrderCheck; 14] = [
+    OrderCheck::Warmup,
+    OrderCheck::ClockSkew,
+    OrderCheck::SymbolStatus,
+    OrderCheck::OrderType,
+    OrderCheck::RejectionPause,
+    OrderCheck::OrderReservation,
+    OrderCheck::PriceBand,
+    OrderCheck::OrderBookFreshness,
+    OrderCheck::MarketDataHealth,
+    OrderCheck::OpenOrdersCount,
+    OrderCheck::OrderRateLimit,
+    OrderCheck::OrderAmount,
+    OrderCheck::TimeInForce,
+    OrderCheck::Capabilities,
+];
+
+impl Exchange {
+    pub fn setup_disabled_order_checks(&self, disabled_order_checks: &[OrderCheck]) {
+        *self.disabled_order_checks.lock() = disabled_order_checks.iter().copied().collect();
+    }
+
+    /// Run enabled pre-flight checks of order one by one. Order is rejected by error of the first
+    /// failed check, so the rest of checks aren't run
+    pub(crate) fn run_order_checks(&self, order_header: &OrderHeader) -> Result<()> {
+        // invalid client order id is rejected by exchange anyway, so its check can't be disabled
+        self.check_order_tag(order_header)?;
+
+        let disabled_order_checks = self.disabled_order_checks.lock().clone();
+
+        for check in ORDER_CHECKS {
+            if disabled_order_checks.contains(&check) {
+                continue;
+            }
+
+            self.run_order_check(check, order_header)?;
+        }
+
+        Ok(())
+    }
+
+    fn run_order_check(&self, check: OrderCheck, order_header: &OrderHeader) -> Result<()> {
+        match check {
+            OrderCheck::Warmup => self.check_warmup(order_header.currency_pair),
+            OrderCheck::ClockSkew => self.check_clock_skew_for_order(order_header),
+            OrderCheck::SymbolStatus => self.check_symbol_status(order_header),
+            OrderCheck::OrderType => self.check_order_type(order_header),
+            OrderCheck::RejectionPause => self.check_rejection_pause(order_header),
+            OrderCheck::OrderReservation => self.check_order_reservation(order_header),
+            OrderCheck::PriceBand => self.check_price_band(order_header),
+            OrderCheck::OrderBookFreshness => self.check_order_book_freshness(order_header),
+            OrderCheck::MarketDataHealth => self.check_market_data_health(order_header),
+            OrderCheck::OpenOrdersCount => self.check_open_orders_count(order_header),
+            OrderCheck::OrderRateLimit => self.check_order_rate_limit(order_header),
+            OrderCheck::OrderAmount => self.check_order_amount(order_header),
+            OrderCheck::TimeInForce => self.check_time_in_force(order_header),
+            OrderCheck::Capabilities => Ok(self
+                .exchange_client
+                .capabilities()
+                .check_order(order_header)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::exchange::OrderBookTop;
+    use crate::exchanges::general::test_helper::{
+        self, get_test_exchange, get_test_exchange_with_symbol,
+    };
+    use crate::exchanges::traits::ExchangeError;
+    use crate::misc::time::time_manager;
+    use crate::settings::{
+        ClockSkewSettings, OrderBookFreshnessSettings, PriceBandAction, PriceBandReference,
+        PriceBandSettings, WarmupSettings,
+    };
+    use mmb_domain::events::{ExchangeEvent, MarkPriceEvent, SubscriptionFailedEvent};
+    use mmb_domain::exchanges::symbol::{Precision, Symbol, SymbolStatus};
+    use mmb_domain::market::{CurrencyPair, ExchangeErrorType};
+    use mmb_domain::order::event::{OrderEvent, OrderEventType};
+    use mmb_domain::order::snapshot::{
+        OrderOptions, OrderSide, OrderStatus, OrderType, ReservationId, TimeInForce, UserOrder,
+    };
+    use mmb_domain::{amount, price};
+    use mmb_utils::cancellation_token::CancellationToken;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+    use std::sync::Arc;
+
+    fn currency_pair() -> CurrencyPair {
+        CurrencyPair::from_codes("phb".into(), "btc".into())
+    }
+
+    fn order_header(exchange: &Exchange) -> OrderHeader {
+        test_helper::order_header(
+            exchange,
+            currency_pair(),
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+        )
+    }
+
+    /// Set up exchange state or order, so specified check rejects order
+    fn fail_order_check(exchange: &Exchange, check: OrderCheck, order_header: &mut OrderHeader) {
+        let currency_pair = currency_pair();
+        match check {
+            OrderCheck::Warmup => exchange.setup_warmup(&WarmupSettings {
+                min_trades: 1,
+                min_candles: 0,
+            }),
+            OrderCheck::ClockSkew => {
+                exchange.setup_clock_skew_monitor(&ClockSkewSettings {
+                    max_offset_ms: 1_000,
+                    check_interval_secs: 60,
+                });
+                let clock_skew_monitor = exchange.clock_skew_monitor.lock().clone();
+                let _ = clock_skew_monitor.expect("in test").update(5_000);
+            }
+            OrderCheck::SymbolStatus => {
+                let mut symbol = (**exchange.symbols.get(&currency_pair).expect("in test")).clone();
+                symbol.status = SymbolStatus::Halt;
+                let _ = exchange.symbols.insert(currency_pair, Arc::new(symbol));
+            }
+            OrderCheck::OrderType => {
+                let mut symbol = (**exchange.symbols.get(&currency_pair).expect("in test")).clone();
+                symbol.allowed_order_types = Some(vec![OrderType::Market]);
+                let _ = exchange.symbols.insert(currency_pair, Arc::new(symbol));
+            }
+            OrderCheck::RejectionPause => {
+                let _ = exchange
+                    .paused_placements
+                    .insert(currency_pair, time_manager::now());
+            }
+            OrderCheck::OrderReservation => exchange.setup_require_order_reservation(true),
+            OrderCheck::PriceBand => {
+                exchange.setup_price_band(&PriceBandSettings {
+                    default_max_deviation: Some(dec!(0.1)),
+                    currency_pairs: vec![],
+                    reference: PriceBandReference::MarkPrice,
+                    action: PriceBandAction::Reject,
+                });
+                let _ = exchange.mark_prices.insert(
+                    currency_pair,
+                    MarkPriceEvent {
+                        exchange_account_id: exchange.exchange_account_id,
+                        currency_pair,
+                        mark_price: price!(100),
+                        index_price: price!(100),
+                        funding_rate: dec!(0),
+                        next_funding_time: time_manager::now(),
+                    },
+                );
+            }
+            OrderCheck::OrderBookFreshness => {
+                exchange.setup_order_book_freshness(&OrderBookFreshnessSettings {
+                    default_max_age_ms: Some(1_000),
+                    currency_pairs: vec![],
+                });
+                let _ = exchange.order_book_top.insert(
+                    currency_pair,
+                    OrderBookTop {
+                        ask: None,
+                        bid: None,
+                        last_update_time: time_manager::now() - chrono::Duration::seconds(5),
+                    },
+                );
+            }
+            OrderCheck::MarketDataHealth => {
+                exchange.mark_market_data_broken(currency_pair, "Invalid symbol")
+            }
+            OrderCheck::OpenOrdersCount => {
+                exchange.setup_max_open_orders_per_currency_pair(Some(0))
+            }
+            OrderCheck::OrderAmount => {
+                let symbol = Symbol::new(
+                    false,
+                    "PHB".into(),
+                    "PHB".into(),
+                    "BTC".into(),
+                    "BTC".into(),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    "PHB".into(),
+                    None,
+                    Precision::ByTick { tick: dec!(0.1) },
+                    Precision::ByTick { tick: dec!(10) },
+                );
+                let _ = exchange.symbols.insert(currency_pair, Arc::new(symbol));
+            }
+            OrderCheck::TimeInForce => {
+                order_header.time_in_force = TimeInForce::Gtd(time_manager::now())
+            }
+            OrderCheck::OrderRateLimit | OrderCheck::Capabilities => {
+                unreachable!("{check:?} can't be failed by test exchange")
+            }
+        }
+    }
+
+    #[rstest]
+    #[case::warmup(OrderCheck::Warmup, ExchangeErrorType::StaleMarketData)]
+    #[case::clock_skew(OrderCheck::ClockSkew, ExchangeErrorType::ClockSkewTooLarge)]
+    #[case::symbol_status(OrderCheck::SymbolStatus, ExchangeErrorType::SymbolNotTrading)]
+    #[case::order_type(OrderCheck::OrderType, ExchangeErrorType::OrderTypeNotAllowed)]
+    #[case::rejection_pause(OrderCheck::RejectionPause, ExchangeErrorType::OrderPlacementPaused)]
+    #[case::order_reservation(OrderCheck::OrderReservation, ExchangeErrorType::InsufficientFunds)]
+    #[case::price_band(OrderCheck::PriceBand, ExchangeErrorType::InvalidOrder)]
+    #[case::order_book_freshness(
+        OrderCheck::OrderBookFreshness,
+        ExchangeErrorType::StaleMarketData
+    )]
+    #[case::market_data_health(OrderCheck::MarketDataHealth, ExchangeErrorType::StaleMarketData)]
+    #[case::open_orders_count(OrderCheck::OpenOrdersCount, ExchangeErrorType::OrderCountLimit)]
+    #[case::order_amount(OrderCheck::OrderAmount, ExchangeErrorType::AmountTooSmall { min_amount: amount!(10) })]
+    #[case::time_in_force(OrderCheck::TimeInForce, ExchangeErrorType::InvalidOrder)]
+    #[tokio::test]
+    async fn reject_order_by_failed_check_unless_disabled(
+        #[case] check: OrderCheck,
+        #[case] expected_error_type: ExchangeErrorType,
+    ) {
+        let (exchange, _) = get_test_exchange(false);
+        let mut order_header = order_header(&exchange);
+        assert!(exchange.run_order_checks(&order_header).is_ok());
+
+        fail_order_check(&exchange, check, &mut order_header);
+        let error = exchange
+            .run_order_checks(&order_header)
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, expected_error_type);
+
+        exchange.setup_disabled_order_checks(&[check]);
+        assert!(exchange.run_order_checks(&order_header).is_ok());
+    }
+
+    #[tokio::test]
+    async fn stop_checks_on_first_failure() {
+        let (exchange, _) = get_test_exchange(false);
+        let mut order_header = order_header(&exchange);
+        fail_order_check(&exchange, OrderCheck::SymbolStatus, &mut order_header);
+        fail_order_check(&exchange, OrderCheck::OpenOrdersCount, &mut order_header);
+
+        let error_type = |exchange: &Exchange| {
+            exchange
+                .run_order_checks(&order_header)
+                .expect_err("in test")
+                .downcast::<ExchangeError>()
+                .expect("in test")
+                .error_type
+        };
+        assert_eq!(error_type(&exchange), ExchangeErrorType::SymbolNotTrading);
+
+        exchange.setup_disabled_order_checks(&[OrderCheck::SymbolStatus]);
+        assert_eq!(error_type(&exchange), ExchangeErrorType::OrderCountLimit);
+    }
+
+    #[tokio::test]
+    async fn clamp_order_price_before_adding_to_pool() {
+        let (exchange, _) = get_test_exchange(false);
+        let order_header = order_header(&exchange);
+        exchange.setup_price_band(&PriceBandSettings {
+            default_max_deviation: Some(dec!(0.1)),
+            currency_pairs: vec![],
+            reference: PriceBandReference::MarkPrice,
+            action: PriceBandAction::Clamp,
+        });
+        let currency_pair = currency_pair();
+        let _ = exchange.mark_prices.insert(
+            currency_pair,
+            MarkPriceEvent {
+                exchange_account_id: exchange.exchange_account_id,
+                currency_pair,
+                mark_price: price!(100),
+                index_price: price!(100),
+                funding_rate: dec!(0),
+                next_funding_time: time_manager::now(),
+            },
+        );
+
+        // order is added to pool before creation as it's done by disposition executor
+        let order = exchange.add_initial_order(&order_header, time_manager::now());
+        assert_eq!(order.price(), price!(90));
+        assert_eq!(order.source_price(), Some(price!(90)));
+        assert!(exchange.run_order_checks(order.header()).is_ok());
+
+        // order which wasn't clamped before adding to pool is rejected
+        let not_clamped = exchange.orders.add_simple_initial(
+            &self::order_header(&exchange),
+            time_manager::now(),
+            None,
+        );
+        let error = exchange
+            .run_order_checks(not_clamped.header())
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::InvalidOrder);
+    }
+
+    #[tokio::test]
+    async fn fail_pre_registered_order_rejected_by_check() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        let mut order_header = order_header(&exchange);
+        fail_order_check(&exchange, OrderCheck::SymbolStatus, &mut order_header);
+
+        // order is added to pool before creation as it's done by disposition executor
+        let order = exchange.orders.add_simple_initial(
+            &order_header,
+            time_manager::now(),
+            exchange.exchange_client.get_initial_extension_data(),
+        );
+
+        let error = exchange
+            .create_order(&order_header, None, CancellationToken::default())
+            .await
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::SymbolNotTrading);
+
+        assert_eq!(order.status(), OrderStatus::FailedToCreate);
+        assert!(!exchange
+            .orders
+            .not_finished
+            .contains_key(&order.client_order_id()));
+
+        let event = events_receiver.try_recv().expect("in test");
+        assert!(matches!(
+            event,
+            ExchangeEvent::OrderEvent(OrderEvent { ref order, event_type: OrderEventType::CreateOrderFailed })
+                if order.client_order_id() == order_header.client_order_id
+        ));
+    }
+
+    #[tokio::test]
+    async fn reject_order_without_reservation_only_if_required() {
+        let (exchange, _) = get_test_exchange(false);
+        let order_header = |reservation_id| OrderHeader {
+            reservation_id,
+            ..order_header(&exchange)
+        };
+
+        assert!(exchange
+            .check_order_reservation(&order_header(None))
+            .is_ok());
+
+        exchange.setup_require_order_reservation(true);
+        assert!(exchange
+            .check_order_reservation(&order_header(None))
+            .is_err());
+        assert!(exchange
+            .check_order_reservation(&order_header(Some(ReservationId::generate())))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_order_priced_off_stale_order_book() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = currency_pair();
+        let order_header = order_header(&exchange);
+        let order_book_top = |last_update_time| OrderBookTop {
+            ask: None,
+            bid: None,
+            last_update_time,
+        };
+
+        assert!(exchange.check_order_book_freshness(&order_header).is_ok());
+
+        exchange.setup_order_book_freshness(&OrderBookFreshnessSettings {
+            default_max_age_ms: Some(1_000),
+            currency_pairs: vec![],
+        });
+        // order book wasn't received yet
+        assert!(exchange.check_order_book_freshness(&order_header).is_err());
+
+        exchange.order_book_top.insert(
+            currency_pair,
+            order_book_top(time_manager::now() - chrono::Duration::seconds(5)),
+        );
+        assert!(exchange.check_order_book_freshness(&order_header).is_err());
+
+        exchange
+            .order_book_top
+            .insert(currency_pair, order_book_top(time_manager::now()));
+        assert!(exchange.check_order_book_freshness(&order_header).is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_orders_only_for_currency_pair_with_broken_market_data() {
+        let (exchange, _) = get_test_exchange(false);
+        let broken_currency_pair = CurrencyPair::from_codes("eth".into(), "btc".into());
+        let healthy_currency_pair = CurrencyPair::from_codes("ltc".into(), "btc".into());
+        let order_header = |currency_pair| {
+            test_helper::order_header(
+                &exchange,
+                currency_pair,
+                OrderSide::Buy,
+                amount!(1),
+                UserOrder::limit(price!(0.1)),
+            )
+        };
+
+        exchange.on_subscription_failed(SubscriptionFailedEvent {
+            exchange_account_id: exchange.exchange_account_id,
+            stream: "ethbtc@depth".to_owned(),
+            currency_pair: Some(broken_currency_pair),
+            reason: "Invalid symbol".to_owned(),
+        });
+        assert!(exchange
+            .check_market_data_health(&order_header(broken_currency_pair))
+            .is_err());
+        assert!(exchange
+            .check_market_data_health(&order_header(healthy_currency_pair))
+            .is_ok());
+        assert_eq!(exchange.broken_market_data(), vec![broken_currency_pair]);
+
+        exchange.on_market_data_received(broken_currency_pair);
+        assert!(exchange
+            .check_market_data_health(&order_header(broken_currency_pair))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_order_exceeding_open_orders_limit() {
+        let (exchange, _) = get_test_exchange(false);
+
+        exchange.setup_max_open_orders_per_currency_pair(Some(2));
+        for _ in 0..2 {
+            let header = order_header(&exchange);
+            assert!(exchange.check_open_orders_count(&header).is_ok());
+            let _ = exchange
+                .orders
+                .add_simple_initial(&header, time_manager::now(), None);
+        }
+
+        let error = exchange
+            .check_open_orders_count(&order_header(&exchange))
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::OrderCountLimit);
+
+        exchange.setup_max_open_orders_per_currency_pair(None);
+        assert!(exchange
+            .check_open_orders_count(&order_header(&exchange))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn reject_order_amount_rounded_to_zero() {
+        let symbol = Arc::new(Symbol::new(
+            false,
+            "PHB".into(),
+            "PHB".into(),
+            "BTC".into(),
+            "BTC".into(),
+            None,
+            None,
+            Some(amount!(2)),
+            None,
+            None,
+            "PHB".into(),
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            Precision::ByTick { tick: dec!(1) },
+        ));
+        let (exchange, _) = get_test_exchange_with_symbol(symbol);
+        let order_header = |amount| {
+            test_helper::order_header(
+                &exchange,
+                currency_pair(),
+                OrderSide::Buy,
+                amount,
+                UserOrder::limit(price!(0.1)),
+            )
+        };
+
+        let error = exchange
+            .check_order_amount(&order_header(amount!(0.7)))
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(
+            error.error_type,
+            ExchangeErrorType::AmountTooSmall {
+                min_amount: amount!(2)
+            }
+        );
+
+        assert!(exchange
+            .check_order_amount(&order_header(amount!(1.7)))
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn clamp_order_price_to_price_band() {
+        let (exchange, _) = get_test_exchange(true);
+        let currency_pair = currency_pair();
+        let order_header = test_helper::order_header(
+            &exchange,
+            currency_pair,
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(110)),
+        );
+
+        exchange.setup_price_band(&PriceBandSettings {
+            default_max_deviation: Some(dec!(0.0333)),
+            currency_pairs: vec![],
+            reference: PriceBandReference::MarkPrice,
+            action: PriceBandAction::Clamp,
+        });
+        let price = |header: &OrderHeader| header.source_price;
+        assert_eq!(
+            price(
+                &exchange
+                    .clamp_by_price_band(&order_header)
+                    .expect("in test")
+            ),
+            Some(price!(110))
+        );
+
+        exchange.mark_prices.insert(
+            currency_pair,
+            MarkPriceEvent {
+                exchange_account_id: exchange.exchange_account_id,
+                currency_pair,
+                mark_price: price!(100),
+                index_price: price!(100),
+                funding_rate: dec!(0),
+                next_funding_time: time_manager::now(),
+            },
+        );
+        let clamped = exchange
+            .clamp_by_price_band(&order_header)
+            .expect("in test");
+        assert_eq!(price(&clamped), Some(price!(103.3)));
+        assert!(matches!(
+            clamped.options,
+            OrderOptions::User(UserOrder::Limit { price, .. }) if price == price!(103.3)
+        ));
+    }
+
+    #[tokio::test]
+    async fn reject_order_creation_until_warmup_completed() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        let currency_pair = currency_pair();
+
+        assert!(exchange.check_warmup(currency_pair).is_ok());
+
+        exchange.setup_warmup(&WarmupSettings {
+            min_trades: 1,
+            min_candles: 0,
+        });
+        assert!(exchange.check_warmup(currency_pair).is_err());
+
+        exchange.update_warmup(currency_pair, |warmup| {
+            warmup.on_order_book_synced(currency_pair)
+        });
+        exchange.update_warmup(currency_pair, |warmup| warmup.on_trades(currency_pair, 1));
+
+        assert!(exchange.check_warmup(currency_pair).is_ok());
+        match events_receiver.try_recv().expect("in test") {
+            ExchangeEvent::WarmupCompleted(event) => assert_eq!(event.currency_pair, currency_pair),
+            event => panic!("Unexpected event {event:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn reject_good_till_date_order_expired_before_submission() {
+        let (exchange, _) = get_test_exchange(false);
+        let order_header =
+            |time_in_force| order_header(&exchange).with_time_in_force(time_in_force);
+        let now = time_manager::now();
+
+        let error = exchange
+            .check_time_in_force(&order_header(TimeInForce::Gtd(now)))
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::InvalidOrder);
+
+        let expire_time = now + chrono::Duration::minutes(1);
+        assert!(exchange
+            .check_time_in_force(&order_header(TimeInForce::Gtd(expire_time)))
+            .is_ok());
+        assert!(exchange
+            .check_time_in_force(&order_header(TimeInForce::Gtc))
+            .is_ok());
+    }
+}
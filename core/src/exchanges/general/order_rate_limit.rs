@@ -0,0 +1,120 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::request_type::RequestType;
+use crate::exchanges::traits::ExchangeError;
+use crate::misc::time::time_manager;
+use crate::orders::order_rate_limiter::OrderRateLimiter;
+use crate::settings::OrderRateLimitSettings;
+use anyhow::{bail, Context, Result};
+use mmb_domain::events::{
+    ExchangeEvent, OrderRateLimitStatus, OrderRateLimitStatusEvent, RateLimitUsage,
+};
+use mmb_domain::market::ExchangeErrorType;
+use mmb_domain::order::snapshot::OrderHeader;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::send_expected::SendExpectedByRef;
+use std::sync::Arc;
+
+impl Exchange {
+    pub fn setup_order_rate_limiter(&self, settings: &OrderRateLimitSettings) {
+        *self.order_rate_limiter.lock() = Some(Arc::new(OrderRateLimiter::new(settings)));
+    }
+
+    /// Order count limits of account and their current usage accounted on exchange side
+    pub async fn get_order_rate_limit_status(
+        &self,
+        cancellation_token: CancellationToken,
+    ) -> Result<OrderRateLimitStatus> {
+        self.timeout_manager
+            .reserve_when_available(
+                self.exchange_account_id,
+                RequestType::GetOrderRateLimitStatus,
+                None,
+                cancellation_token,
+            )
+            .await;
+
+        self.exchange_client
+            .get_order_rate_limit_status()
+            .await
+            .with_context(|| {
+                format!(
+                    "Order rate limit status isn't supported by exchange {}",
+                    self.exchange_account_id
+                )
+            })?
+            .with_context(|| {
+                format!(
+                    "Failed to get order rate limit status on {}",
+                    self.exchange_account_id
+                )
+            })
+    }
+
+    /// Request order count limits status, so order creation is throttled by authoritative
+    /// figures, and publish it for statistics
+    pub async fn refresh_order_rate_limit_status(
+        self: Arc<Self>,
+        cancellation_token: CancellationToken,
+    ) {
+        let status = match self.get_order_rate_limit_status(cancellation_token).await {
+            Ok(status) => status,
+            Err(error) => {
+                log::warn!("{error:?}");
+                return;
+            }
+        };
+
+        if let Some(order_rate_limiter) = self.order_rate_limiter.lock().clone() {
+            order_rate_limiter.sync_status(&status, time_manager::now());
+        }
+
+        self.events_channel
+            .send_expected(ExchangeEvent::OrderRateLimitStatus(
+                OrderRateLimitStatusEvent {
+                    exchange_account_id: self.exchange_account_id,
+                    status,
+                },
+            ));
+    }
+
+    /// Apply order counts reported by exchange in response headers to order rate limiter
+    pub(crate) fn sync_order_rate_limit_usage(&self, usage: &RateLimitUsage) {
+        if let Some(order_rate_limiter) = self.order_rate_limiter.lock().clone() {
+            order_rate_limiter.sync_usage(usage, time_manager::now());
+        }
+    }
+
+    /// Count order sent to exchange in order rate limiter
+    pub(crate) fn register_order_rate_limit_usage(&self) {
+        if let Some(order_rate_limiter) = self.order_rate_limiter.lock().clone() {
+            order_rate_limiter.register_order(time_manager::now());
+        }
+    }
+
+    /// Order which would exceed order count limit of account is rejected locally,
+    /// so exchange doesn't ban API key for exceeding it
+    pub(crate) fn check_order_rate_limit(&self, order_header: &OrderHeader) -> Result<()> {
+        let order_rate_limiter = match self.order_rate_limiter.lock().clone() {
+            None => return Ok(()),
+            Some(order_rate_limiter) => order_rate_limiter,
+        };
+
+        let reached = match order_rate_limiter.check(time_manager::now()) {
+            None => return Ok(()),
+            Some(reached) => reached,
+        };
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::RateLimit,
+            format!(
+                "Order creation {} on {} is rejected because {} of {} orders per {:?} are already used",
+                order_header.client_order_id,
+                self.exchange_account_id,
+                reached.used,
+                reached.limit,
+                reached.interval,
+            ),
+            None,
+        ))
+    }
+}
@@ -0,0 +1,84 @@
+use crate::balance::position_cost::PositionCost;
+use crate::exchanges::general::exchange::Exchange;
+use crate::math::ConvertPercentToRate;
+use mmb_domain::exchanges::symbol::Symbol;
+use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::fill::OrderFill;
+use mmb_domain::order::snapshot::{OrderRole, OrderSide, Price};
+
+impl Exchange {
+    pub fn setup_breakeven_exit_order_role(&self, order_role: OrderRole) {
+        *self.breakeven_exit_order_role.lock() = order_role;
+    }
+
+    pub(super) fn update_position_cost(
+        &self,
+        symbol: &Symbol,
+        side: OrderSide,
+        order_fill: &OrderFill,
+    ) {
+        let price = order_fill.price();
+        let fee =
+            match order_fill.converted_commission_currency_code() == symbol.quote_currency_code {
+                true => order_fill.converted_commission_amount(),
+                false => order_fill.converted_commission_amount() * price,
+            };
+
+        self.position_costs
+            .entry(symbol.currency_pair())
+            .or_default()
+            .apply_fill(side, price, order_fill.amount(), fee);
+    }
+
+    /// Fee-inclusive cost of position of currency pair accumulated from fills since startup
+    pub fn position_cost(&self, currency_pair: CurrencyPair) -> Option<PositionCost> {
+        self.position_costs.get(&currency_pair).map(|x| *x)
+    }
+
+    /// Exit price at which position of currency pair is closed without loss including paid fees
+    /// and expected exit fees by commission of currency pair. Can be used as min take-profit level.
+    /// `None` if there is no open position
+    pub fn breakeven_price(&self, currency_pair: CurrencyPair) -> Option<Price> {
+        let position_cost = self.position_cost(currency_pair)?;
+        let exit_order_role = *self.breakeven_exit_order_role.lock();
+        let exit_fee_rate = self
+            .commission
+            .lock()
+            .get_commission(currency_pair, exit_order_role)
+            .fee
+            .percent_to_rate();
+
+        position_cost.breakeven_price(exit_fee_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use crate::settings::CurrencyPairFeesSettings;
+    use mmb_domain::{amount, price};
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn breakeven_price_by_commission_of_currency_pair() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = CurrencyPair::from_codes("phb".into(), "btc".into());
+        assert_eq!(exchange.breakeven_price(currency_pair), None);
+
+        exchange.setup_currency_pair_fees(&[CurrencyPairFeesSettings {
+            currency_pair,
+            maker: dec!(0),
+            taker: dec!(0.5),
+        }]);
+        let mut position_cost = PositionCost::default();
+        position_cost.apply_fill(OrderSide::Buy, price!(99), amount!(1), amount!(0.5));
+        let _ = exchange.position_costs.insert(currency_pair, position_cost);
+
+        // (99 + 0.5) / (1 - 0.005)
+        assert_eq!(exchange.breakeven_price(currency_pair), Some(price!(100)));
+
+        exchange.setup_breakeven_exit_order_role(OrderRole::Maker);
+        assert_eq!(exchange.breakeven_price(currency_pair), Some(price!(99.5)));
+    }
+}
@@ -0,0 +1,199 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::infrastructure::spawn_future_ok;
+use crate::misc::time::time_manager;
+use futures::future::join_all;
+use itertools::Itertools;
+use mmb_domain::events::{ExchangeEvent, ReconnectGracePeriodEvent, ReconnectGracePeriodStage};
+use mmb_domain::order::snapshot::OrderStatus;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::infrastructure::SpawnFutureFlags;
+use mmb_utils::nothing_to_do;
+use mmb_utils::send_expected::SendExpectedByRef;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::sleep;
+
+impl Exchange {
+    pub fn setup_reconnect_grace_period(&self, grace_period_ms: u64) {
+        *self.reconnect_grace_period.lock() = Some(Duration::from_millis(grace_period_ms));
+    }
+
+    pub fn is_in_reconnect_grace_period(&self) -> bool {
+        self.reconnect_grace_token.lock().is_some()
+    }
+
+    /// Cancellation of orders waits for end of grace period after reconnect, so orders which
+    /// were filled during disconnection aren't cancelled before their fills are reconciled
+    pub(crate) async fn wait_reconnect_grace_period(&self, cancellation_token: &CancellationToken) {
+        let grace_token = match self.reconnect_grace_token.lock().clone() {
+            None => return,
+            Some(grace_token) => grace_token,
+        };
+
+        log::info!(
+            "Order cancellation on {} waits for end of reconnect grace period",
+            self.exchange_account_id
+        );
+        tokio::select! {
+            _ = grace_token.when_cancelled() => nothing_to_do(),
+            _ = cancellation_token.when_cancelled() => nothing_to_do(),
+        }
+    }
+
+    pub(super) fn start_reconnect_grace_period(self: &Arc<Self>) {
+        let grace_period = match self.begin_reconnect_grace_period() {
+            None => return,
+            Some(grace_period) => grace_period,
+        };
+
+        let action = format!(
+            "Exchange account id {} reconnect grace period",
+            self.exchange_account_id
+        );
+        let self_weak = Arc::downgrade(self);
+        let future = async move {
+            if let Some(self_strong) = self_weak.upgrade() {
+                self_strong
+                    .complete_reconnect_grace_period(grace_period)
+                    .await;
+            }
+        };
+        spawn_future_ok(&action, SpawnFutureFlags::STOP_BY_TOKEN, future);
+    }
+
+    /// Returns `None` if grace period isn't configured or is already in progress
+    fn begin_reconnect_grace_period(&self) -> Option<Duration> {
+        let grace_period = (*self.reconnect_grace_period.lock())?;
+
+        let id = self.exchange_account_id;
+        {
+            let mut grace_token = self.reconnect_grace_token.lock();
+            if grace_token.is_some() {
+                log::info!("Exchange account id {id} is already in reconnect grace period");
+                return None;
+            }
+            *grace_token = Some(CancellationToken::new());
+        }
+
+        log::info!("Exchange account id {id} reconnect grace period of {grace_period:?} started");
+        self.send_reconnect_grace_period_event(ReconnectGracePeriodStage::Started);
+
+        Some(grace_period)
+    }
+
+    /// Grace period lasts until fills of open orders are reconciled by REST,
+    /// but not less than configured period
+    async fn complete_reconnect_grace_period(&self, grace_period: Duration) {
+        let stop_token = self.lifetime_manager.stop_token();
+        let _ = tokio::join!(
+            sleep(grace_period),
+            self.reconcile_fills_after_reconnect(stop_token)
+        );
+
+        self.end_reconnect_grace_period();
+    }
+
+    async fn reconcile_fills_after_reconnect(&self, cancellation_token: CancellationToken) {
+        let orders = self
+            .orders
+            .not_finished
+            .iter()
+            .map(|x| x.value().clone())
+            .filter(|x| x.status() != OrderStatus::Creating)
+            .collect_vec();
+
+        join_all(orders.iter().map(|order| async {
+            self.check_order_fills(order, false, None, cancellation_token.clone())
+                .await
+                .unwrap_or_else(|err| {
+                    log::error!(
+                        "Failed to reconcile fills of order {} on {} after reconnect: {err:?}",
+                        order.client_order_id(),
+                        self.exchange_account_id
+                    )
+                })
+        }))
+        .await;
+
+        log::info!(
+            "Fills of {} open orders on {} are reconciled after reconnect",
+            orders.len(),
+            self.exchange_account_id
+        );
+    }
+
+    fn end_reconnect_grace_period(&self) {
+        if let Some(grace_token) = self.reconnect_grace_token.lock().take() {
+            grace_token.cancel();
+        }
+
+        log::info!(
+            "Exchange account id {} reconnect grace period ended",
+            self.exchange_account_id
+        );
+        self.send_reconnect_grace_period_event(ReconnectGracePeriodStage::Ended);
+    }
+
+    fn send_reconnect_grace_period_event(&self, stage: ReconnectGracePeriodStage) {
+        self.events_channel
+            .send_expected(ExchangeEvent::ReconnectGracePeriod(
+                ReconnectGracePeriodEvent {
+                    exchange_account_id: self.exchange_account_id,
+                    stage,
+                    event_creation_time: time_manager::now(),
+                },
+            ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use mmb_domain::events::{ExchangeEvent, ReconnectGracePeriodStage};
+    use mmb_utils::cancellation_token::CancellationToken;
+    use std::time::Duration;
+    use tokio::time::timeout;
+
+    #[tokio::test]
+    async fn cancellation_waits_for_end_of_grace_period() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        exchange.setup_reconnect_grace_period(50);
+
+        let grace_period = exchange.begin_reconnect_grace_period().expect("in test");
+        assert!(exchange.is_in_reconnect_grace_period());
+        assert_eq!(exchange.begin_reconnect_grace_period(), None);
+
+        let cancellation_token = CancellationToken::new();
+        timeout(Duration::from_secs(5), async {
+            tokio::join!(
+                exchange.complete_reconnect_grace_period(grace_period),
+                exchange.wait_reconnect_grace_period(&cancellation_token),
+            )
+        })
+        .await
+        .expect("in test");
+        assert!(!exchange.is_in_reconnect_grace_period());
+
+        let stages: Vec<_> = std::iter::from_fn(|| events_receiver.try_recv().ok())
+            .filter_map(|x| match x {
+                ExchangeEvent::ReconnectGracePeriod(event) => Some(event.stage),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            stages,
+            vec![
+                ReconnectGracePeriodStage::Started,
+                ReconnectGracePeriodStage::Ended
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn no_grace_period_if_not_configured() {
+        let (exchange, _) = get_test_exchange(false);
+
+        assert_eq!(exchange.begin_reconnect_grace_period(), None);
+        assert!(!exchange.is_in_reconnect_grace_period());
+    }
+}
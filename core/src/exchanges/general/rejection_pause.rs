@@ -0,0 +1,216 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::traits::ExchangeError;
+use crate::misc::time::time_manager;
+use crate::settings::RejectionPauseSettings;
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use mmb_domain::events::{ExchangeEvent, OrderPlacementPausedEvent};
+use mmb_domain::market::{CurrencyPair, ExchangeErrorType};
+use mmb_domain::order::snapshot::OrderHeader;
+use mmb_utils::send_expected::SendExpectedByRef;
+
+impl Exchange {
+    pub fn setup_rejection_pause(&self, settings: &RejectionPauseSettings) {
+        *self.rejection_pause_settings.lock() = Some(settings.clone());
+    }
+
+    /// Order placement of currency pair is paused when its orders are rejected more times
+    /// in a row than allowed
+    pub(super) fn register_order_rejection(&self, currency_pair: CurrencyPair) {
+        let max_consecutive_rejections = match &*self.rejection_pause_settings.lock() {
+            None => return,
+            Some(settings) => settings.max_consecutive_rejections,
+        };
+
+        let consecutive_rejections = {
+            let mut rejections = self
+                .consecutive_rejections
+                .entry(currency_pair)
+                .or_insert(0);
+            *rejections += 1;
+            *rejections
+        };
+
+        if consecutive_rejections <= max_consecutive_rejections
+            || self.paused_placements.contains_key(&currency_pair)
+        {
+            return;
+        }
+
+        let now = time_manager::now();
+        let _ = self.paused_placements.insert(currency_pair, now);
+        log::error!(
+            "Orders of {currency_pair} on {} were rejected {consecutive_rejections} times in a row. Order placement for it is paused",
+            self.exchange_account_id
+        );
+
+        self.events_channel
+            .send_expected(ExchangeEvent::OrderPlacementPaused(
+                OrderPlacementPausedEvent {
+                    exchange_account_id: self.exchange_account_id,
+                    currency_pair,
+                    consecutive_rejections,
+                    event_creation_time: now,
+                },
+            ));
+    }
+
+    pub(super) fn register_order_placement(&self, currency_pair: CurrencyPair) {
+        let _ = self.consecutive_rejections.remove(&currency_pair);
+    }
+
+    /// Returns false if order placement for currency pair wasn't paused
+    pub fn resume_order_placement(&self, currency_pair: CurrencyPair) -> bool {
+        let _ = self.consecutive_rejections.remove(&currency_pair);
+        if self.paused_placements.remove(&currency_pair).is_none() {
+            return false;
+        }
+
+        log::info!(
+            "Order placement for {currency_pair} on {} is resumed",
+            self.exchange_account_id
+        );
+        true
+    }
+
+    /// Currency pairs which order placement is paused for because of consecutive rejections
+    pub fn paused_placements(&self) -> Vec<CurrencyPair> {
+        self.paused_placements
+            .iter()
+            .map(|x| *x.key())
+            .sorted_by_key(|x| x.to_string())
+            .collect()
+    }
+
+    /// Paused order placement is resumed by the first order after cooldown
+    pub(crate) fn check_rejection_pause(&self, order_header: &OrderHeader) -> Result<()> {
+        let currency_pair = order_header.currency_pair;
+        let paused_at = match self.paused_placements.get(&currency_pair) {
+            None => return Ok(()),
+            Some(paused_at) => *paused_at,
+        };
+
+        let cooldown_secs = self
+            .rejection_pause_settings
+            .lock()
+            .as_ref()
+            .and_then(|x| x.cooldown_secs);
+        if let Some(cooldown_secs) = cooldown_secs {
+            let cooldown = chrono::Duration::seconds(cooldown_secs as i64);
+            if time_manager::now() - paused_at >= cooldown {
+                let _ = self.resume_order_placement(currency_pair);
+                return Ok(());
+            }
+        }
+
+        bail!(ExchangeError::new(
+            ExchangeErrorType::OrderPlacementPaused,
+            format!(
+                "Order creation {} on {} is rejected because order placement for {currency_pair} is paused after consecutive rejections",
+                order_header.client_order_id, self.exchange_account_id
+            ),
+            None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use mmb_domain::order::snapshot::{ClientOrderId, OrderSide, UserOrder};
+
+    use mmb_domain::{amount, price};
+
+    fn currency_pair() -> CurrencyPair {
+        CurrencyPair::from_codes("phb".into(), "btc".into())
+    }
+
+    fn order_header(exchange: &Exchange) -> OrderHeader {
+        OrderHeader::with_user_order(
+            ClientOrderId::unique_id(),
+            exchange.exchange_account_id,
+            currency_pair(),
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+            None,
+            None,
+            "test".to_owned(),
+        )
+    }
+
+    fn setup_rejection_pause(exchange: &Exchange, cooldown_secs: Option<u64>) {
+        exchange.setup_rejection_pause(&RejectionPauseSettings {
+            max_consecutive_rejections: 2,
+            cooldown_secs,
+        });
+    }
+
+    #[tokio::test]
+    async fn pause_placement_when_rejections_exceed_max() {
+        let (exchange, mut events_receiver) = get_test_exchange(false);
+        setup_rejection_pause(&exchange, None);
+        let order_header = order_header(&exchange);
+
+        exchange.register_order_rejection(currency_pair());
+        exchange.register_order_rejection(currency_pair());
+        assert!(exchange.check_rejection_pause(&order_header).is_ok());
+
+        exchange.register_order_rejection(currency_pair());
+        let error = exchange
+            .check_rejection_pause(&order_header)
+            .expect_err("in test")
+            .downcast::<ExchangeError>()
+            .expect("in test");
+        assert_eq!(error.error_type, ExchangeErrorType::OrderPlacementPaused);
+        assert_eq!(exchange.paused_placements(), vec![currency_pair()]);
+
+        let paused_event = std::iter::from_fn(|| events_receiver.try_recv().ok())
+            .find_map(|x| match x {
+                ExchangeEvent::OrderPlacementPaused(event) => Some(event),
+                _ => None,
+            })
+            .expect("in test");
+        assert_eq!(paused_event.currency_pair, currency_pair());
+        assert_eq!(paused_event.consecutive_rejections, 3);
+
+        assert!(exchange.resume_order_placement(currency_pair()));
+        assert!(exchange.check_rejection_pause(&order_header).is_ok());
+        assert!(!exchange.resume_order_placement(currency_pair()));
+    }
+
+    #[tokio::test]
+    async fn reset_rejections_by_successful_placement() {
+        let (exchange, _events_receiver) = get_test_exchange(false);
+        setup_rejection_pause(&exchange, None);
+
+        exchange.register_order_rejection(currency_pair());
+        exchange.register_order_rejection(currency_pair());
+        exchange.register_order_placement(currency_pair());
+        exchange.register_order_rejection(currency_pair());
+        exchange.register_order_rejection(currency_pair());
+
+        assert!(exchange.paused_placements().is_empty());
+    }
+
+    #[tokio::test]
+    async fn resume_placement_after_cooldown() {
+        let (exchange, _events_receiver) = get_test_exchange(false);
+        setup_rejection_pause(&exchange, Some(60));
+        let order_header = order_header(&exchange);
+
+        let _ = exchange.paused_placements.insert(
+            currency_pair(),
+            time_manager::now() - chrono::Duration::seconds(30),
+        );
+        assert!(exchange.check_rejection_pause(&order_header).is_err());
+
+        let _ = exchange.paused_placements.insert(
+            currency_pair(),
+            time_manager::now() - chrono::Duration::seconds(61),
+        );
+        assert!(exchange.check_rejection_pause(&order_header).is_ok());
+        assert!(exchange.paused_placements().is_empty());
+    }
+}
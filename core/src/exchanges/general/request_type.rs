@@ -22,4 +22,14 @@ pub enum RequestType {
     GetProfileId,
     GetMyTrades,
     SetLeverage,
+    GetLeverageBrackets,
+    SetMarginMode,
+    GetOrderRateLimitStatus,
+    GetServerTime,
+    GetOrderHistory,
+    GetAssetNetworks,
+    ConvertDust,
+    /// Request accounted on exchange side but not reserved locally,
+    /// e.g. made by another application with the same API key
+    Untracked,
 }
@@ -0,0 +1,165 @@
+use crate::exchanges::clock_skew_monitor::ClockSkew;
+use crate::exchanges::general::exchange::Exchange;
+use itertools::Itertools;
+use mmb_domain::events::SystemStatus;
+use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+use mmb_domain::order::snapshot::{
+    Amount, ClientOrderId, ExchangeOrderId, OrderSide, OrderStatus, OrderType, Price,
+};
+use mmb_utils::DateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenOrderState {
+    pub client_order_id: ClientOrderId,
+    pub exchange_order_id: Option<ExchangeOrderId>,
+    pub currency_pair: CurrencyPair,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    pub status: OrderStatus,
+    pub price: Price,
+    pub amount: Amount,
+    pub filled_amount: Amount,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderBookFreshnessState {
+    pub currency_pair: CurrencyPair,
+    pub last_update_time: DateTime,
+    /// Time since the last update of order book top at the moment of snapshot
+    pub age_ms: i64,
+}
+
+/// State of exchange account assembled from local state only, so it's always available
+/// even if exchange isn't reachable
+#[derive(Debug, Clone, Serialize)]
+pub struct ExchangeStateSnapshot {
+    pub exchange_account_id: ExchangeAccountId,
+    pub system_status: SystemStatus,
+    /// Reasons of active blocks of trading, e.g. websocket disconnection or max drawdown pause
+    pub block_reasons: Vec<String>,
+    pub failed_subscriptions: Vec<String>,
+    pub broken_market_data: Vec<CurrencyPair>,
+    /// Currency pairs which order placement is paused for after consecutive rejections
+    pub paused_placements: Vec<CurrencyPair>,
+    pub clock_skew: Option<ClockSkew>,
+    pub open_orders: Vec<OpenOrderState>,
+    pub order_books: Vec<OrderBookFreshnessState>,
+}
+
+impl Exchange {
+    pub fn state_snapshot(&self, now: DateTime) -> ExchangeStateSnapshot {
+        let block_reasons = self
+            .exchange_blocker
+            .upgrade()
+            .map(|x| x.block_reasons(self.exchange_account_id))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|x| x.to_string())
+            .sorted()
+            .collect();
+
+        let open_orders = self
+            .orders
+            .not_finished
+            .iter()
+            .map(|x| {
+                let order = x.value();
+                OpenOrderState {
+                    client_order_id: order.client_order_id(),
+                    exchange_order_id: order.exchange_order_id(),
+                    currency_pair: order.currency_pair(),
+                    side: order.side(),
+                    order_type: order.order_type(),
+                    status: order.status(),
+                    price: order.price(),
+                    amount: order.amount(),
+                    filled_amount: order.filled_amount(),
+                }
+            })
+            .sorted_by_key(|x| x.client_order_id.to_string())
+            .collect();
+
+        let order_books = self
+            .order_book_top
+            .iter()
+            .map(|x| OrderBookFreshnessState {
+                currency_pair: *x.key(),
+                last_update_time: x.last_update_time,
+                age_ms: (now - x.last_update_time).num_milliseconds(),
+            })
+            .sorted_by_key(|x| x.currency_pair.to_string())
+            .collect();
+
+        ExchangeStateSnapshot {
+            exchange_account_id: self.exchange_account_id,
+            system_status: self.system_status(),
+            block_reasons,
+            failed_subscriptions: self.failed_subscriptions(),
+            broken_market_data: self.broken_market_data(),
+            paused_placements: self.paused_placements(),
+            clock_skew: self.clock_skew(),
+            open_orders,
+            order_books,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::exchanges::general::exchange::OrderBookTop;
+    use crate::exchanges::general::test_helper::get_test_exchange;
+    use crate::misc::time::time_manager;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::order::snapshot::{
+        ClientOrderId, OrderHeader, OrderSide, OrderStatus, UserOrder,
+    };
+
+    use mmb_domain::{amount, price};
+
+    #[tokio::test]
+    async fn snapshot_of_local_exchange_state() {
+        let (exchange, _) = get_test_exchange(false);
+        let currency_pair = CurrencyPair::from_codes("phb".into(), "btc".into());
+        let order_header = OrderHeader::with_user_order(
+            ClientOrderId::unique_id(),
+            exchange.exchange_account_id,
+            currency_pair,
+            OrderSide::Sell,
+            amount!(2),
+            UserOrder::limit(price!(0.3)),
+            None,
+            None,
+            "test".to_owned(),
+        );
+        let now = time_manager::now();
+        let _ = exchange.orders.add_simple_initial(&order_header, now, None);
+        let _ = exchange.order_book_top.insert(
+            currency_pair,
+            OrderBookTop {
+                ask: None,
+                bid: None,
+                last_update_time: now - chrono::Duration::milliseconds(1_500),
+            },
+        );
+        exchange.mark_market_data_broken(currency_pair, "Invalid symbol");
+
+        let snapshot = exchange.state_snapshot(now);
+
+        assert_eq!(snapshot.open_orders.len(), 1);
+        let open_order = &snapshot.open_orders[0];
+        assert_eq!(open_order.client_order_id, order_header.client_order_id);
+        assert_eq!(open_order.side, OrderSide::Sell);
+        assert_eq!(open_order.status, OrderStatus::Creating);
+        assert_eq!(open_order.price, price!(0.3));
+        assert_eq!(open_order.amount, amount!(2));
+        assert_eq!(open_order.filled_amount, amount!(0));
+
+        assert_eq!(snapshot.order_books.len(), 1);
+        assert_eq!(snapshot.order_books[0].age_ms, 1_500);
+        assert_eq!(snapshot.broken_market_data, vec![currency_pair]);
+        assert!(snapshot.block_reasons.is_empty());
+        assert!(snapshot.paused_placements.is_empty());
+        assert_eq!(snapshot.clock_skew, None);
+    }
+}
@@ -0,0 +1,342 @@
+//! Handoff of resting orders of a single strategy to a new strategy instance, e.g. for upgrade
+//! of strategy without downtime. Orders of strategy are recognized by its order tag.
+//!
+//! Protocol:
+//! 1. Order management of the old strategy instance is stopped, so it doesn't create, replace
+//!    or cancel orders anymore. Resting orders are left on exchange.
+//! 2. The old instance calls `Exchange::hand_off_strategy_orders` with order tag of strategy.
+//!    Handed off orders are never cancelled by the old instance anymore, even on shutdown.
+//! 3. Returned `StrategyHandoff` is serialized (e.g. to JSON) and passed to the new instance.
+//! 4. The new instance calls `Exchange::adopt_strategy_orders` with deserialized handoff before
+//!    its strategy starts. Like `StartupPolicy::Adopt` but scoped to the tag, orders with the tag
+//!    which are still opened on exchange are loaded into orders pool, keeping original headers
+//!    of handed off orders. Handed off orders which aren't opened anymore (e.g. filled between
+//!    handoff and adoption) are reported, so strategy can reconcile them.
+
+use crate::exchanges::general::exchange::Exchange;
+use crate::misc::time::time_manager;
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use mmb_domain::market::ExchangeAccountId;
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::{ClientOrderId, OrderInfo, OrderSnapshot};
+use mmb_domain::order::tag::decode_tag_from_client_order_id;
+use mmb_utils::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// Resting orders of strategy handed off to a new strategy instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyHandoff {
+    pub exchange_account_id: ExchangeAccountId,
+    pub tag: String,
+    pub orders: Vec<OrderSnapshot>,
+    pub handoff_time: DateTime,
+}
+
+#[derive(Debug, Default)]
+pub struct AdoptedStrategyOrders {
+    /// Orders with the tag which are opened on exchange and managed by this instance now
+    pub adopted: Vec<OrderRef>,
+    /// Handed off orders which aren't opened on exchange anymore
+    pub finished: Vec<ClientOrderId>,
+}
+
+impl Exchange {
+    /// Stops managing not finished orders with the tag and returns them for adoption by
+    /// a new strategy instance
+    pub fn hand_off_strategy_orders(&self, tag: &str) -> StrategyHandoff {
+        let orders = self
+            .orders
+            .not_finished
+            .iter()
+            .filter(|x| has_tag(x.header().tag.as_deref(), &x.client_order_id(), tag))
+            .map(|x| x.deep_clone())
+            .sorted_by_key(|x| x.header.client_order_id.to_string())
+            .collect_vec();
+
+        for order in &orders {
+            match &order.props.exchange_order_id {
+                Some(exchange_order_id) => {
+                    let _ = self
+                        .unmanaged_orders
+                        .lock()
+                        .insert(exchange_order_id.clone());
+                }
+                None => log::warn!(
+                    "Order {} with tag '{tag}' on {} is handed off before its creation is acknowledged",
+                    order.header.client_order_id,
+                    self.exchange_account_id
+                ),
+            }
+        }
+
+        log::info!(
+            "{} orders with tag '{tag}' on {} are handed off",
+            orders.len(),
+            self.exchange_account_id
+        );
+
+        StrategyHandoff {
+            exchange_account_id: self.exchange_account_id,
+            tag: tag.to_owned(),
+            orders,
+            handoff_time: time_manager::now(),
+        }
+    }
+
+    /// Adopts orders of handoff which are still opened on exchange
+    pub async fn adopt_strategy_orders(
+        &self,
+        handoff: &StrategyHandoff,
+    ) -> Result<AdoptedStrategyOrders> {
+        if handoff.exchange_account_id != self.exchange_account_id {
+            bail!(
+                "Orders handed off on {} can't be adopted on {}",
+                handoff.exchange_account_id,
+                self.exchange_account_id
+            );
+        }
+
+        // orders handed off within the same process are unmanaged, so they have to be released
+        // to be returned as opened
+        {
+            let mut unmanaged_orders = self.unmanaged_orders.lock();
+            for exchange_order_id in handoff
+                .orders
+                .iter()
+                .filter_map(|x| x.props.exchange_order_id.as_ref())
+            {
+                let _ = unmanaged_orders.remove(exchange_order_id);
+            }
+        }
+
+        let open_orders = self.get_open_orders(false).await?;
+        Ok(self.adopt_handed_off_orders(handoff, &open_orders))
+    }
+
+    fn adopt_handed_off_orders(
+        &self,
+        handoff: &StrategyHandoff,
+        open_orders: &[OrderInfo],
+    ) -> AdoptedStrategyOrders {
+        let tag = handoff.tag.as_str();
+        let tagged_open_orders = open_orders
+            .iter()
+            .filter(|x| has_tag(x.tag.as_deref(), &x.client_order_id, tag))
+            .collect_vec();
+
+        let mut adopted = Vec::with_capacity(tagged_open_orders.len());
+        let mut missing_in_handoff = Vec::new();
+        for order_info in &tagged_open_orders {
+            let exchange_order_id = &order_info.exchange_order_id;
+
+            // order is already in pool if it's handed off within the same process
+            let existing_order = self
+                .orders
+                .cache_by_exchange_id
+                .get(exchange_order_id)
+                .map(|x| x.clone());
+            if let Some(order) = existing_order {
+                adopted.push(order);
+                continue;
+            }
+
+            let snapshot = handoff.orders.iter().find(|x| {
+                x.props.exchange_order_id.as_ref() == Some(exchange_order_id)
+                    || x.header.client_order_id == order_info.client_order_id
+            });
+            match snapshot {
+                Some(snapshot) => {
+                    let mut snapshot = snapshot.clone();
+                    snapshot.props.exchange_order_id = Some(exchange_order_id.clone());
+                    adopted.push(self.orders.add_snapshot_initial(&snapshot));
+                }
+                None => missing_in_handoff.push((*order_info).clone()),
+            }
+        }
+
+        if !missing_in_handoff.is_empty() {
+            log::warn!(
+                "Orders {} with tag '{tag}' are opened on {} but missing in handoff",
+                missing_in_handoff
+                    .iter()
+                    .map(|x| x.exchange_order_id.as_str())
+                    .join(", "),
+                self.exchange_account_id
+            );
+            self.add_missing_open_orders(&missing_in_handoff);
+            adopted.extend(missing_in_handoff.iter().filter_map(|x| {
+                self.orders
+                    .cache_by_exchange_id
+                    .get(&x.exchange_order_id)
+                    .map(|x| x.clone())
+            }));
+        }
+
+        let finished = handoff
+            .orders
+            .iter()
+            .map(|x| &x.header.client_order_id)
+            .filter(|client_order_id| {
+                !adopted
+                    .iter()
+                    .any(|x| x.client_order_id() == **client_order_id)
+            })
+            .cloned()
+            .collect_vec();
+
+        log::info!(
+            "{} orders with tag '{tag}' are adopted on {}. Handed off orders which aren't opened anymore: {finished:?}",
+            adopted.len(),
+            self.exchange_account_id
+        );
+
+        AdoptedStrategyOrders { adopted, finished }
+    }
+}
+
+fn has_tag(order_tag: Option<&str>, client_order_id: &ClientOrderId, tag: &str) -> bool {
+    order_tag == Some(tag) || decode_tag_from_client_order_id(client_order_id) == Some(tag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::{
+        get_test_exchange, get_test_exchange_with_symbol_and_id,
+    };
+    use crate::infrastructure::init_lifetime_manager;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::order::snapshot::{
+        ExchangeOrderId, OrderHeader, OrderSide, OrderStatus, UserOrder,
+    };
+
+    use mmb_domain::{amount, price};
+    use std::sync::Arc;
+
+    fn currency_pair() -> CurrencyPair {
+        CurrencyPair::from_codes("phb".into(), "btc".into())
+    }
+
+    /// Account id of default test exchange can't be parsed back, so handoff with it
+    /// can't be deserialized
+    fn test_exchange() -> Arc<Exchange> {
+        let (exchange, _) = get_test_exchange(false);
+        let symbol = exchange.get_symbol(currency_pair()).expect("in test");
+        get_test_exchange_with_symbol_and_id(symbol, ExchangeAccountId::new("Binance", 0)).0
+    }
+
+    fn create_order(exchange: &Exchange, exchange_order_id: &str, tag: &str) -> OrderRef {
+        let order_header = OrderHeader::with_user_order(
+            ClientOrderId::unique_id(),
+            exchange.exchange_account_id,
+            currency_pair(),
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+            None,
+            None,
+            "test_strategy".to_owned(),
+        )
+        .with_tag(tag.to_owned());
+
+        let now = time_manager::now();
+        let order = exchange.orders.add_simple_initial(&order_header, now, None);
+        let exchange_order_id = ExchangeOrderId::from(exchange_order_id);
+        order.fn_mut(|x| {
+            x.props.exchange_order_id = Some(exchange_order_id.clone());
+            x.set_status(OrderStatus::Created, now);
+        });
+        exchange
+            .orders
+            .add_exchange_order_id(exchange_order_id, &order);
+        order
+    }
+
+    fn open_order_info(order: &OrderRef) -> OrderInfo {
+        let mut order_info = OrderInfo::new(
+            order.currency_pair(),
+            order.exchange_order_id().expect("in test"),
+            order.client_order_id(),
+            order.side(),
+            OrderStatus::Created,
+            order.price(),
+            order.amount(),
+            price!(0),
+            amount!(0),
+            None,
+            None,
+            None,
+        );
+        order_info.tag = order.header().tag.clone();
+        order_info
+    }
+
+    #[tokio::test]
+    async fn adopt_handed_off_orders_with_tag() {
+        let _ = init_lifetime_manager();
+
+        let old_exchange = test_exchange();
+        let resting = create_order(&old_exchange, "1", "mm");
+        let filled_after_handoff = create_order(&old_exchange, "2", "mm");
+        let other_strategy_order = create_order(&old_exchange, "3", "arb");
+
+        let handoff = old_exchange.hand_off_strategy_orders("mm");
+        assert_eq!(handoff.orders.len(), 2);
+        assert!(old_exchange.has_unmanaged_orders());
+
+        let serialized = serde_json::to_string(&handoff).expect("in test");
+        let handoff: StrategyHandoff = serde_json::from_str(&serialized).expect("in test");
+
+        let new_exchange = test_exchange();
+        let open_orders = [
+            open_order_info(&resting),
+            open_order_info(&other_strategy_order),
+        ];
+        let result = new_exchange.adopt_handed_off_orders(&handoff, &open_orders);
+
+        assert_eq!(result.adopted.len(), 1);
+        let adopted = &result.adopted[0];
+        assert_eq!(adopted.client_order_id(), resting.client_order_id());
+        assert_eq!(adopted.exchange_order_id(), resting.exchange_order_id());
+        assert_eq!(adopted.header().strategy_name, "test_strategy");
+        assert_eq!(adopted.status(), OrderStatus::Created);
+        assert!(new_exchange
+            .orders
+            .cache_by_exchange_id
+            .contains_key(&ExchangeOrderId::from("1")));
+        assert!(!new_exchange
+            .orders
+            .cache_by_exchange_id
+            .contains_key(&ExchangeOrderId::from("3")));
+
+        assert_eq!(
+            result.finished,
+            vec![filled_after_handoff.client_order_id()]
+        );
+    }
+
+    #[tokio::test]
+    async fn adopt_tagged_order_missing_in_handoff() {
+        let _ = init_lifetime_manager();
+
+        let old_exchange = test_exchange();
+        let order = create_order(&old_exchange, "1", "mm");
+        let new_exchange = test_exchange();
+
+        let handoff = StrategyHandoff {
+            exchange_account_id: new_exchange.exchange_account_id,
+            tag: "mm".to_owned(),
+            orders: Vec::new(),
+            handoff_time: time_manager::now(),
+        };
+        let result = new_exchange.adopt_handed_off_orders(&handoff, &[open_order_info(&order)]);
+
+        assert_eq!(result.adopted.len(), 1);
+        assert_eq!(
+            result.adopted[0].exchange_order_id(),
+            order.exchange_order_id()
+        );
+        assert!(result.finished.is_empty());
+    }
+}
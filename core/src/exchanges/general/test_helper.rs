@@ -34,7 +34,9 @@ use mmb_domain::market::{
     CurrencyCode, CurrencyId, CurrencyPair, ExchangeAccountId, SpecificCurrencyPair,
 };
 use mmb_domain::order::pool::{OrderRef, OrdersPool};
-use mmb_domain::order::snapshot::{Amount, ExchangeOrderId, OrderOptions, Price};
+use mmb_domain::order::snapshot::{
+    Amount, ExchangeOrderId, OrderHeader, OrderOptions, Price, UserOrder,
+};
 use mmb_domain::order::snapshot::{ClientOrderId, OrderInfo, OrderRole, OrderSide, OrderSnapshot};
 use mmb_domain::position::{ActivePosition, ClosedPosition};
 use rust_decimal_macros::dec;
@@ -43,6 +45,7 @@ use url::Url;
 
 use crate::database::events::recorder::EventRecorder;
 use crate::exchanges::exchange_blocker::ExchangeBlocker;
+use crate::exchanges::general::capabilities::ExchangeCapabilities;
 use crate::exchanges::general::exchange::RequestResult;
 use crate::exchanges::general::order::cancel::CancelOrderResult;
 use crate::exchanges::general::order::create::CreateOrderResult;
@@ -120,6 +123,16 @@ impl ExchangeClient for TestClient {
     async fn get_server_time(&self) -> Option<Result<i64>> {
         unimplemented!("doesn't need in UT")
     }
+
+    fn capabilities(&self) -> ExchangeCapabilities {
+        ExchangeCapabilities {
+            market_orders: true,
+            maker_only_orders: true,
+            stop_loss_orders: true,
+            trailing_stop_orders: true,
+            ..Default::default()
+        }
+    }
 }
 
 #[async_trait]
@@ -286,6 +299,7 @@ pub(crate) fn get_test_exchange_with_symbol_and_id(
             RestFillsFeatures::default(),
             OrderFeatures {
                 supports_get_order_info_by_client_order_id: true,
+                max_client_order_id_len: Some(36),
                 ..OrderFeatures::default()
             },
             OrderTradeOption::default(),
@@ -348,6 +362,27 @@ pub(crate) fn create_order_ref(
     order_ref.clone()
 }
 
+/// Header of user order of test exchange without reservation
+pub(crate) fn order_header(
+    exchange: &Exchange,
+    currency_pair: CurrencyPair,
+    side: OrderSide,
+    amount: Amount,
+    user_order: UserOrder,
+) -> OrderHeader {
+    OrderHeader::with_user_order(
+        ClientOrderId::unique_id(),
+        exchange.exchange_account_id,
+        currency_pair,
+        side,
+        amount,
+        user_order,
+        None,
+        None,
+        "test".to_owned(),
+    )
+}
+
 pub(crate) fn try_add_snapshot_by_exchange_id(exchange: &Exchange, order_ref: &OrderRef) {
     if let Some(exchange_order_id) = order_ref.exchange_order_id() {
         let _ = exchange
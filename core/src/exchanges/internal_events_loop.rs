@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use mmb_utils::cancellation_token::CancellationToken;
@@ -9,6 +10,7 @@ use parking_lot::Mutex;
 use tokio::sync::{broadcast, oneshot};
 
 use crate::exchanges::general::exchange::{Exchange, OrderBookTop, PriceLevel};
+use crate::lifecycle::event_loop_watchdog::EventLoopWatchdog;
 use crate::lifecycle::trading_engine::Service;
 use crate::order_book::local_snapshot_service::LocalSnapshotsService;
 use mmb_domain::events::ExchangeEvent;
@@ -32,9 +34,10 @@ impl InternalEventsLoop {
         self: Arc<Self>,
         mut events_receiver: broadcast::Receiver<ExchangeEvent>,
         exchanges_map: HashMap<ExchangeAccountId, Arc<Exchange>>,
+        local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
+        event_loop_watchdog: Option<Arc<EventLoopWatchdog>>,
         cancellation_token: CancellationToken,
     ) -> Result<()> {
-        let mut local_snapshots_service = LocalSnapshotsService::default();
         let (work_finished_sender, receiver) = oneshot::channel();
         *self.work_finished_receiver.lock() = Some(receiver);
 
@@ -47,11 +50,12 @@ impl InternalEventsLoop {
                 }
             };
 
+            let iteration_start = Instant::now();
             match event {
                 ExchangeEvent::OrderBookEvent(ref order_book_event) => {
                     update_order_book_top_for_exchange(
                         order_book_event,
-                        &mut local_snapshots_service,
+                        &mut local_snapshots_service.lock(),
                         &exchanges_map,
                     )
                 }
@@ -82,6 +86,62 @@ impl InternalEventsLoop {
                 ExchangeEvent::BalanceUpdate(_) => {}
                 ExchangeEvent::LiquidationPrice(_) => {}
                 ExchangeEvent::Trades(_) => {}
+                ExchangeEvent::AggTrade(_) => {}
+                ExchangeEvent::SystemStatus(_) => {}
+                ExchangeEvent::CandleClosed(_) => {}
+                ExchangeEvent::WarmupCompleted(_) => {}
+                ExchangeEvent::Flatten(_) => {}
+                ExchangeEvent::MaxDrawdownReached(_) => {}
+                ExchangeEvent::ClockSkew(_) => {}
+                ExchangeEvent::RateLimitUsage(event) => {
+                    if let Some(exchange) = exchanges_map.get(&event.exchange_account_id) {
+                        exchange.sync_order_rate_limit_usage(&event.usage);
+                    }
+                }
+                ExchangeEvent::OrderRateLimitStatus(_) => {}
+                ExchangeEvent::PacingChanged(_) => {}
+                ExchangeEvent::OrderAckLatency(_) => {}
+                ExchangeEvent::NearCross(_) => {}
+                ExchangeEvent::FundingPayment(_) => {}
+                ExchangeEvent::RetriesExhausted(_) => {}
+                ExchangeEvent::OrderPlacementPaused(_) => {}
+                ExchangeEvent::Scheduled(_) => {}
+                ExchangeEvent::BalanceChanged(_) => {}
+                ExchangeEvent::ReconnectGracePeriod(_) => {}
+                ExchangeEvent::SubscriptionFailed(event) => {
+                    if let Some(exchange) = exchanges_map.get(&event.exchange_account_id) {
+                        exchange.on_subscription_failed(event);
+                    }
+                }
+                ExchangeEvent::RestUnreachable(event) => {
+                    if let Some(exchange) = exchanges_map.get(&event.exchange_account_id) {
+                        exchange.on_rest_unreachable(event.failures);
+                    }
+                }
+                ExchangeEvent::Liquidation(_) => {}
+                ExchangeEvent::Disconnected(disconnected) => {
+                    let exchange_account_id = disconnected.exchange_account_id;
+                    local_snapshots_service
+                        .lock()
+                        .discard_snapshots(exchange_account_id.exchange_id);
+                    if let Some(exchange) = exchanges_map.get(&exchange_account_id) {
+                        exchange.order_book_top.clear();
+                        // subscriptions are requested again after reconnect
+                        exchange.failed_subscriptions.clear();
+                    }
+                }
+                ExchangeEvent::MarkPrice(mark_price) => {
+                    if let Some(exchange) = exchanges_map.get(&mark_price.exchange_account_id) {
+                        exchange
+                            .mark_prices
+                            .insert(mark_price.currency_pair, mark_price);
+                    }
+                }
+            }
+
+            if let Some(event_loop_watchdog) = &event_loop_watchdog {
+                event_loop_watchdog
+                    .record_iteration("InternalEventsLoop", iteration_start.elapsed());
             }
         }
     }
@@ -103,15 +163,19 @@ fn update_order_book_top_for_exchange(
             bid: snapshot
                 .get_top_bid()
                 .map(|(price, amount)| PriceLevel { price, amount }),
+            last_update_time: snapshot.last_update_time,
         };
 
-        exchanges_map
-            .get(&market_account_id.exchange_account_id)
-            .map(|exchange| {
-                exchange
-                    .order_book_top
-                    .insert(market_account_id.currency_pair, order_book_top)
+        if let Some(exchange) = exchanges_map.get(&market_account_id.exchange_account_id) {
+            let currency_pair = market_account_id.currency_pair;
+            let _ = exchange
+                .order_book_top
+                .insert(currency_pair, order_book_top);
+            exchange.on_market_data_received(currency_pair);
+            exchange.update_warmup(currency_pair, |warmup| {
+                warmup.on_order_book_synced(currency_pair)
             });
+        }
     }
 }
 
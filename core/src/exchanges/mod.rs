@@ -1,9 +1,14 @@
+pub mod asset_networks_cache;
 pub mod block_reasons;
+pub mod clock_skew_monitor;
 pub mod common;
 pub mod exchange_blocker;
 pub mod general;
 pub mod hosts;
 pub(crate) mod internal_events_loop;
+pub mod nonce;
 pub mod rest_client;
+pub mod rest_failure_monitor;
 pub mod timeouts;
 pub mod traits;
+pub mod warmup;
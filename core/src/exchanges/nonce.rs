@@ -0,0 +1,54 @@
+use mmb_utils::time::get_current_milliseconds;
+use parking_lot::Mutex;
+
+/// Nonce for signing private requests: current time in milliseconds, but never less than
+/// the previous nonce + 1. Protects from clock going backward while process is running
+#[derive(Default)]
+pub struct NonceGenerator {
+    last_nonce: Mutex<u64>,
+}
+
+impl NonceGenerator {
+    /// Returns nonce which is strictly greater than all nonces returned before
+    pub fn next(&self) -> u64 {
+        let mut last_nonce = self.last_nonce.lock();
+
+        let nonce = current_milliseconds().max(*last_nonce + 1);
+        *last_nonce = nonce;
+
+        nonce
+    }
+}
+
+fn current_milliseconds() -> u64 {
+    get_current_milliseconds()
+        .try_into()
+        .expect("Current time in milliseconds should be positive")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nonce_strictly_increasing() {
+        let generator = NonceGenerator::default();
+
+        let mut last = generator.next();
+        for _ in 0..1000 {
+            let nonce = generator.next();
+            assert!(nonce > last, "nonce {nonce} should be greater than {last}");
+            last = nonce;
+        }
+    }
+
+    #[test]
+    fn nonce_not_less_than_current_time() {
+        let generator = NonceGenerator::default();
+
+        let before = current_milliseconds();
+        let nonce = generator.next();
+
+        assert!(nonce >= before);
+    }
+}
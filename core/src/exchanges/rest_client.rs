@@ -1,18 +1,24 @@
+use crate::exchanges::rest_failure_monitor::RestFailureMonitor;
 use crate::exchanges::traits::ExchangeError;
+use crate::telemetry::RestSpan;
 use anyhow::Result;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use hyper::client::HttpConnector;
 use hyper::http::request::Builder;
 use hyper::http::uri::{Parts, PathAndQuery};
-use hyper::{Body, Client, Error, Method, Request, Response, StatusCode, Uri};
+use hyper::{Body, Client, Error, HeaderMap, Method, Request, Response, StatusCode, Uri};
 use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
 use log::log;
+use mmb_domain::events::{RateLimitCounter, RateLimitKind, RateLimitUsage};
 use mmb_domain::market::*;
+use mmb_utils::correlation_id::{CorrelationId, OptionCorrelationId};
 use mmb_utils::infrastructure::WithExpect;
 use std::borrow::Cow;
 use std::convert::TryInto;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter, Write};
+use std::future::Future;
+use std::time::Instant;
 use uuid::Uuid;
 
 pub type QueryKey = &'static str;
@@ -83,8 +89,9 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static> ErrorHandlerData<ErrHandl
 
     pub(super) fn request_log(&self, action_name: &str, request_id: &Uuid) {
         log::trace!(
-            "{action_name} request {request_id} on exchange_account_id {}",
-            self.exchange_account_id
+            "{action_name} request {request_id} on exchange_account_id {}, correlation_id: {}",
+            self.exchange_account_id,
+            current_correlation_id()
         );
     }
 
@@ -96,8 +103,9 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static> ErrorHandlerData<ErrHandl
         request_id: &Uuid,
     ) {
         log::trace!(
-            "{fn_name} response on {}: {response:?}, | params {log_args}, request_id: {request_id}",
-            self.exchange_account_id
+            "{fn_name} response on {}: {response:?}, | params {log_args}, request_id: {request_id}, correlation_id: {}",
+            self.exchange_account_id,
+            current_correlation_id()
         );
     }
 
@@ -140,8 +148,8 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static> ErrorHandlerData<ErrHandl
         let mut msg = String::with_capacity(error.message.len() + extra_data_len);
         write!(
             msg,
-            "Response has an error {:?}, on exchange_account_id {}, request_id: {request_id}: {error:?}, params: {log_args}",
-            error.error_type, self.exchange_account_id,
+            "Response has an error {:?}, on exchange_account_id {}, request_id: {request_id}, correlation_id: {}: {error:?}, params: {log_args}",
+            error.error_type, self.exchange_account_id, current_correlation_id(),
         )
         .expect("Writing rest error");
 
@@ -158,6 +166,10 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static> ErrorHandlerData<ErrHandl
     }
 }
 
+fn current_correlation_id() -> OptionCorrelationId {
+    OptionCorrelationId(CorrelationId::current())
+}
+
 enum CheckContent {
     Empty,
     Usable,
@@ -201,6 +213,28 @@ impl Debug for RequestType {
     }
 }
 
+pub type RateLimitUsageCb = Box<dyn Fn(RateLimitUsage) + Send + Sync>;
+
+/// Prefix of response header with server-side rate limit usage followed by interval,
+/// e.g. `X-MBX-USED-WEIGHT-` for `X-MBX-USED-WEIGHT-1M`
+pub type RateLimitHeader = (&'static str, RateLimitKind);
+
+struct RateLimitUsageHandler {
+    headers: &'static [RateLimitHeader],
+    callback: RateLimitUsageCb,
+}
+
+/// Called with count of consecutive failed requests when exchange became unreachable by REST
+pub type RestUnreachableCb = Box<dyn Fn(usize) + Send + Sync>;
+
+/// Called with time of receiving response to every REST request
+pub type RestLatencyCb = Box<dyn Fn(std::time::Duration) + Send + Sync>;
+
+struct RestFailureHandler {
+    monitor: RestFailureMonitor,
+    callback: RestUnreachableCb,
+}
+
 pub struct RestClient<
     ErrHandler: ErrorHandler + Send + Sync + 'static,
     SpecHeaders: RestHeaders + Send + Sync + 'static,
@@ -208,6 +242,9 @@ pub struct RestClient<
     client: Client<HttpsConnector<HttpConnector>>,
     error_handler: ErrorHandlerData<ErrHandler>,
     headers: SpecHeaders,
+    rate_limit_usage_handler: Option<RateLimitUsageHandler>,
+    failure_handler: Option<RestFailureHandler>,
+    latency_callback: Option<RestLatencyCb>,
 }
 
 const KEEP_ALIVE: &str = "keep-alive";
@@ -222,7 +259,45 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
             client: create_client(),
             error_handler,
             headers,
+            rate_limit_usage_handler: None,
+            failure_handler: None,
+            latency_callback: None,
+        }
+    }
+
+    /// Pass rate limits usage from headers of every response to `callback`
+    pub fn with_rate_limit_usage(
+        mut self,
+        headers: &'static [RateLimitHeader],
+        callback: RateLimitUsageCb,
+    ) -> Self {
+        self.rate_limit_usage_handler = Some(RateLimitUsageHandler { headers, callback });
+        self
+    }
+
+    /// Call `callback` when requests repeatedly fail without response from exchange
+    pub fn with_failure_monitor(
+        mut self,
+        monitor: RestFailureMonitor,
+        callback: RestUnreachableCb,
+    ) -> Self {
+        self.failure_handler = Some(RestFailureHandler { monitor, callback });
+        self
+    }
+
+    /// Pass time of receiving response to every request to `callback`
+    pub fn with_latency_observer(mut self, callback: RestLatencyCb) -> Self {
+        self.latency_callback = Some(callback);
+        self
+    }
+
+    async fn send(&self, req: Request<Body>) -> ResponseType {
+        let started_at = Instant::now();
+        let response = self.client.request(req).await;
+        if let (Ok(_), Some(callback)) = (&response, &self.latency_callback) {
+            callback(started_at.elapsed());
         }
+        response
     }
 
     pub async fn get(
@@ -246,15 +321,17 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
                 format!("Error during creation of http {request_type} request {request_id}")
             });
 
-        let response = self.client.request(req).await;
-
-        self.handle_response(
-            response,
-            request_type.as_str(),
-            action_name,
-            log_args,
-            request_id,
-        )
+        self.traced(request_type, action_name, async {
+            let response = self.send(req).await;
+            self.handle_response(
+                response,
+                request_type.as_str(),
+                action_name,
+                log_args,
+                request_id,
+            )
+            .await
+        })
         .await
     }
 
@@ -279,15 +356,17 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
                 format!("Error during creation of http {request_type} request {request_id}")
             });
 
-        let response = self.client.request(req).await;
-
-        self.handle_response(
-            response,
-            request_type.as_str(),
-            action_name,
-            log_args,
-            request_id,
-        )
+        self.traced(request_type, action_name, async {
+            let response = self.send(req).await;
+            self.handle_response(
+                response,
+                request_type.as_str(),
+                action_name,
+                log_args,
+                request_id,
+            )
+            .await
+        })
         .await
     }
 
@@ -316,15 +395,17 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
                 format!("Error during creation of http {request_type} request {request_id}")
             });
 
-        let response = self.client.request(req).await;
-
-        self.handle_response(
-            response,
-            request_type.as_str(),
-            action_name,
-            log_args,
-            request_id,
-        )
+        self.traced(request_type, action_name, async {
+            let response = self.send(req).await;
+            self.handle_response(
+                response,
+                request_type.as_str(),
+                action_name,
+                log_args,
+                request_id,
+            )
+            .await
+        })
         .await
     }
 
@@ -349,16 +430,35 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
                 format!("Error during creation of http {request_type} request {request_id}")
             });
 
-        let response = self.client.request(req).await;
+        self.traced(request_type, action_name, async {
+            let response = self.send(req).await;
+            self.handle_response(
+                response,
+                request_type.as_str(),
+                action_name,
+                log_args,
+                request_id,
+            )
+            .await
+        })
+        .await
+    }
 
-        self.handle_response(
-            response,
+    /// Handle request within span of REST request, which is ended with its result
+    async fn traced(
+        &self,
+        request_type: RequestType,
+        action_name: &'static str,
+        f: impl Future<Output = Result<RestResponse, ExchangeError>>,
+    ) -> Result<RestResponse, ExchangeError> {
+        let span = RestSpan::start(
+            self.error_handler.exchange_account_id,
             request_type.as_str(),
             action_name,
-            log_args,
-            request_id,
-        )
-        .await
+        );
+        let result = f.await;
+        span.end(&result);
+        result
     }
 
     async fn handle_response(
@@ -369,15 +469,31 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
         log_args: String,
         request_id: Uuid,
     ) -> Result<RestResponse, ExchangeError> {
-        let response = response.with_expect(|| {
-            format!("Unable to send {rest_action} request, request_id: {request_id}")
-        });
+        let response = match response {
+            Ok(response) => response,
+            Err(err) => return Err(self.connection_failure(rest_action, request_id, err)),
+        };
         let status = response.status();
-        let request_bytes = hyper::body::to_bytes(response.into_body())
-            .await
-            .with_expect(|| {
-                format!("Unable to convert response body to bytes, request_id: {request_id}")
-            });
+        if let Some(handler) = &self.rate_limit_usage_handler {
+            let usage = parse_rate_limit_usage(response.headers(), handler.headers);
+            if !usage.is_empty() {
+                (handler.callback)(usage);
+            }
+        }
+
+        let request_bytes = match hyper::body::to_bytes(response.into_body()).await {
+            Ok(request_bytes) => request_bytes,
+            Err(err) => return Err(self.connection_failure(rest_action, request_id, err)),
+        };
+
+        match status {
+            StatusCode::BAD_GATEWAY | StatusCode::GATEWAY_TIMEOUT => self.register_failure(),
+            _ => {
+                if let Some(handler) = &self.failure_handler {
+                    handler.monitor.on_success();
+                }
+            }
+        }
 
         let content = std::str::from_utf8(&request_bytes)
             .with_expect(|| format!("Unable to convert response content from utf8: {request_bytes:?}, request_id: {request_id}"))
@@ -391,6 +507,77 @@ impl<ErrHandler: ErrorHandler + Send + Sync + 'static, SpecHeaders: RestHeaders
 
         Ok(request_outcome)
     }
+
+    fn connection_failure(
+        &self,
+        rest_action: &'static str,
+        request_id: Uuid,
+        error: Error,
+    ) -> ExchangeError {
+        let message = format!(
+            "Unable to send {rest_action} request on {}, request_id: {request_id}: {error}",
+            self.error_handler.exchange_account_id
+        );
+        log::warn!("{message}");
+        self.register_failure();
+
+        ExchangeError::new(ExchangeErrorType::SendError, message, None)
+    }
+
+    fn register_failure(&self) {
+        if let Some(handler) = &self.failure_handler {
+            if handler.monitor.on_failure(Instant::now()) {
+                (handler.callback)(handler.monitor.max_failures());
+            }
+        }
+    }
+}
+
+/// Parse server-side rate limits usage from response headers with specified prefixes.
+/// Headers with unknown interval format are skipped
+pub fn parse_rate_limit_usage(
+    headers: &HeaderMap,
+    rate_limit_headers: &[RateLimitHeader],
+) -> RateLimitUsage {
+    let counters = headers
+        .iter()
+        .filter_map(|(name, value)| {
+            let name = name.as_str();
+            let (interval, kind) = rate_limit_headers.iter().find_map(|(prefix, kind)| {
+                name.get(..prefix.len())
+                    .filter(|x| x.eq_ignore_ascii_case(prefix))
+                    .map(|_| (&name[prefix.len()..], *kind))
+            })?;
+
+            Some(RateLimitCounter {
+                kind,
+                interval: parse_rate_limit_interval(interval)?,
+                used: value.to_str().ok()?.trim().parse().ok()?,
+            })
+        })
+        .collect();
+
+    RateLimitUsage { counters }
+}
+
+/// Interval in format `<number><unit>`, e.g. `1M` or `10S`
+fn parse_rate_limit_interval(interval: &str) -> Option<std::time::Duration> {
+    let unit_index = interval.len().checked_sub(1)?;
+    if !interval.is_char_boundary(unit_index) {
+        return None;
+    }
+
+    let (number, unit) = interval.split_at(unit_index);
+    let number: u64 = number.parse().ok()?;
+    let secs_in_unit = match unit {
+        "s" | "S" => 1,
+        "m" | "M" => 60,
+        "h" | "H" => 60 * 60,
+        "d" | "D" => 24 * 60 * 60,
+        _ => return None,
+    };
+
+    Some(std::time::Duration::from_secs(number * secs_in_unit))
 }
 
 fn create_client() -> Client<HttpsConnector<HttpConnector>> {
@@ -539,6 +726,48 @@ mod tests {
         assert_eq!(path_and_query, Uri::from_static("https://host.com/path"))
     }
 
+    #[test]
+    pub fn parse_rate_limit_usage_from_headers() {
+        const RATE_LIMIT_HEADERS: &[RateLimitHeader] = &[
+            ("X-MBX-USED-WEIGHT-", RateLimitKind::RequestWeight),
+            ("X-MBX-ORDER-COUNT-", RateLimitKind::OrdersCount),
+        ];
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", "120".parse().expect("in test"));
+        headers.insert("x-mbx-order-count-10s", "3".parse().expect("in test"));
+        headers.insert(
+            "x-mbx-order-count-1d",
+            "not a number".parse().expect("in test"),
+        );
+        headers.insert("x-mbx-order-count-1w", "5".parse().expect("in test"));
+        headers.insert("content-type", "application/json".parse().expect("in test"));
+
+        let usage = parse_rate_limit_usage(&headers, RATE_LIMIT_HEADERS);
+
+        assert_eq!(usage.counters.len(), 2);
+        assert_eq!(
+            usage.get(
+                RateLimitKind::RequestWeight,
+                std::time::Duration::from_secs(60)
+            ),
+            Some(&RateLimitCounter {
+                kind: RateLimitKind::RequestWeight,
+                interval: std::time::Duration::from_secs(60),
+                used: 120,
+            })
+        );
+        assert_eq!(
+            usage
+                .get(
+                    RateLimitKind::OrdersCount,
+                    std::time::Duration::from_secs(10)
+                )
+                .map(|x| x.used),
+            Some(3)
+        );
+    }
+
     #[test]
     pub fn build_uri_from_empty_builder() {
         let host = "host.com";
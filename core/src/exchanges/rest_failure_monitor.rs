@@ -0,0 +1,101 @@
+use crate::settings::RestFailuresReconnectSettings;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Consecutive REST requests which got no response from exchange (connection errors, gateway
+/// timeouts). Websocket connection can still look alive while REST path is broken, so exchange
+/// is considered unreachable after `max_failures` of them within `window`
+pub struct RestFailureMonitor {
+    max_failures: usize,
+    window: Duration,
+    failures: Mutex<VecDeque<Instant>>,
+}
+
+impl RestFailureMonitor {
+    pub fn new(settings: &RestFailuresReconnectSettings) -> Self {
+        Self {
+            max_failures: settings.max_failures.max(1),
+            window: Duration::from_secs(settings.window_secs),
+            failures: Default::default(),
+        }
+    }
+
+    pub fn max_failures(&self) -> usize {
+        self.max_failures
+    }
+
+    pub fn on_success(&self) {
+        self.failures.lock().clear();
+    }
+
+    /// Register failed request and return `true` when failures reached the limit.
+    /// Counting starts over after that, so the next trigger needs `max_failures` new failures
+    pub fn on_failure(&self, now: Instant) -> bool {
+        let mut failures = self.failures.lock();
+        while failures
+            .front()
+            .map_or(false, |x| now.saturating_duration_since(*x) > self.window)
+        {
+            let _ = failures.pop_front();
+        }
+
+        failures.push_back(now);
+        if failures.len() < self.max_failures {
+            return false;
+        }
+
+        failures.clear();
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor() -> RestFailureMonitor {
+        RestFailureMonitor::new(&RestFailuresReconnectSettings {
+            max_failures: 3,
+            window_secs: 10,
+        })
+    }
+
+    #[test]
+    fn trigger_after_consecutive_failures() {
+        let monitor = monitor();
+        let now = Instant::now();
+
+        assert!(!monitor.on_failure(now));
+        assert!(!monitor.on_failure(now));
+        assert!(monitor.on_failure(now));
+
+        // counting starts over after trigger
+        assert!(!monitor.on_failure(now));
+    }
+
+    #[test]
+    fn success_resets_failures() {
+        let monitor = monitor();
+        let now = Instant::now();
+
+        assert!(!monitor.on_failure(now));
+        assert!(!monitor.on_failure(now));
+        monitor.on_success();
+
+        assert!(!monitor.on_failure(now));
+    }
+
+    #[test]
+    fn failures_outside_window_are_not_counted() {
+        let monitor = monitor();
+        let now = Instant::now();
+
+        assert!(!monitor.on_failure(now));
+        assert!(!monitor.on_failure(now + Duration::from_secs(1)));
+
+        assert!(!monitor.on_failure(now + Duration::from_secs(11)));
+        assert!(!monitor.on_failure(now + Duration::from_secs(12)));
+        assert!(monitor.on_failure(now + Duration::from_secs(13)));
+    }
+}
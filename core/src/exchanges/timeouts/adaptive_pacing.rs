@@ -0,0 +1,114 @@
+use crate::settings::AdaptivePacingSettings;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::time::Duration;
+
+/// Weight of the latest latency in smoothed latency
+const LATENCY_SMOOTHING: Decimal = dec!(0.2);
+
+/// Pacing multiplier derived from smoothed REST latency. Requests budget of period is divided by
+/// multiplier, so requests are sent slower while latency is above threshold, and pacing eases back
+/// as latency recovers
+pub struct AdaptivePacing {
+    latency_threshold_ms: Decimal,
+    max_multiplier: Decimal,
+    smoothed_latency_ms: Option<Decimal>,
+    multiplier: Decimal,
+}
+
+impl AdaptivePacing {
+    pub fn new(settings: &AdaptivePacingSettings) -> Self {
+        Self {
+            latency_threshold_ms: Decimal::from(settings.latency_threshold_ms.max(1)),
+            max_multiplier: Decimal::from(settings.max_multiplier.max(1)),
+            smoothed_latency_ms: None,
+            multiplier: Decimal::ONE,
+        }
+    }
+
+    pub fn multiplier(&self) -> Decimal {
+        self.multiplier
+    }
+
+    /// Register latency of request. Returns new pacing multiplier if it was changed
+    pub fn on_latency(&mut self, latency: Duration) -> Option<Decimal> {
+        let latency_ms = Decimal::from(latency.as_millis() as u64);
+        let smoothed_latency_ms = match self.smoothed_latency_ms {
+            None => latency_ms,
+            Some(smoothed) => smoothed + (latency_ms - smoothed) * LATENCY_SMOOTHING,
+        };
+        self.smoothed_latency_ms = Some(smoothed_latency_ms);
+
+        // rounding prevents changing of requests budget on every request
+        let multiplier = (smoothed_latency_ms / self.latency_threshold_ms)
+            .clamp(Decimal::ONE, self.max_multiplier)
+            .round_dp(1);
+        if multiplier == self.multiplier {
+            return None;
+        }
+
+        self.multiplier = multiplier;
+        Some(multiplier)
+    }
+}
+
+/// Requests budget of period slowed down by pacing multiplier. At least one request is allowed
+pub fn paced_requests_per_period(requests_per_period: usize, multiplier: Decimal) -> usize {
+    if multiplier <= Decimal::ONE {
+        return requests_per_period;
+    }
+
+    let paced = (Decimal::from(requests_per_period) / multiplier).floor();
+    paced.to_usize().unwrap_or(requests_per_period).max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn adaptive_pacing() -> AdaptivePacing {
+        AdaptivePacing::new(&AdaptivePacingSettings {
+            latency_threshold_ms: 100,
+            max_multiplier: 4,
+        })
+    }
+
+    #[test]
+    fn pacing_is_slowed_by_high_latency_and_eased_back() {
+        let mut pacing = adaptive_pacing();
+
+        assert_eq!(pacing.on_latency(Duration::from_millis(50)), None);
+        assert_eq!(pacing.multiplier(), dec!(1));
+
+        assert_eq!(
+            pacing.on_latency(Duration::from_millis(1050)),
+            Some(dec!(2.5))
+        );
+        assert_eq!(
+            pacing.on_latency(Duration::from_millis(5000)),
+            Some(dec!(4))
+        );
+
+        for _ in 0..30 {
+            let _ = pacing.on_latency(Duration::from_millis(50));
+        }
+        assert_eq!(pacing.multiplier(), dec!(1));
+    }
+
+    #[rstest]
+    #[case(1200, dec!(1), 1200)]
+    #[case(1200, dec!(2.5), 480)]
+    #[case(1, dec!(4), 1)]
+    fn paced_requests(
+        #[case] requests_per_period: usize,
+        #[case] multiplier: Decimal,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(
+            paced_requests_per_period(requests_per_period, multiplier),
+            expected
+        );
+    }
+}
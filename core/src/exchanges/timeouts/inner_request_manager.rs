@@ -13,7 +13,10 @@ use mmb_utils::DateTime;
 use std::collections::HashMap;
 
 pub(super) struct InnerRequestsTimeoutManager {
+    /// Requests budget of period slowed down by adaptive pacing
     pub(super) requests_per_period: usize,
+    /// Requests budget of period allowed by exchange
+    pub(super) max_requests_per_period: usize,
     pub(super) period_duration: Duration,
     pub(super) exchange_account_id: ExchangeAccountId,
     pub(super) requests: Vec<Request>,
@@ -106,6 +109,28 @@ impl InnerRequestsTimeoutManager {
         request
     }
 
+    /// Requests made outside of this manager are added after requests with the same start
+    /// time, so tracked requests keep their order
+    pub(super) fn add_untracked_requests(&mut self, count: usize, current_time: DateTime) {
+        let request_index = self
+            .requests
+            .partition_point(|r| r.allowed_start_time <= current_time);
+        let untracked_requests =
+            (0..count).map(|_| Request::new(RequestType::Untracked, current_time, None));
+        let _ = self
+            .requests
+            .splice(request_index..request_index, untracked_requests);
+
+        let last_request_start_time = self
+            .requests
+            .last()
+            .expect("requests can't be empty after adding")
+            .allowed_start_time;
+
+        self.handle_all_decreasing_triggers();
+        self.handle_all_increasing_triggers(last_request_start_time);
+    }
+
     pub(super) fn handle_all_decreasing_triggers(&mut self) {
         let available_requests_count = self.get_all_available_requests_count();
 
@@ -234,6 +259,11 @@ impl InnerRequestsTimeoutManager {
         )
     }
 
+    pub(super) fn get_used_requests_count_at_present(&self, current_time: DateTime) -> usize {
+        self.get_reserved_requests_count_at_present(current_time)
+            .requests_count
+    }
+
     pub(super) fn get_all_available_requests_count(&self) -> usize {
         self.requests_per_period.saturating_sub(self.requests.len())
     }
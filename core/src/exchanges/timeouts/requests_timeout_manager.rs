@@ -7,17 +7,21 @@ use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::infrastructure::{FutureOutcome, SpawnFutureFlags};
 use mmb_utils::{DateTime, OPERATION_CANCELED_MSG};
 use parking_lot::Mutex;
+use rust_decimal::Decimal;
 use tokio::task::JoinHandle;
 use tokio::time::timeout;
 use uuid::Uuid;
 
 use super::{
+    adaptive_pacing::{paced_requests_per_period, AdaptivePacing},
     inner_request_manager::InnerRequestsTimeoutManager,
     more_or_equals_available_requests_count_trigger_scheduler::MoreOrEqualsAvailableRequestsCountTriggerScheduler,
-    pre_reserved_group::PreReservedGroup, request::Request,
+    pre_reserved_group::PreReservedGroup,
+    request::Request,
     triggers::every_requests_count_change_trigger::EveryRequestsCountChangeTrigger,
     triggers::less_or_equals_requests_count_trigger::LessOrEqualsRequestsCountTrigger,
 };
+use crate::settings::AdaptivePacingSettings;
 use crate::{exchanges::general::request_type::RequestType, infrastructure::spawn_future};
 use mmb_domain::market::ExchangeAccountId;
 use mmb_utils::time::ToStdExpected;
@@ -39,6 +43,7 @@ impl Display for RequestGroupId {
 
 pub struct RequestsTimeoutManager {
     inner: Mutex<InnerRequestsTimeoutManager>,
+    adaptive_pacing: Mutex<Option<AdaptivePacing>>,
 }
 
 impl RequestsTimeoutManager {
@@ -50,6 +55,7 @@ impl RequestsTimeoutManager {
     ) -> Arc<Self> {
         let inner = InnerRequestsTimeoutManager {
             requests_per_period,
+            max_requests_per_period: requests_per_period,
             period_duration,
             exchange_account_id,
             requests: Default::default(),
@@ -65,6 +71,7 @@ impl RequestsTimeoutManager {
 
         Arc::new(Self {
             inner: Mutex::new(inner),
+            adaptive_pacing: Mutex::new(None),
         })
     }
 
@@ -382,6 +389,69 @@ impl RequestsTimeoutManager {
     pub fn get_period_duration(&self) -> std::time::Duration {
         self.inner.lock().get_period_duration().to_std_expected()
     }
+
+    /// Replace requests budget, e.g. with limits received from exchange
+    pub fn set_requests_per_period(&self, requests_per_period: usize, period_duration: Duration) {
+        let multiplier = self.pacing_multiplier();
+        let mut inner = self.inner.lock();
+        inner.max_requests_per_period = requests_per_period;
+        inner.requests_per_period = paced_requests_per_period(requests_per_period, multiplier);
+        inner.period_duration = period_duration;
+    }
+
+    pub fn setup_adaptive_pacing(&self, settings: &AdaptivePacingSettings) {
+        *self.adaptive_pacing.lock() = Some(AdaptivePacing::new(settings));
+    }
+
+    /// Current multiplier which requests budget of period is divided by. It's 1 if adaptive
+    /// pacing isn't configured
+    pub fn pacing_multiplier(&self) -> Decimal {
+        self.adaptive_pacing
+            .lock()
+            .as_ref()
+            .map_or(Decimal::ONE, |x| x.multiplier())
+    }
+
+    /// Slow down or ease back requests pacing by observed REST latency.
+    /// Returns new pacing multiplier if it was changed
+    pub fn observe_latency(&self, latency: std::time::Duration) -> Option<Decimal> {
+        let multiplier = self.adaptive_pacing.lock().as_mut()?.on_latency(latency)?;
+
+        let mut inner = self.inner.lock();
+        inner.requests_per_period =
+            paced_requests_per_period(inner.max_requests_per_period, multiplier);
+        log::info!(
+            "Requests pacing multiplier of {} changed to {multiplier} by latency {latency:?}, requests per period: {}",
+            inner.exchange_account_id,
+            inner.requests_per_period
+        );
+
+        Some(multiplier)
+    }
+
+    /// Add requests which were accounted on exchange side but are missed locally.
+    /// Local requests aren't removed because some of them can be still in flight
+    pub fn sync_used_requests(&self, used_requests_count: usize, current_time: DateTime) {
+        let mut inner = self.inner.lock();
+
+        let current_time = inner.get_non_decreasing_time(current_time);
+        inner.remove_outdated_requests(current_time);
+
+        let local_requests_count = inner.get_used_requests_count_at_present(current_time);
+        let used_requests_count = used_requests_count.min(inner.requests_per_period);
+        if used_requests_count <= local_requests_count {
+            return;
+        }
+
+        log::info!(
+            "Server-side usage {used_requests_count} of requests budget of {} is more than local {local_requests_count}",
+            inner.exchange_account_id
+        );
+
+        inner.add_untracked_requests(used_requests_count - local_requests_count, current_time);
+
+        inner.last_time = Some(current_time);
+    }
 }
 
 #[cfg(test)]
@@ -2205,6 +2275,80 @@ mod test {
         }
     }
 
+    mod sync_used_requests {
+        use super::*;
+
+        #[rstest]
+        fn server_side_usage_is_more_than_local(
+            timeout_manager: Arc<RequestsTimeoutManager>,
+        ) -> Result<()> {
+            // Arrange
+            let current_time = Utc::now();
+            let reserved =
+                timeout_manager.try_reserve_instant(RequestType::CreateOrder, current_time, None);
+            assert!(reserved);
+
+            // Act
+            timeout_manager.sync_used_requests(3, current_time);
+
+            // Assert
+            let inner = timeout_manager.inner.lock();
+            assert_eq!(inner.requests.len(), 3);
+            assert_eq!(inner.requests[0].request_type, RequestType::CreateOrder);
+            assert_eq!(inner.requests[1].request_type, RequestType::Untracked);
+            assert_eq!(inner.requests[2].request_type, RequestType::Untracked);
+            assert_eq!(
+                inner.get_available_requests_count_at_present(current_time),
+                2
+            );
+
+            Ok(())
+        }
+
+        #[rstest]
+        fn server_side_usage_is_less_than_local(
+            timeout_manager: Arc<RequestsTimeoutManager>,
+        ) -> Result<()> {
+            // Arrange
+            let current_time = Utc::now();
+            for _ in 0..2 {
+                let reserved = timeout_manager.try_reserve_instant(
+                    RequestType::CreateOrder,
+                    current_time,
+                    None,
+                );
+                assert!(reserved);
+            }
+
+            // Act
+            timeout_manager.sync_used_requests(1, current_time);
+
+            // Assert
+            assert_eq!(timeout_manager.inner.lock().requests.len(), 2);
+
+            Ok(())
+        }
+
+        #[rstest]
+        fn server_side_usage_is_limited_by_requests_per_period(
+            timeout_manager: Arc<RequestsTimeoutManager>,
+        ) -> Result<()> {
+            // Arrange
+            let current_time = Utc::now();
+
+            // Act
+            timeout_manager.sync_used_requests(100, current_time);
+
+            // Assert
+            let reserved =
+                timeout_manager.try_reserve_instant(RequestType::CreateOrder, current_time, None);
+            assert!(!reserved);
+            assert_eq!(timeout_manager.inner.lock().requests.len(), 5);
+
+            Ok(())
+        }
+    }
+
     mod triggers {
         use parking_lot::Mutex;
 
@@ -34,13 +34,14 @@ impl RequestsTimeoutManagerFactory {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RequestTimeoutArguments {
     pub requests_per_period: usize,
     pub period: Duration,
 }
 
 impl RequestTimeoutArguments {
-    pub(crate) fn new(requests_per_period: usize, period: Duration) -> Self {
+    pub fn new(requests_per_period: usize, period: Duration) -> Self {
         Self {
             requests_per_period,
             period,
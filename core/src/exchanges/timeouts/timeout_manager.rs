@@ -18,7 +18,11 @@ use crate::exchanges::general::request_type::RequestType;
 use crate::exchanges::timeouts::requests_timeout_manager::{
     RequestGroupId, RequestsTimeoutManager,
 };
+use crate::exchanges::timeouts::requests_timeout_manager_factory::RequestTimeoutArguments;
+use crate::settings::AdaptivePacingSettings;
+use mmb_domain::events::{RateLimitKind, RateLimitUsage};
 use mmb_domain::market::ExchangeAccountId;
+use rust_decimal::Decimal;
 
 pub type BoxFuture = Box<dyn Future<Output = Result<()>> + Sync + Send>;
 
@@ -121,6 +125,62 @@ impl TimeoutManager {
             .with_expect(|| format!("Can't find timeout manger for {exchange_account_id}"))
             .get_period_duration()
     }
+
+    pub fn set_timeout_arguments(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        timeout_arguments: &RequestTimeoutArguments,
+    ) {
+        self.inner
+            .get(&exchange_account_id)
+            .with_expect(|| format!("Can't find timeout manger for {exchange_account_id}"))
+            .set_requests_per_period(
+                timeout_arguments.requests_per_period,
+                timeout_arguments.period,
+            );
+    }
+
+    pub fn setup_adaptive_pacing(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        settings: &AdaptivePacingSettings,
+    ) {
+        self.inner[&exchange_account_id].setup_adaptive_pacing(settings);
+    }
+
+    pub fn pacing_multiplier(&self, exchange_account_id: ExchangeAccountId) -> Decimal {
+        self.inner[&exchange_account_id].pacing_multiplier()
+    }
+
+    /// Adapt requests pacing to observed REST latency.
+    /// Returns new pacing multiplier if it was changed
+    pub fn observe_request_latency(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        latency: Duration,
+    ) -> Option<Decimal> {
+        self.inner
+            .get(&exchange_account_id)?
+            .observe_latency(latency)
+    }
+
+    /// Reflect in local requests budget the request weight accounted on exchange side
+    /// for the same period
+    pub fn sync_rate_limit_usage(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        usage: &RateLimitUsage,
+    ) {
+        let timeout_manager = self
+            .inner
+            .get(&exchange_account_id)
+            .with_expect(|| format!("Can't find timeout manger for {exchange_account_id}"));
+
+        let period = timeout_manager.get_period_duration();
+        if let Some(counter) = usage.get(RateLimitKind::RequestWeight, period) {
+            timeout_manager.sync_used_requests(counter.used, now());
+        }
+    }
 }
 
 pub fn now() -> DateTime {
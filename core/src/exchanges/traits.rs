@@ -3,7 +3,9 @@ use super::{
     general::order::get_order_trades::OrderTrade,
     timeouts::requests_timeout_manager_factory::RequestTimeoutArguments,
 };
-use crate::connectivity::WebSocketRole;
+use crate::connectivity::{StreamKind, WebSocketParams, WebSocketRole};
+use crate::exchanges::asset_networks_cache::AssetNetworks;
+use crate::exchanges::general::capabilities::ExchangeCapabilities;
 use crate::exchanges::general::exchange::BoxExchangeClient;
 use crate::exchanges::general::exchange::{Exchange, RequestResult};
 use crate::exchanges::general::features::ExchangeFeatures;
@@ -11,12 +13,18 @@ use crate::exchanges::general::order::cancel::CancelOrderResult;
 use crate::exchanges::general::order::create::CreateOrderResult;
 use crate::exchanges::timeouts::timeout_manager::TimeoutManager;
 use crate::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use crate::settings::ExchangeSettings;
+use crate::settings::{AccountType, ExchangeSettings};
 use anyhow::Result;
 use async_trait::async_trait;
 use dashmap::DashMap;
+use mmb_domain::events::{AggTrade, ExchangeEvent, OrderRateLimitStatus, SystemStatus, Trade};
 use mmb_domain::events::{EventSourceType, ExchangeBalancesAndPositions, MetricsEventInfo};
-use mmb_domain::events::{ExchangeEvent, Trade};
+use mmb_domain::exchanges::api_permissions::ApiPermissions;
+use mmb_domain::exchanges::commission::CurrencyPairFees;
+use mmb_domain::exchanges::dust_conversion::DustConversion;
+use mmb_domain::exchanges::endpoint_latency::EndpointLatency;
+use mmb_domain::exchanges::funding::FundingPayment;
+use mmb_domain::exchanges::leverage::LeverageBracket;
 use mmb_domain::exchanges::symbol::{BeforeAfter, Symbol};
 use mmb_domain::market::CurrencyId;
 use mmb_domain::market::{
@@ -28,10 +36,13 @@ use mmb_domain::order::snapshot::Price;
 use mmb_domain::order::snapshot::{
     ClientOrderId, ExchangeOrderId, OrderInfo, OrderInfoExtensionData, OrderSide,
 };
-use mmb_domain::position::{ActivePosition, ClosedPosition};
+use mmb_domain::order_book::order_book_data::OrderBookData;
+use mmb_domain::position::{ActivePosition, ClosedPosition, MarginMode};
 use mmb_utils::DateTime;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -100,6 +111,12 @@ pub trait ExchangeClient: Support {
 
     async fn cancel_all_orders(&self, currency_pair: CurrencyPair) -> Result<()>;
 
+    /// Cancel all orders across all currency pairs by single request.
+    /// Returns `None` if exchange doesn't support it
+    async fn cancel_all_orders_global(&self) -> Option<Result<()>> {
+        None
+    }
+
     async fn get_open_orders(&self) -> Result<Vec<OrderInfo>>;
 
     async fn get_open_orders_by_currency_pair(
@@ -142,13 +159,133 @@ pub trait ExchangeClient: Support {
     /// Need for server time latency calculating
     /// Should return server time with millis accuracy
     async fn get_server_time(&self) -> Option<Result<i64>>;
+
+    /// Exchange-wide operational status for detecting maintenance in advance
+    /// Exchanges without such endpoint are considered always working normally
+    async fn get_system_status(&self) -> Result<SystemStatus> {
+        Ok(SystemStatus::Normal)
+    }
+
+    /// Account-level permissions of API key used by exchange client
+    /// Returns None if exchange doesn't provide such information
+    async fn get_api_permissions(&self) -> Option<Result<ApiPermissions>> {
+        None
+    }
+
+    /// Order count limits of account and their current usage accounted on exchange side.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_order_rate_limit_status(&self) -> Option<Result<OrderRateLimitStatus>> {
+        None
+    }
+
+    /// Fees of currency pairs for the account which differ from default ones
+    /// Returns None if exchange doesn't provide such information
+    async fn get_trading_fees(&self) -> Option<Result<HashMap<CurrencyPair, CurrencyPairFees>>> {
+        None
+    }
+
+    /// Switch margin mode of futures positions of currency pair.
+    /// Returns None if exchange doesn't support margin modes
+    async fn set_margin_mode(
+        &self,
+        _currency_pair: CurrencyPair,
+        _margin_mode: MarginMode,
+    ) -> Option<Result<()>> {
+        None
+    }
+
+    /// Funding payments of perpetual futures positions of currency pair within time range.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_funding_history(
+        &self,
+        _currency_pair: CurrencyPair,
+        _from: DateTime,
+        _to: DateTime,
+    ) -> Option<Result<Vec<FundingPayment>>> {
+        None
+    }
+
+    /// Leverage brackets of futures currency pair ordered by notional cap.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_leverage_brackets(
+        &self,
+        _currency_pair: CurrencyPair,
+    ) -> Option<Result<Vec<LeverageBracket>>> {
+        None
+    }
+
+    /// Set leverage of futures positions of currency pair.
+    /// Returns None if exchange doesn't support setting leverage
+    async fn set_leverage(
+        &self,
+        _currency_pair: CurrencyPair,
+        _leverage: Decimal,
+    ) -> Option<Result<()>> {
+        None
+    }
+
+    /// Full order book snapshot of currency pair requested by REST.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_order_book_snapshot(
+        &self,
+        _currency_pair: CurrencyPair,
+    ) -> Option<Result<OrderBookData>> {
+        None
+    }
+
+    /// Public trades of currency pair within time range aggregated by taker order and price.
+    /// Exchange can restrict length of time range and count of returned records.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_agg_trades(
+        &self,
+        _currency_pair: CurrencyPair,
+        _from: DateTime,
+        _to: DateTime,
+        _limit: Option<u32>,
+    ) -> Option<Result<Vec<AggTrade>>> {
+        None
+    }
+
+    /// Orders of currency pair created within time range in any status including filled
+    /// and cancelled ones. Returns None if exchange doesn't provide such information
+    async fn get_order_history(
+        &self,
+        _currency_pair: CurrencyPair,
+        _from: DateTime,
+        _to: DateTime,
+    ) -> Option<Result<Vec<OrderInfo>>> {
+        None
+    }
+
+    /// Deposit and withdrawal networks of all assets by asset currency code.
+    /// Returns None if exchange doesn't provide such information
+    async fn get_asset_networks(&self) -> Option<Result<AssetNetworks>> {
+        None
+    }
+
+    /// Convert dust balances of specified assets into exchange specific currency.
+    /// Returns None if exchange doesn't support dust conversion
+    async fn convert_dust(&self, _assets: &[CurrencyCode]) -> Option<Result<DustConversion>> {
+        None
+    }
+
+    /// Trading operations which exchange client is able to perform
+    fn capabilities(&self) -> ExchangeCapabilities;
+
+    /// Measure latency to each REST endpoint of exchange and switch client to the fastest one
+    /// Returns None if exchange provides single endpoint only
+    async fn select_fastest_endpoint(&self) -> Option<Result<EndpointLatency>> {
+        None
+    }
 }
 
+/// Last argument is time of order creation on exchange side if exchange reports it
 pub type OrderCreatedCb =
-    Box<dyn Fn(ClientOrderId, ExchangeOrderId, EventSourceType) + Send + Sync>;
+    Box<dyn Fn(ClientOrderId, ExchangeOrderId, EventSourceType, Option<DateTime>) + Send + Sync>;
 
+/// Last argument is time of order cancellation on exchange side if exchange reports it
 pub type OrderCancelledCb =
-    Box<dyn Fn(ClientOrderId, ExchangeOrderId, EventSourceType) + Send + Sync>;
+    Box<dyn Fn(ClientOrderId, ExchangeOrderId, EventSourceType, Option<DateTime>) + Send + Sync>;
 
 pub type HandleTradeCb = Box<dyn Fn(CurrencyPair, Trade) + Send + Sync>;
 
@@ -183,10 +320,20 @@ pub trait Support: Send + Sync {
 
     fn set_traded_specific_currencies(&self, currencies: Vec<SpecificCurrencyPair>);
 
+    /// Kinds of streams required by strategies per currency pair. Streams of currency pairs
+    /// without requirements aren't filtered. Clients which can't filter streams ignore it
+    fn set_required_streams(&self, _required_streams: HashMap<CurrencyPair, HashSet<StreamKind>>) {}
+
     fn is_websocket_enabled(&self, role: WebSocketRole) -> bool;
 
     async fn create_ws_url(&self, role: WebSocketRole) -> Result<Url>;
 
+    /// Params of websocket connection. Connection isn't authenticated by default.
+    /// Compression is requested by exchange settings
+    async fn create_ws_params(&self, role: WebSocketRole) -> Result<WebSocketParams> {
+        Ok(WebSocketParams::new(role, self.create_ws_url(role).await?))
+    }
+
     fn get_specific_currency_pair(&self, currency_pair: CurrencyPair) -> SpecificCurrencyPair;
 
     fn get_supported_currencies(&self) -> &DashMap<CurrencyId, CurrencyCode>;
@@ -230,4 +377,7 @@ pub trait ExchangeClientBuilder {
     fn get_timeout_arguments(&self) -> RequestTimeoutArguments;
 
     fn get_exchange_id(&self) -> ExchangeId;
+
+    /// Account types which exchange client can route requests for
+    fn get_supported_account_types(&self) -> &'static [AccountType];
 }
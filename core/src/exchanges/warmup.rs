@@ -0,0 +1,115 @@
+use crate::settings::WarmupSettings;
+use mmb_domain::market::CurrencyPair;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+
+#[derive(Debug, Default)]
+struct PairWarmupState {
+    is_order_book_synced: bool,
+    trades_count: u64,
+    candles_count: u64,
+    is_ready: bool,
+}
+
+/// Tracks for each currency pair whether enough market data was received to start trading:
+/// local order book is synced and min count of trades and candles is received
+pub struct Warmup {
+    settings: WarmupSettings,
+    pairs: Mutex<HashMap<CurrencyPair, PairWarmupState>>,
+}
+
+impl Warmup {
+    pub fn new(settings: &WarmupSettings) -> Self {
+        Warmup {
+            settings: settings.clone(),
+            pairs: Default::default(),
+        }
+    }
+
+    pub fn is_ready(&self, currency_pair: CurrencyPair) -> bool {
+        self.pairs
+            .lock()
+            .get(&currency_pair)
+            .map_or(false, |state| state.is_ready)
+    }
+
+    /// Returns `true` if currency pair became ready by this update
+    pub fn on_order_book_synced(&self, currency_pair: CurrencyPair) -> bool {
+        self.update(currency_pair, |state| state.is_order_book_synced = true)
+    }
+
+    /// Returns `true` if currency pair became ready by this update
+    pub fn on_trades(&self, currency_pair: CurrencyPair, count: usize) -> bool {
+        self.update(currency_pair, |state| state.trades_count += count as u64)
+    }
+
+    /// Returns `true` if currency pair became ready by this update
+    pub fn on_candles_closed(&self, currency_pair: CurrencyPair, count: usize) -> bool {
+        self.update(currency_pair, |state| state.candles_count += count as u64)
+    }
+
+    fn update(
+        &self,
+        currency_pair: CurrencyPair,
+        update_state: impl FnOnce(&mut PairWarmupState),
+    ) -> bool {
+        let mut pairs = self.pairs.lock();
+        let state = pairs.entry(currency_pair).or_default();
+        if state.is_ready {
+            return false;
+        }
+
+        update_state(state);
+
+        state.is_ready = state.is_order_book_synced
+            && state.trades_count >= self.settings.min_trades
+            && state.candles_count >= self.settings.min_candles;
+
+        state.is_ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency_pair() -> CurrencyPair {
+        CurrencyPair::from_codes("eth".into(), "btc".into())
+    }
+
+    #[test]
+    fn ready_after_all_criteria_are_met() {
+        let warmup = Warmup::new(&WarmupSettings {
+            min_trades: 3,
+            min_candles: 1,
+        });
+        let currency_pair = currency_pair();
+
+        assert!(!warmup.on_trades(currency_pair, 2));
+        assert!(!warmup.on_order_book_synced(currency_pair));
+        assert!(!warmup.on_candles_closed(currency_pair, 1));
+        assert!(!warmup.is_ready(currency_pair));
+
+        assert!(warmup.on_trades(currency_pair, 1));
+        assert!(warmup.is_ready(currency_pair));
+
+        // readiness is reported only once
+        assert!(!warmup.on_trades(currency_pair, 1));
+        assert!(warmup.is_ready(currency_pair));
+    }
+
+    #[test]
+    fn pairs_are_warmed_up_separately() {
+        let warmup = Warmup::new(&WarmupSettings {
+            min_trades: 0,
+            min_candles: 0,
+        });
+        let currency_pair = currency_pair();
+        let other_currency_pair = CurrencyPair::from_codes("xrp".into(), "btc".into());
+
+        assert!(warmup.on_order_book_synced(currency_pair));
+
+        assert!(warmup.is_ready(currency_pair));
+        assert!(!warmup.is_ready(other_currency_pair));
+    }
+}
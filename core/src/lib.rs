@@ -25,6 +25,7 @@ pub mod orders;
 pub mod rpc;
 pub mod service_configuration;
 pub mod statistic_service;
+pub mod telemetry;
 
 pub mod config;
 pub mod database;
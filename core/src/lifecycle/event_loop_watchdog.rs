@@ -0,0 +1,161 @@
+use crate::database::events::recorder::EventRecorder;
+use crate::settings::EventLoopWatchdogSettings;
+use crate::statistic_service::StatisticService;
+use mmb_domain::events::{MetricsEvent, MetricsEventInfoBase, MetricsEventType};
+use mmb_utils::infrastructure::WithExpect;
+use mmb_utils::time::get_current_milliseconds;
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_LATENCY_WINDOW: usize = 1000;
+/// p99 latency is recalculated once per specified count of iterations, so latencies window
+/// isn't sorted on every iteration of hot path
+const P99_REFRESH_PERIOD: u64 = 100;
+
+/// Durations of the latest iterations of events loop
+pub struct LatencyWindow {
+    capacity: usize,
+    latencies: VecDeque<Duration>,
+}
+
+impl LatencyWindow {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            latencies: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    pub fn push(&mut self, latency: Duration) {
+        if self.latencies.len() == self.capacity {
+            let _ = self.latencies.pop_front();
+        }
+        self.latencies.push_back(latency);
+    }
+
+    /// Latency which specified fraction of iterations in window doesn't exceed (nearest rank).
+    /// `None` if there are no iterations yet
+    pub fn percentile(&self, fraction: f64) -> Option<Duration> {
+        if self.latencies.is_empty() {
+            return None;
+        }
+
+        let mut latencies = self.latencies.iter().copied().collect::<Vec<_>>();
+        latencies.sort_unstable();
+
+        let rank = (fraction * latencies.len() as f64).ceil() as usize;
+        Some(latencies[rank.clamp(1, latencies.len()) - 1])
+    }
+}
+
+/// Measures duration of every iteration of events loop. Iteration longer than stall threshold
+/// means that some blocking call got onto async path, so it's reported by warning and metric.
+/// p99 latency of iterations is exposed in statistics
+pub struct EventLoopWatchdog {
+    stall_threshold: Duration,
+    latencies: Mutex<LatencyWindow>,
+    iterations_count: Mutex<u64>,
+    event_recorder: Arc<EventRecorder>,
+    statistics: Arc<StatisticService>,
+}
+
+impl EventLoopWatchdog {
+    pub fn new(
+        settings: &EventLoopWatchdogSettings,
+        event_recorder: Arc<EventRecorder>,
+        statistics: Arc<StatisticService>,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            stall_threshold: Duration::from_millis(settings.stall_threshold_ms),
+            latencies: Mutex::new(LatencyWindow::new(
+                settings.latency_window.unwrap_or(DEFAULT_LATENCY_WINDOW),
+            )),
+            iterations_count: Mutex::new(0),
+            event_recorder,
+            statistics,
+        })
+    }
+
+    pub fn record_iteration(&self, loop_name: &str, latency: Duration) {
+        let p99_latency = {
+            let mut latencies = self.latencies.lock();
+            latencies.push(latency);
+
+            let mut iterations_count = self.iterations_count.lock();
+            *iterations_count += 1;
+            match *iterations_count % P99_REFRESH_PERIOD == 0 {
+                true => latencies.percentile(0.99),
+                false => None,
+            }
+        };
+
+        if let Some(p99_latency) = p99_latency {
+            self.statistics.register_event_loop_p99_latency(p99_latency);
+        }
+
+        if latency > self.stall_threshold {
+            self.on_stall(loop_name, latency);
+        }
+    }
+
+    fn on_stall(&self, loop_name: &str, latency: Duration) {
+        log::warn!(
+            "Iteration of {loop_name} took {} ms which exceeds stall threshold {} ms. Probably there is a blocking call on async path",
+            latency.as_millis(),
+            self.stall_threshold.as_millis()
+        );
+
+        let end_time = get_current_milliseconds();
+        let metrics_event_info = MetricsEventInfoBase::new(
+            end_time - latency.as_millis() as i64,
+            end_time,
+            MetricsEventType::EventLoopStall,
+        );
+        self.event_recorder
+            .save(MetricsEvent::new(&metrics_event_info, 0))
+            .with_expect(|| format!("Failure save metrics event of {loop_name} stall"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn window(latencies_ms: impl IntoIterator<Item = u64>, capacity: usize) -> LatencyWindow {
+        let mut window = LatencyWindow::new(capacity);
+        for latency_ms in latencies_ms {
+            window.push(Duration::from_millis(latency_ms));
+        }
+        window
+    }
+
+    #[rstest]
+    #[case::p99(0.99, 99)]
+    #[case::median(0.5, 50)]
+    #[case::max(1.0, 100)]
+    #[case::min(0.0, 1)]
+    fn percentile_of_latencies(#[case] fraction: f64, #[case] expected_ms: u64) {
+        let window = window((1..=100).rev(), 100);
+
+        assert_eq!(
+            window.percentile(fraction),
+            Some(Duration::from_millis(expected_ms))
+        );
+    }
+
+    #[test]
+    fn keep_only_latest_latencies() {
+        let window = window([500, 1, 2, 3], 3);
+
+        assert_eq!(window.percentile(1.0), Some(Duration::from_millis(3)));
+    }
+
+    #[test]
+    fn no_percentile_without_iterations() {
+        assert_eq!(LatencyWindow::new(10).percentile(0.99), None);
+    }
+}
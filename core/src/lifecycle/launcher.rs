@@ -12,11 +12,21 @@ use crate::exchanges::traits::ExchangeClientBuilder;
 use crate::infrastructure::spawn_future;
 use crate::infrastructure::{init_lifetime_manager, spawn_by_timer, spawn_future_ok};
 use crate::lifecycle::app_lifetime_manager::AppLifetimeManager;
+use crate::lifecycle::event_loop_watchdog::EventLoopWatchdog;
+use crate::lifecycle::scheduler::Scheduler;
 use crate::lifecycle::trading_engine::{EngineContext, TradingEngine};
+use crate::math::set_decimal_precision;
+use crate::misc::time::time_manager;
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::order_book::snapshot_persistence::OrderBookPersistence;
 use crate::rpc::config_waiter::ConfigWaiter;
 use crate::rpc::core_api::CoreApi;
 use crate::services::cleanup_orders::CleanupOrdersService;
-use crate::settings::{AppSettings, CoreSettings};
+use crate::services::sampled_recorder::SampledRecorderService;
+use crate::settings::{
+    AppSettings, CoreSettings, ExchangeSettings, OrderBookPersistenceSettings, StartupPolicy,
+};
+use crate::telemetry::init_tracing;
 use anyhow::{anyhow, bail, Context, Result};
 use core::fmt::Debug;
 use dashmap::DashMap;
@@ -27,6 +37,9 @@ use mmb_database::postgres_db::PgPool;
 use mmb_domain::events::{ExchangeEvent, ExchangeEvents, CHANNEL_MAX_EVENTS_COUNT};
 use mmb_domain::market::ExchangeAccountId;
 use mmb_domain::market::ExchangeId;
+use mmb_domain::market::MarketId;
+use mmb_domain::order_book::local_order_book_snapshot::LocalOrderBookSnapshot;
+use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::infrastructure::{init_infrastructure, SpawnFutureFlags};
 use mmb_utils::logger::print_info;
 use mmb_utils::nothing_to_do;
@@ -46,8 +59,14 @@ use uuid::Uuid;
 
 use crate::lifecycle::app_lifetime_manager::ActionAfterGracefulShutdown;
 use crate::services::cleanup_database::CleanupDatabaseService;
+use crate::services::drawdown_flatten::DrawdownFlattenService;
+use crate::services::dust_conversion::DustConversionService;
 use crate::services::exchange_time_latency::ExchangeTimeLatencyService;
 use crate::services::live_ranges::LiveRangesService;
+use crate::services::scheduled_flatten::ScheduledFlattenService;
+use crate::services::system_status::SystemStatusService;
+
+const DEFAULT_BALANCE_UPDATE_INTERVAL_SECS: u64 = 60;
 
 pub struct EngineBuildConfig {
     pub supported_exchange_clients: HashMap<ExchangeId, Box<dyn ExchangeClientBuilder + 'static>>,
@@ -68,7 +87,7 @@ impl EngineBuildConfig {
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InitSettings<StrategySettings: Clone> {
-    Directly(AppSettings<StrategySettings>),
+    Directly(Box<AppSettings<StrategySettings>>),
     Load {
         config_path: String,
         credentials_path: String,
@@ -139,7 +158,7 @@ where
     let lifetime_manager = init_lifetime_manager();
 
     let settings = match init_user_settings {
-        InitSettings::Directly(v) => v,
+        InitSettings::Directly(v) => *v,
         InitSettings::Load {
             config_path,
             credentials_path,
@@ -151,6 +170,12 @@ where
         }
     };
 
+    set_decimal_precision(settings.core.decimal_precision.unwrap_or_default());
+
+    if let Some(tracing_settings) = &settings.core.tracing {
+        init_tracing(tracing_settings)?;
+    }
+
     let (events_sender, events_receiver) = broadcast::channel(CHANNEL_MAX_EVENTS_COUNT);
 
     let timeout_manager = create_timeout_manager(&settings.core, build_settings);
@@ -220,7 +245,22 @@ where
             .setup_balance_manager(balance_manager.clone())
     }
 
-    start_updating_balances(&lifetime_manager, &balance_manager);
+    if let Some(startup_policy) = settings.core.startup_policy {
+        reconcile_open_orders(
+            &exchanges_map,
+            startup_policy,
+            lifetime_manager.stop_token(),
+        )
+        .await;
+    }
+
+    let balance_update_interval = Duration::from_secs(
+        settings
+            .core
+            .balance_update_interval_secs
+            .unwrap_or(DEFAULT_BALANCE_UPDATE_INTERVAL_SECS),
+    );
+    start_updating_balances(&lifetime_manager, &balance_manager, balance_update_interval);
 
     let (finish_graceful_shutdown_tx, finish_graceful_shutdown_rx) = oneshot::channel();
 
@@ -246,14 +286,29 @@ where
     ))
 }
 
+async fn reconcile_open_orders(
+    exchanges: &DashMap<ExchangeAccountId, Arc<Exchange>>,
+    startup_policy: StartupPolicy,
+    cancellation_token: CancellationToken,
+) {
+    log::info!("Opened orders will be handled according to startup policy {startup_policy:?}");
+
+    join_all(exchanges.iter().map(|x| {
+        x.clone()
+            .reconcile_open_orders(startup_policy, cancellation_token.clone())
+    }))
+    .await;
+}
+
 fn start_updating_balances(
     lifetime_manager: &Arc<AppLifetimeManager>,
     balance_manager: &Arc<Mutex<BalanceManager>>,
+    period: Duration,
 ) {
     spawn_by_timer(
         "Update balances",
-        Duration::from_secs(60),
-        Duration::from_secs(60),
+        period,
+        period,
         SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
         {
             let balance_manager = balance_manager.clone();
@@ -268,6 +323,525 @@ fn start_updating_balances(
     );
 }
 
+fn start_endpoints_probing(
+    exchanges_settings: &[ExchangeSettings],
+    exchanges: &DashMap<ExchangeAccountId, Arc<Exchange>>,
+) {
+    for exchange_settings in exchanges_settings {
+        let endpoint_probing = match &exchange_settings.endpoint_probing {
+            Some(endpoint_probing) => endpoint_probing,
+            None => continue,
+        };
+        let exchange = match exchanges.get(&exchange_settings.exchange_account_id) {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        // the fastest endpoint was already selected on exchange creation
+        let period = Duration::from_secs(endpoint_probing.interval_secs);
+        spawn_by_timer(
+            "Update exchange endpoint",
+            period,
+            period,
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                let exchange = exchange.clone();
+                async move { exchange.update_endpoint().await }
+            },
+        );
+    }
+}
+
+fn start_funding_payments_updating(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    for exchange_settings in exchanges_settings {
+        let funding_payments = match &exchange_settings.funding_payments {
+            Some(funding_payments) => funding_payments,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let cancellation_token = engine_context.lifetime_manager.stop_token();
+        spawn_by_timer(
+            "Update funding payments",
+            Duration::ZERO,
+            Duration::from_secs(funding_payments.update_interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                exchange
+                    .clone()
+                    .update_funding_payments(cancellation_token.clone())
+            },
+        );
+    }
+}
+
+fn start_order_book_snapshots_refreshing(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    for exchange_settings in exchanges_settings {
+        let snapshot_refresh = match &exchange_settings.order_book_snapshot_refresh {
+            Some(snapshot_refresh) => snapshot_refresh,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let period = Duration::from_secs(snapshot_refresh.interval_secs);
+        let cancellation_token = engine_context.lifetime_manager.stop_token();
+        spawn_by_timer(
+            "Refresh order book snapshots",
+            period,
+            period,
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                exchange
+                    .clone()
+                    .refresh_order_book_snapshots(cancellation_token.clone())
+            },
+        );
+    }
+}
+
+fn start_symbol_statuses_refreshing(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+    for exchange_settings in exchanges_settings {
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        // statuses are received with symbols on startup
+        let period = exchange_settings
+            .symbol_status_refresh_interval_secs
+            .map_or(DEFAULT_REFRESH_INTERVAL, Duration::from_secs);
+        spawn_by_timer(
+            "Refresh symbol statuses",
+            period,
+            period,
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || exchange.clone().refresh_symbol_statuses(),
+        );
+    }
+}
+
+fn start_order_rate_limit_status_refreshing(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    for exchange_settings in exchanges_settings {
+        let order_rate_limit = match &exchange_settings.order_rate_limit {
+            Some(order_rate_limit) => order_rate_limit,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let cancellation_token = engine_context.lifetime_manager.stop_token();
+        spawn_by_timer(
+            "Refresh order rate limit status",
+            Duration::ZERO,
+            Duration::from_secs(order_rate_limit.refresh_interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                exchange
+                    .clone()
+                    .refresh_order_rate_limit_status(cancellation_token.clone())
+            },
+        );
+    }
+}
+
+fn start_clock_skew_checking(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    for exchange_settings in exchanges_settings {
+        let clock_skew = match &exchange_settings.clock_skew {
+            Some(clock_skew) => clock_skew,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let cancellation_token = engine_context.lifetime_manager.stop_token();
+        spawn_by_timer(
+            "Check clock skew",
+            Duration::ZERO,
+            Duration::from_secs(clock_skew.check_interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                exchange
+                    .clone()
+                    .check_clock_skew(cancellation_token.clone())
+            },
+        );
+    }
+}
+
+/// Candles are closed right after their interval boundaries, so they are published in quiet market
+fn start_candles_closing(exchanges_settings: &[ExchangeSettings], engine_context: &EngineContext) {
+    // trades of ended interval can be received a bit later than its end
+    const CLOSING_LAG: Duration = Duration::from_secs(1);
+
+    for exchange_settings in exchanges_settings {
+        let candles = match &exchange_settings.candles {
+            Some(candles) => candles,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        // candles are aligned to UNIX epoch
+        let interval_ms = candles.interval_secs * 1000;
+        let now_ms = time_manager::now().timestamp_millis() as u64;
+        let delay = Duration::from_millis(interval_ms - now_ms % interval_ms) + CLOSING_LAG;
+        let closing_handle = spawn_by_timer(
+            "Close candles",
+            delay,
+            Duration::from_secs(candles.interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                let exchange = exchange.clone();
+                async move { exchange.close_candles() }
+            },
+        );
+
+        // closing timer is endless, so after its stop candles are closed by trades only
+        let exchange_account_id = exchange_settings.exchange_account_id;
+        spawn_future_ok(
+            "Watch candles closing",
+            SpawnFutureFlags::STOP_BY_TOKEN,
+            async move {
+                match closing_handle.await {
+                    Ok(outcome) => log::info!(
+                        "Candles closing by timer on {exchange_account_id} is stopped: {outcome:?}"
+                    ),
+                    Err(error) => log::error!(
+                        "Candles closing by timer on {exchange_account_id} failed: {error:?}"
+                    ),
+                }
+            },
+        );
+    }
+}
+
+/// Good till date orders are cancelled on expiry if exchange can't expire them itself
+fn start_expired_orders_cancellation(engine_context: &EngineContext) {
+    for exchange in engine_context.exchanges.iter() {
+        let exchange = exchange.clone();
+        let cancellation_token = engine_context.lifetime_manager.stop_token();
+        spawn_by_timer(
+            "Cancel expired orders",
+            Duration::ZERO,
+            Duration::from_secs(1),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || {
+                exchange
+                    .clone()
+                    .cancel_expired_orders(cancellation_token.clone())
+            },
+        );
+    }
+}
+
+fn load_warm_start_snapshots(
+    order_book_persistence: Option<&OrderBookPersistenceSettings>,
+) -> HashMap<MarketId, LocalOrderBookSnapshot> {
+    let order_book_persistence = match order_book_persistence {
+        Some(order_book_persistence) => OrderBookPersistence::new(order_book_persistence),
+        None => return HashMap::new(),
+    };
+
+    match order_book_persistence.load(time_manager::now()) {
+        Ok(snapshots) => {
+            log::info!(
+                "Order books are warm-started from {} saved snapshots",
+                snapshots.len()
+            );
+            snapshots
+        }
+        Err(error) => {
+            log::warn!("Order books are rebuilt from scratch: {error:?}");
+            HashMap::new()
+        }
+    }
+}
+
+/// Order books restored from saved snapshots are replaced by REST snapshots right after start
+fn resync_warm_started_order_books(markets: Vec<MarketId>, engine_context: &EngineContext) {
+    let markets_by_exchange = markets.into_iter().into_group_map_by(|x| x.exchange_id);
+    for (exchange_id, markets) in markets_by_exchange {
+        let exchange = match engine_context
+            .exchanges
+            .iter()
+            .find(|x| x.key().exchange_id == exchange_id)
+        {
+            Some(exchange) => exchange.value().clone(),
+            None => continue,
+        };
+
+        let currency_pairs = markets.into_iter().map(|x| x.currency_pair).collect();
+        let _ = spawn_future_ok(
+            "Resync warm started order books",
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            exchange.resync_warm_started_order_books(
+                currency_pairs,
+                engine_context.lifetime_manager.stop_token(),
+            ),
+        );
+    }
+}
+
+fn start_order_book_persistence(
+    order_book_persistence_settings: &OrderBookPersistenceSettings,
+    local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
+) {
+    let save_interval = Duration::from_secs(order_book_persistence_settings.save_interval_secs);
+    let order_book_persistence =
+        Arc::new(OrderBookPersistence::new(order_book_persistence_settings));
+    let _ = spawn_by_timer(
+        "Save order book snapshots",
+        save_interval,
+        save_interval,
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        move || {
+            let order_book_persistence = order_book_persistence.clone();
+            let local_snapshots_service = local_snapshots_service.clone();
+            async move {
+                let result = order_book_persistence
+                    .save(&local_snapshots_service.lock(), time_manager::now());
+                if let Err(error) = result {
+                    log::warn!("{error:?}");
+                }
+            }
+        },
+    );
+}
+
+fn start_scheduler(core_settings: &CoreSettings, engine_context: &EngineContext) {
+    let schedules = match &core_settings.schedules {
+        Some(schedules) if !schedules.is_empty() => schedules,
+        _ => return,
+    };
+
+    let scheduler = Arc::new(
+        Scheduler::new(
+            schedules,
+            engine_context.get_events_sender(),
+            time_manager::now(),
+        )
+        .expect("Unable to start scheduler"),
+    );
+    engine_context
+        .shutdown_service
+        .register_core_service(scheduler.clone());
+
+    let period = Duration::from_secs(1);
+    let _ = spawn_by_timer(
+        "scheduler",
+        period,
+        period,
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        move || scheduler.clone().check_schedules(),
+    );
+}
+
+fn start_composite_symbols(engine_context: &EngineContext) {
+    let composite_symbols = engine_context.composite_symbols.clone();
+    if composite_symbols.is_empty() {
+        return;
+    }
+
+    engine_context
+        .shutdown_service
+        .register_core_service(composite_symbols.clone());
+
+    let _ = spawn_future(
+        "composite_symbols start",
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        composite_symbols.start(engine_context.get_events_channel()),
+    );
+}
+
+fn start_sampled_recorder(core_settings: &CoreSettings, engine_context: &EngineContext) {
+    let sampled_recorder_settings = match &core_settings.sampled_recorder {
+        Some(sampled_recorder_settings) => sampled_recorder_settings,
+        None => return,
+    };
+
+    let sampled_recorder = Arc::new(
+        SampledRecorderService::new(sampled_recorder_settings)
+            .expect("Unable to start sampled recorder"),
+    );
+    engine_context
+        .shutdown_service
+        .register_core_service(sampled_recorder.clone());
+
+    let _ = spawn_future(
+        "sampled_recorder start",
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        sampled_recorder
+            .clone()
+            .start(engine_context.get_events_channel()),
+    );
+
+    let flush_interval = Duration::from_secs(sampled_recorder_settings.flush_interval_secs);
+    let _ = spawn_by_timer(
+        "sampled_recorder flush",
+        flush_interval,
+        flush_interval,
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        move || sampled_recorder.clone().flush(),
+    );
+}
+
+fn start_scheduled_flatten(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+) {
+    for exchange_settings in exchanges_settings {
+        let scheduled_flatten = match &exchange_settings.scheduled_flatten {
+            Some(scheduled_flatten) => scheduled_flatten,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let scheduled_flatten_service = Arc::new(ScheduledFlattenService::new(
+            exchange,
+            engine_context.exchange_blocker.clone(),
+            scheduled_flatten.clone(),
+            engine_context.lifetime_manager.stop_token(),
+        ));
+        engine_context
+            .shutdown_service
+            .register_core_service(scheduled_flatten_service.clone());
+
+        spawn_by_timer(
+            "scheduled_flatten",
+            Duration::ZERO,
+            Duration::from_secs(1),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || scheduled_flatten_service.clone().check_schedule(),
+        );
+    }
+}
+
+fn start_dust_conversion(exchanges_settings: &[ExchangeSettings], engine_context: &EngineContext) {
+    for exchange_settings in exchanges_settings {
+        let dust_conversion = match &exchange_settings.dust_conversion {
+            Some(dust_conversion) => dust_conversion,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let dust_conversion_service = Arc::new(DustConversionService::new(
+            exchange,
+            dust_conversion.clone(),
+            engine_context.lifetime_manager.stop_token(),
+        ));
+        engine_context
+            .shutdown_service
+            .register_core_service(dust_conversion_service.clone());
+
+        spawn_by_timer(
+            "dust_conversion",
+            Duration::ZERO,
+            Duration::from_secs(dust_conversion.interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || dust_conversion_service.clone().convert_dust(),
+        );
+    }
+}
+
+fn start_drawdown_flatten(
+    exchanges_settings: &[ExchangeSettings],
+    engine_context: &EngineContext,
+    local_snapshots_service: &Arc<Mutex<LocalSnapshotsService>>,
+) {
+    for exchange_settings in exchanges_settings {
+        let drawdown_flatten = match &exchange_settings.drawdown_flatten {
+            Some(drawdown_flatten) => drawdown_flatten,
+            None => continue,
+        };
+        let exchange = match engine_context
+            .exchanges
+            .get(&exchange_settings.exchange_account_id)
+        {
+            Some(exchange) => exchange.clone(),
+            None => continue,
+        };
+
+        let drawdown_flatten_service = Arc::new(DrawdownFlattenService::new(
+            exchange,
+            engine_context.exchange_blocker.clone(),
+            engine_context.balance_manager.clone(),
+            local_snapshots_service.clone(),
+            drawdown_flatten.clone(),
+            engine_context.lifetime_manager.stop_token(),
+        ));
+        engine_context
+            .shutdown_service
+            .register_core_service(drawdown_flatten_service.clone());
+
+        spawn_by_timer(
+            "drawdown_flatten",
+            Duration::ZERO,
+            Duration::from_secs(drawdown_flatten.check_interval_secs),
+            SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+            move || drawdown_flatten_service.clone().check_drawdown(),
+        );
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 fn run_services<'a, StrategySettings>(
     engine_context: Arc<EngineContext>,
@@ -284,6 +858,13 @@ where
     StrategySettings: Clone + Debug + Deserialize<'a> + Serialize,
 {
     let internal_events_loop = InternalEventsLoop::new();
+    let warm_start_snapshots =
+        load_warm_start_snapshots(settings.core.order_book_persistence.as_ref());
+    let warm_started_markets = warm_start_snapshots.keys().copied().collect_vec();
+    let local_snapshots_service = Arc::new(Mutex::new(
+        LocalSnapshotsService::new(warm_start_snapshots)
+            .with_max_depth(settings.core.order_book_max_depth),
+    ));
     engine_context
         .shutdown_service
         .register_core_service(internal_events_loop.clone());
@@ -292,6 +873,9 @@ where
         engine_context.lifetime_manager.clone(),
         load_pretty_settings(init_user_settings),
         engine_context.statistic_service.clone(),
+        engine_context.exchanges.clone(),
+        engine_context.balance_manager.clone(),
+        local_snapshots_service.clone(),
     )
     .expect("Unable to start control panel");
     engine_context
@@ -308,10 +892,23 @@ where
         internal_events_loop.start(
             events_receiver,
             exchanges_map.into_iter().collect(),
+            local_snapshots_service.clone(),
+            settings.core.event_loop_watchdog.as_ref().map(|x| {
+                EventLoopWatchdog::new(
+                    x,
+                    engine_context.event_recorder.clone(),
+                    engine_context.statistic_service.clone(),
+                )
+            }),
             engine_context.lifetime_manager.stop_token(),
         ),
     );
 
+    resync_warm_started_order_books(warm_started_markets, &engine_context);
+    if let Some(order_book_persistence) = &settings.core.order_book_persistence {
+        start_order_book_persistence(order_book_persistence, local_snapshots_service.clone());
+    }
+
     if let Some(data_services) = data_services {
         engine_context
             .shutdown_service
@@ -372,6 +969,39 @@ where
         },
     );
 
+    let system_status_service =
+        Arc::new(SystemStatusService::new(engine_context.exchanges.clone()));
+    engine_context
+        .shutdown_service
+        .register_core_service(system_status_service.clone());
+
+    let _ = spawn_by_timer(
+        "update_system_statuses",
+        Duration::ZERO,
+        Duration::from_secs(60),
+        SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
+        move || system_status_service.clone().update_system_statuses(),
+    );
+
+    start_endpoints_probing(&settings.core.exchanges, &engine_context.exchanges);
+    start_scheduled_flatten(&settings.core.exchanges, &engine_context);
+    start_dust_conversion(&settings.core.exchanges, &engine_context);
+    start_drawdown_flatten(
+        &settings.core.exchanges,
+        &engine_context,
+        &local_snapshots_service,
+    );
+    start_expired_orders_cancellation(&engine_context);
+    start_funding_payments_updating(&settings.core.exchanges, &engine_context);
+    start_order_book_snapshots_refreshing(&settings.core.exchanges, &engine_context);
+    start_symbol_statuses_refreshing(&settings.core.exchanges, &engine_context);
+    start_order_rate_limit_status_refreshing(&settings.core.exchanges, &engine_context);
+    start_clock_skew_checking(&settings.core.exchanges, &engine_context);
+    start_candles_closing(&settings.core.exchanges, &engine_context);
+    start_sampled_recorder(&settings.core, &engine_context);
+    start_composite_symbols(&engine_context);
+    start_scheduler(&settings.core, &engine_context);
+
     log::info!("TradingEngine started");
     TradingEngine::new(engine_context, settings, finish_graceful_shutdown_rx)
 }
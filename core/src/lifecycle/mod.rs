@@ -1,4 +1,6 @@
 pub mod app_lifetime_manager;
+pub mod event_loop_watchdog;
 pub mod launcher;
+pub mod scheduler;
 pub mod shutdown;
 pub mod trading_engine;
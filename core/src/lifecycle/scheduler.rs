@@ -0,0 +1,352 @@
+use crate::lifecycle::trading_engine::Service;
+use crate::misc::time::time_manager;
+use crate::settings::{MissedTickPolicy, ScheduleSettings};
+use anyhow::{bail, Context, Result};
+use chrono::{Datelike, Duration, NaiveDate, Timelike, Utc};
+use mmb_domain::events::{ExchangeEvent, ScheduledEvent};
+use mmb_utils::send_expected::SendExpectedByRef;
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use std::str::FromStr;
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::sync::oneshot::Receiver;
+
+/// Period which the next occurrence of schedule is searched within
+const MAX_SEARCH_DAYS: i64 = 366 * 5;
+
+/// Occurrence detected later than this is considered missed
+fn max_tick_delay() -> Duration {
+    Duration::minutes(1)
+}
+
+/// Cron expression of 5 fields: minute, hour, day of month, month, day of week (0 or 7 is Sunday).
+/// Fields support `*`, values, ranges `a-b`, lists `a,b` and steps `*/n`, `a-b/n`, `a/n`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// If both days of month and days of week are restricted, day matches either of them
+    is_day_of_month_restricted: bool,
+    is_day_of_week_restricted: bool,
+}
+
+impl FromStr for CronSchedule {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let fields: Vec<&str> = s.split_whitespace().collect();
+        let [minutes, hours, days_of_month, months, days_of_week] = fields[..] else {
+            bail!("Cron expression '{s}' should have 5 fields");
+        };
+
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7)
+            .with_context(|| format!("Invalid days of week in cron expression '{s}'"))?;
+        // both 0 and 7 are Sunday
+        if contains(days_of_week_mask, 7) {
+            days_of_week_mask = (days_of_week_mask | 1) & !(1 << 7);
+        }
+
+        Ok(CronSchedule {
+            minutes: parse_field(minutes, 0, 59)
+                .with_context(|| format!("Invalid minutes in cron expression '{s}'"))?,
+            hours: parse_field(hours, 0, 23)
+                .with_context(|| format!("Invalid hours in cron expression '{s}'"))?,
+            days_of_month: parse_field(days_of_month, 1, 31)
+                .with_context(|| format!("Invalid days of month in cron expression '{s}'"))?,
+            months: parse_field(months, 1, 12)
+                .with_context(|| format!("Invalid months in cron expression '{s}'"))?,
+            days_of_week: days_of_week_mask,
+            is_day_of_month_restricted: !days_of_month.starts_with('*'),
+            is_day_of_week_restricted: !days_of_week.starts_with('*'),
+        })
+    }
+}
+
+impl CronSchedule {
+    /// The first occurrence strictly after `time`. `None` if schedule never occurs, e.g. on
+    /// 30th of February
+    pub fn next_after(&self, time: DateTime) -> Option<DateTime> {
+        let start = time.naive_utc() + Duration::minutes(1);
+        let start_date = start.date();
+
+        (0..MAX_SEARCH_DAYS)
+            .map(|x| start_date + Duration::days(x))
+            .filter(|&date| self.is_day_matched(date))
+            .find_map(|date| {
+                let is_start_date = date == start_date;
+                (0..24)
+                    .filter(|&hour| contains(self.hours, hour))
+                    .filter(|&hour| !is_start_date || hour >= start.hour())
+                    .find_map(|hour| {
+                        let min_minute = match is_start_date && hour == start.hour() {
+                            true => start.minute(),
+                            false => 0,
+                        };
+                        let minute = (min_minute..60).find(|&x| contains(self.minutes, x))?;
+                        date.and_hms_opt(hour, minute, 0)
+                    })
+            })
+            .map(|x| DateTime::from_utc(x, Utc))
+    }
+
+    fn is_day_matched(&self, date: NaiveDate) -> bool {
+        if !contains(self.months, date.month()) {
+            return false;
+        }
+
+        let is_day_of_month_matched = contains(self.days_of_month, date.day());
+        let is_day_of_week_matched =
+            contains(self.days_of_week, date.weekday().num_days_from_sunday());
+        match self.is_day_of_month_restricted && self.is_day_of_week_restricted {
+            true => is_day_of_month_matched || is_day_of_week_matched,
+            false => is_day_of_month_matched && is_day_of_week_matched,
+        }
+    }
+}
+
+/// Bit mask of values allowed by field of cron expression
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64> {
+    let parse_value = |value: &str| {
+        value
+            .parse::<u32>()
+            .with_context(|| format!("Invalid value '{value}'"))
+    };
+
+    let mut mask = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, parse_value(step)?),
+            None => (item, 1),
+        };
+        if step == 0 {
+            bail!("Step of '{item}' should be positive");
+        }
+
+        let (from, to) = match (range, range.split_once('-')) {
+            ("*", _) => (min, max),
+            (_, Some((from, to))) => (parse_value(from)?, parse_value(to)?),
+            // single value with step means range up to max value
+            (_, None) if step > 1 => (parse_value(range)?, max),
+            (_, None) => {
+                let value = parse_value(range)?;
+                (value, value)
+            }
+        };
+        if from < min || to > max || from > to {
+            bail!("Values of '{item}' should be within {min}-{max}");
+        }
+
+        for value in (from..=to).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn contains(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+struct Schedule {
+    name: String,
+    cron: CronSchedule,
+    missed_tick_policy: MissedTickPolicy,
+    next_fire_time: Option<DateTime>,
+}
+
+/// Fires named events by cron-like schedules, which are delivered to strategies by
+/// `DispositionStrategy::on_scheduled`. Time is taken from time manager
+pub struct Scheduler {
+    schedules: Mutex<Vec<Schedule>>,
+    events_sender: broadcast::Sender<ExchangeEvent>,
+}
+
+impl Service for Scheduler {
+    fn name(&self) -> &str {
+        "Scheduler"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl Scheduler {
+    pub fn new(
+        settings: &[ScheduleSettings],
+        events_sender: broadcast::Sender<ExchangeEvent>,
+        now: DateTime,
+    ) -> Result<Self> {
+        let schedules = settings
+            .iter()
+            .map(|x| {
+                let cron = CronSchedule::from_str(&x.cron)
+                    .with_context(|| format!("Invalid schedule {}", x.name))?;
+                Ok(Schedule {
+                    name: x.name.clone(),
+                    next_fire_time: cron.next_after(now),
+                    cron,
+                    missed_tick_policy: x.missed_tick_policy,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            schedules: Mutex::new(schedules),
+            events_sender,
+        })
+    }
+
+    /// Should be called periodically much more often than once a minute
+    pub async fn check_schedules(self: Arc<Self>) {
+        for event in self.due_events(time_manager::now()) {
+            log::info!("Schedule {} fired", event.name);
+            self.events_sender
+                .send_expected(ExchangeEvent::Scheduled(event));
+        }
+    }
+
+    /// Events of schedules which occurred since the previous check. Several occurrences of
+    /// the same schedule are coalesced into the latest one
+    fn due_events(&self, now: DateTime) -> Vec<ScheduledEvent> {
+        let mut schedules = self.schedules.lock();
+        schedules
+            .iter_mut()
+            .filter_map(|schedule| {
+                let mut scheduled_time = schedule.next_fire_time.filter(|&x| x <= now)?;
+                let mut missed_count = 0;
+                loop {
+                    schedule.next_fire_time = schedule.cron.next_after(scheduled_time);
+                    match schedule.next_fire_time {
+                        Some(next_fire_time) if next_fire_time <= now => {
+                            scheduled_time = next_fire_time;
+                            missed_count += 1;
+                        }
+                        _ => break,
+                    }
+                }
+
+                let is_missed = now - scheduled_time > max_tick_delay();
+                if is_missed {
+                    missed_count += 1;
+                }
+                if missed_count > 0 {
+                    log::warn!(
+                        "{missed_count} occurrences of schedule {} were missed, the latest at {scheduled_time}",
+                        schedule.name
+                    );
+                }
+
+                if is_missed && schedule.missed_tick_policy == MissedTickPolicy::Skip {
+                    return None;
+                }
+
+                Some(ScheduledEvent {
+                    name: schedule.name.clone(),
+                    scheduled_time,
+                    event_creation_time: now,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    fn date_time(day: u32, hour: u32, min: u32) -> DateTime {
+        // 2021-09-20 is Monday
+        Utc.ymd(2021, 9, day).and_hms(hour, min, 0)
+    }
+
+    #[rstest]
+    #[case::every_minute("* * * * *", date_time(20, 10, 30), date_time(20, 10, 31))]
+    #[case::hourly("0 * * * *", date_time(20, 10, 0), date_time(20, 11, 0))]
+    #[case::next_day("30 9 * * *", date_time(20, 10, 0), date_time(21, 9, 30))]
+    #[case::step("*/15 * * * *", date_time(20, 10, 16), date_time(20, 10, 30))]
+    #[case::list_and_range("0 8-9,17 * * *", date_time(20, 9, 0), date_time(20, 17, 0))]
+    #[case::weekday("0 0 * * 5", date_time(20, 10, 0), date_time(24, 0, 0))]
+    #[case::sunday_as_7("0 0 * * 7", date_time(20, 10, 0), date_time(26, 0, 0))]
+    #[case::day_of_month_or_week("0 0 25 * 3", date_time(20, 10, 0), date_time(22, 0, 0))]
+    #[case::next_month("0 0 1 * *", date_time(20, 10, 0), Utc.ymd(2021, 10, 1).and_hms(0, 0, 0))]
+    fn next_occurrence(#[case] cron: &str, #[case] time: DateTime, #[case] expected: DateTime) {
+        let cron = CronSchedule::from_str(cron).expect("in test");
+
+        assert_eq!(cron.next_after(time), Some(expected));
+    }
+
+    #[rstest]
+    #[case::few_fields("0 * * *")]
+    #[case::out_of_range("60 * * * *")]
+    #[case::inverted_range("0 10-8 * * *")]
+    #[case::zero_step("*/0 * * * *")]
+    #[case::not_number("a * * * *")]
+    fn invalid_cron_expression(#[case] cron: &str) {
+        assert!(CronSchedule::from_str(cron).is_err());
+    }
+
+    #[test]
+    fn never_occurring_schedule() {
+        let cron = CronSchedule::from_str("0 0 30 2 *").expect("in test");
+
+        assert_eq!(cron.next_after(date_time(20, 10, 0)), None);
+    }
+
+    fn scheduler(missed_tick_policy: MissedTickPolicy, now: DateTime) -> Scheduler {
+        let settings = ScheduleSettings {
+            name: "rebalance".to_owned(),
+            cron: "0 * * * *".to_owned(),
+            missed_tick_policy,
+        };
+        let (events_sender, _) = broadcast::channel(10);
+
+        Scheduler::new(&[settings], events_sender, now).expect("in test")
+    }
+
+    #[rstest]
+    #[case::skip(MissedTickPolicy::Skip)]
+    #[case::fire_once(MissedTickPolicy::FireOnce)]
+    fn fire_occurrence_in_time(#[case] missed_tick_policy: MissedTickPolicy) {
+        let scheduler = scheduler(missed_tick_policy, date_time(20, 10, 30));
+
+        assert!(scheduler.due_events(date_time(20, 10, 59)).is_empty());
+
+        let now = date_time(20, 11, 0) + Duration::seconds(1);
+        let events = scheduler.due_events(now);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].name, "rebalance");
+        assert_eq!(events[0].scheduled_time, date_time(20, 11, 0));
+
+        assert!(scheduler.due_events(now + Duration::seconds(1)).is_empty());
+    }
+
+    #[rstest]
+    #[case::skip(MissedTickPolicy::Skip, None)]
+    #[case::fire_once(MissedTickPolicy::FireOnce, Some(date_time(20, 14, 0)))]
+    fn handle_missed_occurrences(
+        #[case] missed_tick_policy: MissedTickPolicy,
+        #[case] expected_scheduled_time: Option<DateTime>,
+    ) {
+        let scheduler = scheduler(missed_tick_policy, date_time(20, 10, 30));
+
+        let events = scheduler.due_events(date_time(20, 14, 30));
+        assert_eq!(
+            events.first().map(|x| x.scheduled_time),
+            expected_scheduled_time
+        );
+        assert!(events.len() <= 1);
+
+        // schedule continues from the next occurrence after resume
+        let events = scheduler.due_events(date_time(20, 15, 0));
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].scheduled_time, date_time(20, 15, 0));
+    }
+}
@@ -1,5 +1,6 @@
 use super::launcher::unwrap_or_handle_panic;
 use crate::balance::manager::balance_manager::BalanceManager;
+use crate::connectivity::StreamKind;
 use crate::database::events::recorder::EventRecorder;
 use crate::disposition_execution::executor::DispositionExecutorService;
 use crate::disposition_execution::strategy::DispositionStrategy;
@@ -13,9 +14,11 @@ use crate::lifecycle::app_lifetime_manager::ActionAfterGracefulShutdown;
 use crate::lifecycle::app_lifetime_manager::AppLifetimeManager;
 use crate::lifecycle::shutdown::ShutdownService;
 use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::services::composite_symbols::CompositeSymbolsService;
 use crate::settings::DispositionStrategySettings;
-use crate::settings::{AppSettings, CoreSettings};
+use crate::settings::{AppSettings, CoreSettings, ShutdownPolicy};
 use crate::statistic_service::{StatisticEventHandler, StatisticService};
+use crate::telemetry::shutdown_tracing;
 use anyhow::Result;
 use dashmap::DashMap;
 use futures::future::join_all;
@@ -54,6 +57,8 @@ pub struct EngineContext {
     pub balance_manager: Arc<Mutex<BalanceManager>>,
     pub event_recorder: Arc<EventRecorder>,
     pub statistic_service: Arc<StatisticService>,
+    /// Synthetic symbols priced from constituent currency pairs
+    pub composite_symbols: Arc<CompositeSymbolsService>,
     is_graceful_shutdown_started: AtomicBool,
     exchange_events: ExchangeEvents,
     finish_graceful_shutdown_sender: Mutex<Option<oneshot::Sender<ActionAfterGracefulShutdown>>>,
@@ -73,6 +78,10 @@ impl EngineContext {
         event_recorder: Arc<EventRecorder>,
     ) -> Arc<Self> {
         let statistic_service = StatisticService::new();
+        let composite_symbols = Arc::new(CompositeSymbolsService::new(
+            core_settings.composite_symbols.clone().unwrap_or_default(),
+            exchanges.clone(),
+        ));
         let engine_context = Arc::new(EngineContext {
             core_settings,
             exchanges,
@@ -83,6 +92,7 @@ impl EngineContext {
             balance_manager,
             event_recorder,
             statistic_service,
+            composite_symbols,
             is_graceful_shutdown_started: Default::default(),
             exchange_events,
             finish_graceful_shutdown_sender: Mutex::new(Some(finish_graceful_shutdown_sender)),
@@ -124,9 +134,19 @@ impl EngineContext {
         let cancellation_token = CancellationToken::default();
         const TIMEOUT: Duration = Duration::from_secs(5);
 
+        let shutdown_policy = self.core_settings.shutdown_policy;
+        log::info!(
+            "Opened orders will be handled according to shutdown policy {shutdown_policy:?}"
+        );
+
         match timeout(
             TIMEOUT,
-            cancel_opened_orders(&self.exchanges, cancellation_token.clone(), true),
+            cancel_opened_orders(
+                &self.exchanges,
+                shutdown_policy,
+                cancellation_token.clone(),
+                true,
+            ),
         )
         .await
         {
@@ -170,6 +190,9 @@ impl EngineContext {
             .map(|exchange| async move { exchange.clone().disconnect_ws().await });
         join_all(disconnect_websockets).await;
 
+        // exporting of remaining spans blocks current thread
+        let _ = tokio::task::spawn_blocking(shutdown_tracing).await;
+
         self.finish_graceful_shutdown_sender
             .lock()
             .take()
@@ -188,24 +211,91 @@ impl EngineContext {
     pub fn get_events_channel(&self) -> broadcast::Receiver<ExchangeEvent> {
         self.exchange_events.get_events_channel()
     }
+
+    pub fn get_events_sender(&self) -> broadcast::Sender<ExchangeEvent> {
+        self.exchange_events.events_sender()
+    }
 }
 
 async fn cancel_opened_orders(
     exchanges: &DashMap<ExchangeAccountId, Arc<Exchange>>,
+    shutdown_policy: ShutdownPolicy,
     cancellation_token: CancellationToken,
     add_missing_open_orders: bool,
 ) {
+    if shutdown_policy == ShutdownPolicy::LeaveResting {
+        log::warn!(
+            "Opened orders are left on exchanges because of shutdown policy {shutdown_policy:?}"
+        );
+        return;
+    }
+
     log::info!("Canceling opened orders started");
 
     join_all(exchanges.iter().map(|x| {
-        x.clone()
-            .cancel_opened_orders(cancellation_token.clone(), add_missing_open_orders)
+        let exchange = x.clone();
+        let cancellation_token = cancellation_token.clone();
+        async move {
+            match shutdown_policy {
+                ShutdownPolicy::CancelAll => {
+                    cancel_all_orders(exchange, cancellation_token, add_missing_open_orders).await
+                }
+                ShutdownPolicy::CancelNonPassive => {
+                    exchange
+                        .cancel_non_passive_orders(cancellation_token, add_missing_open_orders)
+                        .await
+                }
+                ShutdownPolicy::LeaveResting => nothing_to_do(),
+            }
+        }
     }))
     .await;
 
     log::info!("Canceling opened orders finished");
 }
 
+/// Cancel all orders on account by the broadest cancellation available on exchange.
+/// Orders are cancelled one by one if it failed
+async fn cancel_all_orders(
+    exchange: Arc<Exchange>,
+    cancellation_token: CancellationToken,
+    add_missing_open_orders: bool,
+) {
+    let exchange_account_id = exchange.exchange_account_id;
+    // orders ignored on startup would be cancelled by exchange too
+    if exchange.has_unmanaged_orders() {
+        exchange
+            .cancel_opened_orders(cancellation_token, add_missing_open_orders)
+            .await;
+        return;
+    }
+
+    match exchange
+        .cancel_all_orders_global(cancellation_token.clone())
+        .await
+    {
+        Ok(summary) => {
+            for (currency_pair, result) in &summary {
+                match result {
+                    Ok(()) => log::info!("Cancelled all orders for {currency_pair} on {exchange_account_id}"),
+                    Err(error) => log::error!("Failed to cancel all orders for {currency_pair} on {exchange_account_id}: {error:?}"),
+                }
+            }
+
+            if summary.values().all(|x| x.is_ok()) {
+                return;
+            }
+        }
+        Err(error) => {
+            log::error!("Failed to cancel all orders on {exchange_account_id}: {error:?}")
+        }
+    }
+
+    exchange
+        .cancel_opened_orders(cancellation_token, add_missing_open_orders)
+        .await
+}
+
 async fn close_active_positions(
     exchanges: &DashMap<ExchangeAccountId, Arc<Exchange>>,
     cancellation_token: CancellationToken,
@@ -215,7 +305,12 @@ async fn close_active_positions(
     join_all(
         exchanges
             .iter()
-            .filter(|x| x.exchange_client.get_settings().is_margin_trading)
+            .filter(|x| {
+                x.exchange_client
+                    .get_settings()
+                    .account_type
+                    .is_derivative()
+            })
             .map(|x| x.clone().close_active_positions(cancellation_token.clone())),
     )
     .await;
@@ -283,10 +378,23 @@ impl<StrategySettings: Clone> TradingEngine<StrategySettings> {
             StatisticEventHandler::new(ctx.get_events_channel(), ctx.statistic_service.clone());
 
         let base_settings = &settings.strategy;
+
+        // streams are subscribed on connecting, so requirements are registered before `run`
+        let exchange_account_id = base_settings.exchange_account_id();
+        let exchange = ctx
+            .exchanges
+            .get(&exchange_account_id)
+            .with_expect(|| format!("Exchange {exchange_account_id} of strategy isn't found"));
+        match strategy.required_streams() {
+            Some(streams) => exchange.require_streams(base_settings.currency_pair(), streams),
+            None => exchange.require_streams(base_settings.currency_pair(), StreamKind::ALL),
+        }
+        drop(exchange);
+
         let disposition_executor_service = DispositionExecutorService::new(
             ctx.clone(),
             ctx.get_events_channel(),
-            LocalSnapshotsService::default(),
+            LocalSnapshotsService::default().with_max_depth(settings.core.order_book_max_depth),
             base_settings.exchange_account_id(),
             base_settings.currency_pair(),
             strategy,
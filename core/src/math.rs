@@ -1,3 +1,7 @@
+use crate::settings::DecimalPrecisionSettings;
+use mmb_domain::order::snapshot::{Amount, Price};
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 
@@ -12,6 +16,43 @@ impl ConvertPercentToRate for Decimal {
     }
 }
 
+/// Computation boundaries at which results are rounded by `DecimalPrecisionSettings`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecimalComputation {
+    Commission,
+    ProfitLoss,
+    Valuation,
+}
+
+static DECIMAL_PRECISION: Lazy<RwLock<DecimalPrecisionSettings>> = Lazy::new(Default::default);
+
+/// Configure scales of computations for whole application
+pub fn set_decimal_precision(settings: DecimalPrecisionSettings) {
+    *DECIMAL_PRECISION.write() = settings;
+}
+
+pub trait RoundForComputation {
+    fn round_for(&self, computation: DecimalComputation) -> Self;
+}
+
+impl RoundForComputation for Decimal {
+    fn round_for(&self, computation: DecimalComputation) -> Decimal {
+        DECIMAL_PRECISION.read().round(*self, computation)
+    }
+}
+
+impl RoundForComputation for Amount {
+    fn round_for(&self, computation: DecimalComputation) -> Amount {
+        self.value().round_for(computation).into()
+    }
+}
+
+impl RoundForComputation for Price {
+    fn round_for(&self, computation: DecimalComputation) -> Price {
+        self.value().round_for(computation).into()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -29,4 +70,63 @@ mod test {
 
         assert_eq!(powered, expected);
     }
+
+    #[rstest]
+    #[case(DecimalComputation::Commission, dec!(0.000000000000000000125), dec!(0))]
+    #[case(DecimalComputation::ProfitLoss, dec!(1) / dec!(3), dec!(0.333333333333333333))]
+    #[case(DecimalComputation::Valuation, dec!(2) / dec!(3), dec!(0.666666666667))]
+    #[case(DecimalComputation::Valuation, dec!(0.0000000000005), dec!(0))]
+    #[case(DecimalComputation::Valuation, dec!(0.0000000000015), dec!(0.000000000002))]
+    #[case(DecimalComputation::Valuation, dec!(-0.0000000000015), dec!(-0.000000000002))]
+    #[case(DecimalComputation::Valuation, dec!(12.5), dec!(12.5))]
+    fn round_by_default_precision(
+        #[case] computation: DecimalComputation,
+        #[case] value: Decimal,
+        #[case] expected: Decimal,
+    ) {
+        let rounded = DecimalPrecisionSettings::default().round(value, computation);
+
+        assert_eq!(rounded, expected);
+        assert!(rounded.scale() <= DecimalPrecisionSettings::default().scale(computation));
+    }
+
+    #[test]
+    fn round_by_configured_precision() {
+        let settings = DecimalPrecisionSettings {
+            commission_scale: 2,
+            profit_loss_scale: 4,
+            valuation_scale: 0,
+        };
+        let value = dec!(10.123456789);
+
+        assert_eq!(
+            settings.round(value, DecimalComputation::Commission),
+            dec!(10.12)
+        );
+        assert_eq!(
+            settings.round(value, DecimalComputation::ProfitLoss),
+            dec!(10.1235)
+        );
+        assert_eq!(
+            settings.round(value, DecimalComputation::Valuation),
+            dec!(10)
+        );
+    }
+
+    #[test]
+    fn accumulation_of_rounded_values_is_deterministic() {
+        let settings = DecimalPrecisionSettings::default();
+        let sum_in_order = |values: &[Decimal]| -> Decimal {
+            values
+                .iter()
+                .map(|x| settings.round(*x, DecimalComputation::ProfitLoss))
+                .sum()
+        };
+
+        let values = [dec!(1) / dec!(3), dec!(1) / dec!(7), dec!(-2) / dec!(9)];
+        let reversed = [values[2], values[1], values[0]];
+
+        assert_eq!(sum_in_order(&values), sum_in_order(&reversed));
+        assert_eq!(sum_in_order(&values), dec!(0.253968253968253968));
+    }
 }
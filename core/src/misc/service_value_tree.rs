@@ -9,7 +9,6 @@ use serde::Serialize;
 
 use mmb_domain::order::snapshot::Amount;
 use mmb_utils::hashmap;
-use rust_decimal_macros::dec;
 
 pub(crate) type ConfigurationKeyByServiceName =
     HashMap<ServiceName, ExchangeAccountIdByConfigurationKey>;
@@ -236,7 +235,7 @@ impl ServiceValueTree {
     pub fn add_by_request(&mut self, request: &BalanceRequest, value: Amount) {
         self.set_by_balance_request(
             request,
-            self.get_by_balance_request(request).unwrap_or(dec!(0)) + value,
+            self.get_by_balance_request(request).unwrap_or(Amount::ZERO) + value,
         );
     }
 }
@@ -245,10 +244,10 @@ impl ServiceValueTree {
 mod test {
     use super::*;
 
+    use mmb_domain::amount;
     use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeAccountId};
 
     use mmb_utils::{hashmap, logger::init_logger};
-    use rust_decimal_macros::dec;
 
     use mmb_domain::order::snapshot::Amount;
     use std::collections::HashMap;
@@ -306,7 +305,7 @@ mod test {
                                 exchange_account_id,
                                 currency_pair,
                                 currency_code,
-                                dec!(1),
+                                amount!(1),
                             );
                             balances.insert(
                                 BalanceRequest::new(
@@ -318,7 +317,7 @@ mod test {
                                     currency_pair,
                                     currency_code,
                                 ),
-                                dec!(1),
+                                amount!(1),
                             );
                         }
                     }
@@ -345,13 +344,13 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         let new_service_configuration_key = "new_name".into();
         let new_exchange_account_id = ExchangeAccountId::new("new_acc", 0);
         let new_currency_pair = CurrencyPair::from_codes("new_code_b".into(), "new_code_q".into());
         let new_currency_code = CurrencyCode::new("new_code");
-        let new_value = dec!(1);
+        let new_value = amount!(1);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -476,7 +475,7 @@ mod test {
             "new_currency_pair_q_0".into(),
         );
         let new_currency_code = CurrencyCode::new("0");
-        let new_value = dec!(0);
+        let new_value = amount!(0);
 
         let mut new_tree = ServiceValueTree::default();
         new_tree.set_by_currency_code(
@@ -512,7 +511,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -550,7 +549,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -573,7 +572,7 @@ mod test {
             exchange_account_id,
             currency_pair,
             currency_code,
-            dec!(1),
+            amount!(1),
         );
     }
 
@@ -588,7 +587,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -626,7 +625,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -664,7 +663,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -702,7 +701,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -740,7 +739,7 @@ mod test {
         let exchange_account_id = ExchangeAccountId::new("acc_0", 0);
         let currency_pair = CurrencyPair::from_codes("b_0".into(), "q_0".into());
         let currency_code = CurrencyCode::new("0");
-        let value = dec!(0);
+        let value = amount!(0);
 
         service_value_tree.set_by_service_name(
             service_name,
@@ -1,29 +1,101 @@
-use mmb_domain::market::{MarketAccountId, MarketId};
+use crate::disposition_execution::inventory_skew::{skewed_quotes_around, InventorySkewSettings};
+use mmb_domain::market::{ExchangeId, MarketAccountId, MarketId};
+use mmb_domain::order::snapshot::{Amount, OrderSide, Price};
 use mmb_domain::order_book::event;
 use mmb_domain::order_book::local_order_book_snapshot::{LocalOrderBookSnapshot, ResultAskBidFix};
 use mmb_utils::infrastructure::WithExpect;
+use rust_decimal::Decimal;
 use std::collections::HashMap;
 
 /// Produce and actualize current logical state of order book snapshot according to logical time of handled order book events
 pub struct LocalSnapshotsService {
     local_snapshots: HashMap<MarketId, LocalOrderBookSnapshot>,
+    max_depth: Option<usize>,
 }
 
 impl LocalSnapshotsService {
     pub fn new(local_snapshots: HashMap<MarketId, LocalOrderBookSnapshot>) -> Self {
-        Self { local_snapshots }
+        Self {
+            local_snapshots,
+            max_depth: None,
+        }
+    }
+
+    /// Prune price levels beyond `max_depth` after each update to reduce memory usage.
+    /// Snapshots contain full depth if not specified
+    pub fn with_max_depth(mut self, max_depth: Option<usize>) -> Self {
+        self.max_depth = max_depth;
+        self
     }
 
     pub fn get_snapshot(&self, market_id: MarketId) -> Option<&LocalOrderBookSnapshot> {
         self.local_snapshots.get(&market_id)
     }
 
+    pub fn snapshots(&self) -> impl Iterator<Item = (&MarketId, &LocalOrderBookSnapshot)> {
+        self.local_snapshots.iter()
+    }
+
     pub fn get_snapshot_expected(&self, market_id: MarketId) -> &LocalOrderBookSnapshot {
         self.local_snapshots
             .get(&market_id)
             .with_expect(|| format!("Can't get snapshot for {:?}", market_id))
     }
 
+    /// Average price of filling `notional` in quote currency by local order book of market.
+    /// Returns `None` if there is no snapshot or it doesn't have enough liquidity
+    pub fn price_to_fill(
+        &self,
+        market_id: MarketId,
+        side: OrderSide,
+        notional: Amount,
+    ) -> Option<Price> {
+        self.get_snapshot(market_id)?.price_to_fill(side, notional)
+    }
+
+    /// Relative difference between average fill price of `notional` and middle price of market
+    pub fn expected_slippage(
+        &self,
+        market_id: MarketId,
+        side: OrderSide,
+        notional: Amount,
+    ) -> Option<Decimal> {
+        self.get_snapshot(market_id)?
+            .expected_slippage(side, notional)
+    }
+
+    /// Bid and ask around middle price of market shifted against current inventory with
+    /// aggressiveness from `settings`, see `skewed_quotes_around`. Market is specified by
+    /// `MarketId` because currency pair alone doesn't identify order book.
+    /// Returns `None` if there is no snapshot or one of its sides is empty
+    pub fn skewed_quotes(
+        &self,
+        market_id: MarketId,
+        base_spread: Price,
+        inventory: Amount,
+        max_inventory: Amount,
+        settings: &InventorySkewSettings,
+    ) -> Option<(Price, Price)> {
+        let mid_price = self
+            .get_snapshot(market_id)?
+            .calculate_middle_price(market_id)?;
+
+        Some(skewed_quotes_around(
+            mid_price,
+            base_spread,
+            inventory,
+            max_inventory,
+            settings,
+        ))
+    }
+
+    /// Drop snapshots of exchange which can't be trusted anymore, e.g. after websocket reconnect.
+    /// Updates for dropped snapshots are ignored until fresh snapshot arrives
+    pub fn discard_snapshots(&mut self, exchange_id: ExchangeId) {
+        self.local_snapshots
+            .retain(|market_id, _| market_id.exchange_id != exchange_id);
+    }
+
     /// Create snapshot if it does not exist
     /// Update snapshot if suitable data arrive
     /// Returns `Some(MarketAccountId)` if snapshot update succeeded, otherwise `None`
@@ -40,6 +112,10 @@ impl LocalSnapshotsService {
                     log::warn!("On {market_account_id} orderbook top asks {top_ask} and bids {top_bid} was crossed (fixed now {})", snapshot.get_top_prices())
                 }
 
+                if let Some(max_depth) = self.max_depth {
+                    snapshot.truncate_depth(max_depth);
+                }
+
                 self.local_snapshots.insert(market_id, snapshot);
 
                 Some(market_account_id)
@@ -55,6 +131,10 @@ impl LocalSnapshotsService {
                         log::warn!("On {market_account_id} orderbook top asks {top_ask} and bids {top_bid} was crossed (fixed now {})", snapshot.get_top_prices())
                     }
 
+                    if let Some(max_depth) = self.max_depth {
+                        snapshot.truncate_depth(max_depth);
+                    }
+
                     Some(market_account_id)
                 }
             },
@@ -75,6 +155,7 @@ mod tests {
     use mmb_domain::market::{CurrencyPair, ExchangeAccountId, ExchangeId};
     use mmb_domain::order_book::order_book_data;
     use mmb_domain::order_book_data;
+    use mmb_domain::{amount, price};
     use rust_decimal_macros::*;
     use std::sync::Arc;
 
@@ -158,6 +239,44 @@ mod tests {
         assert!(update_result.is_none());
     }
 
+    #[test]
+    fn ignore_update_after_snapshots_discarded() {
+        let currency_pair = CurrencyPair::from_codes("base".into(), "quote".into());
+        let mut snapshot_service = LocalSnapshotsService::default();
+
+        let order_book_data = order_book_data![
+            dec!(3.4) => dec!(1.2),
+            ;
+            dec!(2.9) => dec!(7.8),
+        ];
+
+        let snapshot_event = create_order_book_event_for_tests(
+            "exchange_id".into(),
+            currency_pair,
+            event::EventType::Snapshot,
+            order_book_data.clone(),
+        );
+        let update_event = create_order_book_event_for_tests(
+            "exchange_id".into(),
+            currency_pair,
+            event::EventType::Update,
+            order_book_data,
+        );
+
+        let market_account_id = snapshot_service.update(&snapshot_event).expect("in test");
+        snapshot_service.discard_snapshots("another_exchange_id".into());
+        assert!(snapshot_service.update(&update_event).is_some());
+
+        snapshot_service.discard_snapshots(market_account_id.exchange_account_id.exchange_id);
+        assert!(snapshot_service
+            .get_snapshot(market_account_id.market_id())
+            .is_none());
+        assert!(snapshot_service.update(&update_event).is_none());
+
+        assert!(snapshot_service.update(&snapshot_event).is_some());
+        assert!(snapshot_service.update(&update_event).is_some());
+    }
+
     #[test]
     fn successful_update() {
         let test_exchange_id = "exchange_id";
@@ -314,4 +433,94 @@ mod tests {
         assert_eq!(snapshot.asks, expected.asks);
         assert_eq!(snapshot.bids, expected.bids);
     }
+
+    #[test]
+    fn prune_levels_beyond_max_depth() {
+        let mut snapshot_service = LocalSnapshotsService::default().with_max_depth(Some(1));
+
+        let order_book_data_snapshot = order_book_data![
+            dec!(3.4) => dec!(1.2),
+            dec!(2.9) => dec!(7.8),
+            ;
+            dec!(1.5) => dec!(4.2),
+            dec!(1.0) => dec!(2.1),
+        ];
+        let order_book_event_snapshot = create_order_book_event_for_tests(
+            "does_not_matter".into(),
+            CurrencyPair::from_codes("base".into(), "quote".into()),
+            event::EventType::Snapshot,
+            order_book_data_snapshot,
+        );
+        snapshot_service
+            .update(&order_book_event_snapshot)
+            .expect("in test");
+
+        let order_book_data_update = order_book_data![
+            dec!(2.5) => dec!(0.3),
+            ;
+            dec!(1.2) => dec!(0.4),
+        ];
+        let order_book_event_update = create_order_book_event_for_tests(
+            "does_not_matter".into(),
+            CurrencyPair::from_codes("base".into(), "quote".into()),
+            event::EventType::Update,
+            order_book_data_update,
+        );
+        let market_account_id = snapshot_service
+            .update(&order_book_event_update)
+            .expect("in test");
+
+        let snapshot = snapshot_service.get_snapshot_expected(market_account_id.market_id());
+        let expected = order_book_data![
+            dec!(2.5) => dec!(0.3),
+            ;
+            dec!(1.5) => dec!(4.2),
+        ];
+        assert_eq!(snapshot.asks, expected.asks);
+        assert_eq!(snapshot.bids, expected.bids);
+    }
+
+    #[test]
+    fn skewed_quotes_around_middle_price() {
+        let mut snapshot_service = LocalSnapshotsService::new(HashMap::new());
+        let order_book_event = create_order_book_event_for_tests(
+            "does_not_matter".into(),
+            CurrencyPair::from_codes("base".into(), "quote".into()),
+            event::EventType::Snapshot,
+            order_book_data![
+                dec!(101) => dec!(1),
+                ;
+                dec!(99) => dec!(1),
+            ],
+        );
+        let market_id = snapshot_service
+            .update(&order_book_event)
+            .expect("in test")
+            .market_id();
+        let settings = InventorySkewSettings {
+            aggressiveness: dec!(1),
+        };
+
+        let quotes = snapshot_service.skewed_quotes(
+            market_id,
+            price!(2),
+            amount!(10),
+            amount!(10),
+            &settings,
+        );
+        assert_eq!(quotes, Some((price!(98), price!(100))));
+
+        let unknown_market_id = MarketId::new(
+            "unknown".into(),
+            CurrencyPair::from_codes("base".into(), "quote".into()),
+        );
+        let quotes = snapshot_service.skewed_quotes(
+            unknown_market_id,
+            price!(2),
+            amount!(10),
+            amount!(10),
+            &settings,
+        );
+        assert_eq!(quotes, None);
+    }
 }
@@ -1 +1,3 @@
 pub mod local_snapshot_service;
+pub mod order_book_freshness;
+pub mod snapshot_persistence;
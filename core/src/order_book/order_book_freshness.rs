@@ -0,0 +1,65 @@
+use crate::settings::{OrderBookFreshnessSettings, PerCurrencyPair};
+use mmb_domain::market::CurrencyPair;
+use std::time::Duration;
+
+/// Max age of local order book after which orders priced off it shouldn't be created
+pub struct OrderBookFreshness {
+    max_age: PerCurrencyPair<Duration>,
+}
+
+impl OrderBookFreshness {
+    pub fn new(settings: &OrderBookFreshnessSettings) -> Self {
+        Self {
+            max_age: PerCurrencyPair::new(
+                settings.default_max_age_ms.map(Duration::from_millis),
+                settings
+                    .currency_pairs
+                    .iter()
+                    .map(|x| (x.currency_pair, Duration::from_millis(x.max_age_ms))),
+            ),
+        }
+    }
+
+    pub fn max_age(&self, currency_pair: CurrencyPair) -> Option<Duration> {
+        self.max_age.get(currency_pair)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::CurrencyPairOrderBookMaxAge;
+
+    #[test]
+    fn specific_max_age_overrides_default() {
+        let freshness = OrderBookFreshness::new(&OrderBookFreshnessSettings {
+            default_max_age_ms: Some(5_000),
+            currency_pairs: vec![CurrencyPairOrderBookMaxAge {
+                currency_pair: CurrencyPair::from_codes("btc".into(), "usdt".into()),
+                max_age_ms: 1_000,
+            }],
+        });
+
+        assert_eq!(
+            freshness.max_age(CurrencyPair::from_codes("btc".into(), "usdt".into())),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            freshness.max_age(CurrencyPair::from_codes("eth".into(), "usdt".into())),
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[test]
+    fn no_max_age_without_settings_for_currency_pair() {
+        let freshness = OrderBookFreshness::new(&OrderBookFreshnessSettings {
+            default_max_age_ms: None,
+            currency_pairs: vec![],
+        });
+
+        assert_eq!(
+            freshness.max_age(CurrencyPair::from_codes("btc".into(), "usdt".into())),
+            None
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::settings::OrderBookPersistenceSettings;
+use anyhow::{Context, Result};
+use mmb_domain::market::MarketId;
+use mmb_domain::order::snapshot::{Amount, Price};
+use mmb_domain::order_book::local_order_book_snapshot::LocalOrderBookSnapshot;
+use mmb_utils::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedOrderBook {
+    market_id: MarketId,
+    asks: Vec<(Price, Amount)>,
+    bids: Vec<(Price, Amount)>,
+    last_update_time: DateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedOrderBooks {
+    saved_time: DateTime,
+    order_books: Vec<PersistedOrderBook>,
+}
+
+/// Saves the latest local order books to file and restores them on startup, so strategy has
+/// order books right after quick restart. Restored order books are too old to be trusted,
+/// so they should be resynced by REST snapshots immediately
+pub struct OrderBookPersistence {
+    file_path: PathBuf,
+    max_age: chrono::Duration,
+}
+
+impl OrderBookPersistence {
+    pub fn new(settings: &OrderBookPersistenceSettings) -> Self {
+        Self {
+            file_path: settings.file_path.clone(),
+            max_age: chrono::Duration::seconds(settings.max_age_secs as i64),
+        }
+    }
+
+    pub fn save(
+        &self,
+        local_snapshots_service: &LocalSnapshotsService,
+        now: DateTime,
+    ) -> Result<()> {
+        let persisted = PersistedOrderBooks {
+            saved_time: now,
+            order_books: local_snapshots_service
+                .snapshots()
+                .map(|(market_id, snapshot)| PersistedOrderBook {
+                    market_id: *market_id,
+                    asks: snapshot
+                        .get_asks_price_levels()
+                        .map(|(p, a)| (*p, *a))
+                        .collect(),
+                    bids: snapshot
+                        .get_bids_price_levels()
+                        .map(|(p, a)| (*p, *a))
+                        .collect(),
+                    last_update_time: snapshot.last_update_time,
+                })
+                .collect(),
+        };
+
+        let content = serde_json::to_string(&persisted)
+            .context("Unable to serialize order book snapshots")?;
+
+        // file is replaced at once, so it can't be read half written after crash
+        let temp_file_path = self.file_path.with_extension("tmp");
+        fs::write(&temp_file_path, content).with_context(|| {
+            format!("Unable to write order book snapshots to {temp_file_path:?}")
+        })?;
+        fs::rename(&temp_file_path, &self.file_path).with_context(|| {
+            format!(
+                "Unable to save order book snapshots to {:?}",
+                self.file_path
+            )
+        })
+    }
+
+    /// Order book snapshots saved not earlier than max age ago. Older snapshots are useless for
+    /// warm start, so their file is removed. Returns empty map if there are no saved snapshots
+    pub fn load(&self, now: DateTime) -> Result<HashMap<MarketId, LocalOrderBookSnapshot>> {
+        let content = match fs::read_to_string(&self.file_path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(HashMap::new()),
+            Err(error) => {
+                return Err(error).with_context(|| {
+                    format!(
+                        "Unable to read order book snapshots from {:?}",
+                        self.file_path
+                    )
+                })
+            }
+        };
+
+        let persisted: PersistedOrderBooks = serde_json::from_str(&content).with_context(|| {
+            format!(
+                "Unable to parse order book snapshots from {:?}",
+                self.file_path
+            )
+        })?;
+
+        if now - persisted.saved_time > self.max_age {
+            log::warn!(
+                "Order book snapshots saved at {} are too old for warm start, so they are discarded",
+                persisted.saved_time
+            );
+            fs::remove_file(&self.file_path).with_context(|| {
+                format!("Unable to remove order book snapshots {:?}", self.file_path)
+            })?;
+            return Ok(HashMap::new());
+        }
+
+        Ok(persisted
+            .order_books
+            .into_iter()
+            .map(|x| {
+                let snapshot = LocalOrderBookSnapshot::new(
+                    x.asks.into_iter().collect(),
+                    x.bids.into_iter().collect(),
+                    x.last_update_time,
+                );
+                (x.market_id, snapshot)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::order_book_data;
+    use mmb_domain::{amount, price};
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    fn persistence() -> OrderBookPersistence {
+        OrderBookPersistence::new(&OrderBookPersistenceSettings {
+            file_path: std::env::temp_dir().join(format!("order_books_{}.json", Uuid::new_v4())),
+            save_interval_secs: 1,
+            max_age_secs: 60,
+        })
+    }
+
+    fn market_id() -> MarketId {
+        MarketId::new(
+            "Binance".into(),
+            CurrencyPair::from_codes("BTC".into(), "USDT".into()),
+        )
+    }
+
+    fn local_snapshots_service(now: DateTime) -> LocalSnapshotsService {
+        let snapshot = order_book_data![
+            dec!(10.5) => dec!(3),
+            dec!(10.2) => dec!(2),
+            ;
+            dec!(10.1) => dec!(5),
+        ]
+        .to_orderbook_snapshot(now);
+
+        LocalSnapshotsService::new(HashMap::from([(market_id(), snapshot)]))
+    }
+
+    #[test]
+    fn warm_start_from_saved_snapshots() {
+        let persistence = persistence();
+        let now = Utc::now();
+
+        persistence
+            .save(&local_snapshots_service(now), now)
+            .expect("in test");
+        let snapshots = persistence
+            .load(now + chrono::Duration::seconds(10))
+            .expect("in test");
+
+        let snapshot = &snapshots[&market_id()];
+        assert_eq!(snapshot.get_top_ask(), Some((price!(10.2), amount!(2))));
+        assert_eq!(snapshot.get_top_bid(), Some((price!(10.1), amount!(5))));
+        assert_eq!(snapshot.asks.len(), 2);
+        assert_eq!(snapshot.last_update_time, now);
+
+        fs::remove_file(&persistence.file_path).expect("in test");
+    }
+
+    #[test]
+    fn discard_too_old_snapshots() {
+        let persistence = persistence();
+        let now = Utc::now();
+
+        persistence
+            .save(&local_snapshots_service(now), now)
+            .expect("in test");
+        let snapshots = persistence
+            .load(now + chrono::Duration::seconds(61))
+            .expect("in test");
+
+        assert!(snapshots.is_empty());
+        assert!(!persistence.file_path.exists());
+    }
+
+    #[test]
+    fn no_saved_snapshots() {
+        let snapshots = persistence().load(Utc::now()).expect("in test");
+
+        assert!(snapshots.is_empty());
+    }
+}
@@ -0,0 +1,180 @@
+use crate::settings::{EventMergePolicy, OrderEventsMergeSettings};
+use mmb_domain::events::EventSourceType;
+use mmb_utils::DateTime;
+
+/// Source and exchange time of order event of some kind (e.g. creation or cancellation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourcedEvent {
+    pub source_type: EventSourceType,
+    /// Time of the event on exchange side. `None` if source doesn't report it
+    pub exchange_time: Option<DateTime>,
+}
+
+impl SourcedEvent {
+    pub fn new(source_type: EventSourceType, exchange_time: Option<DateTime>) -> Self {
+        SourcedEvent {
+            source_type,
+            exchange_time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeDecision {
+    /// There is no applied event of such kind yet
+    Apply,
+    /// Received event should replace already applied one
+    Replace,
+    /// Received event should be ignored in favour of already applied one
+    Ignore,
+}
+
+/// Merges order events of the same kind received from several sources according to configured policy.
+/// E.g. WebSocket event can be applied first for speed and then be reconciled with REST one as more authoritative
+#[derive(Debug, Clone)]
+pub struct OrderEventsMerger {
+    policy: EventMergePolicy,
+    source_priority: Vec<EventSourceType>,
+}
+
+impl OrderEventsMerger {
+    pub fn new(settings: &OrderEventsMergeSettings) -> Self {
+        OrderEventsMerger {
+            policy: settings.policy,
+            source_priority: settings.source_priority.clone(),
+        }
+    }
+
+    pub fn merge(&self, applied: Option<SourcedEvent>, received: SourcedEvent) -> MergeDecision {
+        let applied = match applied {
+            None => return MergeDecision::Apply,
+            Some(applied) => applied,
+        };
+
+        let should_replace = match self.policy {
+            EventMergePolicy::FirstWins => false,
+            EventMergePolicy::LatestTimestampWins => {
+                match (applied.exchange_time, received.exchange_time) {
+                    (Some(applied_time), Some(received_time)) => received_time > applied_time,
+                    _ => false,
+                }
+            }
+            EventMergePolicy::SourcePriority => {
+                self.priority(received.source_type) < self.priority(applied.source_type)
+            }
+        };
+
+        match should_replace {
+            true => MergeDecision::Replace,
+            false => MergeDecision::Ignore,
+        }
+    }
+
+    /// Lower value means higher priority. Sources missing in settings have the lowest priority
+    fn priority(&self, source_type: EventSourceType) -> usize {
+        self.source_priority
+            .iter()
+            .position(|x| *x == source_type)
+            .unwrap_or(self.source_priority.len())
+    }
+}
+
+impl Default for OrderEventsMerger {
+    fn default() -> Self {
+        OrderEventsMerger::new(&OrderEventsMergeSettings::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use rstest::rstest;
+    use EventSourceType::*;
+
+    fn event(source_type: EventSourceType, secs: i64) -> SourcedEvent {
+        let time = Utc.timestamp_opt(secs, 0).single().expect("in test");
+        SourcedEvent::new(source_type, Some(time))
+    }
+
+    fn merger(policy: EventMergePolicy) -> OrderEventsMerger {
+        OrderEventsMerger::new(&OrderEventsMergeSettings {
+            policy,
+            ..Default::default()
+        })
+    }
+
+    #[rstest]
+    #[case::first_wins(EventMergePolicy::FirstWins)]
+    #[case::latest_timestamp_wins(EventMergePolicy::LatestTimestampWins)]
+    #[case::source_priority(EventMergePolicy::SourcePriority)]
+    fn apply_first_event(#[case] policy: EventMergePolicy) {
+        let decision = merger(policy).merge(None, event(WebSocket, 1));
+
+        assert_eq!(decision, MergeDecision::Apply);
+    }
+
+    #[rstest]
+    #[case::first_wins(EventMergePolicy::FirstWins, event(Rest, 2), MergeDecision::Ignore)]
+    #[case::latest(
+        EventMergePolicy::LatestTimestampWins,
+        event(Rest, 2),
+        MergeDecision::Replace
+    )]
+    #[case::outdated(
+        EventMergePolicy::LatestTimestampWins,
+        event(Rest, 0),
+        MergeDecision::Ignore
+    )]
+    #[case::higher_priority(
+        EventMergePolicy::SourcePriority,
+        event(Rest, 2),
+        MergeDecision::Replace
+    )]
+    #[case::same_priority(
+        EventMergePolicy::SourcePriority,
+        event(WebSocket, 2),
+        MergeDecision::Ignore
+    )]
+    #[case::lower_priority(EventMergePolicy::SourcePriority, event(Rpc, 2), MergeDecision::Ignore)]
+    fn merge_with_applied_event(
+        #[case] policy: EventMergePolicy,
+        #[case] received: SourcedEvent,
+        #[case] expected: MergeDecision,
+    ) {
+        let applied = event(WebSocket, 1);
+
+        let decision = merger(policy).merge(Some(applied), received);
+
+        assert_eq!(decision, expected);
+    }
+
+    #[rstest]
+    #[case::received_without_time(event(WebSocket, 1), SourcedEvent::new(Rest, None))]
+    #[case::applied_without_time(SourcedEvent::new(WebSocket, None), event(Rest, 2))]
+    fn ignore_event_without_exchange_time_for_latest_timestamp_wins(
+        #[case] applied: SourcedEvent,
+        #[case] received: SourcedEvent,
+    ) {
+        let decision = merger(EventMergePolicy::LatestTimestampWins).merge(Some(applied), received);
+
+        assert_eq!(decision, MergeDecision::Ignore);
+    }
+
+    #[test]
+    fn source_missing_in_priority_list_has_lowest_priority() {
+        let merger = OrderEventsMerger::new(&OrderEventsMergeSettings {
+            policy: EventMergePolicy::SourcePriority,
+            source_priority: vec![WebSocket],
+        });
+
+        assert_eq!(
+            merger.merge(Some(event(Rest, 1)), event(WebSocket, 2)),
+            MergeDecision::Replace
+        );
+        assert_eq!(
+            merger.merge(Some(event(WebSocket, 1)), event(Rest, 2)),
+            MergeDecision::Ignore
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::settings::FillEventsAggregationSettings;
+use mmb_domain::order::snapshot::{Amount, SystemInternalOrderProps};
+
+/// Holds back fill events of order until total notional of its unreported fills reaches
+/// min notional. Fills are applied to order immediately, only reporting is aggregated
+pub struct FillEventsAggregation {
+    min_notional: Amount,
+}
+
+impl FillEventsAggregation {
+    pub fn new(settings: &FillEventsAggregationSettings) -> Self {
+        Self {
+            min_notional: settings.min_notional,
+        }
+    }
+
+    /// Register applied fill. Returns count of fills which should be reported by single
+    /// fill event or `None` if reporting of fill is held back.
+    /// Fills are always reported when `force_report` is set (e.g. order is completed)
+    pub fn on_fill(
+        &self,
+        props: &mut SystemInternalOrderProps,
+        fill_notional: Amount,
+        force_report: bool,
+    ) -> Option<usize> {
+        props.unreported_fills_count += 1;
+        props.unreported_fills_notional += fill_notional.abs();
+
+        if !force_report && props.unreported_fills_notional < self.min_notional {
+            return None;
+        }
+
+        take_unreported_fills(props)
+    }
+}
+
+/// Reset unreported fills of order. Returns their count if there are any
+pub fn take_unreported_fills(props: &mut SystemInternalOrderProps) -> Option<usize> {
+    props.unreported_fills_notional = Amount::ZERO;
+    match std::mem::take(&mut props.unreported_fills_count) {
+        0 => None,
+        fills_count => Some(fills_count),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use mmb_domain::amount;
+
+    fn aggregation() -> FillEventsAggregation {
+        FillEventsAggregation::new(&FillEventsAggregationSettings {
+            min_notional: amount!(10),
+        })
+    }
+
+    #[test]
+    fn tiny_fills_are_reported_once_accumulated() {
+        let aggregation = aggregation();
+        let mut props = SystemInternalOrderProps::default();
+
+        assert_eq!(aggregation.on_fill(&mut props, amount!(4), false), None);
+        assert_eq!(aggregation.on_fill(&mut props, amount!(4), false), None);
+        assert_eq!(aggregation.on_fill(&mut props, amount!(4), false), Some(3));
+        assert_eq!(props.unreported_fills_count, 0);
+        assert_eq!(props.unreported_fills_notional, amount!(0));
+
+        assert_eq!(aggregation.on_fill(&mut props, amount!(15), false), Some(1));
+    }
+
+    #[test]
+    fn held_back_fills_are_reported_by_force() {
+        let aggregation = aggregation();
+        let mut props = SystemInternalOrderProps::default();
+
+        assert_eq!(aggregation.on_fill(&mut props, amount!(1), false), None);
+        assert_eq!(aggregation.on_fill(&mut props, amount!(1), true), Some(2));
+        assert_eq!(take_unreported_fills(&mut props), None);
+    }
+
+    #[test]
+    fn take_unreported_fills_resets_them() {
+        let aggregation = aggregation();
+        let mut props = SystemInternalOrderProps::default();
+
+        assert_eq!(aggregation.on_fill(&mut props, amount!(1), false), None);
+        assert_eq!(take_unreported_fills(&mut props), Some(1));
+        assert_eq!(take_unreported_fills(&mut props), None);
+    }
+}
@@ -0,0 +1,207 @@
+use crate::settings::IdempotencyCacheSettings;
+use mmb_domain::order::pool::OrderRef;
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter};
+
+/// Token supplied by strategy together with order intent to detect logic-level duplicates
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct IdempotencyToken(String);
+
+impl IdempotencyToken {
+    pub fn new(token: impl Into<String>) -> Self {
+        IdempotencyToken(token.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for IdempotencyToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<&str> for IdempotencyToken {
+    fn from(value: &str) -> Self {
+        IdempotencyToken(value.to_owned())
+    }
+}
+
+pub enum IdempotencyCacheEntry {
+    /// Order intent with the same token was already submitted within TTL
+    Cached(OrderRef),
+    /// Order intent is seen for the first time and order was added to cache
+    New(OrderRef),
+}
+
+#[derive(Default)]
+struct CacheState {
+    orders: HashMap<IdempotencyToken, (OrderRef, DateTime)>,
+    // tokens in insertion order for eviction of the oldest entries
+    insertion_order: VecDeque<IdempotencyToken>,
+}
+
+/// Cache of orders by idempotency token that prevents duplicate submission of the same
+/// order intent within TTL
+pub struct IdempotencyCache {
+    ttl: chrono::Duration,
+    max_size: usize,
+    state: Mutex<CacheState>,
+}
+
+impl IdempotencyCache {
+    pub fn new(settings: &IdempotencyCacheSettings) -> Self {
+        Self {
+            ttl: chrono::Duration::seconds(settings.ttl_secs as i64),
+            max_size: settings.max_size.max(1),
+            state: Default::default(),
+        }
+    }
+
+    /// Returns cached order for token if it was added not earlier than TTL ago,
+    /// otherwise creates order with `create_order` and puts it to cache
+    pub fn get_or_insert_with(
+        &self,
+        token: &IdempotencyToken,
+        now: DateTime,
+        create_order: impl FnOnce() -> OrderRef,
+    ) -> IdempotencyCacheEntry {
+        let mut state = self.state.lock();
+        self.remove_expired(&mut state, now);
+
+        if let Some((order, _)) = state.orders.get(token) {
+            return IdempotencyCacheEntry::Cached(order.clone());
+        }
+
+        while state.orders.len() >= self.max_size {
+            match state.insertion_order.pop_front() {
+                Some(oldest_token) => {
+                    let _ = state.orders.remove(&oldest_token);
+                }
+                None => break,
+            }
+        }
+
+        let order = create_order();
+        let _ = state.orders.insert(token.clone(), (order.clone(), now));
+        state.insertion_order.push_back(token.clone());
+
+        IdempotencyCacheEntry::New(order)
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn remove_expired(&self, state: &mut CacheState, now: DateTime) {
+        while let Some(token) = state.insertion_order.front() {
+            let is_expired = state
+                .orders
+                .get(token)
+                .map(|(_, added_time)| now - *added_time >= self.ttl)
+                .unwrap_or(true);
+
+            if !is_expired {
+                break;
+            }
+
+            if let Some(token) = state.insertion_order.pop_front() {
+                let _ = state.orders.remove(&token);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::general::test_helper::create_order_ref;
+    use chrono::{Duration, Utc};
+    use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+    use mmb_domain::order::snapshot::{ClientOrderId, OrderSide};
+
+    use mmb_domain::{amount, price};
+
+    fn order_ref() -> OrderRef {
+        create_order_ref(
+            &ClientOrderId::unique_id(),
+            None,
+            ExchangeAccountId::new("Binance", 0),
+            CurrencyPair::from_codes("eth".into(), "btc".into()),
+            price!(1),
+            amount!(1),
+            OrderSide::Buy,
+        )
+    }
+
+    fn cache(ttl_secs: u64, max_size: usize) -> IdempotencyCache {
+        IdempotencyCache::new(&IdempotencyCacheSettings { ttl_secs, max_size })
+    }
+
+    fn is_cached(entry: &IdempotencyCacheEntry) -> bool {
+        matches!(entry, IdempotencyCacheEntry::Cached(_))
+    }
+
+    #[test]
+    fn return_cached_order_within_ttl() {
+        let cache = cache(10, 10);
+        let token = IdempotencyToken::from("token");
+        let now = Utc::now();
+
+        let first = cache.get_or_insert_with(&token, now, order_ref);
+        let second = cache.get_or_insert_with(&token, now + Duration::seconds(5), || {
+            panic!("order should be taken from cache")
+        });
+
+        assert!(!is_cached(&first));
+        assert!(is_cached(&second));
+        let (IdempotencyCacheEntry::New(first) | IdempotencyCacheEntry::Cached(first)) = first;
+        let (IdempotencyCacheEntry::New(second) | IdempotencyCacheEntry::Cached(second)) = second;
+        assert_eq!(first.client_order_id(), second.client_order_id());
+    }
+
+    #[test]
+    fn create_new_order_after_ttl_expired() {
+        let cache = cache(10, 10);
+        let token = IdempotencyToken::from("token");
+        let now = Utc::now();
+
+        let _ = cache.get_or_insert_with(&token, now, order_ref);
+        let entry = cache.get_or_insert_with(&token, now + Duration::seconds(10), order_ref);
+
+        assert!(!is_cached(&entry));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn evict_oldest_order_when_cache_is_full() {
+        let cache = cache(10, 2);
+        let now = Utc::now();
+
+        for token in ["first", "second", "third"] {
+            let _ = cache.get_or_insert_with(&token.into(), now, order_ref);
+        }
+
+        assert_eq!(cache.len(), 2);
+        assert!(!is_cached(&cache.get_or_insert_with(
+            &"first".into(),
+            now,
+            order_ref
+        )));
+        assert!(is_cached(&cache.get_or_insert_with(
+            &"third".into(),
+            now,
+            order_ref
+        )));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::settings::{MinOrderLifetimeSettings, PerCurrencyPair};
+use mmb_domain::market::CurrencyPair;
+use mmb_utils::DateTime;
+use std::time::Duration;
+
+/// Min time which order should rest on exchange before it can be cancelled by strategy
+pub struct MinOrderLifetime {
+    lifetime: PerCurrencyPair<Duration>,
+}
+
+impl MinOrderLifetime {
+    pub fn new(settings: &MinOrderLifetimeSettings) -> Self {
+        Self {
+            lifetime: PerCurrencyPair::new(
+                settings.default_ms.map(Duration::from_millis),
+                settings
+                    .currency_pairs
+                    .iter()
+                    .map(|x| (x.currency_pair, Duration::from_millis(x.lifetime_ms))),
+            ),
+        }
+    }
+
+    pub fn get(&self, currency_pair: CurrencyPair) -> Option<Duration> {
+        self.lifetime.get(currency_pair)
+    }
+
+    /// Time left until order created at `init_time` reaches min lifetime.
+    /// `None` if order can be cancelled right now
+    pub fn remaining(
+        &self,
+        currency_pair: CurrencyPair,
+        init_time: DateTime,
+        now: DateTime,
+    ) -> Option<Duration> {
+        let lifetime = self.get(currency_pair)?;
+        let rested = (now - init_time).to_std().unwrap_or_default();
+
+        lifetime.checked_sub(rested).filter(|x| !x.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::CurrencyPairMinOrderLifetime;
+    use chrono::Utc;
+
+    fn min_order_lifetime() -> MinOrderLifetime {
+        MinOrderLifetime::new(&MinOrderLifetimeSettings {
+            default_ms: Some(1_000),
+            currency_pairs: vec![CurrencyPairMinOrderLifetime {
+                currency_pair: CurrencyPair::from_codes("btc".into(), "usdt".into()),
+                lifetime_ms: 5_000,
+            }],
+        })
+    }
+
+    #[test]
+    fn specific_lifetime_overrides_default() {
+        let min_order_lifetime = min_order_lifetime();
+
+        assert_eq!(
+            min_order_lifetime.get(CurrencyPair::from_codes("btc".into(), "usdt".into())),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            min_order_lifetime.get(CurrencyPair::from_codes("eth".into(), "usdt".into())),
+            Some(Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn remaining_lifetime() {
+        let min_order_lifetime = min_order_lifetime();
+        let currency_pair = CurrencyPair::from_codes("btc".into(), "usdt".into());
+        let init_time = Utc::now();
+
+        assert_eq!(
+            min_order_lifetime.remaining(
+                currency_pair,
+                init_time,
+                init_time + chrono::Duration::seconds(2)
+            ),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(
+            min_order_lifetime.remaining(
+                currency_pair,
+                init_time,
+                init_time + chrono::Duration::seconds(5)
+            ),
+            None
+        );
+        assert_eq!(
+            min_order_lifetime.remaining(
+                currency_pair,
+                init_time,
+                init_time + chrono::Duration::seconds(7)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn no_lifetime_without_settings_for_currency_pair() {
+        let min_order_lifetime = MinOrderLifetime::new(&MinOrderLifetimeSettings {
+            default_ms: None,
+            currency_pairs: vec![],
+        });
+        let now = Utc::now();
+
+        assert_eq!(
+            min_order_lifetime.remaining(
+                CurrencyPair::from_codes("btc".into(), "usdt".into()),
+                now,
+                now
+            ),
+            None
+        );
+    }
+}
@@ -1 +1,12 @@
 pub mod buffered_fills;
+pub mod event_merge;
+pub mod fill_aggregation;
+pub mod idempotency_cache;
+pub mod min_order_lifetime;
+pub mod multi_account;
+pub mod near_cross;
+pub mod order_rate_limiter;
+pub mod price_band;
+pub mod replace_price;
+pub mod retry_budget;
+pub mod working_exposure;
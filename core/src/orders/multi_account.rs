@@ -0,0 +1,383 @@
+use crate::lifecycle::trading_engine::EngineContext;
+use crate::service_configuration::configuration_descriptor::ConfigurationDescriptor;
+use anyhow::{bail, Context, Result};
+use futures::future::join_all;
+use itertools::Itertools;
+use mmb_domain::exchanges::symbol::{Round, Symbol};
+use mmb_domain::market::{CurrencyCode, ExchangeAccountId};
+use mmb_domain::order::execution_report::ExecutionReport;
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::{Amount, ClientOrderId, OrderHeader, Price};
+use mmb_utils::cancellation_token::CancellationToken;
+use std::collections::HashMap;
+
+/// Part of parent order which is placed on a single exchange account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChildAllocation {
+    pub exchange_account_id: ExchangeAccountId,
+    pub amount: Amount,
+}
+
+/// Split amount of parent order across exchange accounts pro-rata to their available balances
+/// (in amount currency). Amount above total available balance isn't allocated, rounding
+/// remainder goes to accounts with the largest balances and allocations less than
+/// min amount of symbol are dropped
+pub fn allocate_pro_rata(
+    symbol: &Symbol,
+    amount: Amount,
+    available_balances: &[(ExchangeAccountId, Amount)],
+) -> Vec<ChildAllocation> {
+    let balances = available_balances
+        .iter()
+        .map(|&(exchange_account_id, balance)| {
+            (
+                exchange_account_id,
+                symbol.amount_round(balance, Round::Floor),
+            )
+        })
+        .filter(|(_, balance)| *balance > Amount::ZERO)
+        .collect_vec();
+
+    let total_balance: Amount = balances.iter().map(|(_, balance)| balance).sum();
+    if total_balance.is_zero() {
+        return vec![];
+    }
+
+    let target_amount = symbol.amount_round(amount.min(total_balance), Round::Floor);
+
+    let mut allocations = balances
+        .iter()
+        .map(|&(exchange_account_id, balance)| {
+            let share = target_amount * balance.value() / total_balance.value();
+            ChildAllocation {
+                exchange_account_id,
+                amount: symbol.amount_round(share, Round::Floor).min(balance),
+            }
+        })
+        .collect_vec();
+
+    let mut remainder = target_amount - allocations.iter().map(|x| x.amount).sum::<Amount>();
+    let by_balance_desc = (0..balances.len()).sorted_by(|&l, &r| balances[r].1.cmp(&balances[l].1));
+    for index in by_balance_desc {
+        if remainder.is_zero() {
+            break;
+        }
+
+        let capacity = balances[index].1 - allocations[index].amount;
+        let addition = remainder.min(capacity);
+        allocations[index].amount += addition;
+        remainder -= addition;
+    }
+
+    allocations
+        .into_iter()
+        .filter(|x| !x.amount.is_zero() && symbol.min_amount.map_or(true, |min| x.amount >= min))
+        .collect()
+}
+
+/// Parent order allocated across several exchange accounts as child orders
+#[derive(Debug, Clone)]
+pub struct MultiAccountOrder {
+    pub parent_order_id: ClientOrderId,
+    pub children: Vec<OrderRef>,
+}
+
+impl MultiAccountOrder {
+    pub fn is_finished(&self) -> bool {
+        self.children.iter().all(|x| x.is_finished())
+    }
+
+    pub fn execution_report(&self) -> MultiAccountExecutionReport {
+        MultiAccountExecutionReport::from_reports(
+            self.parent_order_id.clone(),
+            self.children
+                .iter()
+                .map(|x| (x.exchange_account_id(), x.execution_report()))
+                .collect(),
+        )
+    }
+}
+
+/// Fills of child orders per exchange account and aggregated over all of them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiAccountExecutionReport {
+    pub parent_order_id: ClientOrderId,
+    pub by_account: Vec<(ExchangeAccountId, ExecutionReport)>,
+    pub total_filled_amount: Amount,
+    /// Volume weighted average price of fills over all accounts. `None` if there are no fills
+    pub average_fill_price: Option<Price>,
+    /// Total commission amount over all accounts grouped by commission currency
+    pub commissions: HashMap<CurrencyCode, Amount>,
+    pub fills_count: usize,
+}
+
+impl MultiAccountExecutionReport {
+    pub fn from_reports(
+        parent_order_id: ClientOrderId,
+        by_account: Vec<(ExchangeAccountId, ExecutionReport)>,
+    ) -> Self {
+        let mut total_filled_amount = Amount::ZERO;
+        let mut total_cost = Amount::ZERO;
+        let mut commissions = HashMap::new();
+        let mut fills_count = 0;
+        for (_, report) in &by_account {
+            total_filled_amount += report.total_filled_amount;
+            total_cost +=
+                report.average_fill_price.unwrap_or_default() * report.total_filled_amount;
+            fills_count += report.fills_count;
+            for (&currency_code, &amount) in &report.commissions {
+                *commissions.entry(currency_code).or_insert(Amount::ZERO) += amount;
+            }
+        }
+
+        let average_fill_price =
+            (!total_filled_amount.is_zero()).then(|| Price::new(total_cost / total_filled_amount));
+
+        Self {
+            parent_order_id,
+            by_account,
+            total_filled_amount,
+            average_fill_price,
+            commissions,
+            fills_count,
+        }
+    }
+}
+
+/// Allocate parent order across specified exchange accounts pro-rata to their available balances
+/// and create child orders on all of them concurrently. Every child order passes through rate
+/// limits of its own exchange account, so a throttled account doesn't delay the others.
+/// Exchange account of `parent_header` is ignored and its price is used for balance evaluation.
+/// Fails only if no child order is created
+pub async fn create_multi_account_order(
+    engine_context: &EngineContext,
+    configuration_descriptor: ConfigurationDescriptor,
+    parent_header: &OrderHeader,
+    exchange_account_ids: &[ExchangeAccountId],
+    cancellation_token: CancellationToken,
+) -> Result<MultiAccountOrder> {
+    let parent_order_id = ClientOrderId::unique_id();
+    let price = parent_header.source_price.with_context(|| {
+        format!("Price of multi account order {parent_order_id} should be specified")
+    })?;
+
+    let mut symbol = None;
+    let mut available_balances = Vec::with_capacity(exchange_account_ids.len());
+    for &exchange_account_id in exchange_account_ids {
+        let exchange = engine_context
+            .exchanges
+            .get(&exchange_account_id)
+            .with_context(|| format!("Exchange {exchange_account_id} isn't found"))?
+            .clone();
+        let account_symbol = exchange.get_symbol(parent_header.currency_pair)?;
+
+        let balance = engine_context
+            .balance_manager
+            .lock()
+            .get_leveraged_balance_in_amount_currency_code(
+                configuration_descriptor,
+                parent_header.side,
+                exchange_account_id,
+                account_symbol.clone(),
+                price,
+                &mut None,
+            )
+            .unwrap_or_default();
+        available_balances.push((exchange_account_id, balance));
+        symbol.get_or_insert(account_symbol);
+    }
+
+    let symbol = symbol.context("Multi account order requires at least one exchange account")?;
+    let allocations = allocate_pro_rata(&symbol, parent_header.amount, &available_balances);
+    if allocations.is_empty() {
+        bail!(
+            "There is no available balance for multi account order {parent_order_id} on {}",
+            exchange_account_ids.iter().join(", ")
+        );
+    }
+
+    log::info!(
+        "Multi account order {parent_order_id} with amount {} is allocated as {allocations:?}",
+        parent_header.amount
+    );
+
+    let child_orders = allocations.iter().map(|allocation| {
+        let child_header = OrderHeader {
+            client_order_id: ClientOrderId::unique_id(),
+            exchange_account_id: allocation.exchange_account_id,
+            amount: allocation.amount,
+            reservation_id: None,
+            ..parent_header.clone()
+        };
+        let exchange = engine_context
+            .exchanges
+            .get(&allocation.exchange_account_id)
+            .map(|x| x.clone());
+        let cancellation_token = cancellation_token.clone();
+
+        async move {
+            let exchange = exchange.context("Exchange of child order isn't found")?;
+            exchange
+                .create_order(&child_header, None, cancellation_token)
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to create child order {} of multi account order on {}",
+                        child_header.client_order_id, child_header.exchange_account_id
+                    )
+                })
+        }
+    });
+
+    let mut children = Vec::with_capacity(allocations.len());
+    for result in join_all(child_orders).await {
+        match result {
+            Ok(order) => children.push(order),
+            Err(error) => log::error!("{error:?}"),
+        }
+    }
+
+    if children.is_empty() {
+        bail!("None of child orders of multi account order {parent_order_id} is created");
+    }
+
+    Ok(MultiAccountOrder {
+        parent_order_id,
+        children,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::exchanges::symbol::Precision;
+    use mmb_domain::order::snapshot::OrderStatus;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn symbol() -> Symbol {
+        let base: CurrencyCode = "BTC".into();
+        let quote: CurrencyCode = "USDT".into();
+
+        Symbol::new(
+            false,
+            base.as_str().into(),
+            base,
+            quote.as_str().into(),
+            quote,
+            None,
+            None,
+            Some(amount!(0.1)),
+            None,
+            None,
+            base,
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            Precision::ByTick { tick: dec!(0.001) },
+        )
+    }
+
+    fn account(number: u8) -> ExchangeAccountId {
+        ExchangeAccountId::new("Binance", number)
+    }
+
+    #[rstest]
+    #[case::pro_rata(amount!(1), vec![amount!(3), amount!(1)], vec![amount!(0.75), amount!(0.25)])]
+    #[case::remainder_to_largest(amount!(1), vec![amount!(1), amount!(2)], vec![amount!(0.333), amount!(0.667)])]
+    #[case::rounding_remainder(amount!(1), vec![amount!(1), amount!(1), amount!(1)], vec![amount!(0.334), amount!(0.333), amount!(0.333)])]
+    #[case::limited_by_balances(amount!(1), vec![amount!(0.5), amount!(0.2)], vec![amount!(0.5), amount!(0.2)])]
+    fn allocate_amount(
+        #[case] amount: Amount,
+        #[case] balances: Vec<Amount>,
+        #[case] expected: Vec<Amount>,
+    ) {
+        let balances = balances
+            .into_iter()
+            .enumerate()
+            .map(|(i, balance)| (account(i as u8), balance))
+            .collect_vec();
+
+        let allocations = allocate_pro_rata(&symbol(), amount, &balances);
+
+        let expected = expected
+            .into_iter()
+            .enumerate()
+            .map(|(i, amount)| ChildAllocation {
+                exchange_account_id: account(i as u8),
+                amount,
+            })
+            .collect_vec();
+        assert_eq!(allocations, expected);
+    }
+
+    #[test]
+    fn skip_accounts_without_balance_or_below_min_amount() {
+        let balances = [
+            (account(0), amount!(10)),
+            (account(1), amount!(0)),
+            (account(2), amount!(0.5)),
+        ];
+
+        let allocations = allocate_pro_rata(&symbol(), amount!(1), &balances);
+
+        assert_eq!(
+            allocations,
+            vec![ChildAllocation {
+                exchange_account_id: account(0),
+                amount: amount!(0.953),
+            }]
+        );
+    }
+
+    fn report(
+        filled_amount: Amount,
+        average_fill_price: Option<Price>,
+        commission: Amount,
+    ) -> ExecutionReport {
+        ExecutionReport {
+            client_order_id: ClientOrderId::unique_id(),
+            status: OrderStatus::Created,
+            total_filled_amount: filled_amount,
+            average_fill_price,
+            commissions: HashMap::from([("USDT".into(), commission)]),
+            execution_duration: None,
+            fills_count: usize::from(!filled_amount.is_zero()),
+        }
+    }
+
+    #[test]
+    fn aggregate_fills_of_accounts() {
+        let aggregated = MultiAccountExecutionReport::from_reports(
+            ClientOrderId::unique_id(),
+            vec![
+                (
+                    account(0),
+                    report(amount!(3), Some(price!(100)), amount!(0.3)),
+                ),
+                (
+                    account(1),
+                    report(amount!(1), Some(price!(104)), amount!(0.1)),
+                ),
+                (account(2), report(amount!(0), None, amount!(0))),
+            ],
+        );
+
+        assert_eq!(aggregated.total_filled_amount, amount!(4));
+        assert_eq!(aggregated.average_fill_price, Some(price!(101)));
+        assert_eq!(aggregated.commissions[&"USDT".into()], amount!(0.4));
+        assert_eq!(aggregated.fills_count, 2);
+        assert_eq!(aggregated.by_account.len(), 3);
+    }
+
+    #[test]
+    fn no_fills_of_accounts() {
+        let aggregated = MultiAccountExecutionReport::from_reports(
+            ClientOrderId::unique_id(),
+            vec![(account(0), report(amount!(0), None, amount!(0)))],
+        );
+
+        assert_eq!(aggregated.total_filled_amount, amount!(0));
+        assert_eq!(aggregated.average_fill_price, None);
+    }
+}
@@ -0,0 +1,91 @@
+use mmb_domain::order::snapshot::{ClientOrderId, OrderHeader, OrderSide, Price};
+
+/// Own open order of opposite side which can be matched by new order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossedOrder {
+    pub client_order_id: ClientOrderId,
+    pub price: Price,
+}
+
+/// Find own open order of the same currency pair and opposite side which price overlaps with
+/// price of new order: buy at or above own sell, sell at or below own buy. If there are several
+/// of them, the one overlapped the most is returned. Orders without price aren't checked
+pub fn find_crossed_order<'a>(
+    order_header: &OrderHeader,
+    open_orders: impl IntoIterator<Item = &'a OrderHeader>,
+) -> Option<CrossedOrder> {
+    let price = order_header.source_price?;
+
+    let crossed_orders = open_orders.into_iter().filter_map(|open_order| {
+        let is_opposite = open_order.currency_pair == order_header.currency_pair
+            && open_order.side != order_header.side
+            && open_order.client_order_id != order_header.client_order_id;
+        let open_price = open_order.source_price.filter(|_| is_opposite)?;
+
+        let is_crossed = match order_header.side {
+            OrderSide::Buy => price >= open_price,
+            OrderSide::Sell => price <= open_price,
+        };
+        is_crossed.then(|| CrossedOrder {
+            client_order_id: open_order.client_order_id.clone(),
+            price: open_price,
+        })
+    });
+
+    match order_header.side {
+        OrderSide::Buy => crossed_orders.min_by_key(|x| x.price),
+        OrderSide::Sell => crossed_orders.max_by_key(|x| x.price),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+    use mmb_domain::order::snapshot::UserOrder;
+    use rstest::rstest;
+
+    use mmb_domain::{amount, price};
+
+    fn order_header(client_order_id: &str, side: OrderSide, price: Price) -> OrderHeader {
+        OrderHeader::with_user_order(
+            client_order_id.into(),
+            ExchangeAccountId::new("Binance", 0),
+            CurrencyPair::from_codes("btc".into(), "usdt".into()),
+            side,
+            amount!(1),
+            UserOrder::limit(price),
+            None,
+            None,
+            "test".to_owned(),
+        )
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, price!(99), None)]
+    #[case(OrderSide::Buy, price!(100), Some(("sell_1", price!(100))))]
+    #[case(OrderSide::Buy, price!(105), Some(("sell_1", price!(100))))]
+    #[case(OrderSide::Sell, price!(91), None)]
+    #[case(OrderSide::Sell, price!(90), Some(("buy_2", price!(90))))]
+    #[case(OrderSide::Sell, price!(80), Some(("buy_2", price!(90))))]
+    fn find_order_crossed_by_price(
+        #[case] side: OrderSide,
+        #[case] price: Price,
+        #[case] expected: Option<(&str, Price)>,
+    ) {
+        let open_orders = [
+            order_header("sell_1", OrderSide::Sell, price!(100)),
+            order_header("sell_2", OrderSide::Sell, price!(102)),
+            order_header("buy_1", OrderSide::Buy, price!(85)),
+            order_header("buy_2", OrderSide::Buy, price!(90)),
+        ];
+
+        let crossed_order = find_crossed_order(&order_header("new", side, price), &open_orders);
+
+        let expected = expected.map(|(client_order_id, price)| CrossedOrder {
+            client_order_id: client_order_id.into(),
+            price,
+        });
+        assert_eq!(crossed_order, expected);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::settings::OrderRateLimitSettings;
+use mmb_domain::events::{OrderRateLimitStatus, RateLimitKind, RateLimitUsage};
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::time::Duration;
+
+/// Orders counted in fixed window of exchange order count limit interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct IntervalCounter {
+    interval: Duration,
+    limit: usize,
+    used: usize,
+    /// Number of interval window since Unix epoch which `used` is counted for
+    window: i64,
+}
+
+impl IntervalCounter {
+    /// Start counting from zero if window of `used` is already over
+    fn roll(&mut self, now: DateTime) {
+        let window = window_number(self.interval, now);
+        if window != self.window {
+            self.window = window;
+            self.used = 0;
+        }
+    }
+}
+
+fn window_number(interval: Duration, time: DateTime) -> i64 {
+    time.timestamp_millis() / (interval.as_millis().max(1) as i64)
+}
+
+/// Order count limit which usage reached max usage ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OrderRateLimitReached {
+    pub interval: Duration,
+    pub used: usize,
+    pub limit: usize,
+}
+
+/// Counts of orders in intervals of account order count limits. Counts are replaced with
+/// authoritative figures whenever exchange reports them, so local counting only covers orders
+/// sent since the last report
+pub struct OrderRateLimiter {
+    /// Share of limit after which new orders are rejected locally
+    max_usage_ratio: Decimal,
+    counters: Mutex<Vec<IntervalCounter>>,
+}
+
+impl OrderRateLimiter {
+    pub fn new(settings: &OrderRateLimitSettings) -> Self {
+        Self {
+            max_usage_ratio: settings.max_usage_ratio,
+            counters: Default::default(),
+        }
+    }
+
+    /// Replace limits and counts of orders with ones reported by exchange.
+    /// Local count is kept for intervals which exchange reports only limit for
+    pub fn sync_status(&self, status: &OrderRateLimitStatus, now: DateTime) {
+        let mut counters = self.counters.lock();
+        let synced = status
+            .limits
+            .iter()
+            .map(|x| {
+                let local_used = || {
+                    let mut counter = *counters.iter().find(|c| c.interval == x.interval)?;
+                    counter.roll(now);
+                    Some(counter.used)
+                };
+
+                IntervalCounter {
+                    interval: x.interval,
+                    limit: x.limit,
+                    used: x.used.or_else(local_used).unwrap_or_default(),
+                    window: window_number(x.interval, now),
+                }
+            })
+            .collect();
+
+        *counters = synced;
+    }
+
+    /// Replace counts of orders with ones reported by exchange in response headers
+    pub fn sync_usage(&self, usage: &RateLimitUsage, now: DateTime) {
+        for counter in self.counters.lock().iter_mut() {
+            if let Some(reported) = usage.get(RateLimitKind::OrdersCount, counter.interval) {
+                counter.window = window_number(counter.interval, now);
+                counter.used = reported.used;
+            }
+        }
+    }
+
+    /// Count order sent to exchange
+    pub fn register_order(&self, now: DateTime) {
+        for counter in self.counters.lock().iter_mut() {
+            counter.roll(now);
+            counter.used += 1;
+        }
+    }
+
+    /// Limit which usage reached max usage ratio, so new order shouldn't be sent.
+    /// Returns `None` if order can be sent
+    pub fn check(&self, now: DateTime) -> Option<OrderRateLimitReached> {
+        self.counters.lock().iter_mut().find_map(|counter| {
+            counter.roll(now);
+            let is_reached =
+                Decimal::from(counter.used) >= Decimal::from(counter.limit) * self.max_usage_ratio;
+            is_reached.then_some(OrderRateLimitReached {
+                interval: counter.interval,
+                used: counter.used,
+                limit: counter.limit,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use mmb_domain::events::{OrderRateLimit, RateLimitCounter};
+    use rust_decimal_macros::dec;
+
+    const INTERVAL: Duration = Duration::from_secs(10);
+
+    fn limiter(used: Option<usize>, now: DateTime) -> OrderRateLimiter {
+        let limiter = OrderRateLimiter::new(&OrderRateLimitSettings {
+            refresh_interval_secs: 60,
+            max_usage_ratio: dec!(0.8),
+        });
+        limiter.sync_status(
+            &OrderRateLimitStatus {
+                limits: vec![OrderRateLimit {
+                    interval: INTERVAL,
+                    used,
+                    limit: 10,
+                }],
+            },
+            now,
+        );
+        limiter
+    }
+
+    fn window_start() -> DateTime {
+        Utc.timestamp_millis_opt(1_700_000_000_000)
+            .single()
+            .expect("in test")
+    }
+
+    #[test]
+    fn orders_are_rejected_near_limit() {
+        let now = window_start();
+        let limiter = limiter(Some(7), now);
+        assert_eq!(limiter.check(now), None);
+
+        limiter.register_order(now);
+        assert_eq!(
+            limiter.check(now),
+            Some(OrderRateLimitReached {
+                interval: INTERVAL,
+                used: 8,
+                limit: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn count_is_reset_in_next_window() {
+        let now = window_start();
+        let limiter = limiter(Some(9), now);
+        assert!(limiter.check(now).is_some());
+
+        assert_eq!(limiter.check(now + chrono::Duration::seconds(10)), None);
+    }
+
+    #[test]
+    fn reported_usage_replaces_local_count() {
+        let now = window_start();
+        let limiter = limiter(Some(9), now);
+
+        limiter.sync_usage(
+            &RateLimitUsage {
+                counters: vec![RateLimitCounter {
+                    kind: RateLimitKind::OrdersCount,
+                    interval: INTERVAL,
+                    used: 1,
+                }],
+            },
+            now,
+        );
+        assert_eq!(limiter.check(now), None);
+    }
+
+    #[test]
+    fn local_count_is_kept_if_exchange_reports_only_limit() {
+        let now = window_start();
+        let limiter = limiter(Some(8), now);
+
+        limiter.sync_status(
+            &OrderRateLimitStatus {
+                limits: vec![OrderRateLimit {
+                    interval: INTERVAL,
+                    used: None,
+                    limit: 20,
+                }],
+            },
+            now,
+        );
+        assert_eq!(limiter.check(now), None);
+
+        for _ in 0..8 {
+            limiter.register_order(now);
+        }
+        assert!(limiter.check(now).is_some());
+    }
+}
@@ -0,0 +1,150 @@
+use crate::settings::{PerCurrencyPair, PriceBandAction, PriceBandReference, PriceBandSettings};
+use mmb_domain::events::MarkPriceEvent;
+use mmb_domain::market::CurrencyPair;
+use mmb_domain::order::snapshot::Price;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceBandCheck {
+    Inside,
+    /// Order price should be replaced with the nearest band boundary
+    Clamped(Price),
+    Rejected {
+        lower: Price,
+        upper: Price,
+    },
+}
+
+/// Band around mark or index price of futures contract which limit order price should be within
+pub struct PriceBand {
+    max_deviation: PerCurrencyPair<Decimal>,
+    reference: PriceBandReference,
+    action: PriceBandAction,
+}
+
+impl PriceBand {
+    pub fn new(settings: &PriceBandSettings) -> Self {
+        Self {
+            max_deviation: PerCurrencyPair::new(
+                settings.default_max_deviation,
+                settings
+                    .currency_pairs
+                    .iter()
+                    .map(|x| (x.currency_pair, x.max_deviation)),
+            ),
+            reference: settings.reference,
+            action: settings.action,
+        }
+    }
+
+    pub fn max_deviation(&self, currency_pair: CurrencyPair) -> Option<Decimal> {
+        self.max_deviation.get(currency_pair)
+    }
+
+    /// Check order price against band around reference price from `mark_price`.
+    /// Order is inside band if band isn't configured for currency pair
+    pub fn check(
+        &self,
+        currency_pair: CurrencyPair,
+        price: Price,
+        mark_price: &MarkPriceEvent,
+    ) -> PriceBandCheck {
+        let max_deviation = match self.max_deviation(currency_pair) {
+            None => return PriceBandCheck::Inside,
+            Some(max_deviation) => max_deviation,
+        };
+
+        let reference_price = match self.reference {
+            PriceBandReference::MarkPrice => mark_price.mark_price,
+            PriceBandReference::IndexPrice => mark_price.index_price,
+        };
+
+        let lower = reference_price * (Decimal::ONE - max_deviation);
+        let upper = reference_price * (Decimal::ONE + max_deviation);
+        let boundary = if price < lower {
+            lower
+        } else if price > upper {
+            upper
+        } else {
+            return PriceBandCheck::Inside;
+        };
+
+        match self.action {
+            PriceBandAction::Reject => PriceBandCheck::Rejected { lower, upper },
+            PriceBandAction::Clamp => PriceBandCheck::Clamped(boundary),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::CurrencyPairPriceBand;
+    use chrono::Utc;
+    use mmb_domain::market::ExchangeAccountId;
+    use mmb_domain::price;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn currency_pair() -> CurrencyPair {
+        CurrencyPair::from_codes("btc".into(), "usdt".into())
+    }
+
+    fn mark_price() -> MarkPriceEvent {
+        MarkPriceEvent {
+            exchange_account_id: ExchangeAccountId::new("Binance", 0),
+            currency_pair: currency_pair(),
+            mark_price: price!(100),
+            index_price: price!(200),
+            funding_rate: dec!(0),
+            next_funding_time: Utc::now(),
+        }
+    }
+
+    fn price_band(reference: PriceBandReference, action: PriceBandAction) -> PriceBand {
+        PriceBand::new(&PriceBandSettings {
+            default_max_deviation: Some(dec!(0.5)),
+            currency_pairs: vec![CurrencyPairPriceBand {
+                currency_pair: currency_pair(),
+                max_deviation: dec!(0.1),
+            }],
+            reference,
+            action,
+        })
+    }
+
+    #[rstest]
+    #[case(price!(105), PriceBandCheck::Inside)]
+    #[case(price!(111), PriceBandCheck::Rejected { lower: price!(90), upper: price!(110) })]
+    #[case(price!(89), PriceBandCheck::Rejected { lower: price!(90), upper: price!(110) })]
+    fn reject_outside_band(#[case] price: Price, #[case] expected: PriceBandCheck) {
+        let band = price_band(PriceBandReference::MarkPrice, PriceBandAction::Reject);
+
+        assert_eq!(band.check(currency_pair(), price, &mark_price()), expected);
+    }
+
+    #[rstest]
+    #[case(price!(250), PriceBandCheck::Clamped(price!(220)))]
+    #[case(price!(150), PriceBandCheck::Clamped(price!(180)))]
+    #[case(price!(200), PriceBandCheck::Inside)]
+    fn clamp_to_index_price_band(#[case] price: Price, #[case] expected: PriceBandCheck) {
+        let band = price_band(PriceBandReference::IndexPrice, PriceBandAction::Clamp);
+
+        assert_eq!(band.check(currency_pair(), price, &mark_price()), expected);
+    }
+
+    #[test]
+    fn inside_band_if_not_configured_for_currency_pair() {
+        let band = PriceBand::new(&PriceBandSettings {
+            default_max_deviation: None,
+            currency_pairs: vec![],
+            reference: PriceBandReference::MarkPrice,
+            action: PriceBandAction::Reject,
+        });
+
+        assert_eq!(
+            band.check(currency_pair(), price!(1000), &mark_price()),
+            PriceBandCheck::Inside
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::settings::{ReplacePriceAction, ReplacePriceSettings};
+use mmb_domain::exchanges::symbol::{Round, Symbol};
+use mmb_domain::order::snapshot::Price;
+use rust_decimal::prelude::Signed;
+use rust_decimal::Decimal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplacePriceCheck {
+    /// Price of replacement differs from price of original order enough
+    Allowed,
+    /// Replacement isn't needed, so original order is kept
+    Skipped,
+    /// Price of replacement should be moved to the min price change from original order
+    Adjusted(Price),
+}
+
+/// Min price change between original order and its replacement. Exchanges can reject
+/// replacement with the same price or price within min price movement of original one
+pub struct ReplacePriceGuard {
+    min_price_change_ticks: Decimal,
+    action: ReplacePriceAction,
+}
+
+impl ReplacePriceGuard {
+    pub fn new(settings: &ReplacePriceSettings) -> Self {
+        Self {
+            // replacement with the same price is pointless anyway
+            min_price_change_ticks: Decimal::from(settings.min_price_change_ticks.max(1)),
+            action: settings.action,
+        }
+    }
+
+    pub fn check(&self, symbol: &Symbol, old_price: Price, new_price: Price) -> ReplacePriceCheck {
+        let min_price_change =
+            Price::new(symbol.price_precision.get_tick() * self.min_price_change_ticks);
+        let price_change = new_price - old_price;
+        if price_change.abs() >= min_price_change {
+            return ReplacePriceCheck::Allowed;
+        }
+
+        match self.action {
+            ReplacePriceAction::Skip => ReplacePriceCheck::Skipped,
+            // there is no direction to move price of replacement to
+            ReplacePriceAction::Adjust if price_change.is_zero() => ReplacePriceCheck::Skipped,
+            ReplacePriceAction::Adjust => {
+                let price = old_price + min_price_change * price_change.value().signum();
+                ReplacePriceCheck::Adjusted(symbol.price_round(price, Round::ToNearest))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::exchanges::symbol::Precision;
+    use mmb_domain::market::CurrencyCode;
+    use mmb_domain::price;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn symbol() -> Symbol {
+        let base: CurrencyCode = "BTC".into();
+        let quote: CurrencyCode = "USDT".into();
+
+        Symbol::new(
+            false,
+            base.as_str().into(),
+            base,
+            quote.as_str().into(),
+            quote,
+            None,
+            None,
+            None,
+            None,
+            None,
+            base,
+            None,
+            Precision::ByTick { tick: dec!(0.5) },
+            Precision::ByTick { tick: dec!(0.001) },
+        )
+    }
+
+    fn guard(action: ReplacePriceAction) -> ReplacePriceGuard {
+        ReplacePriceGuard::new(&ReplacePriceSettings {
+            min_price_change_ticks: 2,
+            action,
+        })
+    }
+
+    #[rstest]
+    #[case::enough_change_up(price!(101), ReplacePriceCheck::Allowed)]
+    #[case::enough_change_down(price!(98), ReplacePriceCheck::Allowed)]
+    #[case::same_price(price!(100), ReplacePriceCheck::Skipped)]
+    #[case::too_small_change(price!(100.5), ReplacePriceCheck::Skipped)]
+    fn skip_replacement(#[case] new_price: Price, #[case] expected: ReplacePriceCheck) {
+        let check = guard(ReplacePriceAction::Skip).check(&symbol(), price!(100), new_price);
+
+        assert_eq!(check, expected);
+    }
+
+    #[rstest]
+    #[case::enough_change(price!(101.5), ReplacePriceCheck::Allowed)]
+    #[case::same_price(price!(100), ReplacePriceCheck::Skipped)]
+    #[case::too_small_change_up(price!(100.5), ReplacePriceCheck::Adjusted(price!(101)))]
+    #[case::too_small_change_down(price!(99.5), ReplacePriceCheck::Adjusted(price!(99)))]
+    fn adjust_replacement(#[case] new_price: Price, #[case] expected: ReplacePriceCheck) {
+        let check = guard(ReplacePriceAction::Adjust).check(&symbol(), price!(100), new_price);
+
+        assert_eq!(check, expected);
+    }
+}
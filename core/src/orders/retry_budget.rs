@@ -0,0 +1,95 @@
+use crate::settings::OrderRetryBudgetSettings;
+use mmb_domain::order::snapshot::SystemInternalOrderProps;
+use mmb_utils::DateTime;
+use std::time::Duration;
+
+/// Outcome of spending one retry from order retry budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryBudgetCheck {
+    Allowed,
+    /// Budget is exhausted by this retry, so order should be marked failed
+    Exhausted,
+    /// Budget was exhausted by previous retry
+    AlreadyExhausted,
+}
+
+/// Max count of retries and max time of retrying which are shared by all retry mechanisms
+/// of single order, so persistently failing order can't produce a storm of requests
+pub struct RetryBudget {
+    max_attempts: u32,
+    max_total_time: Duration,
+}
+
+impl RetryBudget {
+    pub fn new(settings: &OrderRetryBudgetSettings) -> Self {
+        Self {
+            max_attempts: settings.max_attempts,
+            max_total_time: Duration::from_secs(settings.max_total_secs),
+        }
+    }
+
+    pub fn try_spend(
+        &self,
+        props: &mut SystemInternalOrderProps,
+        now: DateTime,
+    ) -> RetryBudgetCheck {
+        if props.retries_exhausted {
+            return RetryBudgetCheck::AlreadyExhausted;
+        }
+
+        let first_retry_time = *props.first_retry_time.get_or_insert(now);
+        let retrying_time = (now - first_retry_time).to_std().unwrap_or_default();
+        if props.retry_attempts >= self.max_attempts || retrying_time > self.max_total_time {
+            props.retries_exhausted = true;
+            return RetryBudgetCheck::Exhausted;
+        }
+
+        props.retry_attempts += 1;
+        RetryBudgetCheck::Allowed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn retry_budget() -> RetryBudget {
+        RetryBudget::new(&OrderRetryBudgetSettings {
+            max_attempts: 2,
+            max_total_secs: 10,
+        })
+    }
+
+    #[test]
+    fn retries_are_limited_by_attempts() {
+        let budget = retry_budget();
+        let mut props = SystemInternalOrderProps::default();
+        let now = Utc::now();
+
+        assert_eq!(budget.try_spend(&mut props, now), RetryBudgetCheck::Allowed);
+        assert_eq!(budget.try_spend(&mut props, now), RetryBudgetCheck::Allowed);
+        assert_eq!(
+            budget.try_spend(&mut props, now),
+            RetryBudgetCheck::Exhausted
+        );
+        assert_eq!(
+            budget.try_spend(&mut props, now),
+            RetryBudgetCheck::AlreadyExhausted
+        );
+        assert_eq!(props.retry_attempts, 2);
+    }
+
+    #[test]
+    fn retries_are_limited_by_total_time() {
+        let budget = retry_budget();
+        let mut props = SystemInternalOrderProps::default();
+        let now = Utc::now();
+
+        assert_eq!(budget.try_spend(&mut props, now), RetryBudgetCheck::Allowed);
+        assert_eq!(
+            budget.try_spend(&mut props, now + chrono::Duration::seconds(11)),
+            RetryBudgetCheck::Exhausted
+        );
+    }
+}
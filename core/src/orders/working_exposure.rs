@@ -0,0 +1,25 @@
+use mmb_domain::order::snapshot::{Amount, OrderSide};
+use serde::Serialize;
+
+/// Notional of open orders on each side of currency pair in quote currency
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct WorkingExposure {
+    pub buy_notional: Amount,
+    pub sell_notional: Amount,
+    /// Buy notional minus sell notional
+    pub net: Amount,
+}
+
+impl WorkingExposure {
+    pub fn add(&mut self, side: OrderSide, notional: Amount) {
+        match side {
+            OrderSide::Buy => self.buy_notional += notional,
+            OrderSide::Sell => self.sell_notional += notional,
+        }
+        self.net = self.buy_notional - self.sell_notional;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buy_notional.is_zero() && self.sell_notional.is_zero()
+    }
+}
@@ -1,8 +1,13 @@
 use anyhow::Result;
+use dashmap::DashMap;
+use mmb_domain::market::ExchangeAccountId;
 use parking_lot::Mutex;
 use tokio::sync::{mpsc, oneshot};
 
+use crate::balance::manager::balance_manager::BalanceManager;
+use crate::exchanges::general::exchange::Exchange;
 use crate::lifecycle::app_lifetime_manager::{ActionAfterGracefulShutdown, AppLifetimeManager};
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
 use std::sync::Arc;
 
 use crate::{lifecycle::trading_engine::Service, statistic_service::StatisticService};
@@ -27,6 +32,9 @@ impl CoreApi {
         lifetime_manager: Arc<AppLifetimeManager>,
         engine_settings: String,
         statistics: Arc<StatisticService>,
+        exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+        balance_manager: Arc<Mutex<BalanceManager>>,
+        local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
     ) -> Result<Arc<Self>> {
         let (server_stopper_tx, server_stopper_rx) =
             mpsc::channel::<ActionAfterGracefulShutdown>(10);
@@ -39,6 +47,9 @@ impl CoreApi {
             server_stopper_tx.clone(),
             statistics,
             engine_settings,
+            exchanges,
+            balance_manager,
+            local_snapshots_service,
         ));
 
         spawn_server_stopping_action(
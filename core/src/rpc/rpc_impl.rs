@@ -1,12 +1,28 @@
-use jsonrpc_core::Result;
+use dashmap::DashMap;
+use itertools::Itertools;
+use jsonrpc_core::{Error, Result};
+use mmb_domain::events::SystemStatus;
+use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeAccountId};
+use mmb_domain::order::snapshot::Amount;
 use mmb_rpc::rest_api::server_side_error;
 use mmb_rpc::rest_api::MmbRpc;
+use mmb_utils::DateTime;
 use parking_lot::Mutex;
+use serde::Serialize;
 use tokio::sync::mpsc;
 
+use std::collections::HashMap;
+use std::str::FromStr;
 use std::sync::Arc;
 
+use crate::balance::manager::balance_manager::BalanceManager;
+use crate::balance::valuation::{BalanceValuation, ProfitLoss, ValuationMode};
+use crate::exchanges::general::exchange::Exchange;
+use crate::exchanges::general::state_snapshot::ExchangeStateSnapshot;
 use crate::lifecycle::app_lifetime_manager::ActionAfterGracefulShutdown;
+use crate::misc::time::time_manager;
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::orders::working_exposure::WorkingExposure;
 use crate::statistic_service::StatisticService;
 use mmb_rpc::rest_api::ErrorCode;
 
@@ -14,10 +30,45 @@ use super::common::send_restart;
 use super::common::send_stop;
 use super::common::set_config;
 
+#[derive(Serialize)]
+struct CurrencyPairExposure {
+    exchange_account_id: ExchangeAccountId,
+    currency_pair: CurrencyPair,
+    #[serde(flatten)]
+    exposure: WorkingExposure,
+}
+
+#[derive(Serialize)]
+struct BalanceState {
+    exchange_account_id: ExchangeAccountId,
+    currency_code: CurrencyCode,
+    amount: Amount,
+}
+
+#[derive(Serialize)]
+struct PositionState {
+    exchange_account_id: ExchangeAccountId,
+    currency_pair: CurrencyPair,
+    /// In amount currency
+    position: Amount,
+}
+
+/// Engine state assembled from local state only without requests to exchanges
+#[derive(Serialize)]
+struct EngineSnapshot {
+    snapshot_time: DateTime,
+    exchanges: Vec<ExchangeStateSnapshot>,
+    balances: Vec<BalanceState>,
+    positions: Vec<PositionState>,
+}
+
 pub struct RpcImpl {
     server_stopper_tx: Arc<Mutex<Option<mpsc::Sender<ActionAfterGracefulShutdown>>>>,
     statistics: Arc<StatisticService>,
     engine_settings: String,
+    exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+    balance_manager: Arc<Mutex<BalanceManager>>,
+    local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
 }
 
 impl RpcImpl {
@@ -25,18 +76,138 @@ impl RpcImpl {
         server_stopper_tx: Arc<Mutex<Option<mpsc::Sender<ActionAfterGracefulShutdown>>>>,
         statistics: Arc<StatisticService>,
         engine_settings: String,
+        exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+        balance_manager: Arc<Mutex<BalanceManager>>,
+        local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
     ) -> Self {
         Self {
             server_stopper_tx,
             statistics,
             engine_settings,
+            exchanges,
+            balance_manager,
+            local_snapshots_service,
         }
     }
 }
 
 impl MmbRpc for RpcImpl {
     fn health(&self) -> Result<String> {
-        Ok("Engine is working".into())
+        let exchanges_under_maintenance = self
+            .exchanges
+            .iter()
+            .filter(|x| x.system_status() == SystemStatus::Maintenance)
+            .map(|x| x.exchange_account_id.to_string())
+            .sorted()
+            .join(", ");
+
+        let mut health = match exchanges_under_maintenance.is_empty() {
+            true => "Engine is working".to_owned(),
+            false => format!(
+                "Engine is working. Exchanges under maintenance: {exchanges_under_maintenance}"
+            ),
+        };
+
+        let selected_endpoints = self
+            .exchanges
+            .iter()
+            .filter_map(|x| {
+                x.endpoint_latency().map(|endpoint_latency| {
+                    format!(
+                        "{} {} ({} ms)",
+                        x.exchange_account_id,
+                        endpoint_latency.endpoint,
+                        endpoint_latency.latency.as_millis()
+                    )
+                })
+            })
+            .sorted()
+            .join(", ");
+        if !selected_endpoints.is_empty() {
+            health += &format!(". Selected endpoints: {selected_endpoints}");
+        }
+
+        let failed_subscriptions = self
+            .exchanges
+            .iter()
+            .flat_map(|x| {
+                let exchange_account_id = x.exchange_account_id;
+                x.failed_subscriptions()
+                    .into_iter()
+                    .map(move |stream| format!("{exchange_account_id} {stream}"))
+            })
+            .sorted()
+            .join(", ");
+        if !failed_subscriptions.is_empty() {
+            health += &format!(". Failed subscriptions: {failed_subscriptions}");
+        }
+
+        let websocket_compression = self
+            .exchanges
+            .iter()
+            .flat_map(|x| {
+                let exchange_account_id = x.exchange_account_id;
+                x.websocket_compression().into_iter().map(move |state| {
+                    let negotiation = match state.is_negotiated {
+                        true => "negotiated",
+                        false => "declined",
+                    };
+                    format!("{exchange_account_id} {} {negotiation}", state.role)
+                })
+            })
+            .sorted()
+            .join(", ");
+        if !websocket_compression.is_empty() {
+            health += &format!(". Websocket compression: {websocket_compression}");
+        }
+
+        let skewed_clocks = self
+            .exchanges
+            .iter()
+            .filter_map(|x| {
+                let clock_skew = x.clock_skew().filter(|clock_skew| clock_skew.is_exceeded)?;
+                Some(format!(
+                    "{} {} ms",
+                    x.exchange_account_id,
+                    clock_skew.offset_ms.unwrap_or_default()
+                ))
+            })
+            .sorted()
+            .join(", ");
+        if !skewed_clocks.is_empty() {
+            health += &format!(". Orders are refused due to clock skew: {skewed_clocks}");
+        }
+
+        let broken_market_data = self
+            .exchanges
+            .iter()
+            .flat_map(|x| {
+                let exchange_account_id = x.exchange_account_id;
+                x.broken_market_data()
+                    .into_iter()
+                    .map(move |currency_pair| format!("{exchange_account_id} {currency_pair}"))
+            })
+            .sorted()
+            .join(", ");
+        if !broken_market_data.is_empty() {
+            health +=
+                &format!(". Orders are refused due to broken market data: {broken_market_data}");
+        }
+
+        let balance_snapshots = self
+            .exchanges
+            .iter()
+            .filter_map(|x| {
+                let snapshot_time = x.last_balance_snapshot_time()?;
+                Some(format!("{} at {snapshot_time}", x.exchange_account_id))
+            })
+            .sorted()
+            .join(", ");
+        if !balance_snapshots.is_empty() {
+            health += &format!(". Last balance snapshots: {balance_snapshots}");
+        }
+
+        Ok(health)
     }
 
     fn stop(&self) -> Result<String> {
@@ -66,4 +237,255 @@ impl MmbRpc for RpcImpl {
 
         Ok(json_statistic)
     }
+
+    fn orders(&self) -> Result<String> {
+        let exposures = self
+            .exchanges
+            .iter()
+            .flat_map(|exchange| {
+                exchange
+                    .symbols
+                    .iter()
+                    .filter_map(|symbol| {
+                        let currency_pair = *symbol.key();
+                        let exposure = exchange.get_working_exposure(currency_pair).ok()?;
+                        (!exposure.is_empty()).then(|| CurrencyPairExposure {
+                            exchange_account_id: exchange.exchange_account_id,
+                            currency_pair,
+                            exposure,
+                        })
+                    })
+                    .collect_vec()
+            })
+            .collect_vec();
+
+        serde_json::to_string(&exposures).map_err(|err| {
+            log::warn!("Failed to serialize working exposures: {err}");
+            server_side_error(ErrorCode::FailedToSerializeResponse)
+        })
+    }
+
+    fn balances(
+        &self,
+        valuation_mode: String,
+        quote_currency_codes: Vec<String>,
+    ) -> Result<String> {
+        let mode = parse_valuation_mode(&valuation_mode)?;
+        let quote_currency_codes = parse_quote_currency_codes(&quote_currency_codes)?;
+
+        let balances = self
+            .balance_manager
+            .lock()
+            .get_balances()
+            .balances_by_exchange_id
+            .unwrap_or_default();
+
+        let local_snapshots_service = self.local_snapshots_service.lock();
+        let mut valuations = Vec::new();
+        for (exchange_account_id, balances) in balances {
+            let exchange = match self.exchanges.get(&exchange_account_id) {
+                Some(exchange) => exchange,
+                None => continue,
+            };
+
+            for (currency_code, amount) in balances {
+                valuations.extend(quote_currency_codes.iter().map(|&quote_currency_code| {
+                    BalanceValuation::new(
+                        &exchange,
+                        currency_code,
+                        amount,
+                        quote_currency_code,
+                        mode,
+                        &local_snapshots_service,
+                    )
+                }));
+            }
+        }
+        valuations.sort_by_key(|x| {
+            (
+                x.exchange_account_id.to_string(),
+                x.currency_code.to_string(),
+            )
+        });
+
+        serde_json::to_string(&valuations).map_err(|err| {
+            log::warn!("Failed to serialize balance valuations: {err}");
+            server_side_error(ErrorCode::FailedToSerializeResponse)
+        })
+    }
+
+    fn pnl(&self, valuation_mode: String, quote_currency_codes: Vec<String>) -> Result<String> {
+        let mode = parse_valuation_mode(&valuation_mode)?;
+        let quote_currency_codes = parse_quote_currency_codes(&quote_currency_codes)?;
+
+        let (initial_balances, balances) = {
+            let balance_manager = self.balance_manager.lock();
+            let balances = balance_manager
+                .get_balances()
+                .balances_by_exchange_id
+                .unwrap_or_default();
+            let initial_balances = balances
+                .keys()
+                .filter_map(|&exchange_account_id| {
+                    let initial_balances =
+                        balance_manager.get_initial_balances(exchange_account_id)?;
+                    Some((exchange_account_id, initial_balances.clone()))
+                })
+                .collect::<HashMap<_, _>>();
+            (initial_balances, balances)
+        };
+
+        let local_snapshots_service = self.local_snapshots_service.lock();
+        let mut pnls = Vec::new();
+        for (exchange_account_id, balances) in balances {
+            let (exchange, initial_balances) = match (
+                self.exchanges.get(&exchange_account_id),
+                initial_balances.get(&exchange_account_id),
+            ) {
+                (Some(exchange), Some(initial_balances)) => (exchange, initial_balances),
+                _ => continue,
+            };
+
+            pnls.extend(quote_currency_codes.iter().map(|&quote_currency_code| {
+                ProfitLoss::new(
+                    &exchange,
+                    initial_balances,
+                    &balances,
+                    quote_currency_code,
+                    mode,
+                    &local_snapshots_service,
+                )
+            }));
+        }
+        pnls.sort_by_key(|x| x.exchange_account_id.to_string());
+
+        serde_json::to_string(&pnls).map_err(|err| {
+            log::warn!("Failed to serialize PnL: {err}");
+            server_side_error(ErrorCode::FailedToSerializeResponse)
+        })
+    }
+
+    fn snapshot(&self) -> Result<String> {
+        let now = time_manager::now();
+        let exchanges = self
+            .exchanges
+            .iter()
+            .map(|x| x.state_snapshot(now))
+            .sorted_by_key(|x| x.exchange_account_id.to_string())
+            .collect_vec();
+
+        let balances = self.balance_manager.lock().get_balances();
+        let positions = match &balances.position_by_fill_amount {
+            None => vec![],
+            Some(position_by_fill_amount) => self
+                .exchanges
+                .iter()
+                .flat_map(|exchange| {
+                    let exchange_account_id = exchange.exchange_account_id;
+                    exchange
+                        .symbols
+                        .iter()
+                        .filter_map(|symbol| {
+                            let currency_pair = *symbol.key();
+                            let position =
+                                position_by_fill_amount.get(exchange_account_id, currency_pair)?;
+                            (!position.is_zero()).then_some(PositionState {
+                                exchange_account_id,
+                                currency_pair,
+                                position,
+                            })
+                        })
+                        .collect_vec()
+                })
+                .sorted_by_key(|x| {
+                    (
+                        x.exchange_account_id.to_string(),
+                        x.currency_pair.to_string(),
+                    )
+                })
+                .collect_vec(),
+        };
+        let balances = balances
+            .balances_by_exchange_id
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(exchange_account_id, balances)| {
+                balances
+                    .into_iter()
+                    .map(move |(currency_code, amount)| BalanceState {
+                        exchange_account_id,
+                        currency_code,
+                        amount,
+                    })
+            })
+            .sorted_by_key(|x| {
+                (
+                    x.exchange_account_id.to_string(),
+                    x.currency_code.to_string(),
+                )
+            })
+            .collect_vec();
+
+        let snapshot = EngineSnapshot {
+            snapshot_time: now,
+            exchanges,
+            balances,
+            positions,
+        };
+        serde_json::to_string(&snapshot).map_err(|err| {
+            log::warn!("Failed to serialize engine snapshot: {err}");
+            server_side_error(ErrorCode::FailedToSerializeResponse)
+        })
+    }
+
+    fn resume_order_placement(
+        &self,
+        exchange_account_id: String,
+        currency_pair: String,
+    ) -> Result<String> {
+        let exchange_account_id = ExchangeAccountId::from_str(&exchange_account_id)
+            .map_err(|err| Error::invalid_params(format!("{err:?}")))?;
+        let exchange = self.exchanges.get(&exchange_account_id).ok_or_else(|| {
+            Error::invalid_params(format!("Unknown exchange account {exchange_account_id}"))
+        })?;
+
+        let currency_pair = currency_pair.to_lowercase();
+        let currency_pair = exchange
+            .symbols
+            .iter()
+            .map(|x| *x.key())
+            .find(|x| x.to_string() == currency_pair)
+            .ok_or_else(|| {
+                Error::invalid_params(format!(
+                    "Unknown currency pair {currency_pair} on {exchange_account_id}"
+                ))
+            })?;
+
+        Ok(match exchange.resume_order_placement(currency_pair) {
+            true => {
+                format!("Order placement for {currency_pair} on {exchange_account_id} is resumed")
+            }
+            false => format!(
+                "Order placement for {currency_pair} on {exchange_account_id} wasn't paused"
+            ),
+        })
+    }
+}
+
+fn parse_valuation_mode(valuation_mode: &str) -> Result<ValuationMode> {
+    ValuationMode::from_str(valuation_mode).map_err(|err| Error::invalid_params(err.to_string()))
+}
+
+fn parse_quote_currency_codes(quote_currency_codes: &[String]) -> Result<Vec<CurrencyCode>> {
+    if quote_currency_codes.is_empty() || quote_currency_codes.iter().any(|x| x.is_empty()) {
+        return Err(Error::invalid_params(
+            "Quote currency codes should be nonempty",
+        ));
+    }
+
+    Ok(quote_currency_codes
+        .iter()
+        .map(|x| CurrencyCode::from(x.as_str()))
+        .unique()
+        .collect())
 }
@@ -52,4 +52,32 @@ impl MmbRpc for RpcImplNoConfig {
     fn stats(&self) -> Result<String> {
         Ok(CONFIG_IS_NOT_SET.into())
     }
+
+    fn orders(&self) -> Result<String> {
+        Ok(CONFIG_IS_NOT_SET.into())
+    }
+
+    fn balances(
+        &self,
+        _valuation_mode: String,
+        _quote_currency_codes: Vec<String>,
+    ) -> Result<String> {
+        Ok(CONFIG_IS_NOT_SET.into())
+    }
+
+    fn pnl(&self, _valuation_mode: String, _quote_currency_codes: Vec<String>) -> Result<String> {
+        Ok(CONFIG_IS_NOT_SET.into())
+    }
+
+    fn snapshot(&self) -> Result<String> {
+        Ok(CONFIG_IS_NOT_SET.into())
+    }
+
+    fn resume_order_placement(
+        &self,
+        _exchange_account_id: String,
+        _currency_pair: String,
+    ) -> Result<String> {
+        Ok(CONFIG_IS_NOT_SET.into())
+    }
 }
@@ -52,7 +52,8 @@ mod tests {
     use mmb_domain::order::pool::OrdersPool;
     use mmb_domain::order::snapshot::{OrderHeader, OrderSide, OrderStatus, UserOrder};
     use rstest::rstest;
-    use rust_decimal_macros::dec;
+
+    use mmb_domain::{amount, price};
 
     #[rstest]
     #[timeout(std::time::Duration::from_millis(200))]
@@ -64,8 +65,8 @@ mod tests {
             ExchangeAccountId::new("Binance", 0),
             CurrencyPair::from_codes("a".into(), "b".into()),
             OrderSide::Buy,
-            dec!(1),
-            UserOrder::limit(dec!(0.5)),
+            amount!(1),
+            UserOrder::limit(price!(0.5)),
             None,
             None,
             "".to_string(),
@@ -89,8 +90,8 @@ mod tests {
             ExchangeAccountId::new("Binance", 0),
             CurrencyPair::from_codes("a".into(), "b".into()),
             OrderSide::Buy,
-            dec!(1),
-            UserOrder::limit(dec!(0.5)),
+            amount!(1),
+            UserOrder::limit(price!(0.5)),
             None,
             None,
             "".to_string(),
@@ -114,8 +115,8 @@ mod tests {
             ExchangeAccountId::new("Binance", 0),
             CurrencyPair::from_codes("a".into(), "b".into()),
             OrderSide::Buy,
-            dec!(1),
-            UserOrder::limit(dec!(0.5)),
+            amount!(1),
+            UserOrder::limit(price!(0.5)),
             None,
             None,
             "".to_string(),
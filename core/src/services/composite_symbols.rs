@@ -0,0 +1,224 @@
+use crate::balance::valuation::valuation_price;
+use crate::exchanges::general::exchange::Exchange;
+use crate::lifecycle::trading_engine::Service;
+use crate::math::{DecimalComputation, RoundForComputation};
+use crate::misc::time::time_manager;
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::settings::{CompositeConstituent, CompositeSymbolSettings};
+use anyhow::{Context, Result};
+use dashmap::DashMap;
+use mmb_domain::events::ExchangeEvent;
+use mmb_domain::market::{ExchangeAccountId, MarketAccountId, MarketId};
+use mmb_domain::order::snapshot::Amount;
+use mmb_domain::order::snapshot::Price;
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::sync::oneshot::Receiver;
+
+/// Read-only synthetic symbol with price derived from its constituents
+#[derive(Debug, Clone, Serialize)]
+pub struct CompositeSymbol {
+    pub name: String,
+    pub constituents: Vec<CompositeConstituent>,
+    /// `None` if price of any constituent is missing
+    pub price: Option<Price>,
+    pub last_update_time: Option<DateTime>,
+}
+
+/// Composite symbols which prices are recomputed on market data updates of their constituents
+pub struct CompositeSymbolsService {
+    exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+    settings: Vec<CompositeSymbolSettings>,
+    constituent_markets: HashSet<MarketAccountId>,
+    /// Order books of constituents only
+    local_snapshots_service: Mutex<LocalSnapshotsService>,
+    symbols: Mutex<HashMap<String, CompositeSymbol>>,
+}
+
+impl Service for CompositeSymbolsService {
+    fn name(&self) -> &str {
+        "CompositeSymbolsService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl CompositeSymbolsService {
+    pub fn new(
+        settings: Vec<CompositeSymbolSettings>,
+        exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+    ) -> Self {
+        let constituent_markets = settings
+            .iter()
+            .flat_map(|x| &x.constituents)
+            .map(|x| MarketAccountId::new(x.exchange_account_id, x.currency_pair))
+            .collect();
+
+        let symbols = settings
+            .iter()
+            .map(|x| {
+                let symbol = CompositeSymbol {
+                    name: x.name.clone(),
+                    constituents: x.constituents.clone(),
+                    price: None,
+                    last_update_time: None,
+                };
+                (x.name.clone(), symbol)
+            })
+            .collect();
+
+        Self {
+            exchanges,
+            settings,
+            constituent_markets,
+            local_snapshots_service: Mutex::new(LocalSnapshotsService::new(HashMap::new())),
+            symbols: Mutex::new(symbols),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<CompositeSymbol> {
+        self.symbols.lock().get(name).cloned()
+    }
+
+    /// `None` if composite symbol isn't configured or price of any its constituent is missing
+    pub fn price(&self, name: &str) -> Option<Price> {
+        self.symbols.lock().get(name)?.price
+    }
+
+    pub async fn start(
+        self: Arc<Self>,
+        mut events_receiver: broadcast::Receiver<ExchangeEvent>,
+    ) -> Result<()> {
+        loop {
+            let event = events_receiver
+                .recv()
+                .await
+                .context("Error during receiving event in CompositeSymbolsService::start()")?;
+
+            self.handle_event(&event);
+        }
+    }
+
+    fn handle_event(&self, event: &ExchangeEvent) {
+        let market_account_id = match event {
+            ExchangeEvent::Trades(trades) => {
+                MarketAccountId::new(trades.exchange_account_id, trades.currency_pair)
+            }
+            ExchangeEvent::OrderBookEvent(order_book_event) => {
+                let market_account_id = order_book_event.market_account_id();
+                if !self.constituent_markets.contains(&market_account_id) {
+                    return;
+                }
+
+                let mut local_snapshots_service = self.local_snapshots_service.lock();
+                if local_snapshots_service.update(order_book_event).is_none() {
+                    return;
+                }
+                market_account_id
+            }
+            _ => return,
+        };
+
+        if self.constituent_markets.contains(&market_account_id) {
+            self.recompute_prices(market_account_id, time_manager::now());
+        }
+    }
+
+    /// Recompute prices of composite symbols including updated constituent market
+    fn recompute_prices(&self, market_account_id: MarketAccountId, now: DateTime) {
+        let local_snapshots_service = self.local_snapshots_service.lock();
+        let mut symbols = self.symbols.lock();
+        for settings in &self.settings {
+            let is_affected = settings.constituents.iter().any(|x| {
+                MarketAccountId::new(x.exchange_account_id, x.currency_pair) == market_account_id
+            });
+            if !is_affected {
+                continue;
+            }
+
+            let price = composite_price(&settings.constituents, |constituent| {
+                let exchange = self.exchanges.get(&constituent.exchange_account_id)?;
+                let market_id = MarketId::new(
+                    constituent.exchange_account_id.exchange_id,
+                    constituent.currency_pair,
+                );
+                valuation_price(
+                    settings.price_mode,
+                    Amount::new(constituent.weight),
+                    local_snapshots_service.get_snapshot(market_id),
+                    exchange.last_trade_price(constituent.currency_pair),
+                )
+                .map(|(price, _)| price)
+            });
+
+            if let Some(symbol) = symbols.get_mut(&settings.name) {
+                symbol.price = price;
+                symbol.last_update_time = Some(now);
+            }
+        }
+    }
+}
+
+/// Weighted sum of prices of constituents. `None` if price of any constituent is missing
+fn composite_price(
+    constituents: &[CompositeConstituent],
+    constituent_price: impl Fn(&CompositeConstituent) -> Option<Price>,
+) -> Option<Price> {
+    constituents
+        .iter()
+        .map(|x| Some(x.weight * constituent_price(x)?))
+        .sum::<Option<Price>>()
+        .map(|x| x.round_for(DecimalComputation::Valuation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::price;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn constituents() -> Vec<CompositeConstituent> {
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+        let constituent = |base: &str, weight| CompositeConstituent {
+            exchange_account_id,
+            currency_pair: CurrencyPair::from_codes(base.into(), "usdt".into()),
+            weight,
+        };
+
+        vec![
+            constituent("btc", dec!(0.01)),
+            constituent("eth", dec!(0.2)),
+        ]
+    }
+
+    #[rstest]
+    // 0.01 * 20000 + 0.2 * 1500
+    #[case::all_prices(Some(price!(1500)), Some(price!(500)))]
+    #[case::missing_constituent_price(None, None)]
+    fn price_of_composite_symbol(
+        #[case] eth_price: Option<Price>,
+        #[case] expected: Option<Price>,
+    ) {
+        let btc_usdt = CurrencyPair::from_codes("btc".into(), "usdt".into());
+        let price = composite_price(&constituents(), |constituent| {
+            match constituent.currency_pair == btc_usdt {
+                true => Some(price!(20000)),
+                false => eth_price,
+            }
+        });
+
+        assert_eq!(price, expected);
+    }
+}
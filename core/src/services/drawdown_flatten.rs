@@ -0,0 +1,249 @@
+use crate::balance::manager::balance_manager::BalanceManager;
+use crate::balance::valuation::value_in_currency;
+use crate::exchanges::block_reasons::MAX_DRAWDOWN;
+use crate::exchanges::exchange_blocker::{BlockType, ExchangeBlocker};
+use crate::exchanges::general::exchange::Exchange;
+use crate::lifecycle::trading_engine::Service;
+use crate::order_book::local_snapshot_service::LocalSnapshotsService;
+use crate::settings::DrawdownFlattenSettings;
+use anyhow::Result;
+use mmb_domain::exchanges::commission::Percent;
+use mmb_domain::order::snapshot::{Amount, Price};
+use mmb_domain::position::DerivativePosition;
+use mmb_utils::cancellation_token::CancellationToken;
+use parking_lot::Mutex;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+use tokio::sync::oneshot::Receiver;
+
+/// Tracks peak equity of exchange account. Flattens exchange account and pauses order creation
+/// when equity falls from the peak by more than max drawdown
+pub struct DrawdownFlattenService {
+    exchange: Arc<Exchange>,
+    exchange_blocker: Arc<ExchangeBlocker>,
+    balance_manager: Arc<Mutex<BalanceManager>>,
+    local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
+    settings: DrawdownFlattenSettings,
+    peak_equity: Mutex<Option<Amount>>,
+    cancellation_token: CancellationToken,
+}
+
+impl Service for DrawdownFlattenService {
+    fn name(&self) -> &str {
+        "DrawdownFlattenService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl DrawdownFlattenService {
+    pub fn new(
+        exchange: Arc<Exchange>,
+        exchange_blocker: Arc<ExchangeBlocker>,
+        balance_manager: Arc<Mutex<BalanceManager>>,
+        local_snapshots_service: Arc<Mutex<LocalSnapshotsService>>,
+        settings: DrawdownFlattenSettings,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        Self {
+            exchange,
+            exchange_blocker,
+            balance_manager,
+            local_snapshots_service,
+            settings,
+            peak_equity: Mutex::new(None),
+            cancellation_token,
+        }
+    }
+
+    /// Should be called periodically. Equity isn't tracked while order creation is paused
+    /// after max drawdown flatten
+    pub async fn check_drawdown(self: Arc<Self>) {
+        let exchange_account_id = self.exchange.exchange_account_id;
+        if self
+            .exchange_blocker
+            .is_blocked_by_reason(exchange_account_id, MAX_DRAWDOWN)
+        {
+            return;
+        }
+
+        let equity = match self.calculate_equity().await {
+            Some(equity) => equity,
+            None => return,
+        };
+
+        let peak_equity = update_peak_equity(&mut self.peak_equity.lock(), equity);
+        let drawdown_percent = drawdown_percent(peak_equity, equity);
+        if drawdown_percent <= self.settings.max_drawdown_percent {
+            return;
+        }
+
+        let currency_code = self.settings.equity_currency_code;
+        log::error!(
+            "Equity of {exchange_account_id} fell from peak {peak_equity} {currency_code} to {equity} {currency_code}. Drawdown {drawdown_percent}% exceeded max drawdown {}%, flatten started",
+            self.settings.max_drawdown_percent
+        );
+        self.exchange
+            .send_max_drawdown_reached_event(peak_equity, equity, drawdown_percent);
+        self.exchange_blocker
+            .block(exchange_account_id, MAX_DRAWDOWN, BlockType::Manual);
+
+        self.exchange
+            .clone()
+            .flatten(self.cancellation_token.clone())
+            .await;
+
+        log::warn!("Max drawdown flatten of {exchange_account_id} completed");
+        if self.settings.auto_resume {
+            // drawdown is counted from equity after flatten
+            *self.peak_equity.lock() = None;
+            log::info!("Trading on {exchange_account_id} resumed after max drawdown flatten");
+            self.exchange_blocker
+                .unblock(exchange_account_id, MAX_DRAWDOWN);
+        }
+    }
+
+    /// Balances valued in equity currency plus unrealized PnL of derivative positions.
+    /// Returns `None` if some part of equity can't be valued, so incomplete equity doesn't
+    /// look like drawdown
+    async fn calculate_equity(&self) -> Option<Amount> {
+        let exchange_account_id = self.exchange.exchange_account_id;
+        let currency_code = self.settings.equity_currency_code;
+
+        let balances = self
+            .balance_manager
+            .lock()
+            .get_balances()
+            .balances_by_exchange_id
+            .and_then(|mut x| x.remove(&exchange_account_id))
+            .unwrap_or_default();
+
+        let mut equity = Amount::ZERO;
+        {
+            let local_snapshots_service = self.local_snapshots_service.lock();
+            for (balance_currency_code, amount) in balances {
+                match value_in_currency(
+                    &self.exchange,
+                    balance_currency_code,
+                    amount,
+                    currency_code,
+                    self.settings.valuation_mode,
+                    &local_snapshots_service,
+                ) {
+                    Some(value) => equity += value,
+                    None => {
+                        log::warn!("Unable to value {amount} {balance_currency_code} in {currency_code} on {exchange_account_id}, drawdown isn't checked");
+                        return None;
+                    }
+                }
+            }
+        }
+
+        let is_derivative = self
+            .exchange
+            .exchange_client
+            .get_settings()
+            .account_type
+            .is_derivative();
+        if is_derivative {
+            let positions = self
+                .exchange
+                .get_active_positions(self.cancellation_token.clone())
+                .await;
+            for position in positions {
+                let currency_pair = position.derivative.currency_pair;
+                let price = self
+                    .exchange
+                    .mark_prices
+                    .get(&currency_pair)
+                    .map(|x| x.mark_price)
+                    .or_else(|| self.exchange.last_trade_price(currency_pair));
+                match price {
+                    Some(price) => equity += unrealized_pnl(&position.derivative, price),
+                    None => {
+                        log::warn!("Unable to get price of {currency_pair} position on {exchange_account_id}, drawdown isn't checked");
+                        return None;
+                    }
+                }
+            }
+        }
+
+        Some(equity)
+    }
+}
+
+/// Update peak equity with current equity and return the peak
+fn update_peak_equity(peak_equity: &mut Option<Amount>, equity: Amount) -> Amount {
+    let peak = peak_equity.map_or(equity, |x| x.max(equity));
+    *peak_equity = Some(peak);
+    peak
+}
+
+/// Drop of equity from its peak in percents
+fn drawdown_percent(peak_equity: Amount, equity: Amount) -> Percent {
+    if peak_equity <= Amount::ZERO {
+        return Percent::ZERO;
+    }
+
+    ((peak_equity - equity) / peak_equity * dec!(100)).max(Percent::ZERO)
+}
+
+/// PnL of linear derivative position if it was closed by specified price
+fn unrealized_pnl(position: &DerivativePosition, price: Price) -> Amount {
+    position.position * (price - position.average_entry_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::market::CurrencyPair;
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::no_drop(amount!(1000), amount!(1000), dec!(0))]
+    #[case::drop(amount!(1000), amount!(950), dec!(5))]
+    #[case::no_peak(amount!(0), amount!(-10), dec!(0))]
+    fn drawdown_from_peak(
+        #[case] peak_equity: Amount,
+        #[case] equity: Amount,
+        #[case] expected: Percent,
+    ) {
+        assert_eq!(drawdown_percent(peak_equity, equity), expected);
+    }
+
+    #[test]
+    fn peak_equity_only_grows() {
+        let mut peak_equity = None;
+
+        assert_eq!(
+            update_peak_equity(&mut peak_equity, amount!(100)),
+            amount!(100)
+        );
+        assert_eq!(
+            update_peak_equity(&mut peak_equity, amount!(120)),
+            amount!(120)
+        );
+        assert_eq!(
+            update_peak_equity(&mut peak_equity, amount!(90)),
+            amount!(120)
+        );
+    }
+
+    #[rstest]
+    #[case::long(amount!(2), price!(110), amount!(20))]
+    #[case::short(amount!(-2), price!(110), amount!(-20))]
+    fn unrealized_pnl_of_position(
+        #[case] amount: Amount,
+        #[case] price: Price,
+        #[case] expected: Amount,
+    ) {
+        let currency_pair = CurrencyPair::from_codes("BTC".into(), "USDT".into());
+        let position =
+            DerivativePosition::new(currency_pair, amount, price!(100), price!(0), dec!(1));
+
+        assert_eq!(unrealized_pnl(&position, price), expected);
+    }
+}
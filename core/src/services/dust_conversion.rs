@@ -0,0 +1,170 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::lifecycle::trading_engine::Service;
+use crate::misc::time::time_manager;
+use crate::settings::DustConversionSettings;
+use anyhow::Result;
+use chrono::Duration;
+use itertools::Itertools;
+use mmb_domain::market::CurrencyCode;
+use mmb_domain::order::snapshot::Amount;
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::oneshot::Receiver;
+
+/// Converts dust balances of exchange account which stay dust longer than min age
+pub struct DustConversionService {
+    exchange: Arc<Exchange>,
+    settings: DustConversionSettings,
+    /// Time since which balance of asset is dust
+    dust_since: Mutex<HashMap<CurrencyCode, DateTime>>,
+    cancellation_token: CancellationToken,
+}
+
+impl Service for DustConversionService {
+    fn name(&self) -> &str {
+        "DustConversionService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl DustConversionService {
+    pub fn new(
+        exchange: Arc<Exchange>,
+        settings: DustConversionSettings,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        Self {
+            exchange,
+            settings,
+            dust_since: Default::default(),
+            cancellation_token,
+        }
+    }
+
+    /// Should be called periodically
+    pub async fn convert_dust(self: Arc<Self>) {
+        let exchange_account_id = self.exchange.exchange_account_id;
+        let assets = select_dust_assets(
+            &mut self.dust_since.lock(),
+            &self.exchange.dust_balances(),
+            &self.settings,
+            time_manager::now(),
+        );
+        if assets.is_empty() {
+            return;
+        }
+
+        let conversion = match self
+            .exchange
+            .convert_dust(&assets, self.cancellation_token.clone())
+            .await
+        {
+            Ok(conversion) => conversion,
+            Err(error) => {
+                log::error!(
+                    "Dust conversion of {assets:?} on {exchange_account_id} failed: {error:?}"
+                );
+                return;
+            }
+        };
+
+        let mut dust_since = self.dust_since.lock();
+        for converted in &conversion.converted {
+            log::info!(
+                "Dust {} {} on {exchange_account_id} converted into {} with fee {}",
+                converted.amount,
+                converted.asset,
+                converted.converted_amount,
+                converted.fee
+            );
+            let _ = dust_since.remove(&converted.asset);
+        }
+        log::info!(
+            "Dust conversion on {exchange_account_id} completed: received {} with total fee {}",
+            conversion.total_converted_amount,
+            conversion.total_fee
+        );
+    }
+}
+
+/// Assets which balances are dust for at least min age. Assets which balances aren't dust
+/// anymore are forgotten, so their age starts over when they become dust again
+fn select_dust_assets(
+    dust_since: &mut HashMap<CurrencyCode, DateTime>,
+    dust_balances: &[(CurrencyCode, Amount)],
+    settings: &DustConversionSettings,
+    now: DateTime,
+) -> Vec<CurrencyCode> {
+    dust_since.retain(|asset, _| dust_balances.iter().any(|(x, _)| x == asset));
+
+    let min_age = Duration::seconds(settings.min_age_secs as i64);
+    dust_balances
+        .iter()
+        .map(|&(asset, _)| asset)
+        .filter(|asset| settings.assets.as_ref().map_or(true, |x| x.contains(asset)))
+        .filter(|&asset| now - *dust_since.entry(asset).or_insert(now) >= min_age)
+        .sorted_by_key(|x| x.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use mmb_domain::amount;
+
+    fn settings(assets: Option<Vec<CurrencyCode>>) -> DustConversionSettings {
+        DustConversionSettings {
+            interval_secs: 3600,
+            assets,
+            min_age_secs: 600,
+        }
+    }
+
+    #[test]
+    fn select_assets_which_are_dust_longer_than_min_age() {
+        let mut dust_since = HashMap::new();
+        let settings = settings(None);
+        let now = Utc::now();
+        let eth = CurrencyCode::from("eth");
+        let ltc = CurrencyCode::from("ltc");
+
+        let dust_balances = [(eth, amount!(0.0001)), (ltc, amount!(0.001))];
+        assert!(select_dust_assets(&mut dust_since, &dust_balances, &settings, now).is_empty());
+
+        // ltc isn't dust anymore, so its age starts over
+        let later = now + Duration::seconds(300);
+        let dust_balances = [(eth, amount!(0.0001))];
+        assert!(select_dust_assets(&mut dust_since, &dust_balances, &settings, later).is_empty());
+
+        let later = now + Duration::seconds(600);
+        let dust_balances = [(eth, amount!(0.0001)), (ltc, amount!(0.001))];
+        assert_eq!(
+            select_dust_assets(&mut dust_since, &dust_balances, &settings, later),
+            vec![eth]
+        );
+    }
+
+    #[test]
+    fn select_configured_assets_only() {
+        let mut dust_since = HashMap::new();
+        let eth = CurrencyCode::from("eth");
+        let ltc = CurrencyCode::from("ltc");
+        let settings = DustConversionSettings {
+            min_age_secs: 0,
+            ..settings(Some(vec![ltc]))
+        };
+
+        let dust_balances = [(eth, amount!(0.0001)), (ltc, amount!(0.001))];
+        assert_eq!(
+            select_dust_assets(&mut dust_since, &dust_balances, &settings, Utc::now()),
+            vec![ltc]
+        );
+    }
+}
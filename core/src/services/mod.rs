@@ -1,6 +1,12 @@
 pub mod cleanup_database;
 pub mod cleanup_orders;
+pub mod composite_symbols;
+pub mod drawdown_flatten;
+pub mod dust_conversion;
 pub mod exchange_time_latency;
 pub mod live_ranges;
 pub(crate) mod market_prices;
+pub mod sampled_recorder;
+pub mod scheduled_flatten;
+pub mod system_status;
 pub mod usd_convertion;
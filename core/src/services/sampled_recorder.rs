@@ -0,0 +1,278 @@
+use crate::lifecycle::trading_engine::Service;
+use crate::settings::{MarketSamplingSettings, SampledRecorderSettings, SamplingRate};
+use anyhow::{Context, Result};
+use mmb_domain::events::{ExchangeEvent, TradesEvent};
+use mmb_domain::market::MarketAccountId;
+use mmb_domain::order::snapshot::OrderSide;
+use mmb_domain::order_book::event::{EventType, OrderBookEvent};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::sync::oneshot::Receiver;
+
+/// Schema of records is the same for trades and order book events. Each trade and each price
+/// level of order book event is written as separate record, `trade_id` is empty for order books
+const CSV_HEADER: &str = "time,exchange_account_id,currency_pair,kind,side,price,amount,trade_id";
+
+/// Selects events according to sampling rate. Fraction is accumulated instead of random
+/// selection, so exactly specified share of events is recorded
+struct Sampler {
+    rate: SamplingRate,
+    events_count: u64,
+    accumulated_fraction: Decimal,
+}
+
+impl Sampler {
+    fn new(rate: SamplingRate) -> Self {
+        Self {
+            rate,
+            events_count: 0,
+            accumulated_fraction: Decimal::ZERO,
+        }
+    }
+
+    fn sample(&mut self) -> bool {
+        match self.rate {
+            SamplingRate::EveryNth(n) => {
+                self.events_count += 1;
+                if self.events_count < n {
+                    return false;
+                }
+
+                self.events_count = 0;
+                true
+            }
+            SamplingRate::Fraction(fraction) => {
+                self.accumulated_fraction += fraction;
+                if self.accumulated_fraction < Decimal::ONE {
+                    return false;
+                }
+
+                self.accumulated_fraction -= Decimal::ONE;
+                true
+            }
+        }
+    }
+}
+
+struct MarketRecorder<W: Write> {
+    sampler: Sampler,
+    writer: W,
+}
+
+impl<W: Write> MarketRecorder<W> {
+    fn new(rate: SamplingRate, writer: W) -> Self {
+        Self {
+            sampler: Sampler::new(rate),
+            writer,
+        }
+    }
+
+    fn record_trades(&mut self, event: &TradesEvent) -> io::Result<()> {
+        if !self.sampler.sample() {
+            return Ok(());
+        }
+
+        for trade in &event.trades {
+            let side = match trade.side {
+                OrderSide::Buy => "buy",
+                OrderSide::Sell => "sell",
+            };
+            writeln!(
+                self.writer,
+                "{},{},{},trade,{side},{},{},{}",
+                trade.transaction_time.to_rfc3339(),
+                event.exchange_account_id,
+                event.currency_pair,
+                trade.price,
+                trade.quantity,
+                trade.trade_id
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn record_order_book(&mut self, event: &OrderBookEvent) -> io::Result<()> {
+        if !self.sampler.sample() {
+            return Ok(());
+        }
+
+        let time = event.creation_time.to_rfc3339();
+        let kind = match event.event_type {
+            EventType::Snapshot => "snapshot",
+            EventType::Update => "update",
+        };
+        for (side, price_levels) in [("ask", &event.data.asks), ("bid", &event.data.bids)] {
+            for (price, amount) in price_levels {
+                writeln!(
+                    self.writer,
+                    "{time},{},{},{kind},{side},{price},{amount},",
+                    event.exchange_account_id, event.currency_pair
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Records sampled trades and order book events of configured markets to CSV files
+/// for offline analysis and generation of backtest data
+pub struct SampledRecorderService {
+    recorders: Mutex<HashMap<MarketAccountId, MarketRecorder<BufWriter<File>>>>,
+}
+
+impl Service for SampledRecorderService {
+    fn name(&self) -> &str {
+        "SampledRecorderService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        self.flush_all();
+        None
+    }
+}
+
+impl SampledRecorderService {
+    pub fn new(settings: &SampledRecorderSettings) -> Result<Self> {
+        let recorders = settings
+            .markets
+            .iter()
+            .map(|market| {
+                let market_account_id =
+                    MarketAccountId::new(market.exchange_account_id, market.currency_pair);
+                let recorder = MarketRecorder::new(market.sampling_rate, open_csv_file(market)?);
+                Ok((market_account_id, recorder))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            recorders: Mutex::new(recorders),
+        })
+    }
+
+    pub async fn start(
+        self: Arc<Self>,
+        mut events_receiver: broadcast::Receiver<ExchangeEvent>,
+    ) -> Result<()> {
+        loop {
+            let event = events_receiver
+                .recv()
+                .await
+                .context("Error during receiving event in SampledRecorderService::start()")?;
+
+            self.handle_event(&event);
+        }
+    }
+
+    fn handle_event(&self, event: &ExchangeEvent) {
+        let (market_account_id, outcome) = match event {
+            ExchangeEvent::Trades(trades) => {
+                let market_account_id =
+                    MarketAccountId::new(trades.exchange_account_id, trades.currency_pair);
+                match self.recorders.lock().get_mut(&market_account_id) {
+                    None => return,
+                    Some(recorder) => (market_account_id, recorder.record_trades(trades)),
+                }
+            }
+            ExchangeEvent::OrderBookEvent(order_book) => {
+                let market_account_id = order_book.market_account_id();
+                match self.recorders.lock().get_mut(&market_account_id) {
+                    None => return,
+                    Some(recorder) => (market_account_id, recorder.record_order_book(order_book)),
+                }
+            }
+            _ => return,
+        };
+
+        if let Err(error) = outcome {
+            log::error!("Failed to record sampled event of {market_account_id:?}: {error}");
+        }
+    }
+
+    /// Should be called periodically to write buffered records to files
+    pub async fn flush(self: Arc<Self>) {
+        self.flush_all();
+    }
+
+    fn flush_all(&self) {
+        for (market_account_id, recorder) in self.recorders.lock().iter_mut() {
+            if let Err(error) = recorder.writer.flush() {
+                log::error!("Failed to flush sampled events of {market_account_id:?}: {error}");
+            }
+        }
+    }
+}
+
+/// Records are appended to existing file, header is written to new file only
+fn open_csv_file(market: &MarketSamplingSettings) -> Result<BufWriter<File>> {
+    let path = &market.output_path;
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Unable to open file {path:?} for sampled events"))?;
+
+    let is_empty = file.metadata()?.len() == 0;
+    let mut writer = BufWriter::new(file);
+    if is_empty {
+        writeln!(writer, "{CSV_HEADER}")?;
+    }
+
+    Ok(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use mmb_domain::events::{Trade, TradeId};
+    use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
+    use mmb_domain::{amount, price};
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn sampled_count(rate: SamplingRate, events_count: usize) -> usize {
+        let mut sampler = Sampler::new(rate);
+        (0..events_count).filter(|_| sampler.sample()).count()
+    }
+
+    #[rstest]
+    #[case(SamplingRate::EveryNth(1), 10)]
+    #[case(SamplingRate::EveryNth(3), 3)]
+    #[case(SamplingRate::Fraction(dec!(1)), 10)]
+    #[case(SamplingRate::Fraction(dec!(0.25)), 2)]
+    fn sample_events(#[case] rate: SamplingRate, #[case] expected: usize) {
+        assert_eq!(sampled_count(rate, 10), expected);
+    }
+
+    #[test]
+    fn record_sampled_trades() {
+        let mut recorder = MarketRecorder::new(SamplingRate::EveryNth(2), Vec::new());
+        let trades_event = |trade_id: u64| TradesEvent {
+            exchange_account_id: ExchangeAccountId::new("Binance", 0),
+            currency_pair: CurrencyPair::from_codes("btc".into(), "usdt".into()),
+            trades: vec![Trade {
+                trade_id: TradeId::Number(trade_id),
+                price: price!(100.5),
+                quantity: amount!(2),
+                side: OrderSide::Sell,
+                transaction_time: Utc.ymd(2021, 9, 20).and_hms(10, 0, 0),
+            }],
+            receipt_time: Utc::now(),
+        };
+
+        recorder.record_trades(&trades_event(1)).expect("in test");
+        recorder.record_trades(&trades_event(2)).expect("in test");
+
+        assert_eq!(
+            String::from_utf8(recorder.writer).expect("in test"),
+            "2021-09-20T10:00:00+00:00,Binance_0,btc/usdt,trade,sell,100.5,2,2\n"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::exchanges::block_reasons::SCHEDULED_FLATTEN;
+use crate::exchanges::exchange_blocker::{BlockType, ExchangeBlocker};
+use crate::exchanges::general::exchange::Exchange;
+use crate::lifecycle::trading_engine::Service;
+use crate::misc::time::time_manager;
+use crate::settings::ScheduledFlattenSettings;
+use anyhow::Result;
+use chrono::{Duration, NaiveTime, Utc};
+use mmb_utils::cancellation_token::CancellationToken;
+use mmb_utils::DateTime;
+use parking_lot::Mutex;
+use std::sync::Arc;
+use tokio::sync::oneshot::Receiver;
+
+/// Flattens exchange account at configured time of day and pauses order creation until resume time
+pub struct ScheduledFlattenService {
+    exchange: Arc<Exchange>,
+    exchange_blocker: Arc<ExchangeBlocker>,
+    settings: ScheduledFlattenSettings,
+    last_check_time: Mutex<DateTime>,
+    cancellation_token: CancellationToken,
+}
+
+impl Service for ScheduledFlattenService {
+    fn name(&self) -> &str {
+        "ScheduledFlattenService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl ScheduledFlattenService {
+    pub fn new(
+        exchange: Arc<Exchange>,
+        exchange_blocker: Arc<ExchangeBlocker>,
+        settings: ScheduledFlattenSettings,
+        cancellation_token: CancellationToken,
+    ) -> Self {
+        Self {
+            exchange,
+            exchange_blocker,
+            settings,
+            last_check_time: Mutex::new(time_manager::now()),
+            cancellation_token,
+        }
+    }
+
+    /// Should be called periodically. Flatten and resume are performed if their time was reached
+    /// since the previous check
+    pub async fn check_schedule(self: Arc<Self>) {
+        let now = time_manager::now();
+        let previous_check_time = std::mem::replace(&mut *self.last_check_time.lock(), now);
+        let exchange_account_id = self.exchange.exchange_account_id;
+
+        if is_time_reached(previous_check_time, now, self.settings.time) {
+            log::warn!("Scheduled flatten of {exchange_account_id} started");
+            self.exchange_blocker
+                .block(exchange_account_id, SCHEDULED_FLATTEN, BlockType::Manual);
+
+            self.exchange
+                .clone()
+                .flatten(self.cancellation_token.clone())
+                .await;
+
+            log::warn!("Scheduled flatten of {exchange_account_id} completed");
+            if self.settings.resume_time.is_none() {
+                self.exchange_blocker
+                    .unblock(exchange_account_id, SCHEDULED_FLATTEN);
+            }
+        }
+
+        if let Some(resume_time) = self.settings.resume_time {
+            let is_paused = self
+                .exchange_blocker
+                .is_blocked_by_reason(exchange_account_id, SCHEDULED_FLATTEN);
+            if is_paused && is_time_reached(previous_check_time, now, resume_time) {
+                log::info!("Trading on {exchange_account_id} resumed after scheduled flatten");
+                self.exchange_blocker
+                    .unblock(exchange_account_id, SCHEDULED_FLATTEN);
+            }
+        }
+    }
+}
+
+/// Whether time of day `time` occurred within interval (`from`, `to`]
+fn is_time_reached(from: DateTime, to: DateTime, time: NaiveTime) -> bool {
+    let mut occurrence = DateTime::from_utc(from.naive_utc().date().and_time(time), Utc);
+    if occurrence <= from {
+        occurrence += Duration::days(1);
+    }
+
+    occurrence <= to
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use rstest::rstest;
+
+    fn date_time(day: u32, hour: u32, min: u32) -> DateTime {
+        Utc.ymd(2021, 9, day).and_hms(hour, min, 0)
+    }
+
+    #[rstest]
+    #[case::inside(date_time(20, 21, 59), date_time(20, 22, 0), true)]
+    #[case::before(date_time(20, 21, 58), date_time(20, 21, 59), false)]
+    #[case::after(date_time(20, 22, 0), date_time(20, 22, 1), false)]
+    #[case::next_day(date_time(20, 22, 30), date_time(21, 22, 0), true)]
+    #[case::over_midnight(date_time(20, 23, 59), date_time(21, 0, 1), false)]
+    fn time_reached(#[case] from: DateTime, #[case] to: DateTime, #[case] expected: bool) {
+        let time = NaiveTime::from_hms_opt(22, 0, 0).expect("in test");
+
+        assert_eq!(is_time_reached(from, to, time), expected);
+    }
+
+    #[test]
+    fn midnight_reached() {
+        let time = NaiveTime::from_hms_opt(0, 0, 0).expect("in test");
+
+        assert!(is_time_reached(
+            date_time(20, 23, 59),
+            date_time(21, 0, 0),
+            time
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+use crate::exchanges::general::exchange::Exchange;
+use crate::lifecycle::trading_engine::Service;
+use anyhow::Result;
+use dashmap::DashMap;
+use futures::future::join_all;
+use mmb_domain::market::ExchangeAccountId;
+use std::sync::Arc;
+use tokio::sync::oneshot::Receiver;
+
+/// Periodically polls exchange-wide statuses to pause order placement during maintenance
+pub struct SystemStatusService {
+    exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>,
+}
+
+impl Service for SystemStatusService {
+    fn name(&self) -> &str {
+        "SystemStatusService"
+    }
+
+    fn graceful_shutdown(self: Arc<Self>) -> Option<Receiver<Result<()>>> {
+        None
+    }
+}
+
+impl SystemStatusService {
+    pub fn new(exchanges: DashMap<ExchangeAccountId, Arc<Exchange>>) -> Self {
+        Self { exchanges }
+    }
+
+    pub async fn update_system_statuses(self: Arc<Self>) {
+        let exchanges = self.exchanges.iter().map(|x| x.clone()).collect::<Vec<_>>();
+        join_all(exchanges.iter().map(|x| x.update_system_status())).await;
+    }
+}
@@ -25,7 +25,6 @@ use mmb_utils::infrastructure::{SpawnFutureFlags, WithExpect};
 use mmb_utils::{cancellation_token::CancellationToken, send_expected::SendExpected, DateTime};
 use mockall_double::double;
 use parking_lot::Mutex;
-use rust_decimal::Decimal;
 use tokio::sync::{broadcast, mpsc, oneshot};
 
 use super::{
@@ -97,6 +96,10 @@ impl PriceSourceEventLoop {
                                 self.update_cache_and_save(market_id);
                             }
                         },
+                        ExchangeEvent::Disconnected(disconnected) => {
+                            self.local_snapshot_service
+                                .discard_snapshots(disconnected.exchange_account_id.exchange_id);
+                        },
                         _ => continue,
                     }
                 }
@@ -404,14 +407,14 @@ impl PriceSourceService {
 pub struct ConvertAmount {
     pub chain: PriceSourceChain,
     pub src_amount: Amount,
-    pub task_finished_sender: oneshot::Sender<Option<Decimal>>,
+    pub task_finished_sender: oneshot::Sender<Option<Amount>>,
 }
 
 impl ConvertAmount {
     pub fn new(
         chain: PriceSourceChain,
         src_amount: Amount,
-        task_finished_sender: oneshot::Sender<Option<Decimal>>,
+        task_finished_sender: oneshot::Sender<Option<Amount>>,
     ) -> Self {
         Self {
             chain,
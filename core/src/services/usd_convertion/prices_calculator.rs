@@ -18,7 +18,7 @@ pub(crate) fn calculate(
     src_amount: Amount,
     price_source_chain: &PriceSourceChain,
     prices: &HashMap<MarketId, Price>,
-) -> Price {
+) -> Amount {
     calculate_amount_for_chain(src_amount, price_source_chain, |market_id| {
         prices.get(&market_id).cloned()
     })
@@ -37,8 +37,8 @@ fn calculate_amount_for_chain(
         let calculated_price = (calculate_price)(market_id)?;
 
         match step.direction {
-            RebaseDirection::ToQuote => rebase_price *= calculated_price,
-            RebaseDirection::ToBase => rebase_price /= calculated_price,
+            RebaseDirection::ToQuote => rebase_price *= calculated_price.value(),
+            RebaseDirection::ToBase => rebase_price /= calculated_price.value(),
         }
     }
     Some(rebase_price * src_amount)
@@ -83,8 +83,10 @@ mod test {
     use std::sync::Arc;
 
     use chrono::Utc;
+    use mmb_domain::amount;
     use mmb_domain::market::CurrencyPair;
     use mmb_domain::order_book_data;
+    use mmb_domain::price;
     use mmb_utils::hashmap;
     use mockall_double::double;
     use parking_lot::ReentrantMutexGuard;
@@ -171,7 +173,7 @@ mod test {
 
         let snapshot_service = LocalSnapshotsService::new(hashmap![market_id => snapshot]);
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now =
             convert_amount(src_amount, &snapshot_service, &price_source_chain).expect("in test");
 
@@ -187,7 +189,7 @@ mod test {
 
         let snapshot_service = LocalSnapshotsService::new(hashmap![market_id => snapshot]);
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now = convert_amount(src_amount, &snapshot_service, &price_source_chain);
 
         assert!(price_now.is_none());
@@ -199,10 +201,10 @@ mod test {
         let time_in_past = Utc::now();
         let market_id = MarketId::new(PriceSourceServiceTestBase::exchange_id(), currency_pair);
         let price_cache = hashmap![
-            market_id => PriceByOrderSide::new(Some(dec!(10)), Some(dec!(2)))
+            market_id => PriceByOrderSide::new(Some(price!(10)), Some(price!(2)))
         ];
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now =
             convert_amount_in_past(src_amount, &price_cache, time_in_past, &price_source_chain)
                 .expect("in test");
@@ -215,7 +217,7 @@ mod test {
         let (_, price_source_chain, _locker) = generate_one_step_setup();
         let time_in_past = Utc::now();
         let price_cache = HashMap::new();
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now =
             convert_amount_in_past(src_amount, &price_cache, time_in_past, &price_source_chain);
 
@@ -225,14 +227,14 @@ mod test {
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn calculate_amount_with_current_cached_prices_using_one_step_with_price() {
         let (currency_pair, price_source_chain, _locker) = generate_one_step_setup();
-        let cached_price = dec!(6);
+        let cached_price = price!(6);
         let market_id = MarketId::new(PriceSourceServiceTestBase::exchange_id(), currency_pair);
         let price_cache = hashmap![market_id => cached_price];
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now = calculate(src_amount, &price_source_chain, &price_cache);
 
-        assert_eq!(dec!(1) / cached_price * src_amount, price_now);
+        assert_eq!(dec!(1) / cached_price.value() * src_amount, price_now);
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -241,7 +243,7 @@ mod test {
         let (_, price_source_chain, _locker) = generate_one_step_setup();
         let price_cache = HashMap::new();
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let _ = calculate(src_amount, &price_source_chain, &price_cache);
     }
 
@@ -328,18 +330,18 @@ mod test {
             PriceSourceServiceTestBase::exchange_id(),
             setup.currency_pair_2,
         );
-        let cached_price_1 = dec!(6);
-        let cached_price_2 = dec!(7);
+        let cached_price_1 = price!(6);
+        let cached_price_2 = price!(7);
         let price_cache = hashmap![
             market_id_1 => cached_price_1,
             market_id_2 => cached_price_2
         ];
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let price_now = calculate(src_amount, &setup.price_source_chain, &price_cache);
 
         assert_eq!(
-            dec!(1) / cached_price_1 / cached_price_2 * src_amount,
+            dec!(1) / cached_price_1.value() / cached_price_2.value() * src_amount,
             price_now
         );
     }
@@ -352,10 +354,10 @@ mod test {
             PriceSourceServiceTestBase::exchange_id(),
             setup.currency_pair_1,
         );
-        let cached_price = dec!(6);
+        let cached_price = price!(6);
         let price_cache = hashmap![market_id => cached_price];
 
-        let src_amount = dec!(10);
+        let src_amount = amount!(10);
         let _ = calculate(src_amount, &setup.price_source_chain, &price_cache);
     }
 }
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use crate::math::{DecimalComputation, RoundForComputation};
 #[cfg(test)]
 use crate::MOCK_MUTEX;
 use mmb_domain::order::snapshot::Amount;
@@ -62,7 +63,7 @@ impl UsdConverter {
         {
             Ok(usd_amount) => {
                 if usd_amount.is_some() {
-                    return usd_amount;
+                    return usd_amount.map(|x| x.round_for(DecimalComputation::Valuation));
                 }
             }
             Err(error) => log::warn!(
@@ -78,6 +79,7 @@ impl UsdConverter {
         self.denominator_usd_converter
             .calculate_using_denominator(from_currency_code, src_amount)
             .await
+            .map(|x| x.round_for(DecimalComputation::Valuation))
     }
 }
 
@@ -1,6 +1,17 @@
+use crate::balance::valuation::ValuationMode;
+use crate::connectivity::Subscription;
+use crate::math::DecimalComputation;
+use anyhow::{bail, Result};
+use chrono::NaiveTime;
+use mmb_domain::events::{AllowedEventSourceType, EventSourceType};
+use mmb_domain::exchanges::commission::Percent;
 use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeAccountId};
-use mmb_domain::order::snapshot::Amount;
+use mmb_domain::order::snapshot::{Amount, OrderRole};
+use mmb_domain::position::MarginMode;
+use mmb_utils::decimal_parsing::DecimalParsingTolerance;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 pub trait DispositionStrategySettings {
@@ -20,10 +31,199 @@ pub struct AppSettings<StrategySettings: Clone> {
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CoreSettings {
+    #[serde(default)]
+    pub shutdown_policy: ShutdownPolicy,
+    /// Handling of orders opened on exchanges before startup. Such orders aren't handled
+    /// until graceful shutdown if not specified
+    pub startup_policy: Option<StartupPolicy>,
+    /// Period of reconciliation of local balances and reservations with balances requested from
+    /// exchanges. 60 seconds if not specified
+    pub balance_update_interval_secs: Option<u64>,
+    /// Max count of price levels of each side kept in local order books. Levels beyond it are
+    /// pruned after each update, so only top levels up to this limit are valid. Full depth is
+    /// kept if not specified
+    pub order_book_max_depth: Option<usize>,
+    /// Max age of market data events (order book updates, trades, liquidations) relative to now.
+    /// Older events are discarded by strategy event consumer, so strategy doesn't quote off
+    /// prices which became stale while events were queued. Order and fill events are never
+    /// discarded. Disabled if not specified
+    pub max_market_data_age_ms: Option<u64>,
+    /// Max relative deviation of new disposition price from price of existing orders within
+    /// which prices are treated as equal, so orders aren't requoted. 0.00001 if not specified
+    pub requote_price_tolerance: Option<Decimal>,
+    /// Scale of results of fee, PnL and valuation computations. Defaults are used if not specified
+    pub decimal_precision: Option<DecimalPrecisionSettings>,
     pub database: Option<DbSettings>,
+    /// Recording of sampled trades and order book events to CSV files. Disabled if not specified
+    pub sampled_recorder: Option<SampledRecorderSettings>,
+    /// Export of OpenTelemetry spans of orders and REST requests. Spans are emitted only if
+    /// core is built with `otel` feature. Disabled if not specified
+    pub tracing: Option<TracingSettings>,
+    /// Measuring of internal events loop iterations and warning about iterations which stall
+    /// the loop. Disabled if not specified
+    pub event_loop_watchdog: Option<EventLoopWatchdogSettings>,
+    /// Persisting of local order books to file and warm start from them after restart.
+    /// Order books are rebuilt from scratch if not specified
+    pub order_book_persistence: Option<OrderBookPersistenceSettings>,
+    /// Synthetic symbols of index products priced from their constituent currency pairs
+    pub composite_symbols: Option<Vec<CompositeSymbolSettings>>,
+    /// Cron-like schedules of named events delivered to strategies by `on_scheduled`
+    pub schedules: Option<Vec<ScheduleSettings>>,
     pub exchanges: Vec<ExchangeSettings>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScheduleSettings {
+    /// Name of event passed to strategies
+    pub name: String,
+    /// Cron expression of 5 fields in UTC: minute, hour, day of month, month, day of week,
+    /// e.g. "0 * * * *" for every hour
+    pub cron: String,
+    #[serde(default)]
+    pub missed_tick_policy: MissedTickPolicy,
+}
+
+/// Handling of schedule occurrences which weren't fired in time, e.g. because of stall of engine
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MissedTickPolicy {
+    /// Missed occurrences are dropped
+    #[default]
+    Skip,
+    /// Missed occurrences are coalesced into single event fired on resume
+    FireOnce,
+}
+
+/// Synthetic symbol which price is weighted sum of prices of constituent currency pairs,
+/// e.g. basket tracking several assets
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CompositeSymbolSettings {
+    /// Unique name which strategies reference composite symbol by
+    pub name: String,
+    pub constituents: Vec<CompositeConstituent>,
+    /// Price of constituents which composite symbol is priced by
+    #[serde(default)]
+    pub price_mode: ValuationMode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CompositeConstituent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    /// Amount of base currency of constituent in one unit of composite symbol
+    pub weight: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderBookPersistenceSettings {
+    /// JSON file which the latest order books are saved to
+    pub file_path: PathBuf,
+    pub save_interval_secs: u64,
+    /// Max age of saved order books which are still useful for warm start.
+    /// Older order books are discarded on startup
+    pub max_age_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EventLoopWatchdogSettings {
+    /// Max duration of a single iteration of events loop. Longer iterations are reported
+    /// by warning and metric
+    pub stall_threshold_ms: u64,
+    /// Count of the latest iterations which p99 latency is calculated over. 1000 if not specified
+    pub latency_window: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct TracingSettings {
+    /// OTLP gRPC endpoint of collector, e.g. `http://localhost:4317`
+    pub otlp_endpoint: String,
+    pub service_name: String,
+}
+
+/// Results of computations are rounded to specified count of decimal places (midpoint to even),
+/// so they are deterministic and don't accumulate unbounded scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct DecimalPrecisionSettings {
+    pub commission_scale: u32,
+    pub profit_loss_scale: u32,
+    pub valuation_scale: u32,
+}
+
+impl Default for DecimalPrecisionSettings {
+    fn default() -> Self {
+        Self {
+            commission_scale: 18,
+            profit_loss_scale: 18,
+            valuation_scale: 12,
+        }
+    }
+}
+
+impl DecimalPrecisionSettings {
+    pub fn scale(&self, computation: DecimalComputation) -> u32 {
+        match computation {
+            DecimalComputation::Commission => self.commission_scale,
+            DecimalComputation::ProfitLoss => self.profit_loss_scale,
+            DecimalComputation::Valuation => self.valuation_scale,
+        }
+    }
+
+    pub fn round(&self, value: Decimal, computation: DecimalComputation) -> Decimal {
+        value.round_dp_with_strategy(
+            self.scale(computation),
+            RoundingStrategy::MidpointNearestEven,
+        )
+    }
+}
+
+/// Handling of opened orders during graceful shutdown
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ShutdownPolicy {
+    /// Cancel all opened orders
+    #[default]
+    CancelAll,
+    /// Leave all opened orders on exchanges.
+    /// Attention! Orders remain unmanaged after shutdown: they can be filled at any time later
+    /// and nobody will hedge or account resulting positions and balance changes
+    LeaveResting,
+    /// Cancel only orders that can be filled immediately by current order book top
+    /// (buy price is not lower than best ask, sell price is not higher than best bid).
+    /// Orders are cancelled as well if order book top is unknown
+    CancelNonPassive,
+}
+
+/// Handling of orders which are already opened on exchanges on startup,
+/// e.g. left by previous session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum StartupPolicy {
+    /// Load opened orders into orders pool, so they are managed like orders created by this session
+    Adopt,
+    /// Cancel all opened orders to start clean
+    CancelAll,
+    /// Leave opened orders on exchanges untouched. They aren't loaded into orders pool and
+    /// aren't cancelled on shutdown, but cancellation of all orders by flatten affects them
+    Ignore,
+}
+
+/// Kind of exchange account which determines endpoints used for balances, positions and orders
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum AccountType {
+    #[default]
+    Spot,
+    /// Spot trading with borrowed funds
+    Margin,
+    /// Derivatives trading with positions
+    Futures,
+}
+
+impl AccountType {
+    /// Account holds positions in addition to currency balances
+    pub fn is_derivative(&self) -> bool {
+        matches!(self, AccountType::Futures)
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
 pub struct DbSettings {
     pub url: String,
@@ -33,6 +233,32 @@ pub struct DbSettings {
     pub postponed_events_dir: Option<PathBuf>,
 }
 
+/// Which market data events are recorded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SamplingRate {
+    /// Share of events in range (0, 1]
+    Fraction(Decimal),
+    /// Every Nth event
+    EveryNth(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MarketSamplingSettings {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub sampling_rate: SamplingRate,
+    /// CSV file which records are appended to. Should be unique for each market
+    pub output_path: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SampledRecorderSettings {
+    /// Period of flushing buffered records to files
+    pub flush_interval_secs: u64,
+    pub markets: Vec<MarketSamplingSettings>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum CurrencyPairSetting {
@@ -43,6 +269,418 @@ pub enum CurrencyPairSetting {
     Specific(String),
 }
 
+impl CurrencyPairSetting {
+    /// Checks that currency pair isn't malformed. Exchange specific currency pairs aren't checked
+    pub fn validate(&self, known_quote_currencies: Option<&[CurrencyCode]>) -> Result<()> {
+        match self {
+            CurrencyPairSetting::Ordinary { base, quote } => {
+                CurrencyPair::try_from_codes(*base, *quote, known_quote_currencies).map(|_| ())
+            }
+            CurrencyPairSetting::Specific(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct IdempotencyCacheSettings {
+    /// Time during which order intent with the same idempotency token is treated as duplicate
+    pub ttl_secs: u64,
+    /// Max count of cached orders. The oldest orders are evicted when limit is reached
+    pub max_size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CandlesSettings {
+    pub interval_secs: u64,
+}
+
+impl CandlesSettings {
+    pub fn validate(&self) -> Result<()> {
+        if self.interval_secs == 0 {
+            bail!("Candle interval_secs should be positive");
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default)]
+pub struct WebsocketSubscriptionSettings {
+    /// Max count of streams subscribed by single websocket message
+    pub batch_size: usize,
+    /// Min interval between subscription messages to stay under exchange message-rate limit
+    pub interval_ms: u64,
+    /// Exchange rejects whole subscription message if any of its streams is invalid
+    /// (delisted currency pair, typo), so streams of rejected message are subscribed again
+    /// one by one and only invalid ones are left unsubscribed. Otherwise all streams of
+    /// rejected message are reported as failed
+    pub isolate_failed_streams: bool,
+}
+
+impl Default for WebsocketSubscriptionSettings {
+    fn default() -> Self {
+        WebsocketSubscriptionSettings {
+            batch_size: 100,
+            interval_ms: 250,
+            isolate_failed_streams: true,
+        }
+    }
+}
+
+/// Criteria checked for each currency pair before order creation is allowed for it.
+/// Local order book should be synced in addition to them
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WarmupSettings {
+    pub min_trades: u64,
+    /// Only candles aggregated locally are counted, so it requires `candles` settings
+    pub min_candles: u64,
+}
+
+/// Value of setting with overrides for specific currency pairs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PerCurrencyPair<T> {
+    default: Option<T>,
+    by_currency_pair: HashMap<CurrencyPair, T>,
+}
+
+impl<T: Copy> PerCurrencyPair<T> {
+    pub fn new(
+        default: Option<T>,
+        by_currency_pair: impl IntoIterator<Item = (CurrencyPair, T)>,
+    ) -> Self {
+        PerCurrencyPair {
+            default,
+            by_currency_pair: by_currency_pair.into_iter().collect(),
+        }
+    }
+
+    /// Value specified for currency pair or default one if there is no override for it
+    pub fn get(&self, currency_pair: CurrencyPair) -> Option<T> {
+        self.by_currency_pair
+            .get(&currency_pair)
+            .copied()
+            .or(self.default)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CurrencyPairMinOrderLifetime {
+    pub currency_pair: CurrencyPair,
+    pub lifetime_ms: u64,
+}
+
+/// Min time which order should rest on exchange before strategy can cancel it to avoid exchange
+/// penalties for excessive order churn. Risk-driven cancellations (e.g. on shutdown) aren't delayed
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MinOrderLifetimeSettings {
+    /// Min lifetime for currency pairs that aren't specified in `currency_pairs`
+    pub default_ms: Option<u64>,
+    pub currency_pairs: Vec<CurrencyPairMinOrderLifetime>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CurrencyPairOrderBookMaxAge {
+    pub currency_pair: CurrencyPair,
+    pub max_age_ms: u64,
+}
+
+/// Max time since the last update of local order book after which it's considered stale,
+/// so creation of orders priced off it is rejected
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderBookFreshnessSettings {
+    /// Max age for currency pairs that aren't specified in `currency_pairs`
+    pub default_max_age_ms: Option<u64>,
+    pub currency_pairs: Vec<CurrencyPairOrderBookMaxAge>,
+}
+
+/// Reference price which order price band is built around
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PriceBandReference {
+    #[default]
+    MarkPrice,
+    IndexPrice,
+}
+
+/// Handling of order priced outside of price band
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PriceBandAction {
+    /// Order creation fails
+    #[default]
+    Reject,
+    /// Order price is moved to the nearest band boundary
+    Clamp,
+}
+
+/// Handling of replacement priced within min price change of original order
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ReplacePriceAction {
+    /// Replacement isn't created and original order is kept
+    #[default]
+    Skip,
+    /// Price of replacement is moved by min price change from original order
+    /// in direction of requested price
+    Adjust,
+}
+
+/// Min price movement which exchange accepts on cancel-replace of order
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReplacePriceSettings {
+    /// Min price change between original order and its replacement in price ticks of symbol
+    pub min_price_change_ticks: u32,
+    #[serde(default)]
+    pub action: ReplacePriceAction,
+}
+
+/// Check of order before its submission to exchange. Checks run in order of declaration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum OrderCheck {
+    /// Market data of currency pair is accumulated for strategy
+    Warmup,
+    /// Local clock isn't skewed from exchange server clock
+    ClockSkew,
+    /// Currency pair is trading
+    SymbolStatus,
+    /// Order type is allowed by exchange for currency pair
+    OrderType,
+    /// Order placement of currency pair isn't paused after consecutive rejections
+    RejectionPause,
+    /// Balance is reserved for order
+    OrderReservation,
+    /// Order price is within price band. Price of order may be clamped by this check
+    PriceBand,
+    /// Order book which order is priced off is fresh
+    OrderBookFreshness,
+    /// Market data stream of currency pair isn't broken
+    MarketDataHealth,
+    /// Limit of open orders of currency pair isn't reached
+    OpenOrdersCount,
+    /// Order count limits of account aren't reached
+    OrderRateLimit,
+    /// Order amount isn't rounded to zero by amount step
+    OrderAmount,
+    /// Good till date order isn't expired
+    TimeInForce,
+    /// Order type is supported by exchange
+    Capabilities,
+}
+
+/// Fees in percents of currency pair overriding default exchange fees
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CurrencyPairFeesSettings {
+    pub currency_pair: CurrencyPair,
+    pub maker: Percent,
+    pub taker: Percent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CurrencyPairPriceBand {
+    pub currency_pair: CurrencyPair,
+    pub max_deviation: Decimal,
+}
+
+/// Band around mark or index price of futures contract which limit order price should be within,
+/// so orders aren't quoted far from fair value when the last trade is an outlier
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PriceBandSettings {
+    /// Max relative deviation of order price from reference price (e.g. 0.01 for 1%)
+    /// for currency pairs that aren't specified in `currency_pairs`
+    pub default_max_deviation: Option<Decimal>,
+    pub currency_pairs: Vec<CurrencyPairPriceBand>,
+    #[serde(default)]
+    pub reference: PriceBandReference,
+    #[serde(default)]
+    pub action: PriceBandAction,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CurrencyPairMarginMode {
+    pub currency_pair: CurrencyPair,
+    pub margin_mode: MarginMode,
+}
+
+/// Margin mode which is set on exchange for futures currency pair before its orders are created
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MarginModeSettings {
+    /// Margin mode for currency pairs that aren't specified in `currency_pairs`
+    pub default: Option<MarginMode>,
+    pub currency_pairs: Vec<CurrencyPairMarginMode>,
+}
+
+impl MarginModeSettings {
+    pub fn margin_mode(&self, currency_pair: CurrencyPair) -> Option<MarginMode> {
+        self.currency_pairs
+            .iter()
+            .find(|x| x.currency_pair == currency_pair)
+            .map(|x| x.margin_mode)
+            .or(self.default)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EndpointProbingSettings {
+    /// Period of latency re-probing after the fastest endpoint was selected on startup
+    pub interval_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RestFailuresReconnectSettings {
+    /// Consecutive REST connection failures after which exchange is reconnected
+    pub max_failures: usize,
+    /// Failures older than this period aren't counted
+    pub window_secs: u64,
+}
+
+/// Limit of retries made for single order by all retry mechanisms together
+/// (client order id regeneration, creation status reconciliation, cancellation re-sending)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderRetryBudgetSettings {
+    pub max_attempts: u32,
+    /// Period since the first retry of order after which it isn't retried anymore
+    pub max_total_secs: u64,
+}
+
+/// Aggregation of consecutive tiny fills of order into single fill event,
+/// so strategies aren't recalculated on every dust fill
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FillEventsAggregationSettings {
+    /// Fills are reported once their total notional in quote currency reaches this value
+    pub min_notional: Amount,
+}
+
+/// Slowing down of requests pacing while REST latency of exchange is high, in addition to
+/// hard rate limits, so requests aren't piled onto congested exchange
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AdaptivePacingSettings {
+    /// Smoothed REST latency above which requests budget of period is divided by
+    /// ratio of latency to this threshold
+    pub latency_threshold_ms: u64,
+    /// Max multiplier of pacing, e.g. 4 means requests budget is divided by 4 at most
+    pub max_multiplier: u32,
+}
+
+/// Refusing of order creation while local clock is skewed from exchange server clock
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClockSkewSettings {
+    /// Max allowed difference between local and server clocks in either direction
+    pub max_offset_ms: u64,
+    /// Period of measuring clock offset
+    pub check_interval_secs: u64,
+}
+
+/// Pause of order placement for currency pair which orders are rejected by exchange again and
+/// again, e.g. because of bad pricing or stale filters
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct RejectionPauseSettings {
+    /// Order placement is paused when count of consecutive rejections exceeds this value.
+    /// Successful placement resets the count
+    pub max_consecutive_rejections: u32,
+    /// Order placement is resumed automatically after this period. Paused until resume
+    /// by endpoint if not specified
+    pub cooldown_secs: Option<u64>,
+}
+
+/// Sources of events which confirm completion of order requests by type. Defaults of exchange
+/// client are used for types which aren't specified. All supported exchanges (Binance, Bitmex,
+/// Interactive Brokers) accept events of any source by default
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct EventSourcePreferenceSettings {
+    pub create: Option<AllowedEventSourceType>,
+    pub fill: Option<AllowedEventSourceType>,
+    pub cancel: Option<AllowedEventSourceType>,
+}
+
+/// Local throttling of order creation by order count limits of account accounted on exchange side
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderRateLimitSettings {
+    /// Period of requesting order count limits status from exchange
+    pub refresh_interval_secs: u64,
+    /// Share of order count limit after which new orders are rejected locally
+    pub max_usage_ratio: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct FundingPaymentsSettings {
+    /// Period of requesting funding payments settled since previous request
+    pub update_interval_secs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderBookSnapshotRefreshSettings {
+    /// Period of re-requesting full order book snapshots by REST
+    pub interval_secs: u64,
+}
+
+/// Daily cancellation of all orders and closing of all positions, e.g. to avoid holding positions
+/// overnight or paying funding. Times are in UTC
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScheduledFlattenSettings {
+    pub time: NaiveTime,
+    /// Order creation is paused after flatten until this time. Not paused if not specified
+    pub resume_time: Option<NaiveTime>,
+}
+
+/// Periodic conversion of dust balances which can't be traded into exchange specific currency,
+/// e.g. into BNB on Binance. Dust is detected in balances polled from exchange
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DustConversionSettings {
+    pub interval_secs: u64,
+    /// Assets which dust is converted. Dust of all assets is converted if not specified
+    pub assets: Option<Vec<CurrencyCode>>,
+    /// Balance should stay dust for this period before conversion, so residuals of orders which
+    /// are still working aren't converted
+    #[serde(default)]
+    pub min_age_secs: u64,
+}
+
+/// Flatten of exchange account when its equity falls from the peak by more than max drawdown.
+/// Equity is balances valued in equity currency plus unrealized PnL of derivative positions
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DrawdownFlattenSettings {
+    pub equity_currency_code: CurrencyCode,
+    #[serde(default)]
+    pub valuation_mode: ValuationMode,
+    pub max_drawdown_percent: Percent,
+    pub check_interval_secs: u64,
+    /// Order creation is resumed right after flatten and peak equity is tracked anew.
+    /// Otherwise order creation stays paused until restart
+    pub auto_resume: bool,
+}
+
+/// How order events of the same kind received from different sources (REST, WebSocket) are merged
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EventMergePolicy {
+    /// The first received event is applied, later ones are ignored
+    #[default]
+    FirstWins,
+    /// Event with later exchange time replaces already applied one. Events without exchange time
+    /// never replace applied ones, because local receipt times of different sources aren't comparable
+    LatestTimestampWins,
+    /// Event from source with higher priority replaces one from source with lower priority
+    SourcePriority,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OrderEventsMergeSettings {
+    pub policy: EventMergePolicy,
+    /// Event sources from the highest to the lowest priority for `SourcePriority` policy
+    pub source_priority: Vec<EventSourceType>,
+}
+
+impl Default for OrderEventsMergeSettings {
+    fn default() -> Self {
+        OrderEventsMergeSettings {
+            policy: EventMergePolicy::default(),
+            source_priority: vec![
+                EventSourceType::Rest,
+                EventSourceType::RestFallback,
+                EventSourceType::WebSocket,
+                EventSourceType::Rpc,
+            ],
+        }
+    }
+}
+
 // Field order are matter for serialization:
 // Simple values must be emitted before struct with custom serialization
 // https://github.com/alexcrichton/toml-rs/issues/142#issuecomment-278970591
@@ -52,12 +690,122 @@ pub struct ExchangeSettings {
     pub exchange_account_id: ExchangeAccountId,
     pub api_key: String,
     pub secret_key: String,
-    pub is_margin_trading: bool,
+    /// Spot if not specified
+    #[serde(default)]
+    pub account_type: AccountType,
     pub request_trades: bool,
     pub is_reducing_market_data: Option<bool>,
     pub subscribe_to_market_data: bool,
-    pub websocket_channels: Vec<String>,
+    pub websocket_channels: Vec<Subscription>,
+    /// Retry order creation once with newly generated client order id if exchange rejected it
+    /// as duplicate. Disabled if not specified
+    pub regenerate_duplicate_client_order_id: Option<bool>,
+    /// Time in milliseconds after request timestamp during which exchange accepts signed request.
+    /// Exchange default is used if not specified
+    pub recv_window_ms: Option<u64>,
+    /// Period in seconds of extending listen key of user data stream. Too long period lets
+    /// exchange expire the key and close the stream, too short one wastes request weight.
+    /// Exchange default is used if not specified. Recommended values:
+    /// Binance spot - 1800 (key expires in 60 minutes, Binance recommends 30 minutes),
+    /// Binance futures - 3000 (key expires in 60 minutes, pinged a bit before expiration).
+    /// Ignored by exchanges without listen keys, e.g. Bitmex authenticates websocket by API key
+    pub listen_key_ping_interval_secs: Option<u64>,
+    /// Reject creation of orders without balance reservation, so each order is checked against
+    /// available balance minus already reserved one. Disabled if not specified
+    pub require_order_reservation: Option<bool>,
+    /// Quote currencies allowed in `currency_pairs`.
+    /// Quote currencies aren't checked if not specified
+    pub known_quote_currencies: Option<Vec<CurrencyCode>>,
     pub currency_pairs: Option<Vec<CurrencyPairSetting>>,
+    /// Deduplication of order intents by idempotency token. Disabled if not specified
+    pub idempotency_cache: Option<IdempotencyCacheSettings>,
+    /// Local aggregation of candles from trades stream. Disabled if not specified
+    pub candles: Option<CandlesSettings>,
+    /// Batching and pacing of websocket subscription messages. Defaults are used if not specified
+    pub websocket_subscription: Option<WebsocketSubscriptionSettings>,
+    /// Merging of order events received from several sources. The first received event wins if not specified
+    pub order_events_merge: Option<OrderEventsMergeSettings>,
+    /// Warmup phase before order creation is allowed. Disabled if not specified
+    pub warmup: Option<WarmupSettings>,
+    /// Selection of the fastest REST endpoint if exchange provides several ones.
+    /// Default endpoint is used if not specified
+    pub endpoint_probing: Option<EndpointProbingSettings>,
+    /// Delay of order cancellation by strategy until order rests min lifetime.
+    /// Disabled if not specified
+    pub min_order_lifetime: Option<MinOrderLifetimeSettings>,
+    /// Rejection of orders priced off stale local order book. Disabled if not specified
+    pub order_book_freshness: Option<OrderBookFreshnessSettings>,
+    /// Max count of open orders per currency pair. New orders exceeding it are rejected
+    /// before sending. Exchange default is used if not specified
+    pub max_open_orders_per_currency_pair: Option<usize>,
+    /// Band around mark or index price which limit order price should be within.
+    /// Disabled if not specified
+    pub price_band: Option<PriceBandSettings>,
+    /// Fees of currency pairs which differ from default ones. Take precedence over
+    /// fees received from exchange. Not overridden if not specified
+    pub currency_pair_fees: Option<Vec<CurrencyPairFeesSettings>>,
+    /// Flatten of exchange account at configured time of day. Disabled if not specified
+    pub scheduled_flatten: Option<ScheduledFlattenSettings>,
+    /// Pause of order creation and reconnect to exchange after repeated REST connection failures.
+    /// Disabled if not specified
+    pub rest_failures_reconnect: Option<RestFailuresReconnectSettings>,
+    /// Margin mode of futures positions. Margin mode set on exchange account is kept
+    /// if not specified
+    pub margin_mode: Option<MarginModeSettings>,
+    /// Collection of funding payments of perpetual futures positions into statistics.
+    /// Disabled if not specified
+    pub funding_payments: Option<FundingPaymentsSettings>,
+    /// Retries of order after which it's marked failed. Unlimited if not specified
+    pub order_retry_budget: Option<OrderRetryBudgetSettings>,
+    /// Periodic replacement of local order books with REST snapshots to correct drift
+    /// accumulated from stream updates. Disabled if not specified
+    pub order_book_snapshot_refresh: Option<OrderBookSnapshotRefreshSettings>,
+    /// Period of refreshing trading status of currency pairs, so trading halts happened during
+    /// session are respected. 300 seconds if not specified
+    pub symbol_status_refresh_interval_secs: Option<u64>,
+    /// Aggregation of tiny fills into single fill event. Every fill is reported
+    /// if not specified
+    pub fill_events_aggregation: Option<FillEventsAggregationSettings>,
+    /// Throttling of order creation near order count limits of account.
+    /// Disabled if not specified
+    pub order_rate_limit: Option<OrderRateLimitSettings>,
+    /// Flatten and pause of trading on max drawdown of equity. Disabled if not specified
+    pub drawdown_flatten: Option<DrawdownFlattenSettings>,
+    /// Adaptive requests pacing by REST latency. Requests are paced by rate limits only
+    /// if not specified
+    pub adaptive_pacing: Option<AdaptivePacingSettings>,
+    /// Refusing new orders while local clock is skewed from exchange server clock by more
+    /// than allowed. Disabled if not specified
+    pub clock_skew: Option<ClockSkewSettings>,
+    /// Min price change of order replacement. Disabled if not specified
+    pub replace_price: Option<ReplacePriceSettings>,
+    /// Checks of order which are skipped before its submission. All checks run if not specified
+    pub disabled_order_checks: Option<Vec<OrderCheck>>,
+    /// Role of order expected to close position, which fee is included in breakeven price.
+    /// Taker if not specified
+    pub breakeven_exit_order_role: Option<OrderRole>,
+    /// Period of caching deposit and withdrawal networks of assets. 3600 seconds if not specified
+    pub asset_networks_cache_ttl_secs: Option<u64>,
+    /// Pause of order placement for currency pair after consecutive rejections.
+    /// Disabled if not specified
+    pub rejection_pause: Option<RejectionPauseSettings>,
+    /// Authoritative sources of order requests completion by request type.
+    /// Defaults of exchange client if not specified
+    pub event_source_preference: Option<EventSourcePreferenceSettings>,
+    /// Min change of currency balance in polled snapshot relative to balance at the previous
+    /// change event which emits balance change event. Every change emits event if not specified
+    pub balance_change_threshold: Option<Decimal>,
+    /// Conversion of dust balances. Disabled if not specified
+    pub dust_conversion: Option<DustConversionSettings>,
+    /// Period after websocket reconnect when order cancellations wait for reconciliation of fills
+    /// missed during disconnection. Disabled if not specified
+    pub reconnect_grace_period_ms: Option<u64>,
+    /// Representations of decimals accepted in exchange responses. Lenient if not specified
+    pub decimal_parsing_tolerance: Option<DecimalParsingTolerance>,
+    /// Request `permessage-deflate` compression of websocket messages, which trades CPU
+    /// for bandwidth. Exchanges which don't support it send messages uncompressed.
+    /// Disabled if not specified
+    pub websocket_compression: Option<bool>,
 }
 
 impl ExchangeSettings {
@@ -66,18 +814,57 @@ impl ExchangeSettings {
         exchange_account_id: ExchangeAccountId,
         api_key: String,
         secret_key: String,
-        is_margin_trading: bool,
+        account_type: AccountType,
     ) -> Self {
         Self {
             exchange_account_id,
             api_key,
             secret_key,
-            is_margin_trading,
+            account_type,
             request_trades: false,
             websocket_channels: vec![],
+            regenerate_duplicate_client_order_id: None,
+            recv_window_ms: None,
+            listen_key_ping_interval_secs: None,
+            require_order_reservation: None,
+            known_quote_currencies: None,
             currency_pairs: None,
             subscribe_to_market_data: true,
             is_reducing_market_data: None,
+            idempotency_cache: None,
+            candles: None,
+            websocket_subscription: None,
+            order_events_merge: None,
+            warmup: None,
+            endpoint_probing: None,
+            min_order_lifetime: None,
+            order_book_freshness: None,
+            max_open_orders_per_currency_pair: None,
+            price_band: None,
+            currency_pair_fees: None,
+            scheduled_flatten: None,
+            rest_failures_reconnect: None,
+            margin_mode: None,
+            funding_payments: None,
+            order_retry_budget: None,
+            order_book_snapshot_refresh: None,
+            symbol_status_refresh_interval_secs: None,
+            fill_events_aggregation: None,
+            order_rate_limit: None,
+            drawdown_flatten: None,
+            adaptive_pacing: None,
+            clock_skew: None,
+            replace_price: None,
+            disabled_order_checks: None,
+            breakeven_exit_order_role: None,
+            asset_networks_cache_ttl_secs: None,
+            rejection_pause: None,
+            event_source_preference: None,
+            balance_change_threshold: None,
+            dust_conversion: None,
+            reconnect_grace_period_ms: None,
+            decimal_parsing_tolerance: None,
+            websocket_compression: None,
         }
     }
 }
@@ -88,12 +875,51 @@ impl Default for ExchangeSettings {
             exchange_account_id: ExchangeAccountId::new("", 0),
             api_key: "".to_string(),
             secret_key: "".to_string(),
-            is_margin_trading: false,
+            account_type: AccountType::Spot,
             request_trades: false,
             websocket_channels: vec![],
+            regenerate_duplicate_client_order_id: None,
+            recv_window_ms: None,
+            listen_key_ping_interval_secs: None,
+            require_order_reservation: None,
+            known_quote_currencies: None,
             currency_pairs: None,
             subscribe_to_market_data: true,
             is_reducing_market_data: None,
+            idempotency_cache: None,
+            candles: None,
+            websocket_subscription: None,
+            order_events_merge: None,
+            warmup: None,
+            endpoint_probing: None,
+            min_order_lifetime: None,
+            order_book_freshness: None,
+            max_open_orders_per_currency_pair: None,
+            price_band: None,
+            currency_pair_fees: None,
+            scheduled_flatten: None,
+            rest_failures_reconnect: None,
+            margin_mode: None,
+            funding_payments: None,
+            order_retry_budget: None,
+            order_book_snapshot_refresh: None,
+            symbol_status_refresh_interval_secs: None,
+            fill_events_aggregation: None,
+            order_rate_limit: None,
+            drawdown_flatten: None,
+            adaptive_pacing: None,
+            clock_skew: None,
+            replace_price: None,
+            disabled_order_checks: None,
+            breakeven_exit_order_role: None,
+            asset_networks_cache_ttl_secs: None,
+            rejection_pause: None,
+            event_source_preference: None,
+            balance_change_threshold: None,
+            dust_conversion: None,
+            reconnect_grace_period_ms: None,
+            decimal_parsing_tolerance: None,
+            websocket_compression: None,
         }
     }
 }
@@ -4,12 +4,14 @@ use mmb_utils::infrastructure::SpawnFutureFlags;
 use mmb_utils::nothing_to_do;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use std::time::Duration;
 
-use mmb_domain::events::ExchangeEvent;
-use mmb_domain::market::MarketAccountId;
+use mmb_domain::events::{ExchangeEvent, OrderRateLimitStatus, RateLimitUsage};
+use mmb_domain::market::{ExchangeAccountId, MarketAccountId};
+use mmb_domain::order::snapshot::Amount;
 use mmb_domain::order::snapshot::ClientOrderId;
-use mmb_domain::order::snapshot::{Amount, Price};
 use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use tokio::sync::broadcast;
 
@@ -25,6 +27,16 @@ pub struct MarketAccountIdStatistic {
     summary_filled_amount: Amount,
     // Calculated only for completely filled orders
     summary_commission: Amount,
+    // Count of canceled orders per completely filled order. None until any order is filled.
+    // Exchanges may penalize high ratio
+    cancel_to_fill_ratio: Option<Decimal>,
+    // Orders submitted at price overlapping with own order of opposite side
+    near_cross_orders_count: u64,
+    // Funding payments of perpetual futures positions in settlement currency.
+    // Negative if more funding was paid than received
+    summary_funding: Amount,
+    // Orders marked failed because their retries exceeded retry budget
+    retries_exhausted_orders_count: u64,
 }
 
 impl MarketAccountIdStatistic {
@@ -34,6 +46,7 @@ impl MarketAccountIdStatistic {
 
     fn register_canceled_order(&mut self) {
         self.canceled_orders_count += 1;
+        self.update_cancel_to_fill_ratio();
     }
 
     fn increment_partially_filled_orders(&mut self) {
@@ -50,26 +63,56 @@ impl MarketAccountIdStatistic {
 
     fn increment_completely_filled_orders(&mut self) {
         self.fully_filled_orders_count += 1;
+        self.update_cancel_to_fill_ratio();
+    }
+
+    fn update_cancel_to_fill_ratio(&mut self) {
+        self.cancel_to_fill_ratio = (self.fully_filled_orders_count != 0).then(|| {
+            Decimal::from(self.canceled_orders_count)
+                / Decimal::from(self.fully_filled_orders_count)
+        });
     }
 
     fn add_summary_filled_amount(&mut self, filled_amount: Amount) {
         self.summary_filled_amount += filled_amount;
     }
 
-    fn add_summary_commission(&mut self, commission: Price) {
+    fn add_summary_commission(&mut self, commission: Amount) {
         self.summary_commission += commission;
     }
+
+    fn register_near_cross_order(&mut self) {
+        self.near_cross_orders_count += 1;
+    }
+
+    fn add_summary_funding(&mut self, funding: Amount) {
+        self.summary_funding += funding;
+    }
+
+    fn register_retries_exhausted_order(&mut self) {
+        self.retries_exhausted_orders_count += 1;
+    }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct DispositionExecutorStatistic {
     skipped_events_amount: u64,
+    // Market data events discarded because they were older than max market data age
+    discarded_stale_events_amount: u64,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
 pub(crate) struct StatisticServiceState {
     market_account_id_stats: RwLock<HashMap<MarketAccountId, MarketAccountIdStatistic>>,
     disposition_executor_stats: Mutex<DispositionExecutorStatistic>,
+    // Last rate limits usage reported by exchange
+    rate_limit_usage: RwLock<HashMap<ExchangeAccountId, RateLimitUsage>>,
+    // Last order count limits status requested from exchange
+    order_rate_limit_status: RwLock<HashMap<ExchangeAccountId, OrderRateLimitStatus>>,
+    // Current multiplier of requests pacing adapted to REST latency
+    pacing_multiplier: RwLock<HashMap<ExchangeAccountId, Decimal>>,
+    // p99 duration of internal events loop iterations over the latest iterations window
+    event_loop_p99_latency_us: Mutex<Option<u64>>,
 }
 
 impl StatisticServiceState {
@@ -128,7 +171,7 @@ impl StatisticServiceState {
     pub(crate) fn register_commission(
         &self,
         market_account_id: MarketAccountId,
-        commission: Price,
+        commission: Amount,
     ) {
         self.market_account_id_stats
             .write()
@@ -137,9 +180,76 @@ impl StatisticServiceState {
             .add_summary_commission(commission);
     }
 
+    pub(crate) fn register_near_cross_order(&self, market_account_id: MarketAccountId) {
+        self.market_account_id_stats
+            .write()
+            .entry(market_account_id)
+            .or_default()
+            .register_near_cross_order();
+    }
+
+    pub(crate) fn add_summary_funding(&self, market_account_id: MarketAccountId, funding: Amount) {
+        self.market_account_id_stats
+            .write()
+            .entry(market_account_id)
+            .or_default()
+            .add_summary_funding(funding);
+    }
+
+    pub(crate) fn register_retries_exhausted_order(&self, market_account_id: MarketAccountId) {
+        self.market_account_id_stats
+            .write()
+            .entry(market_account_id)
+            .or_default()
+            .register_retries_exhausted_order();
+    }
+
     pub(crate) fn register_skipped_event(&self) {
         self.disposition_executor_stats.lock().skipped_events_amount += 1;
     }
+
+    pub(crate) fn register_discarded_stale_event(&self) {
+        self.disposition_executor_stats
+            .lock()
+            .discarded_stale_events_amount += 1;
+    }
+
+    pub(crate) fn register_rate_limit_usage(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        usage: RateLimitUsage,
+    ) {
+        let _ = self
+            .rate_limit_usage
+            .write()
+            .insert(exchange_account_id, usage);
+    }
+
+    pub(crate) fn register_order_rate_limit_status(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        status: OrderRateLimitStatus,
+    ) {
+        let _ = self
+            .order_rate_limit_status
+            .write()
+            .insert(exchange_account_id, status);
+    }
+
+    pub(crate) fn register_pacing_multiplier(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        multiplier: Decimal,
+    ) {
+        let _ = self
+            .pacing_multiplier
+            .write()
+            .insert(exchange_account_id, multiplier);
+    }
+
+    pub(crate) fn register_event_loop_p99_latency(&self, latency: Duration) {
+        *self.event_loop_p99_latency_us.lock() = Some(latency.as_micros() as u64);
+    }
 }
 
 #[derive(Default, Debug)]
@@ -216,9 +326,61 @@ impl StatisticService {
         }
     }
 
+    pub(crate) fn register_near_cross_order(&self, market_account_id: MarketAccountId) {
+        self.statistic_service_state
+            .register_near_cross_order(market_account_id);
+    }
+
+    pub(crate) fn add_summary_funding(&self, market_account_id: MarketAccountId, funding: Amount) {
+        self.statistic_service_state
+            .add_summary_funding(market_account_id, funding);
+    }
+
+    pub(crate) fn register_retries_exhausted_order(&self, market_account_id: MarketAccountId) {
+        self.statistic_service_state
+            .register_retries_exhausted_order(market_account_id);
+    }
+
     pub(crate) fn register_skipped_event(&self) {
         self.statistic_service_state.register_skipped_event();
     }
+
+    pub(crate) fn register_discarded_stale_event(&self) {
+        self.statistic_service_state
+            .register_discarded_stale_event();
+    }
+
+    pub(crate) fn register_rate_limit_usage(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        usage: RateLimitUsage,
+    ) {
+        self.statistic_service_state
+            .register_rate_limit_usage(exchange_account_id, usage);
+    }
+
+    pub(crate) fn register_order_rate_limit_status(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        status: OrderRateLimitStatus,
+    ) {
+        self.statistic_service_state
+            .register_order_rate_limit_status(exchange_account_id, status);
+    }
+
+    pub(crate) fn register_pacing_multiplier(
+        &self,
+        exchange_account_id: ExchangeAccountId,
+        multiplier: Decimal,
+    ) {
+        self.statistic_service_state
+            .register_pacing_multiplier(exchange_account_id, multiplier);
+    }
+
+    pub(crate) fn register_event_loop_p99_latency(&self, latency: Duration) {
+        self.statistic_service_state
+            .register_event_loop_p99_latency(latency);
+    }
 }
 
 pub struct StatisticEventHandler {
@@ -274,7 +436,7 @@ impl StatisticEventHandler {
                         self.stats
                             .register_canceled_order(market_account_id, &client_order_id);
                     }
-                    OrderEventType::OrderFilled { cloned_order } => {
+                    OrderEventType::OrderFilled { cloned_order, .. } => {
                         self.stats.register_partially_filled_order(
                             market_account_id,
                             &cloned_order.header.client_order_id,
@@ -300,9 +462,61 @@ impl StatisticEventHandler {
                     _ => nothing_to_do(),
                 }
             }
+            ExchangeEvent::NearCross(event) => {
+                self.stats.register_near_cross_order(MarketAccountId::new(
+                    event.exchange_account_id,
+                    event.currency_pair,
+                ));
+            }
+            ExchangeEvent::FundingPayment(event) => {
+                self.stats.add_summary_funding(
+                    MarketAccountId::new(event.exchange_account_id, event.payment.currency_pair),
+                    event.payment.amount,
+                );
+            }
+            ExchangeEvent::RetriesExhausted(event) => {
+                self.stats
+                    .register_retries_exhausted_order(MarketAccountId::new(
+                        event.exchange_account_id,
+                        event.currency_pair,
+                    ));
+            }
+            ExchangeEvent::RateLimitUsage(event) => {
+                self.stats
+                    .register_rate_limit_usage(event.exchange_account_id, event.usage);
+            }
+            ExchangeEvent::OrderRateLimitStatus(event) => {
+                self.stats
+                    .register_order_rate_limit_status(event.exchange_account_id, event.status);
+            }
+            ExchangeEvent::PacingChanged(event) => {
+                self.stats
+                    .register_pacing_multiplier(event.exchange_account_id, event.multiplier);
+            }
             _ => nothing_to_do(),
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn cancel_to_fill_ratio() {
+        let mut statistic = MarketAccountIdStatistic::default();
+        statistic.register_canceled_order();
+        assert_eq!(statistic.cancel_to_fill_ratio, None);
+
+        statistic.increment_completely_filled_orders();
+        statistic.register_canceled_order();
+        statistic.register_canceled_order();
+        assert_eq!(statistic.cancel_to_fill_ratio, Some(dec!(3)));
+
+        statistic.increment_completely_filled_orders();
+        assert_eq!(statistic.cancel_to_fill_ratio, Some(dec!(1.5)));
+    }
+}
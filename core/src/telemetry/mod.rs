@@ -0,0 +1,13 @@
+//! OpenTelemetry spans of order lifecycle (submit → ack → fills → finish) and REST requests
+//! exported via OTLP. Spans are emitted only if core is built with `otel` feature and
+//! tracing is configured in settings, otherwise all functions are no-op
+
+#[cfg(not(feature = "otel"))]
+mod noop;
+#[cfg(feature = "otel")]
+mod otel;
+
+#[cfg(not(feature = "otel"))]
+pub use noop::*;
+#[cfg(feature = "otel")]
+pub use otel::*;
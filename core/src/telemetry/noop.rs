@@ -0,0 +1,34 @@
+use crate::exchanges::traits::ExchangeError;
+use crate::settings::TracingSettings;
+use anyhow::Result;
+use mmb_domain::market::ExchangeAccountId;
+use mmb_domain::order::event::OrderEventType;
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::OrderHeader;
+
+pub fn init_tracing(_settings: &TracingSettings) -> Result<()> {
+    log::warn!(
+        "Tracing is configured, but core is built without `otel` feature, so spans aren't emitted"
+    );
+    Ok(())
+}
+
+pub fn shutdown_tracing() {}
+
+pub fn start_order_span(_order_header: &OrderHeader) {}
+
+pub fn record_order_event(_order: &OrderRef, _event_type: &OrderEventType) {}
+
+pub struct RestSpan;
+
+impl RestSpan {
+    pub fn start(
+        _exchange_account_id: ExchangeAccountId,
+        _method: &'static str,
+        _action_name: &'static str,
+    ) -> Self {
+        RestSpan
+    }
+
+    pub fn end<T>(self, _result: &Result<T, ExchangeError>) {}
+}
@@ -0,0 +1,170 @@
+use crate::exchanges::traits::ExchangeError;
+use crate::settings::TracingSettings;
+use anyhow::{Context, Result};
+use dashmap::DashMap;
+use mmb_domain::market::ExchangeAccountId;
+use mmb_domain::order::event::OrderEventType;
+use mmb_domain::order::pool::OrderRef;
+use mmb_domain::order::snapshot::{ClientOrderId, OrderHeader, OrderStatus};
+use mmb_utils::correlation_id::CorrelationId;
+use once_cell::sync::Lazy;
+use opentelemetry::global::{self, BoxedSpan, BoxedTracer};
+use opentelemetry::sdk::{trace, Resource};
+use opentelemetry::trace::{Span, StatusCode, Tracer};
+use opentelemetry::KeyValue;
+use opentelemetry_otlp::WithExportConfig;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+const TRACER_NAME: &str = "mmb";
+
+static IS_ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Spans of orders which are open from submission until order is finished
+static ORDER_SPANS: Lazy<DashMap<ClientOrderId, BoxedSpan>> = Lazy::new(DashMap::new);
+
+pub fn init_tracing(settings: &TracingSettings) -> Result<()> {
+    let exporter = opentelemetry_otlp::new_exporter()
+        .tonic()
+        .with_endpoint(settings.otlp_endpoint.clone());
+    let resource = Resource::new(vec![KeyValue::new(
+        "service.name",
+        settings.service_name.clone(),
+    )]);
+
+    let _ = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(exporter)
+        .with_trace_config(trace::config().with_resource(resource))
+        .install_batch(opentelemetry::runtime::Tokio)
+        .context("Unable to install OTLP tracing pipeline")?;
+
+    IS_ENABLED.store(true, Ordering::SeqCst);
+    log::info!("Spans are exported to {}", settings.otlp_endpoint);
+
+    Ok(())
+}
+
+/// Export remaining spans. Spans of orders which aren't finished yet are ended as is
+pub fn shutdown_tracing() {
+    if !IS_ENABLED.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    let client_order_ids: Vec<_> = ORDER_SPANS.iter().map(|x| x.key().clone()).collect();
+    for client_order_id in client_order_ids {
+        if let Some((_, mut span)) = ORDER_SPANS.remove(&client_order_id) {
+            span.end();
+        }
+    }
+
+    global::shutdown_tracer_provider();
+}
+
+fn tracer() -> Option<BoxedTracer> {
+    IS_ENABLED
+        .load(Ordering::Relaxed)
+        .then(|| global::tracer(TRACER_NAME))
+}
+
+/// Correlation id is attached to order and REST spans, so requests made for order
+/// can be found in trace of order
+fn correlation_id_attribute() -> Option<KeyValue> {
+    CorrelationId::current().map(|x| KeyValue::new("correlation_id", x.to_string()))
+}
+
+pub fn start_order_span(order_header: &OrderHeader) {
+    let tracer = match tracer() {
+        Some(tracer) => tracer,
+        None => return,
+    };
+
+    let mut attributes = vec![
+        KeyValue::new("exchange", order_header.exchange_account_id.to_string()),
+        KeyValue::new("currency_pair", order_header.currency_pair.to_string()),
+        KeyValue::new("client_order_id", order_header.client_order_id.to_string()),
+        KeyValue::new("side", format!("{:?}", order_header.side)),
+        KeyValue::new("amount", order_header.amount.to_string()),
+    ];
+    attributes.extend(correlation_id_attribute());
+
+    let span = tracer
+        .span_builder("order")
+        .with_attributes(attributes)
+        .start(&tracer);
+    let _ = ORDER_SPANS.insert(order_header.client_order_id.clone(), span);
+}
+
+/// Add lifecycle event to span of order and end the span when order is finished
+pub fn record_order_event(order: &OrderRef, event_type: &OrderEventType) {
+    let client_order_id = order.client_order_id();
+    if let Some(mut span) = ORDER_SPANS.get_mut(&client_order_id) {
+        span.add_event(
+            order_event_name(event_type),
+            vec![KeyValue::new(
+                "filled_amount",
+                order.filled_amount().to_string(),
+            )],
+        );
+    }
+
+    if !order.is_finished() {
+        return;
+    }
+
+    if let Some((_, mut span)) = ORDER_SPANS.remove(&client_order_id) {
+        if order.status() == OrderStatus::FailedToCreate {
+            span.set_status(StatusCode::Error, "Order failed to create".to_owned());
+        }
+        span.end();
+    }
+}
+
+fn order_event_name(event_type: &OrderEventType) -> &'static str {
+    match event_type {
+        OrderEventType::CreateOrderSucceeded => "ack",
+        OrderEventType::CreateOrderFailed => "create_failed",
+        OrderEventType::OrderFilled { .. } => "fill",
+        OrderEventType::OrderCompleted { .. } => "completed",
+        OrderEventType::CancelOrderSucceeded => "canceled",
+        OrderEventType::CancelOrderFailed => "cancel_failed",
+        OrderEventType::ClientOrderIdRegenerated { .. } => "client_order_id_regenerated",
+    }
+}
+
+/// Span of single REST request
+pub struct RestSpan(Option<BoxedSpan>);
+
+impl RestSpan {
+    pub fn start(
+        exchange_account_id: ExchangeAccountId,
+        method: &'static str,
+        action_name: &'static str,
+    ) -> Self {
+        let span = tracer().map(|tracer| {
+            let mut attributes = vec![
+                KeyValue::new("exchange", exchange_account_id.to_string()),
+                KeyValue::new("http.method", method),
+            ];
+            attributes.extend(correlation_id_attribute());
+
+            tracer
+                .span_builder(action_name)
+                .with_attributes(attributes)
+                .start(&tracer)
+        });
+
+        RestSpan(span)
+    }
+
+    pub fn end<T>(self, result: &Result<T, ExchangeError>) {
+        let mut span = match self.0 {
+            Some(span) => span,
+            None => return,
+        };
+
+        if let Err(error) = result {
+            span.set_status(StatusCode::Error, format!("{:?}", error.error_type));
+        }
+        span.end();
+    }
+}
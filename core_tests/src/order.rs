@@ -26,7 +26,7 @@ use std::sync::Arc;
 /// use mmb_domain::market::{ };
 /// use mmb_core::exchanges::general::exchange::Exchange;
 /// use mmb_utils::cancellation_token::CancellationToken;
-/// use rust_decimal_macros::dec;
+/// use mmb_domain::amount;
 /// use std::sync::Arc;
 /// use core_tests::order::OrderProxy;
 /// use mmb_domain::order::snapshot::{Amount, Price};
@@ -40,7 +40,7 @@ use std::sync::Arc;
 ///         amount,
 ///         currency_pair,
 ///     );
-///     order_proxy.amount = dec!(5000); // Optional amount changing
+///     order_proxy.amount = amount!(5000); // Optional amount changing
 ///     let created_order = order_proxy.create_order(exchange.clone()).await;
 /// }
 /// ```
@@ -0,0 +1,292 @@
+use crate::order::snapshot::{Amount, Price};
+use chrono::Duration;
+use mmb_utils::DateTime;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Candle {
+    pub open_time: DateTime,
+    pub close_time: DateTime,
+    pub open: Price,
+    pub high: Price,
+    pub low: Price,
+    pub close: Price,
+    pub volume: Amount,
+}
+
+impl Candle {
+    fn new(open_time: DateTime, interval: Duration, price: Price, volume: Amount) -> Self {
+        Candle {
+            open_time,
+            close_time: open_time + interval,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        }
+    }
+
+    fn add_trade(&mut self, price: Price, amount: Amount) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += amount;
+    }
+}
+
+/// Max count of flat candles filling a gap without trades. Older candles of longer gaps
+/// (e.g. after long disconnection) are skipped, so a gap can't produce unbounded count of candles
+const MAX_GAP_CANDLES: i64 = 1000;
+
+/// Close price of the last closed candle, which is carried forward to flat candles of intervals
+/// without trades
+struct LastClose {
+    next_open_time: DateTime,
+    price: Price,
+}
+
+/// Aggregates trades into candles with fixed interval aligned to UNIX epoch
+pub struct CandleAggregator {
+    interval: Duration,
+    current: Option<Candle>,
+    last_close: Option<LastClose>,
+}
+
+impl CandleAggregator {
+    pub fn new(interval: Duration) -> Self {
+        assert!(
+            interval > Duration::zero(),
+            "Candle interval should be positive"
+        );
+
+        CandleAggregator {
+            interval,
+            current: None,
+            last_close: None,
+        }
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Add trade to current candle and returns candles closed by this trade.
+    /// If there were no trades during some intervals, flat candles with previous close price
+    /// and zero volume are returned for them. Trades from already closed intervals are ignored
+    pub fn add_trade(&mut self, price: Price, amount: Amount, time: DateTime) -> Vec<Candle> {
+        let open_time = self.get_open_time(time);
+        let closed_candles = self.close_candles(open_time);
+
+        let is_closed_interval = match (&self.current, &self.last_close) {
+            (Some(current), _) => open_time < current.open_time,
+            (None, Some(last_close)) => open_time < last_close.next_open_time,
+            (None, None) => false,
+        };
+        if is_closed_interval {
+            log::trace!("Trade at {time} is ignored because its candle is already closed");
+            return closed_candles;
+        }
+
+        match &mut self.current {
+            Some(current) => current.add_trade(price, amount),
+            None => self.current = Some(Candle::new(open_time, self.interval, price, amount)),
+        }
+
+        closed_candles
+    }
+
+    /// Closes candles of intervals ended by `time`, so candles are closed even if there are
+    /// no trades after them. Intervals without trades are closed as flat candles,
+    /// but no more than `MAX_GAP_CANDLES` of the latest ones
+    pub fn close_candles(&mut self, time: DateTime) -> Vec<Candle> {
+        let mut closed_candles = vec![];
+
+        match &self.current {
+            Some(current) if current.close_time > time => return closed_candles,
+            Some(current) => {
+                self.last_close = Some(LastClose {
+                    next_open_time: current.close_time,
+                    price: current.close,
+                });
+                closed_candles.extend(self.current.take());
+            }
+            None => {}
+        }
+
+        let interval_nanos = self.interval_nanos();
+        let latest_open_time = self.get_open_time(time);
+        if let Some(last_close) = &mut self.last_close {
+            let gap_candles_count = (time - last_close.next_open_time)
+                .num_nanoseconds()
+                .map_or(i64::MAX, |x| x / interval_nanos);
+            if gap_candles_count > MAX_GAP_CANDLES {
+                let skipped_count = gap_candles_count - MAX_GAP_CANDLES;
+                log::warn!("{skipped_count} flat candles since {} are skipped because gap without trades is too long", last_close.next_open_time);
+                last_close.next_open_time =
+                    latest_open_time - Duration::nanoseconds(MAX_GAP_CANDLES * interval_nanos);
+            }
+
+            while last_close.next_open_time + self.interval <= time {
+                closed_candles.push(Candle::new(
+                    last_close.next_open_time,
+                    self.interval,
+                    last_close.price,
+                    Amount::ZERO,
+                ));
+                last_close.next_open_time += self.interval;
+            }
+        }
+
+        closed_candles
+    }
+
+    fn get_open_time(&self, time: DateTime) -> DateTime {
+        time - Duration::nanoseconds(time.timestamp_nanos().rem_euclid(self.interval_nanos()))
+    }
+
+    fn interval_nanos(&self) -> i64 {
+        self.interval
+            .num_nanoseconds()
+            .expect("Candle interval should be representable in nanoseconds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{amount, price};
+    use chrono::{TimeZone, Utc};
+
+    fn time(secs: i64) -> DateTime {
+        Utc.timestamp_opt(secs, 0).single().expect("in test")
+    }
+
+    #[test]
+    fn aggregate_trades_into_candle() {
+        let mut aggregator = CandleAggregator::new(Duration::seconds(60));
+
+        assert!(aggregator
+            .add_trade(price!(10), amount!(1), time(61))
+            .is_empty());
+        assert!(aggregator
+            .add_trade(price!(12), amount!(2), time(80))
+            .is_empty());
+        assert!(aggregator
+            .add_trade(price!(9), amount!(1), time(100))
+            .is_empty());
+        assert!(aggregator
+            .add_trade(price!(11), amount!(3), time(119))
+            .is_empty());
+
+        let closed = aggregator.add_trade(price!(15), amount!(1), time(120));
+
+        assert_eq!(
+            closed,
+            vec![Candle {
+                open_time: time(60),
+                close_time: time(120),
+                open: price!(10),
+                high: price!(12),
+                low: price!(9),
+                close: price!(11),
+                volume: amount!(7),
+            }]
+        );
+    }
+
+    #[test]
+    fn carry_forward_close_for_intervals_without_trades() {
+        let mut aggregator = CandleAggregator::new(Duration::seconds(60));
+
+        let _ = aggregator.add_trade(price!(10), amount!(1), time(0));
+        let closed = aggregator.add_trade(price!(12), amount!(1), time(190));
+
+        assert_eq!(closed.len(), 3);
+        for (candle, open_time) in closed[1..].iter().zip([60, 120]) {
+            assert_eq!(candle.open_time, time(open_time));
+            assert_eq!(candle.open, price!(10));
+            assert_eq!(candle.high, price!(10));
+            assert_eq!(candle.low, price!(10));
+            assert_eq!(candle.close, price!(10));
+            assert_eq!(candle.volume, amount!(0));
+        }
+    }
+
+    #[test]
+    fn close_candles_without_next_trade() {
+        let mut aggregator = CandleAggregator::new(Duration::seconds(60));
+
+        let _ = aggregator.add_trade(price!(10), amount!(1), time(0));
+        assert!(aggregator.close_candles(time(59)).is_empty());
+
+        let closed = aggregator.close_candles(time(60));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, time(0));
+        assert_eq!(closed[0].volume, amount!(1));
+
+        assert!(aggregator.close_candles(time(119)).is_empty());
+
+        let closed = aggregator.close_candles(time(185));
+        assert_eq!(
+            closed.iter().map(|x| x.open_time).collect::<Vec<_>>(),
+            vec![time(60), time(120)]
+        );
+        assert!(closed
+            .iter()
+            .all(|x| x.close == price!(10) && x.volume.is_zero()));
+
+        // trade within the interval following flat candles opens a new candle
+        assert!(aggregator
+            .add_trade(price!(12), amount!(1), time(190))
+            .is_empty());
+        assert!(aggregator
+            .add_trade(price!(100), amount!(1), time(179))
+            .is_empty());
+
+        let closed = aggregator.close_candles(time(240));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, time(180));
+        assert_eq!(closed[0].open, price!(12));
+        assert_eq!(closed[0].high, price!(12));
+    }
+
+    #[test]
+    fn cap_flat_candles_of_long_gap() {
+        let mut aggregator = CandleAggregator::new(Duration::seconds(60));
+
+        let _ = aggregator.add_trade(price!(10), amount!(1), time(0));
+        let gap_end = 60 * (MAX_GAP_CANDLES + 100);
+        let closed = aggregator.close_candles(time(gap_end + 30));
+
+        // candle with trade and the latest flat candles
+        assert_eq!(closed.len() as i64, MAX_GAP_CANDLES + 1);
+        assert_eq!(closed[0].open_time, time(0));
+        assert_eq!(closed[1].open_time, time(gap_end - 60 * MAX_GAP_CANDLES));
+        assert_eq!(
+            closed.last().expect("in test").open_time,
+            time(gap_end - 60)
+        );
+        assert!(closed[1..].iter().all(|x| x.close == price!(10)));
+
+        let closed = aggregator.close_candles(time(gap_end + 60));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, time(gap_end));
+    }
+
+    #[test]
+    fn ignore_trades_from_closed_interval() {
+        let mut aggregator = CandleAggregator::new(Duration::seconds(60));
+
+        let _ = aggregator.add_trade(price!(10), amount!(1), time(0));
+        let _ = aggregator.add_trade(price!(12), amount!(1), time(60));
+
+        assert!(aggregator
+            .add_trade(price!(100), amount!(1), time(59))
+            .is_empty());
+
+        let closed = aggregator.add_trade(price!(13), amount!(1), time(120));
+        assert_eq!(closed[0].high, price!(12));
+        assert_eq!(closed[0].volume, amount!(1));
+    }
+}
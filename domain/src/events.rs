@@ -6,14 +6,15 @@ use std::fmt::{Debug, Display, Formatter};
 use mmb_database::impl_event;
 use mmb_utils::DateTime;
 use rust_decimal::Decimal;
-use rust_decimal_macros::dec;
 use serde::{de, Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use tokio::sync::broadcast;
 
+use crate::candle::Candle;
+use crate::exchanges::funding::FundingPayment;
 use crate::market::{CurrencyCode, CurrencyPair, ExchangeAccountId};
 use crate::order::event::OrderEvent;
-use crate::order::snapshot::{Amount, OrderSide, OrderStatus, Price};
+use crate::order::snapshot::{Amount, ClientOrderId, OrderSide, OrderStatus, Price};
 use crate::order_book::event::OrderBookEvent;
 use crate::position::DerivativePosition;
 
@@ -22,7 +23,7 @@ pub const CHANNEL_MAX_EVENTS_COUNT: usize = 200_000;
 #[derive(Debug, Clone)]
 pub struct ExchangeBalance {
     pub currency_code: CurrencyCode,
-    pub balance: Decimal,
+    pub balance: Amount,
 }
 
 #[derive(Clone)]
@@ -36,7 +37,7 @@ impl Debug for ExchangeBalancesAndPositions {
         let non_zero_balances = self
             .balances
             .iter()
-            .filter(|x| x.balance > dec!(0))
+            .filter(|x| x.balance > Amount::ZERO)
             .collect_vec();
         f.debug_struct("ExchangeBalancesAndPositions")
             .field("balances", &non_zero_balances)
@@ -202,6 +203,350 @@ pub struct Trade {
     pub transaction_time: DateTime,
 }
 
+/// Public trades of the same taker order executed at the same price and time,
+/// aggregated by exchange into one record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AggTrade {
+    pub aggregate_trade_id: u64,
+    pub price: Price,
+    pub quantity: Amount,
+    pub first_trade_id: u64,
+    pub last_trade_id: u64,
+    /// Side of taker order
+    pub side: OrderSide,
+    pub transaction_time: DateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AggTradeEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub trade: AggTrade,
+}
+
+/// Exchange-wide operational status
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SystemStatus {
+    #[default]
+    Normal,
+    Maintenance,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemStatusEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub previous_status: SystemStatus,
+    pub status: SystemStatus,
+    pub event_creation_time: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum FlattenStage {
+    Started,
+    Completed,
+}
+
+/// Cancellation of all orders and closing of all positions on exchange account by schedule
+#[derive(Debug, Clone, Serialize)]
+pub struct FlattenEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub stage: FlattenStage,
+    pub event_creation_time: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ReconnectGracePeriodStage {
+    Started,
+    Ended,
+}
+
+/// Period after websocket reconnect when fills missed during disconnection are reconciled by
+/// REST and cancellation of orders is postponed
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconnectGracePeriodEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub stage: ReconnectGracePeriodStage,
+    pub event_creation_time: DateTime,
+}
+
+/// Equity of exchange account fell from its peak by more than max drawdown,
+/// so exchange account is flattened and order creation is paused
+#[derive(Debug, Clone, Serialize)]
+pub struct MaxDrawdownReachedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub peak_equity: Amount,
+    pub equity: Amount,
+    pub drawdown_percent: Decimal,
+    pub event_creation_time: DateTime,
+}
+
+/// Offset of local clock from exchange server clock exceeded max offset or returned within it.
+/// Order creation is refused while it is exceeded
+#[derive(Debug, Clone, Serialize)]
+pub struct ClockSkewEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    /// Local time minus server time
+    pub offset_ms: i64,
+    pub max_offset_ms: i64,
+    pub is_exceeded: bool,
+    pub event_creation_time: DateTime,
+}
+
+/// Kind of rate limit accounted on exchange side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RateLimitKind {
+    RequestWeight,
+    OrdersCount,
+}
+
+/// Server-side usage of rate limit in interval, e.g. `X-MBX-USED-WEIGHT-1M` header of Binance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitCounter {
+    pub kind: RateLimitKind,
+    pub interval: std::time::Duration,
+    pub used: usize,
+}
+
+/// Rate limits usage reported by exchange in response headers
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitUsage {
+    pub counters: Vec<RateLimitCounter>,
+}
+
+impl RateLimitUsage {
+    pub fn is_empty(&self) -> bool {
+        self.counters.is_empty()
+    }
+
+    pub fn get(
+        &self,
+        kind: RateLimitKind,
+        interval: std::time::Duration,
+    ) -> Option<&RateLimitCounter> {
+        self.counters
+            .iter()
+            .find(|x| x.kind == kind && x.interval == interval)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimitUsageEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub usage: RateLimitUsage,
+}
+
+/// Requests pacing was slowed down or eased back by observed REST latency.
+/// Requests budget of period is divided by multiplier
+#[derive(Debug, Clone, Serialize)]
+pub struct PacingChangedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub multiplier: Decimal,
+}
+
+/// Order count limit of account in interval reported by exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderRateLimit {
+    pub interval: std::time::Duration,
+    /// Orders counted by exchange in current interval.
+    /// `None` if exchange reports only the limit itself
+    pub used: Option<usize>,
+    pub limit: usize,
+}
+
+/// Order count limits of account reported by exchange
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderRateLimitStatus {
+    pub limits: Vec<OrderRateLimit>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderRateLimitStatusEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub status: OrderRateLimitStatus,
+}
+
+/// Candle aggregated locally from trades stream was closed
+#[derive(Debug, Clone, Serialize)]
+pub struct CandleClosedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub candle: Candle,
+}
+
+/// Enough market data was received for currency pair to start trading
+#[derive(Debug, Clone, Serialize)]
+pub struct WarmupCompletedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+}
+
+/// Mark price of futures contract against which unrealized PnL and liquidation are calculated
+#[derive(Debug, Clone, Serialize)]
+pub struct MarkPriceEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub mark_price: Price,
+    pub index_price: Price,
+    pub funding_rate: Decimal,
+    pub next_funding_time: DateTime,
+}
+
+/// Forced liquidation order of futures contract executed on exchange
+#[derive(Debug, Clone, Serialize)]
+pub struct LiquidationEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub side: OrderSide,
+    pub price: Price,
+    pub amount: Amount,
+    pub timestamp: DateTime,
+}
+
+/// Close code and reason of websocket connection (RFC 6455, section 7.4)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WebSocketClose {
+    pub code: u16,
+    pub reason: String,
+}
+
+impl WebSocketClose {
+    /// Close frame was received without status code
+    pub const NO_STATUS: u16 = 1005;
+    /// Connection was dropped without close frame
+    pub const ABNORMAL: u16 = 1006;
+    pub const POLICY_VIOLATION: u16 = 1008;
+
+    pub fn new(code: u16, reason: impl Into<String>) -> Self {
+        Self {
+            code,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn abnormal(reason: impl Into<String>) -> Self {
+        Self::new(Self::ABNORMAL, reason)
+    }
+
+    pub fn is_abnormal(&self) -> bool {
+        self.code == Self::ABNORMAL
+    }
+
+    pub fn is_policy_violation(&self) -> bool {
+        self.code == Self::POLICY_VIOLATION
+    }
+}
+
+/// Websocket connection of exchange account was lost. Local order books of the exchange are
+/// out of sync since then, so they should be discarded until fresh snapshots arrive
+#[derive(Debug, Clone, Serialize)]
+pub struct DisconnectedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    /// `None` if connection wasn't established at all
+    pub close: Option<WebSocketClose>,
+    pub event_creation_time: DateTime,
+}
+
+/// REST requests to exchange account failed repeatedly without response from exchange,
+/// so account is unreachable even if websocket connection is still alive
+#[derive(Debug, Clone, Serialize)]
+pub struct RestUnreachableEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    /// Count of consecutive failed requests
+    pub failures: usize,
+    pub event_creation_time: DateTime,
+}
+
+/// Order is submitted at price overlapping with own order of opposite side of the same market,
+/// so they can match each other (wash trade)
+#[derive(Debug, Clone, Serialize)]
+pub struct NearCrossEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub client_order_id: ClientOrderId,
+    pub side: OrderSide,
+    pub price: Price,
+    /// Own resting order of opposite side which price is overlapped
+    pub crossed_client_order_id: ClientOrderId,
+    pub crossed_price: Price,
+    pub event_creation_time: DateTime,
+}
+
+/// Retries of order requests exceeded retry budget, so order is marked failed
+#[derive(Debug, Clone, Serialize)]
+pub struct RetriesExhaustedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub client_order_id: ClientOrderId,
+    pub retry_attempts: u32,
+    pub event_creation_time: DateTime,
+}
+
+/// Funding fee of perpetual futures position which was settled since previous update
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingPaymentEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub payment: FundingPayment,
+}
+
+/// Exchange rejected subscription to websocket stream, e.g. because of delisted currency pair.
+/// Other streams of connection stay subscribed
+#[derive(Debug, Clone, Serialize)]
+pub struct SubscriptionFailedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    /// Exchange specific name of stream
+    pub stream: String,
+    /// `None` if stream isn't related to known currency pair
+    pub currency_pair: Option<CurrencyPair>,
+    pub reason: String,
+}
+
+/// Orders of currency pair were rejected more times in a row than allowed, so order placement
+/// for it is paused until resume or cooldown
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderPlacementPausedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub currency_pair: CurrencyPair,
+    pub consecutive_rejections: u32,
+    pub event_creation_time: DateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BalanceChange {
+    pub currency_code: CurrencyCode,
+    /// Balance at the previous change event or at the first snapshot
+    pub previous: Amount,
+    pub current: Amount,
+}
+
+/// Balances of polled snapshot changed beyond threshold since the previous change event
+#[derive(Debug, Clone, Serialize)]
+pub struct BalanceChangedEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub changes: Vec<BalanceChange>,
+    pub snapshot_time: DateTime,
+}
+
+/// Occurrence of named schedule of time-driven strategy actions, e.g. hourly rebalance
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledEvent {
+    pub name: String,
+    /// Time of schedule occurrence, which can be earlier than creation of event if occurrence
+    /// was missed
+    pub scheduled_time: DateTime,
+    pub event_creation_time: DateTime,
+}
+
+/// Time between submission of order creation and the first acknowledgement of it by exchange
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderAckLatencyEvent {
+    pub exchange_account_id: ExchangeAccountId,
+    pub client_order_id: ClientOrderId,
+    pub latency: std::time::Duration,
+    /// Source of acknowledgement (REST response, websocket execution report, etc.)
+    pub source: EventSourceType,
+    pub event_creation_time: DateTime,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TradesEvent {
     pub exchange_account_id: ExchangeAccountId,
@@ -219,6 +564,44 @@ pub enum ExchangeEvent {
     BalanceUpdate(BalanceUpdateEvent),
     LiquidationPrice(LiquidationPriceEvent),
     Trades(TradesEvent),
+    AggTrade(AggTradeEvent),
+    SystemStatus(SystemStatusEvent),
+    CandleClosed(CandleClosedEvent),
+    WarmupCompleted(WarmupCompletedEvent),
+    MarkPrice(MarkPriceEvent),
+    Liquidation(LiquidationEvent),
+    Flatten(FlattenEvent),
+    MaxDrawdownReached(MaxDrawdownReachedEvent),
+    ClockSkew(ClockSkewEvent),
+    RateLimitUsage(RateLimitUsageEvent),
+    OrderRateLimitStatus(OrderRateLimitStatusEvent),
+    PacingChanged(PacingChangedEvent),
+    Disconnected(DisconnectedEvent),
+    OrderAckLatency(OrderAckLatencyEvent),
+    RestUnreachable(RestUnreachableEvent),
+    NearCross(NearCrossEvent),
+    FundingPayment(FundingPaymentEvent),
+    RetriesExhausted(RetriesExhaustedEvent),
+    SubscriptionFailed(SubscriptionFailedEvent),
+    OrderPlacementPaused(OrderPlacementPausedEvent),
+    Scheduled(ScheduledEvent),
+    BalanceChanged(BalanceChangedEvent),
+    ReconnectGracePeriod(ReconnectGracePeriodEvent),
+}
+
+impl ExchangeEvent {
+    /// Time of market data carried by event. Order book events carry time of receiving, because
+    /// exchanges don't timestamp every order book update.
+    /// Returns `None` for events which aren't market data, e.g. order and fill events
+    pub fn market_data_time(&self) -> Option<DateTime> {
+        match self {
+            ExchangeEvent::OrderBookEvent(event) => Some(event.creation_time),
+            ExchangeEvent::Trades(event) => event.trades.iter().map(|x| x.transaction_time).max(),
+            ExchangeEvent::AggTrade(event) => Some(event.trade.transaction_time),
+            ExchangeEvent::Liquidation(event) => Some(event.timestamp),
+            _ => None,
+        }
+    }
 }
 
 pub struct ExchangeEvents {
@@ -233,13 +616,22 @@ impl ExchangeEvents {
     pub fn get_events_channel(&self) -> broadcast::Receiver<ExchangeEvent> {
         self.events_sender.subscribe()
     }
+
+    pub fn events_sender(&self) -> broadcast::Sender<ExchangeEvent> {
+        self.events_sender.clone()
+    }
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Copy)]
+/// Sources of events which are authoritative for confirming completion of order request
+#[derive(Debug, Default, Clone, PartialEq, Eq, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum AllowedEventSourceType {
+    /// Any source
     #[default]
     All,
+    /// REST fallback requests only, e.g. if websocket events of exchange are unreliable
     FallbackOnly,
+    /// Websocket events and direct REST responses only
     NonFallback,
 }
 
@@ -281,7 +673,11 @@ pub enum MetricsEventType {
     MlPrediction,
     TradeToMl,
     OrderFromCreateToFill,
+    /// From order creation submission to its acknowledgement by exchange
+    OrderFromCreateToAck,
     OrderLifeCycle(OrderStatus),
+    /// Iteration of events loop which exceeded stall threshold
+    EventLoopStall,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Permissions granted to API key on account level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiPermissions {
+    /// Trading is allowed for account type configured in exchange settings
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub is_ip_restricted: bool,
+    /// Key is allowed to trade on some account of exchange
+    pub is_enabled: bool,
+}
@@ -0,0 +1,21 @@
+use crate::order::snapshot::Amount;
+use serde::{Deserialize, Serialize};
+
+/// Blockchain network which asset can be deposited and withdrawn by
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkInfo {
+    pub network: String,
+    /// Network used for withdrawal if network isn't specified
+    pub is_default: bool,
+    pub deposit_enabled: bool,
+    pub withdraw_enabled: bool,
+    /// Fee of withdrawal in asset currency
+    pub withdraw_fee: Amount,
+    pub withdraw_min: Amount,
+    /// Zero if withdrawal amount isn't limited
+    pub withdraw_max: Amount,
+    /// Network confirmations after which deposit is credited to account
+    pub min_confirmations: u32,
+    /// Network confirmations after which deposited asset can be withdrawn
+    pub unlock_confirmations: u32,
+}
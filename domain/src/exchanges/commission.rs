@@ -1,5 +1,7 @@
+use crate::market::CurrencyPair;
 use crate::order::snapshot::OrderRole;
 use rust_decimal::Decimal;
+use std::collections::HashMap;
 
 pub type Percent = Decimal;
 
@@ -18,21 +20,95 @@ impl CommissionForType {
     }
 }
 
+/// Fees of currency pair which differ from default ones (stablecoin pairs, promotions, etc.)
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct CurrencyPairFees {
+    pub maker: Percent,
+    pub taker: Percent,
+}
+
+impl CurrencyPairFees {
+    pub fn new(maker: Percent, taker: Percent) -> Self {
+        Self { maker, taker }
+    }
+}
+
 #[derive(Debug, Default, Eq, PartialEq, Clone)]
 pub struct Commission {
     pub maker: CommissionForType,
     pub taker: CommissionForType,
+    /// Fees overriding default ones for specific currency pairs
+    pub currency_pair_fees: HashMap<CurrencyPair, CurrencyPairFees>,
 }
 
 impl Commission {
     pub fn new(maker: CommissionForType, taker: CommissionForType) -> Self {
-        Self { maker, taker }
+        Self {
+            maker,
+            taker,
+            currency_pair_fees: HashMap::new(),
+        }
     }
 
-    pub fn get_commission(&self, order_role: OrderRole) -> CommissionForType {
-        match order_role {
-            OrderRole::Maker => self.maker.clone(),
-            OrderRole::Taker => self.taker.clone(),
-        }
+    pub fn set_currency_pair_fees(&mut self, currency_pair: CurrencyPair, fees: CurrencyPairFees) {
+        self.currency_pair_fees.insert(currency_pair, fees);
+    }
+
+    /// Commission of currency pair. Referral reward is common for all currency pairs
+    pub fn get_commission(
+        &self,
+        currency_pair: CurrencyPair,
+        order_role: OrderRole,
+    ) -> CommissionForType {
+        let default = match order_role {
+            OrderRole::Maker => &self.maker,
+            OrderRole::Taker => &self.taker,
+        };
+
+        let fee = match self.currency_pair_fees.get(&currency_pair) {
+            Some(fees) => match order_role {
+                OrderRole::Maker => fees.maker,
+                OrderRole::Taker => fees.taker,
+            },
+            None => default.fee,
+        };
+
+        CommissionForType::new(fee, default.referral_reward)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn commission() -> Commission {
+        let mut commission = Commission::new(
+            CommissionForType::new(dec!(0.1), dec!(40)),
+            CommissionForType::new(dec!(0.2), dec!(40)),
+        );
+        commission.set_currency_pair_fees(
+            CurrencyPair::from_codes("usdc".into(), "usdt".into()),
+            CurrencyPairFees::new(dec!(0), dec!(0.01)),
+        );
+        commission
+    }
+
+    #[rstest]
+    #[case("usdc", OrderRole::Maker, dec!(0))]
+    #[case("usdc", OrderRole::Taker, dec!(0.01))]
+    #[case("btc", OrderRole::Maker, dec!(0.1))]
+    #[case("btc", OrderRole::Taker, dec!(0.2))]
+    fn currency_pair_fees_override_default(
+        #[case] base: &str,
+        #[case] order_role: OrderRole,
+        #[case] expected_fee: Percent,
+    ) {
+        let currency_pair = CurrencyPair::from_codes(base.into(), "usdt".into());
+
+        let commission = commission().get_commission(currency_pair, order_role);
+
+        assert_eq!(commission, CommissionForType::new(expected_fee, dec!(40)));
     }
 }
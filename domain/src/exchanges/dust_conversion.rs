@@ -0,0 +1,25 @@
+use crate::market::CurrencyCode;
+use crate::order::snapshot::Amount;
+use serde::Serialize;
+
+/// Conversion of dust balance of single asset
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConvertedDust {
+    pub asset: CurrencyCode,
+    /// Converted amount in asset currency
+    pub amount: Amount,
+    /// Received amount in target currency of conversion after fee
+    pub converted_amount: Amount,
+    /// Fee of conversion in target currency
+    pub fee: Amount,
+}
+
+/// Conversion of small balances which can't be traded into exchange specific currency,
+/// e.g. into BNB on Binance
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DustConversion {
+    pub converted: Vec<ConvertedDust>,
+    /// Received amount in target currency after fees
+    pub total_converted_amount: Amount,
+    pub total_fee: Amount,
+}
@@ -0,0 +1,8 @@
+use std::time::Duration;
+
+/// REST endpoint selected by latency probing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EndpointLatency {
+    pub endpoint: String,
+    pub latency: Duration,
+}
@@ -0,0 +1,18 @@
+use crate::market::{CurrencyCode, CurrencyPair};
+use crate::order::snapshot::Amount;
+use mmb_utils::DateTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Funding fee paid or received for position of perpetual futures contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FundingPayment {
+    pub currency_pair: CurrencyPair,
+    /// Currency in which funding is settled
+    pub currency_code: CurrencyCode,
+    /// Positive for received funding, negative for paid one
+    pub amount: Amount,
+    /// Funding rate applied to position. None if exchange didn't provide it
+    pub rate: Option<Decimal>,
+    pub time: DateTime,
+}
@@ -0,0 +1,96 @@
+use crate::order::snapshot::Amount;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Tier of position notional with leverage allowed for it. Larger positions are allowed
+/// lower leverage and require higher maintenance margin
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeverageBracket {
+    /// Max position notional of the tier in quote currency
+    pub notional_cap: Amount,
+    pub max_leverage: Decimal,
+    /// Share of position notional required to keep position open
+    pub maintenance_margin_rate: Decimal,
+    /// Amount subtracted from maintenance margin calculated by rate, so margin doesn't jump
+    /// on bracket boundaries
+    pub maintenance_amount: Amount,
+}
+
+/// Bracket which position with specified notional falls into.
+/// Returns `None` if notional exceeds caps of all brackets
+pub fn bracket_for_notional(
+    brackets: &[LeverageBracket],
+    notional: Amount,
+) -> Option<&LeverageBracket> {
+    brackets
+        .iter()
+        .filter(|x| notional.abs() <= x.notional_cap)
+        .min_by_key(|x| x.notional_cap)
+}
+
+/// Max notional of position which can be held with specified leverage.
+/// Returns `None` if leverage exceeds max leverage of all brackets
+pub fn max_notional_for_leverage(
+    brackets: &[LeverageBracket],
+    leverage: Decimal,
+) -> Option<Amount> {
+    brackets
+        .iter()
+        .filter(|x| leverage <= x.max_leverage)
+        .map(|x| x.notional_cap)
+        .max()
+}
+
+/// Margin required to keep position with specified notional open.
+/// Returns `None` if notional exceeds caps of all brackets
+pub fn maintenance_margin(brackets: &[LeverageBracket], notional: Amount) -> Option<Amount> {
+    let bracket = bracket_for_notional(brackets, notional)?;
+    Some(notional.abs() * bracket.maintenance_margin_rate - bracket.maintenance_amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
+
+    fn brackets() -> Vec<LeverageBracket> {
+        vec![
+            LeverageBracket {
+                notional_cap: amount!(10000),
+                max_leverage: dec!(75),
+                maintenance_margin_rate: dec!(0.005),
+                maintenance_amount: amount!(0),
+            },
+            LeverageBracket {
+                notional_cap: amount!(100000),
+                max_leverage: dec!(50),
+                maintenance_margin_rate: dec!(0.01),
+                maintenance_amount: amount!(50),
+            },
+        ]
+    }
+
+    #[rstest]
+    #[case(dec!(1), Some(amount!(100000)))]
+    #[case(dec!(50), Some(amount!(100000)))]
+    #[case(dec!(60), Some(amount!(10000)))]
+    #[case(dec!(100), None)]
+    fn max_notional_depends_on_leverage(
+        #[case] leverage: Decimal,
+        #[case] expected: Option<Amount>,
+    ) {
+        assert_eq!(max_notional_for_leverage(&brackets(), leverage), expected);
+    }
+
+    #[rstest]
+    #[case(amount!(5000), Some(amount!(25)))]
+    #[case(amount!(-5000), Some(amount!(25)))]
+    #[case(amount!(10000), Some(amount!(50)))]
+    #[case(amount!(20000), Some(amount!(150)))]
+    #[case(amount!(200000), None)]
+    fn maintenance_margin_by_bracket(#[case] notional: Amount, #[case] expected: Option<Amount>) {
+        assert_eq!(maintenance_margin(&brackets(), notional), expected);
+    }
+}
@@ -1,2 +1,8 @@
+pub mod api_permissions;
+pub mod asset_network;
 pub mod commission;
+pub mod dust_conversion;
+pub mod endpoint_latency;
+pub mod funding;
+pub mod leverage;
 pub mod symbol;
@@ -1,8 +1,8 @@
 use std::hash::{Hash, Hasher};
 
 use crate::market::{powi, CurrencyCode, CurrencyId, CurrencyPair};
-use crate::order::snapshot::OrderSide;
 use crate::order::snapshot::{Amount, Price};
+use crate::order::snapshot::{OrderSide, OrderType};
 use anyhow::{Context, Result};
 use rust_decimal::Decimal;
 use rust_decimal::MathematicalOps;
@@ -53,6 +53,19 @@ impl Precision {
     }
 }
 
+/// Trading status of currency pair on exchange
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SymbolStatus {
+    #[default]
+    Trading,
+    /// Trading is paused temporarily, e.g. for maintenance or between sessions
+    Break,
+    /// Trading is halted by exchange, which often precedes delisting
+    Halt,
+    /// Currency pair is delisted, settled or removed from exchange
+    Closed,
+}
+
 /// Metadata for a currency pair
 #[derive(Debug, Clone, Eq, Serialize)]
 pub struct Symbol {
@@ -76,6 +89,10 @@ pub struct Symbol {
 
     pub price_precision: Precision,
     pub amount_precision: Precision,
+    /// Orders can be created only if currency pair is trading
+    pub status: SymbolStatus,
+    /// Order types allowed by exchange for currency pair. All types are allowed if not specified
+    pub allowed_order_types: Option<Vec<OrderType>>,
 }
 
 impl Symbol {
@@ -98,7 +115,7 @@ impl Symbol {
         max_price: Option<Price>,
         min_amount: Option<Amount>,
         max_amount: Option<Amount>,
-        min_cost: Option<Price>,
+        min_cost: Option<Amount>,
         amount_currency_code: CurrencyCode,
         balance_currency_code: Option<CurrencyCode>,
         price_precision: Precision,
@@ -120,9 +137,21 @@ impl Symbol {
             amount_multiplier: dec!(1),
             price_precision,
             amount_precision,
+            status: SymbolStatus::Trading,
+            allowed_order_types: None,
         }
     }
 
+    pub fn is_trading(&self) -> bool {
+        self.status == SymbolStatus::Trading
+    }
+
+    pub fn is_order_type_allowed(&self, order_type: OrderType) -> bool {
+        self.allowed_order_types
+            .as_ref()
+            .map_or(true, |x| x.contains(&order_type))
+    }
+
     // Currency pair in unified for crate format
     pub fn currency_pair(&self) -> CurrencyPair {
         CurrencyPair::from_codes(self.base_currency_code, self.quote_currency_code)
@@ -146,14 +175,17 @@ impl Symbol {
 
     pub fn price_round(&self, price: Price, round: Round) -> Price {
         match self.price_precision {
-            Precision::ByTick { tick } => Self::round_by_tick(price, tick, round),
-            Precision::ByMantissa { precision } => Self::round_by_mantissa(price, precision, round),
+            Precision::ByTick { tick } => Self::round_by_tick(price.value(), tick, round),
+            Precision::ByMantissa { precision } => {
+                Self::round_by_mantissa(price.value(), precision, round)
+            }
         }
+        .into()
     }
 
     pub fn amount_round(&self, amount: Amount, round: Round) -> Amount {
         match self.amount_precision {
-            Precision::ByTick { tick } => Self::round_by_tick(amount, tick, round),
+            Precision::ByTick { tick } => Self::round_by_tick(amount.value(), tick, round).into(),
             Precision::ByMantissa { precision } => {
                 self.amount_round_precision(amount, round, precision)
             }
@@ -169,7 +201,7 @@ impl Symbol {
     ) -> Amount {
         match self.amount_precision {
             Precision::ByMantissa { precision: _ } => {
-                Self::round_by_mantissa(amount, amount_precision, round)
+                Self::round_by_mantissa(amount.value(), amount_precision, round).into()
             }
             Precision::ByTick { tick: _ } => {
                 panic!("amount_round_precision cannot be called with Precision::ByTick variant")
@@ -184,7 +216,7 @@ impl Symbol {
                 self.amount_round_precision(amount, Round::ToNearest, precision + 2u8)
             }
             Precision::ByTick { tick } => {
-                Self::round_by_tick(amount, tick * dec!(0.01), Round::ToNearest)
+                Self::round_by_tick(amount.value(), tick * dec!(0.01), Round::ToNearest).into()
             }
         }
     }
@@ -218,7 +250,7 @@ impl Symbol {
         }
     }
 
-    fn round_by_mantissa(value: Price, precision: u8, round: Round) -> Price {
+    fn round_by_mantissa(value: Decimal, precision: u8, round: Round) -> Decimal {
         if value.is_zero() {
             return dec!(0);
         }
@@ -228,7 +260,7 @@ impl Symbol {
         Self::inner_round_by_tick(value, powi(dec!(0.1), floor_digits), round)
     }
 
-    fn get_precision_digits_by_fractional(value: Price, precision: u8) -> i8 {
+    fn get_precision_digits_by_fractional(value: Decimal, precision: u8) -> i8 {
         if precision == 0 {
             panic!(
                 "Count of precision digits cannot be less 1 but got {}",
@@ -309,9 +341,9 @@ impl Symbol {
     pub fn convert_amount_into_amount_currency_code(
         &self,
         from_currency_code: CurrencyCode,
-        amount_in_from_currency_code: Decimal,
+        amount_in_from_currency_code: Amount,
         currency_pair_price: Price,
-    ) -> Decimal {
+    ) -> Amount {
         if from_currency_code == self.amount_currency_code {
             return amount_in_from_currency_code;
         }
@@ -372,6 +404,49 @@ impl Symbol {
         })
     }
 
+    /// Residual amount is dust if it can't be traded by single order: after rounding to amount
+    /// precision it's zero, less than `min_amount` or its cost is less than `min_cost` (min notional)
+    pub fn is_dust(&self, amount: Amount, price: Price) -> bool {
+        let tradeable_amount = self.amount_round(amount, Round::Floor);
+        if tradeable_amount <= Amount::ZERO {
+            return true;
+        }
+
+        if matches!(self.min_amount, Some(min_amount) if tradeable_amount < min_amount) {
+            return true;
+        }
+
+        matches!(self.min_cost, Some(min_cost) if tradeable_amount * price < min_cost)
+    }
+
+    /// Exchange accepts whole amounts only (amount step is integer)
+    pub fn has_integer_lots(&self) -> bool {
+        match self.amount_precision {
+            Precision::ByTick { tick } => tick >= dec!(1) && tick.fract().is_zero(),
+            Precision::ByMantissa { .. } => false,
+        }
+    }
+
+    /// Amount is rounded to zero by amount step, so order with such amount can't be created.
+    /// Amount isn't checked if amount step is unknown
+    pub fn is_rounded_to_zero(&self, amount: Amount) -> bool {
+        match self.amount_precision {
+            Precision::ByTick { tick } if tick <= dec!(0) => false,
+            _ => self.amount_round(amount, Round::Floor) <= Amount::ZERO,
+        }
+    }
+
+    /// The least amount of single order by amount step and `min_amount`
+    pub fn min_order_amount(&self) -> Amount {
+        let amount_step = match self.amount_precision {
+            Precision::ByTick { tick } => Amount::new(tick),
+            Precision::ByMantissa { .. } => Amount::ZERO,
+        };
+
+        self.min_amount
+            .map_or(amount_step, |min_amount| min_amount.max(amount_step))
+    }
+
     pub fn get_amount_tick(&self) -> Decimal {
         match self.amount_precision {
             Precision::ByTick { tick } => tick,
@@ -397,6 +472,8 @@ impl Hash for Symbol {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::amount;
+    use crate::price;
     use rust_decimal_macros::dec;
 
     #[test]
@@ -566,9 +643,72 @@ mod test {
         );
     }
 
+    #[rstest]
+    #[case(amount!(0.05), price!(100), true)]
+    #[case(amount!(0.15), price!(100), true)]
+    #[case(amount!(0.25), price!(10), true)]
+    #[case(amount!(0.25), price!(100), false)]
+    #[case(amount!(1.01), price!(10), false)]
+    fn is_dust(#[case] amount: Amount, #[case] price: Price, #[case] expected: bool) {
+        let base_code = CurrencyCode::new("PHB");
+        let quote_code = CurrencyCode::new("BTC");
+        let symbol = Symbol::new(
+            false,
+            "PHB".into(),
+            base_code,
+            "BTC".into(),
+            quote_code,
+            None,
+            None,
+            Some(amount!(0.2)),
+            None,
+            Some(amount!(10)),
+            base_code,
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            Precision::ByTick { tick: dec!(0.1) },
+        );
+
+        assert_eq!(symbol.is_dust(amount, price), expected);
+    }
+
+    #[rstest]
+    #[case(dec!(1), amount!(0.5), true, true)]
+    #[case(dec!(1), amount!(1.5), true, false)]
+    #[case(dec!(0.1), amount!(0.05), false, true)]
+    #[case(dec!(0.1), amount!(0.5), false, false)]
+    #[case(dec!(0), amount!(0.5), false, false)]
+    fn amount_rounded_to_zero_by_step(
+        #[case] amount_tick: Decimal,
+        #[case] amount: Amount,
+        #[case] has_integer_lots: bool,
+        #[case] expected: bool,
+    ) {
+        let symbol = Symbol::new(
+            false,
+            "PHB".into(),
+            "PHB".into(),
+            "BTC".into(),
+            "BTC".into(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            "PHB".into(),
+            None,
+            Precision::ByTick { tick: dec!(0.1) },
+            Precision::ByTick { tick: amount_tick },
+        );
+
+        assert_eq!(symbol.has_integer_lots(), has_integer_lots);
+        assert_eq!(symbol.is_rounded_to_zero(amount), expected);
+    }
+
     mod get_min_amount {
         use crate::exchanges::symbol::{Precision, Symbol};
         use crate::market::CurrencyCode;
+        use crate::{amount, price};
         use rust_decimal_macros::dec;
 
         #[test]
@@ -589,7 +729,7 @@ mod test {
                 quote_code,
                 None,
                 None,
-                Some(dec!(0.4)),
+                Some(amount!(0.4)),
                 None,
                 None,
                 base_code,
@@ -599,10 +739,10 @@ mod test {
             );
 
             let min_amount = symbol
-                .get_min_amount(dec!(0))
+                .get_min_amount(price!(0))
                 .expect("should be able calculate order's min amount");
 
-            assert_eq!(min_amount, dec!(0.4));
+            assert_eq!(min_amount, amount!(0.4));
         }
 
         #[test]
@@ -633,7 +773,7 @@ mod test {
             );
 
             let _ = symbol
-                .get_min_amount(dec!(0))
+                .get_min_amount(price!(0))
                 .expect_err("should be error if min_amount not specified");
         }
     }
@@ -1,4 +1,5 @@
-use anyhow::Result;
+use crate::order::snapshot::Amount;
+use anyhow::{bail, Result};
 use mmb_utils::infrastructure::WithExpect;
 use mmb_utils::{impl_table_type, impl_table_type_raw};
 use rust_decimal::{Decimal, MathematicalOps};
@@ -193,6 +194,78 @@ pub enum ExchangeErrorType {
     ParsingError,
     PendingError(Duration),
     ServiceUnavailable,
+    /// Client order id was already used for another order
+    DuplicateClientOrderId,
+    /// Local market data wasn't updated within freshness threshold, so actions depending on it
+    /// are rejected locally
+    StaleMarketData,
+    /// Operation isn't supported by exchange, so request isn't sent at all
+    Unsupported,
+    /// Order would exceed limit of open orders per currency pair, so it isn't sent at all
+    OrderCountLimit,
+    /// Order amount is rounded to zero by amount step, so it isn't sent at all
+    AmountTooSmall {
+        min_amount: Amount,
+    },
+    /// Retries of requests for order exceeded retry budget, so order is marked failed
+    RetriesExhausted,
+    /// Leverage exceeds max leverage of bracket of position notional, so it isn't set at all
+    LeverageNotAllowed,
+    /// Currency pair isn't trading on exchange (trading break, halt, delisting), so order
+    /// isn't sent at all
+    SymbolNotTrading,
+    /// Local clock is skewed from exchange server clock by more than allowed, so signed
+    /// requests would be rejected and order isn't sent at all. Orders are accepted again
+    /// once clock skew is resynced
+    ClockSkewTooLarge,
+    /// Order placement of currency pair is paused after too many consecutive rejections,
+    /// so order isn't sent at all. Pause is lifted after cooldown or by manual resume
+    OrderPlacementPaused,
+    /// Order type isn't allowed by exchange for currency pair, so order isn't sent at all
+    OrderTypeNotAllowed,
+}
+
+impl ExchangeErrorType {
+    /// Suggested delay before retry when exchange doesn't specify it
+    pub const DEFAULT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+    /// Whether the same request can succeed if it will be retried
+    pub fn is_retryable(&self) -> bool {
+        use ExchangeErrorType::*;
+
+        match self {
+            SendError | RateLimit | PendingError(_) | ServiceUnavailable | StaleMarketData
+            | ClockSkewTooLarge | OrderPlacementPaused => true,
+            Unknown
+            | OrderNotFound
+            | OrderCompleted
+            | InsufficientFunds
+            | InvalidOrder
+            | Authentication
+            | ParsingError
+            | DuplicateClientOrderId
+            | Unsupported
+            | OrderCountLimit
+            | AmountTooSmall { .. }
+            | RetriesExhausted
+            | LeverageNotAllowed
+            | SymbolNotTrading
+            | OrderTypeNotAllowed => false,
+        }
+    }
+
+    /// Suggested delay before retry. `None` if request can be retried immediately or
+    /// isn't retryable at all
+    pub fn retry_delay(&self) -> Option<Duration> {
+        use ExchangeErrorType::*;
+
+        match self {
+            PendingError(pending_time) => Some(*pending_time),
+            RateLimit | ServiceUnavailable | StaleMarketData | ClockSkewTooLarge
+            | OrderPlacementPaused => Some(Self::DEFAULT_RETRY_DELAY),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -311,6 +384,95 @@ mod tests {
             assert_eq!(result, "Binance_1".to_string())
         }
     }
+
+    mod exchange_error_type_retry {
+        use super::*;
+        use rstest::rstest;
+
+        #[rstest]
+        #[case(ExchangeErrorType::SendError, true, None)]
+        #[case(
+            ExchangeErrorType::RateLimit,
+            true,
+            Some(ExchangeErrorType::DEFAULT_RETRY_DELAY)
+        )]
+        #[case(
+            ExchangeErrorType::ServiceUnavailable,
+            true,
+            Some(ExchangeErrorType::DEFAULT_RETRY_DELAY)
+        )]
+        #[case(
+            ExchangeErrorType::PendingError(Duration::from_secs(5)),
+            true,
+            Some(Duration::from_secs(5))
+        )]
+        #[case(ExchangeErrorType::InsufficientFunds, false, None)]
+        #[case(ExchangeErrorType::InvalidOrder, false, None)]
+        #[case(ExchangeErrorType::Unknown, false, None)]
+        #[case(ExchangeErrorType::DuplicateClientOrderId, false, None)]
+        #[case(ExchangeErrorType::RetriesExhausted, false, None)]
+        #[case(ExchangeErrorType::LeverageNotAllowed, false, None)]
+        #[case(ExchangeErrorType::SymbolNotTrading, false, None)]
+        #[case(
+            ExchangeErrorType::ClockSkewTooLarge,
+            true,
+            Some(ExchangeErrorType::DEFAULT_RETRY_DELAY)
+        )]
+        #[case(
+            ExchangeErrorType::OrderPlacementPaused,
+            true,
+            Some(ExchangeErrorType::DEFAULT_RETRY_DELAY)
+        )]
+        #[case(ExchangeErrorType::OrderTypeNotAllowed, false, None)]
+        pub fn retry_hint(
+            #[case] error_type: ExchangeErrorType,
+            #[case] is_retryable: bool,
+            #[case] retry_delay: Option<Duration>,
+        ) {
+            assert_eq!(error_type.is_retryable(), is_retryable);
+            assert_eq!(error_type.retry_delay(), retry_delay);
+        }
+    }
+
+    mod validate_currency_pair {
+        use super::*;
+        use rstest::rstest;
+
+        #[test]
+        pub fn valid() {
+            let known_quote_currencies = ["usdt".into(), "btc".into()];
+            let currency_pair = CurrencyPair::try_from_codes(
+                "ETH".into(),
+                "USDT".into(),
+                Some(&known_quote_currencies),
+            )
+            .expect("in test");
+
+            assert_eq!(currency_pair.as_str(), "eth/usdt");
+        }
+
+        #[rstest]
+        #[case("eth", "eth")]
+        #[case("", "usdt")]
+        #[case("eth/btc", "usdt")]
+        #[case("eth ", "usdt")]
+        #[case("eth", "usdx")]
+        pub fn invalid(#[case] base: &str, #[case] quote: &str) {
+            let known_quote_currencies = ["usdt".into(), "eth".into()];
+            let result = CurrencyPair::try_from_codes(
+                base.into(),
+                quote.into(),
+                Some(&known_quote_currencies),
+            );
+
+            assert!(result.is_err());
+        }
+
+        #[test]
+        pub fn any_quote_if_known_quote_currencies_not_specified() {
+            assert!(CurrencyPair::try_from_codes("eth".into(), "usdx".into(), None).is_ok());
+        }
+    }
 }
 
 impl CurrencyCode {
@@ -344,6 +506,34 @@ impl CurrencyPair {
         Self(SHARED_CURRENCY_PAIR.add_or_get(&[base.as_str(), quote.as_str()].join("/")))
     }
 
+    /// Creates currency pair after validation of currency codes. Intended for currency pairs
+    /// specified by user, `from_codes` should be used for ones received from exchange.
+    /// Quote currency isn't checked if known quote currencies aren't specified
+    pub fn try_from_codes(
+        base: CurrencyCode,
+        quote: CurrencyCode,
+        known_quote_currencies: Option<&[CurrencyCode]>,
+    ) -> Result<Self> {
+        for code in [base, quote] {
+            if code.as_str().is_empty() || !code.as_str().chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                bail!("Invalid currency code '{code}' in currency pair {base}/{quote}");
+            }
+        }
+
+        if base == quote {
+            bail!("Base and quote currencies are the same in currency pair {base}/{quote}");
+        }
+
+        if let Some(known_quote_currencies) = known_quote_currencies {
+            if !known_quote_currencies.contains(&quote) {
+                bail!("Unknown quote currency '{quote}' in currency pair {base}/{quote}");
+            }
+        }
+
+        Ok(Self::from_codes(base, quote))
+    }
+
     pub fn to_codes(&self) -> CurrencyPairCodes {
         let (base, quote) = self
             .as_str()
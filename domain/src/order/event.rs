@@ -3,16 +3,28 @@ use std::sync::Arc;
 use serde::{Deserialize, Serialize};
 
 use crate::order::pool::OrderRef;
-use crate::order::snapshot::OrderSnapshot;
+use crate::order::snapshot::{ClientOrderId, OrderSnapshot};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum OrderEventType {
     CreateOrderSucceeded,
     CreateOrderFailed,
-    OrderFilled { cloned_order: Arc<OrderSnapshot> },
-    OrderCompleted { cloned_order: Arc<OrderSnapshot> },
+    /// `fills_count` is count of the last fills of order reported by event. It's more than 1
+    /// if tiny fills were aggregated into single event
+    OrderFilled {
+        cloned_order: Arc<OrderSnapshot>,
+        fills_count: usize,
+    },
+    OrderCompleted {
+        cloned_order: Arc<OrderSnapshot>,
+    },
     CancelOrderSucceeded,
     CancelOrderFailed,
+    /// Client order id was rejected by exchange as duplicate, so order is submitted again
+    /// with regenerated client order id
+    ClientOrderIdRegenerated {
+        previous_client_order_id: ClientOrderId,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,160 @@
+use crate::market::CurrencyCode;
+use crate::order::fill::OrderFill;
+use crate::order::snapshot::{Amount, ClientOrderId, OrderStatus, Price};
+use mmb_utils::DateTime;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Consolidated information about order execution built from order fills
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionReport {
+    pub client_order_id: ClientOrderId,
+    pub status: OrderStatus,
+    pub total_filled_amount: Amount,
+    /// Volume weighted average price of fills. `None` if order has no fills
+    pub average_fill_price: Option<Price>,
+    /// Total commission amount grouped by commission currency
+    pub commissions: HashMap<CurrencyCode, Amount>,
+    /// Duration from order initialization to the last fill. `None` if order has no fills
+    pub execution_duration: Option<Duration>,
+    pub fills_count: usize,
+}
+
+impl ExecutionReport {
+    pub fn from_fills(
+        client_order_id: ClientOrderId,
+        status: OrderStatus,
+        init_time: DateTime,
+        fills: &[OrderFill],
+    ) -> Self {
+        let mut total_filled_amount = Amount::ZERO;
+        let mut total_cost = Amount::ZERO;
+        let mut commissions = HashMap::new();
+        for fill in fills {
+            total_filled_amount += fill.amount();
+            total_cost += fill.price() * fill.amount();
+            *commissions
+                .entry(fill.commission_currency_code())
+                .or_insert(Amount::ZERO) += fill.commission_amount();
+        }
+
+        let average_fill_price =
+            (!total_filled_amount.is_zero()).then(|| Price::new(total_cost / total_filled_amount));
+
+        let execution_duration = fills
+            .iter()
+            .map(|fill| fill.receive_time())
+            .max()
+            .map(|last_fill_time| (last_fill_time - init_time).to_std().unwrap_or_default());
+
+        Self {
+            client_order_id,
+            status,
+            total_filled_amount,
+            average_fill_price,
+            commissions,
+            execution_duration,
+            fills_count: fills.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::fill::OrderFillType;
+    use crate::order::snapshot::OrderFillRole;
+    use chrono::Utc;
+
+    use crate::{amount, price};
+    use uuid::Uuid;
+
+    fn fill(
+        receive_time: DateTime,
+        price: Price,
+        amount: Amount,
+        commission_currency_code: &str,
+        commission_amount: Amount,
+    ) -> OrderFill {
+        OrderFill::new(
+            Uuid::new_v4(),
+            None,
+            receive_time,
+            OrderFillType::UserTrade,
+            None,
+            price,
+            amount,
+            price * amount,
+            OrderFillRole::Maker,
+            commission_currency_code.into(),
+            commission_amount,
+            amount!(0),
+            commission_currency_code.into(),
+            commission_amount,
+            commission_amount,
+            true,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn without_fills() {
+        let report = ExecutionReport::from_fills(
+            ClientOrderId::unique_id(),
+            OrderStatus::Canceled,
+            Utc::now(),
+            &[],
+        );
+
+        assert_eq!(report.total_filled_amount, amount!(0));
+        assert_eq!(report.average_fill_price, None);
+        assert!(report.commissions.is_empty());
+        assert_eq!(report.execution_duration, None);
+        assert_eq!(report.fills_count, 0);
+    }
+
+    #[test]
+    fn several_fills() {
+        let init_time = Utc::now();
+        let fills = [
+            fill(
+                init_time + chrono::Duration::seconds(1),
+                price!(10),
+                amount!(1),
+                "BTC",
+                amount!(0.1),
+            ),
+            fill(
+                init_time + chrono::Duration::seconds(5),
+                price!(13),
+                amount!(2),
+                "BTC",
+                amount!(0.2),
+            ),
+            fill(
+                init_time + chrono::Duration::seconds(3),
+                price!(12),
+                amount!(1),
+                "BNB",
+                amount!(0.05),
+            ),
+        ];
+
+        let report = ExecutionReport::from_fills(
+            ClientOrderId::unique_id(),
+            OrderStatus::Completed,
+            init_time,
+            &fills,
+        );
+
+        assert_eq!(report.status, OrderStatus::Completed);
+        assert_eq!(report.total_filled_amount, amount!(4));
+        assert_eq!(report.average_fill_price, Some(price!(12)));
+        assert_eq!(report.commissions.len(), 2);
+        assert_eq!(report.commissions[&"BTC".into()], amount!(0.3));
+        assert_eq!(report.commissions[&"BNB".into()], amount!(0.05));
+        assert_eq!(report.execution_duration, Some(Duration::from_secs(5)));
+        assert_eq!(report.fills_count, 3);
+    }
+}
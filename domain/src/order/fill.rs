@@ -1,8 +1,7 @@
 use crate::events::{EventSourceType, TradeId};
 use crate::market::CurrencyCode;
-use crate::order::snapshot::{OrderFillRole, OrderSide};
+use crate::order::snapshot::{Amount, OrderFillRole, OrderSide, Price};
 use mmb_utils::DateTime;
-use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -31,20 +30,20 @@ pub struct OrderFill {
     fill_type: OrderFillType,
 
     trade_id: Option<TradeId>,
-    price: Decimal,
-    amount: Decimal,
-    cost: Decimal,
+    price: Price,
+    amount: Amount,
+    cost: Amount,
     role: OrderFillRole,
     commission_currency_code: CurrencyCode,
-    commission_amount: Decimal,
-    referral_reward_amount: Decimal,
+    commission_amount: Amount,
+    referral_reward_amount: Amount,
 
     /// ConvertedCommissionCurrencyCode is CommissionCurrencyCode if  CommissionCurrencyCode is equal to base or quote
     /// Otherwise it is equal to quote currency code (for example, in the case of BNB fee discount) after conversion
     /// by HandleOrderFilled
     converted_commission_currency_code: CurrencyCode,
-    converted_commission_amount: Decimal,
-    expected_converted_commission_amount: Decimal,
+    converted_commission_amount: Amount,
+    expected_converted_commission_amount: Amount,
 
     is_incremental_fill: bool,
     event_source_type: Option<EventSourceType>,
@@ -59,16 +58,16 @@ impl OrderFill {
         receive_time: DateTime,
         fill_type: OrderFillType,
         trade_id: Option<TradeId>,
-        price: Decimal,
-        amount: Decimal,
-        cost: Decimal,
+        price: Price,
+        amount: Amount,
+        cost: Amount,
         role: OrderFillRole,
         commission_currency_code: CurrencyCode,
-        commission_amount: Decimal,
-        referral_reward_amount: Decimal,
+        commission_amount: Amount,
+        referral_reward_amount: Amount,
         converted_commission_currency_code: CurrencyCode,
-        converted_commission_amount: Decimal,
-        expected_converted_commission_amount: Decimal,
+        converted_commission_amount: Amount,
+        expected_converted_commission_amount: Amount,
         is_incremental_fill: bool,
         event_source_type: Option<EventSourceType>,
         side: Option<OrderSide>,
@@ -107,13 +106,13 @@ impl OrderFill {
     pub fn trade_id(&self) -> Option<&TradeId> {
         self.trade_id.as_ref()
     }
-    pub fn price(&self) -> Decimal {
+    pub fn price(&self) -> Price {
         self.price
     }
-    pub fn amount(&self) -> Decimal {
+    pub fn amount(&self) -> Amount {
         self.amount
     }
-    pub fn cost(&self) -> Decimal {
+    pub fn cost(&self) -> Amount {
         self.cost
     }
     pub fn role(&self) -> OrderFillRole {
@@ -122,19 +121,19 @@ impl OrderFill {
     pub fn commission_currency_code(&self) -> CurrencyCode {
         self.commission_currency_code
     }
-    pub fn commission_amount(&self) -> Decimal {
+    pub fn commission_amount(&self) -> Amount {
         self.commission_amount
     }
-    pub fn referral_reward_amount(&self) -> Decimal {
+    pub fn referral_reward_amount(&self) -> Amount {
         self.referral_reward_amount
     }
     pub fn converted_commission_currency_code(&self) -> CurrencyCode {
         self.converted_commission_currency_code
     }
-    pub fn converted_commission_amount(&self) -> Decimal {
+    pub fn converted_commission_amount(&self) -> Amount {
         self.converted_commission_amount
     }
-    pub fn expected_converted_commission_amount(&self) -> Decimal {
+    pub fn expected_converted_commission_amount(&self) -> Amount {
         self.expected_converted_commission_amount
     }
     pub fn is_incremental_fill(&self) -> bool {
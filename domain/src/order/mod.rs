@@ -1,4 +1,7 @@
 pub mod event;
+pub mod execution_report;
 pub mod fill;
 pub mod pool;
 pub mod snapshot;
+pub mod tag;
+pub mod units;
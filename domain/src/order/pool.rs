@@ -1,12 +1,15 @@
 use crate::market::CurrencyPair;
 use crate::market::ExchangeAccountId;
+use crate::order::execution_report::ExecutionReport;
 use crate::order::fill::OrderFill;
 use crate::order::snapshot::{
     Amount, ClientOrderId, ExchangeOrderId, OrderHeader, OrderInfoExtensionData, OrderMut,
-    OrderSimpleProps, OrderSnapshot, OrderStatus, Price,
+    OrderSimpleProps, OrderSnapshot, OrderStatus, Price, ReservationId,
 };
 use crate::order::snapshot::{OrderRole, OrderSide, OrderType};
+use anyhow::Result;
 use dashmap::DashMap;
+use mmb_utils::correlation_id::OptionCorrelationId;
 use mmb_utils::DateTime;
 use parking_lot::RwLock;
 use std::borrow::{Borrow, BorrowMut};
@@ -117,6 +120,9 @@ impl OrderRef {
     pub fn exchange_order_id(&self) -> Option<ExchangeOrderId> {
         self.fn_ref(|x| x.exchange_order_id())
     }
+    pub fn correlation_id(&self) -> OptionCorrelationId {
+        OptionCorrelationId(self.fn_ref(|x| x.internal_props.correlation_id))
+    }
     pub fn order_ids(&self) -> (ClientOrderId, Option<ExchangeOrderId>) {
         let client_order_id = self.client_order_id();
         (client_order_id, self.fn_ref(|x| x.exchange_order_id()))
@@ -139,6 +145,36 @@ impl OrderRef {
     pub fn get_fills(&self) -> (Vec<OrderFill>, Amount) {
         self.fn_ref(|order| (order.fills.fills.clone(), order.fills.filled_amount))
     }
+
+    /// Header of order replacing this one with new price. By default replacement amount is
+    /// amount which is left unfilled for the moment
+    pub fn replacement_header(
+        &self,
+        client_order_id: ClientOrderId,
+        price: Price,
+        amount: Option<Amount>,
+        reservation_id: Option<ReservationId>,
+    ) -> Result<OrderHeader> {
+        self.header().replacement(
+            client_order_id,
+            price,
+            self.filled_amount(),
+            amount,
+            reservation_id,
+        )
+    }
+
+    pub fn execution_report(&self) -> ExecutionReport {
+        let client_order_id = self.client_order_id();
+        self.fn_ref(|order| {
+            ExecutionReport::from_fills(
+                client_order_id,
+                order.status(),
+                order.init_time(),
+                &order.fills.fills,
+            )
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -170,9 +206,41 @@ impl OrdersPool {
             .insert(client_order_id.clone(), order_ref.clone());
         let _ = self.not_finished.insert(client_order_id, order_ref.clone());
 
+        if let Some(exchange_order_id) = &snapshot.props.exchange_order_id {
+            self.add_exchange_order_id(exchange_order_id.clone(), &order_ref);
+        }
+
         order_ref
     }
 
+    /// Index order by exchange order id, so it can be found without scanning the pool
+    pub fn add_exchange_order_id(&self, exchange_order_id: ExchangeOrderId, order: &OrderRef) {
+        let _ = self
+            .cache_by_exchange_id
+            .insert(exchange_order_id, order.clone());
+    }
+
+    /// Find order by exchange order id. Falls back to client order id if order isn't indexed by
+    /// exchange order id yet, but only if order has the same exchange order id. Such order is
+    /// indexed by exchange order id for the next lookups
+    pub fn find(
+        &self,
+        exchange_order_id: &ExchangeOrderId,
+        client_order_id: Option<&ClientOrderId>,
+    ) -> Option<OrderRef> {
+        if let Some(order) = self.cache_by_exchange_id.get(exchange_order_id) {
+            return Some(order.clone());
+        }
+
+        let order = self.cache_by_client_id.get(client_order_id?)?.clone();
+        if order.exchange_order_id().as_ref() != Some(exchange_order_id) {
+            return None;
+        }
+
+        self.add_exchange_order_id(exchange_order_id.clone(), &order);
+        Some(order)
+    }
+
     /// Create `OrderRef` by specified `OrderHeader` with default other properties and insert it in order pool.
     pub fn add_simple_initial(
         &self,
@@ -209,4 +277,93 @@ impl OrdersPool {
             }
         }
     }
+
+    /// Replace order by the same order with another client order id. Header of order can't be
+    /// changed, so state of order is copied to new `OrderRef`, and previous one is removed from pool
+    pub fn rekey(&self, order: &OrderRef, client_order_id: ClientOrderId) -> OrderRef {
+        let previous_client_order_id = order.client_order_id();
+        let _ = self.cache_by_client_id.remove(&previous_client_order_id);
+        let _ = self.not_finished.remove(&previous_client_order_id);
+
+        let mut snapshot = order.deep_clone();
+        snapshot.header.client_order_id = client_order_id;
+        self.add_snapshot_initial(&snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::snapshot::UserOrder;
+    use chrono::Utc;
+
+    use crate::{amount, price};
+
+    fn add_order(pool: &OrdersPool) -> OrderRef {
+        let header = OrderHeader::with_user_order(
+            ClientOrderId::unique_id(),
+            ExchangeAccountId::new("Binance", 0),
+            CurrencyPair::from_codes("eth".into(), "btc".into()),
+            OrderSide::Buy,
+            amount!(1),
+            UserOrder::limit(price!(0.1)),
+            None,
+            None,
+            "test".to_owned(),
+        );
+
+        pool.add_simple_initial(&header, Utc::now(), None)
+    }
+
+    #[test]
+    fn find_by_exchange_order_id() {
+        let pool = OrdersPool::new();
+        let order = add_order(&pool);
+        let exchange_order_id = ExchangeOrderId::from("1");
+        pool.add_exchange_order_id(exchange_order_id.clone(), &order);
+
+        let found = pool.find(&exchange_order_id, None).expect("in test");
+
+        assert_eq!(found.client_order_id(), order.client_order_id());
+    }
+
+    #[test]
+    fn find_by_client_order_id_only_with_the_same_exchange_order_id() {
+        let pool = OrdersPool::new();
+        let order = add_order(&pool);
+        let client_order_id = order.client_order_id();
+        let exchange_order_id = ExchangeOrderId::from("1");
+
+        assert!(pool
+            .find(&exchange_order_id, Some(&client_order_id))
+            .is_none());
+
+        order.fn_mut(|x| x.props.exchange_order_id = Some(exchange_order_id.clone()));
+        let found = pool
+            .find(&exchange_order_id, Some(&client_order_id))
+            .expect("in test");
+
+        assert_eq!(found.client_order_id(), client_order_id);
+        assert!(pool.cache_by_exchange_id.contains_key(&exchange_order_id));
+    }
+
+    #[test]
+    fn rekey_order() {
+        let pool = OrdersPool::new();
+        let order = add_order(&pool);
+        let previous_client_order_id = order.client_order_id();
+        let client_order_id = ClientOrderId::unique_id();
+
+        let rekeyed = pool.rekey(&order, client_order_id.clone());
+
+        assert_eq!(rekeyed.client_order_id(), client_order_id);
+        assert_eq!(rekeyed.price(), order.price());
+        assert_eq!(rekeyed.status(), order.status());
+        assert!(!pool
+            .cache_by_client_id
+            .contains_key(&previous_client_order_id));
+        assert!(!pool.not_finished.contains_key(&previous_client_order_id));
+        assert!(pool.cache_by_client_id.contains_key(&client_order_id));
+        assert!(pool.not_finished.contains_key(&client_order_id));
+    }
 }
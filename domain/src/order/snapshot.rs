@@ -2,10 +2,13 @@ use crate::events::EventSourceType;
 use crate::market::CurrencyPair;
 use crate::market::{ExchangeAccountId, ExchangeErrorType, MarketAccountId, MarketId};
 use crate::order::fill::OrderFill;
+use crate::position::MarginMode;
+use anyhow::{bail, Result};
 use chrono::Utc;
 use dyn_clone::{clone_trait_object, DynClone};
 use enum_map::Enum;
 use mmb_database::impl_event;
+use mmb_utils::correlation_id::CorrelationId;
 use mmb_utils::{impl_from_for_str_id, DateTime};
 use mmb_utils::{impl_str_id, impl_u64_id, time::get_atomic_current_secs};
 use once_cell::sync::Lazy;
@@ -24,9 +27,7 @@ use uuid::Uuid;
 
 pub type SortedOrderData = BTreeMap<Price, Amount>;
 
-pub type Price = Decimal;
-/// Amount is a currency quantity alias: order amount, position amount, balance amount, etc.
-pub type Amount = Decimal;
+pub use crate::order::units::{Amount, Price};
 pub type String16 = SmallString<[u8; 16]>;
 
 #[derive(Debug, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Hash, Enum)]
@@ -111,6 +112,25 @@ pub enum OrderExecutionType {
     MakerOnly = 1,
 }
 
+/// How long order stays active on exchange
+#[derive(Debug, Default, Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Hash)]
+pub enum TimeInForce {
+    /// Good till cancelled
+    #[default]
+    Gtc,
+    /// Good till date. Order is expired at specified time
+    Gtd(DateTime),
+}
+
+impl TimeInForce {
+    pub fn expire_time(&self) -> Option<DateTime> {
+        match self {
+            TimeInForce::Gtc => None,
+            TimeInForce::Gtd(expire_time) => Some(*expire_time),
+        }
+    }
+}
+
 impl_str_id!(ClientOrderId);
 
 impl_from_for_str_id!(i64, ClientOrderId);
@@ -158,6 +178,32 @@ impl OrderStatus {
         use OrderStatus::*;
         matches!(*self, FailedToCreate | Canceled | Completed)
     }
+
+    /// Order lifecycle state machine. Repeated setting of the same status is allowed.
+    /// NOTE: `Canceled -> Completed` is allowed because exchange can report fills of an order
+    /// after cancellation and such order should be finished as completed
+    pub fn can_change_to(&self, new_status: OrderStatus) -> bool {
+        use OrderStatus::*;
+
+        if *self == new_status {
+            return true;
+        }
+
+        match *self {
+            Creating => matches!(
+                new_status,
+                Created | FailedToCreate | Canceling | Canceled | Completed
+            ),
+            Created => matches!(
+                new_status,
+                Canceling | Canceled | FailedToCancel | Completed
+            ),
+            Canceling => matches!(new_status, Canceled | FailedToCancel | Completed),
+            FailedToCancel => matches!(new_status, Canceling | Canceled | Completed),
+            Canceled => new_status == Completed,
+            FailedToCreate | Completed => false,
+        }
+    }
 }
 
 // Id for reserved amount
@@ -296,6 +342,24 @@ pub struct OrderHeader {
 
     pub signal_id: Option<String>,
     pub strategy_name: String,
+
+    /// Text attached to order on exchange for filtering orders in exchange UI.
+    /// Encoded to client order id if exchange doesn't support tags
+    #[serde(default)]
+    pub tag: Option<String>,
+
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+
+    /// Margin mode of futures position which order is routed to.
+    /// Margin mode from exchange settings is used if not specified
+    #[serde(default)]
+    pub margin_mode: Option<MarginMode>,
+
+    /// Order creation polls order info until exchange confirms that order is resting and fails
+    /// if it isn't confirmed within this timeout. Disabled if not specified
+    #[serde(default)]
+    pub resting_confirmation_timeout_ms: Option<u64>,
 }
 
 impl OrderHeader {
@@ -348,7 +412,75 @@ impl OrderHeader {
             reservation_id,
             signal_id,
             strategy_name,
+            tag: None,
+            time_in_force: TimeInForce::Gtc,
+            margin_mode: None,
+            resting_confirmation_timeout_ms: None,
+        }
+    }
+
+    pub fn with_tag(mut self, tag: String) -> Self {
+        self.tag = Some(tag);
+        self
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = time_in_force;
+        self
+    }
+
+    pub fn with_margin_mode(mut self, margin_mode: MarginMode) -> Self {
+        self.margin_mode = Some(margin_mode);
+        self
+    }
+
+    pub fn with_resting_confirmation(mut self, timeout_ms: u64) -> Self {
+        self.resting_confirmation_timeout_ms = Some(timeout_ms);
+        self
+    }
+
+    /// Header of limit order replacing this one with new price. Replacement amount is amount which
+    /// is left unfilled, so already filled part isn't exposed again, unless `amount` is specified.
+    /// Replacement has its own balance reservation because original order keeps its one
+    /// until it is cancelled
+    pub fn replacement(
+        &self,
+        client_order_id: ClientOrderId,
+        price: Price,
+        filled_amount: Amount,
+        amount: Option<Amount>,
+        reservation_id: Option<ReservationId>,
+    ) -> Result<OrderHeader> {
+        let execution_type = match &self.options {
+            OrderOptions::User(UserOrder::Limit { execution_type, .. }) => *execution_type,
+            _ => bail!(
+                "Only limit order can be replaced but order {} is {:?}",
+                self.client_order_id,
+                self.order_type
+            ),
+        };
+
+        let amount = amount.unwrap_or(self.amount - filled_amount);
+        if amount <= Amount::ZERO {
+            bail!(
+                "Replacement amount {amount} of order {} should be positive",
+                self.client_order_id
+            );
         }
+
+        let options = OrderOptions::User(UserOrder::Limit {
+            price,
+            execution_type,
+        });
+        Ok(OrderHeader {
+            client_order_id,
+            amount,
+            order_type: options.get_order_type(),
+            source_price: options.get_source_price(),
+            options,
+            reservation_id,
+            ..self.clone()
+        })
     }
 
     pub fn market_account_id(&self) -> MarketAccountId {
@@ -462,31 +594,59 @@ pub struct OrderStatusHistory {
     status_changes: Vec<OrderStatusChange>,
 }
 
+impl OrderStatusHistory {
+    pub fn last_change_time(&self) -> Option<DateTime> {
+        self.status_changes.last().map(|x| x.time)
+    }
+}
+
 /// Helping properties for trading engine internal use
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct SystemInternalOrderProps {
     pub creation_event_source_type: Option<EventSourceType>,
+    /// Exchange time of applied creation event if its source reports it
+    #[serde(default)]
+    pub creation_event_time: Option<DateTime>,
     pub last_order_creation_status_request_time: Option<DateTime>,
     pub last_creation_error_type: Option<ExchangeErrorType>,
     pub last_creation_error_message: String,
 
     pub cancellation_event_source_type: Option<EventSourceType>,
+    /// Exchange time of applied cancellation event if its source reports it
+    #[serde(default)]
+    pub cancellation_event_time: Option<DateTime>,
     pub last_order_cancellation_status_request_time: Option<DateTime>,
     pub last_cancellation_error: Option<ExchangeErrorType>,
 
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub is_canceling_from_wait_cancel_order: bool,
 
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub canceled_not_from_wait_cancel_order: bool,
 
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub was_cancellation_event_raised: bool,
 
     pub last_order_trades_request_time: Option<DateTime>,
 
     pub handled_by_balance_recovery: bool,
     pub filled_amount_after_cancellation: Option<Amount>,
+
+    /// Id for tracing order creation through requests, ack and fills in logs
+    pub correlation_id: Option<CorrelationId>,
+
+    /// Count of retries of requests made for order by all retry mechanisms
+    #[serde(default)]
+    pub retry_attempts: u32,
+    pub first_retry_time: Option<DateTime>,
+    #[serde(default)]
+    pub retries_exhausted: bool,
+    /// Fills applied to order but not reported by fill event yet, because their total notional
+    /// is below min notional of fill events aggregation
+    #[serde(default)]
+    pub unreported_fills_count: usize,
+    #[serde(default)]
+    pub unreported_fills_notional: Amount,
 }
 
 /// It may be necessary for an exchange to store specific information for an order.
@@ -561,12 +721,18 @@ pub struct OrderInfo {
     pub order_status: OrderStatus,
     pub price: Price,
     pub amount: Amount,
-    pub average_fill_price: Decimal,
-    pub filled_amount: Decimal,
+    pub average_fill_price: Price,
+    pub filled_amount: Amount,
     pub commission_currency_code: Option<String>,
-    pub commission_rate: Option<Price>,
+    pub commission_rate: Option<Decimal>,
     pub commission_amount: Option<Amount>,
     pub extension_data: Option<Box<dyn OrderInfoExtensionData>>,
+    /// Tag attached to order on exchange
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Time when order is expired by exchange. `None` for good till cancelled orders
+    #[serde(default)]
+    pub expire_time: Option<DateTime>,
 }
 
 impl OrderInfo {
@@ -579,10 +745,10 @@ impl OrderInfo {
         order_status: OrderStatus,
         price: Price,
         amount: Amount,
-        average_fill_price: Decimal,
-        filled_amount: Decimal,
+        average_fill_price: Price,
+        filled_amount: Amount,
         commission_currency_code: Option<String>,
-        commission_rate: Option<Price>,
+        commission_rate: Option<Decimal>,
         commission_amount: Option<Amount>,
     ) -> Self {
         Self {
@@ -599,6 +765,8 @@ impl OrderInfo {
             commission_rate,
             commission_amount,
             extension_data: None,
+            tag: None,
+            expire_time: None,
         }
     }
 }
@@ -624,8 +792,9 @@ impl OrderMut {
         self.props.status
     }
 
-    pub fn set_status(&mut self, new_status: OrderStatus, time: DateTime) {
-        set_status(&mut self.props, &mut self.status_history, new_status, time);
+    /// Returns `false` if status change was rejected as illegal or outdated
+    pub fn set_status(&mut self, new_status: OrderStatus, time: DateTime) -> bool {
+        set_status(&mut self.props, &mut self.status_history, new_status, time)
     }
 
     pub fn is_finished(&self) -> bool {
@@ -753,8 +922,9 @@ impl OrderSnapshot {
         self.fills.fills.push(fill);
     }
 
-    pub fn set_status(&mut self, new_status: OrderStatus, time: DateTime) {
-        set_status(&mut self.props, &mut self.status_history, new_status, time);
+    /// Returns `false` if status change was rejected as illegal or outdated
+    pub fn set_status(&mut self, new_status: OrderStatus, time: DateTime) -> bool {
+        set_status(&mut self.props, &mut self.status_history, new_status, time)
     }
 }
 
@@ -776,12 +946,27 @@ impl Display for PriceByOrderSide {
     }
 }
 
+/// Status changes can come from REST and websocket in any order, so we apply only legal
+/// transitions and the latest change wins
 fn set_status(
     props: &mut OrderSimpleProps,
     status_history: &mut OrderStatusHistory,
     new_status: OrderStatus,
     time: DateTime,
-) {
+) -> bool {
+    let current_status = props.status;
+    if !current_status.can_change_to(new_status) {
+        log::warn!("Illegal order status change {current_status:?} -> {new_status:?} was rejected");
+        return false;
+    }
+
+    if let Some(last_change_time) = status_history.last_change_time() {
+        if time < last_change_time {
+            log::warn!("Outdated order status change {current_status:?} -> {new_status:?} at {time} was rejected because last change was at {last_change_time}");
+            return false;
+        }
+    }
+
     props.status = new_status;
     if new_status.is_finished() {
         props.finished_time = Some(time);
@@ -790,5 +975,211 @@ fn set_status(
         id: Uuid::default(),
         status: new_status,
         time,
-    })
+    });
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use rstest::rstest;
+
+    use crate::{amount, price};
+
+    fn props_with_status(status: OrderStatus) -> (OrderSimpleProps, OrderStatusHistory) {
+        let mut props = OrderSimpleProps::from_init_time(Utc::now());
+        props.status = status;
+        (props, OrderStatusHistory::default())
+    }
+
+    #[rstest]
+    #[case(OrderStatus::Creating, OrderStatus::Creating, true)]
+    #[case(OrderStatus::Creating, OrderStatus::Created, true)]
+    #[case(OrderStatus::Creating, OrderStatus::FailedToCreate, true)]
+    #[case(OrderStatus::Creating, OrderStatus::Canceling, true)]
+    #[case(OrderStatus::Creating, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Creating, OrderStatus::FailedToCancel, false)]
+    #[case(OrderStatus::Creating, OrderStatus::Completed, true)]
+    #[case(OrderStatus::Created, OrderStatus::Creating, false)]
+    #[case(OrderStatus::Created, OrderStatus::Created, true)]
+    #[case(OrderStatus::Created, OrderStatus::FailedToCreate, false)]
+    #[case(OrderStatus::Created, OrderStatus::Canceling, true)]
+    #[case(OrderStatus::Created, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Created, OrderStatus::FailedToCancel, true)]
+    #[case(OrderStatus::Created, OrderStatus::Completed, true)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::Creating, false)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::Created, false)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::FailedToCreate, true)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::Canceling, false)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::Canceled, false)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::FailedToCancel, false)]
+    #[case(OrderStatus::FailedToCreate, OrderStatus::Completed, false)]
+    #[case(OrderStatus::Canceling, OrderStatus::Creating, false)]
+    #[case(OrderStatus::Canceling, OrderStatus::Created, false)]
+    #[case(OrderStatus::Canceling, OrderStatus::FailedToCreate, false)]
+    #[case(OrderStatus::Canceling, OrderStatus::Canceling, true)]
+    #[case(OrderStatus::Canceling, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Canceling, OrderStatus::FailedToCancel, true)]
+    #[case(OrderStatus::Canceling, OrderStatus::Completed, true)]
+    #[case(OrderStatus::Canceled, OrderStatus::Creating, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::Created, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::FailedToCreate, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::Canceling, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::Canceled, OrderStatus::FailedToCancel, false)]
+    #[case(OrderStatus::Canceled, OrderStatus::Completed, true)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::Creating, false)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::Created, false)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::FailedToCreate, false)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::Canceling, true)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::Canceled, true)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::FailedToCancel, true)]
+    #[case(OrderStatus::FailedToCancel, OrderStatus::Completed, true)]
+    #[case(OrderStatus::Completed, OrderStatus::Creating, false)]
+    #[case(OrderStatus::Completed, OrderStatus::Created, false)]
+    #[case(OrderStatus::Completed, OrderStatus::FailedToCreate, false)]
+    #[case(OrderStatus::Completed, OrderStatus::Canceling, false)]
+    #[case(OrderStatus::Completed, OrderStatus::Canceled, false)]
+    #[case(OrderStatus::Completed, OrderStatus::FailedToCancel, false)]
+    #[case(OrderStatus::Completed, OrderStatus::Completed, true)]
+    fn status_transition(
+        #[case] from: OrderStatus,
+        #[case] to: OrderStatus,
+        #[case] expected: bool,
+    ) {
+        let (mut props, mut status_history) = props_with_status(from);
+
+        let is_applied = set_status(&mut props, &mut status_history, to, Utc::now());
+
+        assert_eq!(from.can_change_to(to), expected);
+        assert_eq!(is_applied, expected);
+        let expected_status = if expected { to } else { from };
+        assert_eq!(props.status, expected_status);
+    }
+
+    #[test]
+    fn outdated_status_change_rejected() {
+        let (mut props, mut status_history) = props_with_status(OrderStatus::Creating);
+        let now = Utc::now();
+
+        assert!(set_status(
+            &mut props,
+            &mut status_history,
+            OrderStatus::Canceling,
+            now
+        ));
+        // late REST response about creation should not overwrite newer status
+        let is_applied = set_status(
+            &mut props,
+            &mut status_history,
+            OrderStatus::Canceled,
+            now - Duration::seconds(1),
+        );
+
+        assert!(!is_applied);
+        assert_eq!(props.status, OrderStatus::Canceling);
+        assert_eq!(status_history.last_change_time(), Some(now));
+    }
+
+    #[test]
+    fn finished_time_set_only_for_applied_changes() {
+        let (mut props, mut status_history) = props_with_status(OrderStatus::Completed);
+
+        let is_applied = set_status(
+            &mut props,
+            &mut status_history,
+            OrderStatus::Canceled,
+            Utc::now(),
+        );
+
+        assert!(!is_applied);
+        assert_eq!(props.finished_time, None);
+        assert_eq!(status_history.last_change_time(), None);
+    }
+
+    fn limit_order_header(amount: Amount, price: Price) -> OrderHeader {
+        OrderHeader::with_user_order(
+            ClientOrderId::new("original".into()),
+            ExchangeAccountId::new("Binance", 0),
+            CurrencyPair::from_codes("btc".into(), "usdt".into()),
+            OrderSide::Buy,
+            amount,
+            UserOrder::Limit {
+                price,
+                execution_type: OrderExecutionType::MakerOnly,
+            },
+            Some(ReservationId::generate()),
+            None,
+            "test".to_owned(),
+        )
+    }
+
+    #[test]
+    fn replacement_of_partially_filled_order_has_remaining_amount() {
+        let header = limit_order_header(amount!(10), price!(100));
+        // 40% of order amount was filled
+        let filled_amount = amount!(4);
+        let reservation_id = ReservationId::generate();
+
+        let replacement = header
+            .replacement(
+                ClientOrderId::new("replacement".into()),
+                price!(101),
+                filled_amount,
+                None,
+                Some(reservation_id),
+            )
+            .expect("in test");
+
+        assert_eq!(replacement.client_order_id.as_str(), "replacement");
+        assert_eq!(replacement.amount, amount!(6));
+        assert_eq!(replacement.price(), price!(101));
+        assert_eq!(replacement.source_price, Some(price!(101)));
+        assert_eq!(replacement.side, header.side);
+        assert_eq!(replacement.currency_pair, header.currency_pair);
+        assert_eq!(replacement.reservation_id, Some(reservation_id));
+        assert_ne!(replacement.reservation_id, header.reservation_id);
+        assert!(matches!(
+            replacement.options,
+            OrderOptions::User(UserOrder::Limit {
+                execution_type: OrderExecutionType::MakerOnly,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn replacement_amount_can_be_overridden() {
+        let header = limit_order_header(amount!(10), price!(100));
+
+        let replacement = header
+            .replacement(
+                ClientOrderId::new("replacement".into()),
+                price!(99),
+                amount!(4),
+                Some(amount!(8)),
+                None,
+            )
+            .expect("in test");
+
+        assert_eq!(replacement.amount, amount!(8));
+        assert_eq!(replacement.price(), price!(99));
+    }
+
+    #[test]
+    fn fully_filled_order_cant_be_replaced() {
+        let header = limit_order_header(amount!(10), price!(100));
+
+        let result = header.replacement(
+            ClientOrderId::new("replacement".into()),
+            price!(101),
+            amount!(10),
+            None,
+            None,
+        );
+
+        assert!(result.is_err());
+    }
 }
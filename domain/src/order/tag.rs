@@ -0,0 +1,68 @@
+//! Fallback for exchanges that can't attach text tag to order: tag is encoded as prefix of
+//! client order id, so it is visible in exchange UI and can be restored from open orders.
+
+use crate::order::snapshot::ClientOrderId;
+
+pub const CLIENT_ORDER_ID_TAG_SEPARATOR: char = ':';
+
+/// Tag can contain only ASCII alphanumeric characters, `.`, `_` and `-` to be safely sent in
+/// request query and client order id on any exchange
+pub fn is_valid_tag(tag: &str) -> bool {
+    !tag.is_empty()
+        && tag
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'))
+}
+
+/// Returns client order id in format `{tag}:{client_order_id}`.
+/// Tag must not contain separator, otherwise it can't be decoded back.
+pub fn encode_tag_to_client_order_id(tag: &str, client_order_id: &ClientOrderId) -> ClientOrderId {
+    format!("{tag}{CLIENT_ORDER_ID_TAG_SEPARATOR}{client_order_id}")
+        .as_str()
+        .into()
+}
+
+/// Returns tag encoded by `encode_tag_to_client_order_id` or `None` if client order id isn't tagged
+pub fn decode_tag_from_client_order_id(client_order_id: &ClientOrderId) -> Option<&str> {
+    client_order_id
+        .as_str()
+        .split_once(CLIENT_ORDER_ID_TAG_SEPARATOR)
+        .map(|(tag, _)| tag)
+        .filter(|tag| !tag.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_tag() {
+        let client_order_id = ClientOrderId::from("1665000000");
+
+        let tagged = encode_tag_to_client_order_id("mm-btc", &client_order_id);
+
+        assert_eq!(tagged.as_str(), "mm-btc:1665000000");
+        assert_eq!(decode_tag_from_client_order_id(&tagged), Some("mm-btc"));
+    }
+
+    #[test]
+    fn validate_tag() {
+        assert!(is_valid_tag("mm_btc-1.0"));
+        assert!(!is_valid_tag(""));
+        assert!(!is_valid_tag("mm:btc"));
+        assert!(!is_valid_tag("mm btc"));
+        assert!(!is_valid_tag("mm&btc"));
+    }
+
+    #[test]
+    fn decode_untagged_client_order_id() {
+        assert_eq!(
+            decode_tag_from_client_order_id(&ClientOrderId::from("1665000000")),
+            None
+        );
+        assert_eq!(
+            decode_tag_from_client_order_id(&ClientOrderId::from(":1665000000")),
+            None
+        );
+    }
+}
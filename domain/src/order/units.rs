@@ -0,0 +1,310 @@
+//! Newtypes of decimal quantities used by orders and order books, so price can't be passed
+//! where amount is expected and vice versa. Arithmetic is implemented only where result has
+//! meaning: prices and amounts can be added to values of the same type and scaled by `Decimal`,
+//! `Price * Amount` is notional amount in quote currency.
+
+use mmb_utils::decimal_inverse_sign::DecimalInverseSign;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// Create `Price` from decimal literal, e.g. `price!(0.01)`
+#[macro_export]
+macro_rules! price {
+    ($value: expr) => {
+        $crate::order::snapshot::Price::new(rust_decimal_macros::dec!($value))
+    };
+}
+
+/// Create `Amount` from decimal literal, e.g. `amount!(0.01)`
+#[macro_export]
+macro_rules! amount {
+    ($value: expr) => {
+        $crate::order::snapshot::Amount::new(rust_decimal_macros::dec!($value))
+    };
+}
+
+macro_rules! impl_decimal_unit {
+    ($(#[$meta: meta])* $type: ident) => {
+        $(#[$meta])*
+        #[derive(
+            Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+        )]
+        #[serde(transparent)]
+        pub struct $type(Decimal);
+
+        impl $type {
+            pub const ZERO: $type = $type(Decimal::ZERO);
+            pub const ONE: $type = $type(Decimal::ONE);
+            pub const MAX: $type = $type(Decimal::MAX);
+
+            pub const fn new(value: Decimal) -> Self {
+                $type(value)
+            }
+
+            pub const fn value(self) -> Decimal {
+                self.0
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            pub fn is_sign_positive(&self) -> bool {
+                self.0.is_sign_positive()
+            }
+
+            pub fn is_sign_negative(&self) -> bool {
+                self.0.is_sign_negative()
+            }
+
+            pub fn abs(&self) -> Self {
+                $type(self.0.abs())
+            }
+
+            pub fn floor(&self) -> Self {
+                $type(self.0.floor())
+            }
+
+            pub fn ceil(&self) -> Self {
+                $type(self.0.ceil())
+            }
+
+            pub fn trunc(&self) -> Self {
+                $type(self.0.trunc())
+            }
+
+            pub fn normalize(&self) -> Self {
+                $type(self.0.normalize())
+            }
+
+            pub fn round_dp(&self, dp: u32) -> Self {
+                $type(self.0.round_dp(dp))
+            }
+
+            pub fn round_dp_with_strategy(&self, dp: u32, strategy: RoundingStrategy) -> Self {
+                $type(self.0.round_dp_with_strategy(dp, strategy))
+            }
+
+            pub fn scale(&self) -> u32 {
+                self.0.scale()
+            }
+
+            pub fn to_f64(&self) -> Option<f64> {
+                self.0.to_f64()
+            }
+        }
+
+        impl From<Decimal> for $type {
+            fn from(value: Decimal) -> Self {
+                $type(value)
+            }
+        }
+
+        impl From<$type> for Decimal {
+            fn from(value: $type) -> Self {
+                value.0
+            }
+        }
+
+        impl FromStr for $type {
+            type Err = rust_decimal::Error;
+
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                Decimal::from_str(value).map($type)
+            }
+        }
+
+        // Formatted as plain decimal to keep logs and explanations readable
+        impl Debug for $type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl Display for $type {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl Add for $type {
+            type Output = $type;
+
+            fn add(self, rhs: $type) -> $type {
+                $type(self.0 + rhs.0)
+            }
+        }
+
+        impl AddAssign for $type {
+            fn add_assign(&mut self, rhs: $type) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Sub for $type {
+            type Output = $type;
+
+            fn sub(self, rhs: $type) -> $type {
+                $type(self.0 - rhs.0)
+            }
+        }
+
+        impl SubAssign for $type {
+            fn sub_assign(&mut self, rhs: $type) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $type {
+            type Output = $type;
+
+            fn neg(self) -> $type {
+                $type(-self.0)
+            }
+        }
+
+        impl Mul<Decimal> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: Decimal) -> $type {
+                $type(self.0 * rhs)
+            }
+        }
+
+        impl Mul<$type> for Decimal {
+            type Output = $type;
+
+            fn mul(self, rhs: $type) -> $type {
+                $type(self * rhs.0)
+            }
+        }
+
+        impl MulAssign<Decimal> for $type {
+            fn mul_assign(&mut self, rhs: Decimal) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl Div<Decimal> for $type {
+            type Output = $type;
+
+            fn div(self, rhs: Decimal) -> $type {
+                $type(self.0 / rhs)
+            }
+        }
+
+        impl DivAssign<Decimal> for $type {
+            fn div_assign(&mut self, rhs: Decimal) {
+                self.0 /= rhs;
+            }
+        }
+
+        /// Ratio of values of the same type
+        impl Div for $type {
+            type Output = Decimal;
+
+            fn div(self, rhs: $type) -> Decimal {
+                self.0 / rhs.0
+            }
+        }
+
+        impl DecimalInverseSign for $type {
+            fn inverse_sign(&mut self) {
+                self.0.inverse_sign();
+            }
+        }
+
+        impl Sum for $type {
+            fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+                $type(iter.map(|x| x.0).sum())
+            }
+        }
+
+        impl<'a> Sum<&'a $type> for $type {
+            fn sum<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+                $type(iter.map(|x| x.0).sum())
+            }
+        }
+    };
+}
+
+impl_decimal_unit!(
+    /// Price of base currency in quote currency
+    Price
+);
+impl_decimal_unit!(
+    /// Amount is a currency quantity: order amount, position amount, balance amount, etc.
+    Amount
+);
+
+/// Notional amount in quote currency
+impl Mul<Amount> for Price {
+    type Output = Amount;
+
+    fn mul(self, rhs: Amount) -> Amount {
+        Amount(self.0 * rhs.0)
+    }
+}
+
+/// Notional amount in quote currency
+impl Mul<Price> for Amount {
+    type Output = Amount;
+
+    fn mul(self, rhs: Price) -> Amount {
+        Amount(self.0 * rhs.0)
+    }
+}
+
+/// Amount in base currency which notional amount in quote currency buys by the price
+impl Div<Price> for Amount {
+    type Output = Amount;
+
+    fn div(self, rhs: Price) -> Amount {
+        Amount(self.0 / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn notional_of_price_and_amount() {
+        let notional = price!(101.5) * amount!(2);
+
+        assert_eq!(notional, amount!(203));
+        assert_eq!(notional / price!(101.5), amount!(2));
+    }
+
+    #[test]
+    fn arithmetic_within_type() {
+        assert_eq!(price!(1.5) + price!(0.5), price!(2));
+        assert_eq!(amount!(1.5) - amount!(2), amount!(-0.5));
+        assert_eq!(price!(3) * dec!(0.5), price!(1.5));
+        assert_eq!(amount!(3) / dec!(2), amount!(1.5));
+        assert_eq!(amount!(3) / amount!(2), dec!(1.5));
+        assert_eq!([amount!(1), amount!(2)].iter().sum::<Amount>(), amount!(3));
+    }
+
+    #[test]
+    fn serialized_as_decimal() {
+        let serialized = serde_json::to_string(&price!(1.25)).expect("in test");
+        assert_eq!(serialized, r#""1.25""#);
+
+        let deserialized: Amount = serde_json::from_str(&serialized).expect("in test");
+        assert_eq!(deserialized, amount!(1.25));
+    }
+
+    #[test]
+    fn formatted_as_decimal() {
+        assert_eq!(format!("{:?}", Some(price!(10.2))), "Some(10.2)");
+        assert_eq!(amount!(0.5).to_string(), "0.5");
+    }
+}
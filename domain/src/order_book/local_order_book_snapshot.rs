@@ -3,7 +3,9 @@ use crate::order::snapshot::*;
 use crate::order::snapshot::{PriceByOrderSide, SortedOrderData};
 use crate::order_book::order_book_data::OrderBookData;
 use mmb_utils::DateTime;
+use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use std::time::Duration;
 
 /// Fields from OrderSnapshot for exclude order
 pub struct DataToExcludeOrder {
@@ -50,12 +52,47 @@ impl LocalOrderBookSnapshot {
         }
     }
 
+    /// Snapshot wasn't updated longer than `max_age`, e.g. because of silent death of stream
+    pub fn is_stale(&self, max_age: Duration, now: DateTime) -> bool {
+        (now - self.last_update_time).to_std().unwrap_or_default() > max_age
+    }
+
     /// Update inner asks and bids
     pub fn apply_update(&mut self, update: &OrderBookData, update_time: DateTime) {
         OrderBookData::apply_update(&mut self.asks, &mut self.bids, update);
         self.last_update_time = update_time;
     }
 
+    /// Remove price levels beyond `max_depth` from both sides, so top `max_depth` levels stay
+    /// accurate. Pruned levels aren't restored if better levels are removed later: book side can
+    /// contain less than `max_depth` levels until the next update or snapshot
+    pub fn truncate_depth(&mut self, max_depth: usize) {
+        if let Some(&first_pruned_ask) = self.asks.keys().nth(max_depth) {
+            let _ = self.asks.split_off(&first_pruned_ask);
+        }
+
+        let bids_to_prune = self.bids.len().saturating_sub(max_depth);
+        if bids_to_prune > 0 {
+            match self.bids.keys().nth(bids_to_prune) {
+                Some(&worst_kept_bid) => self.bids = self.bids.split_off(&worst_kept_bid),
+                None => self.bids.clear(),
+            }
+        }
+    }
+
+    /// Return snapshot with top `depth` price levels of each side.
+    /// If snapshot is pruned by max depth, result is valid only for `depth` up to this limit
+    pub fn get_depth(&self, depth: usize) -> LocalOrderBookSnapshot {
+        let asks = self.get_asks_price_levels().take(depth);
+        let bids = self.get_bids_price_levels().take(depth);
+
+        LocalOrderBookSnapshot::new(
+            asks.map(|(&price, &amount)| (price, amount)).collect(),
+            bids.map(|(&price, &amount)| (price, amount)).collect(),
+            self.last_update_time,
+        )
+    }
+
     pub fn exclude_orders<T>(&mut self, orders: T)
     where
         T: IntoIterator<Item = DataToExcludeOrder>,
@@ -103,7 +140,7 @@ impl LocalOrderBookSnapshot {
         let book_side = self.get_order_book_side(order.side);
 
         if let Some(amount) = book_side.get(&order.price) {
-            let new_amount = amount - order.amount;
+            let new_amount = *amount - order.amount;
 
             if new_amount.is_sign_negative() || new_amount.is_zero() {
                 let _ = book_side.remove(&order.price);
@@ -156,6 +193,85 @@ impl LocalOrderBookSnapshot {
         Some((top_ask + top_bid) * dec!(0.5))
     }
 
+    /// Average price of filling `notional` in quote currency by walking price levels of
+    /// opposite book side. Returns `None` if book doesn't have enough liquidity
+    pub fn price_to_fill(&self, side: OrderSide, notional: Amount) -> Option<Price> {
+        if notional <= Amount::ZERO {
+            return None;
+        }
+
+        let price_levels: Box<dyn Iterator<Item = (&Price, &Amount)>> = match side {
+            OrderSide::Buy => Box::new(self.get_asks_price_levels()),
+            OrderSide::Sell => Box::new(self.get_bids_price_levels()),
+        };
+
+        let mut remaining_notional = notional;
+        let mut filled_amount = Amount::ZERO;
+        for (&price, &amount) in price_levels {
+            let level_notional = price * amount;
+            if level_notional >= remaining_notional {
+                // notional / (filled_amount + remaining_notional / price) with a single
+                // division, so price isn't distorted by rounding of partially filled amount
+                return Some(Price::new(
+                    notional * price / (filled_amount * price + remaining_notional),
+                ));
+            }
+
+            filled_amount += amount;
+            remaining_notional -= level_notional;
+        }
+
+        None
+    }
+
+    /// Average price of filling `amount` in base currency by walking price levels of
+    /// opposite book side. Returns `None` if book doesn't have enough liquidity
+    pub fn price_to_fill_amount(&self, side: OrderSide, amount: Amount) -> Option<Price> {
+        if amount <= Amount::ZERO {
+            return None;
+        }
+
+        let price_levels: Box<dyn Iterator<Item = (&Price, &Amount)>> = match side {
+            OrderSide::Buy => Box::new(self.get_asks_price_levels()),
+            OrderSide::Sell => Box::new(self.get_bids_price_levels()),
+        };
+
+        let mut remaining_amount = amount;
+        let mut filled_notional = Amount::ZERO;
+        for (&price, &level_amount) in price_levels {
+            if level_amount >= remaining_amount {
+                filled_notional += remaining_amount * price;
+                return Some(Price::new(filled_notional / amount));
+            }
+
+            filled_notional += level_amount * price;
+            remaining_amount -= level_amount;
+        }
+
+        None
+    }
+
+    /// Relative difference between average fill price of `notional` and middle price.
+    /// Positive value means fill is worse than middle price
+    pub fn expected_slippage(&self, side: OrderSide, notional: Amount) -> Option<Decimal> {
+        let (top_ask, top_bid) = match self.get_top_prices() {
+            PriceByOrderSide {
+                top_ask: Some(top_ask),
+                top_bid: Some(top_bid),
+            } => (top_ask, top_bid),
+            _ => return None,
+        };
+
+        let middle_price = (top_ask + top_bid) * dec!(0.5);
+        let price = self.price_to_fill(side, notional)?;
+        let slippage = match side {
+            OrderSide::Buy => price - middle_price,
+            OrderSide::Sell => middle_price - price,
+        };
+
+        Some(slippage / middle_price)
+    }
+
     /// Removed asks and bids between top price levels if it's crossed
     pub fn fix_asks_bids_if_needed(&mut self) -> ResultAskBidFix {
         match self.get_top_prices() {
@@ -198,64 +314,111 @@ impl LocalOrderBookSnapshot {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{amount, price};
     use chrono::Utc;
+    use rstest::rstest;
 
     #[test]
     fn get_top_ask() {
         let mut asks = SortedOrderData::new();
-        asks.insert(dec!(1.0), dec!(0.1));
-        asks.insert(dec!(3.0), dec!(4.2));
+        asks.insert(price!(1.0), amount!(0.1));
+        asks.insert(price!(3.0), amount!(4.2));
         let bids = SortedOrderData::new();
 
         let order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
         let top_ask = order_book_snapshot.get_top_ask().expect("in test");
 
-        assert_eq!(top_ask, (dec!(1.0), dec!(0.1)))
+        assert_eq!(top_ask, (price!(1.0), amount!(0.1)))
     }
 
     #[test]
     fn get_asks_price_levels() {
         let mut asks = SortedOrderData::new();
-        asks.insert(dec!(1.0), dec!(0.1));
-        asks.insert(dec!(3.0), dec!(4.2));
+        asks.insert(price!(1.0), amount!(0.1));
+        asks.insert(price!(3.0), amount!(4.2));
         let bids = SortedOrderData::new();
 
         let order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
         let mut iter = order_book_snapshot.get_asks_price_levels();
 
-        assert_eq!(iter.next().expect("in test"), (&dec!(1.0), &dec!(0.1)));
-        assert_eq!(iter.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
+        assert_eq!(iter.next().expect("in test"), (&price!(1.0), &amount!(0.1)));
+        assert_eq!(iter.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
+    }
+
+    fn snapshot_with_levels(levels_count: u32) -> LocalOrderBookSnapshot {
+        let asks = (1..=levels_count).map(|x| (Price::from(Decimal::from(100 + x)), amount!(1)));
+        let bids = (1..=levels_count).map(|x| (Price::from(Decimal::from(100 - x)), amount!(1)));
+
+        LocalOrderBookSnapshot::new(asks.collect(), bids.collect(), Utc::now())
+    }
+
+    #[test]
+    fn truncate_depth_keeps_top_levels() {
+        let mut order_book_snapshot = snapshot_with_levels(5);
+
+        order_book_snapshot.truncate_depth(2);
+
+        let asks = order_book_snapshot.asks.keys().copied().collect::<Vec<_>>();
+        let bids = order_book_snapshot.bids.keys().copied().collect::<Vec<_>>();
+        assert_eq!(asks, vec![price!(101), price!(102)]);
+        assert_eq!(bids, vec![price!(98), price!(99)]);
+    }
+
+    #[test]
+    fn truncate_depth_without_excess_levels() {
+        let mut order_book_snapshot = snapshot_with_levels(2);
+
+        order_book_snapshot.truncate_depth(3);
+        assert_eq!(order_book_snapshot.asks.len(), 2);
+        assert_eq!(order_book_snapshot.bids.len(), 2);
+
+        order_book_snapshot.truncate_depth(0);
+        assert!(order_book_snapshot.asks.is_empty());
+        assert!(order_book_snapshot.bids.is_empty());
+    }
+
+    #[test]
+    fn get_depth() {
+        let order_book_snapshot = snapshot_with_levels(5);
+
+        let depth = order_book_snapshot.get_depth(3);
+
+        assert_eq!(depth.asks.len(), 3);
+        assert_eq!(depth.bids.len(), 3);
+        assert_eq!(depth.get_top_ask(), order_book_snapshot.get_top_ask());
+        assert_eq!(depth.get_top_bid(), order_book_snapshot.get_top_bid());
+        assert_eq!(depth.bids.keys().next(), Some(&price!(97)));
     }
 
     #[test]
     fn get_top_bid() {
         let asks = SortedOrderData::new();
         let mut bids = SortedOrderData::new();
-        bids.insert(dec!(1.0), dec!(0.1));
-        bids.insert(dec!(3.0), dec!(4.2));
+        bids.insert(price!(1.0), amount!(0.1));
+        bids.insert(price!(3.0), amount!(4.2));
 
         let order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
         let top_bid = order_book_snapshot.get_top_bid().expect("in test");
 
-        assert_eq!(top_bid, (dec!(3.0), dec!(4.2)))
+        assert_eq!(top_bid, (price!(3.0), amount!(4.2)))
     }
 
     #[test]
     fn get_bids_price_levels() {
         let asks = SortedOrderData::new();
         let mut bids = SortedOrderData::new();
-        bids.insert(dec!(1.0), dec!(0.1));
-        bids.insert(dec!(3.0), dec!(4.2));
+        bids.insert(price!(1.0), amount!(0.1));
+        bids.insert(price!(3.0), amount!(4.2));
 
         let order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
         let mut iter = order_book_snapshot.get_bids_price_levels();
 
-        assert_eq!(iter.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
-        assert_eq!(iter.next().expect("in test"), (&dec!(1.0), &dec!(0.1)));
+        assert_eq!(iter.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
+        assert_eq!(iter.next().expect("in test"), (&price!(1.0), &amount!(0.1)));
     }
 
     #[test]
@@ -273,14 +436,14 @@ mod tests {
     #[test]
     fn remove_bid_order_completely() {
         // Construct update
-        let order = DataToExcludeOrder::new(dec!(1.0), dec!(0.5), OrderSide::Buy);
+        let order = DataToExcludeOrder::new(price!(1.0), amount!(0.5), OrderSide::Buy);
         let orders = vec![order];
 
         // Construct main object
         let asks = SortedOrderData::new();
         let mut bids = SortedOrderData::new();
-        bids.insert(dec!(1.0), dec!(0.5));
-        bids.insert(dec!(3.0), dec!(4.2));
+        bids.insert(price!(1.0), amount!(0.5));
+        bids.insert(price!(3.0), amount!(4.2));
 
         let mut order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
@@ -288,7 +451,7 @@ mod tests {
 
         let mut bids = order_book_snapshot.get_bids_price_levels();
         // Still exists
-        assert_eq!(bids.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
+        assert_eq!(bids.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
         // Was removed cause amount became <= 0
         assert_eq!(bids.next(), None);
     }
@@ -296,14 +459,14 @@ mod tests {
     #[test]
     fn decrease_bid_amount() {
         // Construct update
-        let order = DataToExcludeOrder::new(dec!(1.0), dec!(0.3), OrderSide::Buy);
+        let order = DataToExcludeOrder::new(price!(1.0), amount!(0.3), OrderSide::Buy);
         let orders = vec![order];
 
         // Construct main object
         let asks = SortedOrderData::new();
         let mut bids = SortedOrderData::new();
-        bids.insert(dec!(1.0), dec!(0.5));
-        bids.insert(dec!(3.0), dec!(4.2));
+        bids.insert(price!(1.0), amount!(0.5));
+        bids.insert(price!(3.0), amount!(4.2));
 
         let mut order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
@@ -311,22 +474,22 @@ mod tests {
 
         let mut bids = order_book_snapshot.get_bids_price_levels();
         // Still exists
-        assert_eq!(bids.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
+        assert_eq!(bids.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
         // Amount value was updated
-        assert_eq!(bids.next().expect("in test"), (&dec!(1.0), &dec!(0.2)));
+        assert_eq!(bids.next().expect("in test"), (&price!(1.0), &amount!(0.2)));
     }
 
     #[test]
     fn remove_ask_order_completely() {
         // Construct update
-        let order = DataToExcludeOrder::new(dec!(1.0), dec!(0.5), OrderSide::Sell);
+        let order = DataToExcludeOrder::new(price!(1.0), amount!(0.5), OrderSide::Sell);
         let orders = vec![order];
 
         // Construct main object
         let mut asks = SortedOrderData::new();
         let bids = SortedOrderData::new();
-        asks.insert(dec!(1.0), dec!(0.5));
-        asks.insert(dec!(3.0), dec!(4.2));
+        asks.insert(price!(1.0), amount!(0.5));
+        asks.insert(price!(3.0), amount!(4.2));
 
         let mut order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
@@ -334,7 +497,7 @@ mod tests {
 
         let mut asks = order_book_snapshot.get_asks_price_levels();
         // Still exists
-        assert_eq!(asks.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
+        assert_eq!(asks.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
         // Was removed cause amount became <= 0
         assert_eq!(asks.next(), None);
     }
@@ -342,14 +505,14 @@ mod tests {
     #[test]
     fn decrease_ask_amount() {
         // Construct update
-        let order = DataToExcludeOrder::new(dec!(1.0), dec!(1.5), OrderSide::Sell);
+        let order = DataToExcludeOrder::new(price!(1.0), amount!(1.5), OrderSide::Sell);
         let orders = vec![order];
 
         // Construct main object
         let mut asks = SortedOrderData::new();
         let bids = SortedOrderData::new();
-        asks.insert(dec!(1.0), dec!(2.1));
-        asks.insert(dec!(3.0), dec!(4.2));
+        asks.insert(price!(1.0), amount!(2.1));
+        asks.insert(price!(3.0), amount!(4.2));
 
         let mut order_book_snapshot = LocalOrderBookSnapshot::new(asks, bids, Utc::now());
 
@@ -357,8 +520,80 @@ mod tests {
 
         let mut asks = order_book_snapshot.get_asks_price_levels();
         // Amount value was updated
-        assert_eq!(asks.next().expect("in test"), (&dec!(1.0), &dec!(0.6)));
+        assert_eq!(asks.next().expect("in test"), (&price!(1.0), &amount!(0.6)));
         // Still exists
-        assert_eq!(asks.next().expect("in test"), (&dec!(3.0), &dec!(4.2)));
+        assert_eq!(asks.next().expect("in test"), (&price!(3.0), &amount!(4.2)));
+    }
+
+    #[test]
+    fn is_stale() {
+        let last_update_time = Utc::now();
+        let order_book_snapshot = LocalOrderBookSnapshot::new(
+            SortedOrderData::new(),
+            SortedOrderData::new(),
+            last_update_time,
+        );
+        let max_age = Duration::from_secs(5);
+        let after = |secs| last_update_time + chrono::Duration::seconds(secs);
+
+        assert!(!order_book_snapshot.is_stale(max_age, last_update_time));
+        assert!(!order_book_snapshot.is_stale(max_age, after(5)));
+        assert!(order_book_snapshot.is_stale(max_age, after(6)));
+    }
+
+    fn liquidity_snapshot() -> LocalOrderBookSnapshot {
+        let asks = [(price!(101), amount!(1)), (price!(102), amount!(2))];
+        let bids = [(price!(99), amount!(1)), (price!(98), amount!(2))];
+
+        LocalOrderBookSnapshot::new(
+            asks.into_iter().collect(),
+            bids.into_iter().collect(),
+            Utc::now(),
+        )
+    }
+
+    #[rstest]
+    #[case(OrderSide::Buy, amount!(50), Some(price!(101)))]
+    #[case(OrderSide::Buy, amount!(203), Some(price!(101.5)))]
+    #[case(OrderSide::Sell, amount!(197), Some(price!(98.5)))]
+    #[case(OrderSide::Buy, amount!(306), None)]
+    #[case(OrderSide::Sell, amount!(0), None)]
+    fn price_to_fill(
+        #[case] side: OrderSide,
+        #[case] notional: Amount,
+        #[case] expected: Option<Price>,
+    ) {
+        assert_eq!(liquidity_snapshot().price_to_fill(side, notional), expected);
+    }
+
+    #[rstest]
+    #[case(OrderSide::Sell, amount!(0.5), Some(price!(99)))]
+    #[case(OrderSide::Sell, amount!(2), Some(price!(98.5)))]
+    #[case(OrderSide::Buy, amount!(3), Some(Price::new(dec!(305) / dec!(3))))]
+    #[case(OrderSide::Sell, amount!(4), None)]
+    #[case(OrderSide::Buy, amount!(0), None)]
+    fn price_to_fill_amount(
+        #[case] side: OrderSide,
+        #[case] amount: Amount,
+        #[case] expected: Option<Price>,
+    ) {
+        assert_eq!(
+            liquidity_snapshot().price_to_fill_amount(side, amount),
+            expected
+        );
+    }
+
+    #[test]
+    fn expected_slippage() {
+        let snapshot = liquidity_snapshot();
+
+        assert_eq!(
+            snapshot.expected_slippage(OrderSide::Buy, amount!(203)),
+            Some(dec!(0.015))
+        );
+        assert_eq!(
+            snapshot.expected_slippage(OrderSide::Sell, amount!(99)),
+            Some(dec!(0.01))
+        );
     }
 }
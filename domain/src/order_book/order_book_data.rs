@@ -29,12 +29,12 @@ use mmb_utils::DateTime;
 macro_rules! order_book_data {
     ($( $key_a: expr => $val_a: expr ),*, ;
      $( $key_b: expr => $val_b: expr ),*,) => {{
-        use rust_decimal::Decimal;
+        use $crate::order::snapshot::{Amount, Price};
         let mut asks = $crate::order::snapshot::SortedOrderData::new();
-        asks.extend(vec![ $( ($key_a, $val_a), )* ] as Vec<(Decimal, Decimal)>);
+        asks.extend(vec![ $( (Price::from($key_a), Amount::from($val_a)), )* ] as Vec<(Price, Amount)>);
 
         let mut bids = $crate::order::snapshot::SortedOrderData::new();
-        bids.extend(vec![ $( ($key_b, $val_b), )* ] as Vec<(Decimal, Decimal)>);
+        bids.extend(vec![ $( (Price::from($key_b), Amount::from($val_b)), )* ] as Vec<(Price, Amount)>);
 
         $crate::order_book::order_book_data::OrderBookData::new(asks, bids)
     }};
@@ -96,6 +96,7 @@ impl OrderBookData {
 
 #[cfg(test)]
 mod tests {
+    use crate::{amount, price};
     use rust_decimal_macros::dec;
 
     #[test]
@@ -118,8 +119,8 @@ mod tests {
 
         main_order_data.update(updates);
 
-        assert_eq!(main_order_data.asks.get(&dec!(1.0)), Some(&dec!(2.0)));
-        assert_eq!(main_order_data.asks.get(&dec!(3.0)), Some(&dec!(4.0)));
+        assert_eq!(main_order_data.asks.get(&price!(1.0)), Some(&amount!(2.0)));
+        assert_eq!(main_order_data.asks.get(&price!(3.0)), Some(&amount!(4.0)));
     }
 
     #[test]
@@ -142,8 +143,8 @@ mod tests {
 
         main_order_data.update(updates);
 
-        assert_eq!(main_order_data.bids.get(&dec!(1.0)), Some(&dec!(2.2)));
-        assert_eq!(main_order_data.bids.get(&dec!(3.0)), Some(&dec!(4.0)));
+        assert_eq!(main_order_data.bids.get(&price!(1.0)), Some(&amount!(2.2)));
+        assert_eq!(main_order_data.bids.get(&price!(3.0)), Some(&amount!(4.0)));
     }
 
     #[test]
@@ -160,8 +161,8 @@ mod tests {
 
         main_order_data.update(updates);
 
-        assert_eq!(main_order_data.bids.get(&dec!(1.0)), Some(&dec!(1.0)));
-        assert_eq!(main_order_data.bids.get(&dec!(3.0)), Some(&dec!(1.0)));
+        assert_eq!(main_order_data.bids.get(&price!(1.0)), Some(&amount!(1.0)));
+        assert_eq!(main_order_data.bids.get(&price!(3.0)), Some(&amount!(1.0)));
     }
 
     #[test]
@@ -192,12 +193,12 @@ mod tests {
         main_order_data.update(updates);
 
         // Updated from second update
-        assert_eq!(main_order_data.asks.get(&dec!(1.0)), Some(&dec!(2.8)));
+        assert_eq!(main_order_data.asks.get(&price!(1.0)), Some(&amount!(2.8)));
         // Unchanged
-        assert_eq!(main_order_data.asks.get(&dec!(2.0)), Some(&dec!(5.6)));
+        assert_eq!(main_order_data.asks.get(&price!(2.0)), Some(&amount!(5.6)));
         // Updated from first update
-        assert_eq!(main_order_data.asks.get(&dec!(3.0)), Some(&dec!(4.0)));
+        assert_eq!(main_order_data.asks.get(&price!(3.0)), Some(&amount!(4.0)));
         // Deleted because 0 amount in second update
-        assert_eq!(main_order_data.asks.get(&dec!(6.0)), None);
+        assert_eq!(main_order_data.asks.get(&price!(6.0)), None);
     }
 }
@@ -3,11 +3,30 @@ use crate::order::snapshot::{Amount, ExchangeOrderId, OrderSide, Price, String16
 use mmb_utils::DateTime;
 use once_cell::sync::Lazy;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// How collateral is shared between futures positions. Liquidation of isolated position is
+/// limited to margin assigned to it, cross position can use the whole account balance
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginMode {
+    Cross,
+    Isolated,
+}
+
+impl Display for MarginMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MarginMode::Cross => f.write_str("cross"),
+            MarginMode::Isolated => f.write_str("isolated"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DerivativePosition {
     pub currency_pair: CurrencyPair,
@@ -15,6 +34,8 @@ pub struct DerivativePosition {
     pub average_entry_price: Price,
     pub liquidation_price: Price,
     pub leverage: Decimal,
+    /// `None` if exchange doesn't report margin mode of position
+    pub margin_mode: Option<MarginMode>,
 }
 
 impl DerivativePosition {
@@ -31,9 +52,15 @@ impl DerivativePosition {
             average_entry_price,
             liquidation_price,
             leverage,
+            margin_mode: None,
         }
     }
 
+    pub fn with_margin_mode(mut self, margin_mode: MarginMode) -> Self {
+        self.margin_mode = Some(margin_mode);
+        self
+    }
+
     pub fn get_side(&self) -> OrderSide {
         debug_assert!(!self.position.is_zero());
 
@@ -51,6 +51,7 @@ async fn main() -> Result<()> {
             settings.strategy.currency_pair(),
             settings.strategy.spread,
             settings.strategy.max_amount,
+            settings.strategy.amount_quantization.clone(),
             engine.context(),
         );
 
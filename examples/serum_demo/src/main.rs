@@ -44,6 +44,7 @@ async fn main() {
             settings.strategy.currency_pair(),
             settings.strategy.spread,
             settings.strategy.max_amount,
+            settings.strategy.amount_quantization.clone(),
             engine.context(),
         );
 
@@ -1,6 +1,9 @@
 use anyhow::Result;
 use itertools::Itertools;
 use mmb_core::balance::manager::balance_manager::BalanceManager;
+use mmb_core::disposition_execution::amount_quantization::{
+    AmountQuantizationSettings, AmountQuantizer,
+};
 use mmb_core::disposition_execution::strategy::DispositionStrategy;
 use mmb_core::disposition_execution::{
     PriceSlot, TradeCycle, TradeDisposition, TradingContext, TradingContextBySide,
@@ -14,22 +17,24 @@ use mmb_domain::events::ExchangeEvent;
 use mmb_domain::exchanges::symbol::Round;
 use mmb_domain::market::CurrencyPair;
 use mmb_domain::market::{ExchangeAccountId, MarketAccountId, MarketId};
-use mmb_domain::order::snapshot::Amount;
+use mmb_domain::order::snapshot::{Amount, Price};
 use mmb_domain::order::snapshot::{OrderRole, OrderSide, OrderSnapshot};
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::infrastructure::WithExpect;
 use mmb_utils::DateTime;
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ExampleStrategySettings {
-    pub spread: Decimal,
+    pub spread: Price,
     pub currency_pair: CurrencyPairSetting,
-    pub max_amount: Decimal,
+    pub max_amount: Amount,
     pub exchange_account_id: ExchangeAccountId,
+    /// Rounding of order amounts to a grid coarser than amount step of exchange.
+    /// Amounts are rounded by amount step only if not specified
+    pub amount_quantization: Option<AmountQuantizationSettings>,
 }
 
 impl DispositionStrategySettings for ExampleStrategySettings {
@@ -57,18 +62,20 @@ impl DispositionStrategySettings for ExampleStrategySettings {
 pub struct ExampleStrategy {
     target_eai: ExchangeAccountId,
     currency_pair: CurrencyPair,
-    spread: Decimal,
+    spread: Price,
     engine_context: Arc<EngineContext>,
     configuration_descriptor: ConfigurationDescriptor,
-    max_amount: Decimal,
+    max_amount: Amount,
+    amount_quantizer: Option<AmountQuantizer>,
 }
 
 impl ExampleStrategy {
     pub fn new(
         target_eai: ExchangeAccountId,
         currency_pair: CurrencyPair,
-        spread: Decimal,
-        max_amount: Decimal,
+        spread: Price,
+        max_amount: Amount,
+        amount_quantization: Option<AmountQuantizationSettings>,
         engine_context: Arc<EngineContext>,
     ) -> Box<Self> {
         let configuration_descriptor = ConfigurationDescriptor::new(
@@ -90,6 +97,11 @@ impl ExampleStrategy {
             .with_expect(|| format!("failed to get symbol from exchange for {currency_pair}"))
             .clone();
 
+        let amount_quantizer = amount_quantization.map(|settings| {
+            AmountQuantizer::new(&settings, &symbol)
+                .with_expect(|| format!("Invalid amount quantization for {currency_pair}"))
+        });
+
         engine_context
             .balance_manager
             .lock()
@@ -102,6 +114,7 @@ impl ExampleStrategy {
             engine_context,
             configuration_descriptor,
             max_amount,
+            amount_quantizer,
         })
     }
 
@@ -197,7 +210,10 @@ impl ExampleStrategy {
             )
         };
 
-        let amount = symbol.amount_round(amount, Round::Floor);
+        let amount = match &self.amount_quantizer {
+            Some(amount_quantizer) => amount_quantizer.quantize(&symbol, amount, Round::Floor),
+            None => symbol.amount_round(amount, Round::Floor),
+        };
 
         Some(TradingContextBySide {
             max_amount: self.max_amount,
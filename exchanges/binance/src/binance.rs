@@ -8,20 +8,27 @@ use hyper::header::CONTENT_TYPE;
 use hyper::http::request::Builder;
 use hyper::Uri;
 use itertools::Itertools;
-use mmb_utils::time::{get_current_milliseconds, u64_to_date_time};
+use mmb_utils::infrastructure::WithExpect;
+use mmb_utils::time::u64_to_date_time;
 use mmb_utils::DateTime;
 use parking_lot::{Mutex, RwLock};
+use rust_decimal::Decimal;
 use serde_json::Value;
 use sha2::Sha256;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::future::Future;
 use std::sync::Arc;
 use std::time::{Duration, UNIX_EPOCH};
 use tokio::sync::broadcast;
 
 use super::support::{
-    BinanceDerivativeAccountInfo, BinanceOrderInfo, BinancePosition, BinanceSpotAccountInfo,
+    get_order_book_side, BinanceAggTrade, BinanceDerivativeAccountInfo, BinanceFundingRate,
+    BinanceIncome, BinanceOrderInfo, BinancePosition, BinanceSpotAccountInfo, PendingSubscriptions,
 };
+use mmb_core::connectivity::{StreamKind, Subscription};
+use mmb_core::exchanges::asset_networks_cache::AssetNetworks;
+use mmb_core::exchanges::common::send_event;
 use mmb_core::exchanges::general::exchange::BoxExchangeClient;
 use mmb_core::exchanges::general::exchange::Exchange;
 use mmb_core::exchanges::general::features::{
@@ -31,37 +38,56 @@ use mmb_core::exchanges::general::handlers::handle_order_filled::FillAmount;
 use mmb_core::exchanges::general::handlers::handle_order_filled::FillEvent;
 use mmb_core::exchanges::general::order::get_order_trades::OrderTrade;
 use mmb_core::exchanges::hosts::Hosts;
+use mmb_core::exchanges::nonce::NonceGenerator;
 use mmb_core::exchanges::rest_client::{
-    ErrorHandler, ErrorHandlerData, RequestType, RestClient, RestHeaders, RestResponse, UriBuilder,
+    ErrorHandler, ErrorHandlerData, RateLimitHeader, RateLimitUsageCb, RequestType, RestClient,
+    RestHeaders, RestLatencyCb, RestResponse, RestUnreachableCb, UriBuilder,
 };
+use mmb_core::exchanges::rest_failure_monitor::RestFailureMonitor;
 use mmb_core::exchanges::timeouts::timeout_manager::TimeoutManager;
 use mmb_core::exchanges::traits::{ExchangeClientBuilder, ExchangeError, HandleMetricsCb};
 use mmb_core::exchanges::traits::{
     ExchangeClientBuilderResult, HandleOrderFilledCb, HandleTradeCb, OrderCancelledCb,
-    OrderCreatedCb, Support,
+    OrderCreatedCb, SendWebsocketMessageCb, Support,
 };
 use mmb_core::exchanges::{
     general::features::{ExchangeFeatures, OpenOrdersType},
     timeouts::requests_timeout_manager_factory::RequestTimeoutArguments,
 };
 use mmb_core::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use mmb_core::settings::ExchangeSettings;
-use mmb_domain::events::{AllowedEventSourceType, EventSourceType};
-use mmb_domain::events::{ExchangeBalance, ExchangeEvent, TradeId};
-use mmb_domain::exchanges::symbol::{Precision, Symbol};
+use mmb_core::settings::{AccountType, ExchangeSettings};
+use mmb_domain::events::{AggTrade, AllowedEventSourceType, EventSourceType};
+use mmb_domain::events::{ExchangeBalance, ExchangeEvent, SystemStatus, TradeId};
+use mmb_domain::events::{OrderRateLimit, OrderRateLimitStatus, PacingChangedEvent, RateLimitKind};
+use mmb_domain::events::{RateLimitUsageEvent, RestUnreachableEvent};
+use mmb_domain::exchanges::api_permissions::ApiPermissions;
+use mmb_domain::exchanges::asset_network::NetworkInfo;
+use mmb_domain::exchanges::commission::{CurrencyPairFees, Percent};
+use mmb_domain::exchanges::dust_conversion::{ConvertedDust, DustConversion};
+use mmb_domain::exchanges::funding::FundingPayment;
+use mmb_domain::exchanges::leverage::LeverageBracket;
+use mmb_domain::exchanges::symbol::{Precision, Symbol, SymbolStatus};
 use mmb_domain::market::{CurrencyCode, CurrencyId, CurrencyPair, ExchangeErrorType, ExchangeId};
 use mmb_domain::market::{ExchangeAccountId, SpecificCurrencyPair};
 use mmb_domain::order::fill::OrderFillType;
 use mmb_domain::order::pool::{OrderRef, OrdersPool};
 use mmb_domain::order::snapshot::*;
 use mmb_domain::order::snapshot::{Amount, Price};
-use mmb_domain::position::{ActivePosition, DerivativePosition};
+use mmb_domain::order::tag::decode_tag_from_client_order_id;
+use mmb_domain::order_book::order_book_data::OrderBookData;
+use mmb_domain::position::{ActivePosition, DerivativePosition, MarginMode};
+use mmb_utils::decimal_parsing::{deserialize_decimal, parse_decimal, DecimalParsingTolerance};
 use mmb_utils::value_to_decimal::GetOrErr;
 use serde::{Deserialize, Serialize};
 use sha2::digest::generic_array::GenericArray;
 
 const LISTEN_KEY: &str = "listenKey";
 
+const RATE_LIMIT_HEADERS: &[RateLimitHeader] = &[
+    ("X-MBX-USED-WEIGHT-", RateLimitKind::RequestWeight),
+    ("X-MBX-ORDER-COUNT-", RateLimitKind::OrdersCount),
+];
+
 #[derive(Default)]
 pub struct ErrorHandlerBinance;
 
@@ -130,6 +156,8 @@ impl ErrorHandler for ErrorHandlerBinance {
             | "Filter failure: PERCENT_PRICE"
             | "Quantity less than zero."
             | "Precision is over the maximum defined for this asset." => InvalidOrder,
+            // -4116 futures error code
+            "Duplicate order sent." | "ClientOrderId is duplicated." => DuplicateClientOrderId,
             msg if msg.contains("Too many requests;") => RateLimit,
             _ => Unknown,
         }
@@ -138,6 +166,73 @@ impl ErrorHandler for ErrorHandlerBinance {
 
 const EMPTY_RESPONSE_IS_OK: bool = false;
 
+/// Max `recvWindow` accepted by Binance
+const MAX_RECV_WINDOW_MS: u64 = 60_000;
+/// `recvWindow` above this value makes requests vulnerable to replay for a long time
+const HIGH_RECV_WINDOW_MS: u64 = 10_000;
+
+/// Binance closes user data stream if its listen key isn't extended within this period
+const LISTEN_KEY_LIFETIME: Duration = Duration::from_secs(60 * 60);
+/// Binance recommends to extend spot listen key about every 30 minutes
+const SPOT_LISTEN_KEY_PING_INTERVAL: Duration = Duration::from_secs(30 * 60);
+/// Binance recommends to extend futures listen key about every 60 minutes, which is its whole
+/// lifetime, so it's extended 10 minutes earlier to tolerate delayed requests
+const FUTURES_LISTEN_KEY_PING_INTERVAL: Duration = Duration::from_secs(50 * 60);
+
+/// Max count of records Binance returns for single funding history request
+const FUNDING_HISTORY_PAGE_LIMIT: usize = 1000;
+/// Max difference between time of funding fee and funding time of rate it was charged by
+const FUNDING_TIME_TOLERANCE_MS: i64 = 60_000;
+
+/// Max count of orders Binance returns for single order history request
+const ORDER_HISTORY_PAGE_LIMIT: usize = 1000;
+
+/// Depth of order book snapshot requested by REST. Binance allows up to 1000 levels for both
+/// spot and futures markets, so it covers depth of websocket streams
+const ORDER_BOOK_SNAPSHOT_LIMIT: usize = 1000;
+
+fn get_recv_window_ms(id: ExchangeAccountId, recv_window_ms: Option<u64>) -> Option<u64> {
+    let recv_window_ms = recv_window_ms?;
+    if recv_window_ms > MAX_RECV_WINDOW_MS {
+        log::warn!("recv_window_ms {recv_window_ms} for {id} is greater than max allowed {MAX_RECV_WINDOW_MS}, so max value is used");
+        return Some(MAX_RECV_WINDOW_MS);
+    }
+
+    if recv_window_ms > HIGH_RECV_WINDOW_MS {
+        log::warn!("recv_window_ms {recv_window_ms} for {id} is high, consider to sync time with exchange instead");
+    }
+
+    Some(recv_window_ms)
+}
+
+fn get_listen_key_ping_interval(
+    id: ExchangeAccountId,
+    account_type: AccountType,
+    interval_secs: Option<u64>,
+) -> Duration {
+    let default_interval = match account_type.is_derivative() {
+        true => FUTURES_LISTEN_KEY_PING_INTERVAL,
+        false => SPOT_LISTEN_KEY_PING_INTERVAL,
+    };
+
+    let interval = match interval_secs {
+        None => return default_interval,
+        Some(interval_secs) => Duration::from_secs(interval_secs),
+    };
+
+    if interval >= LISTEN_KEY_LIFETIME {
+        log::warn!(
+            "listen_key_ping_interval_secs {} for {id} isn't less than listen key lifetime {} secs, so default {} secs is used",
+            interval.as_secs(),
+            LISTEN_KEY_LIFETIME.as_secs(),
+            default_interval.as_secs()
+        );
+        return default_interval;
+    }
+
+    interval
+}
+
 pub struct Binance {
     pub settings: ExchangeSettings,
     pub hosts: Hosts,
@@ -147,6 +242,7 @@ pub struct Binance {
     pub handle_order_filled_callback: HandleOrderFilledCb,
     pub handle_trade_callback: HandleTradeCb,
     pub(super) handle_metrics_callback: HandleMetricsCb,
+    pub(super) websocket_message_callback: Arc<SendWebsocketMessageCb>,
 
     pub unified_to_specific: RwLock<HashMap<CurrencyPair, SpecificCurrencyPair>>,
     pub specific_to_unified: RwLock<HashMap<SpecificCurrencyPair, CurrencyPair>>,
@@ -158,6 +254,8 @@ pub struct Binance {
 
     // Currencies used for trading according to user settings
     pub(super) traded_specific_currencies: Mutex<Vec<SpecificCurrencyPair>>,
+    /// Kinds of market data streams required by strategies per currency pair
+    pub(super) required_streams: Mutex<HashMap<CurrencyPair, HashSet<StreamKind>>>,
 
     pub(super) last_trade_ids: DashMap<CurrencyPair, TradeId>,
 
@@ -172,6 +270,15 @@ pub struct Binance {
 
     // NOTE: None when websocket is disconnected
     pub(super) listen_key: RwLock<Option<String>>,
+    // Time when listen key was received or extended last time, None when websocket is disconnected
+    pub(super) listen_key_extended_at: Mutex<Option<DateTime>>,
+    pub(super) listen_key_ping_interval: Duration,
+    pub(super) pending_subscriptions: Mutex<PendingSubscriptions>,
+
+    pub(super) nonce_generator: NonceGenerator,
+    pub(super) recv_window_ms: Option<u64>,
+    // REST host selected by latency probing, `hosts.rest_host` by default
+    pub(super) rest_host: RwLock<&'static str>,
 }
 
 impl Binance {
@@ -210,34 +317,70 @@ impl Binance {
             .is_reducing_market_data
             .unwrap_or(is_reducing_market_data);
 
-        let hosts = Self::make_hosts(settings.is_margin_trading);
+        let hosts = Self::make_hosts(settings.account_type);
+        let rest_host = RwLock::new(hosts.rest_host);
         let exchange_account_id = settings.exchange_account_id;
+        let recv_window_ms = get_recv_window_ms(id, settings.recv_window_ms);
+        let listen_key_ping_interval = get_listen_key_ping_interval(
+            id,
+            settings.account_type,
+            settings.listen_key_ping_interval_secs,
+        );
+
+        let mut rest_client = RestClient::new(
+            ErrorHandlerData::new(
+                EMPTY_RESPONSE_IS_OK,
+                exchange_account_id,
+                ErrorHandlerBinance::default(),
+            ),
+            RestHeadersBinance {
+                api_key: settings.api_key.clone(),
+                is_usd_m_futures: settings.account_type.is_derivative(),
+            },
+        )
+        .with_rate_limit_usage(
+            RATE_LIMIT_HEADERS,
+            Self::rate_limit_usage_callback(
+                id,
+                timeout_manager.clone(),
+                events_channel.clone(),
+                lifetime_manager.clone(),
+            ),
+        )
+        .with_latency_observer(Self::rest_latency_callback(
+            id,
+            timeout_manager.clone(),
+            events_channel.clone(),
+            lifetime_manager.clone(),
+        ));
+        if let Some(rest_failures_reconnect) = &settings.rest_failures_reconnect {
+            rest_client = rest_client.with_failure_monitor(
+                RestFailureMonitor::new(rest_failures_reconnect),
+                Self::rest_unreachable_callback(
+                    id,
+                    events_channel.clone(),
+                    lifetime_manager.clone(),
+                ),
+            );
+        }
 
         Self {
             id,
-            order_created_callback: Box::new(|_, _, _| {}),
-            order_cancelled_callback: Box::new(|_, _, _| {}),
+            order_created_callback: Box::new(|_, _, _, _| {}),
+            order_cancelled_callback: Box::new(|_, _, _, _| {}),
             handle_order_filled_callback: Box::new(|_| {}),
             handle_trade_callback: Box::new(|_, _| {}),
             handle_metrics_callback: Box::new(|_| {}),
+            websocket_message_callback: Arc::new(Box::new(|_, _| Ok(()))),
             unified_to_specific: Default::default(),
             specific_to_unified: Default::default(),
             supported_currencies: Default::default(),
             working_currencies_ids: Default::default(),
             traded_specific_currencies: Default::default(),
+            required_streams: Default::default(),
             last_trade_ids: Default::default(),
             subscribe_to_market_data: settings.subscribe_to_market_data,
-            rest_client: RestClient::new(
-                ErrorHandlerData::new(
-                    EMPTY_RESPONSE_IS_OK,
-                    exchange_account_id,
-                    ErrorHandlerBinance::default(),
-                ),
-                RestHeadersBinance {
-                    api_key: settings.api_key.clone(),
-                    is_usd_m_futures: settings.is_margin_trading,
-                },
-            ),
+            rest_client,
             timeout_manager,
             is_reducing_market_data,
             settings,
@@ -245,22 +388,114 @@ impl Binance {
             events_channel,
             lifetime_manager,
             listen_key: Default::default(),
+            listen_key_extended_at: Default::default(),
+            listen_key_ping_interval,
+            pending_subscriptions: Default::default(),
+            nonce_generator: NonceGenerator::default(),
+            recv_window_ms,
+            rest_host,
+        }
+    }
+
+    /// Keep local requests budget in sync with request weight accounted by Binance
+    fn rate_limit_usage_callback(
+        id: ExchangeAccountId,
+        timeout_manager: Arc<TimeoutManager>,
+        events_channel: broadcast::Sender<ExchangeEvent>,
+        lifetime_manager: Arc<AppLifetimeManager>,
+    ) -> RateLimitUsageCb {
+        Box::new(move |usage| {
+            timeout_manager.sync_rate_limit_usage(id, &usage);
+
+            let event = ExchangeEvent::RateLimitUsage(RateLimitUsageEvent {
+                exchange_account_id: id,
+                usage,
+            });
+            let _ = send_event(&events_channel, lifetime_manager.clone(), id, event);
+        })
+    }
+
+    /// Adapt requests pacing to Binance REST latency
+    fn rest_latency_callback(
+        id: ExchangeAccountId,
+        timeout_manager: Arc<TimeoutManager>,
+        events_channel: broadcast::Sender<ExchangeEvent>,
+        lifetime_manager: Arc<AppLifetimeManager>,
+    ) -> RestLatencyCb {
+        Box::new(move |latency| {
+            if let Some(multiplier) = timeout_manager.observe_request_latency(id, latency) {
+                let event = ExchangeEvent::PacingChanged(PacingChangedEvent {
+                    exchange_account_id: id,
+                    multiplier,
+                });
+                let _ = send_event(&events_channel, lifetime_manager.clone(), id, event);
+            }
+        })
+    }
+
+    fn rest_unreachable_callback(
+        id: ExchangeAccountId,
+        events_channel: broadcast::Sender<ExchangeEvent>,
+        lifetime_manager: Arc<AppLifetimeManager>,
+    ) -> RestUnreachableCb {
+        Box::new(move |failures| {
+            let event = ExchangeEvent::RestUnreachable(RestUnreachableEvent {
+                exchange_account_id: id,
+                failures,
+                event_creation_time: Utc::now(),
+            });
+            let _ = send_event(&events_channel, lifetime_manager.clone(), id, event);
+        })
+    }
+
+    /// All REST hosts of Binance API with the same functionality
+    pub(super) fn rest_hosts(account_type: AccountType) -> &'static [&'static str] {
+        match account_type {
+            AccountType::Futures => &["https://fapi.binance.com"],
+            AccountType::Spot | AccountType::Margin => &[
+                "https://api.binance.com",
+                "https://api1.binance.com",
+                "https://api2.binance.com",
+                "https://api3.binance.com",
+                "https://api4.binance.com",
+            ],
         }
     }
 
-    pub fn make_hosts(is_margin_trading: bool) -> Hosts {
-        if is_margin_trading {
-            Hosts {
+    pub(super) fn rest_uri_host(&self) -> &'static str {
+        let rest_host = *self.rest_host.read();
+        rest_host
+            .strip_prefix("https://")
+            .with_expect(|| format!("Rest host {rest_host} should start with https://"))
+    }
+
+    #[named]
+    pub(super) async fn request_ping(
+        &self,
+        rest_host: &'static str,
+    ) -> Result<RestResponse, ExchangeError> {
+        let path = self.get_uri_path("/fapi/v1/ping", "/api/v3/ping");
+        let builder = UriBuilder::from_path(path);
+        let uri_host = rest_host.strip_prefix("https://").unwrap_or(rest_host);
+        let uri = builder.build_uri(uri_host, false);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    pub fn make_hosts(account_type: AccountType) -> Hosts {
+        match account_type {
+            AccountType::Futures => Hosts {
                 web_socket_host: "wss://fstream.binance.com",
                 web_socket2_host: "wss://fstream.binance.com",
                 rest_host: "https://fapi.binance.com",
-            }
-        } else {
-            Hosts {
+            },
+            AccountType::Spot | AccountType::Margin => Hosts {
                 web_socket_host: "wss://stream.binance.com:9443",
                 web_socket2_host: "wss://stream.binance.com:9443",
                 rest_host: "https://api.binance.com",
-            }
+            },
         }
     }
 
@@ -268,7 +503,7 @@ impl Binance {
     pub(super) async fn request_listen_key(&self) -> Result<RestResponse, ExchangeError> {
         let path = self.get_uri_path("/fapi/v1/listenKey", "/api/v3/userDataStream");
         let builder = UriBuilder::from_path(path);
-        let (uri, query) = builder.build_uri_and_query(self.hosts.rest_uri_host(), false);
+        let (uri, query) = builder.build_uri_and_query(self.rest_uri_host(), false);
 
         self.rest_client
             .post(uri, Some(query), function_name!(), "".to_string())
@@ -292,7 +527,7 @@ impl Binance {
         let path = self.get_uri_path("/fapi/v1/listenKey", "/api/v3/userDataStream");
         let mut builder = UriBuilder::from_path(path);
         builder.add_kv(LISTEN_KEY, listen_key);
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .put(uri, function_name!(), "".to_string())
@@ -309,9 +544,41 @@ impl Binance {
         specific_currency_pair: &SpecificCurrencyPair,
         channel: &str,
     ) -> String {
+        let specific_currency_pair = specific_currency_pair.to_string().to_lowercase();
         format!("{specific_currency_pair}@{channel}")
     }
 
+    /// Binance channel of market data stream. User data isn't subscribed by channel
+    /// because it's received from secondary websocket by listen key
+    pub(super) fn get_channel_name(subscription: &Subscription) -> Option<String> {
+        let channel = match subscription {
+            Subscription::OrderBook {
+                depth,
+                update_speed_ms,
+            } => {
+                let depth = depth.map(|x| x.to_string()).unwrap_or_default();
+                match update_speed_ms {
+                    None => format!("depth{depth}"),
+                    Some(update_speed_ms) => format!("depth{depth}@{update_speed_ms}ms"),
+                }
+            }
+            Subscription::Trades => "trade".to_owned(),
+            Subscription::AggTrades => "aggTrade".to_owned(),
+            Subscription::BookTicker => "bookTicker".to_owned(),
+            Subscription::Klines { interval } => format!("kline_{interval}"),
+            // futures only, updated every 3s by default or every 1s
+            Subscription::MarkPrice { update_speed_ms } => match update_speed_ms {
+                Some(update_speed_ms) if *update_speed_ms < 3000 => "markPrice@1s".to_owned(),
+                _ => "markPrice".to_owned(),
+            },
+            // futures only
+            Subscription::Liquidations => "forceOrder".to_owned(),
+            Subscription::UserData => return None,
+        };
+
+        Some(channel)
+    }
+
     fn _is_websocket_reconnecting(&self) -> bool {
         todo!("is_websocket_reconnecting")
     }
@@ -340,7 +607,11 @@ impl Binance {
     }
 
     pub(super) fn add_authentification(&self, builder: &mut UriBuilder) {
-        let time_stamp = get_current_milliseconds();
+        if let Some(recv_window_ms) = self.recv_window_ms {
+            builder.add_kv("recvWindow", recv_window_ms);
+        }
+
+        let time_stamp = self.nonce_generator.next();
         builder.add_kv("timestamp", time_stamp);
 
         self.write_signature_to_builder(builder);
@@ -358,7 +629,7 @@ impl Binance {
     }
 
     pub(super) fn specific_order_info_to_unified(&self, specific: &BinanceOrderInfo) -> OrderInfo {
-        OrderInfo::new(
+        let mut order_info = OrderInfo::new(
             self.get_unified_currency_pair(&specific.specific_currency_pair)
                 .expect("expected known currency pair"),
             specific.exchange_order_id.to_string().as_str().into(),
@@ -372,7 +643,17 @@ impl Binance {
             None,
             None,
             None,
-        )
+        );
+        // Binance doesn't support order tags, so tag is encoded to client order id
+        order_info.tag =
+            decode_tag_from_client_order_id(&order_info.client_order_id).map(str::to_owned);
+        // goodTillDate is 0 for orders which aren't good till date
+        order_info.expire_time = specific
+            .good_till_date
+            .filter(|x| *x > 0)
+            .map(u64_to_date_time);
+
+        order_info
     }
 
     pub(super) fn handle_order_fill(
@@ -397,7 +678,7 @@ impl Binance {
 
         let client_order_id = {
             let field_name = match execution_type {
-                "CANCELED" | "EXPIRED" if !self.settings.is_margin_trading => "C",
+                "CANCELED" | "EXPIRED" if !self.settings.account_type.is_derivative() => "C",
                 _ => "c",
             };
             json_response[field_name]
@@ -412,6 +693,7 @@ impl Binance {
                         client_order_id.into(),
                         exchange_order_id.into(),
                         EventSourceType::WebSocket,
+                        Some(event_time),
                     );
                 }
                 _ => log::error!("execution_type is NEW but order_status is {order_status} for message {msg_to_log}"),
@@ -422,6 +704,7 @@ impl Binance {
                         client_order_id.into(),
                         exchange_order_id.into(),
                         EventSourceType::WebSocket,
+                        Some(event_time),
                     );
                 }
                 _ => log::error!("execution_type is CANCELED but order_status is {order_status} for message {msg_to_log}"),
@@ -431,11 +714,14 @@ impl Binance {
                 // We get notification of rejected orders from the rest responses
             }
             "EXPIRED" => match time_in_force {
-                "GTX" => {
+                // maker only order which would be filled immediately or good till date order
+                // which reached expire time
+                "GTX" | "GTD" => {
                     (self.order_cancelled_callback)(
                         client_order_id.into(),
                         exchange_order_id.into(),
                         EventSourceType::WebSocket,
+                        Some(event_time),
                     );
                 }
                 _ => log::error!("Order {client_order_id} was expired, message: {msg_to_log}"),
@@ -501,9 +787,10 @@ impl Binance {
             OrderRole::Taker
         };
 
+        let tolerance = self.decimal_parsing_tolerance();
         let fill_amount = FillAmount::Incremental {
-            fill_amount: last_filled_amount.parse()?,
-            total_filled_amount: Some(total_filled_amount.parse()?),
+            fill_amount: Amount::new(parse_decimal(last_filled_amount, tolerance)?),
+            total_filled_amount: Some(Amount::new(parse_decimal(total_filled_amount, tolerance)?)),
         };
 
         let fill_event = FillEvent {
@@ -511,12 +798,12 @@ impl Binance {
             trade_id: Some(trade_id),
             client_order_id: Some(client_order_id),
             exchange_order_id,
-            fill_price: last_filled_price.parse()?,
+            fill_price: Price::new(parse_decimal(last_filled_price, tolerance)?),
             fill_amount,
             order_role: Some(order_role),
             commission_currency_code: Some(commission_currency_code),
             commission_rate: None,
-            commission_amount: Some(commission_amount.parse()?),
+            commission_amount: Some(Amount::new(parse_decimal(commission_amount, tolerance)?)),
             fill_type,
             special_order_data: None,
             fill_date: Some(event_time),
@@ -551,12 +838,29 @@ impl Binance {
         Ok(ExchangeOrderId::new(order_id_str))
     }
 
+    /// Time of order creation or cancellation on exchange from response on order request
+    pub(super) fn get_transaction_time(response: &RestResponse) -> Option<DateTime> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct TransactionTime {
+            // spot responds with transactTime and futures with updateTime
+            transact_time: Option<u64>,
+            update_time: Option<u64>,
+        }
+
+        let deserialized: TransactionTime = serde_json::from_str(&response.content).ok()?;
+        deserialized
+            .transact_time
+            .or(deserialized.update_time)
+            .map(u64_to_date_time)
+    }
+
     pub(super) fn get_uri_path<'a>(
         &self,
         margin_trading_url: &'a str,
         not_margin_trading_url: &'a str,
     ) -> &'a str {
-        match self.settings.is_margin_trading {
+        match self.settings.account_type.is_derivative() {
             true => margin_trading_url,
             false => not_margin_trading_url,
         }
@@ -567,7 +871,7 @@ impl Binance {
         &self,
         builder: UriBuilder,
     ) -> Result<RestResponse, ExchangeError> {
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
@@ -587,7 +891,7 @@ impl Binance {
         builder.add_kv("symbol", specific_currency_pair);
         builder.add_kv("origClientOrderId", &client_order_id);
         self.add_authentification(&mut builder);
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         let log_args = format!("order {client_order_id}");
 
@@ -661,7 +965,7 @@ impl Binance {
 
         self.add_authentification(&mut builder);
 
-        let (uri, query) = builder.build_uri_and_query(self.hosts.rest_uri_host(), false);
+        let (uri, query) = builder.build_uri_and_query(self.rest_uri_host(), false);
 
         let log_args = format!("Close position response for {position:?} {price:?}");
         self.rest_client
@@ -674,13 +978,370 @@ impl Binance {
         let mut builder = UriBuilder::from_path("/fapi/v2/positionRisk");
         self.add_authentification(&mut builder);
 
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_set_margin_mode(
+        &self,
+        currency_pair: CurrencyPair,
+        margin_mode: MarginMode,
+    ) -> Result<RestResponse, ExchangeError> {
+        let mut builder = UriBuilder::from_path("/fapi/v1/marginType");
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        let margin_type = match margin_mode {
+            MarginMode::Cross => "CROSSED",
+            MarginMode::Isolated => "ISOLATED",
+        };
+        builder.add_kv("marginType", margin_type);
+        self.add_authentification(&mut builder);
+
+        let (uri, query) = builder.build_uri_and_query(self.rest_uri_host(), false);
+
+        let log_args = format!("Set margin mode {margin_mode} for {currency_pair}");
+        self.rest_client
+            .post(uri, Some(query), function_name!(), log_args)
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_leverage_brackets(
+        &self,
+        currency_pair: CurrencyPair,
+    ) -> Result<RestResponse, ExchangeError> {
+        let mut builder = UriBuilder::from_path("/fapi/v1/leverageBracket");
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        self.add_authentification(&mut builder);
+
+        let uri = builder.build_uri(self.rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    /// Binance responds with list of symbols brackets, but newer API versions respond
+    /// with single object if symbol is specified, so both forms are accepted
+    pub(super) fn parse_leverage_brackets(response: &RestResponse) -> Result<Vec<LeverageBracket>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceLeverageBracket {
+            #[serde(deserialize_with = "deserialize_decimal")]
+            initial_leverage: Decimal,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            notional_cap: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            maint_margin_ratio: Decimal,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            cum: Amount,
+        }
+
+        #[derive(Deserialize)]
+        struct BinanceSymbolBrackets {
+            brackets: Vec<BinanceLeverageBracket>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum BinanceLeverageBracketsResponse {
+            List(Vec<BinanceSymbolBrackets>),
+            Single(BinanceSymbolBrackets),
+        }
+
+        let symbol_brackets = match serde_json::from_str(&response.content)
+            .context("Unable to parse leverage brackets")?
+        {
+            BinanceLeverageBracketsResponse::Single(symbol_brackets) => symbol_brackets,
+            BinanceLeverageBracketsResponse::List(list) => list
+                .into_iter()
+                .next()
+                .context("Leverage brackets response is empty")?,
+        };
+
+        Ok(symbol_brackets
+            .brackets
+            .into_iter()
+            .map(|bracket| LeverageBracket {
+                notional_cap: bracket.notional_cap,
+                max_leverage: bracket.initial_leverage,
+                maintenance_margin_rate: bracket.maint_margin_ratio,
+                maintenance_amount: bracket.cum,
+            })
+            .sorted_by_key(|bracket| bracket.notional_cap)
+            .collect())
+    }
+
+    #[named]
+    pub(super) async fn request_set_leverage(
+        &self,
+        currency_pair: CurrencyPair,
+        leverage: Decimal,
+    ) -> Result<RestResponse, ExchangeError> {
+        let mut builder = UriBuilder::from_path("/fapi/v1/leverage");
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        // Binance accepts integer leverage only
+        builder.add_kv("leverage", leverage.trunc());
+        self.add_authentification(&mut builder);
+
+        let (uri, query) = builder.build_uri_and_query(self.rest_uri_host(), false);
+
+        let log_args = format!("Set leverage {leverage} for {currency_pair}");
+        self.rest_client
+            .post(uri, Some(query), function_name!(), log_args)
+            .await
+    }
+
+    /// Funding fees of futures positions of currency pair within time range in milliseconds
+    #[named]
+    pub(super) async fn request_funding_income(
+        &self,
+        currency_pair: CurrencyPair,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<RestResponse, ExchangeError> {
+        let mut builder = UriBuilder::from_path("/fapi/v1/income");
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        builder.add_kv("incomeType", "FUNDING_FEE");
+        builder.add_kv("startTime", start_time);
+        builder.add_kv("endTime", end_time);
+        builder.add_kv("limit", FUNDING_HISTORY_PAGE_LIMIT);
+        self.add_authentification(&mut builder);
+
+        let uri = builder.build_uri(self.rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    /// Funding rates of currency pair within time range in milliseconds
+    #[named]
+    pub(super) async fn request_funding_rates(
+        &self,
+        currency_pair: CurrencyPair,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<RestResponse, ExchangeError> {
+        let mut builder = UriBuilder::from_path("/fapi/v1/fundingRate");
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        builder.add_kv("startTime", start_time);
+        builder.add_kv("endTime", end_time);
+        builder.add_kv("limit", FUNDING_HISTORY_PAGE_LIMIT);
+
+        let uri = builder.build_uri(self.rest_uri_host(), false);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    pub(super) fn parse_funding_income(response: &RestResponse) -> Result<Vec<BinanceIncome>> {
+        serde_json::from_str(&response.content).context("Unable to parse funding income")
+    }
+
+    pub(super) fn parse_funding_rates(response: &RestResponse) -> Result<Vec<BinanceFundingRate>> {
+        serde_json::from_str(&response.content).context("Unable to parse funding rates")
+    }
+
+    /// Funding fees of currency pair with rates they were charged by.
+    /// Binance returns limited count of records per request, so time range is requested by pages
+    pub(super) async fn load_funding_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+    ) -> Result<Vec<FundingPayment>> {
+        let (start_time, end_time) = (from.timestamp_millis(), to.timestamp_millis());
+
+        let incomes = request_pages(
+            start_time,
+            end_time,
+            |page_start_time| async move {
+                let response = self
+                    .request_funding_income(currency_pair, page_start_time, end_time)
+                    .await
+                    .map_err(|err| anyhow!("Get funding income request failed: {err:?}"))?;
+                Self::parse_funding_income(&response)
+            },
+            |income: &BinanceIncome| income.time,
+        )
+        .await?;
+
+        if incomes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let rates = request_pages(
+            start_time,
+            end_time,
+            |page_start_time| async move {
+                let response = self
+                    .request_funding_rates(currency_pair, page_start_time, end_time)
+                    .await
+                    .map_err(|err| anyhow!("Get funding rates request failed: {err:?}"))?;
+                Self::parse_funding_rates(&response)
+            },
+            |rate: &BinanceFundingRate| rate.funding_time,
+        )
+        .await?;
+
+        incomes
+            .into_iter()
+            .map(|income| {
+                let currency_code = self
+                    .get_currency_code(&income.asset.as_str().into())
+                    .with_context(|| format!("Unknown funding asset {}", income.asset))?;
+                Ok(FundingPayment {
+                    currency_pair,
+                    currency_code,
+                    amount: income.income,
+                    rate: find_funding_rate(&rates, income.time),
+                    time: u64_to_date_time(income.time as u64),
+                })
+            })
+            .collect()
+    }
+
+    #[named]
+    pub(super) async fn request_order_book_snapshot(
+        &self,
+        currency_pair: CurrencyPair,
+    ) -> Result<RestResponse, ExchangeError> {
+        let path = self.get_uri_path("/fapi/v1/depth", "/api/v3/depth");
+        let mut builder = UriBuilder::from_path(path);
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        builder.add_kv("limit", ORDER_BOOK_SNAPSHOT_LIMIT);
+
+        let uri = builder.build_uri(self.rest_uri_host(), false);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    pub(super) fn decimal_parsing_tolerance(&self) -> DecimalParsingTolerance {
+        self.settings.decimal_parsing_tolerance.unwrap_or_default()
+    }
+
+    pub(super) fn parse_order_book_snapshot(
+        response: &RestResponse,
+        tolerance: DecimalParsingTolerance,
+    ) -> Result<OrderBookData> {
+        let data: Value = serde_json::from_str(&response.content)
+            .context("Unable to parse order book snapshot")?;
+        let raw_asks = data["asks"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Unable to parse 'asks' in Binance"))?;
+        let raw_bids = data["bids"]
+            .as_array()
+            .ok_or_else(|| anyhow!("Unable to parse 'bids' in Binance"))?;
+
+        Ok(OrderBookData::new(
+            get_order_book_side(raw_asks, tolerance)?,
+            get_order_book_side(raw_bids, tolerance)?,
+        ))
+    }
+
+    #[named]
+    pub(super) async fn request_agg_trades(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+        limit: Option<u32>,
+    ) -> Result<RestResponse, ExchangeError> {
+        let path = self.get_uri_path("/fapi/v1/aggTrades", "/api/v3/aggTrades");
+        let mut builder = UriBuilder::from_path(path);
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        builder.add_kv("startTime", from.timestamp_millis());
+        builder.add_kv("endTime", to.timestamp_millis());
+        if let Some(limit) = limit {
+            builder.add_kv("limit", limit);
+        }
+
+        let uri = builder.build_uri(self.rest_uri_host(), false);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    pub(super) fn parse_agg_trades(response: &RestResponse) -> Result<Vec<AggTrade>> {
+        let trades: Vec<BinanceAggTrade> =
+            serde_json::from_str(&response.content).context("Unable to parse aggregated trades")?;
+
+        Ok(trades.into_iter().map(AggTrade::from).collect())
+    }
+
+    /// Orders of currency pair starting from specified order id or, if it isn't specified,
+    /// created within time range in milliseconds
+    #[named]
+    pub(super) async fn request_order_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from_order_id: Option<i64>,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<RestResponse, ExchangeError> {
+        let path = self.get_uri_path("/fapi/v1/allOrders", "/api/v3/allOrders");
+        let mut builder = UriBuilder::from_path(path);
+        builder.add_kv("symbol", self.get_specific_currency_pair(currency_pair));
+        match from_order_id {
+            Some(from_order_id) => builder.add_kv("orderId", from_order_id),
+            None => {
+                builder.add_kv("startTime", start_time);
+                builder.add_kv("endTime", end_time);
+            }
+        }
+        builder.add_kv("limit", ORDER_HISTORY_PAGE_LIMIT);
+        self.add_authentification(&mut builder);
+
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
             .await
     }
 
+    pub(super) fn parse_order_history(response: &RestResponse) -> Result<Vec<BinanceOrderInfo>> {
+        serde_json::from_str(&response.content).context("Unable to parse order history")
+    }
+
+    /// Orders of currency pair created within time range in any status.
+    /// Binance returns limited count of orders per request and pages them by order id,
+    /// so the first page is requested by time range and the next ones by order id
+    pub(super) async fn load_order_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+    ) -> Result<Vec<OrderInfo>> {
+        let (start_time, end_time) = (from.timestamp_millis(), to.timestamp_millis());
+
+        let orders = request_pages_by_id(
+            end_time,
+            |from_order_id| async move {
+                let response = self
+                    .request_order_history(currency_pair, from_order_id, start_time, end_time)
+                    .await
+                    .map_err(|err| anyhow!("Get order history request failed: {err:?}"))?;
+                Self::parse_order_history(&response)
+            },
+            |order: &BinanceOrderInfo| order.exchange_order_id,
+            |order: &BinanceOrderInfo| order.time,
+        )
+        .await?;
+
+        Ok(orders
+            .iter()
+            .map(|order| self.specific_order_info_to_unified(order))
+            .collect())
+    }
+
     pub(super) fn parse_active_positions(
         &self,
         response: &RestResponse,
@@ -716,7 +1377,8 @@ impl Binance {
                     position.average_entry_price,
                     position.liquidation_price,
                     position.leverage,
-                );
+                )
+                .with_margin_mode(position.margin_mode);
 
                 // We don't receive `timestamp` from exchange
                 Ok(ActivePosition::new(derivative_position, Utc::now()))
@@ -728,7 +1390,7 @@ impl Binance {
         let path = self.get_uri_path("/fapi/v2/account", "/api/v3/account");
         let mut builder = UriBuilder::from_path(path);
         self.add_authentification(&mut builder);
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
@@ -789,7 +1451,7 @@ impl Binance {
         builder.add_kv("orderId", exchange_order_id);
         self.add_authentification(&mut builder);
 
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         let log_args = format!("Cancel order for {}", order.client_order_id());
         self.rest_client
@@ -816,7 +1478,7 @@ impl Binance {
         builder.add_kv("symbol", specific_currency_pair);
         self.add_authentification(&mut builder);
 
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
@@ -833,9 +1495,12 @@ impl Binance {
         struct BinanceMyTrade {
             id: Value,
             order_id: u64,
+            #[serde(deserialize_with = "deserialize_decimal")]
             price: Price,
             #[serde(alias = "qty")]
+            #[serde(deserialize_with = "deserialize_decimal")]
             amount: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
             commission: Amount,
             #[serde(alias = "commissionAsset")]
             commission_currency_code: CurrencyId,
@@ -892,7 +1557,7 @@ impl Binance {
     ) -> Result<RestResponse, ExchangeError> {
         let header = order.header();
         let specific_currency_pair = self.get_specific_currency_pair(header.currency_pair);
-        let is_margin_trading = self.settings.is_margin_trading;
+        let is_derivative = self.settings.account_type.is_derivative();
 
         let path = self.get_uri_path("/fapi/v1/order", "/api/v3/order");
         let mut builder = UriBuilder::from_path(path);
@@ -901,7 +1566,7 @@ impl Binance {
         builder.add_kv("quantity", header.amount);
         builder.add_kv("newClientOrderId", &header.client_order_id);
 
-        match (is_margin_trading, &header.options) {
+        match (is_derivative, &header.options) {
             (false, OrderOptions::User(user_order)) => match user_order {
                 UserOrder::Limit {
                     price,
@@ -942,9 +1607,15 @@ impl Binance {
                 } => {
                     builder.add_kv("type", "LIMIT");
                     builder.add_kv("price", price);
-                    match *execution_type == OrderExecutionType::MakerOnly {
-                        true => builder.add_kv("timeInForce", "GTX"),
-                        false => builder.add_kv("timeInForce", "GTC"),
+                    match (execution_type, header.time_in_force) {
+                        (OrderExecutionType::MakerOnly, _) => builder.add_kv("timeInForce", "GTX"),
+                        (OrderExecutionType::None, TimeInForce::Gtc) => {
+                            builder.add_kv("timeInForce", "GTC")
+                        }
+                        (OrderExecutionType::None, TimeInForce::Gtd(expire_time)) => {
+                            builder.add_kv("timeInForce", "GTD");
+                            builder.add_kv("goodTillDate", expire_time.timestamp_millis());
+                        }
                     }
                 }
                 UserOrder::Market => builder.add_kv("type", "MARKET"),
@@ -962,7 +1633,7 @@ impl Binance {
 
         self.add_authentification(&mut builder);
 
-        let (uri, query) = builder.build_uri_and_query(self.hosts.rest_uri_host(), false);
+        let (uri, query) = builder.build_uri_and_query(self.rest_uri_host(), false);
 
         let log_args = format!("Create order for {header:?}");
         self.rest_client
@@ -974,7 +1645,7 @@ impl Binance {
     pub(super) async fn request_all_symbols(&self) -> Result<RestResponse, ExchangeError> {
         let path = self.get_uri_path("/fapi/v1/exchangeInfo", "/api/v3/exchangeInfo");
         let builder = UriBuilder::from_path(path);
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), false);
+        let uri = builder.build_uri(self.rest_uri_host(), false);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
@@ -995,6 +1666,9 @@ impl Binance {
                 continue;
             }
 
+            let status = Binance::get_symbol_status(&symbol.get_as_str("status")?);
+            let allowed_order_types = Binance::get_allowed_order_types(symbol);
+
             let base_currency_id = &symbol
                 .get_as_str("baseAsset")
                 .expect("Unable to get base currency id from Binance");
@@ -1018,7 +1692,7 @@ impl Binance {
                 .insert(specific_currency_pair, unified_currency_pair);
 
             let (amount_currency_code, balance_currency_code) =
-                match self.settings.is_margin_trading {
+                match self.settings.account_type.is_derivative() {
                     // TODO need explicit specify type of using Binance futures markets
                     // true => (quote, Some(base)),
                     true => (base, Some(quote)),
@@ -1037,25 +1711,29 @@ impl Binance {
                 .get("filters")
                 .and_then(|filters| filters.as_array())
                 .expect("Unable to get filters as array from Binance");
+            let tolerance = self.decimal_parsing_tolerance();
             for filter in filters {
                 let filter_name = filter.get_as_str("filterType")?;
+                let get_decimal = |key| filter.get_as_decimal_with(key, tolerance);
                 match filter_name.as_str() {
                     "PRICE_FILTER" => {
-                        min_price = filter.get_as_decimal("minPrice");
-                        max_price = filter.get_as_decimal("maxPrice");
-                        price_tick = filter.get_as_decimal("tickSize");
+                        min_price = get_decimal("minPrice")?;
+                        max_price = get_decimal("maxPrice")?;
+                        price_tick = get_decimal("tickSize")?;
                     }
                     "LOT_SIZE" => {
-                        min_amount = filter.get_as_decimal("minQty");
-                        max_amount = filter.get_as_decimal("maxQty");
-                        amount_tick = filter.get_as_decimal("stepSize");
+                        min_amount = get_decimal("minQty")?;
+                        max_amount = get_decimal("maxQty")?;
+                        amount_tick = get_decimal("stepSize")?;
                     }
                     "MIN_NOTIONAL" => {
-                        min_cost = match self.settings.is_margin_trading {
-                            true => filter.get_as_decimal("notional"),
-                            false => filter.get_as_decimal("minNotional"),
+                        min_cost = match self.settings.account_type.is_derivative() {
+                            true => get_decimal("notional")?,
+                            false => get_decimal("minNotional")?,
                         };
                     }
+                    // replaces MIN_NOTIONAL on spot
+                    "NOTIONAL" => min_cost = get_decimal("minNotional")?,
                     _ => {}
                 }
             }
@@ -1076,22 +1754,24 @@ impl Binance {
                 ),
             };
 
-            let symbol = Symbol::new(
-                self.settings.is_margin_trading,
+            let mut symbol = Symbol::new(
+                self.settings.account_type.is_derivative(),
                 base_currency_id.as_str().into(),
                 base,
                 quote_currency_id.as_str().into(),
                 quote,
-                min_price,
-                max_price,
-                min_amount,
-                max_amount,
-                min_cost,
+                min_price.map(Price::new),
+                max_price.map(Price::new),
+                min_amount.map(Amount::new),
+                max_amount.map(Amount::new),
+                min_cost.map(Amount::new),
                 amount_currency_code,
                 balance_currency_code,
                 price_precision,
                 amount_precision,
             );
+            symbol.status = status;
+            symbol.allowed_order_types = allowed_order_types;
 
             supported_symbols.push(Arc::new(symbol))
         }
@@ -1099,19 +1779,186 @@ impl Binance {
         Ok(supported_symbols)
     }
 
-    fn is_unsupported_symbol(symbol: &Value) -> bool {
-        let code = &symbol
-            .get_as_str("symbol")
-            .expect("Unable to get symbol code from Binance");
-
-        // Binance adds "_<NUMBERS>" to old symbol's code
-        code.contains('_') || symbol["status"] != "TRADING"
+    pub(super) fn default_timeout_arguments() -> RequestTimeoutArguments {
+        RequestTimeoutArguments::from_requests_per_minute(1200)
     }
 
-    pub(super) fn get_event_time(data: &Value) -> Result<DateTime> {
-        let event_time_u64 = data["E"]
-            .as_u64()
-            .ok_or_else(|| anyhow!("Unable to parse transaction time"))?;
+    /// Request weight limit from `rateLimits` of `exchangeInfo` response
+    pub(super) fn parse_rate_limits(
+        response: &RestResponse,
+    ) -> Result<Option<RequestTimeoutArguments>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceRateLimit {
+            rate_limit_type: String,
+            interval: String,
+            interval_num: i64,
+            limit: usize,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceExchangeInfo {
+            rate_limits: Option<Vec<BinanceRateLimit>>,
+        }
+
+        let exchange_info: BinanceExchangeInfo = serde_json::from_str(&response.content)
+            .context("Failed to parse rate limits from Binance exchangeInfo response")?;
+
+        let rate_limit = match exchange_info
+            .rate_limits
+            .unwrap_or_default()
+            .into_iter()
+            .find(|x| x.rate_limit_type == "REQUEST_WEIGHT")
+        {
+            None => return Ok(None),
+            Some(rate_limit) => rate_limit,
+        };
+
+        let period = Self::rate_limit_period(&rate_limit.interval, rate_limit.interval_num)?;
+
+        Ok(Some(RequestTimeoutArguments::new(rate_limit.limit, period)))
+    }
+
+    fn rate_limit_period(interval: &str, interval_num: i64) -> Result<chrono::Duration> {
+        Ok(match interval {
+            "SECOND" => chrono::Duration::seconds(interval_num),
+            "MINUTE" => chrono::Duration::minutes(interval_num),
+            "HOUR" => chrono::Duration::hours(interval_num),
+            "DAY" => chrono::Duration::days(interval_num),
+            interval => bail!("Unknown Binance rate limit interval {interval}"),
+        })
+    }
+
+    #[named]
+    pub(super) async fn request_order_rate_limit_status(
+        &self,
+    ) -> Result<RestResponse, ExchangeError> {
+        let path = self.get_uri_path("/fapi/v1/rateLimit/order", "/api/v3/rateLimit/order");
+        let mut builder = UriBuilder::from_path(path);
+        self.add_authentification(&mut builder);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    /// Futures API reports only limits, so order counts of them are taken
+    /// from `X-MBX-ORDER-COUNT-*` response headers
+    pub(super) fn parse_order_rate_limit_status(
+        response: &RestResponse,
+    ) -> Result<OrderRateLimitStatus> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceOrderRateLimit {
+            rate_limit_type: String,
+            interval: String,
+            interval_num: i64,
+            limit: usize,
+            count: Option<usize>,
+        }
+
+        let rate_limits: Vec<BinanceOrderRateLimit> = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance order rate limit response")?;
+
+        let limits = rate_limits
+            .into_iter()
+            .filter(|x| x.rate_limit_type == "ORDERS")
+            .map(|x| {
+                Ok(OrderRateLimit {
+                    interval: Self::rate_limit_period(&x.interval, x.interval_num)?.to_std()?,
+                    used: x.count,
+                    limit: x.limit,
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(OrderRateLimitStatus { limits })
+    }
+
+    /// Configure requests budget by limits received from exchange or fall back to built-in ones
+    pub(super) fn setup_rate_limits(&self, response: &RestResponse) {
+        let timeout_arguments = match Self::parse_rate_limits(response) {
+            Ok(Some(timeout_arguments)) => {
+                self.timeout_manager
+                    .set_timeout_arguments(self.id, &timeout_arguments);
+                timeout_arguments
+            }
+            Ok(None) => {
+                log::warn!(
+                    "Rate limits are absent in exchangeInfo of {}, built-in limits are used",
+                    self.id
+                );
+                Self::default_timeout_arguments()
+            }
+            Err(error) => {
+                log::error!(
+                    "Unable to parse rate limits of {}, built-in limits are used: {error:?}",
+                    self.id
+                );
+                Self::default_timeout_arguments()
+            }
+        };
+
+        log::info!(
+            "Effective request limits for {}: {timeout_arguments}",
+            self.id
+        );
+    }
+
+    fn is_unsupported_symbol(symbol: &Value) -> bool {
+        let code = &symbol
+            .get_as_str("symbol")
+            .expect("Unable to get symbol code from Binance");
+
+        // Binance adds "_<NUMBERS>" to old symbol's code
+        code.contains('_')
+            || symbol.get_as_str("status").map_or(true, |status| {
+                Binance::get_symbol_status(&status) == SymbolStatus::Closed
+            })
+    }
+
+    /// Order types of `orderTypes` which are supported by core. `None` if they aren't listed,
+    /// so any order type is allowed
+    pub(super) fn get_allowed_order_types(symbol: &Value) -> Option<Vec<OrderType>> {
+        let order_types = symbol.get("orderTypes")?.as_array()?;
+
+        let allowed_order_types = order_types
+            .iter()
+            .filter_map(|x| match x.as_str()? {
+                "LIMIT" | "LIMIT_MAKER" => Some(OrderType::Limit),
+                "MARKET" => Some(OrderType::Market),
+                "STOP_LOSS" | "STOP_LOSS_LIMIT" | "STOP" | "STOP_MARKET" => {
+                    Some(OrderType::StopLoss)
+                }
+                "TRAILING_STOP_MARKET" => Some(OrderType::TrailingStop),
+                // TAKE_PROFIT, TAKE_PROFIT_LIMIT, TAKE_PROFIT_MARKET aren't supported by core
+                _ => None,
+            })
+            .unique()
+            .collect();
+
+        Some(allowed_order_types)
+    }
+
+    /// Symbols which aren't trading are kept, so orders for them are rejected locally
+    /// until trading is resumed
+    pub(super) fn get_symbol_status(status: &str) -> SymbolStatus {
+        match status {
+            "TRADING" => SymbolStatus::Trading,
+            "HALT" => SymbolStatus::Halt,
+            "DELIVERED" | "CLOSE" => SymbolStatus::Closed,
+            // BREAK, PRE_TRADING, POST_TRADING, END_OF_DAY, AUCTION_MATCH, PENDING_TRADING,
+            // PRE_DELIVERING, DELIVERING, PRE_SETTLE, SETTLING
+            _ => SymbolStatus::Break,
+        }
+    }
+
+    pub(super) fn get_event_time(data: &Value) -> Result<DateTime> {
+        let event_time_u64 = data["E"]
+            .as_u64()
+            .ok_or_else(|| anyhow!("Unable to parse transaction time"))?;
 
         Ok(u64_to_date_time(event_time_u64))
     }
@@ -1120,7 +1967,7 @@ impl Binance {
     pub(super) async fn request_get_server_time(&self) -> Result<RestResponse, ExchangeError> {
         let path = self.get_uri_path("/fapi/v1/time", "/api/v3/time");
         let builder = UriBuilder::from_path(path);
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), false);
+        let uri = builder.build_uri(self.rest_uri_host(), false);
 
         self.rest_client
             .get(uri, function_name!(), "".to_string())
@@ -1138,6 +1985,246 @@ impl Binance {
             .context("Failed to parse Binance get time response")?;
         Ok(server_time_struct.time)
     }
+
+    #[named]
+    pub(super) async fn request_system_status(&self) -> Result<RestResponse, ExchangeError> {
+        // system status is provided by spot API only
+        let builder = UriBuilder::from_path("/sapi/v1/system/status");
+        let uri = builder.build_uri(Self::make_hosts(AccountType::Spot).rest_uri_host(), false);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_api_permissions(&self) -> Result<RestResponse, ExchangeError> {
+        // API restrictions are provided by spot API only
+        let mut builder = UriBuilder::from_path("/sapi/v1/account/apiRestrictions");
+        self.add_authentification(&mut builder);
+        let uri = builder.build_uri(Self::make_hosts(AccountType::Spot).rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_trading_fees(&self) -> Result<RestResponse, ExchangeError> {
+        // trading fees per symbol are provided by spot API only
+        let mut builder = UriBuilder::from_path("/sapi/v1/asset/tradeFee");
+        self.add_authentification(&mut builder);
+        let uri = builder.build_uri(Self::make_hosts(AccountType::Spot).rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_asset_networks(&self) -> Result<RestResponse, ExchangeError> {
+        // networks of assets are provided by spot API only
+        let mut builder = UriBuilder::from_path("/sapi/v1/capital/config/getall");
+        self.add_authentification(&mut builder);
+        let uri = builder.build_uri(Self::make_hosts(AccountType::Spot).rest_uri_host(), true);
+
+        self.rest_client
+            .get(uri, function_name!(), "".to_string())
+            .await
+    }
+
+    #[named]
+    pub(super) async fn request_convert_dust(
+        &self,
+        assets: &[CurrencyCode],
+    ) -> Result<RestResponse, ExchangeError> {
+        // dust is converted into BNB by spot API only
+        let mut builder = UriBuilder::from_path("/sapi/v1/asset/dust");
+        for asset in assets {
+            builder.add_kv("asset", asset.as_str().to_uppercase());
+        }
+        self.add_authentification(&mut builder);
+
+        let (uri, query) =
+            builder.build_uri_and_query(Self::make_hosts(AccountType::Spot).rest_uri_host(), false);
+
+        let log_args = format!("Convert dust of {assets:?}");
+        self.rest_client
+            .post(uri, Some(query), function_name!(), log_args)
+            .await
+    }
+
+    pub(super) fn parse_dust_conversion(response: &RestResponse) -> Result<DustConversion> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceDustTransfer {
+            from_asset: String,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            amount: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            transfered_amount: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            service_charge_amount: Amount,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceDustConversion {
+            #[serde(deserialize_with = "deserialize_decimal")]
+            total_service_charge: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            total_transfered: Amount,
+            transfer_result: Vec<BinanceDustTransfer>,
+        }
+
+        let conversion: BinanceDustConversion = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance dust conversion response")?;
+
+        Ok(DustConversion {
+            converted: conversion
+                .transfer_result
+                .into_iter()
+                .map(|x| ConvertedDust {
+                    asset: CurrencyCode::new(&x.from_asset),
+                    amount: x.amount,
+                    converted_amount: x.transfered_amount,
+                    fee: x.service_charge_amount,
+                })
+                .collect(),
+            total_converted_amount: conversion.total_transfered,
+            total_fee: conversion.total_service_charge,
+        })
+    }
+
+    pub(super) fn parse_asset_networks(response: &RestResponse) -> Result<AssetNetworks> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceNetwork {
+            network: String,
+            is_default: bool,
+            deposit_enable: bool,
+            withdraw_enable: bool,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            withdraw_fee: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            withdraw_min: Amount,
+            #[serde(deserialize_with = "deserialize_decimal")]
+            withdraw_max: Amount,
+            min_confirm: u32,
+            un_lock_confirm: u32,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceCoinConfig {
+            coin: String,
+            network_list: Vec<BinanceNetwork>,
+        }
+
+        let coins: Vec<BinanceCoinConfig> = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance asset networks response")?;
+
+        Ok(coins
+            .into_iter()
+            .map(|coin| {
+                let networks = coin
+                    .network_list
+                    .into_iter()
+                    .map(|x| NetworkInfo {
+                        network: x.network,
+                        is_default: x.is_default,
+                        deposit_enabled: x.deposit_enable,
+                        withdraw_enabled: x.withdraw_enable,
+                        withdraw_fee: x.withdraw_fee,
+                        withdraw_min: x.withdraw_min,
+                        withdraw_max: x.withdraw_max,
+                        min_confirmations: x.min_confirm,
+                        unlock_confirmations: x.un_lock_confirm,
+                    })
+                    .collect();
+                (CurrencyCode::new(&coin.coin), networks)
+            })
+            .collect())
+    }
+
+    /// Binance returns fees as rates, so they are converted to percents.
+    /// Fees of symbols which aren't traded are skipped
+    pub(super) fn parse_trading_fees(
+        response: &RestResponse,
+        specific_to_unified: &HashMap<SpecificCurrencyPair, CurrencyPair>,
+    ) -> Result<HashMap<CurrencyPair, CurrencyPairFees>> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceTradeFee {
+            symbol: SpecificCurrencyPair,
+            maker_commission: Percent,
+            taker_commission: Percent,
+        }
+
+        let trade_fees: Vec<BinanceTradeFee> = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance trading fees response")?;
+
+        Ok(trade_fees
+            .into_iter()
+            .filter_map(|fee| {
+                let currency_pair = specific_to_unified.get(&fee.symbol)?;
+                let fees = CurrencyPairFees::new(
+                    fee.maker_commission * Percent::ONE_HUNDRED,
+                    fee.taker_commission * Percent::ONE_HUNDRED,
+                );
+                Some((*currency_pair, fees))
+            })
+            .collect())
+    }
+
+    pub(super) fn parse_api_permissions(
+        response: &RestResponse,
+        account_type: AccountType,
+    ) -> Result<ApiPermissions> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct BinanceApiRestrictions {
+            ip_restrict: bool,
+            enable_withdrawals: bool,
+            enable_spot_and_margin_trading: bool,
+            enable_margin: bool,
+            enable_futures: bool,
+        }
+
+        let restrictions: BinanceApiRestrictions = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance API restrictions response")?;
+
+        Ok(ApiPermissions {
+            can_trade: match account_type {
+                AccountType::Spot => restrictions.enable_spot_and_margin_trading,
+                AccountType::Margin => {
+                    restrictions.enable_spot_and_margin_trading && restrictions.enable_margin
+                }
+                AccountType::Futures => restrictions.enable_futures,
+            },
+            can_withdraw: restrictions.enable_withdrawals,
+            is_ip_restricted: restrictions.ip_restrict,
+            // Binance doesn't report disabled keys explicitly, but such keys aren't allowed to trade
+            // on any account. Reading is allowed for every key, so it says nothing about that
+            is_enabled: restrictions.enable_spot_and_margin_trading || restrictions.enable_futures,
+        })
+    }
+
+    pub(super) fn parse_system_status(response: &RestResponse) -> Result<SystemStatus> {
+        #[derive(Deserialize)]
+        struct BinanceSystemStatus {
+            status: u8,
+        }
+
+        let system_status: BinanceSystemStatus = serde_json::from_str(&response.content)
+            .context("Failed to parse Binance system status response")?;
+
+        match system_status.status {
+            0 => Ok(SystemStatus::Normal),
+            1 => Ok(SystemStatus::Maintenance),
+            status => bail!("Unknown Binance system status {status}"),
+        }
+    }
 }
 
 pub(super) fn get_server_order_side(side: OrderSide) -> &'static str {
@@ -1192,6 +2279,7 @@ impl ExchangeClientBuilder for BinanceBuilder {
                 RestFillsFeatures::new(RestFillsType::None),
                 OrderFeatures {
                     supports_get_order_info_by_client_order_id: true,
+                    max_client_order_id_len: Some(36),
                     ..OrderFeatures::default()
                 },
                 OrderTradeOption::default(),
@@ -1205,12 +2293,89 @@ impl ExchangeClientBuilder for BinanceBuilder {
     }
 
     fn get_timeout_arguments(&self) -> RequestTimeoutArguments {
-        RequestTimeoutArguments::from_requests_per_minute(1200)
+        Binance::default_timeout_arguments()
     }
 
     fn get_exchange_id(&self) -> ExchangeId {
         "Binance".into()
     }
+
+    fn get_supported_account_types(&self) -> &'static [AccountType] {
+        &[AccountType::Spot, AccountType::Futures]
+    }
+}
+
+/// Request records within time range page by page. Next page starts right after
+/// the last record of previous one until page isn't full or range is exhausted
+async fn request_pages<T, F, Fut>(
+    start_time: i64,
+    end_time: i64,
+    request_page: F,
+    record_time: impl Fn(&T) -> i64,
+) -> Result<Vec<T>>
+where
+    F: Fn(i64) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let mut records = Vec::new();
+    let mut page_start_time = start_time;
+    while page_start_time <= end_time {
+        let page = request_page(page_start_time).await?;
+        let is_last_page = page.len() < FUNDING_HISTORY_PAGE_LIMIT;
+        match page.last() {
+            Some(last_record) => page_start_time = record_time(last_record) + 1,
+            None => break,
+        }
+
+        records.extend(page);
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(records)
+}
+
+/// Request records page by page where next page starts right after id of the last record
+/// of previous one until page isn't full or records created after `end_time` are reached
+async fn request_pages_by_id<T, F, Fut>(
+    end_time: i64,
+    request_page: F,
+    record_id: impl Fn(&T) -> i64,
+    record_time: impl Fn(&T) -> Option<i64>,
+) -> Result<Vec<T>>
+where
+    F: Fn(Option<i64>) -> Fut,
+    Fut: Future<Output = Result<Vec<T>>>,
+{
+    let is_in_range = |record: &T| record_time(record).map_or(true, |time| time <= end_time);
+
+    let mut records = Vec::new();
+    let mut from_id = None;
+    loop {
+        let page = request_page(from_id).await?;
+        let is_last_page = page.len() < ORDER_HISTORY_PAGE_LIMIT || !page.iter().all(is_in_range);
+        match page.last() {
+            Some(last_record) => from_id = Some(record_id(last_record) + 1),
+            None => break,
+        }
+
+        records.extend(page.into_iter().filter(is_in_range));
+        if is_last_page {
+            break;
+        }
+    }
+
+    Ok(records)
+}
+
+/// Income is charged at funding time, but their timestamps can differ slightly
+fn find_funding_rate(rates: &[BinanceFundingRate], income_time: i64) -> Option<Decimal> {
+    rates
+        .iter()
+        .filter(|rate| (rate.funding_time - income_time).abs() <= FUNDING_TIME_TOLERANCE_MS)
+        .min_by_key(|rate| (rate.funding_time - income_time).abs())
+        .map(|rate| rate.funding_rate)
 }
 
 #[cfg(test)]
@@ -1218,8 +2383,12 @@ mod tests {
     use super::*;
     use mmb_core::exchanges::timeouts::requests_timeout_manager_factory::RequestsTimeoutManagerFactory;
     use mmb_core::lifecycle::launcher::EngineBuildConfig;
+    use mmb_domain::order_book_data;
+    use mmb_domain::{amount, price};
     use mmb_utils::cancellation_token::CancellationToken;
     use mmb_utils::hashmap;
+    use rstest::rstest;
+    use rust_decimal_macros::dec;
 
     pub(crate) fn get_timeout_manager(
         exchange_account_id: ExchangeAccountId,
@@ -1237,6 +2406,82 @@ mod tests {
         TimeoutManager::new(hashmap![exchange_account_id => request_timeout_manager])
     }
 
+    #[test]
+    fn rest_uri_host_follows_selected_endpoint() {
+        let exchange_account_id: ExchangeAccountId = "Binance_0".parse().expect("in test");
+        let settings = ExchangeSettings::new_short(
+            exchange_account_id,
+            "".into(),
+            "".into(),
+            AccountType::Spot,
+        );
+
+        let (tx, _) = broadcast::channel(10);
+        let binance = Binance::new(
+            exchange_account_id,
+            settings,
+            tx,
+            AppLifetimeManager::new(CancellationToken::default()),
+            get_timeout_manager(exchange_account_id),
+            false,
+        );
+        assert_eq!(binance.rest_uri_host(), "api.binance.com");
+
+        *binance.rest_host.write() = Binance::rest_hosts(AccountType::Spot)[3];
+        assert_eq!(binance.rest_uri_host(), "api3.binance.com");
+    }
+
+    #[test]
+    fn recv_window_is_capped_by_exchange_max() {
+        let exchange_account_id: ExchangeAccountId = "Binance_0".parse().expect("in test");
+
+        assert_eq!(get_recv_window_ms(exchange_account_id, None), None);
+        assert_eq!(
+            get_recv_window_ms(exchange_account_id, Some(7_000)),
+            Some(7_000)
+        );
+        assert_eq!(
+            get_recv_window_ms(exchange_account_id, Some(20_000)),
+            Some(20_000)
+        );
+        assert_eq!(
+            get_recv_window_ms(exchange_account_id, Some(120_000)),
+            Some(MAX_RECV_WINDOW_MS)
+        );
+    }
+
+    #[rstest]
+    #[case(AccountType::Spot, None, SPOT_LISTEN_KEY_PING_INTERVAL)]
+    #[case(AccountType::Futures, None, FUTURES_LISTEN_KEY_PING_INTERVAL)]
+    #[case(AccountType::Futures, Some(600), Duration::from_secs(600))]
+    #[case(AccountType::Spot, Some(3600), SPOT_LISTEN_KEY_PING_INTERVAL)]
+    fn listen_key_ping_interval_is_less_than_key_lifetime(
+        #[case] account_type: AccountType,
+        #[case] interval_secs: Option<u64>,
+        #[case] expected: Duration,
+    ) {
+        let exchange_account_id: ExchangeAccountId = "Binance_0".parse().expect("in test");
+
+        assert_eq!(
+            get_listen_key_ping_interval(exchange_account_id, account_type, interval_secs),
+            expected
+        );
+    }
+
+    #[test]
+    fn clarify_duplicate_client_order_id_error() {
+        for (message, code) in [
+            ("Duplicate order sent.", -2010),
+            ("ClientOrderId is duplicated.", -4116),
+        ] {
+            let error = ExchangeError::new(ExchangeErrorType::Unknown, message.into(), Some(code));
+            assert_eq!(
+                ErrorHandlerBinance.clarify_error_type(&error),
+                ExchangeErrorType::DuplicateClientOrderId
+            );
+        }
+    }
+
     #[test]
     fn generate_signature() {
         // All values and strings gotten from binanсe API example
@@ -1246,7 +2491,7 @@ mod tests {
             exchange_account_id,
             "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A".into(),
             "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j".into(),
-            false,
+            AccountType::Spot,
         );
 
         let (tx, _) = broadcast::channel(10);
@@ -1279,4 +2524,412 @@ mod tests {
 
         assert_eq!(signature_value, expected);
     }
+
+    #[rstest]
+    #[case("TRADING", SymbolStatus::Trading)]
+    #[case("BREAK", SymbolStatus::Break)]
+    #[case("END_OF_DAY", SymbolStatus::Break)]
+    #[case("HALT", SymbolStatus::Halt)]
+    #[case("DELIVERED", SymbolStatus::Closed)]
+    fn get_symbol_status(#[case] status: &str, #[case] expected: SymbolStatus) {
+        assert_eq!(Binance::get_symbol_status(status), expected);
+    }
+
+    #[test]
+    fn get_allowed_order_types() {
+        let symbol = serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderTypes": ["LIMIT", "LIMIT_MAKER", "STOP_LOSS_LIMIT", "TAKE_PROFIT_LIMIT"]
+        });
+
+        assert_eq!(
+            Binance::get_allowed_order_types(&symbol),
+            Some(vec![OrderType::Limit, OrderType::StopLoss])
+        );
+        assert_eq!(
+            Binance::get_allowed_order_types(&serde_json::json!({ "symbol": "BTCUSDT" })),
+            None
+        );
+    }
+
+    #[test]
+    fn parse_system_status() {
+        let response = |content: &str| RestResponse {
+            status: hyper::StatusCode::OK,
+            content: content.to_owned(),
+        };
+
+        let status = Binance::parse_system_status(&response(r#"{"status":0,"msg":"normal"}"#))
+            .expect("in test");
+        assert_eq!(status, SystemStatus::Normal);
+
+        let status =
+            Binance::parse_system_status(&response(r#"{"status":1,"msg":"system maintenance"}"#))
+                .expect("in test");
+        assert_eq!(status, SystemStatus::Maintenance);
+
+        assert!(Binance::parse_system_status(&response(r#"{"status":2}"#)).is_err());
+    }
+
+    #[test]
+    fn parse_trading_fees() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"symbol":"BTCUSDT","makerCommission":"0.001","takerCommission":"0.001"},{"symbol":"USDCUSDT","makerCommission":"0","takerCommission":"0.0001"},{"symbol":"ETHBTC","makerCommission":"0.001","takerCommission":"0.001"}]"#.to_owned(),
+        };
+        let btc_usdt = CurrencyPair::from_codes("btc".into(), "usdt".into());
+        let usdc_usdt = CurrencyPair::from_codes("usdc".into(), "usdt".into());
+        let specific_to_unified =
+            HashMap::from([("BTCUSDT".into(), btc_usdt), ("USDCUSDT".into(), usdc_usdt)]);
+
+        let fees = Binance::parse_trading_fees(&response, &specific_to_unified).expect("in test");
+
+        assert_eq!(
+            fees,
+            HashMap::from([
+                (btc_usdt, CurrencyPairFees::new(dec!(0.1), dec!(0.1))),
+                (usdc_usdt, CurrencyPairFees::new(dec!(0), dec!(0.01))),
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_dust_conversion() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"{"totalServiceCharge":"0.02102542","totalTransfered":"1.05127099","transferResult":[{"amount":"0.03000000","fromAsset":"ETH","operateTime":1563368549307,"serviceChargeAmount":"0.00500000","tranId":2970932918,"transferedAmount":"0.25000000"},{"amount":"0.09000000","fromAsset":"LTC","operateTime":1563368549404,"serviceChargeAmount":"0.01602542","tranId":2970932918,"transferedAmount":"0.80127099"}]}"#.to_owned(),
+        };
+
+        let conversion = Binance::parse_dust_conversion(&response).expect("in test");
+
+        assert_eq!(conversion.total_converted_amount, amount!(1.05127099));
+        assert_eq!(conversion.total_fee, amount!(0.02102542));
+        assert_eq!(conversion.converted.len(), 2);
+        assert_eq!(
+            conversion.converted[0],
+            ConvertedDust {
+                asset: CurrencyCode::from("eth"),
+                amount: amount!(0.03),
+                converted_amount: amount!(0.25),
+                fee: amount!(0.005),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_asset_networks() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"coin":"BTC","depositAllEnable":true,"withdrawAllEnable":true,"name":"Bitcoin","free":"0","locked":"0","freeze":"0","withdrawing":"0","ipoing":"0","ipoable":"0","storage":"0","isLegalMoney":false,"trading":true,"networkList":[{"network":"BNB","coin":"BTC","withdrawIntegerMultiple":"0.00000001","isDefault":false,"depositEnable":true,"withdrawEnable":false,"depositDesc":"","withdrawDesc":"","specialTips":"","name":"BEP2","resetAddressStatus":false,"addressRegex":"^(bnb1)[0-9a-z]{38}$","memoRegex":"^[0-9A-Za-z\\-_]{1,120}$","withdrawFee":"0.0000026","withdrawMin":"0.0000052","withdrawMax":"0","minConfirm":1,"unLockConfirm":0,"sameAddress":true},{"network":"BTC","coin":"BTC","withdrawIntegerMultiple":"0.00000001","isDefault":true,"depositEnable":true,"withdrawEnable":true,"depositDesc":"","withdrawDesc":"","specialTips":"","name":"BTC","resetAddressStatus":false,"addressRegex":"^[13][a-km-zA-HJ-NP-Z1-9]{25,34}$","memoRegex":"","withdrawFee":"0.0005","withdrawMin":"0.001","withdrawMax":"9999999999.99999999","minConfirm":1,"unLockConfirm":2,"sameAddress":false}]}]"#.to_owned(),
+        };
+
+        let networks = Binance::parse_asset_networks(&response).expect("in test");
+
+        let btc_networks = &networks[&CurrencyCode::from("btc")];
+        assert_eq!(btc_networks.len(), 2);
+        assert!(!btc_networks[0].withdraw_enabled);
+        assert_eq!(
+            btc_networks[1],
+            NetworkInfo {
+                network: "BTC".to_owned(),
+                is_default: true,
+                deposit_enabled: true,
+                withdraw_enabled: true,
+                withdraw_fee: amount!(0.0005),
+                withdraw_min: amount!(0.001),
+                withdraw_max: amount!(9999999999.99999999),
+                min_confirmations: 1,
+                unlock_confirmations: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_api_permissions() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"{"ipRestrict":false,"createTime":1698645219000,"enableReading":true,"enableWithdrawals":true,"enableInternalTransfer":false,"enableMargin":false,"enableFutures":false,"permitsUniversalTransfer":false,"enableVanillaOptions":false,"enableSpotAndMarginTrading":true}"#.to_owned(),
+        };
+
+        let permissions =
+            Binance::parse_api_permissions(&response, AccountType::Spot).expect("in test");
+        assert_eq!(
+            permissions,
+            ApiPermissions {
+                can_trade: true,
+                can_withdraw: true,
+                is_ip_restricted: false,
+                is_enabled: true,
+            }
+        );
+
+        let permissions =
+            Binance::parse_api_permissions(&response, AccountType::Margin).expect("in test");
+        assert!(!permissions.can_trade);
+
+        let permissions =
+            Binance::parse_api_permissions(&response, AccountType::Futures).expect("in test");
+        assert!(!permissions.can_trade);
+        assert!(permissions.is_enabled);
+
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"{"ipRestrict":false,"createTime":1698645219000,"enableReading":true,"enableWithdrawals":false,"enableInternalTransfer":false,"enableMargin":false,"enableFutures":false,"permitsUniversalTransfer":false,"enableVanillaOptions":false,"enableSpotAndMarginTrading":false}"#.to_owned(),
+        };
+
+        let permissions =
+            Binance::parse_api_permissions(&response, AccountType::Spot).expect("in test");
+        assert!(!permissions.is_enabled);
+    }
+
+    #[test]
+    fn parse_rate_limits() {
+        let response = |content: &str| RestResponse {
+            status: hyper::StatusCode::OK,
+            content: content.to_owned(),
+        };
+
+        let timeout_arguments = Binance::parse_rate_limits(&response(
+            r#"{"timezone":"UTC","rateLimits":[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50},{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE","intervalNum":1,"limit":6000}],"symbols":[]}"#,
+        ))
+        .expect("in test");
+        assert_eq!(
+            timeout_arguments,
+            Some(RequestTimeoutArguments::new(
+                6000,
+                chrono::Duration::minutes(1)
+            ))
+        );
+
+        let timeout_arguments =
+            Binance::parse_rate_limits(&response(r#"{"timezone":"UTC","symbols":[]}"#))
+                .expect("in test");
+        assert_eq!(timeout_arguments, None);
+    }
+
+    #[test]
+    fn parse_order_rate_limit_status() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50,"count":12},{"rateLimitType":"ORDERS","interval":"DAY","intervalNum":1,"limit":160000,"count":3000}]"#.to_owned(),
+        };
+
+        let status = Binance::parse_order_rate_limit_status(&response).expect("in test");
+        assert_eq!(
+            status.limits,
+            vec![
+                OrderRateLimit {
+                    interval: Duration::from_secs(10),
+                    used: Some(12),
+                    limit: 50,
+                },
+                OrderRateLimit {
+                    interval: Duration::from_secs(86400),
+                    used: Some(3000),
+                    limit: 160000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn channel_names() {
+        let order_book = Subscription::OrderBook {
+            depth: Some(20),
+            update_speed_ms: Some(100),
+        };
+        assert_eq!(
+            Binance::get_channel_name(&order_book),
+            Some("depth20@100ms".to_owned())
+        );
+        assert_eq!(
+            Binance::get_channel_name(&Subscription::AggTrades),
+            Some("aggTrade".to_owned())
+        );
+        assert_eq!(
+            Binance::get_channel_name(&Subscription::BookTicker),
+            Some("bookTicker".to_owned())
+        );
+        assert_eq!(
+            Binance::get_channel_name(&Subscription::Liquidations),
+            Some("forceOrder".to_owned())
+        );
+        assert_eq!(Binance::get_channel_name(&Subscription::UserData), None);
+
+        let stream_name = Binance::get_stream_name(&"BTCUSDT".into(), "bookTicker");
+        assert_eq!(stream_name, "btcusdt@bookTicker");
+    }
+
+    #[test]
+    fn request_funding_history_by_pages() {
+        // two full pages and the last one with single record
+        let times = (0..2 * FUNDING_HISTORY_PAGE_LIMIT as i64 + 1).collect_vec();
+        let requested_start_times = Mutex::new(Vec::new());
+
+        let records = futures::executor::block_on(request_pages(
+            0,
+            i64::MAX,
+            |start_time| {
+                requested_start_times.lock().push(start_time);
+                let page = times
+                    .iter()
+                    .copied()
+                    .filter(|&time| time >= start_time)
+                    .take(FUNDING_HISTORY_PAGE_LIMIT)
+                    .collect_vec();
+                async move { Ok(page) }
+            },
+            |&time: &i64| time,
+        ))
+        .expect("in test");
+
+        assert_eq!(records, times);
+        assert_eq!(*requested_start_times.lock(), vec![0, 1000, 2000]);
+    }
+
+    #[test]
+    fn request_order_history_by_pages() {
+        // (order id, creation time) of two full pages and the last one with records
+        // created after end of time range
+        let orders = (0..2 * ORDER_HISTORY_PAGE_LIMIT as i64 + 10)
+            .map(|id| (id + 100, id))
+            .collect_vec();
+        let end_time = 2 * ORDER_HISTORY_PAGE_LIMIT as i64 + 4;
+        let requested_from_ids = Mutex::new(Vec::new());
+
+        let records = futures::executor::block_on(request_pages_by_id(
+            end_time,
+            |from_id| {
+                requested_from_ids.lock().push(from_id);
+                let page = orders
+                    .iter()
+                    .copied()
+                    .filter(|&(id, _)| from_id.map_or(true, |from_id| id >= from_id))
+                    .take(ORDER_HISTORY_PAGE_LIMIT)
+                    .collect_vec();
+                async move { Ok(page) }
+            },
+            |&(id, _): &(i64, i64)| id,
+            |&(_, time): &(i64, i64)| Some(time),
+        ))
+        .expect("in test");
+
+        assert_eq!(records, orders[..=end_time as usize].to_vec());
+        assert_eq!(
+            *requested_from_ids.lock(),
+            vec![None, Some(1100), Some(2100)]
+        );
+    }
+
+    #[test]
+    fn parse_order_history() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"symbol":"LTCBTC","orderId":1,"orderListId":-1,"clientOrderId":"myOrder1","price":"0.1","origQty":"1.0","executedQty":"1.0","cummulativeQuoteQty":"0.1","status":"FILLED","timeInForce":"GTC","type":"LIMIT","side":"BUY","stopPrice":"0.0","icebergQty":"0.0","time":1499827319559,"updateTime":1499827319559,"isWorking":true,"origQuoteOrderQty":"0.000000"},{"symbol":"LTCBTC","orderId":2,"orderListId":-1,"clientOrderId":"myOrder2","price":"0.2","origQty":"2.0","executedQty":"0.5","cummulativeQuoteQty":"0.1","status":"CANCELED","timeInForce":"GTC","type":"LIMIT","side":"SELL","stopPrice":"0.0","icebergQty":"0.0","time":1499827319600,"updateTime":1499827319700,"isWorking":true,"origQuoteOrderQty":"0.000000"}]"#.to_owned(),
+        };
+
+        let orders = Binance::parse_order_history(&response).expect("in test");
+
+        assert_eq!(
+            orders
+                .iter()
+                .map(|x| (x.exchange_order_id, x.status.as_str(), x.time))
+                .collect_vec(),
+            vec![
+                (1, "FILLED", Some(1499827319559)),
+                (2, "CANCELED", Some(1499827319600)),
+            ]
+        );
+    }
+
+    #[test]
+    fn funding_rate_is_matched_by_funding_time() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"symbol":"BTCUSDT","fundingRate":"-0.00030000","fundingTime":1570608000000},{"symbol":"BTCUSDT","fundingRate":"0.00010000","fundingTime":1570636800000}]"#.to_owned(),
+        };
+        let rates = Binance::parse_funding_rates(&response).expect("in test");
+
+        assert_eq!(
+            find_funding_rate(&rates, 1570608000012),
+            Some(dec!(-0.0003))
+        );
+        assert_eq!(find_funding_rate(&rates, 1570636799990), Some(dec!(0.0001)));
+        assert_eq!(find_funding_rate(&rates, 1570622400000), None);
+    }
+
+    #[rstest]
+    #[case::list(r#"[{"symbol":"ETHUSDT","notionalCoef":1.5,"brackets":[{"bracket":2,"initialLeverage":50,"notionalCap":100000,"notionalFloor":10000,"maintMarginRatio":0.01,"cum":50},{"bracket":1,"initialLeverage":75,"notionalCap":10000,"notionalFloor":0,"maintMarginRatio":0.005,"cum":0}]}]"#)]
+    #[case::single(r#"{"symbol":"ETHUSDT","notionalCoef":1.5,"brackets":[{"bracket":1,"initialLeverage":75,"notionalCap":10000,"notionalFloor":0,"maintMarginRatio":0.005,"cum":0},{"bracket":2,"initialLeverage":50,"notionalCap":100000,"notionalFloor":10000,"maintMarginRatio":0.01,"cum":50}]}"#)]
+    fn parse_leverage_brackets(#[case] content: &str) {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: content.to_owned(),
+        };
+
+        let brackets = Binance::parse_leverage_brackets(&response).expect("in test");
+
+        assert_eq!(
+            brackets,
+            vec![
+                LeverageBracket {
+                    notional_cap: amount!(10000),
+                    max_leverage: dec!(75),
+                    maintenance_margin_rate: dec!(0.005),
+                    maintenance_amount: amount!(0),
+                },
+                LeverageBracket {
+                    notional_cap: amount!(100000),
+                    max_leverage: dec!(50),
+                    maintenance_margin_rate: dec!(0.01),
+                    maintenance_amount: amount!(50),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_order_book_snapshot() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"{"lastUpdateId":1027024,"bids":[["4.00000000","431.00000000"],["3.90000000","12.00000000"]],"asks":[["4.00000200","12.00000000"]]}"#.to_owned(),
+        };
+
+        let snapshot =
+            Binance::parse_order_book_snapshot(&response, DecimalParsingTolerance::Lenient)
+                .expect("in test");
+
+        assert_eq!(
+            snapshot,
+            order_book_data![
+                dec!(4.000002) => dec!(12),
+                ;
+                dec!(4) => dec!(431),
+                dec!(3.9) => dec!(12),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_agg_trades() {
+        let response = RestResponse {
+            status: hyper::StatusCode::OK,
+            content: r#"[{"a":26129,"p":"0.01633102","q":"4.70443515","f":27781,"l":27782,"T":1498793709153,"m":false,"M":true}]"#.to_owned(),
+        };
+
+        let trades = Binance::parse_agg_trades(&response).expect("in test");
+
+        assert_eq!(
+            trades,
+            vec![AggTrade {
+                aggregate_trade_id: 26129,
+                price: price!(0.01633102),
+                quantity: amount!(4.70443515),
+                first_trade_id: 27781,
+                last_trade_id: 27782,
+                side: OrderSide::Buy,
+                transaction_time: u64_to_date_time(1498793709153),
+            }]
+        );
+    }
 }
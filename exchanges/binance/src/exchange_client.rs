@@ -4,6 +4,8 @@ use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use function_name::named;
 use itertools::Itertools;
+use mmb_core::exchanges::asset_networks_cache::AssetNetworks;
+use mmb_core::exchanges::general::capabilities::ExchangeCapabilities;
 use mmb_core::exchanges::general::exchange::RequestResult;
 use mmb_core::exchanges::general::order::cancel::CancelOrderResult;
 use mmb_core::exchanges::general::order::create::CreateOrderResult;
@@ -11,22 +13,43 @@ use mmb_core::exchanges::general::order::get_order_trades::OrderTrade;
 use mmb_core::exchanges::general::request_type::RequestType;
 use mmb_core::exchanges::rest_client::UriBuilder;
 use mmb_core::exchanges::traits::{ExchangeClient, ExchangeError, Support};
-use mmb_domain::events::{EventSourceType, ExchangeBalancesAndPositions};
+use mmb_core::misc::time::time_manager;
+use mmb_domain::events::{AggTrade, SystemStatus};
+use mmb_domain::events::{EventSourceType, ExchangeBalancesAndPositions, OrderRateLimitStatus};
+use mmb_domain::exchanges::api_permissions::ApiPermissions;
+use mmb_domain::exchanges::commission::CurrencyPairFees;
+use mmb_domain::exchanges::dust_conversion::DustConversion;
+use mmb_domain::exchanges::endpoint_latency::EndpointLatency;
+use mmb_domain::exchanges::funding::FundingPayment;
+use mmb_domain::exchanges::leverage::LeverageBracket;
 use mmb_domain::exchanges::symbol::Symbol;
-use mmb_domain::market::CurrencyPair;
+use mmb_domain::market::{CurrencyCode, CurrencyPair};
 use mmb_domain::order::pool::OrderRef;
 use mmb_domain::order::snapshot::Price;
 use mmb_domain::order::snapshot::*;
-use mmb_domain::position::{ActivePosition, ClosedPosition};
+use mmb_domain::order_book::order_book_data::OrderBookData;
+use mmb_domain::position::{ActivePosition, ClosedPosition, MarginMode};
 use mmb_utils::DateTime;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+const ENDPOINT_PROBE_ATTEMPTS: usize = 3;
+
+/// Margin type request responds with `{"code": 200, "msg": "success"}`
+/// which is treated as error response
+const MARGIN_TYPE_CHANGED_CODE: i64 = 200;
+/// -4046 NO_NEED_TO_CHANGE_MARGIN_TYPE
+const MARGIN_TYPE_NOT_CHANGED_CODE: i64 = -4046;
 
 #[async_trait]
 impl ExchangeClient for Binance {
     async fn create_order(&self, order: &OrderRef) -> CreateOrderResult {
         match self.request_create_order(order).await {
             Ok(request_outcome) => match self.get_order_id(&request_outcome) {
-                Ok(order_id) => CreateOrderResult::succeed(&order_id, EventSourceType::Rest),
+                Ok(order_id) => CreateOrderResult::succeed(&order_id, EventSourceType::Rest)
+                    .with_exchange_time(Binance::get_transaction_time(&request_outcome)),
                 Err(error) => CreateOrderResult::failed(error, EventSourceType::Rest),
             },
             Err(err) => CreateOrderResult::failed(err, EventSourceType::Rest),
@@ -39,8 +62,9 @@ impl ExchangeClient for Binance {
         exchange_order_id: &ExchangeOrderId,
     ) -> CancelOrderResult {
         match self.request_cancel_order(order, exchange_order_id).await {
-            Ok(_) => {
+            Ok(request_outcome) => {
                 CancelOrderResult::succeed(order.client_order_id(), EventSourceType::Rest, None)
+                    .with_exchange_time(Binance::get_transaction_time(&request_outcome))
             }
             Err(err) => CancelOrderResult::failed(err, EventSourceType::Rest),
         }
@@ -54,7 +78,7 @@ impl ExchangeClient for Binance {
         builder.add_kv("symbol", specific_currency_pair);
         self.add_authentification(&mut builder);
 
-        let uri = builder.build_uri(self.hosts.rest_uri_host(), true);
+        let uri = builder.build_uri(self.rest_uri_host(), true);
 
         self.rest_client
             .delete(uri, function_name!(), String::new())
@@ -111,7 +135,7 @@ impl ExchangeClient for Binance {
     async fn get_balance_and_positions(&self) -> Result<ExchangeBalancesAndPositions> {
         // Binance does return positions from GET request /fapi/v2/account but without liquidation_price field
         // so we have to use separate requests for balance and positions
-        Ok(match self.settings.is_margin_trading {
+        Ok(match self.settings.account_type.is_derivative() {
             true => {
                 let (balance_response, position_response) =
                     tokio::join!(self.request_get_balance(), self.request_get_position());
@@ -151,6 +175,7 @@ impl ExchangeClient for Binance {
 
     async fn build_all_symbols(&self) -> Result<Vec<Arc<Symbol>>> {
         let response = &self.request_all_symbols().await?;
+        self.setup_rate_limits(response);
         self.parse_all_symbols(response)
     }
 
@@ -160,6 +185,252 @@ impl ExchangeClient for Binance {
             Err(err) => Some(Err(anyhow!("Get server time request failed: {err:?}"))),
         }
     }
+
+    fn capabilities(&self) -> ExchangeCapabilities {
+        ExchangeCapabilities {
+            market_orders: true,
+            maker_only_orders: true,
+            stop_loss_orders: true,
+            trailing_stop_orders: !self.settings.account_type.is_derivative(),
+            // MAX_NUM_ORDERS filter of exchange info
+            max_open_orders_per_currency_pair: Some(200),
+            good_till_date_orders: self.settings.account_type.is_derivative(),
+            margin_modes: self.settings.account_type.is_derivative(),
+            ..Default::default()
+        }
+    }
+
+    async fn set_margin_mode(
+        &self,
+        currency_pair: CurrencyPair,
+        margin_mode: MarginMode,
+    ) -> Option<Result<()>> {
+        if !self.settings.account_type.is_derivative() {
+            return None;
+        }
+
+        match self
+            .request_set_margin_mode(currency_pair, margin_mode)
+            .await
+        {
+            Ok(_) => Some(Ok(())),
+            Err(err)
+                if matches!(
+                    err.code,
+                    Some(MARGIN_TYPE_CHANGED_CODE | MARGIN_TYPE_NOT_CHANGED_CODE)
+                ) =>
+            {
+                Some(Ok(()))
+            }
+            Err(err) => Some(Err(anyhow!("Set margin mode request failed: {err:?}"))),
+        }
+    }
+
+    async fn get_funding_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+    ) -> Option<Result<Vec<FundingPayment>>> {
+        if !self.settings.account_type.is_derivative() {
+            return None;
+        }
+
+        Some(self.load_funding_history(currency_pair, from, to).await)
+    }
+
+    async fn get_leverage_brackets(
+        &self,
+        currency_pair: CurrencyPair,
+    ) -> Option<Result<Vec<LeverageBracket>>> {
+        if !self.settings.account_type.is_derivative() {
+            return None;
+        }
+
+        let response = match self.request_leverage_brackets(currency_pair).await {
+            Ok(response) => response,
+            Err(err) => {
+                return Some(Err(anyhow!(
+                    "Get leverage brackets request failed: {err:?}"
+                )))
+            }
+        };
+
+        Some(Self::parse_leverage_brackets(&response))
+    }
+
+    async fn set_leverage(
+        &self,
+        currency_pair: CurrencyPair,
+        leverage: Decimal,
+    ) -> Option<Result<()>> {
+        if !self.settings.account_type.is_derivative() {
+            return None;
+        }
+
+        match self.request_set_leverage(currency_pair, leverage).await {
+            Ok(_) => Some(Ok(())),
+            Err(err) => Some(Err(anyhow!("Set leverage request failed: {err:?}"))),
+        }
+    }
+
+    async fn get_order_book_snapshot(
+        &self,
+        currency_pair: CurrencyPair,
+    ) -> Option<Result<OrderBookData>> {
+        let response = match self.request_order_book_snapshot(currency_pair).await {
+            Ok(response) => response,
+            Err(err) => {
+                return Some(Err(anyhow!(
+                    "Get order book snapshot request failed: {err:?}"
+                )))
+            }
+        };
+
+        Some(Self::parse_order_book_snapshot(
+            &response,
+            self.decimal_parsing_tolerance(),
+        ))
+    }
+
+    async fn get_agg_trades(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+        limit: Option<u32>,
+    ) -> Option<Result<Vec<AggTrade>>> {
+        let response = match self
+            .request_agg_trades(currency_pair, from, to, limit)
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                return Some(Err(anyhow!(
+                    "Get aggregated trades request failed: {err:?}"
+                )))
+            }
+        };
+
+        Some(Self::parse_agg_trades(&response))
+    }
+
+    async fn get_order_history(
+        &self,
+        currency_pair: CurrencyPair,
+        from: DateTime,
+        to: DateTime,
+    ) -> Option<Result<Vec<OrderInfo>>> {
+        Some(self.load_order_history(currency_pair, from, to).await)
+    }
+
+    async fn get_system_status(&self) -> Result<SystemStatus> {
+        let response = self
+            .request_system_status()
+            .await
+            .context("Get system status request failed")?;
+
+        Self::parse_system_status(&response)
+    }
+
+    async fn select_fastest_endpoint(&self) -> Option<Result<EndpointLatency>> {
+        let rest_hosts = Self::rest_hosts(self.settings.account_type);
+        if rest_hosts.len() < 2 {
+            return None;
+        }
+
+        let mut latencies = Vec::with_capacity(rest_hosts.len());
+        for &rest_host in rest_hosts {
+            // the best of several attempts excludes connection establishing time
+            let mut best_latency = None;
+            for _ in 0..ENDPOINT_PROBE_ATTEMPTS {
+                let started_at = Instant::now();
+                match self.request_ping(rest_host).await {
+                    Ok(_) => {
+                        let latency = started_at.elapsed();
+                        best_latency =
+                            Some(best_latency.map_or(latency, |x: Duration| x.min(latency)));
+                    }
+                    Err(err) => log::warn!("Failed to ping {rest_host}: {err:?}"),
+                }
+            }
+
+            if let Some(latency) = best_latency {
+                latencies.push((rest_host, latency));
+            }
+        }
+
+        let (rest_host, latency) = match latencies.into_iter().min_by_key(|(_, latency)| *latency) {
+            Some(fastest) => fastest,
+            None => return Some(Err(anyhow!("All REST endpoints are unavailable"))),
+        };
+        *self.rest_host.write() = rest_host;
+
+        Some(Ok(EndpointLatency {
+            endpoint: rest_host.to_owned(),
+            latency,
+        }))
+    }
+
+    async fn get_trading_fees(&self) -> Option<Result<HashMap<CurrencyPair, CurrencyPairFees>>> {
+        // futures fees are requested per symbol, so only spot fees are loaded
+        if self.settings.account_type.is_derivative() {
+            return None;
+        }
+
+        let response = match self.request_trading_fees().await {
+            Ok(response) => response,
+            Err(err) => return Some(Err(anyhow!("Get trading fees request failed: {err:?}"))),
+        };
+
+        Some(Self::parse_trading_fees(
+            &response,
+            &self.specific_to_unified.read(),
+        ))
+    }
+
+    async fn get_asset_networks(&self) -> Option<Result<AssetNetworks>> {
+        let response = match self.request_asset_networks().await {
+            Ok(response) => response,
+            Err(err) => return Some(Err(anyhow!("Get asset networks request failed: {err:?}"))),
+        };
+
+        Some(Self::parse_asset_networks(&response))
+    }
+
+    async fn convert_dust(&self, assets: &[CurrencyCode]) -> Option<Result<DustConversion>> {
+        let response = match self.request_convert_dust(assets).await {
+            Ok(response) => response,
+            Err(err) => return Some(Err(anyhow!("Convert dust request failed: {err:?}"))),
+        };
+
+        Some(Self::parse_dust_conversion(&response))
+    }
+
+    async fn get_order_rate_limit_status(&self) -> Option<Result<OrderRateLimitStatus>> {
+        let response = match self.request_order_rate_limit_status().await {
+            Ok(response) => response,
+            Err(err) => {
+                return Some(Err(anyhow!(
+                    "Get order rate limit status request failed: {err:?}"
+                )))
+            }
+        };
+
+        Some(Self::parse_order_rate_limit_status(&response))
+    }
+
+    async fn get_api_permissions(&self) -> Option<Result<ApiPermissions>> {
+        let response = match self.request_api_permissions().await {
+            Ok(response) => response,
+            Err(err) => return Some(Err(anyhow!("Get API permissions request failed: {err:?}"))),
+        };
+
+        Some(Self::parse_api_permissions(
+            &response,
+            self.settings.account_type,
+        ))
+    }
 }
 
 impl Binance {
@@ -197,6 +468,24 @@ impl Binance {
         unreachable!()
     }
 
+    /// Extend listen key if ping interval elapsed since it was received or extended last time.
+    /// Failed ping is retried on the next check
+    pub(crate) async fn ping_listen_key_if_due(&self) {
+        let extended_at = match *self.listen_key_extended_at.lock() {
+            // websocket is disconnected, so there is no listen key to extend
+            None => return,
+            Some(extended_at) => extended_at,
+        };
+
+        if is_listen_key_ping_due(
+            extended_at,
+            self.listen_key_ping_interval,
+            time_manager::now(),
+        ) {
+            self.ping_listen_key().await;
+        }
+    }
+
     pub(crate) async fn ping_listen_key(&self) {
         // TODO check is_trading
 
@@ -225,8 +514,15 @@ impl Binance {
         };
 
         match self.request_update_listen_key(&listen_key).await {
-            Ok(_) => log::trace!("Updated listenKey"),
+            Ok(_) => {
+                *self.listen_key_extended_at.lock() = Some(time_manager::now());
+                log::trace!("Updated listenKey")
+            }
             Err(err) => log::warn!("Failed to update listenKey {err}"),
         }
     }
 }
+
+fn is_listen_key_ping_due(extended_at: DateTime, ping_interval: Duration, now: DateTime) -> bool {
+    (now - extended_at).to_std().unwrap_or_default() >= ping_interval
+}
@@ -1,7 +1,8 @@
 use mmb_utils::infrastructure::{SpawnFutureFlags, WithExpect};
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use async_trait::async_trait;
 use chrono::{TimeZone, Utc};
 use dashmap::DashMap;
@@ -15,24 +16,28 @@ use std::time::Duration;
 use url::Url;
 
 use super::binance::Binance;
-use mmb_core::connectivity::WebSocketRole;
+use mmb_core::connectivity::{StreamKind, Subscription, WebSocketParams, WebSocketRole};
 use mmb_core::exchanges::common::send_event;
 use mmb_core::exchanges::general::exchange::Exchange;
 use mmb_core::exchanges::traits::{HandleMetricsCb, Support};
 use mmb_core::exchanges::traits::{
     HandleOrderFilledCb, HandleTradeCb, OrderCancelledCb, OrderCreatedCb, SendWebsocketMessageCb,
 };
-use mmb_core::infrastructure::spawn_by_timer;
-use mmb_core::settings::ExchangeSettings;
+use mmb_core::infrastructure::{spawn_by_timer, spawn_future};
+use mmb_core::misc::time::time_manager;
+use mmb_core::settings::{ExchangeSettings, WebsocketSubscriptionSettings};
 use mmb_domain::events::{
-    EventSourceType, ExchangeEvent, MetricsEventInfo, MetricsEventType, Trade, TradeId,
+    AggTrade, AggTradeEvent, EventSourceType, ExchangeEvent, LiquidationEvent, MarkPriceEvent,
+    MetricsEventInfo, MetricsEventType, SubscriptionFailedEvent, Trade, TradeId,
 };
-use mmb_domain::market::{CurrencyCode, CurrencyPair};
+use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeAccountId};
 use mmb_domain::market::{CurrencyId, SpecificCurrencyPair};
 use mmb_domain::order::snapshot::SortedOrderData;
 use mmb_domain::order::snapshot::*;
 use mmb_domain::order_book::event::{EventType, OrderBookEvent};
 use mmb_domain::order_book::order_book_data::OrderBookData;
+use mmb_domain::position::MarginMode;
+use mmb_utils::decimal_parsing::{deserialize_decimal, parse_decimal, DecimalParsingTolerance};
 use mmb_utils::time::get_current_milliseconds;
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
@@ -43,13 +48,21 @@ pub struct BinanceOrderInfo {
     pub exchange_order_id: i64, //< local type is ExchangeOrderId
     #[serde(rename = "clientOrderId")]
     pub client_order_id: ClientOrderId,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub price: Price,
     #[serde(rename = "origQty")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub orig_quantity: Amount,
     #[serde(rename = "executedQty")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub executed_quantity: Amount,
     pub status: String,
     pub side: String,
+    /// Expire time in milliseconds of good till date order. Futures only
+    #[serde(rename = "goodTillDate", default)]
+    pub good_till_date: Option<u64>,
+    /// Creation time in milliseconds. Not provided in responses to order creation
+    pub time: Option<i64>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -67,7 +80,8 @@ pub(crate) struct BinanceSpotAccountInfo<'a> {
 #[derive(Debug, Deserialize)]
 pub(super) struct BinanceSpotBalances<'a> {
     pub(super) asset: &'a str,
-    pub(super) free: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) free: Amount,
 }
 
 /// Corresponds https://binance-docs.github.io/apidocs/futures/en/#account-information-v2-user_data
@@ -88,8 +102,9 @@ pub(super) struct BinanceSpotBalances<'a> {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct BinanceDerivativeBalances<'a> {
-    pub(super) asset: &'a str,             // asset name
-    pub(super) available_balance: Decimal, // available balance
+    pub(super) asset: &'a str, // asset name
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) available_balance: Amount, // available balance
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
@@ -97,12 +112,77 @@ pub(super) struct BinancePosition {
     #[serde(rename = "symbol")]
     pub(super) specific_currency_pair: SpecificCurrencyPair,
     #[serde(rename = "positionAmt")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(super) position_amount: Amount,
     #[serde(rename = "entryPrice")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(super) average_entry_price: Price,
     #[serde(rename = "liquidationPrice")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(super) liquidation_price: Price,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(super) leverage: Decimal,
+    #[serde(rename = "marginType")]
+    pub(super) margin_mode: MarginMode,
+}
+
+/// Record of futures income history, e.g. funding fee
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct BinanceIncome {
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) income: Amount,
+    pub(super) asset: String,
+    /// Time in milliseconds
+    pub(super) time: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct BinanceFundingRate {
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) funding_rate: Decimal,
+    /// Time in milliseconds
+    pub(super) funding_time: i64,
+}
+
+/// Trades of the same taker order at the same price aggregated by Binance.
+/// REST and websocket use the same format
+#[derive(Debug, Clone, Deserialize)]
+pub(super) struct BinanceAggTrade {
+    #[serde(rename = "a")]
+    pub(super) aggregate_trade_id: u64,
+    #[serde(rename = "p")]
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) price: Price,
+    #[serde(rename = "q")]
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(super) quantity: Amount,
+    #[serde(rename = "f")]
+    pub(super) first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub(super) last_trade_id: u64,
+    /// Time in milliseconds
+    #[serde(rename = "T")]
+    pub(super) time: i64,
+    #[serde(rename = "m")]
+    pub(super) is_buyer_maker: bool,
+}
+
+impl From<BinanceAggTrade> for AggTrade {
+    fn from(trade: BinanceAggTrade) -> Self {
+        AggTrade {
+            aggregate_trade_id: trade.aggregate_trade_id,
+            price: trade.price,
+            quantity: trade.quantity,
+            first_trade_id: trade.first_trade_id,
+            last_trade_id: trade.last_trade_id,
+            side: match trade.is_buyer_maker {
+                true => OrderSide::Sell,
+                false => OrderSide::Buy,
+            },
+            transaction_time: Utc.timestamp_millis(trade.time),
+        }
+    }
 }
 
 #[async_trait]
@@ -135,6 +215,16 @@ impl Support for Binance {
                     return Ok(());
                 }
 
+                if stream.ends_with("@aggTrade") {
+                    let event = parse_agg_trade(self.id, currency_pair, data)?;
+                    return send_event(
+                        &self.events_channel,
+                        self.lifetime_manager.clone(),
+                        self.id,
+                        ExchangeEvent::AggTrade(event),
+                    );
+                }
+
                 // TODO handle public stream
                 let stream_tail = &stream[byte_index + 1..];
                 if stream_tail.starts_with("depth1000") {
@@ -146,6 +236,45 @@ impl Support for Binance {
                     self.process_snapshot_update(currency_pair, data)?;
                     return Ok(());
                 }
+
+                if stream_tail.starts_with("markPrice") {
+                    let tolerance = self.decimal_parsing_tolerance();
+                    let event = parse_mark_price(self.id, currency_pair, data, tolerance)?;
+                    return send_event(
+                        &self.events_channel,
+                        self.lifetime_manager.clone(),
+                        self.id,
+                        ExchangeEvent::MarkPrice(event),
+                    );
+                }
+
+                if stream_tail.starts_with("forceOrder") {
+                    let tolerance = self.decimal_parsing_tolerance();
+                    let event = parse_liquidation(self.id, currency_pair, data, tolerance)?;
+                    return send_event(
+                        &self.events_channel,
+                        self.lifetime_manager.clone(),
+                        self.id,
+                        ExchangeEvent::Liquidation(event),
+                    );
+                }
+            }
+
+            return Ok(());
+        }
+
+        // Response on subscription request
+        if let Some(request_id) = data.get("id") {
+            let streams = request_id
+                .as_u64()
+                .map(|request_id| self.pending_subscriptions.lock().complete(request_id))
+                .unwrap_or_default();
+            match data.get("error") {
+                Some(error) => self.on_subscription_failed(request_id, streams, error)?,
+                None => log::trace!(
+                    "Binance websocket request {request_id} for {} succeeded",
+                    self.id
+                ),
             }
 
             return Ok(());
@@ -183,16 +312,22 @@ impl Support for Binance {
     }
 
     fn on_connected(&self) -> Result<()> {
+        self.subscribe_to_streams();
+
         Ok(())
     }
 
     fn on_disconnected(&self) -> Result<()> {
         *self.listen_key.write() = None;
+        self.pending_subscriptions.lock().clear();
+        *self.listen_key_extended_at.lock() = None;
 
         Ok(())
     }
 
-    fn set_send_websocket_message_callback(&mut self, _callback: SendWebsocketMessageCb) {}
+    fn set_send_websocket_message_callback(&mut self, callback: SendWebsocketMessageCb) {
+        self.websocket_message_callback = Arc::new(callback);
+    }
 
     fn set_order_created_callback(&mut self, callback: OrderCreatedCb) {
         self.order_created_callback = callback;
@@ -218,6 +353,10 @@ impl Support for Binance {
         *self.traded_specific_currencies.lock() = currencies;
     }
 
+    fn set_required_streams(&self, required_streams: HashMap<CurrencyPair, HashSet<StreamKind>>) {
+        *self.required_streams.lock() = required_streams;
+    }
+
     fn is_websocket_enabled(&self, role: WebSocketRole) -> bool {
         match role {
             WebSocketRole::Main => true,
@@ -229,10 +368,7 @@ impl Support for Binance {
 
     async fn create_ws_url(&self, role: WebSocketRole) -> Result<Url> {
         let (host, path) = match role {
-            WebSocketRole::Main => (
-                &self.hosts.web_socket_host,
-                self.build_ws_main_path(&self.settings.websocket_channels[..]),
-            ),
+            WebSocketRole::Main => (&self.hosts.web_socket_host, "/stream".to_owned()),
             WebSocketRole::Secondary => (
                 &self.hosts.web_socket2_host,
                 self.build_ws_secondary_path().await?,
@@ -243,6 +379,15 @@ impl Support for Binance {
             .with_context(|| format!("Unable parse websocket {role:?} uri"))
     }
 
+    async fn create_ws_params(&self, role: WebSocketRole) -> Result<WebSocketParams> {
+        let params = WebSocketParams::new(role, self.create_ws_url(role).await?);
+        match role {
+            WebSocketRole::Main => Ok(params),
+            // user data stream is authenticated by listen key in url
+            WebSocketRole::Secondary => Ok(params.with_auth(None)),
+        }
+    }
+
     fn get_specific_currency_pair(&self, currency_pair: CurrencyPair) -> SpecificCurrencyPair {
         self.unified_to_specific.read()[&currency_pair]
     }
@@ -255,11 +400,7 @@ impl Support for Binance {
         message.contains("executionReport")
     }
 
-    fn log_unknown_message(
-        &self,
-        exchange_account_id: mmb_domain::market::ExchangeAccountId,
-        message: &str,
-    ) {
+    fn log_unknown_message(&self, exchange_account_id: ExchangeAccountId, message: &str) {
         log::info!("Unknown message for {exchange_account_id}: {message}");
     }
 
@@ -285,15 +426,20 @@ impl Binance {
 
         *trade_id_from_lasts = trade_id.clone();
 
-        let price: Decimal = data["p"]
-            .as_str()
-            .context("Unable to get string from 'p' field json data")?
-            .parse()?;
+        let tolerance = self.decimal_parsing_tolerance();
+        let price = Price::new(parse_decimal(
+            data["p"]
+                .as_str()
+                .context("Unable to get string from 'p' field json data")?,
+            tolerance,
+        )?);
 
-        let quantity: Decimal = data["q"]
-            .as_str()
-            .context("Unable to get string from 'q' field json data")?
-            .parse()?;
+        let quantity = Amount::new(parse_decimal(
+            data["q"]
+                .as_str()
+                .context("Unable to get string from 'q' field json data")?,
+            tolerance,
+        )?);
         let order_side = if data["m"] == true {
             OrderSide::Sell
         } else {
@@ -325,7 +471,8 @@ impl Binance {
     }
 
     pub fn process_snapshot_update(&self, currency_pair: CurrencyPair, data: &Value) -> Result<()> {
-        let (last_update_id, raw_asks, raw_bids) = match self.settings.is_margin_trading {
+        let (last_update_id, raw_asks, raw_bids) = match self.settings.account_type.is_derivative()
+        {
             true => {
                 let last_update_id = data["u"].to_string();
                 let raw_asks = data["a"]
@@ -359,8 +506,9 @@ impl Binance {
             MetricsEventType::OrderBookEvent,
         ));
 
-        let asks = get_order_book_side(raw_asks)?;
-        let bids = get_order_book_side(raw_bids)?;
+        let tolerance = self.decimal_parsing_tolerance();
+        let asks = get_order_book_side(raw_asks, tolerance)?;
+        let bids = get_order_book_side(raw_bids, tolerance)?;
 
         let order_book_data = OrderBookData::new(asks, bids);
         self.handle_order_book_snapshot(currency_pair, &last_update_id, order_book_data, None)
@@ -409,22 +557,125 @@ impl Binance {
         self.get_unified_currency_pair(&specific_currency_pair)
     }
 
-    fn build_ws_main_path(&self, websocket_channels: &[String]) -> String {
-        let stream_names = self
-            .traded_specific_currencies
+    /// Streams of configured subscriptions for each traded currency pair.
+    /// Streams which aren't required by strategies for currency pair are skipped
+    fn build_ws_stream_names(&self, subscriptions: &[Subscription]) -> Vec<String> {
+        let required_streams = self.required_streams.lock();
+
+        self.traded_specific_currencies
             .lock()
             .iter()
-            .flat_map(|currency_pair| {
-                let mut results = Vec::new();
-                for channel in websocket_channels {
-                    let result = Self::get_stream_name(currency_pair, channel);
-                    results.push(result);
-                }
-                results
+            .flat_map(|specific_currency_pair| {
+                let required = self
+                    .get_unified_currency_pair(specific_currency_pair)
+                    .ok()
+                    .and_then(|currency_pair| required_streams.get(&currency_pair));
+
+                subscriptions
+                    .iter()
+                    .filter(move |subscription| {
+                        required.map_or(true, |x| x.contains(&subscription.stream_kind()))
+                    })
+                    .filter_map(Self::get_channel_name)
+                    .map(move |channel| Self::get_stream_name(specific_currency_pair, &channel))
             })
-            .join("/");
-        let ws_path = format!("/stream?streams={stream_names}");
-        ws_path.to_lowercase()
+            .collect_vec()
+    }
+
+    /// Subscribe to market data streams by batched messages sent with pacing to not exceed
+    /// Binance limit of incoming websocket messages
+    fn subscribe_to_streams(&self) {
+        let stream_names = self.build_ws_stream_names(&self.settings.websocket_channels[..]);
+        let batch_size = self.subscription_settings().batch_size;
+
+        let messages = self
+            .pending_subscriptions
+            .lock()
+            .add_requests(&stream_names, batch_size);
+        self.send_subscribe_messages(messages);
+    }
+
+    fn subscription_settings(&self) -> WebsocketSubscriptionSettings {
+        self.settings
+            .websocket_subscription
+            .clone()
+            .unwrap_or_default()
+    }
+
+    /// Binance rejects whole subscription request if any of its streams is invalid. Streams of
+    /// rejected batch are subscribed again one by one, so valid streams keep working and only
+    /// invalid ones are reported as failed
+    fn on_subscription_failed(
+        &self,
+        request_id: &Value,
+        streams: Vec<String>,
+        error: &Value,
+    ) -> Result<()> {
+        if streams.len() > 1 && self.subscription_settings().isolate_failed_streams {
+            log::warn!(
+                "Binance websocket request {request_id} for {} failed: {error}. Its {} streams are subscribed one by one",
+                self.id,
+                streams.len()
+            );
+            let messages = self.pending_subscriptions.lock().add_requests(&streams, 1);
+            self.send_subscribe_messages(messages);
+            return Ok(());
+        }
+
+        log::error!(
+            "Binance websocket request {request_id} for {} failed: {error}. Failed streams: {streams:?}",
+            self.id
+        );
+        for stream in streams {
+            let currency_pair = stream.split_once('@').and_then(|(currency_pair, _)| {
+                self.currency_pair_from_web_socket(currency_pair).ok()
+            });
+            let event = SubscriptionFailedEvent {
+                exchange_account_id: self.id,
+                stream,
+                currency_pair,
+                reason: error.to_string(),
+            };
+            send_event(
+                &self.events_channel,
+                self.lifetime_manager.clone(),
+                self.id,
+                ExchangeEvent::SubscriptionFailed(event),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Send subscription messages with pacing to not exceed Binance limit of incoming
+    /// websocket messages
+    fn send_subscribe_messages(&self, messages: Vec<String>) {
+        if messages.is_empty() {
+            return;
+        }
+
+        let send_websocket_message = self.websocket_message_callback.clone();
+        let interval = Duration::from_millis(self.subscription_settings().interval_ms);
+        let exchange_account_id = self.id;
+        let action = async move {
+            for (index, message) in messages.into_iter().enumerate() {
+                if index > 0 {
+                    tokio::time::sleep(interval).await;
+                }
+
+                send_websocket_message(WebSocketRole::Main, message).with_context(|| {
+                    format!("Unable to send subscription message for {exchange_account_id}")
+                })?;
+            }
+
+            Ok(())
+        };
+
+        spawn_future(
+            "Subscribe to Binance websocket streams",
+            SpawnFutureFlags::STOP_BY_TOKEN,
+            action,
+        );
     }
 
     async fn build_ws_secondary_path(&self) -> Result<String> {
@@ -433,18 +684,22 @@ impl Binance {
         let ws_path = format!("/ws/{listen_key}");
 
         *self.listen_key.write() = Some(listen_key);
+        *self.listen_key_extended_at.lock() = Some(time_manager::now());
 
         Ok(ws_path)
     }
 }
 
+/// Listen key is extended when its ping interval elapsed, so the interval is checked
+/// much more often than it lasts
+const LISTEN_KEY_PING_CHECK_PERIOD: Duration = Duration::from_secs(60);
+
 fn start_updating_listen_key(exchange: &Arc<Exchange>) {
     let exchange_wk = Arc::downgrade(exchange);
-    let period = Duration::from_secs(20 * 60);
     spawn_by_timer(
         "Update listen key",
-        period,
-        period,
+        LISTEN_KEY_PING_CHECK_PERIOD,
+        LISTEN_KEY_PING_CHECK_PERIOD,
         SpawnFutureFlags::STOP_BY_TOKEN | SpawnFutureFlags::DENY_CANCELLATION,
         move || {
             let exchange_wk = exchange_wk.clone();
@@ -459,26 +714,320 @@ fn start_updating_listen_key(exchange: &Arc<Exchange>) {
                     .as_any()
                     .downcast_ref::<Binance>()
                     .expect("received non Binance exchange client in method of updating listen keys by timer")
-                    .ping_listen_key()
+                    .ping_listen_key_if_due()
                     .await;
             }
         },
     );
 }
 
-fn get_order_book_side(levels: &[Value]) -> Result<SortedOrderData> {
+/// Subscription requests sent by websocket and not responded yet, so streams of rejected
+/// request can be found by its id
+#[derive(Default)]
+pub(super) struct PendingSubscriptions {
+    last_request_id: u64,
+    streams_by_request_id: HashMap<u64, Vec<String>>,
+}
+
+impl PendingSubscriptions {
+    /// Register requests subscribing streams by batches and return their messages
+    pub(super) fn add_requests(
+        &mut self,
+        stream_names: &[String],
+        batch_size: usize,
+    ) -> Vec<String> {
+        stream_names
+            .chunks(batch_size.max(1))
+            .map(|streams| {
+                self.last_request_id += 1;
+                let _ = self
+                    .streams_by_request_id
+                    .insert(self.last_request_id, streams.to_vec());
+
+                serde_json::json!({
+                    "method": "SUBSCRIBE",
+                    "params": streams,
+                    "id": self.last_request_id,
+                })
+                .to_string()
+            })
+            .collect_vec()
+    }
+
+    /// Streams of responded request
+    pub(super) fn complete(&mut self, request_id: u64) -> Vec<String> {
+        self.streams_by_request_id
+            .remove(&request_id)
+            .unwrap_or_default()
+    }
+
+    /// Requests of closed connection won't be responded
+    pub(super) fn clear(&mut self) {
+        self.streams_by_request_id.clear();
+    }
+}
+
+fn parse_mark_price(
+    exchange_account_id: ExchangeAccountId,
+    currency_pair: CurrencyPair,
+    data: &Value,
+    tolerance: DecimalParsingTolerance,
+) -> Result<MarkPriceEvent> {
+    let get_decimal = |field: &str| -> Result<Decimal> {
+        let value = data[field]
+            .as_str()
+            .with_context(|| format!("Unable to get string from '{field}' field json data"))?;
+        parse_decimal(value, tolerance)
+            .with_context(|| format!("Unable to parse '{field}' field of mark price"))
+    };
+
+    let next_funding_time = data["T"]
+        .as_i64()
+        .context("Unable to get i64 from 'T' field json data")?;
+
+    Ok(MarkPriceEvent {
+        exchange_account_id,
+        currency_pair,
+        mark_price: Price::new(get_decimal("p")?),
+        index_price: Price::new(get_decimal("i")?),
+        funding_rate: get_decimal("r")?,
+        next_funding_time: Utc.timestamp_millis(next_funding_time),
+    })
+}
+
+fn parse_agg_trade(
+    exchange_account_id: ExchangeAccountId,
+    currency_pair: CurrencyPair,
+    data: &Value,
+) -> Result<AggTradeEvent> {
+    let trade = BinanceAggTrade::deserialize(data).context("Unable to parse aggregated trade")?;
+
+    Ok(AggTradeEvent {
+        exchange_account_id,
+        currency_pair,
+        trade: trade.into(),
+    })
+}
+
+fn parse_liquidation(
+    exchange_account_id: ExchangeAccountId,
+    currency_pair: CurrencyPair,
+    data: &Value,
+    tolerance: DecimalParsingTolerance,
+) -> Result<LiquidationEvent> {
+    let order = &data["o"];
+    let get_decimal = |field: &str| -> Result<Decimal> {
+        let value = order[field]
+            .as_str()
+            .with_context(|| format!("Unable to get string from '{field}' field json data"))?;
+        parse_decimal(value, tolerance)
+            .with_context(|| format!("Unable to parse '{field}' field of liquidation order"))
+    };
+
+    let side = match order["S"].as_str() {
+        Some("BUY") => OrderSide::Buy,
+        Some("SELL") => OrderSide::Sell,
+        side => bail!("Unexpected side {side:?} of liquidation order"),
+    };
+    let timestamp = order["T"]
+        .as_i64()
+        .context("Unable to get i64 from 'T' field json data")?;
+
+    Ok(LiquidationEvent {
+        exchange_account_id,
+        currency_pair,
+        side,
+        // average price and accumulated filled amount of liquidation order
+        price: Price::new(get_decimal("ap")?),
+        amount: Amount::new(get_decimal("z")?),
+        timestamp: Utc.timestamp_millis(timestamp),
+    })
+}
+
+pub(super) fn get_order_book_side(
+    levels: &[Value],
+    tolerance: DecimalParsingTolerance,
+) -> Result<SortedOrderData> {
     levels
         .iter()
         .map(|x| {
             let price = x[0]
                 .as_str()
-                .ok_or_else(|| anyhow!("Unable parse price of order book side in Binance"))?
-                .parse()?;
+                .ok_or_else(|| anyhow!("Unable parse price of order book side in Binance"))?;
             let amount = x[1]
                 .as_str()
-                .ok_or_else(|| anyhow!("Unable parse amount of order book side in Binance"))?
-                .parse()?;
-            Ok((price, amount))
+                .ok_or_else(|| anyhow!("Unable parse amount of order book side in Binance"))?;
+            Ok((
+                Price::new(parse_decimal(price, tolerance)?),
+                Amount::new(parse_decimal(amount, tolerance)?),
+            ))
         })
         .try_collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mmb_domain::{amount, price};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn batch_subscribe_messages() {
+        let stream_names = ["btcusdt@trade", "btcusdt@depth", "ethusdt@trade"]
+            .map(str::to_owned)
+            .to_vec();
+
+        let mut pending_subscriptions = PendingSubscriptions::default();
+        let messages = pending_subscriptions.add_requests(&stream_names, 2);
+
+        assert_eq!(
+            messages,
+            vec![
+                r#"{"id":1,"method":"SUBSCRIBE","params":["btcusdt@trade","btcusdt@depth"]}"#,
+                r#"{"id":2,"method":"SUBSCRIBE","params":["ethusdt@trade"]}"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn no_subscribe_messages_without_streams() {
+        assert!(PendingSubscriptions::default()
+            .add_requests(&[], 100)
+            .is_empty());
+    }
+
+    #[test]
+    fn streams_of_responded_subscription_request() {
+        let stream_names = ["btcusdt@trade", "btcusdt@depth", "ethusdt@trade"]
+            .map(str::to_owned)
+            .to_vec();
+        let mut pending_subscriptions = PendingSubscriptions::default();
+        let _ = pending_subscriptions.add_requests(&stream_names, 2);
+
+        let messages = pending_subscriptions.add_requests(&stream_names[..1], 1);
+        assert_eq!(
+            messages,
+            vec![r#"{"id":3,"method":"SUBSCRIBE","params":["btcusdt@trade"]}"#]
+        );
+
+        assert_eq!(
+            pending_subscriptions.complete(2),
+            vec!["ethusdt@trade".to_owned()]
+        );
+        assert!(pending_subscriptions.complete(2).is_empty());
+    }
+
+    #[test]
+    fn parse_mark_price_update() {
+        let data: Value = serde_json::from_str(
+            r#"{"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000","i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000}"#,
+        )
+        .expect("in test");
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+        let currency_pair = CurrencyPair::from_codes("btc".into(), "usdt".into());
+
+        let event = parse_mark_price(
+            exchange_account_id,
+            currency_pair,
+            &data,
+            DecimalParsingTolerance::Lenient,
+        )
+        .expect("in test");
+
+        assert_eq!(event.currency_pair, currency_pair);
+        assert_eq!(event.mark_price, price!(11794.15));
+        assert_eq!(event.index_price, price!(11784.62659091));
+        assert_eq!(event.funding_rate, dec!(0.00038167));
+        assert_eq!(event.next_funding_time.timestamp_millis(), 1562306400000);
+    }
+
+    #[test]
+    fn reject_mark_price_without_index_price() {
+        let data: Value = serde_json::from_str(
+            r#"{"e":"markPriceUpdate","s":"BTCUSDT","p":"11794.15","r":"0.00038167","T":1562306400000}"#,
+        )
+        .expect("in test");
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+        let currency_pair = CurrencyPair::from_codes("btc".into(), "usdt".into());
+
+        assert!(parse_mark_price(
+            exchange_account_id,
+            currency_pair,
+            &data,
+            DecimalParsingTolerance::Lenient,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn parse_agg_trade_update() {
+        let data: Value = serde_json::from_str(
+            r#"{"e":"aggTrade","E":123456789,"s":"BTCUSDT","a":5933014,"p":"0.001","q":"100","f":100,"l":105,"T":123456785,"m":true,"M":true}"#,
+        )
+        .expect("in test");
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+        let currency_pair = CurrencyPair::from_codes("btc".into(), "usdt".into());
+
+        let event = parse_agg_trade(exchange_account_id, currency_pair, &data).expect("in test");
+
+        assert_eq!(event.currency_pair, currency_pair);
+        assert_eq!(event.trade.aggregate_trade_id, 5933014);
+        assert_eq!(event.trade.price, price!(0.001));
+        assert_eq!(event.trade.quantity, amount!(100));
+        assert_eq!(
+            (event.trade.first_trade_id, event.trade.last_trade_id),
+            (100, 105)
+        );
+        assert_eq!(event.trade.side, OrderSide::Sell);
+        assert_eq!(event.trade.transaction_time.timestamp_millis(), 123456785);
+    }
+
+    #[test]
+    fn parse_liquidation_order() {
+        let data: Value = serde_json::from_str(
+            r#"{"e":"forceOrder","E":1568014460893,"o":{"s":"BTCUSDT","S":"SELL","o":"LIMIT","f":"IOC","q":"0.014","p":"9910","ap":"9910.5","X":"FILLED","l":"0.014","z":"0.014","T":1568014460893}}"#,
+        )
+        .expect("in test");
+        let exchange_account_id = ExchangeAccountId::new("Binance", 0);
+        let currency_pair = CurrencyPair::from_codes("btc".into(), "usdt".into());
+
+        let event = parse_liquidation(
+            exchange_account_id,
+            currency_pair,
+            &data,
+            DecimalParsingTolerance::Lenient,
+        )
+        .expect("in test");
+
+        assert_eq!(event.currency_pair, currency_pair);
+        assert_eq!(event.side, OrderSide::Sell);
+        assert_eq!(event.price, price!(9910.5));
+        assert_eq!(event.amount, amount!(0.014));
+        assert_eq!(event.timestamp.timestamp_millis(), 1568014460893);
+    }
+
+    #[test]
+    fn parse_order_book_side_in_scientific_notation() {
+        let levels: Vec<Value> =
+            serde_json::from_str(r#"[["1E-8","100.00000000"],["0.00000002","5.0E+2"]]"#)
+                .expect("in test");
+
+        let side = get_order_book_side(&levels, DecimalParsingTolerance::Lenient).expect("in test");
+        assert_eq!(
+            side.into_iter().collect::<Vec<_>>(),
+            vec![
+                (price!(0.00000001), amount!(100)),
+                (price!(0.00000002), amount!(500))
+            ]
+        );
+
+        assert!(get_order_book_side(&levels, DecimalParsingTolerance::Strict).is_err());
+    }
+
+    #[test]
+    fn reject_malformed_order_book_level() {
+        let levels: Vec<Value> = serde_json::from_str(r#"[["1..5","100"]]"#).expect("in test");
+
+        assert!(get_order_book_side(&levels, DecimalParsingTolerance::Lenient).is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use binance::binance::Binance;
 use mmb_core::balance::manager::balance_manager::BalanceManager;
+use mmb_core::connectivity::Subscription;
 use mmb_core::database::events::recorder::EventRecorder;
 use mmb_core::exchanges::exchange_blocker::ExchangeBlocker;
 use mmb_core::exchanges::general::currency_pair_to_symbol_converter::CurrencyPairToSymbolConverter;
@@ -10,7 +11,7 @@ use mmb_core::exchanges::hosts::Hosts;
 use mmb_core::exchanges::timeouts::requests_timeout_manager_factory::RequestTimeoutArguments;
 use mmb_core::infrastructure::init_lifetime_manager;
 use mmb_core::settings::CurrencyPairSetting;
-use mmb_core::settings::ExchangeSettings;
+use mmb_core::settings::{AccountType, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::exchanges::commission::Commission;
 use mmb_domain::market::*;
@@ -66,7 +67,7 @@ impl BinanceBuilder {
             ),
             Commission::default(),
             true,
-            false,
+            AccountType::Spot,
         )
         .await
     }
@@ -97,7 +98,7 @@ impl BinanceBuilder {
             ),
             Commission::default(),
             true,
-            false,
+            AccountType::Spot,
         )
         .await
     }
@@ -119,7 +120,7 @@ impl BinanceBuilder {
             ),
             Commission::default(),
             true,
-            true,
+            AccountType::Futures,
         )
         .await
     }
@@ -130,7 +131,7 @@ impl BinanceBuilder {
         features: ExchangeFeatures,
         commission: Commission,
         need_to_clean_up: bool,
-        is_margin_trading: bool,
+        account_type: AccountType,
     ) -> Result<Self> {
         let (api_key, secret_key) = match get_binance_credentials() {
             Ok((api_key, secret_key)) => (api_key, secret_key),
@@ -142,12 +143,8 @@ impl BinanceBuilder {
             ));
         }
 
-        let mut settings = ExchangeSettings::new_short(
-            exchange_account_id,
-            api_key,
-            secret_key,
-            is_margin_trading,
-        );
+        let mut settings =
+            ExchangeSettings::new_short(exchange_account_id, api_key, secret_key, account_type);
 
         // default currency pair for tests
         settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
@@ -177,7 +174,13 @@ impl BinanceBuilder {
         let lifetime_manager = init_lifetime_manager();
         let (tx, rx) = broadcast::channel(10);
 
-        settings.websocket_channels = vec!["depth".into(), "trade".into()];
+        settings.websocket_channels = vec![
+            Subscription::OrderBook {
+                depth: None,
+                update_speed_ms: None,
+            },
+            Subscription::Trades,
+        ];
 
         let binance = Box::new(Binance::new(
             exchange_account_id,
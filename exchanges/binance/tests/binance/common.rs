@@ -20,7 +20,6 @@ use mmb_domain::order::snapshot::{Amount, OrderSide, Price};
 use mmb_utils::hashmap;
 use mmb_utils::infrastructure::WithExpect;
 use mmb_utils::value_to_decimal::GetOrErr;
-use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -115,10 +114,10 @@ pub(crate) async fn get_prices(
 ) -> (Price, Price) {
     #[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
     struct OrderBook {
-        pub bids: Vec<(Decimal, Decimal)>,
+        pub bids: Vec<(Price, Amount)>,
     }
 
-    let mut builder = UriBuilder::from_path(match settings.is_margin_trading {
+    let mut builder = UriBuilder::from_path(match settings.account_type.is_derivative() {
         true => "/fapi/v1/depth",
         false => "/api/v3/depth",
     });
@@ -129,7 +128,7 @@ pub(crate) async fn get_prices(
         uri,
         &settings.api_key,
         settings.exchange_account_id,
-        settings.is_margin_trading,
+        settings.account_type.is_derivative(),
     )
     .await;
 
@@ -148,7 +147,7 @@ pub(crate) async fn get_prices(
         .0;
 
     (
-        top_bid_price + price_precision.get_tick() * dec!(2),
+        top_bid_price + Price::new(price_precision.get_tick() * dec!(2)),
         low_bid_price,
     )
 }
@@ -161,7 +160,7 @@ pub(crate) async fn get_min_amount(
     price: Price,
     symbol: &Symbol,
 ) -> Amount {
-    let mut builder = UriBuilder::from_path(match settings.is_margin_trading {
+    let mut builder = UriBuilder::from_path(match settings.account_type.is_derivative() {
         true => "/fapi/v1/exchangeInfo",
         false => "/api/v3/exchangeInfo",
     });
@@ -172,7 +171,7 @@ pub(crate) async fn get_min_amount(
         uri,
         &settings.api_key,
         settings.exchange_account_id,
-        settings.is_margin_trading,
+        settings.account_type.is_derivative(),
     )
     .await;
 
@@ -196,13 +195,13 @@ pub(crate) async fn get_min_amount(
         .expect("Failed to get min_notional_filter");
 
     let min_notional = min_notional_filter
-        .get_as_decimal(match settings.is_margin_trading {
+        .get_as_decimal(match settings.account_type.is_derivative() {
             true => "notional",
             false => "minNotional",
         })
         .expect("Failed to get min_notional");
 
-    symbol.amount_round(min_notional / price, Round::Ceiling)
+    symbol.amount_round(Amount::new(min_notional) / price, Round::Ceiling)
 }
 
 pub(crate) fn get_position_value_by_side(side: OrderSide, position: Amount) -> Amount {
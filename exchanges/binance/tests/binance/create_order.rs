@@ -2,10 +2,10 @@ use crate::binance::binance_builder::BinanceBuilder;
 use core_tests::order::OrderProxy;
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::order::event::OrderEventType;
+use mmb_domain::{amount, price};
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
 use rstest::rstest;
-use rust_decimal_macros::*;
 use std::time::Duration;
 
 #[rstest]
@@ -76,8 +76,8 @@ async fn should_fail() {
         binance_builder.exchange.exchange_account_id,
         Some("FromShouldFailTest".to_owned()),
         CancellationToken::default(),
-        dec!(0.0000000000000000001),
-        dec!(1),
+        price!(0.0000000000000000001),
+        amount!(1),
         binance_builder.default_currency_pair,
     );
 
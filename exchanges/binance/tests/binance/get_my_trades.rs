@@ -8,7 +8,7 @@ mod futures {
         ExchangeFeatures, OpenOrdersType, OrderFeatures, OrderTradeOption, RestFillsFeatures,
         RestFillsType, WebSocketOptions,
     };
-    use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+    use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
     use mmb_domain::events::AllowedEventSourceType;
     use mmb_domain::exchanges::commission::Commission;
     use mmb_utils::cancellation_token::CancellationToken;
@@ -26,8 +26,12 @@ mod futures {
             Err(_) => return,
         };
         let exchange_account_id = default_exchange_account_id();
-        let mut settings =
-            ExchangeSettings::new_short(exchange_account_id, api_key, secret_key, true);
+        let mut settings = ExchangeSettings::new_short(
+            exchange_account_id,
+            api_key,
+            secret_key,
+            AccountType::Futures,
+        );
         settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
             base: "BTC".into(),
             quote: "USDT".into(),
@@ -1,5 +1,5 @@
 use mmb_core::exchanges::general::features::*;
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::AllowedEventSourceType;
 use mmb_domain::exchanges::commission::Commission;
 use mmb_utils::cancellation_token::CancellationToken;
@@ -69,7 +69,8 @@ async fn get_open_orders_for_each_currency_pair_separately() {
 
     let exchange_account_id = default_exchange_account_id();
     let (api_key, secret_key) = get_binance_credentials_or_exit!();
-    let mut settings = ExchangeSettings::new_short(exchange_account_id, api_key, secret_key, false);
+    let mut settings =
+        ExchangeSettings::new_short(exchange_account_id, api_key, secret_key, AccountType::Spot);
 
     settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
         base: "btc".into(),
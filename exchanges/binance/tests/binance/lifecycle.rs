@@ -28,7 +28,7 @@ async fn launch_engine() {
         Err(_) => return, // For CI, while we cant setup keys on github
     };
 
-    let init_settings = InitSettings::Directly(settings);
+    let init_settings = InitSettings::Directly(Box::new(settings));
     let engine = launch_trading_engine(&config, init_settings)
         .await
         .expect("in tests");
@@ -40,7 +40,7 @@ async fn orders_cancelled() {
     settings.core.exchanges[0].api_key = api_key.clone();
     settings.core.exchanges[0].api_key = secret_key;
 
-    let init_settings = InitSettings::Directly(settings.clone());
+    let init_settings = InitSettings::Directly(Box::new(settings.clone()));
     let engine = launch_trading_engine(&config, init_settings)
         .await
         .expect("in test");
@@ -70,7 +70,7 @@ async fn orders_cancelled() {
         let _ = exchange.cancel_all_orders(test_currency_pair).await;
         let (execution_price, min_price) = get_prices(
             get_specific_currency_pair_for_tests(&exchange, test_currency_pair),
-            &Binance::make_hosts(exchange_settings.is_margin_trading),
+            &Binance::make_hosts(exchange_settings.account_type),
             &exchange_settings,
             &symbol.price_precision,
         )
@@ -78,7 +78,7 @@ async fn orders_cancelled() {
 
         let amount = get_min_amount(
             get_specific_currency_pair_for_tests(&exchange, test_currency_pair),
-            &Binance::make_hosts(exchange_settings.is_margin_trading),
+            &Binance::make_hosts(exchange_settings.account_type),
             &exchange_settings,
             execution_price,
             &symbol,
@@ -28,7 +28,7 @@ use mmb_core::exchanges::traits::{
     Support,
 };
 use mmb_core::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use mmb_core::settings::ExchangeSettings;
+use mmb_core::settings::{AccountType, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeBalance, ExchangeEvent};
 use mmb_domain::exchanges::symbol::{Precision, Symbol};
 use mmb_domain::market::{
@@ -36,10 +36,10 @@ use mmb_domain::market::{
 };
 use mmb_domain::order::pool::{OrderRef, OrdersPool};
 use mmb_domain::order::snapshot::{
-    ExchangeOrderId, ExternalOrder, OrderExecutionType, OrderInfo, OrderOptions, OrderRole,
+    Amount, ExchangeOrderId, ExternalOrder, OrderExecutionType, OrderInfo, OrderOptions, OrderRole,
     OrderSide, OrderStatus, Price, UserOrder,
 };
-use mmb_domain::position::{ActivePosition, ClosedPosition, DerivativePosition};
+use mmb_domain::position::{ActivePosition, ClosedPosition, DerivativePosition, MarginMode};
 use mmb_utils::DateTime;
 use parking_lot::{Mutex, RwLock};
 use rust_decimal::Decimal;
@@ -209,8 +209,8 @@ impl Bitmex {
             traded_specific_currencies: Default::default(),
             events_channel,
             lifetime_manager,
-            order_created_callback: Box::new(|_, _, _| {}),
-            order_cancelled_callback: Box::new(|_, _, _| {}),
+            order_created_callback: Box::new(|_, _, _, _| {}),
+            order_cancelled_callback: Box::new(|_, _, _, _| {}),
             handle_order_filled_callback: Box::new(|_| {}),
             handle_trade_callback: Box::new(|_, _| {}),
             handle_metrics_callback: Box::new(|_| {}),
@@ -260,20 +260,20 @@ impl Bitmex {
                         .insert(specific_currency_pair, unified_currency_pair);
 
                     let (amount_currency_code, balance_currency_code) =
-                        match self.settings.is_margin_trading {
+                        match self.settings.account_type.is_derivative() {
                             true => (quote, Some(base)),
                             false => (base, None),
                         };
 
                     Arc::new(Symbol::new(
-                        self.settings.is_margin_trading,
+                        self.settings.account_type.is_derivative(),
                         symbol.base_id.into(),
                         base,
                         symbol.quote_id.into(),
                         quote,
                         None,
                         symbol.max_price,
-                        Some(symbol.amount_tick),
+                        Some(Amount::new(symbol.amount_tick)),
                         symbol.max_amount,
                         None,
                         amount_currency_code,
@@ -294,7 +294,7 @@ impl Bitmex {
         let symbol_type = BitmexSymbolType::try_from(symbol.symbol_type).ok()?;
 
         let is_active_symbol = symbol.state == "Open";
-        let is_supported = match self.settings.is_margin_trading {
+        let is_supported = match self.settings.account_type.is_derivative() {
             true => symbol_type == BitmexSymbolType::PerpetualContract && symbol.id != "ETHUSD_ETH", // ETHUSD_ETH is a ETH-margined perpetual swap. We don't support it at the moment
             false => symbol_type == BitmexSymbolType::Spot,
         };
@@ -319,6 +319,9 @@ impl Bitmex {
         builder.add_kv("side", header.side);
         builder.add_kv("orderQty", header.amount);
         builder.add_kv("clOrdID", header.client_order_id.as_str());
+        if let Some(tag) = &header.tag {
+            builder.add_kv("text", tag);
+        }
 
         match header.options {
             OrderOptions::User(user_order) => match user_order {
@@ -411,21 +414,21 @@ impl Bitmex {
     fn specific_order_info_to_unified(&self, specific: &BitmexOrderInfo) -> OrderInfo {
         let price = match specific.price {
             Some(price) => price,
-            None => dec!(0),
+            None => Price::ZERO,
         };
         let average_price = match specific.average_fill_price {
             Some(price) => price,
-            None => dec!(0),
+            None => Price::ZERO,
         };
         let amount = match specific.amount {
             Some(amount) => amount,
-            None => dec!(0),
+            None => Amount::ZERO,
         };
         let filled_amount = match specific.filled_amount {
             Some(amount) => amount,
-            None => dec!(0),
+            None => Amount::ZERO,
         };
-        OrderInfo::new(
+        let mut order_info = OrderInfo::new(
             self.get_unified_currency_pair(&specific.specific_currency_pair)
                 .expect("Expected known currency pair"),
             specific.exchange_order_id.clone(),
@@ -440,7 +443,10 @@ impl Bitmex {
             None,
             None,
             None,
-        )
+        );
+        order_info.tag = specific.text.clone();
+
+        order_info
     }
 
     pub(super) fn get_unified_currency_pair(
@@ -692,7 +698,7 @@ impl Bitmex {
 
                 Result::<_, anyhow::Error>::Ok(ExchangeBalance {
                     currency_code,
-                    balance: balance_info.balance * balance_rate,
+                    balance: balance_info.balance * *balance_rate,
                 })
             })
             .try_collect()
@@ -726,13 +732,17 @@ impl Bitmex {
                     average_entry_price: position.average_entry_price.unwrap_or_default(),
                     liquidation_price: position.liquidation_price.unwrap_or_default(),
                     leverage: position.leverage,
+                    margin_mode: Some(match position.cross_margin {
+                        true => MarginMode::Cross,
+                        false => MarginMode::Isolated,
+                    }),
                 };
 
                 Ok(ActivePosition::new(derivative_position, position.timestamp))
             }))
     }
 
-    pub(super) fn get_order_role_by_commission_amount(commission_amount: Decimal) -> OrderRole {
+    pub(super) fn get_order_role_by_commission_amount(commission_amount: Amount) -> OrderRole {
         match commission_amount.is_sign_positive() {
             true => OrderRole::Taker,
             false => OrderRole::Maker,
@@ -796,6 +806,8 @@ impl ExchangeClientBuilder for BitmexBuilder {
                     order_was_completed_error_for_cancellation: true,
                     supports_already_cancelled_order: true,
                     supports_stop_loss_order: true,
+                    supports_order_tag: true,
+                    max_client_order_id_len: Some(36),
                 },
                 OrderTradeOption {
                     supports_trade_time: true,
@@ -825,6 +837,10 @@ impl ExchangeClientBuilder for BitmexBuilder {
     fn get_exchange_id(&self) -> ExchangeId {
         "Bitmex".into()
     }
+
+    fn get_supported_account_types(&self) -> &'static [AccountType] {
+        &[AccountType::Spot, AccountType::Futures]
+    }
 }
 
 #[cfg(test)]
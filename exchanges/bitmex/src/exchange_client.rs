@@ -1,7 +1,8 @@
 use crate::bitmex::Bitmex;
-use anyhow::{bail, Result};
+use anyhow::{anyhow, bail, Result};
 use async_trait::async_trait;
 use itertools::Itertools;
+use mmb_core::exchanges::general::capabilities::ExchangeCapabilities;
 use mmb_core::exchanges::general::exchange::RequestResult;
 use mmb_core::exchanges::general::order::cancel::CancelOrderResult;
 use mmb_core::exchanges::general::order::create::CreateOrderResult;
@@ -48,6 +49,15 @@ impl ExchangeClient for Bitmex {
         }
     }
 
+    async fn cancel_all_orders_global(&self) -> Option<Result<()>> {
+        let result = match self.do_cancel_all_orders().await {
+            Ok(_) => Ok(()),
+            Err(error) => Err(anyhow!("Failed to cancel all orders: {error:?}")),
+        };
+
+        Some(result)
+    }
+
     async fn get_open_orders(&self) -> Result<Vec<OrderInfo>> {
         let response = self.request_open_orders(None).await?;
 
@@ -91,7 +101,7 @@ impl ExchangeClient for Bitmex {
     }
 
     async fn get_balance_and_positions(&self) -> Result<ExchangeBalancesAndPositions> {
-        Ok(match self.settings.is_margin_trading {
+        Ok(match self.settings.account_type.is_derivative() {
             true => {
                 let (balance_response, position_response) =
                     tokio::join!(self.request_get_balance(), self.request_get_position());
@@ -145,4 +155,14 @@ impl ExchangeClient for Bitmex {
         // TODO Need to receive Bitmex server time
         None
     }
+
+    fn capabilities(&self) -> ExchangeCapabilities {
+        ExchangeCapabilities {
+            market_orders: true,
+            maker_only_orders: true,
+            stop_loss_orders: true,
+            trailing_stop_orders: true,
+            ..Default::default()
+        }
+    }
 }
@@ -18,13 +18,12 @@ use mmb_core::exchanges::traits::{
     SendWebsocketMessageCb, Support,
 };
 use mmb_core::settings::ExchangeSettings;
-use mmb_domain::events::{EventSourceType, ExchangeEvent, Trade};
+use mmb_domain::events::{EventSourceType, ExchangeEvent, SubscriptionFailedEvent, Trade};
 use mmb_domain::market::{CurrencyCode, CurrencyId, CurrencyPair, SpecificCurrencyPair};
 use mmb_domain::order::fill::OrderFillType;
 use mmb_domain::order::snapshot::{Amount, OrderSide, Price};
 use mmb_domain::order_book::event::{EventType, OrderBookEvent};
 use mmb_domain::order_book::order_book_data::OrderBookData;
-use rust_decimal_macros::dec;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::any::Any;
@@ -181,11 +180,51 @@ impl Bitmex {
                     subscription_result.request
                 );
                 log::error!("{err}");
-                bail!(err)
+
+                if subscription_result.request["op"]
+                    != SubscriptionOperationType::Subscribe.as_str()
+                {
+                    bail!(err)
+                }
+
+                // connection is kept, so other subscriptions continue to work
+                self.report_failed_subscriptions(&subscription_result)
             }
         }
     }
 
+    fn report_failed_subscriptions(&self, subscription_result: &SubscriptionResult) -> Result<()> {
+        let reason = subscription_result
+            .error
+            .clone()
+            .unwrap_or_else(|| "subscription is rejected".to_owned());
+        let streams = subscription_result.request["args"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str);
+        for stream in streams {
+            // subscription argument has format `table:symbol`
+            let currency_pair = stream
+                .split_once(':')
+                .and_then(|(_, symbol)| self.get_unified_currency_pair(&symbol.into()).ok());
+            let event = SubscriptionFailedEvent {
+                exchange_account_id: self.settings.exchange_account_id,
+                stream: stream.to_owned(),
+                currency_pair,
+                reason: reason.clone(),
+            };
+            send_event(
+                &self.events_channel,
+                self.lifetime_manager.clone(),
+                self.settings.exchange_account_id,
+                ExchangeEvent::SubscriptionFailed(event),
+            )?;
+        }
+
+        Ok(())
+    }
+
     fn handle_websocket_data(&self, payload: BitmexPayloadData) -> Result<()> {
         match payload {
             BitmexPayloadData::OrderBookL2(data) | BitmexPayloadData::OrderBookL2_25(data) => {
@@ -244,7 +283,7 @@ impl Bitmex {
 
                         Self::add_order_book_info(
                             *price,
-                            dec!(0),
+                            Amount::ZERO,
                             record.side,
                             &mut order_book_data,
                         );
@@ -392,6 +431,7 @@ impl Bitmex {
                             data.client_order_id,
                             data.exchange_order_id,
                             EventSourceType::WebSocket,
+                            Some(data.timestamp),
                         );
                     }
                 }
@@ -399,6 +439,7 @@ impl Bitmex {
                     data.client_order_id,
                     data.exchange_order_id,
                     EventSourceType::WebSocket,
+                    Some(data.timestamp),
                 ),
                 BitmexOrderExecutionPayload::Rejected(_) => (), // Nothing to do cause it's been already handled during create_order() response handling
                 BitmexOrderExecutionPayload::Filled(variant)
@@ -517,6 +558,8 @@ struct InformationMessage<'a> {
 struct SubscriptionResult {
     success: bool,
     request: Value,
+    #[serde(default)]
+    error: Option<String>,
 }
 
 #[derive(Serialize)]
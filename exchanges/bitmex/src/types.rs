@@ -2,6 +2,7 @@ use anyhow::bail;
 use mmb_domain::events::TradeId;
 use mmb_domain::market::SpecificCurrencyPair;
 use mmb_domain::order::snapshot::{Amount, ClientOrderId, ExchangeOrderId, OrderSide, Price};
+use mmb_utils::decimal_parsing::deserialize_decimal;
 use mmb_utils::DateTime;
 use rust_decimal::Decimal;
 use serde::{de, Deserialize, Deserializer};
@@ -126,8 +127,10 @@ pub(crate) struct BitmexSymbol<'a> {
     pub(crate) quote_id: &'a str,
     pub(crate) state: &'a str,
     #[serde(rename = "tickSize")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) price_tick: Decimal,
     #[serde(rename = "lotSize")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) amount_tick: Decimal,
     #[serde(rename = "maxPrice")]
     pub(crate) max_price: Option<Price>,
@@ -272,6 +275,7 @@ pub(crate) struct BitmexOrderInfo<'a> {
     #[serde(rename = "ordStatus")]
     pub(crate) status: &'a str,
     pub(crate) side: OrderSide,
+    pub(crate) text: Option<String>,
 }
 
 /// Bitmex Order Book description
@@ -288,7 +292,9 @@ pub struct BitmexOrderBookInsert {
     pub symbol: SpecificCurrencyPair,
     pub id: u64,
     pub side: OrderSide,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub size: Amount,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub price: Price,
 }
 
@@ -304,6 +310,7 @@ pub(crate) struct BitmexOrderBookUpdate {
     pub(crate) symbol: SpecificCurrencyPair,
     pub(crate) id: u64,
     pub(crate) side: OrderSide,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) size: Amount,
 }
 
@@ -362,7 +369,9 @@ pub(crate) struct BitmexOrderBookUpdate {
 pub(crate) struct BitmexTradePayload {
     pub(crate) symbol: SpecificCurrencyPair,
     pub(crate) side: OrderSide,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) size: Amount,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) price: Price,
     #[serde(rename = "trdMatchID")]
     pub(crate) trade_id: TradeId,
@@ -378,6 +387,8 @@ pub(crate) struct BitmexOrderStatus<'a> {
     pub(crate) client_order_id: ClientOrderId,
     #[serde(rename = "orderID")]
     pub(crate) exchange_order_id: ExchangeOrderId,
+    #[serde(deserialize_with = "deserialize_datetime")]
+    pub(crate) timestamp: DateTime,
 }
 
 #[derive(Deserialize, Debug)]
@@ -391,12 +402,16 @@ pub(crate) struct BitmexOrderFillTrade<'a> {
     #[serde(rename = "orderID")]
     pub(crate) exchange_order_id: ExchangeOrderId,
     #[serde(rename = "lastPx")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) fill_price: Price,
     #[serde(rename = "lastQty")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) fill_amount: Amount,
     #[serde(rename = "cumQty")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) total_filled_amount: Amount,
     #[serde(rename = "orderQty")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) amount: Amount,
     #[serde(deserialize_with = "deserialize_datetime")]
     pub(crate) timestamp: DateTime,
@@ -405,9 +420,11 @@ pub(crate) struct BitmexOrderFillTrade<'a> {
     #[serde(rename = "settlCurrency")]
     pub(crate) currency: &'a str,
     #[serde(rename = "commission")]
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) commission_rate: Decimal,
     #[serde(rename = "execComm")]
-    pub(crate) commission_amount: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(crate) commission_amount: Amount,
 }
 
 #[derive(Deserialize, Debug)]
@@ -461,7 +478,8 @@ pub(crate) struct BitmexOrderFillDummy {}
 pub(crate) struct BitmexBalanceInfo<'a> {
     pub(crate) currency: &'a str,
     #[serde(rename = "availableMargin")]
-    pub(crate) balance: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(crate) balance: Amount,
 }
 
 /// Bitmex position response description
@@ -562,12 +580,16 @@ pub(crate) struct BitmexBalanceInfo<'a> {
 pub(crate) struct PositionPayload {
     pub(crate) symbol: SpecificCurrencyPair,
     #[serde(rename = "currentQty")]
-    pub(crate) amount: Decimal,
+    #[serde(deserialize_with = "deserialize_decimal")]
+    pub(crate) amount: Amount,
     #[serde(rename = "avgEntryPrice")]
     pub(crate) average_entry_price: Option<Price>,
     #[serde(rename = "liquidationPrice")]
     pub(crate) liquidation_price: Option<Price>,
+    #[serde(deserialize_with = "deserialize_decimal")]
     pub(crate) leverage: Decimal,
+    #[serde(rename = "crossMargin")]
+    pub(crate) cross_margin: bool,
     #[serde(rename = "isOpen")]
     pub(crate) is_open: bool,
     #[serde(deserialize_with = "deserialize_datetime")]
@@ -1,4 +1,5 @@
 use crate::bitmex::bitmex_builder::BitmexBuilder;
+use mmb_core::settings::AccountType;
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
 
@@ -6,7 +7,7 @@ use mmb_utils::logger::init_logger;
 async fn get_balance_successfully() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(v) => v,
         Err(_) => return,
     };
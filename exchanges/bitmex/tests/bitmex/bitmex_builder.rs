@@ -15,7 +15,7 @@ use mmb_core::exchanges::general::features::{
 use mmb_core::exchanges::hosts::Hosts;
 use mmb_core::exchanges::timeouts::requests_timeout_manager_factory::RequestTimeoutArguments;
 use mmb_core::infrastructure::init_lifetime_manager;
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::exchanges::commission::Commission;
 use mmb_domain::market::{CurrencyPair, ExchangeAccountId};
@@ -53,7 +53,7 @@ impl BitmexBuilder {
         BitmexBuilder::try_new_with_settings(settings, features, Commission::default()).await
     }
 
-    pub(crate) async fn build_account(is_margin_trading: bool) -> Result<Self> {
+    pub(crate) async fn build_account(account_type: AccountType) -> Result<Self> {
         BitmexBuilder::try_new(
             default_exchange_account_id(),
             ExchangeFeatures::new(
@@ -71,7 +71,7 @@ impl BitmexBuilder {
                 AllowedEventSourceType::default(),
             ),
             Commission::default(),
-            is_margin_trading,
+            account_type,
         )
         .await
     }
@@ -79,7 +79,7 @@ impl BitmexBuilder {
     pub(crate) async fn build_account_with_source_types(
         allowed_create_event_source_type: AllowedEventSourceType,
         allowed_cancel_event_source_type: AllowedEventSourceType,
-        is_margin_trading: bool,
+        account_type: AccountType,
     ) -> Result<Self> {
         let exchange_account_id: ExchangeAccountId = "Bitmex_0".parse().expect("in test");
         BitmexBuilder::try_new(
@@ -102,7 +102,7 @@ impl BitmexBuilder {
                 allowed_cancel_event_source_type,
             ),
             Commission::default(),
-            is_margin_trading,
+            account_type,
         )
         .await
     }
@@ -111,7 +111,7 @@ impl BitmexBuilder {
         exchange_account_id: ExchangeAccountId,
         features: ExchangeFeatures,
         commission: Commission,
-        is_margin_trading: bool,
+        account_type: AccountType,
     ) -> Result<Self> {
         let (api_key, secret_key) = match get_bitmex_credentials() {
             Ok((api_key, secret_key)) => (api_key, secret_key),
@@ -123,15 +123,11 @@ impl BitmexBuilder {
             )
         }
 
-        let mut settings = ExchangeSettings::new_short(
-            exchange_account_id,
-            api_key,
-            secret_key,
-            is_margin_trading,
-        );
+        let mut settings =
+            ExchangeSettings::new_short(exchange_account_id, api_key, secret_key, account_type);
 
         // Default currency pair for tests
-        match is_margin_trading {
+        match account_type.is_derivative() {
             true => {
                 settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
                     base: "XBT".into(),
@@ -6,7 +6,7 @@ use mmb_core::exchanges::general::features::{
     ExchangeFeatures, OpenOrdersType, OrderFeatures, OrderTradeOption, RestFillsFeatures,
     RestFillsType, WebSocketOptions,
 };
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::AllowedEventSourceType;
 use mmb_domain::market::ExchangeErrorType;
 use mmb_utils::cancellation_token::CancellationToken;
@@ -18,7 +18,7 @@ use tokio::time::sleep;
 async fn cancelled_successfully() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(bitmex_builder) => bitmex_builder,
         Err(_) => return,
     };
@@ -47,7 +47,7 @@ async fn cancelled_successfully() {
 async fn cancel_all() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(bitmex_builder) => bitmex_builder,
         Err(_) => return,
     };
@@ -87,7 +87,7 @@ async fn cancel_all() {
 async fn nothing_to_cancel() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(bitmex_builder) => bitmex_builder,
         Err(_) => return,
     };
@@ -123,8 +123,12 @@ async fn cancel_after_fill() {
         Ok((api_key, secret_key)) => (api_key, secret_key),
         Err(_) => return,
     };
-    let mut settings =
-        ExchangeSettings::new_short(default_exchange_account_id(), api_key, secret_key, true);
+    let mut settings = ExchangeSettings::new_short(
+        default_exchange_account_id(),
+        api_key,
+        secret_key,
+        AccountType::Futures,
+    );
     settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
         base: "XBT".into(),
         quote: "USD".into(),
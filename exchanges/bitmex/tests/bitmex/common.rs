@@ -64,7 +64,7 @@ pub(crate) async fn get_prices(
         .price;
 
     (
-        top_bid_price + price_precision.get_tick() * dec!(2),
+        top_bid_price + Price::new(price_precision.get_tick() * dec!(2)),
         low_bid_price,
     )
 }
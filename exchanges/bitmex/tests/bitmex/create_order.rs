@@ -1,11 +1,12 @@
 use crate::bitmex::bitmex_builder::BitmexBuilder;
 use core_tests::order::OrderProxy;
+use mmb_core::settings::AccountType;
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::order::event::OrderEventType;
+use mmb_domain::{amount, price};
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
 use rstest::rstest;
-use rust_decimal_macros::dec;
 use std::time::Duration;
 
 #[rstest]
@@ -19,7 +20,7 @@ async fn create_successfully(#[case] allowed_create_event_source_type: AllowedEv
     let mut bitmex_builder = match BitmexBuilder::build_account_with_source_types(
         allowed_create_event_source_type,
         AllowedEventSourceType::default(),
-        true,
+        AccountType::Futures,
     )
     .await
     {
@@ -68,7 +69,7 @@ async fn create_successfully(#[case] allowed_create_event_source_type: AllowedEv
 async fn should_fail() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(bitmex_builder) => bitmex_builder,
         Err(_) => return,
     };
@@ -77,8 +78,8 @@ async fn should_fail() {
         bitmex_builder.exchange.exchange_account_id,
         Some("FromShouldFailTest".to_owned()),
         CancellationToken::default(),
-        dec!(0.0000000000000000001),
-        dec!(1),
+        price!(0.0000000000000000001),
+        amount!(1),
         bitmex_builder.default_currency_pair,
     );
     order_proxy.timeout = Duration::from_secs(15);
@@ -5,7 +5,7 @@ use mmb_core::exchanges::general::features::{
     ExchangeFeatures, OpenOrdersType, OrderFeatures, OrderTradeOption, RestFillsFeatures,
     WebSocketOptions,
 };
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::AllowedEventSourceType;
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
@@ -19,8 +19,12 @@ async fn get_open_orders() {
         Ok((api_key, secret_key)) => (api_key, secret_key),
         Err(_) => return,
     };
-    let mut settings =
-        ExchangeSettings::new_short(default_exchange_account_id(), api_key, secret_key, true);
+    let mut settings = ExchangeSettings::new_short(
+        default_exchange_account_id(),
+        api_key,
+        secret_key,
+        AccountType::Futures,
+    );
     settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
         base: "XBT".into(),
         quote: "USD".into(),
@@ -78,8 +82,12 @@ async fn get_open_orders_by_currency_pair() {
         Ok((api_key, secret_key)) => (api_key, secret_key),
         Err(_) => return,
     };
-    let mut settings =
-        ExchangeSettings::new_short(default_exchange_account_id(), api_key, secret_key, true);
+    let mut settings = ExchangeSettings::new_short(
+        default_exchange_account_id(),
+        api_key,
+        secret_key,
+        AccountType::Futures,
+    );
     settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
         base: "XBT".into(),
         quote: "USD".into(),
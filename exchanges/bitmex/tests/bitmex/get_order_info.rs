@@ -1,5 +1,6 @@
 use crate::bitmex::bitmex_builder::BitmexBuilder;
 use core_tests::order::OrderProxy;
+use mmb_core::settings::AccountType;
 use mmb_domain::order::snapshot::ReservationId;
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
@@ -9,7 +10,7 @@ use std::time::Duration;
 async fn get_order_info() {
     init_logger();
 
-    let bitmex_builder = match BitmexBuilder::build_account(true).await {
+    let bitmex_builder = match BitmexBuilder::build_account(AccountType::Futures).await {
         Ok(bitmex_builder) => bitmex_builder,
         Err(_) => return,
     };
@@ -5,7 +5,7 @@ use mmb_core::exchanges::general::features::{
     ExchangeFeatures, OpenOrdersType, OrderFeatures, OrderTradeOption, RestFillsFeatures,
     RestFillsType, WebSocketOptions,
 };
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::AllowedEventSourceType;
 use mmb_utils::cancellation_token::CancellationToken;
 use mmb_utils::logger::init_logger;
@@ -20,8 +20,12 @@ async fn test_positions() {
         Ok((api_key, secret_key)) => (api_key, secret_key),
         Err(_) => return,
     };
-    let mut settings =
-        ExchangeSettings::new_short(default_exchange_account_id(), api_key, secret_key, true);
+    let mut settings = ExchangeSettings::new_short(
+        default_exchange_account_id(),
+        api_key,
+        secret_key,
+        AccountType::Futures,
+    );
     settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
         base: "XBT".into(),
         quote: "USD".into(),
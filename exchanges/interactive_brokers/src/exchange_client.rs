@@ -2,6 +2,7 @@ use crate::interactive_brokers::InteractiveBrokers;
 use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use function_name::named;
+use mmb_core::exchanges::general::capabilities::ExchangeCapabilities;
 use mmb_core::exchanges::general::exchange::RequestResult;
 use mmb_core::exchanges::general::order::cancel::CancelOrderResult;
 use mmb_core::exchanges::general::order::create::CreateOrderResult;
@@ -154,7 +155,7 @@ impl ExchangeClient for InteractiveBrokers {
     /// TODO: Optimize - rewrite with no `Vec` reallocation
     async fn get_balance_and_positions(&self) -> anyhow::Result<ExchangeBalancesAndPositions> {
         // TODO: Optimize - rewrite with no `Vec` reallocation
-        let positions = match self.get_settings().is_margin_trading {
+        let positions = match self.get_settings().account_type.is_derivative() {
             true => Some(
                 self.get_positions_inner()
                     .await?
@@ -258,4 +259,9 @@ impl ExchangeClient for InteractiveBrokers {
     async fn get_server_time(&self) -> Option<anyhow::Result<i64>> {
         todo!()
     }
+
+    fn capabilities(&self) -> ExchangeCapabilities {
+        // Only plain limit orders are implemented
+        ExchangeCapabilities::default()
+    }
 }
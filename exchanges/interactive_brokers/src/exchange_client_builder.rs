@@ -7,7 +7,7 @@ use mmb_core::exchanges::timeouts::requests_timeout_manager_factory::RequestTime
 use mmb_core::exchanges::timeouts::timeout_manager::TimeoutManager;
 use mmb_core::exchanges::traits::{ExchangeClientBuilder, ExchangeClientBuilderResult};
 use mmb_core::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use mmb_core::settings::ExchangeSettings;
+use mmb_core::settings::{AccountType, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::market::ExchangeId;
 use mmb_domain::order::pool::OrdersPool;
@@ -55,4 +55,8 @@ impl ExchangeClientBuilder for InteractiveBrokersBuilder {
     fn get_exchange_id(&self) -> ExchangeId {
         "IBKR".into()
     }
+
+    fn get_supported_account_types(&self) -> &'static [AccountType] {
+        &[AccountType::Spot, AccountType::Futures]
+    }
 }
@@ -29,10 +29,11 @@ use mmb_domain::exchanges::symbol::Symbol;
 use mmb_domain::market::{CurrencyCode, CurrencyPair, ExchangeErrorType};
 use mmb_domain::order::fill::OrderFillType;
 use mmb_domain::order::snapshot::{
-    ClientOrderId, ExchangeOrderId, OrderInfo, OrderRole, OrderSide as MmbOrderSide,
-    OrderStatus as MmbOrderStatus,
+    Amount, ClientOrderId, ExchangeOrderId, OrderInfo, OrderRole, OrderSide as MmbOrderSide,
+    OrderStatus as MmbOrderStatus, Price,
 };
 use mmb_domain::position::{ActivePosition, ActivePositionId, DerivativePosition};
+use mmb_utils::decimal_parsing::{parse_decimal, DecimalParsingTolerance};
 use mmb_utils::DateTime;
 use rust_decimal::Decimal;
 use std::collections::HashMap;
@@ -154,8 +155,8 @@ impl InteractiveBrokers {
         &self,
         currency_pair: &CurrencyPair,
         side: MmbOrderSide,
-        price: Decimal,
-        amount: Decimal,
+        price: Price,
+        amount: Amount,
     ) -> anyhow::Result<ExchangeOrderId> {
         let next_id = self.next_order_id();
         let contract = self
@@ -219,8 +220,8 @@ impl InteractiveBrokers {
         &self,
         currency_pair: &CurrencyPair,
         side: MmbOrderSide,
-        price: Decimal,
-        amount: Decimal,
+        price: Price,
+        amount: Amount,
     ) -> anyhow::Result<ExchangeOrderId> {
         let exchange_order_id = self
             .create_order_request(currency_pair, side, price, amount)
@@ -288,11 +289,13 @@ impl InteractiveBrokers {
                     let is_expected_status = status == IbOrderStatus::Cancelled;
 
                     if is_expected_order_id && is_expected_status {
-                        let filled = Decimal::from_f64_retain(filled).ok_or_else(|| {
-                            format!(
+                        let filled = Decimal::from_f64_retain(filled)
+                            .map(Amount::new)
+                            .ok_or_else(|| {
+                                format!(
                                 "fn {f_n}: order filled amount: Decimal::from_f64_retain error.",
                             )
-                        });
+                            });
 
                         match filled {
                             Ok(filled) => CancelOrderResult::succeed(
@@ -553,18 +556,26 @@ impl InteractiveBrokers {
                 .try_into()
                 .map_err(|e: String| anyhow!(e))?;
             // TODO: Check if right value used
-            let price = Decimal::from_f64_retain(order.lmt_price).context(anyhow!(
-                "fn {f_n}: Order price: Decimal::from_f64_retain error.",
-            ))?;
-            let amount = Decimal::from_f64_retain(order.total_quantity).context(anyhow!(
-                "fn {f_n}: Order amount: Decimal::from_f64_retain error.",
-            ))?;
-            let filled_amount = Decimal::from_f64_retain(order.filled_quantity).context(
-                anyhow!("fn {f_n}: Order filled amount: Decimal::from_f64_retain error."),
-            )?;
-            let commission_amount = Decimal::from_f64_retain(order_state.commission).context(
-                anyhow!("fn {f_n}: Order commission amount: Decimal::from_f64_retain error."),
-            )?;
+            let price = Decimal::from_f64_retain(order.lmt_price)
+                .map(Price::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order price: Decimal::from_f64_retain error.",
+                ))?;
+            let amount = Decimal::from_f64_retain(order.total_quantity)
+                .map(Amount::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order amount: Decimal::from_f64_retain error.",
+                ))?;
+            let filled_amount = Decimal::from_f64_retain(order.filled_quantity)
+                .map(Amount::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order filled amount: Decimal::from_f64_retain error."
+                ))?;
+            let commission_amount = Decimal::from_f64_retain(order_state.commission)
+                .map(Amount::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order commission amount: Decimal::from_f64_retain error."
+                ))?;
 
             Ok(OrderInfo::new(
                 CurrencyPair::from_codes(base, quote),
@@ -598,15 +609,21 @@ impl InteractiveBrokers {
         {
             let order_id = order.order_id;
             // TODO: Check if right value used
-            let price = Decimal::from_f64_retain(order.lmt_price).context(anyhow!(
-                "fn {f_n}: Order price: Decimal::from_f64_retain error.",
-            ))?;
-            let amount = Decimal::from_f64_retain(order.total_quantity).context(anyhow!(
-                "fn {f_n}: Order amount: Decimal::from_f64_retain error.",
-            ))?;
-            let commission_amount = Decimal::from_f64_retain(order_state.commission).context(
-                anyhow!("fn {f_n}: Order commission amount: Decimal::from_f64_retain error."),
-            )?;
+            let price = Decimal::from_f64_retain(order.lmt_price)
+                .map(Price::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order price: Decimal::from_f64_retain error.",
+                ))?;
+            let amount = Decimal::from_f64_retain(order.total_quantity)
+                .map(Amount::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order amount: Decimal::from_f64_retain error.",
+                ))?;
+            let commission_amount = Decimal::from_f64_retain(order_state.commission)
+                .map(Amount::new)
+                .context(anyhow!(
+                    "fn {f_n}: Order commission amount: Decimal::from_f64_retain error."
+                ))?;
 
             Ok(OrderTrade::new(
                 order_id.into(),
@@ -635,9 +652,9 @@ impl InteractiveBrokers {
             value, currency, ..
         } = msg
         {
-            let balance = Decimal::from_str(&value).with_context(|| {
-                format!("fn {f_n}: account balance: Decimal::from_f64_retain error.")
-            })?;
+            let balance = parse_decimal(&value, DecimalParsingTolerance::Lenient)
+                .map(Amount::new)
+                .with_context(|| format!("fn {f_n}: account balance: parse_decimal error."))?;
 
             Ok(ExchangeBalance {
                 currency_code: CurrencyCode::from(currency.as_str()),
@@ -663,10 +680,13 @@ impl InteractiveBrokers {
         } = msg
         {
             let position = Decimal::from_f64_retain(position)
+                .map(Amount::new)
                 .with_context(|| format!("fn {f_n}: position: Decimal::from_f64_retain error."))?;
-            let avg_cost = Decimal::from_f64_retain(avg_cost).with_context(|| {
-                format!("fn {f_n}: Position avg_cost: Decimal::from_f64_retain error.")
-            })?;
+            let avg_cost = Decimal::from_f64_retain(avg_cost)
+                .map(Price::new)
+                .with_context(|| {
+                    format!("fn {f_n}: Position avg_cost: Decimal::from_f64_retain error.")
+                })?;
             let leverage = Decimal::from_f64_retain(1.0).with_context(|| {
                 format!("fn {f_n}: Position leverage: Decimal::from_f64_retain error.")
             })?;
@@ -679,6 +699,7 @@ impl InteractiveBrokers {
                 average_entry_price: avg_cost,
                 liquidation_price: avg_cost,
                 leverage,
+                margin_mode: None,
             };
 
             // We don't receive `timestamp` from exchange
@@ -707,16 +728,20 @@ impl InteractiveBrokers {
             order_state,
         } = msg
         {
-            let fill_price = Decimal::from_f64_retain(order.lmt_price).with_context(|| {
-                format!("fn {f_n}: order fill price: Decimal::from_f64_retain error.",)
-            })?;
-            let total_quantity =
-                Decimal::from_f64_retain(order.total_quantity).with_context(|| {
+            let fill_price = Decimal::from_f64_retain(order.lmt_price)
+                .map(Price::new)
+                .with_context(|| {
+                    format!("fn {f_n}: order fill price: Decimal::from_f64_retain error.",)
+                })?;
+            let total_quantity = Decimal::from_f64_retain(order.total_quantity)
+                .map(Amount::new)
+                .with_context(|| {
                     format!("fn {f_n}: order total filled amount: Decimal::from_f64_retain error.",)
                 })?;
 
             let fill_amount = {
                 let filled_quantity = Decimal::from_f64_retain(order.filled_quantity)
+                    .map(Amount::new)
                     .with_context(|| {
                         format!("fn {f_n}: order filled amount: Decimal::from_f64_retain error.",)
                     })?;
@@ -730,8 +755,9 @@ impl InteractiveBrokers {
             let commission_currency_code =
                 CurrencyCode::from(order_state.commission_currency.as_str());
 
-            let commission_amount =
-                Decimal::from_f64_retain(order_state.commission).with_context(|| {
+            let commission_amount = Decimal::from_f64_retain(order_state.commission)
+                .map(Amount::new)
+                .with_context(|| {
                     format!("fn {f_n}: order commission amount: Decimal::from_f64_retain error.",)
                 })?;
 
@@ -806,17 +832,19 @@ impl InteractiveBrokers {
     fn make_order(
         &self,
         side: MmbOrderSide,
-        price: Decimal,
-        amount: Decimal,
+        price: Price,
+        amount: Amount,
     ) -> anyhow::Result<Order> {
         let f_n = function_name!();
 
         let side: IbOrderSide = side.into();
 
         let price = price
+            .value()
             .try_into()
             .context(anyhow!("fn {f_n}: Error converting order `price` to f64."))?;
         let amount = amount
+            .value()
             .try_into()
             .context(anyhow!("fn {f_n}: Error converting order `amount` to f64."))?;
 
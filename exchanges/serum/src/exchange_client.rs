@@ -11,6 +11,7 @@ use solana_program::pubkey::Pubkey;
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use mmb_core::exchanges::general::capabilities::ExchangeCapabilities;
 use mmb_core::exchanges::general::exchange::RequestResult;
 use mmb_core::exchanges::general::order::cancel::CancelOrderResult;
 use mmb_core::exchanges::general::order::create::CreateOrderResult;
@@ -172,4 +173,9 @@ impl ExchangeClient for Serum {
     async fn get_server_time(&self) -> Option<Result<i64>> {
         None
     }
+
+    fn capabilities(&self) -> ExchangeCapabilities {
+        // Only plain limit orders are implemented
+        ExchangeCapabilities::default()
+    }
 }
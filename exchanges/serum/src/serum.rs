@@ -60,7 +60,7 @@ use mmb_core::exchanges::traits::{
     HandleOrderFilledCb, HandleTradeCb, OrderCancelledCb, OrderCreatedCb,
 };
 use mmb_core::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use mmb_core::settings::ExchangeSettings;
+use mmb_core::settings::{AccountType, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeBalance, ExchangeEvent};
 use mmb_domain::exchanges::symbol::{Precision, Symbol};
 use mmb_domain::market::{
@@ -175,8 +175,8 @@ impl Serum {
             settings,
             payer,
             orders,
-            order_created_callback: Box::new(|_, _, _| {}),
-            order_cancelled_callback: Box::new(|_, _, _| {}),
+            order_created_callback: Box::new(|_, _, _, _| {}),
+            order_cancelled_callback: Box::new(|_, _, _, _| {}),
             handle_order_filled_callback: Box::new(|_| {}),
             handle_trade_callback: Box::new(|_, _| {}),
             unified_to_specific: Default::default(),
@@ -564,6 +564,8 @@ impl Serum {
                             owner: Some(market_info.owner_address),
                             actual_status: OrderStatus::Created,
                         })),
+                        tag: None,
+                        expire_time: None,
                     })
                 }
             }
@@ -983,6 +985,10 @@ impl ExchangeClientBuilder for SerumBuilder {
     fn get_exchange_id(&self) -> ExchangeId {
         "Serum".into()
     }
+
+    fn get_supported_account_types(&self) -> &'static [AccountType] {
+        &[AccountType::Spot]
+    }
 }
 
 pub(super) struct FillEventsCache {
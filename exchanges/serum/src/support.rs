@@ -266,8 +266,9 @@ impl Serum {
             .map(|order| (order.client_order_id.clone(), order))
             .collect();
 
+        // only creating and canceling orders are handled, so finished ones aren't scanned
         self.orders
-            .cache_by_client_id
+            .not_finished
             .iter()
             .filter(|order| order.currency_pair() == currency_pair)
             .for_each(|order_ref| {
@@ -284,6 +285,7 @@ impl Serum {
                                         client_order_id.clone(),
                                         order_from_event.exchange_order_id.clone(),
                                         EventSourceType::Rpc,
+                                        None,
                                     );
 
                                     serum_extension_data.actual_status = OrderStatus::Created;
@@ -304,6 +306,7 @@ impl Serum {
                                     client_order_id.clone(),
                                     exchange_order_id.clone(),
                                     EventSourceType::Rpc,
+                                    None,
                                 );
 
                                 serum_extension_data.actual_status = OrderStatus::Canceled;
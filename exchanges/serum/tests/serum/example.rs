@@ -39,7 +39,7 @@ async fn example() {
         parse_settings::<ExampleStrategySettings>(include_str!("config.toml"), &credentials)
             .expect("Error loading initial settings");
 
-    let init_settings = InitSettings::Directly(settings);
+    let init_settings = InitSettings::Directly(Box::new(settings));
     loop {
         let engine = launch_trading_engine(&engine_config, init_settings.clone())
             .await
@@ -76,6 +76,7 @@ async fn example() {
             settings.strategy.currency_pair(),
             settings.strategy.spread,
             settings.strategy.max_amount,
+            settings.strategy.amount_quantization.clone(),
             ctx.clone(),
         );
 
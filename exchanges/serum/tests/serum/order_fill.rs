@@ -164,6 +164,7 @@ async fn receive_exchange_order_event(
         if let ExchangeEvent::OrderEvent(order_event) = &event {
             if let OrderEventType::OrderFilled {
                 cloned_order: order,
+                ..
             } = &order_event.event_type
             {
                 if client_order_id == order.header.client_order_id {
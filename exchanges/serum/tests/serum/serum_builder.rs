@@ -19,7 +19,7 @@ use mmb_core::exchanges::timeouts::timeout_manager::TimeoutManager;
 use mmb_core::exchanges::traits::{ExchangeClientBuilder, ExchangeClientBuilderResult};
 use mmb_core::infrastructure::init_lifetime_manager;
 use mmb_core::lifecycle::app_lifetime_manager::AppLifetimeManager;
-use mmb_core::settings::{CurrencyPairSetting, ExchangeSettings};
+use mmb_core::settings::{AccountType, CurrencyPairSetting, ExchangeSettings};
 use mmb_domain::events::{AllowedEventSourceType, ExchangeEvent};
 use mmb_domain::exchanges::commission::Commission;
 use mmb_domain::market::{ExchangeAccountId, ExchangeId};
@@ -86,8 +86,12 @@ impl SerumBuilder {
         commission: Commission,
         secret_key: String,
     ) -> Result<Self> {
-        let mut settings =
-            ExchangeSettings::new_short(exchange_account_id, "".to_string(), secret_key, false);
+        let mut settings = ExchangeSettings::new_short(
+            exchange_account_id,
+            "".to_string(),
+            secret_key,
+            AccountType::Spot,
+        );
 
         settings.currency_pairs = Some(vec![CurrencyPairSetting::Ordinary {
             base: "sol".into(),
@@ -198,4 +202,8 @@ impl ExchangeClientBuilder for ExchangeSerumBuilder {
     fn get_exchange_id(&self) -> ExchangeId {
         "Serum".into()
     }
+
+    fn get_supported_account_types(&self) -> &'static [AccountType] {
+        &[AccountType::Spot]
+    }
 }
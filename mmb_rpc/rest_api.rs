@@ -22,12 +22,33 @@ pub trait MmbRpc {
 
     #[rpc(name = "stats")]
     fn stats(&self) -> Result<String>;
+
+    #[rpc(name = "orders")]
+    fn orders(&self) -> Result<String>;
+
+    #[rpc(name = "balances")]
+    fn balances(&self, valuation_mode: String, quote_currency_codes: Vec<String>)
+        -> Result<String>;
+
+    #[rpc(name = "pnl")]
+    fn pnl(&self, valuation_mode: String, quote_currency_codes: Vec<String>) -> Result<String>;
+
+    #[rpc(name = "snapshot")]
+    fn snapshot(&self) -> Result<String>;
+
+    #[rpc(name = "resume_order_placement")]
+    fn resume_order_placement(
+        &self,
+        exchange_account_id: String,
+        currency_pair: String,
+    ) -> Result<String>;
 }
 
 pub enum ErrorCode {
     StopperIsNone = 1,
     UnableToSendSignal = 2,
     FailedToSaveNewConfig = 3,
+    FailedToSerializeResponse = 4,
 }
 
 pub fn server_side_error(code: ErrorCode) -> Error {
@@ -35,6 +56,7 @@ pub fn server_side_error(code: ErrorCode) -> Error {
         ErrorCode::StopperIsNone => "Server stopper is none",
         ErrorCode::UnableToSendSignal => "Unable to send signal",
         ErrorCode::FailedToSaveNewConfig => "Failed to save new config",
+        ErrorCode::FailedToSerializeResponse => "Failed to serialize response",
     };
     log::error!("Rest API error: {}", reason);
     Error::new(jsonrpc_core::ErrorCode::ServerError(code as i64))
@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::future::Future;
+use uuid::Uuid;
+
+tokio::task_local! {
+    static CORRELATION_ID: CorrelationId;
+}
+
+/// Identifier for tracing a single operation (e.g. order creation) across log lines of
+/// all requests and events related to it.
+/// NOTE: should not be used as metrics label because of unbounded cardinality
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CorrelationId(Uuid);
+
+impl CorrelationId {
+    pub fn generate() -> Self {
+        CorrelationId(Uuid::new_v4())
+    }
+
+    /// Correlation id of operation executed in current task if any
+    pub fn current() -> Option<Self> {
+        CORRELATION_ID.try_with(|id| *id).ok()
+    }
+
+    /// Correlation id of operation executed in current task or new one
+    pub fn current_or_generate() -> Self {
+        Self::current().unwrap_or_else(Self::generate)
+    }
+
+    /// Execute future with specified correlation id available through `CorrelationId::current()`
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CORRELATION_ID.scope(self, future).await
+    }
+}
+
+impl Display for CorrelationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Helper for logging optional correlation id
+pub struct OptionCorrelationId(pub Option<CorrelationId>);
+
+impl Display for OptionCorrelationId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(correlation_id) => write!(f, "{correlation_id}"),
+            None => write!(f, "-"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn current_correlation_id_inside_scope() {
+        assert_eq!(CorrelationId::current(), None);
+
+        let correlation_id = CorrelationId::generate();
+        let current = correlation_id
+            .scope(async { CorrelationId::current() })
+            .await;
+
+        assert_eq!(current, Some(correlation_id));
+        assert_eq!(CorrelationId::current(), None);
+    }
+}
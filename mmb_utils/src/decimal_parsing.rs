@@ -0,0 +1,183 @@
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Representations of decimals in exchange responses which parsing accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecimalParsingTolerance {
+    /// Plain notation only, e.g. "0.00000100"
+    Strict,
+    /// Scientific notation (e.g. "1E-8"), surrounding whitespaces and explicit plus sign as well
+    #[default]
+    Lenient,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecimalParseError {
+    pub value: String,
+    pub reason: String,
+}
+
+impl DecimalParseError {
+    fn new(value: &str, reason: impl Into<String>) -> Self {
+        DecimalParseError {
+            value: value.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for DecimalParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unable to parse decimal from '{}': {}",
+            self.value, self.reason
+        )
+    }
+}
+
+impl std::error::Error for DecimalParseError {}
+
+/// Parse decimal from exchange response. Trailing zeros are stripped, so the same value
+/// is parsed uniformly regardless of its representation
+pub fn parse_decimal(
+    value: &str,
+    tolerance: DecimalParsingTolerance,
+) -> Result<Decimal, DecimalParseError> {
+    let trimmed = match tolerance {
+        DecimalParsingTolerance::Strict => {
+            if value.trim() != value {
+                return Err(DecimalParseError::new(
+                    value,
+                    "surrounding whitespaces aren't allowed",
+                ));
+            }
+            if value.starts_with('+') {
+                return Err(DecimalParseError::new(
+                    value,
+                    "explicit plus sign isn't allowed",
+                ));
+            }
+
+            value
+        }
+        DecimalParsingTolerance::Lenient => {
+            let trimmed = value.trim();
+            trimmed.strip_prefix('+').unwrap_or(trimmed)
+        }
+    };
+
+    if trimmed.is_empty() {
+        return Err(DecimalParseError::new(value, "value is empty"));
+    }
+
+    let is_scientific = trimmed.contains(['e', 'E']);
+    let parsed = match (is_scientific, tolerance) {
+        (false, _) => Decimal::from_str(trimmed),
+        (true, DecimalParsingTolerance::Lenient) => Decimal::from_scientific(trimmed),
+        (true, DecimalParsingTolerance::Strict) => {
+            return Err(DecimalParseError::new(
+                value,
+                "scientific notation isn't allowed",
+            ))
+        }
+    };
+
+    parsed
+        .map(|x| x.normalize())
+        .map_err(|err| DecimalParseError::new(value, err.to_string()))
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DecimalRepresentation {
+    String(String),
+    Number(serde_json::Number),
+}
+
+/// Deserialize decimal represented by string or number with lenient parsing.
+/// Types constructed from decimal (e.g. prices and amounts) are supported as well.
+/// Usage: `#[serde(deserialize_with = "deserialize_decimal")]`
+pub fn deserialize_decimal<'de, D: Deserializer<'de>, T: From<Decimal>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    let value = match DecimalRepresentation::deserialize(deserializer)? {
+        DecimalRepresentation::String(value) => value,
+        DecimalRepresentation::Number(value) => value.to_string(),
+    };
+
+    parse_decimal(&value, DecimalParsingTolerance::Lenient)
+        .map(T::from)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn parse_exchange_specific_representations() {
+        let cases = [
+            // Binance filters and balances
+            ("0.00000100", dec!(0.000001)),
+            ("1000.00000000", dec!(1000)),
+            // scientific notation of small prices and amounts
+            ("1E-8", dec!(0.00000001)),
+            ("1e-8", dec!(0.00000001)),
+            ("5.0E-4", dec!(0.0005)),
+            ("-2.5E+3", dec!(-2500)),
+            ("1.2E3", dec!(1200)),
+            (" 42 ", dec!(42)),
+            ("+1.5", dec!(1.5)),
+        ];
+
+        for (value, expected) in cases {
+            let parsed = parse_decimal(value, DecimalParsingTolerance::Lenient).expect("in test");
+            assert_eq!(parsed, expected, "value '{value}'");
+            assert_eq!(parsed.to_string(), expected.normalize().to_string());
+        }
+    }
+
+    #[test]
+    fn strict_parsing_accepts_plain_notation_only() {
+        let strict = DecimalParsingTolerance::Strict;
+        assert_eq!(parse_decimal("0.00000100", strict), Ok(dec!(0.000001)));
+
+        for value in ["1E-8", " 42", "42 ", "+1.5"] {
+            assert!(parse_decimal(value, strict).is_err(), "value '{value}'");
+        }
+    }
+
+    #[test]
+    fn return_error_on_malformed_values() {
+        for value in ["", "  ", "abc", "1..2", "1E", "E-8", "NaN", "0x10"] {
+            let error =
+                parse_decimal(value, DecimalParsingTolerance::Lenient).expect_err("in test");
+            assert_eq!(error.value, value);
+        }
+    }
+
+    #[test]
+    fn deserialize_decimal_from_string_or_number() {
+        #[derive(Deserialize)]
+        struct Response {
+            #[serde(deserialize_with = "deserialize_decimal")]
+            price: Decimal,
+        }
+
+        for json in [
+            r#"{"price":"1E-8"}"#,
+            r#"{"price":"0.00000001"}"#,
+            r#"{"price":1e-8}"#,
+        ] {
+            let response: Response = serde_json::from_str(json).expect("in test");
+            assert_eq!(response.price, dec!(0.00000001), "json {json}");
+        }
+
+        assert!(serde_json::from_str::<Response>(r#"{"price":"1..2"}"#).is_err());
+    }
+}
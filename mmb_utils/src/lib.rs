@@ -17,7 +17,9 @@
 )]
 
 pub mod cancellation_token;
+pub mod correlation_id;
 pub mod decimal_inverse_sign;
+pub mod decimal_parsing;
 pub mod impl_id;
 pub mod impl_mocks;
 pub mod impl_table_types;
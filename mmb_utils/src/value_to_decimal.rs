@@ -1,12 +1,17 @@
-use std::str::FromStr;
-
-use anyhow::{Context, Result};
+use crate::decimal_parsing::{parse_decimal, DecimalParsingTolerance};
+use anyhow::{bail, Context, Result};
 use rust_decimal::Decimal;
 use serde_json::Value;
 
 pub trait GetOrErr {
     fn get_as_str(&self, key: &str) -> Result<String>;
     fn get_as_decimal(&self, key: &str) -> Option<Decimal>;
+    /// `None` if there is no value by key. Error if value is malformed
+    fn get_as_decimal_with(
+        &self,
+        key: &str,
+        tolerance: DecimalParsingTolerance,
+    ) -> Result<Option<Decimal>>;
 }
 
 impl GetOrErr for Value {
@@ -20,8 +25,25 @@ impl GetOrErr for Value {
     }
 
     fn get_as_decimal(&self, key: &str) -> Option<Decimal> {
-        self.get(key)
-            .and_then(|value| value.as_str())
-            .and_then(|value| Decimal::from_str(value).ok())
+        self.get_as_decimal_with(key, DecimalParsingTolerance::Lenient)
+            .ok()
+            .flatten()
+    }
+
+    fn get_as_decimal_with(
+        &self,
+        key: &str,
+        tolerance: DecimalParsingTolerance,
+    ) -> Result<Option<Decimal>> {
+        let value = match self.get(key) {
+            None | Some(Value::Null) => return Ok(None),
+            Some(Value::String(value)) => value.clone(),
+            Some(Value::Number(value)) => value.to_string(),
+            Some(value) => bail!("Unable to get {key} as decimal from {value}"),
+        };
+
+        parse_decimal(&value, tolerance)
+            .map(Some)
+            .with_context(|| format!("Unable to get {key} as decimal"))
     }
 }
@@ -1,6 +1,5 @@
 use std::collections::HashMap;
 
-use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Postgres};
 
@@ -17,7 +16,7 @@ pub struct BalancesService {
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct BalancesRecord {
-    pub balances_by_exchange_id: Option<HashMap<ExchangeId, HashMap<CurrencyCode, Decimal>>>,
+    pub balances_by_exchange_id: Option<HashMap<ExchangeId, HashMap<CurrencyCode, Amount>>>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -1,11 +1,10 @@
 use chrono::DateTime;
 use itertools::Itertools;
 use paperclip::actix::Apiv2Schema;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::{Pool, Postgres};
 
-use mmb_domain::order::snapshot::{Amount, Price};
-
 use crate::services::data_provider::model::EventTimedRecord;
 use crate::types::{CurrencyPair, ExchangeId};
 
@@ -18,8 +17,8 @@ pub struct ExplanationService {
 #[derive(Serialize, Deserialize, Apiv2Schema)]
 #[serde(rename_all(deserialize = "snake_case", serialize = "camelCase"))]
 pub struct PriceLevelRecord {
-    pub price: Price,
-    pub amount: Amount,
+    pub price: Decimal,
+    pub amount: Decimal,
     pub reasons: Vec<String>,
     pub mode_name: String,
 }
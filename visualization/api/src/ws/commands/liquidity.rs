@@ -229,7 +229,7 @@ fn calc_indicators(
     total_bid: Option<Amount>,
     total_ask: Option<Amount>,
     desired_amount: Amount,
-) -> (Option<Decimal>, Option<Decimal>, Decimal, Decimal, Decimal) {
+) -> (Option<Decimal>, Option<Amount>, Decimal, Decimal, Decimal) {
     let spread = match (top_ask_price, top_bid_price) {
         (Some(ask), Some(bid)) => Some((ask - bid) / ask * dec!(100)),
         _ => None,
@@ -266,18 +266,19 @@ mod tests {
     use rust_decimal_macros::dec;
 
     use crate::ws::commands::liquidity::calc_indicators;
+    use mmb_domain::{amount, price};
 
     #[test]
     fn calc_indicators_test_values() {
         let (spread, total_volume, volume_pct, bid_pct, ask_pct) = calc_indicators(
-            Some(dec!(1)),
-            Some(dec!(2)),
-            Some(dec!(0.0008)),
-            Some(dec!(0.0002)),
-            dec!(0.001),
+            Some(price!(1)),
+            Some(price!(2)),
+            Some(amount!(0.0008)),
+            Some(amount!(0.0002)),
+            amount!(0.001),
         );
         assert_eq!(spread, Some(dec!(50)));
-        assert_eq!(total_volume, Some(dec!(0.001)));
+        assert_eq!(total_volume, Some(amount!(0.001)));
         assert_eq!(bid_pct, dec!(80));
         assert_eq!(ask_pct, dec!(20));
         assert_eq!(volume_pct, dec!(100));
@@ -286,7 +287,7 @@ mod tests {
     #[test]
     fn calc_indicators_test_none() {
         let (spread, total_volume, volume_pct, bid_pct, ask_pct) =
-            calc_indicators(None, None, None, None, dec!(0.001));
+            calc_indicators(None, None, None, None, amount!(0.001));
         assert_eq!(spread, None);
         assert_eq!(total_volume, None);
         assert_eq!(bid_pct, Decimal::zero());
@@ -55,13 +55,18 @@ pub async fn start_visualization_data_saving(
                     ExchangeEvent::OrderBookEvent(ref ob_event) => {
                         snapshots_service.update(ob_event)
                     }
+                    ExchangeEvent::Disconnected(ref disconnected) => {
+                        snapshots_service
+                            .discard_snapshots(disconnected.exchange_account_id.exchange_id);
+                        None
+                    }
                     ExchangeEvent::OrderEvent(order_event) => match order_event.event_type {
                         OrderEventType::CreateOrderSucceeded
                         | OrderEventType::OrderCompleted { .. }
                         | OrderEventType::CancelOrderSucceeded => {
                             Some(order_event.order.header().market_account_id())
                         }
-                        OrderEventType::OrderFilled { cloned_order } => {
+                        OrderEventType::OrderFilled { cloned_order, .. } => {
                             save_transaction(
                                 &ctx,
                                 &cloned_order,